@@ -0,0 +1,112 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::io::{self, Read, Write};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_io::{AsyncBufRead, AsyncRead, AsyncWrite};
+
+use crate::mem::Memory;
+use crate::{BytesBufWriter, BytesViewAsyncReader};
+
+impl AsyncRead for BytesViewAsyncReader {
+    #[cfg_attr(test, mutants::skip)] // Trivial forwarder.
+    fn poll_read(self: Pin<&mut Self>, _cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        Poll::Ready(self.get_mut().inner.read(buf))
+    }
+}
+
+impl AsyncBufRead for BytesViewAsyncReader {
+    #[cfg_attr(test, mutants::skip)] // Trivial forwarder.
+    fn poll_fill_buf(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        Poll::Ready(Ok(self.get_mut().inner.first_slice()))
+    }
+
+    #[cfg_attr(test, mutants::skip)] // Trivial forwarder.
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().inner.advance(amt);
+    }
+}
+
+impl<M: Memory + Unpin> AsyncWrite for BytesBufWriter<M> {
+    #[cfg_attr(test, mutants::skip)] // Trivial forwarder.
+    fn poll_write(self: Pin<&mut Self>, _cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Poll::Ready(self.get_mut().write(buf))
+    }
+
+    #[cfg_attr(test, mutants::skip)] // No-op, nothing to test.
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    #[cfg_attr(test, mutants::skip)] // No-op, nothing to test.
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use std::task::Waker;
+
+    use super::*;
+    use crate::BytesView;
+    use crate::mem::testing::TransparentMemory;
+
+    fn ready<T>(poll: Poll<T>) -> T {
+        match poll {
+            Poll::Ready(value) => value,
+            Poll::Pending => panic!("the bytes are in memory, so the operation must not wait"),
+        }
+    }
+
+    #[test]
+    fn read_multi_span() {
+        let memory = TransparentMemory::new();
+        let view_part1 = BytesView::copied_from_slice(b"Hello, ", &memory);
+        let view_part2 = BytesView::copied_from_slice(b"world!", &memory);
+        let mut reader = BytesView::from_views([view_part1, view_part2]).into_async_read();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let mut buffer = [0u8; 10];
+        assert_eq!(ready(Pin::new(&mut reader).poll_read(&mut cx, &mut buffer)).unwrap(), 10);
+        assert_eq!(&buffer, b"Hello, wor");
+
+        assert_eq!(ready(Pin::new(&mut reader).poll_read(&mut cx, &mut buffer)).unwrap(), 3);
+        assert_eq!(&buffer[..3], b"ld!");
+
+        assert_eq!(ready(Pin::new(&mut reader).poll_read(&mut cx, &mut buffer)).unwrap(), 0);
+    }
+
+    #[test]
+    fn buf_read_fill_and_consume() {
+        let memory = TransparentMemory::new();
+        let view_part1 = BytesView::copied_from_slice(b"Hello, ", &memory);
+        let view_part2 = BytesView::copied_from_slice(b"world!", &memory);
+        let mut reader = BytesView::from_views([view_part1, view_part2]).into_async_read();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        assert_eq!(ready(Pin::new(&mut reader).poll_fill_buf(&mut cx)).unwrap(), b"Hello, ");
+        Pin::new(&mut reader).consume(7);
+
+        assert_eq!(ready(Pin::new(&mut reader).poll_fill_buf(&mut cx)).unwrap(), b"world!");
+        Pin::new(&mut reader).consume(2);
+
+        assert_eq!(reader.into_inner(), b"rld!");
+    }
+
+    #[test]
+    fn write_extends_buffer() {
+        let memory = TransparentMemory::new();
+        let mut writer = memory.reserve(4).into_writer(&memory);
+        let mut cx = Context::from_waker(Waker::noop());
+
+        assert_eq!(ready(Pin::new(&mut writer).poll_write(&mut cx, b"Hello, world!")).unwrap(), 13);
+        ready(Pin::new(&mut writer).poll_flush(&mut cx)).unwrap();
+        ready(Pin::new(&mut writer).poll_close(&mut cx)).unwrap();
+
+        assert_eq!(writer.into_inner().consume_all(), b"Hello, world!");
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Compatibility with the async I/O traits of the `futures-io` and `tokio` packages.
+
+#[cfg(any(test, feature = "futures-io"))]
+mod futures_compat;
+#[cfg(any(test, feature = "tokio"))]
+mod tokio_compat;
+
+use crate::BytesView;
+
+/// Adapter that implements the async `AsyncRead` and `AsyncBufRead` traits for [`BytesView`].
+///
+/// Create an instance via [`BytesView::into_async_read()`].
+///
+/// The adapter implements the traits of `futures-io` when the `futures-io` Cargo feature is
+/// enabled and the traits of `tokio` when the `tokio` Cargo feature is enabled. The bytes are
+/// already in memory, so reads never return [`Poll::Pending`][std::task::Poll::Pending].
+#[derive(Debug)]
+pub struct BytesViewAsyncReader {
+    inner: BytesView,
+}
+
+impl BytesViewAsyncReader {
+    /// Returns the wrapped [`BytesView`], holding the bytes that have not been read yet.
+    #[must_use]
+    pub fn into_inner(self) -> BytesView {
+        self.inner
+    }
+}
+
+impl BytesView {
+    /// Converts this instance into an async [`AsyncRead`][1] and [`AsyncBufRead`][2] adapter.
+    ///
+    /// This lets the byte sequence be read by async codecs without a blocking adapter. As
+    /// with the [`BufRead`][std::io::BufRead] implementation of [`BytesView`], the adapter
+    /// needs no intermediate buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// # #[cfg(feature = "futures-io")]
+    /// # {
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// use bytesbuf::BytesView;
+    /// use futures_io::AsyncRead;
+    ///
+    /// let view = BytesView::copied_from_slice(b"Hello, world!", &memory);
+    /// let mut reader = view.into_async_read();
+    ///
+    /// let mut buffer = [0u8; 5];
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let Poll::Ready(bytes_read) = Pin::new(&mut reader).poll_read(&mut cx, &mut buffer) else {
+    ///     unreachable!("the bytes are in memory, so reading never waits");
+    /// };
+    ///
+    /// assert_eq!(bytes_read?, 5);
+    /// assert_eq!(&buffer, b"Hello");
+    /// # }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// [1]: https://docs.rs/futures-io/latest/futures_io/trait.AsyncRead.html
+    /// [2]: https://docs.rs/futures-io/latest/futures_io/trait.AsyncBufRead.html
+    #[must_use]
+    pub fn into_async_read(self) -> BytesViewAsyncReader {
+        BytesViewAsyncReader { inner: self }
+    }
+}
@@ -5,6 +5,8 @@ use std::io::Write;
 
 use crate::BytesBuf;
 use crate::mem::Memory;
+#[cfg(any(test, feature = "serde"))]
+use crate::varint::MAX_VARINT_LEN;
 
 /// The minimum reservation size for a [`BytesBufWriter`] when the buffer has no existing capacity.
 const INITIAL_RESERVATION_BYTES: usize = 4096;
@@ -19,6 +21,9 @@ const GROWTH_RESERVATION_BYTES: usize = 65536;
 /// The adapter will automatically extend the underlying [`BytesBuf`] as needed when writing
 /// by allocating additional memory capacity from the memory provider `M`.
 ///
+/// When the `futures-io` or `tokio` Cargo feature is enabled, the adapter also implements the
+/// `AsyncWrite` trait of that crate. Writes never wait, because they only append to memory.
+///
 /// [1]: crate::BytesBuf::into_writer
 #[derive(Debug)]
 pub struct BytesBufWriter<M: Memory> {
@@ -68,6 +73,20 @@ impl<M: Memory> BytesBufWriter<M> {
 
         self.inner.reserve(reservation, &self.memory);
     }
+
+    /// Appends a slice of bytes, extending the buffer as needed.
+    #[cfg(any(test, feature = "serde"))]
+    pub(crate) fn put_slice(&mut self, src: &[u8]) {
+        self.ensure_sufficient_capacity(src.len());
+        self.inner.put_slice(src);
+    }
+
+    /// Appends a LEB128 variable-length integer, extending the buffer as needed.
+    #[cfg(any(test, feature = "serde"))]
+    pub(crate) fn put_varint_u64(&mut self, value: u64) {
+        self.ensure_sufficient_capacity(MAX_VARINT_LEN);
+        self.inner.put_varint_u64(value);
+    }
 }
 
 impl<M: Memory> Write for BytesBufWriter<M> {
@@ -6,7 +6,7 @@ use std::num::NonZero;
 use std::ptr::NonNull;
 use std::sync::atomic::{self, AtomicUsize};
 
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
 use smallvec::SmallVec;
 
 use crate::mem::{Block, BlockRef, BlockRefDynamic, BlockRefVTable, BlockSize};
@@ -60,6 +60,16 @@ impl From<Bytes> for BytesView {
     }
 }
 
+impl From<BytesMut> for BytesView {
+    /// Converts a [`BytesMut`] instance into a `BytesView`.
+    ///
+    /// This operation is always zero-copy, as the `BytesMut` is frozen into a [`Bytes`] instance
+    /// whose memory the `BytesView` reuses, though does cost a small dynamic allocation.
+    fn from(value: BytesMut) -> Self {
+        value.freeze().into()
+    }
+}
+
 /// An implementation of `BlockRef` that reuses immutable memory of an owned `Bytes` instance.
 struct BytesBlock {
     // This field exists to keep the Bytes alive. The data within is accessed directly via pointers.
@@ -193,7 +203,7 @@ impl Iterator for BytesBlockIterator {
 #[cfg_attr(coverage_nightly, coverage(off))]
 #[cfg(test)]
 mod tests {
-    use bytes::{BufMut, BytesMut};
+    use bytes::BufMut;
 
     use super::*;
 
@@ -212,6 +222,21 @@ mod tests {
         assert_eq!(view.first_slice().as_ptr(), bytes_data_ptr);
     }
 
+    #[test]
+    fn bytes_mut_to_view() {
+        let mut bytes = BytesMut::with_capacity(64);
+        bytes.put_slice(b"Hello, world!");
+
+        let bytes_data_ptr = bytes.as_ptr();
+
+        let view: BytesView = bytes.into();
+
+        assert_eq!(view, b"Hello, world!");
+
+        // The frozen `BytesMut` keeps its memory, so this is zero-copy, too.
+        assert_eq!(view.first_slice().as_ptr(), bytes_data_ptr);
+    }
+
     #[test]
     fn zero_sized_bytes() {
         let bytes = Bytes::new();
@@ -3,7 +3,7 @@
 
 use std::io::IoSlice;
 
-use bytes::Buf;
+use bytes::{Buf, Bytes};
 
 use crate::BytesView;
 
@@ -20,23 +20,21 @@ impl Buf for BytesView {
 
     #[cfg_attr(test, mutants::skip)] // Trivial forwarder.
     fn chunks_vectored<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
-        if dst.is_empty() {
-            return 0;
-        }
-
-        let slice_count = self.spans_reversed.len().min(dst.len());
-
-        for (i, span) in self.spans_reversed.iter().rev().take(slice_count).enumerate() {
-            *dst.get_mut(i).expect("guarded by min()") = IoSlice::new(span);
-        }
-
-        slice_count
+        self.as_io_slices(dst)
     }
 
     #[cfg_attr(test, mutants::skip)] // Trivial forwarder.
     fn advance(&mut self, cnt: usize) {
         self.advance(cnt);
     }
+
+    fn copy_to_bytes(&mut self, len: usize) -> Bytes {
+        // Unlike the default implementation, which always copies, this shares the memory of
+        // the view with the `Bytes` when the range lies within a single span.
+        let bytes = self.range(..len).to_bytes();
+        self.advance(len);
+        bytes
+    }
 }
 
 #[cfg_attr(coverage_nightly, coverage(off))]
@@ -89,6 +87,40 @@ mod tests {
         assert_eq!(&*io_slices[2], &[0x44; 25]);
     }
 
+    #[test]
+    fn copy_to_bytes_shares_single_span() {
+        let memory = FixedBlockMemory::new(nz!(25));
+
+        // 25 x 2
+        let mut buf = memory.reserve(50);
+        buf.put_byte_repeated(0x11, 25);
+        buf.put_byte_repeated(0x22, 25);
+
+        let mut view = buf.consume_all();
+        let first_span_ptr = view.first_slice().as_ptr();
+
+        let bytes = Buf::copy_to_bytes(&mut view, 10);
+        assert_eq!(bytes, [0x11; 10].as_slice());
+        assert_eq!(bytes.as_ptr(), first_span_ptr);
+        assert_eq!(view.len(), 40);
+
+        // Crossing into the second span requires a copy, but yields the same bytes.
+        let bytes = Buf::copy_to_bytes(&mut view, 20);
+        assert_eq!(&bytes[..15], [0x11; 15].as_slice());
+        assert_eq!(&bytes[15..], [0x22; 5].as_slice());
+        assert_eq!(view.len(), 20);
+        assert_eq!(view, [0x22; 20].as_slice());
+    }
+
+    #[test]
+    #[should_panic(expected = "provided range out of view bounds")]
+    fn copy_to_bytes_past_end_panics() {
+        let memory = FixedBlockMemory::new(nz!(25));
+        let mut view = BytesView::copied_from_slice(b"1234", &memory);
+
+        let _bytes = Buf::copy_to_bytes(&mut view, 5);
+    }
+
     #[test]
     fn chunks_vectored_empty_dst() {
         let memory = FixedBlockMemory::new(nz!(25));
@@ -0,0 +1,106 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Table-driven CRC-32 checksums, processing eight bytes per step ("slicing-by-8").
+
+/// Lookup tables of a reflected CRC-32 polynomial. Table `n` holds the CRC of each byte value
+/// followed by `n` zero bytes.
+pub(crate) type CrcTables = [[u32; 256]; 8];
+
+/// Tables of the CRC-32 used by Ethernet, gzip and zip.
+pub(crate) static CRC32: CrcTables = tables(0xEDB8_8320);
+
+/// Tables of the CRC-32C (Castagnoli) used by many network protocols and storage formats.
+pub(crate) static CRC32C: CrcTables = tables(0x82F6_3B78);
+
+const fn tables(polynomial: u32) -> CrcTables {
+    let mut tables = [[0; 256]; 8];
+
+    let mut byte = 0;
+    while byte < 256 {
+        #[expect(clippy::cast_possible_truncation, reason = "byte values are below 256")]
+        let mut crc = byte as u32;
+
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 0 { crc >> 1 } else { (crc >> 1) ^ polynomial };
+            bit += 1;
+        }
+
+        tables[0][byte] = crc;
+        byte += 1;
+    }
+
+    let mut byte = 0;
+    while byte < 256 {
+        let mut table = 1;
+        while table < 8 {
+            let previous = tables[table - 1][byte];
+            tables[table][byte] = (previous >> 8) ^ tables[0][(previous & 0xFF) as usize];
+            table += 1;
+        }
+
+        byte += 1;
+    }
+
+    tables
+}
+
+/// Continues a CRC calculation over `bytes`.
+///
+/// The state starts as `!0` and the checksum is the inverted final state, so the state can be
+/// carried across any number of calls to checksum data that is not contiguous in memory.
+pub(crate) fn update(tables: &CrcTables, mut state: u32, bytes: &[u8]) -> u32 {
+    let (chunks, remainder) = bytes.as_chunks::<8>();
+
+    for chunk in chunks {
+        let [b0, b1, b2, b3, b4, b5, b6, b7] = *chunk;
+        let low = u32::from_le_bytes([b0, b1, b2, b3]) ^ state;
+        let high = u32::from_le_bytes([b4, b5, b6, b7]);
+
+        state = tables[7][(low & 0xFF) as usize]
+            ^ tables[6][((low >> 8) & 0xFF) as usize]
+            ^ tables[5][((low >> 16) & 0xFF) as usize]
+            ^ tables[4][(low >> 24) as usize]
+            ^ tables[3][(high & 0xFF) as usize]
+            ^ tables[2][((high >> 8) & 0xFF) as usize]
+            ^ tables[1][((high >> 16) & 0xFF) as usize]
+            ^ tables[0][(high >> 24) as usize];
+    }
+
+    for &byte in remainder {
+        state = (state >> 8) ^ tables[0][((state ^ u32::from(byte)) & 0xFF) as usize];
+    }
+
+    state
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checksum(tables: &CrcTables, bytes: &[u8]) -> u32 {
+        !update(tables, !0, bytes)
+    }
+
+    #[test]
+    fn check_values() {
+        // The standard check input of CRC catalogues.
+        assert_eq!(checksum(&CRC32, b"123456789"), 0xCBF4_3926);
+        assert_eq!(checksum(&CRC32C, b"123456789"), 0xE306_9283);
+        assert_eq!(checksum(&CRC32, b""), 0);
+    }
+
+    #[test]
+    fn slicing_matches_bytewise() {
+        let data: Vec<u8> = (0..=255).cycle().take(1000).collect();
+
+        let mut bytewise = !0;
+        for byte in &data {
+            bytewise = update(&CRC32C, bytewise, std::slice::from_ref(byte));
+        }
+
+        assert_eq!(update(&CRC32C, !0, &data), bytewise);
+    }
+}
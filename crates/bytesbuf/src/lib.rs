@@ -33,11 +33,29 @@
 //!   specific primitive type (`u16`/`i16` through `u128`/`i128`, plus `f32`/`f64`) in little-,
 //!   big-, or native-endian byte order.
 //! * [`get_byte()`] reads a single byte.
+//! * [`get_varint_u64()`] and the sibling `get_varint_<type>()` methods read LEB128
+//!   variable-length integers (`u32`/`u64`, plus zigzag-encoded `i32`/`i64`), as used by
+//!   Protocol Buffers.
 //! * [`copy_to_slice()`] copies bytes into a provided slice.
 //! * [`copy_to_uninit_slice()`] copies bytes into a provided uninitialized slice.
 //! * [`BytesView`] implements [`std::io::Read`] and [`std::io::BufRead`] directly, since
 //!   it is already a buffered byte sequence.
 //!
+//! To inspect bytes without consuming them, for example to read a length prefix before deciding
+//! whether the whole message has arrived, [`peek_u32_le()`], the sibling `peek_<type>_<endianness>()`
+//! methods, [`peek_byte()`] and [`peek_to_slice()`] read from the front of the view and leave it
+//! unchanged. [`range()`] returns a new view over part of the byte sequence without copying it.
+//!
+//! To parse delimited data, such as HTTP headers or newline-delimited JSON, [`find_byte()`] and
+//! [`find_slice()`] search the whole view, across span boundaries. [`split_until()`] detaches
+//! the bytes up to and including a delimiter and [`split_lines()`] iterates over the lines of
+//! the view, both returning views over the same memory instead of copies.
+//!
+//! For text protocols, [`get_str()`] consumes a number of bytes as a UTF-8 string,
+//! [`try_into_string()`] converts the whole view and [`to_utf8_lossy()`] replaces invalid
+//! UTF-8. They validate characters that straddle span boundaries, without first copying the
+//! bytes into an intermediate [`Vec<u8>`].
+//!
 //! ```
 //! # let memory = bytesbuf::mem::GlobalPool::new();
 //! # let message = BytesView::copied_from_slice(b"1234123412341234", &memory);
@@ -88,6 +106,10 @@
 //! println!("Inspected a view over {len} bytes with slice lengths: {slice_lengths:?}");
 //! ```
 //!
+//! To write the byte sequence with vectored I/O, such as [`Write::write_vectored()`], without
+//! copying it into consecutive memory, [`as_io_slices()`] and [`io_slices()`] return the slices
+//! of the view as [`IoSlice`]s.
+//!
 //! To reuse a [`BytesView`], clone it before consuming the contents. This is a cheap
 //! zero-copy operation.
 //!
@@ -155,6 +177,8 @@
 //! * [`put_slice()`] appends a slice of bytes.
 //! * [`put_byte()`] appends a single byte.
 //! * [`put_byte_repeated()`] appends multiple repetitions of a byte.
+//! * [`put_varint_u64()`] and the sibling `put_varint_<type>()` methods append LEB128
+//!   variable-length integers (`u32`/`u64`, plus zigzag-encoded `i32`/`i64`).
 //! * [`put_bytes()`] appends an existing [`BytesView`].
 //!
 //! ```
@@ -463,7 +487,50 @@
 //!   You are discouraged from using this method in any performance-relevant logic path.
 //! * `BytesView::from(Bytes)` or `let s: BytesView = bytes.into()` converts a [`Bytes`] instance
 //!   into a [`BytesView`]. This is an efficient zero-copy operation that reuses the memory of the
-//!   `Bytes` instance.
+//!   `Bytes` instance. `BytesView::from(BytesMut)` does the same for a `BytesMut` instance.
+//!
+//! The `bytes` crate also defines the `Buf` and `BufMut` traits for reading from and writing to
+//! byte buffers, which much of the ecosystem, such as `hyper` and `tonic`, is generic over. With
+//! the same feature, [`BytesView`] implements `Buf` and [`BytesBuf`] implements `BufMut`, so they
+//! can be passed to such code directly, without converting or copying them. Taking a [`Bytes`]
+//! from the front of a [`BytesView`] with `Buf::copy_to_bytes()` is zero-copy when the bytes taken
+//! are consecutive in memory.
+//!
+//! # Compatibility with Async I/O Traits
+//!
+//! Async codecs are often generic over the `AsyncRead` and `AsyncWrite` traits of the
+//! `futures-io` or `tokio` crates. When the `futures-io` or `tokio` Cargo feature is enabled,
+//! the traits of that crate are implemented by:
+//!
+//! * The adapter returned by `BytesView::into_async_read()`, which implements `AsyncRead` and
+//!   `AsyncBufRead` by consuming the byte sequence, without an intermediate buffer.
+//! * [`BytesBufWriter`], returned by [`BytesBuf::into_writer()`], which implements
+//!   `AsyncWrite` by appending to the buffer, extending it with memory from a memory provider
+//!   as needed.
+//!
+//! The bytes are in memory, so these never wait and can be used without blocking adapters.
+//!
+//! # Serialization with `serde`
+//!
+//! When the `serde` Cargo feature is enabled, the `serde` module provides a compact binary
+//! format that serializes values directly into a [`BytesBuf`] and deserializes them from the
+//! front of a [`BytesView`], without an intermediate `Vec<u8>`. This lets serialized values be
+//! passed to sockets and files without copying them.
+//!
+//! # Hashing and Checksums
+//!
+//! [`BytesView::hash_into()`] feeds the bytes of a view to a streaming [`Hasher`][std::hash::Hasher]
+//! one span at a time, so checking the integrity of a large message does not require gathering
+//! it into contiguous memory. The `crc` Cargo feature adds CRC-32 and CRC-32C checksums of a view
+//! and the `xxh3` Cargo feature adds xxh3 hashes, which are calculated in the same way.
+//!
+//! # Observing Memory Usage
+//!
+//! [`GlobalPool::stats()`][mem::GlobalPool::stats] returns a [`MemoryStats`][mem::MemoryStats]
+//! snapshot of the memory blocks handed out by the pool and the bytes of memory capacity that are
+//! still outstanding, which tells whether the memory growth of a service comes from the pool and
+//! helps to detect byte sequences that are never dropped. When the `metrics` Cargo feature is
+//! enabled, `GlobalPool::register_metrics()` reports the same counters as OpenTelemetry metrics.
 //!
 //! # Static Data
 //!
@@ -533,16 +600,37 @@
 //!
 //! See the `mem::testing` module for details (requires `test-util` Cargo feature).
 //!
+//! The `bolero` Cargo feature adds generators of byte sequences with random content and
+//! memory layout, for property tests checking that your code does not depend on how a
+//! byte sequence is split into memory blocks.
+//!
 //! [`get_u64_le()`]: crate::BytesView::get_u64_le
 //! [`get_byte()`]: crate::BytesView::get_byte
+//! [`get_varint_u64()`]: crate::BytesView::get_varint_u64
 //! [`copy_to_slice()`]: crate::BytesView::copy_to_slice
 //! [`copy_to_uninit_slice()`]: crate::BytesView::copy_to_uninit_slice
 //! [`first_slice()`]: crate::BytesView::first_slice
+//! [`peek_u32_le()`]: crate::BytesView::peek_u32_le
+//! [`peek_byte()`]: crate::BytesView::peek_byte
+//! [`peek_to_slice()`]: crate::BytesView::peek_to_slice
+//! [`range()`]: crate::BytesView::range
+//! [`find_byte()`]: crate::BytesView::find_byte
+//! [`find_slice()`]: crate::BytesView::find_slice
+//! [`split_until()`]: crate::BytesView::split_until
+//! [`split_lines()`]: crate::BytesView::split_lines
+//! [`get_str()`]: crate::BytesView::get_str
+//! [`try_into_string()`]: crate::BytesView::try_into_string
+//! [`to_utf8_lossy()`]: crate::BytesView::to_utf8_lossy
+//! [`as_io_slices()`]: crate::BytesView::as_io_slices
+//! [`io_slices()`]: crate::BytesView::io_slices
+//! [`IoSlice`]: std::io::IoSlice
+//! [`Write::write_vectored()`]: std::io::Write::write_vectored
 //! [ViewAdvance]: crate::BytesView::advance
 //! [`put_u64_le()`]: crate::BytesBuf::put_u64_le
 //! [`put_slice()`]: crate::BytesBuf::put_slice
 //! [`put_byte()`]: crate::BytesBuf::put_byte
 //! [`put_byte_repeated()`]: crate::BytesBuf::put_byte_repeated
+//! [`put_varint_u64()`]: crate::BytesBuf::put_varint_u64
 //! [`put_bytes()`]: crate::BytesBuf::put_bytes
 //! [`first_unfilled_slice()`]: crate::BytesBuf::first_unfilled_slice
 //! [BufAdvance]: crate::BytesBuf::advance
@@ -564,28 +652,42 @@
 // for organizational purposes, to help navigate the API documentation better. Both
 // sets of types very often need to be used together, so they are not functionally separate.
 pub mod mem;
+#[cfg(any(test, feature = "serde"))]
+pub mod serde;
 
+#[cfg(any(test, feature = "futures-io", feature = "tokio"))]
+mod async_compat;
 mod buf;
 mod buf_put;
 mod buf_writer;
 #[cfg(any(test, feature = "bytes-compat"))]
 mod bytes_compat;
 mod constants;
+#[cfg(any(test, feature = "crc"))]
+mod crc;
 mod memory_guard;
 mod span;
 mod span_builder;
+mod varint;
 mod vec;
 mod view;
 mod view_get;
+mod view_hash;
+mod view_peek;
 mod view_read;
+mod view_search;
+mod view_str;
 
+#[cfg(any(test, feature = "futures-io", feature = "tokio"))]
+pub use async_compat::BytesViewAsyncReader;
 pub use buf::{BytesBuf, BytesBufRemaining, BytesBufVectoredWrite};
 pub use buf_writer::BytesBufWriter;
 pub use constants::MAX_INLINE_SPANS;
 pub use memory_guard::MemoryGuard;
 pub(crate) use span::Span;
 pub(crate) use span_builder::SpanBuilder;
-pub use view::{BytesView, BytesViewSlices};
+pub use view::{BytesView, BytesViewIoSlices, BytesViewSlices};
+pub use view_search::BytesViewLines;
 
 #[cfg(test)]
 mod testing;
@@ -15,7 +15,12 @@ use thread_aware::ThreadAware;
 
 use crate::BytesBuf;
 use crate::constants::ERR_POISONED_LOCK;
-use crate::mem::{Block, BlockRef, BlockRefDynamic, BlockRefVTable, BlockSize, Memory};
+use crate::mem::global_builder::PoolConfig;
+use crate::mem::stats::StatsLedger;
+use crate::mem::tag::{TagAccount, TagLedger};
+use crate::mem::{
+    Block, BlockRef, BlockRefDynamic, BlockRefVTable, BlockSize, BudgetExceededError, GlobalPoolBuilder, Memory, MemoryStats, MemoryTag,
+};
 
 /// A memory pool that obtains memory from the Rust global allocator.
 ///
@@ -35,10 +40,41 @@ use crate::mem::{Block, BlockRef, BlockRefDynamic, BlockRefVTable, BlockSize, Me
 /// penalty is not incurred. If no suitable thread-aware API is available, use a thread-local pool
 /// via the `thread_local!` macro.
 ///
+/// # Memory usage attribution
+///
+/// Memory reserved with [`reserve_tagged()`][Self::reserve_tagged] is attributed to a
+/// [`MemoryTag`], so the outstanding memory of each subsystem of an application can be queried
+/// with [`outstanding_bytes()`][Self::outstanding_bytes] and limited with
+/// [`set_budget()`][Self::set_budget]. The accounting is shared by all clones of the pool,
+/// including clones on other threads.
+///
+/// # Observability
+///
+/// The pool counts the memory blocks it hands out and the bytes of memory capacity that are
+/// outstanding, aggregated across all clones of the pool. Use [`stats()`][Self::stats] to take a
+/// snapshot of the counters, or, with the `metrics` feature, `register_metrics()` to report them
+/// as OpenTelemetry metrics.
+///
+/// # Configuration
+///
+/// Use [`builder()`][Self::builder] to tune the block sizes for a workload or to limit the memory
+/// that the pool keeps for reuse. Long-running services can also call [`trim()`][Self::trim], for
+/// example from a periodic timer, to return pooled memory that is no longer used.
+///
 /// [thread-aware]: https://docs.rs/thread_aware
 #[derive(Clone, Debug, ThreadAware)]
 pub struct GlobalPool {
     inner: thread_aware::Arc<GlobalPoolInner, thread_aware::PerCore>,
+
+    // Deliberately not relocated, so that memory usage is aggregated across all threads.
+    #[thread_aware(skip)]
+    tags: Arc<TagLedger>,
+
+    // Deliberately not relocated, for the same reason as `tags`.
+    #[thread_aware(skip)]
+    stats: Arc<StatsLedger>,
+
+    config: PoolConfig,
 }
 
 impl GlobalPool {
@@ -57,8 +93,31 @@ impl GlobalPool {
         reason = "to avoid accidental confusion with some 'default' global memory pool, which does not exist"
     )]
     pub fn new() -> Self {
+        Self::builder().build()
+    }
+
+    /// Starts building a memory pool with custom configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytesbuf::mem::GlobalPool;
+    ///
+    /// let memory = GlobalPool::builder().block_sizes([4096, 65_536]).build();
+    ///
+    /// assert_eq!(memory.reserve(100).capacity(), 4096);
+    /// assert_eq!(memory.reserve(5000).capacity(), 65_536);
+    /// ```
+    pub fn builder() -> GlobalPoolBuilder {
+        GlobalPoolBuilder::new()
+    }
+
+    pub(crate) fn with_config(config: PoolConfig) -> Self {
         Self {
-            inner: thread_aware::Arc::<_, thread_aware::PerCore>::new(GlobalPoolInner::new),
+            inner: thread_aware::Arc::<_, thread_aware::PerCore>::new_with(config, GlobalPoolInner::new),
+            tags: Arc::default(),
+            stats: Arc::default(),
+            config,
         }
     }
 
@@ -82,7 +141,171 @@ impl GlobalPool {
     #[must_use]
     #[inline]
     pub fn reserve(&self, min_bytes: usize) -> crate::BytesBuf {
-        self.inner.reserve(min_bytes)
+        self.inner.reserve(min_bytes, None, &self.stats)
+    }
+
+    /// Reserves at least `min_bytes` bytes of memory capacity, attributed to `tag`.
+    ///
+    /// The reserved capacity counts towards the outstanding memory of `tag` until all the
+    /// memory blocks are returned to the pool, i.e. until every [`BytesBuf`] and
+    /// [`BytesView`][crate::BytesView] referencing them is dropped.
+    ///
+    /// Apart from the accounting, this behaves like [`reserve()`][Self::reserve].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reservation would exceed the budget of `tag`.
+    ///
+    /// # Panics
+    ///
+    /// May panic if the operating system runs out of memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytesbuf::mem::{GlobalPool, MemoryTag};
+    ///
+    /// const INGEST: MemoryTag = MemoryTag::new("ingest");
+    ///
+    /// let memory = GlobalPool::new();
+    /// memory.set_budget(INGEST, Some(4096));
+    ///
+    /// let buf = memory.reserve_tagged(1000, INGEST).unwrap();
+    /// assert_eq!(memory.outstanding_bytes(INGEST), buf.capacity());
+    ///
+    /// // The budget does not fit another 4 KiB reservation until the buffer is dropped.
+    /// assert!(memory.reserve_tagged(4096, INGEST).is_err());
+    ///
+    /// drop(buf);
+    /// assert_eq!(memory.outstanding_bytes(INGEST), 0);
+    /// assert!(memory.reserve_tagged(4096, INGEST).is_ok());
+    /// ```
+    pub fn reserve_tagged(&self, min_bytes: usize, tag: MemoryTag) -> Result<crate::BytesBuf, BudgetExceededError> {
+        if min_bytes == 0 {
+            return Ok(BytesBuf::new());
+        }
+
+        let account = self.tags.account(tag);
+
+        if let Err(error) = account.acquire(tag, self.config.reserved_bytes(min_bytes)) {
+            self.stats.reserve_failed();
+            RESERVE_FAILURES.with(Event::observe_once);
+            return Err(error);
+        }
+
+        Ok(self.inner.reserve(min_bytes, Some(&account), &self.stats))
+    }
+
+    /// Limits the outstanding memory of `tag` to `max_bytes`, or removes the limit if `None`.
+    ///
+    /// Reservations that would exceed the budget are rejected by
+    /// [`reserve_tagged()`][Self::reserve_tagged]. Memory that is already outstanding is not
+    /// affected by a new budget, even if it exceeds the budget.
+    pub fn set_budget(&self, tag: MemoryTag, max_bytes: Option<usize>) {
+        self.tags.account(tag).set_budget(max_bytes);
+    }
+
+    /// Returns the number of bytes of memory capacity reserved for `tag` that have not yet
+    /// been returned to the pool.
+    #[must_use]
+    pub fn outstanding_bytes(&self, tag: MemoryTag) -> usize {
+        self.tags.outstanding(tag)
+    }
+
+    /// Returns the outstanding memory of every tag used with this pool, sorted by tag name.
+    ///
+    /// Tags stay listed after all their memory is returned to the pool, with 0 bytes outstanding.
+    #[must_use]
+    pub fn outstanding_bytes_by_tag(&self) -> Vec<(MemoryTag, usize)> {
+        self.tags.outstanding_by_tag()
+    }
+
+    /// Returns a snapshot of the memory usage of the pool, aggregated across all its clones.
+    ///
+    /// Memory blocks count as outstanding until every [`BytesBuf`] and
+    /// [`BytesView`][crate::BytesView] referencing them is dropped, also after the pool itself
+    /// is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytesbuf::mem::GlobalPool;
+    ///
+    /// let memory = GlobalPool::new();
+    ///
+    /// let _buf = memory.reserve(70_000);
+    ///
+    /// let stats = memory.stats();
+    /// assert_eq!(stats.blocks_allocated(), 2);
+    /// assert_eq!(stats.bytes_outstanding(), 2 * 65_536);
+    /// ```
+    #[must_use]
+    pub fn stats(&self) -> MemoryStats {
+        self.stats.snapshot()
+    }
+
+    /// Reports the memory usage of the pool as OpenTelemetry metrics of `meter_provider`.
+    ///
+    /// The pool registers observable instruments that read the same counters as
+    /// [`stats()`][Self::stats] whenever the metrics are collected:
+    ///
+    /// - `bytesbuf.global_pool.blocks.allocated` - memory blocks handed out (counter).
+    /// - `bytesbuf.global_pool.blocks.outstanding` - memory blocks not yet returned (gauge).
+    /// - `bytesbuf.global_pool.memory.outstanding` - bytes of memory capacity not yet returned (gauge).
+    /// - `bytesbuf.global_pool.memory.outstanding.peak` - highest bytes outstanding at once (gauge).
+    /// - `bytesbuf.global_pool.reserve.failures` - rejected reservations (counter).
+    ///
+    /// Register each pool once. The instruments have no attributes, so registering several
+    /// pools with the same meter provider makes their metrics indistinguishable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "metrics")]
+    /// # {
+    /// use bytesbuf::mem::GlobalPool;
+    /// use opentelemetry_sdk::metrics::SdkMeterProvider;
+    ///
+    /// let meter_provider = SdkMeterProvider::default();
+    ///
+    /// let memory = GlobalPool::new();
+    /// memory.register_metrics(&meter_provider);
+    /// # }
+    /// ```
+    #[cfg(any(test, feature = "metrics"))]
+    pub fn register_metrics(&self, meter_provider: &dyn opentelemetry::metrics::MeterProvider) {
+        self.stats.register_metrics(meter_provider, "bytesbuf.global_pool");
+    }
+
+    /// Returns pooled memory that is not in use to the Rust global allocator.
+    ///
+    /// The pool keeps the memory of released blocks for reuse, so its memory usage does not
+    /// shrink on its own after a peak in load. This releases the pooled memory of each block size
+    /// of which no blocks are outstanding. Memory of block sizes with outstanding blocks is kept,
+    /// because it can only be returned in its entirety.
+    ///
+    /// Only the pool that serves this thread is trimmed. Clones of the pool that were moved to
+    /// other threads with thread-aware APIs are served by pools of their own, which are trimmed
+    /// by calling this on those clones.
+    ///
+    /// Returns the number of bytes of memory capacity released.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytesbuf::mem::GlobalPool;
+    ///
+    /// let memory = GlobalPool::new();
+    ///
+    /// let buf = memory.reserve(200_000);
+    /// drop(buf);
+    ///
+    /// assert_eq!(memory.trim(), 4 * 65_536);
+    /// assert_eq!(memory.trim(), 0);
+    /// ```
+    #[expect(clippy::must_use_candidate, reason = "the released byte count is informational")]
+    pub fn trim(&self) -> usize {
+        self.inner.trim()
     }
 }
 
@@ -123,10 +346,6 @@ fn new_block_pool<const SIZE: usize>() -> Pool<NeutralBlock<SIZE>> {
 }
 
 #[derive(Debug)]
-#[expect(
-    clippy::struct_field_names,
-    reason = "pool_ prefix provides clarity for the size-differentiated sub-pools"
-)]
 struct GlobalPoolInner {
     // Each sub-pool is guarded by its own mutex because memory providers need to be thread-safe.
     // The point is not so much that memory will be requested from multiple threads (though it
@@ -164,10 +383,16 @@ struct GlobalPoolInner {
     pool_4k: SubPool<4096>,
     pool_16k: SubPool<16_384>,
     pool_64k: SubPool<65_536>,
+
+    // The memory capacity of the sub-pools, which is only updated when a sub-pool grows or is
+    // trimmed, so this is not on the path of every allocation.
+    pooled: PooledCapacity,
+
+    config: PoolConfig,
 }
 
 impl GlobalPoolInner {
-    fn new() -> Self {
+    fn new(config: PoolConfig) -> Self {
         INSTANCES_CREATED.with(Event::observe_once);
 
         Self {
@@ -175,30 +400,94 @@ impl GlobalPoolInner {
             pool_4k: Arc::new(Mutex::new(new_block_pool())),
             pool_16k: Arc::new(Mutex::new(new_block_pool())),
             pool_64k: Arc::new(Mutex::new(new_block_pool())),
+            pooled: PooledCapacity {
+                bytes: AtomicUsize::new(0),
+                max_bytes: config.max_pooled_bytes,
+            },
+            config,
         }
     }
 
-    fn reserve(&self, min_bytes: usize) -> crate::BytesBuf {
+    fn reserve(&self, min_bytes: usize, account: Option<&Arc<TagAccount>>, stats: &Arc<StatsLedger>) -> crate::BytesBuf {
         RESERVATION_REQUESTED_SIZE.with(|e| e.observe(min_bytes));
 
         if min_bytes == 0 {
             return BytesBuf::new();
         }
 
-        // Pick the smallest sub-pool that fits, then use only that block size for the entire
-        // reservation. For requests exceeding the largest block size, use multiple blocks of
-        // the largest size. Using uniform block sizes avoids imbalances when repeated
+        // Pick the smallest configured sub-pool that fits, then use only that block size for the
+        // entire reservation. For requests exceeding the largest block size, use multiple blocks
+        // of the largest size. Using uniform block sizes avoids imbalances when repeated
         // reservations are not perfectly aligned with block size boundaries.
-        if min_bytes <= 1024 {
-            allocate_uniform::<1024>(&self.pool_1k, &BLOCK_REF_FNS_1K, min_bytes)
-        } else if min_bytes <= 4096 {
-            allocate_uniform::<4096>(&self.pool_4k, &BLOCK_REF_FNS_4K, min_bytes)
-        } else if min_bytes <= 16_384 {
-            allocate_uniform::<16_384>(&self.pool_16k, &BLOCK_REF_FNS_16K, min_bytes)
-        } else {
-            allocate_uniform::<65_536>(&self.pool_64k, &BLOCK_REF_FNS_64K, min_bytes)
+        match self.config.block_size(min_bytes) {
+            1024 => allocate_uniform::<1024>(&self.pool_1k, &BLOCK_REF_FNS_1K, min_bytes, account, stats, &self.pooled),
+            4096 => allocate_uniform::<4096>(&self.pool_4k, &BLOCK_REF_FNS_4K, min_bytes, account, stats, &self.pooled),
+            16_384 => allocate_uniform::<16_384>(&self.pool_16k, &BLOCK_REF_FNS_16K, min_bytes, account, stats, &self.pooled),
+            _ => allocate_uniform::<65_536>(&self.pool_64k, &BLOCK_REF_FNS_64K, min_bytes, account, stats, &self.pooled),
         }
     }
+
+    fn trim(&self) -> usize {
+        let released =
+            trim_sub_pool(&self.pool_1k) + trim_sub_pool(&self.pool_4k) + trim_sub_pool(&self.pool_16k) + trim_sub_pool(&self.pool_64k);
+
+        self.pooled.bytes.fetch_sub(released, atomic::Ordering::Relaxed);
+
+        released
+    }
+}
+
+/// Tracks the memory capacity of the sub-pools of a [`GlobalPoolInner`] against its limit.
+#[derive(Debug)]
+struct PooledCapacity {
+    bytes: AtomicUsize,
+    max_bytes: Option<usize>,
+}
+
+impl PooledCapacity {
+    /// Accounts for a sub-pool growing by `bytes`, unless that would exceed the limit.
+    fn try_grow(&self, bytes: usize) -> bool {
+        // Relaxed because the capacity is independent of any other state. Concurrent growth of
+        // several sub-pools may overshoot the limit by a chunk each, which is of no consequence.
+        let Some(max_bytes) = self.max_bytes else {
+            self.bytes.fetch_add(bytes, atomic::Ordering::Relaxed);
+            return true;
+        };
+
+        self.bytes
+            .fetch_update(atomic::Ordering::Relaxed, atomic::Ordering::Relaxed, |current| {
+                current.checked_add(bytes).filter(|&grown| grown <= max_bytes)
+            })
+            .is_ok()
+    }
+}
+
+/// Replaces the sub-pool with a new one if none of its blocks are outstanding, which returns the
+/// memory of the old sub-pool to the global allocator.
+///
+/// Returns the number of bytes of memory capacity released.
+fn trim_sub_pool<const SIZE: usize>(pool_arc: &SubPool<SIZE>) -> usize {
+    let mut pool = pool_arc.lock().expect(ERR_POISONED_LOCK);
+
+    if pool.capacity() == 0 || !pool.is_empty() {
+        return 0;
+    }
+
+    let released = pool_capacity_bytes(&pool);
+    *pool = new_block_pool();
+
+    released
+}
+
+/// Returns the memory capacity of a sub-pool in bytes.
+fn pool_capacity_bytes<const SIZE: usize>(pool: &Pool<NeutralBlock<SIZE>>) -> usize {
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the capacity of a sub-pool is backed by memory, so its size in bytes fits in usize"
+    )]
+    let capacity = pool.capacity() as usize;
+
+    capacity * SIZE
 }
 
 /// Allocates one or more blocks of the same size to satisfy `min_bytes`.
@@ -206,6 +495,9 @@ fn allocate_uniform<const SIZE: usize>(
     pool_arc: &SubPool<SIZE>,
     vtable: &'static BlockRefVTable<BlockMeta<SIZE>>,
     min_bytes: usize,
+    account: Option<&Arc<TagAccount>>,
+    stats: &Arc<StatsLedger>,
+    pooled: &PooledCapacity,
 ) -> crate::BytesBuf {
     let block_count = min_bytes.div_ceil(SIZE);
 
@@ -220,7 +512,7 @@ fn allocate_uniform<const SIZE: usize>(
         // we still held the lock.
         let block = {
             let pool = pool_arc.lock().expect(ERR_POISONED_LOCK);
-            allocate_block(&pool, vtable)
+            allocate_limited_block(&pool, vtable, account, stats, pooled)
         };
 
         return BytesBuf::from_block(block);
@@ -228,19 +520,67 @@ fn allocate_uniform<const SIZE: usize>(
 
     let pool = pool_arc.lock().expect(ERR_POISONED_LOCK);
 
-    let blocks = iter::repeat_with(|| allocate_block(&pool, vtable)).take(block_count);
+    let blocks = iter::repeat_with(|| allocate_limited_block(&pool, vtable, account, stats, pooled)).take(block_count);
 
     BytesBuf::from_blocks(blocks)
 }
 
-/// Allocates a single block from the given sub-pool.
+/// Allocates a single block from the given sub-pool, unless the sub-pool would have to grow
+/// beyond the limit of the pooled memory capacity.
+///
+/// Blocks beyond the limit are allocated from a pool of their own, which returns its memory to
+/// the global allocator when the block is released and the pool is torn down.
 ///
 /// The caller is responsible for locking the pool and observing metrics.
-fn allocate_block<const SIZE: usize>(pool: &Pool<NeutralBlock<SIZE>>, vtable: &'static BlockRefVTable<BlockMeta<SIZE>>) -> Block {
+fn allocate_limited_block<const SIZE: usize>(
+    pool: &Pool<NeutralBlock<SIZE>>,
+    vtable: &'static BlockRefVTable<BlockMeta<SIZE>>,
+    account: Option<&Arc<TagAccount>>,
+    stats: &Arc<StatsLedger>,
+    pooled: &PooledCapacity,
+) -> Block {
+    let capacity_before = pool_capacity_bytes(pool);
+
+    // Without an available slot, the sub-pool grows by a chunk.
+    let mut expected_growth = 0;
+    if pool.available() == 0 {
+        expected_growth = pool.chunk_size() as usize * SIZE;
+
+        if !pooled.try_grow(expected_growth) {
+            let own_pool = Pool::builder().chunk_size(1).build();
+            return allocate_block(&own_pool, vtable, account, stats);
+        }
+    }
+
+    let block = allocate_block(pool, vtable, account, stats);
+
+    // The number of available slots is approximate while blocks are concurrently released, so we
+    // correct the accounting if the sub-pool did not grow as expected.
+    let growth = pool_capacity_bytes(pool) - capacity_before;
+    if growth > expected_growth {
+        pooled.bytes.fetch_add(growth - expected_growth, atomic::Ordering::Relaxed);
+    } else if growth < expected_growth {
+        pooled.bytes.fetch_sub(expected_growth - growth, atomic::Ordering::Relaxed);
+    }
+
+    block
+}
+
+/// Allocates a single block from the given pool.
+///
+/// The caller is responsible for locking the pool and observing metrics.
+fn allocate_block<const SIZE: usize>(
+    pool: &Pool<NeutralBlock<SIZE>>,
+    vtable: &'static BlockRefVTable<BlockMeta<SIZE>>,
+    account: Option<&Arc<TagAccount>>,
+    stats: &Arc<StatsLedger>,
+) -> Block {
     let initialize_block = |place: &mut MaybeUninit<NeutralBlock<SIZE>>, handle: NonNull<NeutralBlock<SIZE>>| {
         let meta = BlockMeta {
             handle: BlockHandle(handle),
             ref_count: AtomicUsize::new(1),
+            account: account.cloned(),
+            stats: Arc::clone(stats),
         };
 
         in_place_initialize_block(place, meta);
@@ -252,6 +592,8 @@ fn allocate_block<const SIZE: usize>(pool: &Pool<NeutralBlock<SIZE>>, vtable: &'
     // and we are required to fully initialize the object before returning (we do).
     let handle = unsafe { insert_with_handle_to_self(pool, initialize_block) };
 
+    stats.block_allocated(SIZE);
+
     // SAFETY: After initialization (above), we only access the block via shared references.
     let block = unsafe { handle.as_ref() };
 
@@ -317,6 +659,14 @@ struct BlockMeta<const SIZE: usize> {
 
     /// Whoever decrements this to zero is responsible for returning the block to the pool.
     ref_count: AtomicUsize,
+
+    /// The account of the tag the block was reserved for, if any. The capacity of the block
+    /// is returned to the account when the block is returned to the pool.
+    account: Option<Arc<TagAccount>>,
+
+    /// The counters of the pool the block was reserved from, which are updated when the block
+    /// is returned to the pool.
+    stats: Arc<StatsLedger>,
 }
 
 #[cfg_attr(test, mutants::skip)] // Failure to initialize can violate memory safety.
@@ -432,6 +782,12 @@ unsafe impl<const SIZE: usize> BlockRefDynamic for BlockMeta<SIZE> {
         // On x86 this does nothing but on weaker memory models writes could be delayed.
         atomic::fence(atomic::Ordering::Acquire);
 
+        if let Some(account) = &state.account {
+            account.release(SIZE);
+        }
+
+        state.stats.block_released(SIZE);
+
         // Copy the self-handle out before we relinquish `state`: reconstructing and
         // dropping the box below runs the block's destructor, which invalidates it.
         let handle = state.handle;
@@ -483,6 +839,11 @@ thread_local! {
     static INSTANCES_CREATED: Event = Event::builder()
         .name("bytesbuf_global_pool_instances_total")
         .build();
+
+    // Counts reservations rejected because they would exceed the budget of their tag.
+    static RESERVE_FAILURES: Event = Event::builder()
+        .name("bytesbuf_global_pool_reserve_failures_total")
+        .build();
 }
 
 #[cfg_attr(coverage_nightly, coverage(off))]
@@ -806,6 +1167,268 @@ mod tests {
         assert_eq!(buf.capacity(), 65_536 * 2);
     }
 
+    #[test]
+    fn reserved_bytes_match_capacity() {
+        let memory = GlobalPool::new();
+
+        for min_bytes in [0, 1, 1024, 1025, 4096, 4097, 16_384, 16_385, 65_536, 70_000, 200_000] {
+            assert_eq!(
+                memory.reserve(min_bytes).capacity(),
+                memory.config.reserved_bytes(min_bytes),
+                "{min_bytes}"
+            );
+        }
+    }
+
+    #[test]
+    fn tagged_memory_is_accounted_until_released() {
+        const INGEST: MemoryTag = MemoryTag::new("ingest");
+        const CACHE: MemoryTag = MemoryTag::new("cache");
+
+        let memory = GlobalPool::new();
+
+        let ingest = memory.reserve_tagged(100, INGEST).unwrap();
+        let cache = memory.reserve_tagged(70_000, CACHE).unwrap();
+        let _untagged = memory.reserve(100);
+        assert!(memory.reserve_tagged(0, INGEST).unwrap().is_empty());
+
+        assert_eq!(memory.outstanding_bytes(INGEST), 1024);
+        assert_eq!(memory.outstanding_bytes(CACHE), 65_536 * 2);
+        assert_eq!(memory.outstanding_bytes_by_tag(), [(CACHE, 65_536 * 2), (INGEST, 1024)]);
+
+        // A block stays outstanding while any view still references it.
+        let mut cache = cache;
+        cache.put_byte(1);
+        let view = cache.consume_all();
+        drop(cache);
+        assert_eq!(memory.outstanding_bytes(CACHE), 65_536);
+
+        drop(view);
+        drop(ingest);
+        assert_eq!(memory.outstanding_bytes_by_tag(), [(CACHE, 0), (INGEST, 0)]);
+    }
+
+    #[test]
+    fn tag_budget_rejects_reservations() {
+        const WRITE: MemoryTag = MemoryTag::new("write");
+
+        let memory = GlobalPool::new();
+        memory.set_budget(WRITE, Some(2048));
+
+        // Clones of the pool share the budget.
+        let clone = memory.clone();
+        let first = memory.reserve_tagged(1000, WRITE).unwrap();
+        let _second = clone.reserve_tagged(1000, WRITE).unwrap();
+
+        let error = memory.reserve_tagged(1, WRITE).unwrap_err();
+        assert_eq!(error.tag(), WRITE);
+        assert_eq!(error.outstanding(), 2048);
+        assert_eq!(error.requested(), 1024);
+
+        drop(first);
+        let _third = memory.reserve_tagged(1, WRITE).unwrap();
+
+        memory.set_budget(WRITE, None);
+        let _fourth = memory.reserve_tagged(1_000_000, WRITE).unwrap();
+    }
+
+    #[test]
+    fn tagged_memory_released_on_other_thread() {
+        const INGEST: MemoryTag = MemoryTag::new("ingest");
+
+        let memory = GlobalPool::new();
+        let buf = memory.reserve_tagged(65_536, INGEST).unwrap();
+
+        thread::spawn(move || drop(buf)).join().unwrap();
+
+        assert_eq!(memory.outstanding_bytes(INGEST), 0);
+    }
+
+    #[test]
+    fn stats_track_blocks_until_released() {
+        let memory = GlobalPool::new();
+        assert_eq!(memory.stats(), MemoryStats::default());
+
+        // Clones of the pool share the counters.
+        let clone = memory.clone();
+        let small = memory.reserve(100);
+        let large = clone.reserve(70_000);
+
+        let stats = memory.stats();
+        assert_eq!(stats.blocks_allocated(), 3);
+        assert_eq!(stats.blocks_outstanding(), 3);
+        assert_eq!(stats.bytes_outstanding(), 1024 + 65_536 * 2);
+
+        // A block stays outstanding while any view still references it.
+        let mut large = large;
+        large.put_byte(1);
+        let view = large.consume_all();
+        drop(large);
+        assert_eq!(memory.stats().bytes_outstanding(), 1024 + 65_536);
+
+        drop(view);
+        drop(small);
+
+        let stats = memory.stats();
+        assert_eq!(stats.blocks_allocated(), 3);
+        assert_eq!(stats.blocks_outstanding(), 0);
+        assert_eq!(stats.bytes_outstanding(), 0);
+        assert_eq!(stats.peak_bytes_outstanding(), 1024 + 65_536 * 2);
+        assert_eq!(stats.reserve_failures(), 0);
+    }
+
+    #[test]
+    fn stats_count_rejected_reservations() {
+        const WRITE: MemoryTag = MemoryTag::new("write");
+
+        let memory = GlobalPool::new();
+        memory.set_budget(WRITE, Some(1024));
+
+        let _buf = memory.reserve_tagged(1000, WRITE).unwrap();
+        memory.reserve_tagged(1000, WRITE).unwrap_err();
+
+        let stats = memory.stats();
+        assert_eq!(stats.blocks_allocated(), 1);
+        assert_eq!(stats.reserve_failures(), 1);
+    }
+
+    #[test]
+    fn stats_survive_pool_and_other_thread() {
+        let memory = GlobalPool::new();
+        let buf = memory.reserve(65_536);
+        let clone = memory.clone();
+        drop(memory);
+
+        thread::spawn(move || drop(buf)).join().unwrap();
+
+        assert_eq!(clone.stats().bytes_outstanding(), 0);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // OpenTelemetry SDK uses a background thread that Miri cannot run.
+    fn metrics_report_stats() {
+        use opentelemetry_sdk::metrics::data::{AggregatedMetrics, MetricData};
+        use opentelemetry_sdk::metrics::{InMemoryMetricExporter, SdkMeterProvider};
+
+        let exporter = InMemoryMetricExporter::default();
+        let meter_provider = SdkMeterProvider::builder().with_periodic_exporter(exporter.clone()).build();
+
+        let memory = GlobalPool::new();
+        memory.register_metrics(&meter_provider);
+
+        let _buf = memory.reserve(70_000);
+        meter_provider.force_flush().unwrap();
+
+        let metrics = exporter.get_finished_metrics().unwrap();
+        let value = |name: &str| {
+            let metric = metrics
+                .iter()
+                .flat_map(opentelemetry_sdk::metrics::data::ResourceMetrics::scope_metrics)
+                .flat_map(opentelemetry_sdk::metrics::data::ScopeMetrics::metrics)
+                .find(|metric| metric.name() == name)
+                .unwrap();
+
+            match metric.data() {
+                AggregatedMetrics::U64(MetricData::Gauge(data)) => data.data_points().next().unwrap().value(),
+                AggregatedMetrics::U64(MetricData::Sum(data)) => data.data_points().next().unwrap().value(),
+                _ => panic!("unexpected data of {name}"),
+            }
+        };
+
+        assert_eq!(value("bytesbuf.global_pool.blocks.allocated"), 2);
+        assert_eq!(value("bytesbuf.global_pool.blocks.outstanding"), 2);
+        assert_eq!(value("bytesbuf.global_pool.memory.outstanding"), 65_536 * 2);
+        assert_eq!(value("bytesbuf.global_pool.memory.outstanding.peak"), 65_536 * 2);
+        assert_eq!(value("bytesbuf.global_pool.reserve.failures"), 0);
+    }
+
+    #[test]
+    fn configured_block_sizes() {
+        const WRITE: MemoryTag = MemoryTag::new("write");
+
+        let memory = GlobalPool::builder().block_sizes([4096, 65_536]).build();
+
+        assert_eq!(memory.reserve(1).capacity(), 4096);
+        assert_eq!(memory.reserve(4097).capacity(), 65_536);
+        assert_eq!(memory.reserve(70_000).capacity(), 65_536 * 2);
+
+        let _buf = memory.reserve_tagged(100, WRITE).unwrap();
+        assert_eq!(memory.outstanding_bytes(WRITE), 4096);
+        assert!(memory.inner.pool_1k.lock().unwrap().capacity() == 0);
+    }
+
+    #[test]
+    fn max_pooled_bytes_limits_pool_growth() {
+        // A chunk of the 1 KiB sub-pool holds 64 blocks.
+        let memory = GlobalPool::builder().max_pooled_bytes(65_536).build();
+
+        let pooled: Vec<_> = iter::repeat_with(|| memory.reserve(1024)).take(64).collect();
+        let beyond_limit = memory.reserve(1024);
+        assert_eq!(beyond_limit.capacity(), 1024);
+
+        let pool_1k = memory.inner.pool_1k.lock().unwrap().capacity();
+        assert_eq!(pool_1k, 64);
+        assert_eq!(memory.inner.pooled.bytes.load(atomic::Ordering::Relaxed), 65_536);
+
+        // Released pooled blocks are reused without exceeding the limit.
+        drop(pooled);
+        drop(beyond_limit);
+        let _reused = memory.reserve(1024);
+        assert_eq!(memory.inner.pool_1k.lock().unwrap().capacity(), 64);
+        assert_eq!(memory.stats().blocks_outstanding(), 1);
+    }
+
+    #[test]
+    fn zero_max_pooled_bytes_pools_nothing() {
+        let memory = GlobalPool::builder().max_pooled_bytes(0).build();
+
+        let mut buf = memory.reserve(200_000);
+        buf.put_byte_repeated(7, 200_000);
+        let view = buf.consume_all();
+        assert_eq!(view, vec![7; 200_000].as_slice());
+
+        assert_eq!(memory.inner.pool_64k.lock().unwrap().capacity(), 0);
+
+        drop(view);
+        drop(buf);
+        assert_eq!(memory.stats().bytes_outstanding(), 0);
+    }
+
+    #[test]
+    fn trim_releases_idle_sub_pools() {
+        let memory = GlobalPool::new();
+
+        let small = memory.reserve(100);
+        let large = memory.reserve(70_000);
+        drop(large);
+
+        // The 1 KiB sub-pool still has an outstanding block.
+        assert_eq!(memory.trim(), 2 * 65_536);
+        assert_eq!(memory.inner.pool_64k.lock().unwrap().capacity(), 0);
+        assert_eq!(memory.inner.pool_1k.lock().unwrap().capacity(), 64);
+        assert_eq!(memory.trim(), 0);
+
+        drop(small);
+        assert_eq!(memory.trim(), 65_536);
+        assert_eq!(memory.inner.pooled.bytes.load(atomic::Ordering::Relaxed), 0);
+        assert_all_pools_empty(&memory.inner);
+
+        // The pool keeps working after being trimmed.
+        assert_eq!(memory.reserve(100).capacity(), 1024);
+    }
+
+    #[test]
+    fn trim_frees_capacity_for_limited_pool() {
+        let memory = GlobalPool::builder().max_pooled_bytes(65_536).build();
+
+        drop(memory.reserve(65_536));
+        memory.trim();
+
+        // The limit applies to the current capacity, so the trimmed capacity can be pooled again.
+        _ = memory.reserve(1024);
+        assert_eq!(memory.inner.pool_1k.lock().unwrap().capacity(), 64);
+    }
+
     #[test]
     fn relocated_pool_works() {
         let affinities = pinned_affinities(&[2]);
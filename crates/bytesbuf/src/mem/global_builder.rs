@@ -0,0 +1,171 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use thread_aware::ThreadAware;
+
+use crate::mem::GlobalPool;
+
+/// The block sizes that a [`GlobalPool`] can use, in ascending order.
+pub(crate) const BLOCK_SIZES: [usize; 4] = [1024, 4096, 16_384, 65_536];
+
+/// Creates a [`GlobalPool`] with custom configuration.
+///
+/// Returned by [`GlobalPool::builder()`]. A pool built without changing any settings is the
+/// same as one created by [`GlobalPool::new()`].
+///
+/// # Examples
+///
+/// ```
+/// use bytesbuf::mem::GlobalPool;
+///
+/// // A service that mostly reads large payloads, with at most 16 MiB of pooled memory.
+/// let memory = GlobalPool::builder()
+///     .block_sizes([16_384, 65_536])
+///     .max_pooled_bytes(16 * 1024 * 1024)
+///     .build();
+///
+/// assert_eq!(memory.reserve(100).capacity(), 16_384);
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct GlobalPoolBuilder {
+    config: PoolConfig,
+}
+
+impl GlobalPoolBuilder {
+    pub(crate) fn new() -> Self {
+        Self {
+            config: PoolConfig {
+                block_sizes: 0b1111,
+                max_pooled_bytes: None,
+            },
+        }
+    }
+
+    /// Sets the sizes of the memory blocks that the pool uses.
+    ///
+    /// The supported block sizes are 1024, 4096, 16384 and 65536 bytes, all of which are used by
+    /// default. A reservation uses blocks of the smallest configured size that fits it, or
+    /// multiple blocks of the largest configured size if none fits.
+    ///
+    /// Leaving out the small block sizes reduces the number of blocks needed for large payloads,
+    /// at the cost of more unused capacity in small reservations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no block size is given or if a block size is not supported.
+    pub fn block_sizes(mut self, sizes: impl IntoIterator<Item = usize>) -> Self {
+        let mut block_sizes = 0;
+
+        for size in sizes {
+            let bit = BLOCK_SIZES
+                .iter()
+                .position(|&supported| supported == size)
+                .map_or(0, |index| 1 << index);
+            assert!(
+                bit != 0,
+                "block size {size} is not one of the supported block sizes {BLOCK_SIZES:?}"
+            );
+
+            block_sizes |= bit;
+        }
+
+        assert!(block_sizes != 0, "at least one block size must be configured");
+
+        self.config.block_sizes = block_sizes;
+        self
+    }
+
+    /// Limits the memory capacity that the pool keeps for reuse, in bytes.
+    ///
+    /// The pool obtains memory from the Rust global allocator in chunks of about 64 KiB and never
+    /// returns it on its own, so that it can be reused. Once the limit is reached, reservations
+    /// are still fulfilled, but with memory blocks that are returned to the global allocator as
+    /// soon as they are released, instead of being pooled.
+    ///
+    /// The limit applies separately to each of the per-thread pools that serve the clones of a
+    /// [`GlobalPool`]. By default, the pooled memory is not limited.
+    pub fn max_pooled_bytes(mut self, max_bytes: usize) -> Self {
+        self.config.max_pooled_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Creates the memory pool.
+    #[must_use]
+    pub fn build(self) -> GlobalPool {
+        GlobalPool::with_config(self.config)
+    }
+}
+
+/// The configuration of a [`GlobalPool`], shared by the pool of each thread.
+#[derive(Clone, Copy, Debug, ThreadAware)]
+pub(crate) struct PoolConfig {
+    /// Bit `i` is set if `BLOCK_SIZES[i]` is used. At least one bit is set.
+    block_sizes: u8,
+
+    pub(crate) max_pooled_bytes: Option<usize>,
+}
+
+impl PoolConfig {
+    /// Returns the size of the blocks used to satisfy a reservation of `min_bytes`.
+    pub(crate) fn block_size(self, min_bytes: usize) -> usize {
+        let mut largest = 0;
+
+        for (index, &size) in BLOCK_SIZES.iter().enumerate() {
+            if self.block_sizes & (1 << index) != 0 {
+                if min_bytes <= size {
+                    return size;
+                }
+
+                largest = size;
+            }
+        }
+
+        largest
+    }
+
+    /// Returns the capacity that a reservation of `min_bytes` provides.
+    pub(crate) fn reserved_bytes(self, min_bytes: usize) -> usize {
+        let block_size = self.block_size(min_bytes);
+        min_bytes.div_ceil(block_size) * block_size
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_block_sizes() {
+        let config = GlobalPoolBuilder::new().config;
+
+        assert_eq!(config.block_size(0), 1024);
+        assert_eq!(config.block_size(1024), 1024);
+        assert_eq!(config.block_size(1025), 4096);
+        assert_eq!(config.block_size(16_385), 65_536);
+        assert_eq!(config.block_size(1_000_000), 65_536);
+    }
+
+    #[test]
+    fn custom_block_sizes() {
+        let config = GlobalPoolBuilder::new().block_sizes([16_384, 4096]).config;
+
+        assert_eq!(config.block_size(1), 4096);
+        assert_eq!(config.block_size(4097), 16_384);
+        assert_eq!(config.block_size(16_385), 16_384);
+        assert_eq!(config.reserved_bytes(16_385), 32_768);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsupported_block_size_panics() {
+        _ = GlobalPoolBuilder::new().block_sizes([2048]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_block_sizes_panics() {
+        _ = GlobalPoolBuilder::new().block_sizes([]);
+    }
+}
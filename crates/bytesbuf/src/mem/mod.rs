@@ -40,19 +40,25 @@ mod block_ref;
 
 mod callback_memory;
 mod global;
+mod global_builder;
 mod has_memory;
 mod memory;
 mod memory_shared;
 mod opaque_memory;
+mod stats;
+mod tag;
 
 pub use block::{Block, BlockSize};
 pub use block_ref::{BlockMeta, BlockRef, BlockRefDynamic, BlockRefDynamicWithMeta, BlockRefVTable};
 pub use callback_memory::CallbackMemory;
 pub use global::GlobalPool;
+pub use global_builder::GlobalPoolBuilder;
 pub use has_memory::HasMemory;
 pub use memory::Memory;
 pub use memory_shared::MemoryShared;
 pub use opaque_memory::OpaqueMemory;
+pub use stats::MemoryStats;
+pub use tag::{BudgetExceededError, MemoryTag};
 
 #[cfg(any(test, feature = "test-util"))]
 pub mod testing;
@@ -0,0 +1,203 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#[cfg(any(test, feature = "metrics"))]
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+#[cfg(any(test, feature = "metrics"))]
+use opentelemetry::InstrumentationScope;
+#[cfg(any(test, feature = "metrics"))]
+use opentelemetry::metrics::MeterProvider;
+
+#[cfg(any(test, feature = "metrics"))]
+const METER_NAME: &str = "bytesbuf";
+#[cfg(any(test, feature = "metrics"))]
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A snapshot of the memory usage of a memory provider.
+///
+/// Returned by [`GlobalPool::stats()`][crate::mem::GlobalPool::stats]. Comparing the bytes
+/// outstanding with the memory usage of the process tells whether memory growth comes from
+/// the pool or from elsewhere, and bytes outstanding that keep growing while the load is
+/// steady point to buffers or views that are never dropped.
+///
+/// # Examples
+///
+/// ```
+/// use bytesbuf::mem::GlobalPool;
+///
+/// let memory = GlobalPool::new();
+///
+/// let buf = memory.reserve(100);
+/// assert_eq!(memory.stats().bytes_outstanding(), buf.capacity());
+///
+/// drop(buf);
+/// let stats = memory.stats();
+/// assert_eq!(stats.bytes_outstanding(), 0);
+/// assert_eq!(stats.peak_bytes_outstanding(), 1024);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    blocks_allocated: u64,
+    blocks_outstanding: usize,
+    bytes_outstanding: usize,
+    peak_bytes_outstanding: usize,
+    reserve_failures: u64,
+}
+
+impl MemoryStats {
+    /// Returns the number of memory blocks handed out since the provider was created.
+    #[must_use]
+    pub fn blocks_allocated(&self) -> u64 {
+        self.blocks_allocated
+    }
+
+    /// Returns the number of memory blocks that have not yet been returned to the provider.
+    #[must_use]
+    pub fn blocks_outstanding(&self) -> usize {
+        self.blocks_outstanding
+    }
+
+    /// Returns the number of bytes of memory capacity that have not yet been returned to
+    /// the provider.
+    #[must_use]
+    pub fn bytes_outstanding(&self) -> usize {
+        self.bytes_outstanding
+    }
+
+    /// Returns the highest number of bytes of memory capacity that was outstanding at any
+    /// point since the provider was created.
+    #[must_use]
+    pub fn peak_bytes_outstanding(&self) -> usize {
+        self.peak_bytes_outstanding
+    }
+
+    /// Returns the number of memory reservations that were rejected, for example because
+    /// they would have exceeded the budget of a [`MemoryTag`][crate::mem::MemoryTag].
+    #[must_use]
+    pub fn reserve_failures(&self) -> u64 {
+        self.reserve_failures
+    }
+}
+
+/// Tracks the counters reported by [`MemoryStats`], shared by all clones of a memory provider
+/// and by the blocks it hands out, which report back when they are returned.
+#[derive(Debug, Default)]
+pub(crate) struct StatsLedger {
+    blocks_allocated: AtomicU64,
+    blocks_outstanding: AtomicUsize,
+    bytes_outstanding: AtomicUsize,
+    peak_bytes_outstanding: AtomicUsize,
+    reserve_failures: AtomicU64,
+}
+
+impl StatsLedger {
+    /// Records that a block of `size` bytes was handed out.
+    pub(crate) fn block_allocated(&self, size: usize) {
+        // Relaxed because the counters are independent of each other and of any memory they
+        // describe, so a snapshot only needs each counter to be eventually up to date.
+        self.blocks_allocated.fetch_add(1, Ordering::Relaxed);
+        self.blocks_outstanding.fetch_add(1, Ordering::Relaxed);
+
+        let outstanding = self.bytes_outstanding.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak_bytes_outstanding.fetch_max(outstanding, Ordering::Relaxed);
+    }
+
+    /// Records that a block of `size` bytes was returned.
+    pub(crate) fn block_released(&self, size: usize) {
+        self.blocks_outstanding.fetch_sub(1, Ordering::Relaxed);
+        self.bytes_outstanding.fetch_sub(size, Ordering::Relaxed);
+    }
+
+    /// Records that a reservation was rejected.
+    pub(crate) fn reserve_failed(&self) {
+        self.reserve_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> MemoryStats {
+        MemoryStats {
+            blocks_allocated: self.blocks_allocated.load(Ordering::Relaxed),
+            blocks_outstanding: self.blocks_outstanding.load(Ordering::Relaxed),
+            bytes_outstanding: self.bytes_outstanding.load(Ordering::Relaxed),
+            peak_bytes_outstanding: self.peak_bytes_outstanding.load(Ordering::Relaxed),
+            reserve_failures: self.reserve_failures.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Creates observable OpenTelemetry instruments that report the counters of the ledger,
+    /// naming them with `prefix`.
+    ///
+    /// The callbacks of the instruments keep the ledger alive for as long as the meter
+    /// provider exists.
+    #[cfg(any(test, feature = "metrics"))]
+    pub(crate) fn register_metrics(self: &Arc<Self>, meter_provider: &dyn MeterProvider, prefix: &str) {
+        let meter = meter_provider.meter_with_scope(InstrumentationScope::builder(METER_NAME).with_version(VERSION).build());
+
+        let ledger = Arc::clone(self);
+        meter
+            .u64_observable_counter(format!("{prefix}.blocks.allocated"))
+            .with_description("Number of memory blocks handed out.")
+            .with_unit("{block}")
+            .with_callback(move |observer| observer.observe(ledger.snapshot().blocks_allocated(), &[]))
+            .build();
+
+        let ledger = Arc::clone(self);
+        meter
+            .u64_observable_gauge(format!("{prefix}.blocks.outstanding"))
+            .with_description("Number of memory blocks not yet returned.")
+            .with_unit("{block}")
+            .with_callback(move |observer| observer.observe(ledger.snapshot().blocks_outstanding() as u64, &[]))
+            .build();
+
+        let ledger = Arc::clone(self);
+        meter
+            .u64_observable_gauge(format!("{prefix}.memory.outstanding"))
+            .with_description("Bytes of memory capacity not yet returned.")
+            .with_unit("By")
+            .with_callback(move |observer| observer.observe(ledger.snapshot().bytes_outstanding() as u64, &[]))
+            .build();
+
+        let ledger = Arc::clone(self);
+        meter
+            .u64_observable_gauge(format!("{prefix}.memory.outstanding.peak"))
+            .with_description("Highest number of bytes of memory capacity outstanding at once.")
+            .with_unit("By")
+            .with_callback(move |observer| observer.observe(ledger.snapshot().peak_bytes_outstanding() as u64, &[]))
+            .build();
+
+        let ledger = Arc::clone(self);
+        meter
+            .u64_observable_counter(format!("{prefix}.reserve.failures"))
+            .with_description("Number of rejected memory reservations.")
+            .with_unit("{reservation}")
+            .with_callback(move |observer| observer.observe(ledger.snapshot().reserve_failures(), &[]))
+            .build();
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ledger_tracks_outstanding_and_peak() {
+        let ledger = StatsLedger::default();
+        assert_eq!(ledger.snapshot(), MemoryStats::default());
+
+        ledger.block_allocated(1024);
+        ledger.block_allocated(4096);
+        ledger.block_released(1024);
+        ledger.block_allocated(1024);
+        ledger.block_released(4096);
+        ledger.reserve_failed();
+
+        let stats = ledger.snapshot();
+        assert_eq!(stats.blocks_allocated(), 3);
+        assert_eq!(stats.blocks_outstanding(), 1);
+        assert_eq!(stats.bytes_outstanding(), 1024);
+        assert_eq!(stats.peak_bytes_outstanding(), 5120);
+        assert_eq!(stats.reserve_failures(), 1);
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::constants::ERR_POISONED_LOCK;
+
+/// Attributes memory reserved from a [`GlobalPool`][crate::mem::GlobalPool] to a subsystem
+/// of the application, such as request ingestion, cache fills or response writing.
+///
+/// The pool tracks how many bytes of memory capacity are outstanding for each tag, which helps
+/// to find the subsystem responsible when hunting memory growth. Each tag can also be given a
+/// budget that limits its outstanding memory.
+///
+/// Tags are identified by their name. Applications that prefer an enum of subsystems can
+/// convert it into tags with a `From` implementation.
+///
+/// # Examples
+///
+/// ```
+/// use bytesbuf::mem::MemoryTag;
+///
+/// const INGEST: MemoryTag = MemoryTag::new("ingest");
+///
+/// assert_eq!(INGEST.name(), "ingest");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MemoryTag(&'static str);
+
+impl MemoryTag {
+    /// Creates a tag with the given name.
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self(name)
+    }
+
+    /// Returns the name of the tag.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        self.0
+    }
+}
+
+impl Display for MemoryTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// A tagged memory reservation was rejected because it would exceed the budget of its tag.
+///
+/// Returned by [`GlobalPool::reserve_tagged()`][crate::mem::GlobalPool::reserve_tagged].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BudgetExceededError {
+    tag: MemoryTag,
+    budget: usize,
+    outstanding: usize,
+    requested: usize,
+}
+
+impl BudgetExceededError {
+    /// Returns the tag whose budget would have been exceeded.
+    #[must_use]
+    pub fn tag(&self) -> MemoryTag {
+        self.tag
+    }
+
+    /// Returns the budget of the tag, in bytes.
+    #[must_use]
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Returns the number of bytes outstanding for the tag when the reservation was rejected.
+    #[must_use]
+    pub fn outstanding(&self) -> usize {
+        self.outstanding
+    }
+
+    /// Returns the number of bytes the rejected reservation would have taken from the pool.
+    ///
+    /// This may exceed the requested minimum, as the pool reserves whole memory blocks.
+    #[must_use]
+    pub fn requested(&self) -> usize {
+        self.requested
+    }
+}
+
+impl Display for BudgetExceededError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "reserving {} bytes of memory tagged '{}' would exceed its budget of {} bytes, {} bytes are outstanding",
+            self.requested, self.tag, self.budget, self.outstanding
+        )
+    }
+}
+
+impl std::error::Error for BudgetExceededError {}
+
+/// The outstanding memory of every tag used with a pool, shared by all clones of the pool.
+#[derive(Debug, Default)]
+pub(crate) struct TagLedger {
+    accounts: Mutex<HashMap<MemoryTag, Arc<TagAccount>>>,
+}
+
+impl TagLedger {
+    /// Returns the account of `tag`, opening one if the tag has not been used yet.
+    pub(crate) fn account(&self, tag: MemoryTag) -> Arc<TagAccount> {
+        let mut accounts = self.accounts.lock().expect(ERR_POISONED_LOCK);
+        Arc::clone(accounts.entry(tag).or_default())
+    }
+
+    pub(crate) fn outstanding(&self, tag: MemoryTag) -> usize {
+        let accounts = self.accounts.lock().expect(ERR_POISONED_LOCK);
+        accounts.get(&tag).map_or(0, |account| account.outstanding())
+    }
+
+    /// Returns the outstanding bytes of every tag that was ever used, sorted by tag name.
+    pub(crate) fn outstanding_by_tag(&self) -> Vec<(MemoryTag, usize)> {
+        let accounts = self.accounts.lock().expect(ERR_POISONED_LOCK);
+
+        let mut usage: Vec<_> = accounts.iter().map(|(tag, account)| (*tag, account.outstanding())).collect();
+        usage.sort_unstable();
+        usage
+    }
+}
+
+/// The outstanding memory and budget of one tag.
+///
+/// Every tagged memory block holds a reference to the account of its tag, and returns its
+/// capacity to the account when the block itself is returned to the pool.
+#[derive(Debug)]
+pub(crate) struct TagAccount {
+    outstanding: AtomicUsize,
+    // `usize::MAX` if the tag has no budget.
+    budget: AtomicUsize,
+}
+
+impl Default for TagAccount {
+    fn default() -> Self {
+        Self {
+            outstanding: AtomicUsize::new(0),
+            budget: AtomicUsize::new(usize::MAX),
+        }
+    }
+}
+
+impl TagAccount {
+    pub(crate) fn outstanding(&self) -> usize {
+        self.outstanding.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_budget(&self, budget: Option<usize>) {
+        self.budget.store(budget.unwrap_or(usize::MAX), Ordering::Relaxed);
+    }
+
+    /// Takes `bytes` from the budget of the account, unless that would exceed the budget.
+    pub(crate) fn acquire(&self, tag: MemoryTag, bytes: usize) -> Result<(), BudgetExceededError> {
+        let budget = self.budget.load(Ordering::Relaxed);
+
+        // Relaxed because the counter is independent of any other state.
+        self.outstanding
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |outstanding| {
+                outstanding.checked_add(bytes).filter(|total| *total <= budget)
+            })
+            .map(|_| ())
+            .map_err(|outstanding| BudgetExceededError {
+                tag,
+                budget,
+                outstanding,
+                requested: bytes,
+            })
+    }
+
+    pub(crate) fn release(&self, bytes: usize) {
+        self.outstanding.fetch_sub(bytes, Ordering::Relaxed);
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INGEST: MemoryTag = MemoryTag::new("ingest");
+
+    #[test]
+    fn account_enforces_budget() {
+        let account = TagAccount::default();
+        account.set_budget(Some(100));
+
+        account.acquire(INGEST, 60).unwrap();
+        let error = account.acquire(INGEST, 60).unwrap_err();
+        assert_eq!(error.tag(), INGEST);
+        assert_eq!(error.budget(), 100);
+        assert_eq!(error.outstanding(), 60);
+        assert_eq!(error.requested(), 60);
+        assert_eq!(
+            error.to_string(),
+            "reserving 60 bytes of memory tagged 'ingest' would exceed its budget of 100 bytes, 60 bytes are outstanding"
+        );
+
+        account.release(60);
+        account.acquire(INGEST, 100).unwrap();
+        assert_eq!(account.outstanding(), 100);
+
+        account.set_budget(None);
+        account.acquire(INGEST, 1_000_000).unwrap();
+    }
+
+    #[test]
+    fn ledger_lists_tags_by_name() {
+        let ledger = TagLedger::default();
+        ledger.account(MemoryTag::new("write")).acquire(MemoryTag::new("write"), 5).unwrap();
+        ledger.account(INGEST).acquire(INGEST, 7).unwrap();
+
+        assert_eq!(ledger.outstanding(INGEST), 7);
+        assert_eq!(ledger.outstanding(MemoryTag::new("unused")), 0);
+        assert_eq!(ledger.outstanding_by_tag(), [(INGEST, 7), (MemoryTag::new("write"), 5)]);
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::num::NonZero;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread;
+use std::time::Duration;
+
+use thread_aware::ThreadAware;
+
+use crate::BytesBuf;
+use crate::mem::testing::std_alloc_block;
+use crate::mem::{BlockSize, Memory};
+
+const DEFAULT_MAX_BLOCK_SIZE: NonZero<BlockSize> = NonZero::new(8).expect("8 is not zero");
+
+/// A memory provider that misbehaves, returning tiny memory blocks and reserving memory slowly.
+///
+/// Every memory capacity reservation is cut into blocks of random size, from 1 byte up to
+/// the maximum block size, so byte sequences are fragmented unpredictably. Reservations can
+/// also block the calling thread for a while, to simulate an allocator under memory pressure.
+///
+/// This provider is meant for testing that code handling byte sequences copes with whatever
+/// memory it gets. All user code is expected to correctly operate with memory blocks of any
+/// size, including single-byte blocks.
+///
+/// The block sizes and delays are picked by a random number generator shared by all clones
+/// of the provider. Set a [`seed`][Self::seed] to reproduce a sequence of reservations.
+///
+/// # Performance
+///
+/// This memory provider is a simple implementation that does not perform any pooling
+/// or performance optimization, so should not be used in real code.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZero;
+///
+/// use bytesbuf::BytesView;
+/// use bytesbuf::mem::testing::FlakyMemory;
+///
+/// let memory = FlakyMemory::new()
+///     .max_block_size(NonZero::new(4).unwrap())
+///     .seed(42);
+///
+/// let data = BytesView::copied_from_slice(b"Hello, world! This is a test!", &memory);
+/// assert_eq!(data, b"Hello, world! This is a test!");
+///
+/// // The data is spread over blocks of 1 to 4 bytes.
+/// assert!(data.slices().all(|(slice, _)| slice.len() <= 4));
+/// ```
+#[derive(Clone, Debug, ThreadAware)]
+pub struct FlakyMemory {
+    // Plain configuration copied into every clone, so there is nothing to relocate.
+    #[thread_aware(skip)]
+    max_block_size: NonZero<BlockSize>,
+    #[thread_aware(skip)]
+    delay_rate: f64,
+    #[thread_aware(skip)]
+    delay: Duration,
+    // Shared between clones on purpose, so that a seed determines all reservations.
+    #[thread_aware(skip)]
+    rng: Arc<Mutex<fastrand::Rng>>,
+}
+
+impl FlakyMemory {
+    /// Creates a new instance of the memory provider.
+    ///
+    /// By default, blocks are up to 8 bytes in size and reservations are never delayed.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            max_block_size: DEFAULT_MAX_BLOCK_SIZE,
+            delay_rate: 0.0,
+            delay: Duration::ZERO,
+            rng: Arc::new(Mutex::new(fastrand::Rng::new())),
+        }
+    }
+
+    /// Sets the maximum size of the memory blocks, 8 bytes by default.
+    ///
+    /// The size of each block is picked from `1..=max_block_size`.
+    #[must_use]
+    pub fn max_block_size(mut self, max_block_size: NonZero<BlockSize>) -> Self {
+        self.max_block_size = max_block_size;
+        self
+    }
+
+    /// Sets the probability of a reservation to block the calling thread for `delay`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not between 0.0 and 1.0.
+    #[must_use]
+    pub fn delay_rate(mut self, rate: f64, delay: Duration) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&rate),
+            "the delay rate must be between 0.0 and 1.0, got {rate}"
+        );
+        self.delay_rate = rate;
+        self.delay = delay;
+        self
+    }
+
+    /// Seeds the random number generator that picks the block sizes and delays.
+    #[must_use]
+    pub fn seed(self, seed: u64) -> Self {
+        self.rng().seed(seed);
+        self
+    }
+
+    /// Reserves at least `min_bytes` bytes of memory capacity.
+    ///
+    /// The capacity is made up of blocks of random size, so it may exceed `min_bytes`
+    /// by up to one less than the maximum block size.
+    ///
+    /// Returns a [`BytesBuf`] that can be used to fill the reserved memory with data.
+    ///
+    /// # Zero-sized reservations
+    ///
+    /// Reserving zero bytes of memory is a valid operation and will return a [`BytesBuf`]
+    /// with zero bytes of capacity. Zero-sized reservations are never delayed.
+    ///
+    /// # Panics
+    ///
+    /// May panic if the operating system runs out of memory.
+    #[must_use]
+    pub fn reserve(&self, min_bytes: usize) -> BytesBuf {
+        if min_bytes == 0 {
+            return BytesBuf::default();
+        }
+
+        let mut rng = self.rng();
+
+        if rng.f64() < self.delay_rate {
+            thread::sleep(self.delay);
+        }
+
+        let mut blocks = Vec::new();
+        let mut capacity = 0;
+
+        while capacity < min_bytes {
+            let block_size = rng.u32(1..=self.max_block_size.get());
+            let block_size = NonZero::new(block_size).expect("the block size range starts at 1");
+
+            blocks.push(std_alloc_block::allocate(block_size));
+            capacity += block_size.get() as usize;
+        }
+
+        BytesBuf::from_blocks(blocks)
+    }
+
+    // The generator state is valid after every call, so it can be used even if a panicking
+    // test poisoned the lock.
+    fn rng(&self) -> MutexGuard<'_, fastrand::Rng> {
+        self.rng.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for FlakyMemory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Memory for FlakyMemory {
+    #[cfg_attr(test, mutants::skip)] // Trivial forwarder.
+    fn reserve(&self, min_bytes: usize) -> BytesBuf {
+        self.reserve(min_bytes)
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use new_zealand::nz;
+    use static_assertions::assert_impl_all;
+
+    use super::*;
+    use crate::BytesView;
+    use crate::mem::MemoryShared;
+
+    assert_impl_all!(FlakyMemory: MemoryShared);
+
+    fn block_sizes(view: &BytesView) -> Vec<usize> {
+        view.slices().map(|(slice, _)| slice.len()).collect()
+    }
+
+    #[test]
+    fn blocks_are_tiny() {
+        let memory = FlakyMemory::new().max_block_size(nz!(3));
+
+        let buf = memory.reserve(0);
+        assert_eq!(buf.capacity(), 0);
+
+        let buf = memory.reserve(100);
+        assert!((100..103).contains(&buf.capacity()));
+
+        let data = BytesView::copied_from_slice(&[7; 100], &memory);
+        assert_eq!(data.to_vec(), [7; 100]);
+        assert!(block_sizes(&data).iter().all(|len| (1..=3).contains(len)));
+    }
+
+    #[test]
+    fn seed_reproduces_block_sizes() {
+        let layout = |seed| {
+            let memory = FlakyMemory::new().seed(seed);
+            block_sizes(&BytesView::copied_from_slice(&[1; 64], &memory))
+        };
+
+        let sizes = layout(7);
+        assert_eq!(sizes, layout(7));
+        assert!(sizes.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn reservations_are_delayed() {
+        let memory = FlakyMemory::new().delay_rate(1.0, Duration::from_millis(10));
+
+        let start = Instant::now();
+        let _buf = memory.reserve(1);
+        assert!(start.elapsed() >= Duration::from_millis(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "the delay rate must be between 0.0 and 1.0, got -1")]
+    fn invalid_delay_rate_panics() {
+        let _memory = FlakyMemory::default().delay_rate(-1.0, Duration::from_millis(1));
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::num::NonZero;
+
+use bolero::{Driver, ValueGenerator};
+
+use crate::BytesView;
+use crate::mem::BlockSize;
+use crate::mem::testing::FixedBlockMemory;
+
+const DEFAULT_MAX_LEN: usize = 256;
+const DEFAULT_MAX_BLOCK_SIZE: NonZero<BlockSize> = NonZero::new(16).expect("16 is not zero");
+
+/// Marks the consumed bytes preceding the content of a view, to make reading them stand out.
+const CONSUMED_BYTE: u8 = 0xCC;
+
+/// Returns a [`bolero`] generator of [`BytesView`]s with random content and a random layout.
+///
+/// All user code is expected to correctly operate on byte sequences of any layout. Use this
+/// generator to check that code reading a [`BytesView`] does not depend on how its content
+/// happens to be split into memory blocks.
+///
+/// Every generated view is backed by a [`FixedBlockMemory`] with a random block size and
+/// consists of a random number of segments, each of which either:
+///
+/// * shares its memory blocks with the other shared segments of the view, or
+/// * has its own memory blocks, starting after a partially consumed prefix.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// # #[cfg(feature = "bolero")] {
+/// use bytesbuf::mem::testing::views;
+///
+/// bolero::check!()
+///     .with_generator(views().max_len(64))
+///     .for_each(|view| {
+///         let content = view.to_vec();
+///         assert_eq!(view.len(), content.len());
+///     });
+/// # }
+/// # }
+/// ```
+pub fn views() -> ViewGenerator {
+    ViewGenerator {
+        content: None,
+        max_len: DEFAULT_MAX_LEN,
+        max_block_size: DEFAULT_MAX_BLOCK_SIZE,
+    }
+}
+
+/// Returns a [`bolero`] generator of [`BytesView`]s with the given content and a random layout.
+///
+/// The views are laid out the same way as [`views()`] lays them out, which is useful for parsers
+/// that need well-formed input.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() {
+/// # #[cfg(feature = "bolero")] {
+/// use bytesbuf::mem::testing::views_of;
+///
+/// bolero::check!()
+///     .with_generator(views_of(b"GET / HTTP/1.1\r\n"))
+///     .for_each(|view| {
+///         assert_eq!(*view, b"GET / HTTP/1.1\r\n");
+///     });
+/// # }
+/// # }
+/// ```
+pub fn views_of(content: impl AsRef<[u8]>) -> ViewGenerator {
+    let content = content.as_ref().to_vec();
+
+    ViewGenerator {
+        max_len: content.len(),
+        content: Some(content),
+        max_block_size: DEFAULT_MAX_BLOCK_SIZE,
+    }
+}
+
+/// A [`bolero`] generator of [`BytesView`]s, created by [`views()`] or [`views_of()`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct ViewGenerator {
+    content: Option<Vec<u8>>,
+    max_len: usize,
+    max_block_size: NonZero<BlockSize>,
+}
+
+impl ViewGenerator {
+    /// Sets the maximum length of the random content of the views, 256 bytes by default.
+    ///
+    /// Has no effect on views generated by [`views_of()`].
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        if self.content.is_none() {
+            self.max_len = max_len;
+        }
+        self
+    }
+
+    /// Sets the maximum size of the memory blocks backing the views, 16 bytes by default.
+    ///
+    /// The block size of each view is picked from `1..=max_block_size`.
+    pub fn max_block_size(mut self, max_block_size: NonZero<BlockSize>) -> Self {
+        self.max_block_size = max_block_size;
+        self
+    }
+}
+
+impl ValueGenerator for ViewGenerator {
+    type Output = BytesView;
+
+    fn generate<D: Driver>(&self, driver: &mut D) -> Option<Self::Output> {
+        let block_size = (1..=self.max_block_size.get()).generate(driver)?;
+        let block_size = NonZero::new(block_size).expect("the block size range starts at 1");
+        let memory = FixedBlockMemory::new(block_size);
+
+        let content = if let Some(content) = &self.content {
+            content.clone()
+        } else {
+            let len = (0..=self.max_len).generate(driver)?;
+            (0..len).map(|_| driver.produce::<u8>()).collect::<Option<Vec<_>>>()?
+        };
+
+        // The shared segments are all ranges of this view, so they share its memory blocks.
+        let shared = BytesView::copied_from_slice(&content, &memory);
+
+        let mut segments = Vec::new();
+        let mut offset = 0;
+
+        while offset < content.len() {
+            let len = (1..=content.len() - offset).generate(driver)?;
+
+            let segment = if driver.gen_bool(None)? {
+                shared.range(offset..offset + len)
+            } else {
+                let consumed = (0..=block_size.get() as usize).generate(driver)?;
+
+                let mut buf = memory.reserve(consumed + len);
+                buf.put_byte_repeated(CONSUMED_BYTE, consumed);
+                buf.put_slice(&content[offset..offset + len]);
+
+                let mut segment = buf.consume_all();
+                segment.advance(consumed);
+                segment
+            };
+
+            segments.push(segment);
+            offset += len;
+        }
+
+        Some(BytesView::from_views(segments))
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(miri))] // `bolero::check!` needs filesystem access that Miri does not provide.
+mod tests {
+    use super::*;
+
+    #[test]
+    fn views_of_keep_content() {
+        bolero::check!()
+            .with_generator(views_of(b"Hello, world! This is a test!"))
+            .for_each(|view| {
+                assert_eq!(*view, b"Hello, world! This is a test!");
+            });
+    }
+
+    #[test]
+    fn views_respect_limits() {
+        bolero::check!()
+            .with_generator(views().max_len(32).max_block_size(NonZero::new(4).unwrap()))
+            .for_each(|view| {
+                assert!(view.len() <= 32);
+                assert!(view.slices().all(|(slice, _)| !slice.is_empty() && slice.len() <= 4));
+            });
+    }
+
+    #[test]
+    fn views_vary_layout() {
+        let mut multi_slice = false;
+        let mut single_byte_slices = false;
+
+        bolero::check!()
+            .with_generator(views_of([7_u8; 64]))
+            .with_iterations(1000)
+            .for_each(|view| {
+                let slice_count = view.slices().count();
+                multi_slice |= slice_count > 1;
+                single_byte_slices |= slice_count == 64;
+            });
+
+        assert!(multi_slice);
+        assert!(single_byte_slices);
+    }
+
+    #[test]
+    fn max_len_is_fixed_by_content() {
+        let generator = views_of(b"abc").max_len(100);
+        assert_eq!(generator.max_len, 3);
+    }
+}
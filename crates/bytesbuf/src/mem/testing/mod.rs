@@ -5,6 +5,9 @@
 //!
 //! This module contains special-purpose memory providers that are not optimized for real-world
 //! usage but may be useful to test corner cases of byte sequence processing in your code.
+//!
+//! With the `bolero` Cargo feature, it also contains [`bolero`] generators of byte sequences
+//! with random layouts, for property tests of code that reads byte sequences.
 
 #[cfg(test)]
 mod test_block;
@@ -15,12 +18,22 @@ pub(crate) use test_block::*;
 #[cfg(any(test, feature = "test-util"))]
 mod fixed_block;
 
+#[cfg(any(test, feature = "test-util"))]
+mod flaky;
+
+#[cfg(any(test, feature = "bolero"))]
+mod generators;
+
 #[cfg(any(test, feature = "test-util"))]
 mod transparent;
 
 #[cfg(any(test, feature = "test-util"))]
 pub use fixed_block::FixedBlockMemory;
 #[cfg(any(test, feature = "test-util"))]
+pub use flaky::FlakyMemory;
+#[cfg(any(test, feature = "bolero"))]
+pub use generators::{ViewGenerator, views, views_of};
+#[cfg(any(test, feature = "test-util"))]
 pub use transparent::TransparentMemory;
 
 #[cfg(any(test, feature = "test-util"))]
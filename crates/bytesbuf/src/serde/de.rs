@@ -0,0 +1,504 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::str;
+
+use ::serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+
+use super::{Error, Result};
+use crate::BytesView;
+
+/// Deserializes a value from the front of a byte sequence, consuming its bytes.
+///
+/// Any bytes after the value are left in the view, so that consecutive values can be
+/// deserialized from the same view.
+///
+/// # Example
+///
+/// ```
+/// # let memory = bytesbuf::mem::GlobalPool::new();
+/// use bytesbuf::BytesView;
+/// use bytesbuf::serde::from_view;
+///
+/// let mut view = BytesView::copied_from_slice(b"\x07\x00\x03abc", &memory);
+///
+/// let (number, text): (u16, String) = from_view(&mut view)?;
+///
+/// assert_eq!(number, 7);
+/// assert_eq!(text, "abc");
+/// # Ok::<(), bytesbuf::serde::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the view does not start with a value of type `T`, for example because
+/// the view ends before the value does. The view is left in an unspecified state.
+pub fn from_view<T: DeserializeOwned>(view: &mut BytesView) -> Result<T> {
+    T::deserialize(&mut Deserializer::new(view))
+}
+
+/// Deserializes values from the front of a [`BytesView`], consuming their bytes.
+///
+/// Strings and byte arrays that lie within a single span of the view are passed to the
+/// deserialized type without copying them first. Use [`from_view()`] to deserialize a single
+/// value.
+#[derive(Debug)]
+pub struct Deserializer<'a> {
+    view: &'a mut BytesView,
+}
+
+impl<'a> Deserializer<'a> {
+    /// Creates a deserializer that consumes values from the front of `view`.
+    #[must_use]
+    pub fn new(view: &'a mut BytesView) -> Self {
+        Self { view }
+    }
+
+    /// Returns an error if the view holds fewer than `len` bytes.
+    fn require(&self, len: usize) -> Result<()> {
+        if self.view.len() < len {
+            return Err(Error::caused_by("the data ends before the value does"));
+        }
+
+        Ok(())
+    }
+
+    fn get_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        self.require(N)?;
+        let mut array = [0_u8; N];
+        self.view.copy_to_slice(&mut array);
+        Ok(array)
+    }
+
+    fn get_len(&mut self) -> Result<usize> {
+        let len = self.view.get_varint_u64().map_err(Error::caused_by)?;
+        usize::try_from(len).map_err(Error::caused_by)
+    }
+
+    /// Returns the length of a string or byte array, checking that its bytes are in the view.
+    fn get_bytes_len(&mut self) -> Result<usize> {
+        let len = self.get_len()?;
+        self.require(len)?;
+        Ok(len)
+    }
+
+    fn get_tag(&mut self) -> Result<bool> {
+        match self.get_array::<1>()? {
+            [0] => Ok(false),
+            [1] => Ok(true),
+            [tag] => Err(Error::caused_by(format!("{tag} is neither 0 nor 1"))),
+        }
+    }
+}
+
+/// Implements the [`Deserializer`](de::Deserializer) methods of numbers by reading their
+/// little-endian bytes.
+macro_rules! deserialize_numbers {
+    ($($method:ident => $visit:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                visitor.$visit(<$ty>::from_le_bytes(self.get_array()?))
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'_> {
+    type Error = Error;
+
+    deserialize_numbers!(
+        deserialize_i8 => visit_i8(i8),
+        deserialize_i16 => visit_i16(i16),
+        deserialize_i32 => visit_i32(i32),
+        deserialize_i64 => visit_i64(i64),
+        deserialize_i128 => visit_i128(i128),
+        deserialize_u8 => visit_u8(u8),
+        deserialize_u16 => visit_u16(u16),
+        deserialize_u32 => visit_u32(u32),
+        deserialize_u64 => visit_u64(u64),
+        deserialize_u128 => visit_u128(u128),
+        deserialize_f32 => visit_f32(f32),
+        deserialize_f64 => visit_f64(f64),
+    );
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(Error::caused_by(
+            "the format is not self-describing, so the type of the value must be known",
+        ))
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_bool(self.get_tag()?)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let code = u32::from_le_bytes(self.get_array()?);
+        let c = char::from_u32(code).ok_or_else(|| Error::caused_by(format!("{code:#x} is not a valid char")))?;
+        visitor.visit_char(c)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let len = self.get_bytes_len()?;
+
+        if let Some(bytes) = self.view.first_slice().get(..len) {
+            let value = visitor.visit_str(str::from_utf8(bytes).map_err(Error::caused_by)?);
+            self.view.advance(len);
+            return value;
+        }
+
+        // The string straddles a span boundary, so we gather it.
+        visitor.visit_string(self.view.get_str(len).map_err(Error::caused_by)?)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let len = self.get_bytes_len()?;
+
+        if let Some(bytes) = self.view.first_slice().get(..len) {
+            let value = visitor.visit_bytes(bytes);
+            self.view.advance(len);
+            return value;
+        }
+
+        // The bytes straddle a span boundary, so we gather them.
+        visitor.visit_byte_buf(self.view.split_to(len).to_vec())
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.get_tag()? {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let len = self.get_len()?;
+        visitor.visit_seq(Elements { de: self, remaining: len })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(Elements { de: self, remaining: len })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(self, _name: &'static str, len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let len = self.get_len()?;
+        visitor.visit_map(Elements { de: self, remaining: len })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(self, _name: &'static str, fields: &'static [&'static str], visitor: V) -> Result<V::Value> {
+        self.deserialize_tuple(fields.len(), visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value> {
+        visitor.visit_enum(self)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// The elements of a sequence, tuple or struct, or the entries of a map, of known length.
+struct Elements<'a, 'b> {
+    de: &'a mut Deserializer<'b>,
+    remaining: usize,
+}
+
+impl<'de> de::SeqAccess<'de> for Elements<'_, '_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de> de::MapAccess<'de> for Elements<'_, '_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de> de::EnumAccess<'de> for &mut Deserializer<'_> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self)> {
+        let variant_index = self.view.get_varint_u32().map_err(Error::caused_by)?;
+        let variant = seed.deserialize(IntoDeserializer::<Error>::into_deserializer(variant_index))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for &mut Deserializer<'_> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        de::Deserializer::deserialize_tuple(self, len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(self, fields: &'static [&'static str], visitor: V) -> Result<V::Value> {
+        de::Deserializer::deserialize_tuple(self, fields.len(), visitor)
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ::serde::{Deserialize, Serialize};
+    use ohno::ErrorExt;
+
+    use super::*;
+    use crate::mem::testing::TransparentMemory;
+    use crate::serde::to_view;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Line(i32, i32),
+        Rect { width: u16, height: u16 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Everything {
+        flag: bool,
+        small: i8,
+        big: u128,
+        letter: char,
+        name: String,
+        #[serde(with = "serde_bytes_compat")]
+        payload: Vec<u8>,
+        nothing: Option<u32>,
+        something: Option<u32>,
+        shapes: Vec<Shape>,
+        labels: BTreeMap<String, u64>,
+        unit: (),
+        pair: (u8, String),
+    }
+
+    /// Serializes a `Vec<u8>` with `serialize_bytes` instead of as a sequence.
+    mod serde_bytes_compat {
+        use ::serde::de::{self, Visitor};
+        use ::serde::{Deserializer, Serializer};
+
+        pub(super) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(bytes)
+        }
+
+        pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+            struct BytesVisitor;
+
+            impl Visitor<'_> for BytesVisitor {
+                type Value = Vec<u8>;
+
+                fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    formatter.write_str("bytes")
+                }
+
+                fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                    Ok(v.to_vec())
+                }
+            }
+
+            deserializer.deserialize_bytes(BytesVisitor)
+        }
+    }
+
+    fn everything() -> Everything {
+        Everything {
+            flag: true,
+            small: -5,
+            big: u128::MAX - 1,
+            letter: 'ß',
+            name: "Grüß Gott".to_string(),
+            payload: vec![0, 1, 2, 255],
+            nothing: None,
+            something: Some(7),
+            shapes: vec![
+                Shape::Empty,
+                Shape::Circle(1.5),
+                Shape::Line(-1, 1),
+                Shape::Rect { width: 3, height: 4 },
+            ],
+            labels: BTreeMap::from([("a".to_string(), 1), ("b".to_string(), u64::MAX)]),
+            unit: (),
+            pair: (9, "nine".to_string()),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let memory = TransparentMemory::new();
+
+        let mut view = to_view(&everything(), &memory).unwrap();
+        assert_eq!(from_view::<Everything>(&mut view).unwrap(), everything());
+        assert!(view.is_empty());
+    }
+
+    #[test]
+    fn round_trip_multi_span() {
+        let memory = TransparentMemory::new();
+        let serialized = to_view(&everything(), &memory).unwrap().to_vec();
+
+        // One span per byte, so every string, byte array and number straddles span boundaries.
+        let mut view = BytesView::from_views(serialized.iter().map(|byte| BytesView::copied_from_slice(&[*byte], &memory)));
+        assert_eq!(from_view::<Everything>(&mut view).unwrap(), everything());
+        assert!(view.is_empty());
+    }
+
+    #[test]
+    fn consecutive_values() {
+        let memory = TransparentMemory::new();
+        let mut view = to_view(&1_u32, &memory).unwrap().concat(to_view("two", &memory).unwrap());
+
+        assert_eq!(from_view::<u32>(&mut view).unwrap(), 1);
+        assert_eq!(from_view::<String>(&mut view).unwrap(), "two");
+        assert!(view.is_empty());
+    }
+
+    #[test]
+    fn encoding() {
+        let memory = TransparentMemory::new();
+
+        let view = to_view(&Shape::Rect { width: 3, height: 258 }, &memory).unwrap();
+        assert_eq!(view, b"\x03\x03\x00\x02\x01");
+
+        let view = to_view(&(Some(true), None::<u8>, vec!['a']), &memory).unwrap();
+        assert_eq!(view, b"\x01\x01\x00\x01a\x00\x00\x00");
+    }
+
+    #[test]
+    fn truncated_data_is_error() {
+        let memory = TransparentMemory::new();
+        let serialized = to_view(&everything(), &memory).unwrap();
+
+        for len in 0..serialized.len() {
+            let mut view = serialized.range(..len);
+            from_view::<Everything>(&mut view).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn invalid_data_is_error() {
+        let memory = TransparentMemory::new();
+
+        let mut view = BytesView::copied_from_slice(b"\x02", &memory);
+        let error = from_view::<bool>(&mut view).unwrap_err();
+        assert_eq!(error.message(), "2 is neither 0 nor 1");
+
+        let mut view = BytesView::copied_from_slice(&0xD800_u32.to_le_bytes(), &memory);
+        let error = from_view::<char>(&mut view).unwrap_err();
+        assert_eq!(error.message(), "0xd800 is not a valid char");
+
+        let mut view = BytesView::copied_from_slice(b"\x01\xFF", &memory);
+        from_view::<String>(&mut view).unwrap_err();
+
+        let mut view = BytesView::copied_from_slice(b"\x09", &memory);
+        from_view::<Shape>(&mut view).unwrap_err();
+
+        let mut view = BytesView::copied_from_slice(b"\x05abc", &memory);
+        let error = from_view::<String>(&mut view).unwrap_err();
+        assert_eq!(error.message(), "the data ends before the value does");
+    }
+
+    #[test]
+    fn self_describing_types_are_error() {
+        #[derive(Debug, Deserialize)]
+        #[serde(untagged)]
+        enum Untagged {
+            Number(#[expect(dead_code, reason = "only deserialized")] u8),
+        }
+
+        let memory = TransparentMemory::new();
+        let mut view = BytesView::copied_from_slice(b"\x01", &memory);
+
+        let error = from_view::<Untagged>(&mut view).unwrap_err();
+        assert_eq!(
+            error.message(),
+            "the format is not self-describing, so the type of the value must be known"
+        );
+    }
+
+    #[test]
+    fn unknown_length_is_error() {
+        struct Unknown;
+
+        impl Serialize for Unknown {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                use ::serde::ser::SerializeSeq;
+                serializer.serialize_seq(None)?.end()
+            }
+        }
+
+        let memory = TransparentMemory::new();
+
+        let error = to_view(&Unknown, &memory).unwrap_err();
+        assert_eq!(error.message(), "sequences of unknown length cannot be serialized");
+    }
+}
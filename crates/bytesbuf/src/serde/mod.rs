@@ -0,0 +1,88 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! A compact binary `serde` format that serializes into a [`BytesBuf`] and deserializes from a
+//! [`BytesView`].
+//!
+//! Values are written directly into the memory of the buffer and read directly from the spans
+//! of the view, so a (de)serialization pipeline needs no intermediate `Vec<u8>`. Strings and
+//! byte arrays that lie within a single span are passed to the deserialized type without
+//! copying them first.
+//!
+//! This module is available when the `serde` Cargo feature is enabled.
+//!
+//! # Example
+//!
+//! ```
+//! # let memory = bytesbuf::mem::GlobalPool::new();
+//! use bytesbuf::serde::{from_view, to_view};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Greeting {
+//!     id: u32,
+//!     text: String,
+//! }
+//!
+//! let greeting = Greeting {
+//!     id: 42,
+//!     text: "Hello, world!".to_string(),
+//! };
+//!
+//! let mut view = to_view(&greeting, &memory)?;
+//! assert_eq!(from_view::<Greeting>(&mut view)?, greeting);
+//! assert!(view.is_empty());
+//! # Ok::<(), bytesbuf::serde::Error>(())
+//! ```
+//!
+//! # Format
+//!
+//! The format is not self-describing: the type of a value must be known to deserialize it,
+//! so types that deserialize via `deserialize_any`, such as `serde_json::Value` or untagged
+//! enums, are not supported.
+//!
+//! * Integers and floating-point numbers are written in little-endian byte order, with the
+//!   width of their type.
+//! * `bool` is written as a byte holding `0` or `1`, and `char` as a `u32`.
+//! * Strings and byte arrays are written as their length followed by their bytes.
+//! * Sequences and maps are written as their length followed by their elements, or keys and
+//!   values, so their length must be known when they are serialized.
+//! * `Option` is written as a byte holding `0` for `None`, or `1` followed by the value.
+//! * Tuples and structs are written as their fields in order, without names.
+//! * Enum variants are written as their index followed by their fields.
+//! * Units, unit structs and newtype wrappers add no bytes of their own.
+//!
+//! Lengths and variant indices are LEB128 variable-length integers, as written by
+//! [`BytesBuf::put_varint_u64()`].
+
+mod de;
+mod ser;
+
+pub use de::{Deserializer, from_view};
+pub use ser::{Serializer, to_view};
+
+#[cfg(doc)]
+use crate::{BytesBuf, BytesView};
+
+/// An error that occurred while serializing or deserializing a value.
+///
+/// This is returned by serialized types that refuse to serialize, by sequences and maps of
+/// unknown length, and by data that does not hold a value of the deserialized type, such as
+/// data that ends early.
+#[ohno::error]
+pub struct Error {}
+
+/// A `Result` that may contain an [`Error`] from this module.
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl ::serde::ser::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self::caused_by(msg.to_string())
+    }
+}
+
+impl ::serde::de::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self::caused_by(msg.to_string())
+    }
+}
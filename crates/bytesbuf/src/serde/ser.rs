@@ -0,0 +1,265 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use ::serde::ser::{self, Serialize};
+
+use super::{Error, Result};
+use crate::mem::Memory;
+use crate::{BytesBuf, BytesBufWriter, BytesView};
+
+/// Serializes a value into a new byte sequence, using memory from `memory`.
+///
+/// # Example
+///
+/// ```
+/// # let memory = bytesbuf::mem::GlobalPool::new();
+/// use bytesbuf::serde::to_view;
+///
+/// let view = to_view(&(7_u16, "abc"), &memory)?;
+///
+/// assert_eq!(view, b"\x07\x00\x03abc");
+/// # Ok::<(), bytesbuf::serde::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the value fails to serialize, or if it holds a sequence or map of
+/// unknown length.
+pub fn to_view<T, M>(value: &T, memory: M) -> Result<BytesView>
+where
+    T: Serialize + ?Sized,
+    M: Memory,
+{
+    let mut serializer = Serializer::new(BytesBuf::new(), memory);
+    value.serialize(&mut serializer)?;
+    Ok(serializer.into_inner().consume_all())
+}
+
+/// Serializes values by appending them to a [`BytesBuf`].
+///
+/// The buffer is extended as needed with memory from the memory provider `M`, in the same
+/// way as by [`BytesBufWriter`]. Use [`to_view()`] to serialize a single value into a new byte
+/// sequence.
+///
+/// # Example
+///
+/// ```
+/// # let memory = bytesbuf::mem::GlobalPool::new();
+/// use bytesbuf::BytesBuf;
+/// use bytesbuf::serde::Serializer;
+/// use serde::Serialize;
+///
+/// let mut serializer = Serializer::new(BytesBuf::new(), &memory);
+/// 1_u8.serialize(&mut serializer)?;
+/// true.serialize(&mut serializer)?;
+///
+/// assert_eq!(serializer.into_inner().consume_all(), b"\x01\x01");
+/// # Ok::<(), bytesbuf::serde::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Serializer<M: Memory> {
+    writer: BytesBufWriter<M>,
+}
+
+impl<M: Memory> Serializer<M> {
+    /// Creates a serializer that appends to `buf`, keeping any data already in it.
+    #[must_use]
+    pub fn new(buf: BytesBuf, memory: M) -> Self {
+        Self {
+            writer: buf.into_writer(memory),
+        }
+    }
+
+    /// Returns the buffer that holds the serialized values.
+    #[must_use]
+    pub fn into_inner(self) -> BytesBuf {
+        self.writer.into_inner()
+    }
+
+    fn put_len(&mut self, len: usize) {
+        self.writer.put_varint_u64(len as u64);
+    }
+
+    fn put_variant_index(&mut self, variant_index: u32) {
+        self.writer.put_varint_u64(variant_index.into());
+    }
+}
+
+/// Implements the [`Serializer`](ser::Serializer) methods of numbers by writing their
+/// little-endian bytes.
+macro_rules! serialize_numbers {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<()> {
+                self.writer.put_slice(&v.to_le_bytes());
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl<M: Memory> ser::Serializer for &mut Serializer<M> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    serialize_numbers!(
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+    );
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.writer.put_slice(&[u8::from(v)]);
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.serialize_u32(v.into())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.serialize_bytes(v.as_bytes())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.put_len(v.len());
+        self.writer.put_slice(v);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.writer.put_slice(&[0]);
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<()> {
+        self.writer.put_slice(&[1]);
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, variant_index: u32, _variant: &'static str) -> Result<()> {
+        self.put_variant_index(variant_index);
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.put_variant_index(variant_index);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self> {
+        let len = len.ok_or_else(|| Error::caused_by("sequences of unknown length cannot be serialized"))?;
+        self.put_len(len);
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(self, _name: &'static str, variant_index: u32, _variant: &'static str, _len: usize) -> Result<Self> {
+        self.put_variant_index(variant_index);
+        Ok(self)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self> {
+        let len = len.ok_or_else(|| Error::caused_by("maps of unknown length cannot be serialized"))?;
+        self.put_len(len);
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(self, _name: &'static str, variant_index: u32, _variant: &'static str, _len: usize) -> Result<Self> {
+        self.put_variant_index(variant_index);
+        Ok(self)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// Implements a compound serializer trait whose elements are written one after the other.
+macro_rules! serialize_compound {
+    ($trait:ident, $method:ident($($key:ident),*)) => {
+        impl<M: Memory> ser::$trait for &mut Serializer<M> {
+            type Ok = ();
+            type Error = Error;
+
+            fn $method<T: Serialize + ?Sized>(&mut self, $($key: &'static str,)* value: &T) -> Result<()> {
+                value.serialize(&mut **self)
+            }
+
+            fn end(self) -> Result<()> {
+                Ok(())
+            }
+        }
+    };
+}
+
+serialize_compound!(SerializeSeq, serialize_element());
+serialize_compound!(SerializeTuple, serialize_element());
+serialize_compound!(SerializeTupleStruct, serialize_field());
+serialize_compound!(SerializeTupleVariant, serialize_field());
+// Fields are written in order, so their names are not needed.
+serialize_compound!(SerializeStruct, serialize_field(_key));
+serialize_compound!(SerializeStructVariant, serialize_field(_key));
+
+impl<M: Memory> ser::SerializeMap for &mut Serializer<M> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
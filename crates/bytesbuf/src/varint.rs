@@ -0,0 +1,338 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Variable-length integers in the LEB128 encoding used by Protocol Buffers.
+//!
+//! Each byte holds 7 bits of the value, least significant group first, with the high bit set
+//! on every byte but the last. Signed values are zigzag-encoded first, so that numbers of
+//! small magnitude take few bytes whether they are positive or negative.
+
+use std::io;
+
+use crate::{BytesBuf, BytesView};
+
+/// The maximum number of bytes of an encoded `u64`.
+pub(crate) const MAX_VARINT_LEN: usize = 10;
+
+impl BytesBuf {
+    /// Appends a `u32` to the buffer as a LEB128 variable-length integer.
+    ///
+    /// The value takes 1 to 5 bytes, fewer for smaller values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::mem::Memory;
+    ///
+    /// let mut buf = memory.reserve(8);
+    ///
+    /// buf.put_varint_u32(1);
+    /// buf.put_varint_u32(300);
+    ///
+    /// assert_eq!(buf.consume_all(), &[0x01, 0xAC, 0x02]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is insufficient remaining capacity in the buffer.
+    #[inline]
+    pub fn put_varint_u32(&mut self, value: u32) {
+        self.put_varint_u64(value.into());
+    }
+
+    /// Appends a `u64` to the buffer as a LEB128 variable-length integer.
+    ///
+    /// The value takes 1 to 10 bytes, fewer for smaller values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is insufficient remaining capacity in the buffer.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "each byte holds the lowest 7 bits of the remaining value"
+    )]
+    pub fn put_varint_u64(&mut self, mut value: u64) {
+        let mut bytes = [0_u8; MAX_VARINT_LEN];
+        let mut len = 0;
+
+        while value >= 0x80 {
+            bytes[len] = value as u8 | 0x80;
+            value >>= 7;
+            len += 1;
+        }
+
+        bytes[len] = value as u8;
+        self.put_slice(&bytes[..=len]);
+    }
+
+    /// Appends an `i32` to the buffer as a zigzag-encoded LEB128 variable-length integer.
+    ///
+    /// This is the encoding of the `sint32` type of Protocol Buffers. Values of small magnitude
+    /// take few bytes, whether they are positive or negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::mem::Memory;
+    ///
+    /// let mut buf = memory.reserve(8);
+    ///
+    /// buf.put_varint_i32(-1);
+    /// buf.put_varint_i32(1);
+    ///
+    /// assert_eq!(buf.consume_all(), &[0x01, 0x02]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is insufficient remaining capacity in the buffer.
+    #[inline]
+    pub fn put_varint_i32(&mut self, value: i32) {
+        self.put_varint_u32(((value << 1) ^ (value >> 31)).cast_unsigned());
+    }
+
+    /// Appends an `i64` to the buffer as a zigzag-encoded LEB128 variable-length integer.
+    ///
+    /// This is the encoding of the `sint64` type of Protocol Buffers. Values of small magnitude
+    /// take few bytes, whether they are positive or negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is insufficient remaining capacity in the buffer.
+    #[inline]
+    pub fn put_varint_i64(&mut self, value: i64) {
+        self.put_varint_u64(((value << 1) ^ (value >> 63)).cast_unsigned());
+    }
+}
+
+impl BytesView {
+    /// Consumes a LEB128 variable-length integer from the front of the view as a `u32`.
+    ///
+    /// The integer may span multiple spans of the view. If it cannot be read, nothing is
+    /// consumed, so a caller that receives [`UnexpectedEof`][io::ErrorKind::UnexpectedEof] can
+    /// retry once more data has arrived.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let mut view = BytesView::copied_from_slice(&[0xAC, 0x02, 0x80], &memory);
+    ///
+    /// assert_eq!(view.get_varint_u32()?, 300);
+    ///
+    /// // The rest of the next integer has not arrived yet.
+    /// let error = view.get_varint_u32().unwrap_err();
+    /// assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+    /// assert_eq!(view.len(), 1);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`UnexpectedEof`][io::ErrorKind::UnexpectedEof] if the view ends
+    /// before the last byte of the integer, or of kind [`InvalidData`][io::ErrorKind::InvalidData]
+    /// if the integer does not fit in a `u32`.
+    #[expect(clippy::cast_possible_truncation, reason = "the integer was checked to fit in 32 bits")]
+    pub fn get_varint_u32(&mut self) -> io::Result<u32> {
+        Ok(self.get_varint(u32::BITS)? as u32)
+    }
+
+    /// Consumes a LEB128 variable-length integer from the front of the view as a `u64`.
+    ///
+    /// The integer may span multiple spans of the view. If it cannot be read, nothing is
+    /// consumed, so a caller that receives [`UnexpectedEof`][io::ErrorKind::UnexpectedEof] can
+    /// retry once more data has arrived.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`UnexpectedEof`][io::ErrorKind::UnexpectedEof] if the view ends
+    /// before the last byte of the integer, or of kind [`InvalidData`][io::ErrorKind::InvalidData]
+    /// if the integer does not fit in a `u64`.
+    pub fn get_varint_u64(&mut self) -> io::Result<u64> {
+        self.get_varint(u64::BITS)
+    }
+
+    /// Consumes a zigzag-encoded LEB128 variable-length integer from the front of the view as
+    /// an `i32`.
+    ///
+    /// This is the encoding of the `sint32` type of Protocol Buffers. If the integer cannot be
+    /// read, nothing is consumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let mut view = BytesView::copied_from_slice(&[0x01, 0x02], &memory);
+    ///
+    /// assert_eq!(view.get_varint_i32()?, -1);
+    /// assert_eq!(view.get_varint_i32()?, 1);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`UnexpectedEof`][io::ErrorKind::UnexpectedEof] if the view ends
+    /// before the last byte of the integer, or of kind [`InvalidData`][io::ErrorKind::InvalidData]
+    /// if the integer does not fit in an `i32`.
+    pub fn get_varint_i32(&mut self) -> io::Result<i32> {
+        let value = self.get_varint_u32()?;
+        Ok((value >> 1).cast_signed() ^ -(value & 1).cast_signed())
+    }
+
+    /// Consumes a zigzag-encoded LEB128 variable-length integer from the front of the view as
+    /// an `i64`.
+    ///
+    /// This is the encoding of the `sint64` type of Protocol Buffers. If the integer cannot be
+    /// read, nothing is consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`UnexpectedEof`][io::ErrorKind::UnexpectedEof] if the view ends
+    /// before the last byte of the integer, or of kind [`InvalidData`][io::ErrorKind::InvalidData]
+    /// if the integer does not fit in an `i64`.
+    pub fn get_varint_i64(&mut self) -> io::Result<i64> {
+        let value = self.get_varint_u64()?;
+        Ok((value >> 1).cast_signed() ^ -(value & 1).cast_signed())
+    }
+
+    /// Consumes a LEB128 variable-length integer of at most `bits` significant bits.
+    fn get_varint(&mut self, bits: u32) -> io::Result<u64> {
+        let (value, len) = self.peek_varint(bits)?;
+        self.advance(len);
+        Ok(value)
+    }
+
+    /// Reads a LEB128 variable-length integer of at most `bits` significant bits, returning it
+    /// together with its encoded length, without consuming it.
+    fn peek_varint(&self, bits: u32) -> io::Result<(u64, usize)> {
+        let mut value = 0_u64;
+        let mut shift = 0;
+
+        for (index, byte) in self.slices().flat_map(|(slice, _meta)| slice.iter().copied()).enumerate() {
+            let group = u64::from(byte & 0x7F);
+
+            // Rejects both groups past the end of the integer and bits that overflow it.
+            if shift >= bits || group.checked_shr(bits - shift).is_some_and(|excess| excess != 0) {
+                return Err(io::ErrorKind::InvalidData.into());
+            }
+
+            value |= group << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok((value, index + 1));
+            }
+
+            shift += 7;
+        }
+
+        Err(io::ErrorKind::UnexpectedEof.into())
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mem::testing::TransparentMemory;
+
+    #[test]
+    fn round_trip() {
+        let memory = TransparentMemory::new();
+        let mut buf = memory.reserve(128);
+
+        let unsigned = [0, 1, 127, 128, 300, u64::from(u32::MAX), u64::MAX];
+        let signed = [0, -1, 1, -64, 64, i64::from(i32::MIN), i64::MIN, i64::MAX];
+
+        buf.put_varint_u32(u32::MAX);
+        buf.put_varint_i32(i32::MIN);
+        buf.put_varint_i32(i32::MAX);
+        for value in unsigned {
+            buf.put_varint_u64(value);
+        }
+        for value in signed {
+            buf.put_varint_i64(value);
+        }
+
+        let mut view = buf.consume_all();
+
+        assert_eq!(view.get_varint_u32().unwrap(), u32::MAX);
+        assert_eq!(view.get_varint_i32().unwrap(), i32::MIN);
+        assert_eq!(view.get_varint_i32().unwrap(), i32::MAX);
+        for value in unsigned {
+            assert_eq!(view.get_varint_u64().unwrap(), value);
+        }
+        for value in signed {
+            assert_eq!(view.get_varint_i64().unwrap(), value);
+        }
+        assert!(view.is_empty());
+    }
+
+    #[test]
+    fn encoded_lengths() {
+        let memory = TransparentMemory::new();
+
+        for (value, len) in [(0, 1), (127, 1), (128, 2), (16_383, 2), (16_384, 3), (u64::MAX, 10)] {
+            let mut buf = memory.reserve(10);
+            buf.put_varint_u64(value);
+            assert_eq!(buf.len(), len, "{value}");
+        }
+
+        let mut buf = memory.reserve(10);
+        buf.put_varint_i64(i64::MIN);
+        assert_eq!(buf.consume_all(), &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]);
+    }
+
+    #[test]
+    fn get_multi_span() {
+        let memory = TransparentMemory::new();
+        let view_part1 = BytesView::copied_from_slice(&[0xE5], &memory);
+        let view_part2 = BytesView::copied_from_slice(&[0x8E], &memory);
+        let view_part3 = BytesView::copied_from_slice(&[0x26, 0x07], &memory);
+        let mut view = BytesView::from_views([view_part1, view_part2, view_part3]);
+
+        assert_eq!(view.get_varint_u32().unwrap(), 624_485);
+        assert_eq!(view, &[0x07]);
+    }
+
+    #[test]
+    fn get_incomplete_does_not_consume() {
+        let memory = TransparentMemory::new();
+        let mut view = BytesView::copied_from_slice(&[0xFF, 0xFF], &memory);
+
+        let error = view.get_varint_u64().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(view.len(), 2);
+
+        let error = BytesView::new().get_varint_i64().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn get_overflow_is_invalid() {
+        let memory = TransparentMemory::new();
+
+        // u32::MAX + 1 takes a fifth group with a bit past the 32 bits.
+        let mut view = BytesView::copied_from_slice(&[0x80, 0x80, 0x80, 0x80, 0x10], &memory);
+        let error = view.get_varint_u32().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(view.len(), 5);
+        assert_eq!(view.get_varint_u64().unwrap(), 1 << 32);
+
+        // An eleventh byte is too many for a u64.
+        let mut view = BytesView::copied_from_slice(&[0x80; 11], &memory);
+        let error = view.get_varint_u64().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        // The tenth byte can only hold the highest bit of a u64.
+        let overflowing = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02];
+        let mut view = BytesView::copied_from_slice(&overflowing, &memory);
+        let error = view.get_varint_u64().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
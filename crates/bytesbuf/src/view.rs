@@ -2,6 +2,7 @@
 // Licensed under the MIT License.
 
 use std::hash::{Hash, Hasher};
+use std::io::IoSlice;
 use std::iter;
 use std::num::NonZero;
 use std::ops::{Bound, RangeBounds};
@@ -589,6 +590,87 @@ impl BytesView {
         BytesViewSlices::new(self)
     }
 
+    /// Fills `dst` with the slices that make up the front of this view, for vectored I/O.
+    ///
+    /// Returns the number of slices written to the front of `dst`, which is the smaller of the
+    /// number of slices in the view and the length of `dst`. If `dst` is too short to hold all
+    /// the slices, the remaining slices can be written after [advancing][Self::advance] the view
+    /// past the bytes that were consumed.
+    ///
+    /// The slices can be passed to vectored writes, such as [`Write::write_vectored()`], which
+    /// write the non-consecutive parts of the byte sequence without copying them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use std::io::{IoSlice, Write};
+    ///
+    /// use bytesbuf::BytesView;
+    ///
+    /// # let part1 = BytesView::copied_from_slice(b"Hello, ", &memory);
+    /// # let part2 = BytesView::copied_from_slice(b"world!", &memory);
+    /// let mut view = BytesView::from_views([part1, part2]);
+    /// let mut output = Vec::new();
+    ///
+    /// while !view.is_empty() {
+    ///     let mut io_slices = [IoSlice::new(&[]); 8];
+    ///     let count = view.as_io_slices(&mut io_slices);
+    ///
+    ///     let written = output.write_vectored(&io_slices[..count])?;
+    ///     view.advance(written);
+    /// }
+    ///
+    /// assert_eq!(output, b"Hello, world!");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// [`Write::write_vectored()`]: std::io::Write::write_vectored
+    pub fn as_io_slices<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        let mut count = 0;
+
+        for (slot, slice) in dst.iter_mut().zip(self.io_slices()) {
+            *slot = slice;
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Iterates over the slices that make up this view as [`IoSlice`]s, for vectored I/O.
+    ///
+    /// This is a convenience for collecting the slices of the whole view into a collection
+    /// that can be passed to vectored writes, such as [`Write::write_vectored()`]. To fill an
+    /// existing array instead, use [`as_io_slices()`][Self::as_io_slices].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use std::io::{IoSlice, Write};
+    ///
+    /// use bytesbuf::BytesView;
+    ///
+    /// # let part1 = BytesView::copied_from_slice(b"Hello, ", &memory);
+    /// # let part2 = BytesView::copied_from_slice(b"world!", &memory);
+    /// let view = BytesView::from_views([part1, part2]);
+    ///
+    /// let io_slices: Vec<IoSlice<'_>> = view.io_slices().collect();
+    /// assert_eq!(io_slices.len(), 2);
+    ///
+    /// let mut output = Vec::new();
+    /// let written = output.write_vectored(&io_slices)?;
+    ///
+    /// assert_eq!(written, 13);
+    /// assert_eq!(output, b"Hello, world!");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// [`Write::write_vectored()`]: std::io::Write::write_vectored
+    pub fn io_slices(&self) -> BytesViewIoSlices<'_> {
+        BytesViewIoSlices { inner: self.slices() }
+    }
+
     /// Copies the byte sequence into a new [`Vec<u8>`].
     ///
     /// The view itself is left unchanged.
@@ -775,6 +857,72 @@ impl BytesView {
         new_view.append(other);
         new_view
     }
+
+    /// Splits the view in two at `at`, returning the first `at` bytes and keeping the rest.
+    ///
+    /// This is a zero-copy operation: both views share the memory capacity of the original
+    /// view. It is useful for framing, where a frame is detached from the front of a view
+    /// that may already contain the start of the next frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let mut view = BytesView::copied_from_slice(b"\x05Hello\x06world!", &memory);
+    ///
+    /// let mut frames = Vec::new();
+    /// while !view.is_empty() {
+    ///     let frame_len = view.get_byte() as usize;
+    ///     frames.push(view.split_to(frame_len));
+    /// }
+    ///
+    /// assert_eq!(frames, [b"Hello".as_slice(), b"world!".as_slice()]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the number of bytes in the view.
+    #[must_use = "use `advance()` to drop bytes from the front of the view"]
+    pub fn split_to(&mut self, at: usize) -> Self {
+        assert!(at <= self.len(), "attempted to split past end of the view");
+
+        let front = self.range(..at);
+        self.advance(at);
+        front
+    }
+
+    /// Splits the view in two at `at`, keeping the first `at` bytes and returning the rest.
+    ///
+    /// This is a zero-copy operation: both views share the memory capacity of the original
+    /// view.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let mut view = BytesView::copied_from_slice(b"Hello, world!", &memory);
+    ///
+    /// let rest = view.split_off(5);
+    ///
+    /// assert_eq!(view, b"Hello");
+    /// assert_eq!(rest, b", world!");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the number of bytes in the view.
+    #[must_use = "use `range()` to keep only the front of the view"]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len(), "attempted to split past end of the view");
+
+        let back = self.range(at..);
+        *self = self.range(..at);
+        back
+    }
 }
 
 impl Default for BytesView {
@@ -1035,6 +1183,30 @@ impl<'s> Iterator for BytesViewSlices<'s> {
     }
 }
 
+/// Iterator over the slices of a [`BytesView`] as [`IoSlice`]s.
+///
+/// Returned by [`BytesView::io_slices()`].
+#[must_use]
+#[derive(Debug)]
+pub struct BytesViewIoSlices<'s> {
+    inner: BytesViewSlices<'s>,
+}
+
+impl<'s> Iterator for BytesViewIoSlices<'s> {
+    type Item = IoSlice<'s>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(slice, _meta)| IoSlice::new(slice))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every span that has not been returned yet is returned as a slice.
+        (self.inner.previous_span_index, Some(self.inner.previous_span_index))
+    }
+}
+
+impl ExactSizeIterator for BytesViewIoSlices<'_> {}
+
 const SPAN_COUNT_BUCKETS: &[Magnitude] = &[0, 1, 2, 4, 8, 16, 32];
 
 thread_local! {
@@ -1498,6 +1670,109 @@ mod tests {
         assert_eq!(view.slices().count(), 0);
     }
 
+    #[test]
+    fn as_io_slices_fills_front_of_dst() {
+        let memory = TransparentMemory::new();
+        let segment1 = BytesView::copied_from_slice(b"Hello, ", &memory);
+        let segment2 = BytesView::copied_from_slice(b"world", &memory);
+        let segment3 = BytesView::copied_from_slice(b"!", &memory);
+
+        let mut view = BytesView::from_views(vec![segment1, segment2, segment3]);
+
+        let mut io_slices = [IoSlice::new(&[]); 4];
+        assert_eq!(view.as_io_slices(&mut io_slices), 3);
+        assert_eq!(&*io_slices[0], b"Hello, ");
+        assert_eq!(&*io_slices[1], b"world");
+        assert_eq!(&*io_slices[2], b"!");
+        assert!(io_slices[3].is_empty());
+
+        let mut io_slices = [IoSlice::new(&[]); 2];
+        assert_eq!(view.as_io_slices(&mut io_slices), 2);
+        assert_eq!(&*io_slices[1], b"world");
+
+        assert_eq!(view.as_io_slices(&mut []), 0);
+
+        view.advance(9);
+        let mut io_slices = [IoSlice::new(&[]); 4];
+        assert_eq!(view.as_io_slices(&mut io_slices), 2);
+        assert_eq!(&*io_slices[0], b"rld");
+    }
+
+    #[test]
+    fn io_slices_iterator() {
+        let memory = TransparentMemory::new();
+        let segment1 = BytesView::copied_from_slice(b"Hello, ", &memory);
+        let segment2 = BytesView::copied_from_slice(b"world!", &memory);
+
+        let view = BytesView::from_views(vec![segment1, segment2]);
+
+        let mut io_slices = view.io_slices();
+        assert_eq!(io_slices.len(), 2);
+        assert_eq!(io_slices.next().as_deref(), Some(b"Hello, ".as_slice()));
+        assert_eq!(io_slices.len(), 1);
+        assert_eq!(io_slices.next().as_deref(), Some(b"world!".as_slice()));
+        assert_eq!(io_slices.len(), 0);
+        assert!(io_slices.next().is_none());
+
+        assert_eq!(BytesView::new().io_slices().count(), 0);
+    }
+
+    #[test]
+    fn split_to_across_spans() {
+        let memory = TransparentMemory::new();
+        let segment1 = BytesView::copied_from_slice(b"Hello, ", &memory);
+        let segment2 = BytesView::copied_from_slice(b"world!", &memory);
+
+        let mut view = BytesView::from_views(vec![segment1.clone(), segment2]);
+
+        let front = view.split_to(9);
+        assert_eq!(front, b"Hello, wo");
+        assert_eq!(view, b"rld!");
+
+        // Both views share the memory of the original one.
+        assert_eq!(front.first_slice().as_ptr(), segment1.first_slice().as_ptr());
+
+        assert!(view.split_to(0).is_empty());
+        assert_eq!(view.split_to(4), b"rld!");
+        assert!(view.is_empty());
+    }
+
+    #[test]
+    fn split_off_across_spans() {
+        let memory = TransparentMemory::new();
+        let segment1 = BytesView::copied_from_slice(b"Hello, ", &memory);
+        let segment2 = BytesView::copied_from_slice(b"world!", &memory);
+
+        let mut view = BytesView::from_views(vec![segment1, segment2]);
+
+        let back = view.split_off(3);
+        assert_eq!(view, b"Hel");
+        assert_eq!(back, b"lo, world!");
+        assert_eq!(back.len(), 10);
+
+        assert!(view.split_off(3).is_empty());
+        assert_eq!(view.split_off(0), b"Hel");
+        assert!(view.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to split past end of the view")]
+    fn split_to_past_end_panics() {
+        let memory = TransparentMemory::new();
+        let mut view = BytesView::copied_from_slice(b"Hello", &memory);
+
+        let _front = view.split_to(6);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to split past end of the view")]
+    fn split_off_past_end_panics() {
+        let memory = TransparentMemory::new();
+        let mut view = BytesView::copied_from_slice(b"Hello", &memory);
+
+        let _back = view.split_off(6);
+    }
+
     #[test]
     fn to_vec_single_span() {
         let memory = TransparentMemory::new();
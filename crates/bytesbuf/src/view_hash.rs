@@ -0,0 +1,219 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! We separate out the methods that hash and checksum the contents of the view.
+//!
+//! All of them walk the spans of the view in place, so checking the integrity of a large
+//! multi-span message does not require gathering it into contiguous memory first.
+
+use std::hash::Hasher;
+
+use crate::BytesView;
+
+impl BytesView {
+    /// Feeds the bytes of the view to `state`, one span at a time and without copying.
+    ///
+    /// This is meant for streaming hash functions and digests, for which writing the bytes in
+    /// pieces gives the same result as writing them at once, such as
+    /// [`DefaultHasher`][std::hash::DefaultHasher] or the xxh3 hasher of the `xxhash-rust`
+    /// crate. The result then only depends on the bytes, so it matches the hash of the same
+    /// bytes in a slice. Calling this on several views hashes their concatenation.
+    ///
+    /// Unlike the [`Hash`][std::hash::Hash] implementation of [`BytesView`], no length prefix is
+    /// written and the spans are passed to the hasher as they are. With hashers for which split
+    /// writes differ from a single write, views with equal contents but different spans may hash
+    /// differently, so use [`Hash`][std::hash::Hash] for hash map keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use std::hash::{DefaultHasher, Hasher};
+    ///
+    /// use bytesbuf::BytesView;
+    ///
+    /// let header = BytesView::copied_from_slice(b"Hello, ", &memory);
+    /// let body = BytesView::copied_from_slice(b"world!", &memory);
+    ///
+    /// let mut hasher = DefaultHasher::new();
+    /// header.hash_into(&mut hasher);
+    /// body.hash_into(&mut hasher);
+    ///
+    /// let mut expected = DefaultHasher::new();
+    /// expected.write(b"Hello, world!");
+    /// assert_eq!(hasher.finish(), expected.finish());
+    /// ```
+    pub fn hash_into<H: Hasher>(&self, state: &mut H) {
+        for (slice, _meta) in self.slices() {
+            state.write(slice);
+        }
+    }
+
+    /// Calculates the CRC-32 checksum of the view, as used by Ethernet, gzip and zip.
+    ///
+    /// This is available when the `crc` Cargo feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "crc")]
+    /// # {
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let view = BytesView::copied_from_slice(b"123456789", &memory);
+    ///
+    /// assert_eq!(view.crc32(), 0xCBF4_3926);
+    /// # }
+    /// ```
+    #[cfg(any(test, feature = "crc"))]
+    #[must_use]
+    pub fn crc32(&self) -> u32 {
+        self.crc(&crate::crc::CRC32)
+    }
+
+    /// Calculates the CRC-32C (Castagnoli) checksum of the view, as used by many network
+    /// protocols and storage formats.
+    ///
+    /// This is available when the `crc` Cargo feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "crc")]
+    /// # {
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let view = BytesView::copied_from_slice(b"123456789", &memory);
+    ///
+    /// assert_eq!(view.crc32c(), 0xE306_9283);
+    /// # }
+    /// ```
+    #[cfg(any(test, feature = "crc"))]
+    #[must_use]
+    pub fn crc32c(&self) -> u32 {
+        self.crc(&crate::crc::CRC32C)
+    }
+
+    /// Calculates the 64-bit xxh3 hash of the view.
+    ///
+    /// The result is the same as that of `xxhash_rust::xxh3::xxh3_64()` for the same bytes in a
+    /// slice. To hash several views together, pass an `xxhash_rust::xxh3::Xxh3` to
+    /// [`hash_into()`][Self::hash_into].
+    ///
+    /// This is available when the `xxh3` Cargo feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "xxh3")]
+    /// # {
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let a = BytesView::copied_from_slice(b"Hello, world!", &memory);
+    /// let b = BytesView::copied_from_slice(b"Hello, world?", &memory);
+    ///
+    /// assert_ne!(a.xxh3_64(), b.xxh3_64());
+    /// # }
+    /// ```
+    #[cfg(any(test, feature = "xxh3"))]
+    #[must_use]
+    pub fn xxh3_64(&self) -> u64 {
+        self.xxh3().digest()
+    }
+
+    /// Calculates the 128-bit xxh3 hash of the view.
+    ///
+    /// The result is the same as that of `xxhash_rust::xxh3::xxh3_128()` for the same bytes in
+    /// a slice.
+    ///
+    /// This is available when the `xxh3` Cargo feature is enabled.
+    #[cfg(any(test, feature = "xxh3"))]
+    #[must_use]
+    pub fn xxh3_128(&self) -> u128 {
+        self.xxh3().digest128()
+    }
+
+    #[cfg(any(test, feature = "crc"))]
+    fn crc(&self, tables: &crate::crc::CrcTables) -> u32 {
+        let state = self
+            .slices()
+            .fold(!0, |state, (slice, _meta)| crate::crc::update(tables, state, slice));
+        !state
+    }
+
+    #[cfg(any(test, feature = "xxh3"))]
+    fn xxh3(&self) -> xxhash_rust::xxh3::Xxh3 {
+        let mut hasher = xxhash_rust::xxh3::Xxh3::new();
+
+        for (slice, _meta) in self.slices() {
+            hasher.update(slice);
+        }
+
+        hasher
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use std::hash::DefaultHasher;
+
+    use super::*;
+    use crate::mem::testing::TransparentMemory;
+
+    const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+    /// [`DATA`] split into spans of uneven length, including spans shorter than 8 bytes.
+    fn multi_span_view() -> BytesView {
+        let memory = TransparentMemory::new();
+        BytesView::from_views(
+            [&DATA[..3], &DATA[3..20], &DATA[20..21], &DATA[21..]]
+                .into_iter()
+                .map(|part| BytesView::copied_from_slice(part, &memory)),
+        )
+    }
+
+    #[test]
+    fn hash_into_matches_contiguous_bytes() {
+        let mut hasher = DefaultHasher::new();
+        multi_span_view().hash_into(&mut hasher);
+
+        let mut expected = DefaultHasher::new();
+        expected.write(DATA);
+
+        assert_eq!(hasher.finish(), expected.finish());
+    }
+
+    #[test]
+    fn hash_into_empty_writes_nothing() {
+        let mut hasher = DefaultHasher::new();
+        BytesView::new().hash_into(&mut hasher);
+
+        assert_eq!(hasher.finish(), DefaultHasher::new().finish());
+    }
+
+    #[test]
+    fn crc_multi_span() {
+        let view = multi_span_view();
+
+        assert_eq!(view.crc32(), 0x414F_A339);
+        assert_eq!(view.crc32c(), 0x2262_0404);
+        assert_eq!(BytesView::new().crc32(), 0);
+        assert_eq!(BytesView::new().crc32c(), 0);
+    }
+
+    #[test]
+    fn xxh3_multi_span() {
+        let view = multi_span_view();
+
+        assert_eq!(view.xxh3_64(), xxhash_rust::xxh3::xxh3_64(DATA));
+        assert_eq!(view.xxh3_128(), xxhash_rust::xxh3::xxh3_128(DATA));
+
+        let mut hasher = xxhash_rust::xxh3::Xxh3::new();
+        view.hash_into(&mut hasher);
+        assert_eq!(hasher.finish(), xxhash_rust::xxh3::xxh3_64(DATA));
+    }
+}
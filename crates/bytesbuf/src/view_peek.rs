@@ -0,0 +1,267 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! We separate out the methods that read from the front of the view without consuming it.
+
+use crate::BytesView;
+
+/// Generates the little-, big-, and native-endian peek accessors for a primitive numeric type.
+///
+/// The generated methods delegate to [`BytesView::peek_array`] for the shared span traversal and
+/// only select the byte order via the primitive's inherent `from_*_bytes` associated function.
+macro_rules! peek_num_accessors {
+    ($t:ty, $le:ident, $be:ident, $ne:ident) => {
+        #[doc = concat!("Reads a `", stringify!($t), "` from the front of the view in little-endian byte order,")]
+        /// without consuming it.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the view does not cover enough bytes of data.
+        #[inline]
+        #[must_use]
+        pub fn $le(&self) -> $t {
+            <$t>::from_le_bytes(self.peek_array())
+        }
+
+        #[doc = concat!("Reads a `", stringify!($t), "` from the front of the view in big-endian byte order,")]
+        /// without consuming it.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the view does not cover enough bytes of data.
+        #[inline]
+        #[must_use]
+        pub fn $be(&self) -> $t {
+            <$t>::from_be_bytes(self.peek_array())
+        }
+
+        #[doc = concat!("Reads a `", stringify!($t), "` from the front of the view in native-endian byte order,")]
+        /// without consuming it.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the view does not cover enough bytes of data.
+        #[inline]
+        #[must_use]
+        pub fn $ne(&self) -> $t {
+            <$t>::from_ne_bytes(self.peek_array())
+        }
+    };
+}
+
+impl BytesView {
+    /// Reads the first byte of the view without consuming it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let mut view = BytesView::copied_from_slice(b"ABC", &memory);
+    ///
+    /// assert_eq!(view.peek_byte(), b'A');
+    /// assert_eq!(view.get_byte(), b'A');
+    /// assert_eq!(view.peek_byte(), b'B');
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the view does not cover enough bytes of data.
+    #[inline]
+    #[must_use]
+    pub fn peek_byte(&self) -> u8 {
+        *self.first_slice().first().expect("view must cover at least one byte")
+    }
+
+    /// Copies bytes from the front of the view into an initialized slice, without consuming them.
+    ///
+    /// This lets a parser inspect a header, such as a length prefix, and only consume it once
+    /// enough data has arrived for the whole message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let view = BytesView::copied_from_slice(b"Hello, world!", &memory);
+    ///
+    /// let mut buffer = [0u8; 5];
+    /// view.peek_to_slice(&mut buffer);
+    ///
+    /// assert_eq!(&buffer, b"Hello");
+    /// assert_eq!(view.len(), 13); // Nothing was consumed.
+    /// ```
+    ///
+    /// To create a view over part of the byte sequence without copying it, use
+    /// [`range()`][Self::range] instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the destination is larger than the view.
+    pub fn peek_to_slice(&self, mut dst: &mut [u8]) {
+        assert!(self.len() >= dst.len());
+
+        for (src, _meta) in self.slices() {
+            if dst.is_empty() {
+                break;
+            }
+
+            let bytes_to_copy = dst.len().min(src.len());
+            dst[..bytes_to_copy].copy_from_slice(&src[..bytes_to_copy]);
+            dst = &mut dst[bytes_to_copy..];
+        }
+    }
+
+    /// Copies exactly `N` bytes from the front of the view into an array, without consuming them.
+    ///
+    /// The numeric peek accessors delegate here, as the numeric get accessors delegate to
+    /// `get_array`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the view does not cover at least `N` bytes.
+    #[inline]
+    fn peek_array<const N: usize>(&self) -> [u8; N] {
+        let mut array = [0_u8; N];
+
+        match self.first_slice().get(..N) {
+            Some(src) => array.copy_from_slice(src),
+            // The bytes straddle a span boundary (or are not there at all), so we gather them.
+            None => self.peek_to_slice(&mut array),
+        }
+
+        array
+    }
+
+    peek_num_accessors!(u16, peek_u16_le, peek_u16_be, peek_u16_ne);
+    peek_num_accessors!(i16, peek_i16_le, peek_i16_be, peek_i16_ne);
+    peek_num_accessors!(u32, peek_u32_le, peek_u32_be, peek_u32_ne);
+    peek_num_accessors!(i32, peek_i32_le, peek_i32_be, peek_i32_ne);
+    peek_num_accessors!(u64, peek_u64_le, peek_u64_be, peek_u64_ne);
+    peek_num_accessors!(i64, peek_i64_le, peek_i64_be, peek_i64_ne);
+    peek_num_accessors!(u128, peek_u128_le, peek_u128_be, peek_u128_ne);
+    peek_num_accessors!(i128, peek_i128_le, peek_i128_be, peek_i128_ne);
+    peek_num_accessors!(f32, peek_f32_le, peek_f32_be, peek_f32_ne);
+    peek_num_accessors!(f64, peek_f64_le, peek_f64_be, peek_f64_ne);
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mem::testing::TransparentMemory;
+
+    #[test]
+    fn peek_byte() {
+        let memory = TransparentMemory::new();
+        let view_part1 = BytesView::copied_from_slice(&[1], &memory);
+        let view_part2 = BytesView::copied_from_slice(&[2, 3], &memory);
+        let mut view = BytesView::from_views([view_part1, view_part2]);
+
+        assert_eq!(view.peek_byte(), 1);
+        assert_eq!(view.peek_byte(), 1);
+
+        view.advance(1);
+        assert_eq!(view.peek_byte(), 2);
+        assert_eq!(view.len(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn peek_byte_empty_panics() {
+        let _ = BytesView::new().peek_byte();
+    }
+
+    #[test]
+    fn peek_to_slice_multi_span() {
+        let memory = TransparentMemory::new();
+        let view_part1 = BytesView::copied_from_slice(&[10_u8, 20], &memory);
+        let view_part2 = BytesView::copied_from_slice(&[30_u8, 40, 50], &memory);
+        let view = BytesView::from_views([view_part1, view_part2]);
+
+        let mut dst = [0u8; 3];
+        view.peek_to_slice(&mut dst);
+        assert_eq!(dst, [10, 20, 30]);
+
+        let mut dst = [0u8; 5];
+        view.peek_to_slice(&mut dst);
+        assert_eq!(dst, [10, 20, 30, 40, 50]);
+
+        view.peek_to_slice(&mut []);
+        assert_eq!(view.len(), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn peek_to_bigger_slice_panics() {
+        let memory = TransparentMemory::new();
+        let view = BytesView::copied_from_slice(&[1, 2, 3, 4], &memory);
+
+        let mut dst = [0u8; 8];
+        view.peek_to_slice(&mut dst);
+    }
+
+    #[test]
+    fn peek_num_does_not_consume() {
+        let memory = TransparentMemory::new();
+        let mut view = BytesView::copied_from_slice(&[0x12, 0x34, 0x56, 0x78], &memory);
+
+        assert_eq!(view.peek_u16_le(), 0x3412);
+        assert_eq!(view.peek_u16_be(), 0x1234);
+        assert_eq!(view.peek_u32_be(), 0x1234_5678);
+        if cfg!(target_endian = "big") {
+            assert_eq!(view.peek_u16_ne(), 0x1234);
+        } else {
+            assert_eq!(view.peek_u16_ne(), 0x3412);
+        }
+        assert_eq!(view.len(), 4);
+
+        assert_eq!(view.peek_u32_be(), view.get_u32_be());
+        assert!(view.is_empty());
+    }
+
+    #[test]
+    fn peek_wide_value_multi_span() {
+        let memory = TransparentMemory::new();
+        let value: u128 = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210;
+        let bytes = value.to_le_bytes();
+
+        // Split at an odd offset so the value straddles the span boundary and exercises buffering.
+        let view_part1 = BytesView::copied_from_slice(&bytes[..5], &memory);
+        let view_part2 = BytesView::copied_from_slice(&bytes[5..], &memory);
+        let view = BytesView::from_views([view_part1, view_part2]);
+
+        assert_eq!(view.peek_u128_le(), value);
+        assert_eq!(view.peek_i128_le(), value.cast_signed());
+        assert_eq!(view.len(), 16);
+    }
+
+    #[test]
+    fn peek_signed_and_float() {
+        let memory = TransparentMemory::new();
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&std::f64::consts::E.to_be_bytes());
+        let view = BytesView::copied_from_slice(&data, &memory);
+
+        assert_eq!(view.peek_f64_be().to_bits(), std::f64::consts::E.to_bits());
+        assert_eq!(view.peek_i16_be(), i16::from_be_bytes([data[0], data[1]]));
+        assert_eq!(
+            view.peek_f32_le().to_bits(),
+            f32::from_le_bytes([data[0], data[1], data[2], data[3]]).to_bits()
+        );
+        assert_eq!(view.len(), 8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn peek_num_insufficient_bytes_panics() {
+        let memory = TransparentMemory::new();
+        let view = BytesView::copied_from_slice(&[0x00, 0x01, 0x02], &memory);
+
+        // Only three bytes are available, so peeking at a four-byte value must panic.
+        let _ = view.peek_u32_le();
+    }
+}
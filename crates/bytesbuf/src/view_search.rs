@@ -0,0 +1,308 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! We separate out the methods that search the view for delimiters and split it at them.
+
+use std::iter::FusedIterator;
+use std::mem;
+
+use crate::BytesView;
+
+impl BytesView {
+    /// Returns the position of the first occurrence of `byte` in the view.
+    ///
+    /// The search covers all the spans of the view, so it also finds bytes beyond the
+    /// [`first_slice()`][Self::first_slice].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let view = BytesView::copied_from_slice(b"key=value", &memory);
+    ///
+    /// assert_eq!(view.find_byte(b'='), Some(3));
+    /// assert_eq!(view.find_byte(b'&'), None);
+    /// ```
+    #[must_use]
+    pub fn find_byte(&self, byte: u8) -> Option<usize> {
+        self.find_byte_from(0, byte)
+    }
+
+    /// Returns the position of the first occurrence of `needle` in the view.
+    ///
+    /// The needle is also found when it straddles the boundary between spans. An empty needle
+    /// is found at the start of the view.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let view = BytesView::copied_from_slice(b"Host: example.com\r\n\r\nbody", &memory);
+    ///
+    /// // The end of the headers of an HTTP/1.1 message.
+    /// assert_eq!(view.find_slice(b"\r\n\r\n"), Some(17));
+    /// assert_eq!(view.find_slice(b"\n\n"), None);
+    /// ```
+    #[must_use]
+    pub fn find_slice(&self, needle: &[u8]) -> Option<usize> {
+        let Some(&first) = needle.first() else {
+            return Some(0);
+        };
+
+        let mut start = 0;
+        while let Some(position) = self.find_byte_from(start, first) {
+            if self.len() - position < needle.len() {
+                return None;
+            }
+
+            if self.matches_at(position, needle) {
+                return Some(position);
+            }
+
+            start = position + 1;
+        }
+
+        None
+    }
+
+    /// Splits off the bytes up to and including the first occurrence of `delimiter`.
+    ///
+    /// Returns `None` and leaves the view unchanged if the view does not contain the
+    /// delimiter, for example because the rest of a message has not arrived yet. This is a
+    /// zero-copy operation, as [`split_to()`][Self::split_to] is.
+    ///
+    /// Unlike [`BufRead::read_until()`][std::io::BufRead::read_until], which copies into a
+    /// vector and also returns data without a delimiter at the end of the input, this only
+    /// returns complete delimited sequences.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let mut view = BytesView::copied_from_slice(b"{\"id\":1}\n{\"id\":2}\n{\"id\"", &memory);
+    ///
+    /// let mut records = Vec::new();
+    /// while let Some(record) = view.split_until(b'\n') {
+    ///     records.push(record);
+    /// }
+    ///
+    /// assert_eq!(
+    ///     records,
+    ///     [b"{\"id\":1}\n".as_slice(), b"{\"id\":2}\n".as_slice()]
+    /// );
+    /// assert_eq!(view, b"{\"id\"".as_slice());
+    /// ```
+    #[must_use = "the delimited bytes are removed from the view"]
+    pub fn split_until(&mut self, delimiter: u8) -> Option<Self> {
+        let position = self.find_byte(delimiter)?;
+        Some(self.split_to(position + 1))
+    }
+
+    /// Returns an iterator over the lines of the view, as views over the same memory.
+    ///
+    /// Lines are terminated by `\n` or `\r\n`, which are not part of the returned views. The
+    /// last line does not need to be terminated, and an empty view has no lines, as with
+    /// [`str::lines()`]. Lines may span multiple spans of the view.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let view =
+    ///     BytesView::copied_from_slice(b"GET / HTTP/1.1\r\nHost: example.com\r\n\r\n", &memory);
+    ///
+    /// let lines: Vec<BytesView> = view.split_lines().collect();
+    ///
+    /// assert_eq!(
+    ///     lines,
+    ///     [
+    ///         b"GET / HTTP/1.1".as_slice(),
+    ///         b"Host: example.com".as_slice(),
+    ///         b"".as_slice()
+    ///     ]
+    /// );
+    /// ```
+    pub fn split_lines(&self) -> BytesViewLines {
+        BytesViewLines { remaining: self.clone() }
+    }
+
+    /// Returns the position of the first occurrence of `byte` at or after `start`.
+    fn find_byte_from(&self, start: usize, byte: u8) -> Option<usize> {
+        let mut offset = 0;
+
+        for (slice, _meta) in self.slices() {
+            let slice_end = offset + slice.len();
+
+            if slice_end > start {
+                let skip = start.saturating_sub(offset);
+
+                if let Some(position) = slice[skip..].iter().position(|&b| b == byte) {
+                    return Some(offset + skip + position);
+                }
+            }
+
+            offset = slice_end;
+        }
+
+        None
+    }
+
+    /// Returns whether the view contains `needle` at `start`.
+    ///
+    /// The caller must ensure that the view covers at least `start + needle.len()` bytes.
+    fn matches_at(&self, start: usize, mut needle: &[u8]) -> bool {
+        let mut offset = 0;
+
+        for (slice, _meta) in self.slices() {
+            if needle.is_empty() {
+                break;
+            }
+
+            let slice_end = offset + slice.len();
+
+            if slice_end > start {
+                // Only the first matching slice starts after the beginning, the rest are compared from their start.
+                let available = &slice[start.saturating_sub(offset)..];
+                let compare_len = available.len().min(needle.len());
+
+                if available[..compare_len] != needle[..compare_len] {
+                    return false;
+                }
+
+                needle = &needle[compare_len..];
+            }
+
+            offset = slice_end;
+        }
+
+        needle.is_empty()
+    }
+}
+
+/// Iterator over the lines of a [`BytesView`].
+///
+/// Returned by [`BytesView::split_lines()`]. Each line is a view over the memory of the
+/// original view, without its line terminator.
+#[must_use]
+#[derive(Debug)]
+pub struct BytesViewLines {
+    remaining: BytesView,
+}
+
+impl Iterator for BytesViewLines {
+    type Item = BytesView;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let Some(position) = self.remaining.find_byte(b'\n') else {
+            // The last line is not terminated, so it is all that is left.
+            return Some(mem::take(&mut self.remaining));
+        };
+
+        let mut line = self.remaining.split_to(position);
+        self.remaining.advance(1);
+
+        if line.slices().last().and_then(|(slice, _meta)| slice.last()) == Some(&b'\r') {
+            line = line.range(..line.len() - 1);
+        }
+
+        Some(line)
+    }
+}
+
+impl FusedIterator for BytesViewLines {}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mem::testing::TransparentMemory;
+
+    fn multi_span_view(parts: &[&[u8]]) -> BytesView {
+        let memory = TransparentMemory::new();
+        BytesView::from_views(parts.iter().map(|part| BytesView::copied_from_slice(part, &memory)))
+    }
+
+    #[test]
+    fn find_byte_multi_span() {
+        let view = multi_span_view(&[b"ab", b"cd", b"ec"]);
+
+        assert_eq!(view.find_byte(b'a'), Some(0));
+        assert_eq!(view.find_byte(b'c'), Some(2));
+        assert_eq!(view.find_byte(b'e'), Some(4));
+        assert_eq!(view.find_byte(b'z'), None);
+        assert_eq!(BytesView::new().find_byte(b'a'), None);
+
+        assert_eq!(view.find_byte_from(3, b'c'), Some(5));
+        assert_eq!(view.find_byte_from(6, b'c'), None);
+    }
+
+    #[test]
+    fn find_slice_multi_span() {
+        let view = multi_span_view(&[b"Hel", b"lo, w", b"o", b"rld"]);
+
+        assert_eq!(view.find_slice(b""), Some(0));
+        assert_eq!(view.find_slice(b"Hello"), Some(0));
+        assert_eq!(view.find_slice(b"lo"), Some(3));
+        assert_eq!(view.find_slice(b"world"), Some(7));
+        assert_eq!(view.find_slice(b"ld"), Some(10));
+        assert_eq!(view.find_slice(b"worlds"), None);
+        assert_eq!(view.find_slice(b"wow"), None);
+        assert_eq!(BytesView::new().find_slice(b"a"), None);
+    }
+
+    #[test]
+    fn find_slice_after_partial_match() {
+        // The first candidate only matches up to the span boundary.
+        let view = multi_span_view(&[b"aab", b"aac"]);
+
+        assert_eq!(view.find_slice(b"aac"), Some(3));
+        assert_eq!(view.find_slice(b"baa"), Some(2));
+    }
+
+    #[test]
+    fn split_until_multi_span() {
+        let mut view = multi_span_view(&[b"one\ntw", b"o\nthr", b"ee"]);
+
+        assert_eq!(view.split_until(b'\n').unwrap(), b"one\n".as_slice());
+        assert_eq!(view.split_until(b'\n').unwrap(), b"two\n".as_slice());
+        assert!(view.split_until(b'\n').is_none());
+        assert_eq!(view, b"three".as_slice());
+    }
+
+    #[test]
+    fn split_lines_multi_span() {
+        let view = multi_span_view(&[b"first\r", b"\nsec", b"ond\n\nlast\r"]);
+
+        let lines: Vec<BytesView> = view.split_lines().collect();
+
+        assert_eq!(
+            lines,
+            [b"first".as_slice(), b"second".as_slice(), b"".as_slice(), b"last\r".as_slice()]
+        );
+        assert_eq!(view.len(), 20);
+    }
+
+    #[test]
+    fn split_lines_terminated_and_empty() {
+        let view = multi_span_view(&[b"a\n", b"b\r\n"]);
+
+        let lines: Vec<BytesView> = view.split_lines().collect();
+        assert_eq!(lines, [b"a".as_slice(), b"b".as_slice()]);
+
+        let mut lines = BytesView::new().split_lines();
+        assert!(lines.next().is_none());
+        assert!(lines.next().is_none());
+    }
+}
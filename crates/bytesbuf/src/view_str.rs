@@ -0,0 +1,165 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! We separate out the methods that interpret the view as UTF-8 text.
+//!
+//! A character may be split between two spans, so the bytes are validated after they are
+//! gathered into the resulting string, which is the only copy made.
+
+use std::borrow::Cow;
+use std::str::Utf8Error;
+use std::string::FromUtf8Error;
+
+use crate::BytesView;
+
+impl BytesView {
+    /// Consumes `len` bytes from the front of the view and returns them as a string.
+    ///
+    /// The bytes are validated as UTF-8 also when a character straddles the boundary between
+    /// spans. If the bytes are not valid UTF-8, nothing is consumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let mut view = BytesView::copied_from_slice(b"\x06Gr\xC3\xBC\xC3\x9F!", &memory);
+    ///
+    /// let len = view.get_byte() as usize;
+    /// assert_eq!(view.get_str(len)?, "Grüß");
+    /// assert_eq!(view, b"!");
+    /// # Ok::<(), std::str::Utf8Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not valid UTF-8.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is greater than the number of bytes in the view.
+    pub fn get_str(&mut self, len: usize) -> Result<String, Utf8Error> {
+        let string = self.range(..len).try_into_string().map_err(|e| e.utf8_error())?;
+        self.advance(len);
+        Ok(string)
+    }
+
+    /// Returns the byte sequence as a string, replacing invalid UTF-8 with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][char::REPLACEMENT_CHARACTER].
+    ///
+    /// If the view consists of a single span of valid UTF-8, the string borrows from the view
+    /// instead of copying it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let view = BytesView::copied_from_slice(b"Hello, \xF0world", &memory);
+    ///
+    /// assert_eq!(view.to_utf8_lossy(), "Hello, \u{FFFD}world");
+    /// ```
+    #[must_use]
+    pub fn to_utf8_lossy(&self) -> Cow<'_, str> {
+        if self.first_slice().len() == self.len() {
+            return String::from_utf8_lossy(self.first_slice());
+        }
+
+        Cow::Owned(String::from_utf8_lossy(&self.to_vec()).into_owned())
+    }
+
+    /// Converts the byte sequence into a string, if it is valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let memory = bytesbuf::mem::GlobalPool::new();
+    /// use bytesbuf::BytesView;
+    ///
+    /// let view = BytesView::copied_from_slice(b"Hello, world!", &memory);
+    /// assert_eq!(view.try_into_string()?, "Hello, world!");
+    ///
+    /// let view = BytesView::copied_from_slice(b"\xFF", &memory);
+    /// assert!(view.try_into_string().is_err());
+    /// # Ok::<(), std::string::FromUtf8Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the byte sequence is not valid UTF-8. The error holds a copy of
+    /// the bytes, available via [`FromUtf8Error::into_bytes()`].
+    pub fn try_into_string(self) -> Result<String, FromUtf8Error> {
+        String::from_utf8(self.to_vec())
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mem::testing::TransparentMemory;
+
+    /// "Grüß" with the "ü" split between the first two spans.
+    fn split_character_view() -> BytesView {
+        let memory = TransparentMemory::new();
+        let view_part1 = BytesView::copied_from_slice(b"Gr\xC3", &memory);
+        let view_part2 = BytesView::copied_from_slice(b"\xBC\xC3\x9F", &memory);
+        let view_part3 = BytesView::copied_from_slice(b"!", &memory);
+        BytesView::from_views([view_part1, view_part2, view_part3])
+    }
+
+    #[test]
+    fn get_str_multi_span() {
+        let mut view = split_character_view();
+
+        assert_eq!(view.get_str(6).unwrap(), "Grüß");
+        assert_eq!(view.get_str(0).unwrap(), "");
+        assert_eq!(view.get_str(1).unwrap(), "!");
+        assert!(view.is_empty());
+    }
+
+    #[test]
+    fn get_str_invalid_does_not_consume() {
+        let mut view = split_character_view();
+
+        // The length ends in the middle of the "ü".
+        let error = view.get_str(3).unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(view.len(), 7);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_str_past_end_panics() {
+        let mut view = split_character_view();
+        let _ = view.get_str(8);
+    }
+
+    #[test]
+    fn to_utf8_lossy_borrows_single_span() {
+        let memory = TransparentMemory::new();
+        let view = BytesView::copied_from_slice(b"Hello", &memory);
+
+        assert!(matches!(view.to_utf8_lossy(), Cow::Borrowed("Hello")));
+        assert!(matches!(BytesView::new().to_utf8_lossy(), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn to_utf8_lossy_multi_span() {
+        let view = split_character_view();
+        assert_eq!(view.to_utf8_lossy(), "Grüß!");
+
+        let view = view.range(..3).concat(view.range(4..));
+        assert_eq!(view.to_utf8_lossy(), "Gr\u{FFFD}ß!");
+    }
+
+    #[test]
+    fn try_into_string_multi_span() {
+        assert_eq!(split_character_view().try_into_string().unwrap(), "Grüß!");
+
+        let error = split_character_view().range(..3).try_into_string().unwrap_err();
+        assert_eq!(error.into_bytes(), b"Gr\xC3");
+    }
+}
@@ -70,7 +70,7 @@ pub trait Read: HasMemory + Memory + Debug {
     /// ```
     /// # fn main() {
     /// # #[cfg(feature = "test-util")] {
-    /// # testing_aids::execute_or_terminate_process(|| futures::executor::block_on(async {
+    /// # testing_aids::execute_or_terminate_process(None, || futures::executor::block_on(async {
     /// # use bytesbuf_io::testing::Null;
     /// use bytesbuf::mem::Memory;
     /// use bytesbuf_io::Read;
@@ -114,7 +114,7 @@ pub trait Read: HasMemory + Memory + Debug {
     /// ```
     /// # fn main() {
     /// # #[cfg(feature = "test-util")] {
-    /// # testing_aids::execute_or_terminate_process(|| futures::executor::block_on(async {
+    /// # testing_aids::execute_or_terminate_process(None, || futures::executor::block_on(async {
     /// # use bytesbuf_io::testing::Null;
     /// use bytesbuf_io::Read;
     ///
@@ -175,7 +175,7 @@ pub trait Read: HasMemory + Memory + Debug {
     /// ```
     /// # fn main() {
     /// # #[cfg(feature = "test-util")] {
-    /// # testing_aids::execute_or_terminate_process(|| futures::executor::block_on(async {
+    /// # testing_aids::execute_or_terminate_process(None, || futures::executor::block_on(async {
     /// # use bytesbuf_io::testing::Null;
     /// use bytesbuf_io::Read;
     ///
@@ -33,7 +33,7 @@ pub trait ReadExt: Read {
     /// ```
     /// # fn main() {
     /// # #[cfg(feature = "test-util")] {
-    /// # testing_aids::execute_or_terminate_process(|| futures::executor::block_on(async {
+    /// # testing_aids::execute_or_terminate_process(None, || futures::executor::block_on(async {
     /// # use bytesbuf_io::testing::Null;
     /// use bytesbuf_io::ReadExt;
     ///
@@ -58,7 +58,7 @@ pub trait ReadExt: Read {
     /// ```
     /// # fn main() {
     /// # #[cfg(feature = "test-util")] {
-    /// # testing_aids::execute_or_terminate_process(|| futures::executor::block_on(async {
+    /// # testing_aids::execute_or_terminate_process(None, || futures::executor::block_on(async {
     /// # use bytesbuf_io::testing::FakeRead;
     /// # use bytesbuf::BytesView;
     /// # use bytesbuf::mem::GlobalPool;
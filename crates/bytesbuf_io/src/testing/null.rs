@@ -213,7 +213,7 @@ mod tests {
 
     #[test]
     fn smoke_test() {
-        execute_or_terminate_process(|| {
+        execute_or_terminate_process(None, || {
             futures::executor::block_on(async {
                 let mut s = Null::new();
 
@@ -238,7 +238,7 @@ mod tests {
 
     #[test]
     fn default_returns_working_instance() {
-        execute_or_terminate_process(|| {
+        execute_or_terminate_process(None, || {
             futures::executor::block_on(async {
                 let mut s = Null::default();
 
@@ -62,7 +62,7 @@ pub trait Write: HasMemory + Memory + Debug {
     /// ```
     /// # fn main() {
     /// # #[cfg(feature = "test-util")] {
-    /// # testing_aids::execute_or_terminate_process(|| futures::executor::block_on(async {
+    /// # testing_aids::execute_or_terminate_process(None, || futures::executor::block_on(async {
     /// # use bytesbuf_io::testing::Null;
     /// use bytesbuf::mem::Memory;
     /// use bytesbuf_io::Write;
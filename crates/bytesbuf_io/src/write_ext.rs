@@ -22,7 +22,7 @@ pub trait WriteExt: Write {
     /// ```
     /// # fn main() {
     /// # #[cfg(feature = "test-util")] {
-    /// # testing_aids::execute_or_terminate_process(|| futures::executor::block_on(async {
+    /// # testing_aids::execute_or_terminate_process(None, || futures::executor::block_on(async {
     /// # use bytesbuf_io::testing::Null;
     /// use std::convert::Infallible;
     ///
@@ -9,7 +9,7 @@ use std::hash::Hash;
 
 use cachet_tier::{CacheEntry, CacheTier, DynamicCache, SizeError};
 use tick::Clock;
-use uniflight::Merger;
+use uniflight::{ExecuteError, Merger};
 
 use crate::Error;
 use crate::builder::CacheBuilder;
@@ -53,6 +53,17 @@ where
     }
 }
 
+/// Converts the failure of a merged operation into a cache error.
+///
+/// A panic of the leader is wrapped as [`uniflight::LeaderPanicked`] so that callers can
+/// inspect it as the source of the error.
+fn merge_failed(err: ExecuteError) -> Error {
+    match err {
+        ExecuteError::Panicked(panicked) => Error::from_source(panicked),
+        err => Error::from_source(err),
+    }
+}
+
 impl<K, V> Debug for Mergers<K, V> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Mergers").finish_non_exhaustive()
@@ -240,7 +251,7 @@ where
                     .get
                     .execute(key, move || async move { storage.get(&owned).await })
                     .await
-                    .unwrap_or_else(|panicked| Err(Error::from_source(panicked)));
+                    .unwrap_or_else(|err| Err(merge_failed(err)));
                 (result, true)
             } else {
                 let owned = key.to_owned();
@@ -321,7 +332,7 @@ where
                     .invalidate
                     .execute(key, move || async move { storage.invalidate(&owned).await })
                     .await
-                    .unwrap_or_else(|panicked| Err(Error::from_source(panicked)));
+                    .unwrap_or_else(|err| Err(merge_failed(err)));
                 (result, true)
             } else {
                 let owned = key.to_owned();
@@ -469,7 +480,7 @@ where
                     .get_or_insert
                     .execute(key, move || async move { self.do_get_or_insert(&owned, f).await })
                     .await
-                    .unwrap_or_else(|panicked| Err(Error::from_source(panicked)));
+                    .unwrap_or_else(|err| Err(merge_failed(err)));
                 (result, true)
             } else {
                 (self.do_get_or_insert(&owned, f).await, false)
@@ -560,7 +571,7 @@ where
                 .get_or_insert_with
                 .execute(key, move || async move { self.do_get_or_insert_with(&owned, f).await })
                 .await
-                .unwrap_or_else(|panicked| Err(Error::from_source(panicked)))
+                .unwrap_or_else(|err| Err(merge_failed(err)))
         } else {
             self.do_get_or_insert_with(&owned, f).await
         }
@@ -640,7 +651,7 @@ where
                 .try_get_or_insert_with
                 .execute(key, move || async move { self.do_try_get_or_insert_with(&owned, f).await })
                 .await
-                .unwrap_or_else(|panicked| Err(Error::from_source(panicked)))
+                .unwrap_or_else(|err| Err(merge_failed(err)))
         } else {
             self.do_try_get_or_insert_with(&owned, f).await
         }
@@ -717,7 +728,7 @@ where
                     .try_get_or_insert
                     .execute(key, move || async move { self.do_try_get_or_insert(&owned, f).await })
                     .await
-                    .unwrap_or_else(|panicked| Err(Error::from_source(panicked)));
+                    .unwrap_or_else(|err| Err(merge_failed(err)));
                 (result, true)
             } else {
                 (self.do_try_get_or_insert(&owned, f).await, false)
@@ -805,7 +816,7 @@ where
                     .optionally_get_or_insert
                     .execute(key, move || async move { self.do_optionally_get_or_insert(&owned, f).await })
                     .await
-                    .unwrap_or_else(|panicked| Err(Error::from_source(panicked)));
+                    .unwrap_or_else(|err| Err(merge_failed(err)));
                 (result, true)
             } else {
                 (self.do_optionally_get_or_insert(&owned, f).await, false)
@@ -15,7 +15,7 @@ use std::time::Duration;
 
 use cachet::{Cache, CacheEntry};
 use serial_test::serial;
-use testing_aids::TEST_TIMEOUT;
+use testing_aids::test_timeout;
 use testing_aids::tracing_logs::write_to_stdout_and_buffer;
 use tick::Clock;
 
@@ -41,7 +41,7 @@ async fn memory_size_eviction_emits_telemetry() {
     // Drive enough churn to force size-based evictions. Moka's housekeeping
     // runs periodically (and as a side effect of cache operations), so we keep
     // exercising the cache while waiting for an eviction event to surface.
-    let deadline = std::time::Instant::now() + TEST_TIMEOUT;
+    let deadline = std::time::Instant::now() + test_timeout();
     let mut i: i32 = 0;
     while std::time::Instant::now() < deadline {
         for _ in 0..256 {
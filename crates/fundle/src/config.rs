@@ -0,0 +1,105 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Binding bundle fields to configuration.
+//!
+//! Bundle fields marked `#[bundle(config)]` are deserialized from a [`ConfigSource`] instead of
+//! being constructed by setter closures. For such bundles, the builder gets a
+//! `with_config(&source)` method that deserializes all marked fields at once, each from the
+//! section named after the field. Later setters access them like any other field.
+//!
+//! ```rust
+//! use fundle::config::{ConfigError, ConfigSource};
+//! use serde::de::DeserializeOwned;
+//! # #[derive(Clone, serde::Deserialize)]
+//! # pub struct HttpConfig { port: u16 }
+//! # pub struct Server;
+//! # impl Server { fn bind(port: u16) -> Self { Self } }
+//!
+//! struct JsonConfig(serde_json::Value);
+//!
+//! impl ConfigSource for JsonConfig {
+//!     fn section<T: DeserializeOwned>(&self, key: &str) -> Result<T, ConfigError> {
+//!         let section = self.0.get(key).cloned().unwrap_or_default();
+//!         serde_json::from_value(section).map_err(|e| ConfigError::new(key, e))
+//!     }
+//! }
+//!
+//! #[fundle::bundle]
+//! pub struct AppState {
+//!     #[bundle(config)]
+//!     http: HttpConfig,
+//!     server: Server,
+//! }
+//!
+//! fn app(config: &JsonConfig) -> Result<AppState, ConfigError> {
+//!     Ok(AppState::builder()
+//!         .with_config(config)?
+//!         .server(|x| Server::bind(x.http().port))
+//!         .build())
+//! }
+//! # fn main() {
+//! #     let config = JsonConfig(serde_json::json!({ "http": { "port": 8080 } }));
+//! #     app(&config).unwrap();
+//! # }
+//! ```
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use serde_core::de::DeserializeOwned;
+
+/// A configuration that bundle fields are deserialized from.
+///
+/// Implement it for the configuration format of the application, such as a parsed JSON or TOML
+/// document, or a layered configuration of files and environment variables.
+pub trait ConfigSource {
+    /// Deserializes the section `key` of the configuration.
+    ///
+    /// # Errors
+    ///
+    /// If the section can't be deserialized into `T`, for example because it is missing
+    /// required values.
+    fn section<T: DeserializeOwned>(&self, key: &str) -> Result<T, ConfigError>;
+}
+
+impl<S: ConfigSource + ?Sized> ConfigSource for &S {
+    fn section<T: DeserializeOwned>(&self, key: &str) -> Result<T, ConfigError> {
+        (**self).section(key)
+    }
+}
+
+/// A configuration section that could not be deserialized.
+#[derive(Debug)]
+pub struct ConfigError {
+    key: String,
+    source: Box<dyn Error + Send + Sync>,
+}
+
+impl ConfigError {
+    /// Creates an error for the section `key`, caused by `source`.
+    pub fn new(key: impl Into<String>, source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self {
+            key: key.into(),
+            source: source.into(),
+        }
+    }
+
+    /// Returns the key of the section that could not be deserialized.
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid configuration section `{}`", self.key)
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&*self.source)
+    }
+}
@@ -24,3 +24,25 @@ pub trait Export<const N: usize> {
     /// Get the N-th export.
     fn get(&self) -> &Self::T;
 }
+
+/// A dependency `T` of the `deps` struct `D`, provided by `Self`.
+///
+/// Implemented for every type that implements `AsRef<T>`. The `deps` macro requires it instead
+/// of `AsRef<T>`, so that missing dependencies are reported naming the missing type, and the
+/// `deps` struct that needs it.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not provide the `{T}` dependency of `{D}`",
+    label = "missing `AsRef<{T}>`",
+    note = "bundles implement `AsRef` for fields with unique types, and for types listed in `#[forward(...)]`"
+)]
+pub trait Provides<T: ?Sized, D: ?Sized> {
+    /// Get the dependency.
+    fn provide(&self) -> &T;
+}
+
+#[diagnostic::do_not_recommend]
+impl<S: AsRef<T> + ?Sized, T: ?Sized, D: ?Sized> Provides<T, D> for S {
+    fn provide(&self) -> &T {
+        self.as_ref()
+    }
+}
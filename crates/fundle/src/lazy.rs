@@ -0,0 +1,124 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Bundle fields that are constructed on first use.
+
+use std::fmt::{self, Debug, Formatter};
+use std::ops::Deref;
+use std::sync::{Mutex, OnceLock};
+
+type Initializer<T> = Box<dyn FnOnce() -> T + Send>;
+
+/// A value that is constructed the first time it is accessed.
+///
+/// Bundle fields of type `Lazy<T>` get an additional `foo_lazy` setter that stores an
+/// initializer instead of a value, so expensive components are only built by code paths that
+/// use them. The setter closure captures the dependencies it needs from the builder and returns
+/// the initializer:
+///
+/// ```rust
+/// use fundle::Lazy;
+/// # #[derive(Clone)]
+/// # pub struct Logger;
+/// # pub struct Database;
+/// # impl Database { fn connect(_: &Logger) -> Self { Self } }
+///
+/// #[fundle::bundle]
+/// pub struct AppState {
+///     logger: Logger,
+///     database: Lazy<Database>,
+/// }
+///
+/// fn main() {
+///     let app = AppState::builder()
+///         .logger(|_| Logger)
+///         .database_lazy(|x| {
+///             let logger = x.logger().clone();
+///             move || Database::connect(&logger)
+///         })
+///         .build();
+///
+///     assert!(app.database.get().is_none());
+///     let _: &Database = app.as_ref();
+///     assert!(app.database.get().is_some());
+/// }
+/// ```
+///
+/// Initialization is thread-safe: the initializer runs at most once, and concurrent accesses
+/// wait for it to finish. If the initializer panics, all later accesses panic as well.
+pub struct Lazy<T> {
+    value: OnceLock<T>,
+    initializer: Mutex<Option<Initializer<T>>>,
+}
+
+impl<T> Lazy<T> {
+    /// Creates a value that is constructed by `initializer` on first access.
+    pub fn new(initializer: impl FnOnce() -> T + Send + 'static) -> Self {
+        Self {
+            value: OnceLock::new(),
+            initializer: Mutex::new(Some(Box::new(initializer))),
+        }
+    }
+
+    /// Returns the value, constructing it if this is the first access.
+    ///
+    /// # Panics
+    ///
+    /// If the initializer panics, now or during an earlier access.
+    pub fn force(&self) -> &T {
+        self.value.get_or_init(|| {
+            let initializer = self
+                .initializer
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .take()
+                .expect("the value is uninitialized only if the initializer was taken by an access that panicked");
+            initializer()
+        })
+    }
+
+    /// Returns the value if it has already been constructed.
+    pub fn get(&self) -> Option<&T> {
+        self.value.get()
+    }
+}
+
+impl<T> From<T> for Lazy<T> {
+    /// Creates an already constructed value.
+    fn from(value: T) -> Self {
+        Self {
+            value: OnceLock::from(value),
+            initializer: Mutex::new(None),
+        }
+    }
+}
+
+impl<T: Default + 'static> Default for Lazy<T> {
+    /// Creates a value that is constructed with [`Default`] on first access.
+    fn default() -> Self {
+        Self::new(T::default)
+    }
+}
+
+impl<T> Deref for Lazy<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.force()
+    }
+}
+
+impl<T> AsRef<T> for Lazy<T> {
+    fn as_ref(&self) -> &T {
+        self.force()
+    }
+}
+
+impl<T: Debug> Debug for Lazy<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.get() {
+            Some(value) => f.debug_tuple("Lazy").field(value).finish(),
+            None => f.write_str("Lazy(<uninitialized>)"),
+        }
+    }
+}
@@ -60,10 +60,27 @@
 //! # Capabilities
 //!
 //! - **Type-safe builder pattern** - Each field must be set exactly once before building
+//! - **Optional fields** - Fields marked `#[bundle(default)]` or typed `Option<T>` fall back to their default
+//! - **Conditional fields** - Fields can be compiled out with `#[cfg(...)]` or enabled at runtime with `#[bundle(if_enabled)]`
+//! - **Lazy fields** - Fields typed [`Lazy<T>`] can be constructed on first use
+//! - **Test overrides** - `override_foo(value)` substitutes a field while reusing an existing builder chain
+//! - **Scoped bundles** - `#[bundle(parent = AppState)]` bundles are created per request with `from_parent(&app, ...)`
+//! - **Configuration binding** - Fields marked `#[bundle(config)]` are deserialized from a configuration source
+//! - **Ordered shutdown** - Fields marked `#[bundle(shutdown)]` are shut down in reverse initialization order
 //! - **Dependency injection** - Fields can access previously set fields during construction
 //! - **Automatic `AsRef` implementations** - Generated for unique field types
+//! - **Dependency checks** - [`check_deps!`] names each dependency a bundle doesn't provide
+//! - **Generic bundles** - Bundles like `AppState<C: Clock>` keep their lifetime, type, and const parameters
+//! - **Trait-object dependencies** - Fields typed `Arc<dyn Trait>` let consumers depend on abstractions
 //! - **Multiple setter variants** - Regular, try (fallible), async, and async-try setters
 //!
+//! # Features
+//!
+//! - **`config`**: Enables the `config` module and the `with_config()` method generated for
+//!   bundles with `#[bundle(config)]` fields.
+//! - **`shutdown`**: Enables the `shutdown` module and the `shutdown()` method generated for
+//!   bundles with `#[bundle(shutdown)]` fields.
+//!
 //! # Quick Start
 //!
 //! ```rust
@@ -100,11 +117,56 @@
 #![doc(html_logo_url = "https://media.githubusercontent.com/media/microsoft/oxidizer/refs/heads/main/crates/fundle/logo.png")]
 #![doc(html_favicon_url = "https://media.githubusercontent.com/media/microsoft/oxidizer/refs/heads/main/crates/fundle/favicon.ico")]
 
+#[cfg(feature = "config")]
+pub mod config;
 #[doc(hidden)]
 pub mod exports;
+mod lazy;
+#[cfg(feature = "shutdown")]
+pub mod shutdown;
 
 // Re-export proc macros from fundle_macros
 pub use fundle_macros::{bundle, deps, newtype};
+pub use lazy::Lazy;
+
+/// Checks at compile time that a bundle provides all dependencies of `deps` structs.
+///
+/// Converting a bundle that lacks a dependency into a `deps` struct fails where the conversion
+/// happens, often deep inside a constructor taking `impl Into<ServiceDeps>`. This macro
+/// reports the problem next to the bundle definition instead, with one error per missing
+/// dependency type.
+///
+/// ```rust
+/// # #[derive(Clone)]
+/// # pub struct Logger {}
+/// # #[derive(Clone)]
+/// # pub struct Config {}
+/// #[fundle::bundle]
+/// pub struct AppState {
+///     logger: Logger,
+///     config: Config,
+/// }
+///
+/// #[fundle::deps]
+/// pub struct ServiceDeps {
+///     logger: Logger,
+///     config: Config,
+/// }
+///
+/// fundle::check_deps!(AppState => ServiceDeps);
+/// # fn main() {}
+/// ```
+///
+/// If `AppState` had no `config` field, this reports
+/// ``error[E0277]: `AppState` does not provide the `Config` dependency of `ServiceDeps` ``.
+#[macro_export]
+macro_rules! check_deps {
+    ($bundle:ty => $($deps:ty),+ $(,)?) => {
+        const _: () = {
+            $(<$deps>::__fundle_check_deps::<$bundle>();)+
+        };
+    };
+}
 
 // Internal helpers. These are used for type state pattern used by the `bundle` macro.
 // Specifically, if you do
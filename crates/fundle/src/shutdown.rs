@@ -0,0 +1,140 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Ordered graceful shutdown of bundles.
+//!
+//! Bundle fields marked `#[bundle(shutdown)]` must implement [`Shutdown`]. For such bundles, the
+//! `bundle` macro generates an async `shutdown(&self, clock, timeout)` method that shuts the
+//! marked fields down one after another, in the reverse order they were initialized by the
+//! builder. Components are therefore stopped before the dependencies they were built from.
+//!
+//! Each component gets `timeout` to shut down. Components that exceed it are abandoned so the
+//! remaining ones still get their turn, and are reported in the returned [`ShutdownError`].
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use fundle::shutdown::Shutdown;
+//! # #[derive(Clone)]
+//! # pub struct Logger;
+//! # impl Logger { fn flush(&self) {} }
+//! # pub struct Database;
+//! # impl Database { async fn close(&self) {} }
+//! # impl Shutdown for Logger { async fn shutdown(&self) { self.flush() } }
+//! # impl Shutdown for Database { async fn shutdown(&self) { self.close().await } }
+//!
+//! #[fundle::bundle]
+//! pub struct AppState {
+//!     #[bundle(shutdown)]
+//!     logger: Logger,
+//!     #[bundle(shutdown)]
+//!     database: Database,
+//! }
+//!
+//! async fn stop(app: AppState, clock: &tick::Clock) {
+//!     // Shuts down the database first, then the logger
+//!     if let Err(e) = app.shutdown(clock, Duration::from_secs(5)).await {
+//!         eprintln!("{e}");
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+
+use std::fmt::{self, Display, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[doc(no_inline)]
+pub use tick::Clock;
+use tick::FutureExt;
+
+use crate::Lazy;
+
+/// Components that can be shut down gracefully as part of a bundle.
+pub trait Shutdown {
+    /// Releases the resources of the component, for example by flushing buffers or closing
+    /// connections.
+    fn shutdown(&self) -> impl Future<Output = ()> + Send;
+}
+
+impl<T: Shutdown + Sync + ?Sized> Shutdown for Arc<T> {
+    fn shutdown(&self) -> impl Future<Output = ()> + Send {
+        (**self).shutdown()
+    }
+}
+
+impl<T: Shutdown + Sync> Shutdown for Option<T> {
+    async fn shutdown(&self) {
+        if let Some(component) = self {
+            component.shutdown().await;
+        }
+    }
+}
+
+impl<T: Shutdown + Send + Sync> Shutdown for Lazy<T> {
+    /// Shuts the value down if it was constructed, without constructing it otherwise.
+    async fn shutdown(&self) {
+        if let Some(component) = self.get() {
+            component.shutdown().await;
+        }
+    }
+}
+
+/// The components that did not shut down in time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShutdownError {
+    timed_out: Vec<&'static str>,
+}
+
+impl ShutdownError {
+    /// Returns the names of the fields that timed out, in the order they were shut down.
+    #[must_use]
+    pub fn timed_out(&self) -> &[&'static str] {
+        &self.timed_out
+    }
+}
+
+impl Display for ShutdownError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "components timed out during shutdown: {}", self.timed_out.join(", "))
+    }
+}
+
+impl std::error::Error for ShutdownError {}
+
+/// Shuts down the components of a bundle, used by the code generated by the `bundle` macro.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct ShutdownSequence<'a> {
+    clock: &'a Clock,
+    timeout: Duration,
+    timed_out: Vec<&'static str>,
+}
+
+impl<'a> ShutdownSequence<'a> {
+    #[must_use]
+    pub const fn new(clock: &'a Clock, timeout: Duration) -> Self {
+        Self {
+            clock,
+            timeout,
+            timed_out: Vec::new(),
+        }
+    }
+
+    pub async fn component(&mut self, name: &'static str, component: &(impl Shutdown + Sync)) {
+        if component.shutdown().timeout(self.clock, self.timeout).await.is_err() {
+            self.timed_out.push(name);
+        }
+    }
+
+    /// # Errors
+    ///
+    /// If any component timed out.
+    pub fn finish(self) -> Result<(), ShutdownError> {
+        if self.timed_out.is_empty() {
+            Ok(())
+        } else {
+            Err(ShutdownError { timed_out: self.timed_out })
+        }
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![allow(
+    unused_attributes,
+    clippy::empty_structs_with_brackets,
+    clippy::redundant_type_annotations,
+    clippy::items_after_statements,
+    missing_docs,
+    reason = "Unit tests"
+)]
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Logger {
+    name: String,
+}
+
+#[cfg(not(test))]
+#[derive(Debug, Default, Clone)]
+pub struct Telemetry {}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Metrics {
+    prefix: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tracer {
+    endpoint: String,
+}
+
+// Integration tests are compiled with `cfg(test)`, so `cfg(not(test))` fields are compiled out
+#[fundle::bundle]
+struct AppState {
+    logger: Logger,
+    #[cfg(test)]
+    audit_logger: Logger,
+    #[cfg(not(test))]
+    telemetry: Telemetry,
+    #[cfg(test)]
+    metrics: Metrics,
+    #[cfg(not(test))]
+    #[bundle(default)]
+    disabled_metrics: Metrics,
+    #[bundle(if_enabled)]
+    tracer: Option<Tracer>,
+}
+
+#[fundle::deps]
+struct MetricsDeps {
+    metrics: Metrics,
+}
+
+fn builder()
+-> AppStateBuilder<fundle::Write, fundle::NotSet, fundle::NotSet, fundle::NotSet, fundle::NotSet, fundle::NotSet, fundle::NotSet> {
+    AppState::builder()
+}
+
+#[test]
+fn compiled_out_fields_are_not_required() {
+    let app = builder()
+        .logger(|_| Logger { name: "app".to_string() })
+        .audit_logger(|_| Logger::default())
+        .metrics(|x| Metrics {
+            prefix: x.logger().name.clone(),
+        })
+        .build();
+
+    assert_eq!(app.metrics.prefix, "app");
+    assert_eq!(app.tracer, None);
+
+    let deps = MetricsDeps::from(&app);
+    assert_eq!(deps.metrics, app.metrics);
+}
+
+#[test]
+fn compiled_in_fields_can_be_selected() {
+    let app = builder()
+        .metrics(|_| Metrics { prefix: "svc".to_string() })
+        .audit_logger(|_| Logger { name: "audit".to_string() })
+        .logger(|x| {
+            let with_audit = AppState!(select(x) => Logger(audit_logger));
+            let metrics: &Metrics = with_audit.as_ref();
+            let audit_logger: &Logger = with_audit.as_ref();
+            Logger {
+                name: format!("{}-{}", metrics.prefix, audit_logger.name),
+            }
+        })
+        .build();
+
+    assert_eq!(app.logger.name, "svc-audit");
+}
+
+#[test]
+fn if_enabled_fields_are_only_constructed_if_enabled() {
+    let enabled = builder()
+        .logger(|_| Logger {
+            name: "enabled".to_string(),
+        })
+        .audit_logger(|_| Logger::default())
+        .tracer_if_enabled(true, |x| Tracer {
+            endpoint: x.logger().name.clone(),
+        })
+        .metrics(|_| Metrics::default())
+        .build();
+    assert_eq!(
+        enabled.tracer,
+        Some(Tracer {
+            endpoint: "enabled".to_string()
+        })
+    );
+
+    let disabled = builder()
+        .logger(|_| Logger::default())
+        .audit_logger(|_| Logger::default())
+        .tracer_if_enabled(false, |_| panic!("disabled fields are not constructed"))
+        .metrics(|_| Metrics::default())
+        .build();
+    assert_eq!(disabled.tracer, None);
+}
+
+#[test]
+fn if_enabled_fields_keep_overrides() {
+    let app = builder()
+        .override_tracer(Some(Tracer {
+            endpoint: "mock".to_string(),
+        }))
+        .logger(|_| Logger::default())
+        .audit_logger(|_| Logger::default())
+        .tracer_if_enabled(false, |_| panic!("overridden fields are not constructed"))
+        .metrics(|_| Metrics::default())
+        .build();
+
+    assert_eq!(app.tracer.map(|tracer| tracer.endpoint).as_deref(), Some("mock"));
+}
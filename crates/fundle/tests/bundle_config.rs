@@ -0,0 +1,95 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![allow(
+    unused_attributes,
+    clippy::empty_structs_with_brackets,
+    clippy::redundant_type_annotations,
+    clippy::items_after_statements,
+    missing_docs,
+    reason = "Unit tests"
+)]
+
+use std::error::Error;
+
+use fundle::config::{ConfigError, ConfigSource};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+struct JsonConfig(serde_json::Value);
+
+impl ConfigSource for JsonConfig {
+    fn section<T: DeserializeOwned>(&self, key: &str) -> Result<T, ConfigError> {
+        let section = self.0.get(key).cloned().unwrap_or_default();
+        serde_json::from_value(section).map_err(|e| ConfigError::new(key, e))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct HttpConfig {
+    port: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CacheConfig {
+    capacity: usize,
+}
+
+#[derive(Debug)]
+pub struct Server {
+    port: u16,
+}
+
+#[fundle::bundle]
+struct AppState {
+    #[bundle(config)]
+    http: HttpConfig,
+    server: Server,
+    #[bundle(config)]
+    cache: Option<CacheConfig>,
+}
+
+#[test]
+fn config_fields_deserialized_from_source() {
+    let config = JsonConfig(json!({
+        "http": { "port": 8080 },
+        "cache": { "capacity": 16 },
+    }));
+
+    let app = AppState::builder()
+        .with_config(&config)
+        .unwrap()
+        .server(|x| Server { port: x.http().port })
+        .build();
+
+    assert_eq!(app.http, HttpConfig { port: 8080 });
+    assert_eq!(app.cache, Some(CacheConfig { capacity: 16 }));
+    assert_eq!(app.server.port, 8080);
+}
+
+#[test]
+fn config_fields_keep_overrides() {
+    let config = JsonConfig(json!({ "http": { "port": 8080 } }));
+
+    let app = AppState::builder()
+        .override_http(HttpConfig { port: 1234 })
+        .with_config(&config)
+        .unwrap()
+        .server(|x| Server { port: x.http().port })
+        .build();
+
+    assert_eq!(app.server.port, 1234);
+    assert_eq!(app.cache, None);
+}
+
+#[test]
+fn config_errors_name_section() {
+    let config = JsonConfig(json!({ "http": { "port": "not a number" } }));
+
+    let error = AppState::builder().with_config(&config).map(|_| ()).unwrap_err();
+
+    assert_eq!(error.key(), "http");
+    assert_eq!(error.to_string(), "invalid configuration section `http`");
+    assert!(error.source().is_some());
+}
@@ -0,0 +1,62 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![allow(
+    unused_attributes,
+    clippy::empty_structs_with_brackets,
+    clippy::redundant_type_annotations,
+    clippy::items_after_statements,
+    missing_docs,
+    reason = "Unit tests"
+)]
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Logger {
+    name: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Metrics {
+    prefix: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tracer {
+    endpoint: String,
+}
+
+#[fundle::bundle]
+struct AppState {
+    logger: Logger,
+    #[bundle(default)]
+    metrics: Metrics,
+    tracer: Option<Tracer>,
+}
+
+#[test]
+fn optional_fields_default_when_unset() {
+    let app = AppState::builder().logger(|_| Logger { name: "app".to_string() }).build();
+
+    assert_eq!(app.logger.name, "app");
+    assert_eq!(app.metrics, Metrics::default());
+    assert_eq!(app.tracer, None);
+}
+
+#[test]
+fn optional_fields_keep_set_values() {
+    let app = AppState::builder()
+        .metrics(|_| Metrics { prefix: "svc".to_string() })
+        .tracer(|_| {
+            Some(Tracer {
+                endpoint: "localhost".to_string(),
+            })
+        })
+        .logger(|x| Logger {
+            name: format!("{}-logger", x.metrics().prefix),
+        })
+        .build();
+
+    assert_eq!(app.logger.name, "svc-logger");
+    assert_eq!(app.metrics.prefix, "svc");
+    assert_eq!(app.tracer.unwrap().endpoint, "localhost");
+}
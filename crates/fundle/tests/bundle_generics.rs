@@ -0,0 +1,132 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![allow(
+    unused_attributes,
+    clippy::empty_structs_with_brackets,
+    clippy::redundant_type_annotations,
+    clippy::items_after_statements,
+    missing_docs,
+    reason = "Unit tests"
+)]
+
+pub trait Clock: Clone {
+    fn now(&self) -> u64;
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        1
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FixedClock(u64);
+
+impl Clock for FixedClock {
+    fn now(&self) -> u64 {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Logger {
+    started: u64,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    name: String,
+}
+
+#[fundle::bundle]
+pub struct AppState<C: Clock> {
+    clock: C,
+    logger: Logger,
+    audit: Logger,
+}
+
+#[fundle::bundle]
+struct Borrowed<'a, R = SystemClock>
+where
+    R: Clock,
+{
+    config: &'a Config,
+    clock: R,
+    #[bundle(default)]
+    logger: Logger,
+}
+
+#[fundle::bundle]
+struct Buffers<const N: usize> {
+    buffer: [u8; N],
+}
+
+#[fundle::deps]
+struct ClockDeps {
+    clock: FixedClock,
+}
+
+#[test]
+fn generic_fields_are_set_through_builder() {
+    let app = AppState::builder()
+        .clock(|_| FixedClock(42))
+        .logger(|x| Logger { started: x.clock().now() })
+        .audit(|x| Logger {
+            started: x.logger().started + 1,
+        })
+        .build();
+
+    assert_eq!(app.logger.started, 42);
+    assert_eq!(app.audit.started, 43);
+
+    let clock: &FixedClock = app.as_ref();
+    assert_eq!(clock.now(), 42);
+
+    let deps = ClockDeps::from(&app);
+    assert_eq!(deps.clock.now(), 42);
+}
+
+#[test]
+fn generic_fields_can_be_selected() {
+    let app = AppState::builder()
+        .clock(|_| SystemClock)
+        .logger(|_| Logger { started: 7 })
+        .audit(|x| {
+            let with_logger = AppState!(select(x) => Logger(logger));
+            let clock: &SystemClock = with_logger.as_ref();
+            let logger: &Logger = with_logger.as_ref();
+            Logger {
+                started: logger.started + clock.now(),
+            }
+        })
+        .build();
+
+    assert_eq!(app.audit.started, 8);
+}
+
+#[test]
+fn lifetimes_and_where_clauses_are_kept() {
+    let config = Config { name: "app".to_string() };
+    let borrowed: Borrowed<'_> = Borrowed::builder()
+        .config(|_| &config)
+        .clock(|x| {
+            assert_eq!(x.config().name, "app");
+            SystemClock
+        })
+        .build();
+
+    let config: &&Config = borrowed.as_ref();
+    assert_eq!(config.name, "app");
+    assert_eq!(borrowed.logger, Logger::default());
+}
+
+#[test]
+fn const_generics_are_kept() {
+    let buffers = Buffers::builder().buffer(|_| [1, 2, 3]).build();
+    let buffer: &[u8; 3] = buffers.as_ref();
+    assert_eq!(buffer, &[1, 2, 3]);
+}
@@ -0,0 +1,110 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![allow(
+    unused_attributes,
+    clippy::empty_structs_with_brackets,
+    clippy::redundant_type_annotations,
+    clippy::items_after_statements,
+    missing_docs,
+    reason = "Unit tests"
+)]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use fundle::Lazy;
+
+#[derive(Debug, Default, Clone)]
+pub struct Logger {
+    connections: Arc<AtomicUsize>,
+}
+
+#[derive(Debug)]
+pub struct Database {
+    url: String,
+}
+
+impl Database {
+    fn connect(url: &str, logger: &Logger) -> Self {
+        logger.connections.fetch_add(1, Ordering::Relaxed);
+        Self { url: url.to_string() }
+    }
+}
+
+#[fundle::bundle]
+struct AppState {
+    logger: Logger,
+    database: Lazy<Database>,
+}
+
+fn app() -> AppState {
+    AppState::builder()
+        .logger(|_| Logger::default())
+        .database_lazy(|x| {
+            let logger = x.logger().clone();
+            move || Database::connect("postgresql://localhost", &logger)
+        })
+        .build()
+}
+
+#[test]
+fn lazy_field_is_constructed_once_on_first_access() {
+    let app = app();
+    assert!(app.database.get().is_none());
+    assert_eq!(app.logger.connections.load(Ordering::Relaxed), 0);
+
+    let database: &Database = app.as_ref();
+    assert_eq!(database.url, "postgresql://localhost");
+    assert_eq!(app.database.url, "postgresql://localhost");
+    assert_eq!(app.logger.connections.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn lazy_field_is_initialized_once_across_threads() {
+    let app = app();
+
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| assert_eq!(app.database.url, "postgresql://localhost"));
+        }
+    });
+
+    assert_eq!(app.logger.connections.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn lazy_field_accepts_eager_values() {
+    let app = AppState::builder()
+        .logger(|_| Logger::default())
+        .database(|_| Lazy::from(Database { url: "eager".to_string() }))
+        .build();
+
+    assert_eq!(app.database.get().unwrap().url, "eager");
+    assert_eq!(format!("{:?}", app.database), r#"Lazy(Database { url: "eager" })"#);
+}
+
+#[test]
+fn lazy_default_and_debug() {
+    let lazy: Lazy<Vec<u8>> = Lazy::default();
+    assert_eq!(format!("{lazy:?}"), "Lazy(<uninitialized>)");
+    assert!(lazy.is_empty());
+    assert_eq!(format!("{lazy:?}"), "Lazy([])");
+}
+
+#[test]
+fn lazy_initializer_panic_is_sticky() {
+    let lazy: Lazy<u8> = Lazy::new(|| panic!("initialization failed"));
+
+    let first = std::panic::catch_unwind(|| *lazy.force()).unwrap_err();
+    assert_eq!(first.downcast_ref::<&str>(), Some(&"initialization failed"));
+
+    let second = std::panic::catch_unwind(|| *lazy.force()).unwrap_err();
+    let message = second
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| second.downcast_ref::<&str>().copied())
+        .unwrap();
+    assert!(message.contains("access that panicked"), "{message}");
+    assert!(lazy.get().is_none());
+}
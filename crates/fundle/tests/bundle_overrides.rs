@@ -0,0 +1,80 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![allow(
+    unused_attributes,
+    clippy::empty_structs_with_brackets,
+    clippy::redundant_type_annotations,
+    clippy::items_after_statements,
+    missing_docs,
+    reason = "Unit tests"
+)]
+
+use fundle::{NotSet, Write};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Logger {
+    name: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Database {
+    logger: &'static str,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Metrics {
+    enabled: bool,
+}
+
+#[fundle::bundle]
+struct AppState {
+    logger: Logger,
+    database: Database,
+    #[bundle(default)]
+    metrics: Metrics,
+}
+
+// The builder chain of the application, shared by production code and tests
+fn configure(builder: AppStateBuilder<Write, NotSet, NotSet, NotSet>) -> AppState {
+    builder
+        .logger(|_| Logger { name: "production" })
+        .database(|x| Database { logger: x.logger().name })
+        .build()
+}
+
+#[test]
+fn without_overrides() {
+    let app = configure(AppState::builder());
+
+    assert_eq!(app.logger.name, "production");
+    assert_eq!(app.database.logger, "production");
+    assert!(!app.metrics.enabled);
+}
+
+#[test]
+fn override_substitutes_field_and_is_visible_to_dependents() {
+    let app = configure(AppState::builder().override_logger(Logger { name: "mock" }));
+
+    assert_eq!(app.logger.name, "mock");
+    assert_eq!(app.database.logger, "mock");
+}
+
+#[test]
+fn override_skips_setter_closure() {
+    let app = AppState::builder()
+        .override_database(Database { logger: "mock" })
+        .logger(|_| Logger { name: "production" })
+        .database_try(|_| Err::<Database, _>(std::io::Error::other("not called")))
+        .unwrap()
+        .build();
+
+    assert_eq!(app.database.logger, "mock");
+}
+
+#[test]
+fn override_applies_to_unset_optional_fields() {
+    let app = configure(AppState::builder().override_metrics(Metrics { enabled: true }));
+
+    assert!(app.metrics.enabled);
+}
@@ -0,0 +1,100 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![allow(
+    unused_attributes,
+    clippy::empty_structs_with_brackets,
+    clippy::redundant_type_annotations,
+    clippy::items_after_statements,
+    missing_docs,
+    reason = "Unit tests"
+)]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Debug, Default)]
+pub struct Logger {
+    name: String,
+}
+
+#[derive(Debug, Default)]
+pub struct Database {
+    url: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestId(u64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deadline(Duration);
+
+#[fundle::bundle]
+pub struct AppState {
+    logger: Arc<Logger>,
+    database: Arc<Database>,
+}
+
+#[fundle::bundle(parent = AppState)]
+struct RequestState {
+    #[bundle(from_parent)]
+    logger: Arc<Logger>,
+    request_id: RequestId,
+    #[bundle(from_parent)]
+    database: Arc<Database>,
+    deadline: Deadline,
+}
+
+#[fundle::deps]
+struct HandlerDeps {
+    logger: Arc<Logger>,
+    request_id: RequestId,
+}
+
+fn app() -> AppState {
+    AppState::builder()
+        .logger(|_| Arc::new(Logger { name: "app".to_string() }))
+        .database(|_| {
+            Arc::new(Database {
+                url: "db://localhost".to_string(),
+            })
+        })
+        .build()
+}
+
+#[test]
+fn from_parent_shares_parent_fields() {
+    let app = app();
+    let request = RequestState::from_parent(&app, RequestId(7), Deadline(Duration::from_secs(1)));
+
+    assert!(Arc::ptr_eq(&request.logger, &app.logger));
+    assert!(Arc::ptr_eq(&request.database, &app.database));
+    assert_eq!(request.logger.name, "app");
+    assert_eq!(request.database.url, "db://localhost");
+    assert_eq!(request.request_id, RequestId(7));
+    assert_eq!(request.deadline, Deadline(Duration::from_secs(1)));
+}
+
+#[test]
+fn from_parent_bundle_satisfies_deps() {
+    let app = app();
+    let request = RequestState::from_parent(&app, RequestId(3), Deadline(Duration::from_millis(50)));
+
+    let deps = HandlerDeps::from(&request);
+    assert_eq!(deps.logger.name, "app");
+    assert_eq!(deps.request_id, RequestId(3));
+}
+
+#[test]
+fn from_parent_bundle_keeps_builder() {
+    let app = app();
+    let request = RequestState::builder()
+        .logger(|_| Arc::clone(&app.logger))
+        .database(|_| Arc::new(Database::default()))
+        .request_id(|_| RequestId(1))
+        .deadline(|_| Deadline(Duration::ZERO))
+        .build();
+
+    assert!(Arc::ptr_eq(&request.logger, &app.logger));
+    assert!(!Arc::ptr_eq(&request.database, &app.database));
+}
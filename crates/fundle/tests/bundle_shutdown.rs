@@ -0,0 +1,179 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![allow(
+    unused_attributes,
+    clippy::empty_structs_with_brackets,
+    clippy::redundant_type_annotations,
+    clippy::items_after_statements,
+    missing_docs,
+    reason = "Unit tests"
+)]
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use fundle::Lazy;
+use fundle::shutdown::Shutdown;
+use tick::ClockControl;
+
+type Log = Arc<Mutex<Vec<&'static str>>>;
+
+#[derive(Debug, Clone)]
+pub struct Component {
+    name: &'static str,
+    log: Log,
+    hang: bool,
+}
+
+impl Component {
+    fn new(name: &'static str, log: &Log) -> Self {
+        Self {
+            name,
+            log: Arc::clone(log),
+            hang: false,
+        }
+    }
+
+    fn hanging(name: &'static str, log: &Log) -> Self {
+        Self {
+            hang: true,
+            ..Self::new(name, log)
+        }
+    }
+}
+
+impl Shutdown for Component {
+    async fn shutdown(&self) {
+        self.log.lock().expect("no test panics while holding the log lock").push(self.name);
+        if self.hang {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Config {}
+
+#[fundle::bundle]
+struct AppState {
+    #[bundle(shutdown)]
+    database: Component,
+    config: Config,
+    #[bundle(shutdown)]
+    logger: Lazy<Component>,
+    #[bundle(shutdown, default)]
+    metrics: Option<Component>,
+}
+
+#[test]
+fn shutdown_in_reverse_initialization_order() {
+    let log = Log::default();
+    let clock = ClockControl::new().auto_advance_timers(true).to_clock();
+
+    let app = AppState::builder()
+        .logger(|_| Lazy::from(Component::new("logger", &log)))
+        .config(|_| Config {})
+        .metrics(|_| Some(Component::new("metrics", &log)))
+        .database(|_| Component::new("database", &log))
+        .build();
+
+    futures::executor::block_on(app.shutdown(&clock, Duration::from_secs(1))).unwrap();
+    assert_eq!(*log.lock().unwrap(), ["database", "metrics", "logger"]);
+}
+
+#[test]
+fn shutdown_skips_unset_and_uninitialized_fields() {
+    let log = Log::default();
+    let clock = ClockControl::new().auto_advance_timers(true).to_clock();
+
+    let lazy_log = Arc::clone(&log);
+    let app = AppState::builder()
+        .database(|_| Component::new("database", &log))
+        .logger_lazy(move |_| {
+            let log = Arc::clone(&lazy_log);
+            move || Component::new("logger", &log)
+        })
+        .config(|_| Config {})
+        .build();
+
+    futures::executor::block_on(app.shutdown(&clock, Duration::from_secs(1))).unwrap();
+    assert_eq!(*log.lock().unwrap(), ["database"]);
+}
+
+#[test]
+fn shutdown_reports_timed_out_components() {
+    let log = Log::default();
+    let clock = ClockControl::new().auto_advance_timers(true).to_clock();
+
+    let app = AppState::builder()
+        .database(|_| Component::new("database", &log))
+        .logger(|_| Lazy::from(Component::hanging("logger", &log)))
+        .config(|_| Config {})
+        .build();
+
+    let error = futures::executor::block_on(app.shutdown(&clock, Duration::from_secs(1))).unwrap_err();
+
+    assert_eq!(error.timed_out(), ["logger"]);
+    assert_eq!(error.to_string(), "components timed out during shutdown: logger");
+    assert_eq!(*log.lock().unwrap(), ["logger", "database"]);
+}
+
+#[fundle::bundle]
+pub struct SharedState {
+    config: Arc<Config>,
+}
+
+#[fundle::bundle(parent = SharedState)]
+struct ScopedState {
+    #[bundle(from_parent)]
+    config: Arc<Config>,
+    #[bundle(shutdown)]
+    session: Component,
+    #[bundle(shutdown)]
+    transaction: Component,
+}
+
+#[test]
+fn shutdown_from_parent_bundle_in_reverse_declaration_order() {
+    let log = Log::default();
+    let clock = ClockControl::new().auto_advance_timers(true).to_clock();
+
+    let shared = SharedState::builder().config(|_| Arc::new(Config {})).build();
+    let scoped = ScopedState::from_parent(&shared, Component::new("session", &log), Component::new("transaction", &log));
+
+    futures::executor::block_on(scoped.shutdown(&clock, Duration::from_secs(1))).unwrap();
+    assert_eq!(*log.lock().unwrap(), ["transaction", "session"]);
+}
+
+#[fundle::bundle]
+struct ConditionalState {
+    #[bundle(shutdown)]
+    database: Component,
+    #[cfg(not(test))]
+    #[bundle(shutdown)]
+    telemetry: Component,
+    #[bundle(shutdown, if_enabled)]
+    tracer: Option<Component>,
+}
+
+#[test]
+fn shutdown_skips_compiled_out_and_disabled_fields() {
+    let log = Log::default();
+    let clock = ClockControl::new().auto_advance_timers(true).to_clock();
+
+    let enabled = ConditionalState::builder()
+        .database(|_| Component::new("database", &log))
+        .tracer_if_enabled(true, |_| Component::new("tracer", &log))
+        .build();
+    futures::executor::block_on(enabled.shutdown(&clock, Duration::from_secs(1))).unwrap();
+    assert_eq!(*log.lock().unwrap(), ["tracer", "database"]);
+
+    log.lock().unwrap().clear();
+    let disabled = ConditionalState::builder()
+        .database(|_| Component::new("database", &log))
+        .tracer_if_enabled(false, |_| Component::new("tracer", &log))
+        .build();
+    futures::executor::block_on(disabled.shutdown(&clock, Duration::from_secs(1))).unwrap();
+    assert_eq!(*log.lock().unwrap(), ["database"]);
+}
@@ -0,0 +1,133 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![allow(
+    unused_attributes,
+    clippy::empty_structs_with_brackets,
+    clippy::redundant_type_annotations,
+    clippy::items_after_statements,
+    missing_docs,
+    reason = "Unit tests"
+)]
+
+use std::sync::Arc;
+
+pub trait Log: Send + Sync {
+    fn name(&self) -> &str;
+}
+
+pub trait Store {
+    fn get(&self, key: &str) -> Option<String>;
+}
+
+#[derive(Debug)]
+pub struct ConsoleLog;
+
+impl Log for ConsoleLog {
+    fn name(&self) -> &'static str {
+        "console"
+    }
+}
+
+#[derive(Debug)]
+pub struct MemoryStore;
+
+impl Store for MemoryStore {
+    fn get(&self, key: &str) -> Option<String> {
+        Some(key.to_uppercase())
+    }
+}
+
+#[fundle::bundle]
+pub struct AppState {
+    log: Arc<dyn Log>,
+    store: Arc<dyn Store + Send + Sync>,
+}
+
+#[fundle::deps]
+struct ServiceDeps {
+    log: Arc<dyn Log>,
+    store: Arc<dyn Store + Send + Sync>,
+}
+
+#[fundle::bundle]
+struct StoreBundle {
+    #[forward(Arc<dyn Store + Send + Sync>)]
+    app: AppState,
+    count: u32,
+}
+
+#[fundle::bundle]
+struct ReorderedBounds {
+    store: Arc<dyn Store + Sync + Send>,
+    backup: Arc<dyn Store + Send + Sync + 'static>,
+    log: Arc<dyn Log>,
+}
+
+#[fundle::newtype]
+struct AuditLog(Arc<dyn Log>);
+
+struct Service {
+    deps: ServiceDeps,
+}
+
+impl Service {
+    fn new(deps: impl Into<ServiceDeps>) -> Self {
+        Self { deps: deps.into() }
+    }
+}
+
+#[test]
+fn trait_object_fields() {
+    let app = AppState::builder()
+        .log(|_| Arc::new(ConsoleLog))
+        .store(|x| {
+            let log: &Arc<dyn Log> = x.as_ref();
+            assert_eq!(log.name(), "console");
+            Arc::new(MemoryStore)
+        })
+        .build();
+
+    let service = Service::new(&app);
+    assert_eq!(service.deps.log.name(), "console");
+    assert_eq!(service.deps.store.get("key").as_deref(), Some("KEY"));
+    assert!(Arc::ptr_eq(&service.deps.log, &app.log));
+
+    let audit = AuditLog::from(&app);
+    assert_eq!(audit.name(), "console");
+}
+
+#[test]
+fn trait_object_fields_forwarded() {
+    let bundle = StoreBundle::builder()
+        .app(|_| {
+            AppState::builder()
+                .log(|_| Arc::new(ConsoleLog))
+                .store(|_| Arc::new(MemoryStore))
+                .build()
+        })
+        .count(|x| {
+            let store: &Arc<dyn Store + Send + Sync> = x.as_ref();
+            u32::from(store.get("key").is_some())
+        })
+        .build();
+
+    let store: &Arc<dyn Store + Send + Sync> = bundle.as_ref();
+    assert_eq!(store.get("a").as_deref(), Some("A"));
+    assert_eq!(bundle.count, 1);
+}
+
+#[test]
+fn trait_object_fields_with_reordered_bounds_are_the_same_type() {
+    let bundle = ReorderedBounds::builder()
+        .store(|_| Arc::new(MemoryStore))
+        .backup(|_| Arc::new(MemoryStore))
+        .log(|_| Arc::new(ConsoleLog))
+        .build();
+
+    // Both store fields have the same type, so only the unique log gets an `AsRef` impl
+    let log: &Arc<dyn Log> = bundle.as_ref();
+    assert_eq!(log.name(), "console");
+    assert_eq!(bundle.backup.get("b").as_deref(), Some("B"));
+    assert_eq!(bundle.store.get("s").as_deref(), Some("S"));
+}
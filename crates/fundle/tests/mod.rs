@@ -14,6 +14,8 @@ fn proc() {
     t.pass("tests/proc/bundle_forward.rs");
     t.pass("tests/proc/bundle_simple.rs");
     t.compile_fail("tests/proc/bundle_tupled.rs");
+    t.compile_fail("tests/proc/deps_check.rs");
+    t.compile_fail("tests/proc/deps_missing.rs");
     t.pass("tests/proc/deps_simple.rs");
     t.pass("tests/proc/newtype_simple.rs");
 }
@@ -0,0 +1,32 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#[derive(Clone)]
+pub struct Logger {}
+
+#[derive(Clone)]
+pub struct Config {}
+
+#[derive(Clone)]
+pub struct Telemetry {}
+
+#[fundle::bundle]
+pub struct AppState {
+    logger: Logger,
+}
+
+#[fundle::deps]
+pub struct ServiceDeps {
+    logger: Logger,
+    config: Config,
+    telemetry: Telemetry,
+}
+
+#[fundle::deps]
+pub struct LoggerDeps {
+    logger: Logger,
+}
+
+fundle::check_deps!(AppState => LoggerDeps, ServiceDeps);
+
+fn main() {}
@@ -0,0 +1,26 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#[derive(Clone)]
+pub struct Logger {}
+
+#[derive(Clone)]
+pub struct Config {}
+
+#[fundle::bundle]
+pub struct AppState {
+    logger: Logger,
+}
+
+#[fundle::deps]
+pub struct ServiceDeps {
+    logger: Logger,
+    config: Config,
+}
+
+fn service(_deps: impl Into<ServiceDeps>) {}
+
+fn main() {
+    let app = AppState::builder().logger(|_| Logger {}).build();
+    service(&app);
+}
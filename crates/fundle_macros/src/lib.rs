@@ -66,6 +66,24 @@ use proc_macro::TokenStream;
 /// - `foo_try(|builder| -> Result<T, E>)` - Fallible setter
 /// - `foo_try_async(|builder| async -> Result<T, E>)` - Async fallible setter
 ///
+/// In tests, `override_foo(value)` substitutes the field with `value` before its setter is
+/// called. The builder chain stays the same and still has to call the setter, but the setter
+/// then keeps the override instead of invoking its closure. This allows reusing the builder
+/// chain of an application with mock components:
+///
+/// ```rust,ignore
+/// fn configure(builder: AppStateBuilder<Write, NotSet, NotSet>) -> AppState {
+///     builder.logger(|_| Logger::new()).database(|x| Database::connect(x)).build()
+/// }
+///
+/// let app = configure(AppState::builder().override_logger(Logger::mock()));
+/// ```
+///
+/// For fields of type `fundle::Lazy<T>`, a `foo_lazy(|builder| -> impl FnOnce() -> T)` setter
+/// is generated as well. It stores the returned initializer, which constructs the value the
+/// first time it is accessed. The bundle implements `AsRef<T>` for such fields, forcing their
+/// initialization.
+///
 /// # Select Macro
 ///
 /// Inside setter closures, use the generated `StructName!(select(builder) => Type(field), ...)`
@@ -100,6 +118,133 @@ use proc_macro::TokenStream;
 ///     .build();
 /// ```
 ///
+/// # Optional Fields
+///
+/// Fields marked `#[bundle(default)]` and fields of type `Option<T>` don't need a setter
+/// call before `build()`. Unset fields are initialized with their `Default` value, which is
+/// `None` for `Option<T>`. Later setters can only access optional fields that were set.
+///
+/// ```rust,ignore
+/// # use fundle_proc as fundle;
+/// # #[derive(Default)]
+/// # struct Metrics;
+/// # struct Logger;
+/// # struct Tracer;
+/// #[fundle::bundle]
+/// pub struct AppState {
+///     logger: Logger,
+///     #[bundle(default)]
+///     metrics: Metrics,
+///     tracer: Option<Tracer>,
+/// }
+///
+/// let app = AppState::builder().logger(|_| Logger).build();
+/// ```
+///
+/// # Generic Bundles
+///
+/// Bundles can have lifetime, type, and const parameters, for example to stay generic over a
+/// `Clock` implementation instead of erasing it behind a trait object. The builder and all
+/// generated impls repeat the parameters of the struct along with their bounds, so fields typed
+/// after a parameter get an `AsRef` implementation like any other field:
+///
+/// ```rust,ignore
+/// #[fundle::bundle]
+/// pub struct AppState<C: Clock> {
+///     clock: C,
+///     scheduler: Scheduler,
+/// }
+///
+/// let app = AppState::builder()
+///     .clock(|_| SystemClock::new())
+///     .scheduler(|x| Scheduler::new(x.clock().clone()))
+///     .build();
+/// ```
+///
+/// Parameters can't be named after the builder state of a field, which is the field name in
+/// uppercase, such as `CLOCK` for `clock`.
+///
+/// # Conditional Fields
+///
+/// Fields can be compiled out with `#[cfg(...)]`, for example to gate them behind a feature. The
+/// builder then neither requires nor offers a setter for them, and two fields of the same type
+/// under mutually exclusive conditions, such as `feature = "otel"` and `not(feature = "otel")`,
+/// each get an `AsRef` implementation when they are compiled in.
+///
+/// To decide at runtime instead, mark an `Option<T>` field `#[bundle(if_enabled)]`. Besides the
+/// regular setters, the builder gets a `foo_if_enabled(enabled, |x| ...)` method that only calls
+/// the closure if `enabled` is `true`, and leaves the field `None` otherwise:
+///
+/// ```rust,ignore
+/// #[fundle::bundle]
+/// pub struct AppState {
+///     logger: Logger,
+///     #[cfg(feature = "otel")]
+///     telemetry: Telemetry,
+///     #[bundle(if_enabled)]
+///     tracer: Option<Tracer>,
+/// }
+///
+/// let app = AppState::builder()
+///     .logger(|_| Logger::new())
+///     .tracer_if_enabled(settings.tracing, |x| Tracer::new(x.logger()))
+///     .build();
+/// ```
+///
+/// # Configuration
+///
+/// With the `config` feature of `fundle`, fields marked `#[bundle(config)]` must implement
+/// `serde::Deserialize`, and the builder gets a `with_config(&source)` method. It sets all
+/// marked fields at once by deserializing them from a `fundle::config::ConfigSource`, each from
+/// the section named after the field, so that later setters can read them:
+///
+/// ```rust,ignore
+/// let app = AppState::builder()
+///     .with_config(&config)?
+///     .server(|x| Server::bind(x.http().port))
+///     .build();
+/// ```
+///
+/// # Scoped Bundles
+///
+/// A bundle declared with `#[bundle(parent = AppState)]` gets a
+/// `from_parent(&AppState, extras...)` constructor, for bundles that live for a narrower scope
+/// than the application, such as a single request. Fields marked `#[bundle(from_parent)]` are
+/// cloned from the parent through its `AsRef` implementations, so they are usually `Arc`s shared
+/// with the parent. All other fields are arguments of the constructor, in declaration order.
+///
+/// ```rust,ignore
+/// # use fundle_proc as fundle;
+/// # use std::sync::Arc;
+/// # struct Database;
+/// # struct RequestId;
+/// # struct Deadline;
+/// #[fundle::bundle(parent = AppState)]
+/// pub struct RequestState {
+///     #[bundle(from_parent)]
+///     database: Arc<Database>,
+///     request_id: RequestId,
+///     deadline: Deadline,
+/// }
+///
+/// let request = RequestState::from_parent(&app, request_id, deadline);
+/// ```
+///
+/// # Shutdown
+///
+/// With the `shutdown` feature of `fundle`, fields marked `#[bundle(shutdown)]` must implement
+/// `fundle::shutdown::Shutdown`, and the bundle gets an async
+/// `shutdown(&self, clock, timeout)` method. It shuts the marked fields down in the reverse
+/// order the builder initialized them, giving each of them `timeout` to finish. Bundles with
+/// such fields can only be created with their builder.
+///
+/// # Trait-Object Fields
+///
+/// Fields can be typed `Arc<dyn Trait>`, so that `#[deps]` structs and other consumers depend on
+/// an abstraction instead of a concrete type. Trait objects with the same bounds are the same type
+/// for `AsRef` generation, regardless of the order their bounds are written in, and
+/// `#[forward(Arc<dyn Trait>)]` forwards them like any other type.
+///
 /// # Forward Attribute
 ///
 /// Use `#[forward]` on fields to forward their `AsRef` implementations to the main struct:
@@ -139,7 +284,7 @@ pub fn bundle(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,ignore
 /// # use fundle_macros as fundle;
 /// # #[derive(Clone)]
 /// # struct Config {}
@@ -184,6 +329,13 @@ pub fn bundle(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - All field types must implement `Clone`
 /// - The source type must implement `AsRef<T>` for each field type
 /// - Only works with structs that have named fields
+///
+/// # Diagnostics
+///
+/// The generated `From` implementation requires `AsRef<T>` through a `fundle` helper trait, so
+/// a failed conversion names the missing dependency type. Use
+/// `fundle::check_deps!(AppState => ServiceDeps)` next to a bundle to check that it provides
+/// every dependency of a `deps` struct, with one error per missing type.
 #[proc_macro_attribute]
 #[cfg_attr(test, mutants::skip)]
 #[cfg_attr(coverage_nightly, coverage(off))]
@@ -200,7 +352,7 @@ pub fn deps(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// wrappers around existing types while maintaining easy conversion and access patterns.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// # use fundle_macros as fundle;
 /// # #[derive(Clone)]
 /// # pub struct Logger;
@@ -7,10 +7,12 @@ use std::collections::HashMap;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use syn::parse::Parser;
-use syn::{Attribute, Fields, FieldsNamed, ItemStruct, Path, Type, Visibility, parse2};
+use syn::visit_mut::{self, VisitMut};
+use syn::{Attribute, Fields, FieldsNamed, GenericParam, Generics, ItemStruct, Type, TypeParamBound, TypeTraitObject, Visibility, parse2};
 
 /// Fundle bundle procedural macro implementation
-pub fn bundle(_attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+pub fn bundle(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let bundle_options = parse_bundle_options(attr)?;
     let input: ItemStruct = parse2(item)?;
 
     let struct_name = &input.ident;
@@ -32,6 +34,58 @@ pub fn bundle(_attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream>
         .collect();
     let field_types: Vec<_> = field_info.iter().map(|f| &f.ty).collect();
 
+    // Parse `#[bundle(...)]` field options
+    let field_options = field_info
+        .iter()
+        .map(|field| parse_field_options(&field.attrs))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let optional: Vec<_> = field_options
+        .iter()
+        .zip(&field_types)
+        .map(|(options, ty)| options.default || wrapped_type(ty, "Option").is_some())
+        .collect();
+    let lazy_types: Vec<_> = field_types.iter().map(|ty| wrapped_type(ty, "Lazy")).collect();
+    let shutdown: Vec<_> = field_options.iter().map(|options| options.shutdown).collect();
+    let init_order = InitOrder::new(shutdown.contains(&true));
+    let from_parent: Vec<_> = field_options.iter().map(|options| options.from_parent).collect();
+    let config: Vec<_> = field_options.iter().map(|options| options.config).collect();
+    let if_enabled: Vec<_> = field_options
+        .iter()
+        .zip(&field_types)
+        .map(|(options, ty)| if options.if_enabled { wrapped_type(ty, "Option") } else { None })
+        .collect();
+
+    // `#[cfg(...)]` conditions of each field, repeated on everything generated for the field
+    let cfg_predicates = field_info
+        .iter()
+        .map(|field| parse_cfg_predicate(&field.attrs))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let cfgs: Vec<_> = cfg_predicates
+        .iter()
+        .map(|predicate| predicate.as_ref().map(|predicate| quote!(#[cfg(#predicate)])).unwrap_or_default())
+        .collect();
+
+    for (field, options) in field_info.iter().zip(&field_options) {
+        if options.if_enabled && wrapped_type(&field.ty, "Option").is_none() {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "fundle::bundle `if_enabled` fields must have type `Option<T>`",
+            ));
+        }
+        if options.from_parent && bundle_options.parent.is_none() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "fundle::bundle `from_parent` fields require a parent, declare it with #[bundle(parent = Type)]",
+            ));
+        }
+        if options.from_parent && options.shutdown {
+            return Err(syn::Error::new_spanned(
+                field,
+                "fundle::bundle `from_parent` fields are shut down by their parent, remove the `shutdown` option",
+            ));
+        }
+    }
+
     // Parse forward attributes
     let mut forward_info = Vec::new();
     for (i, field) in field_info.iter().enumerate() {
@@ -46,12 +100,34 @@ pub fn bundle(_attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream>
         .map(|name| Ident::new(&name.to_string().to_uppercase(), name.span()))
         .collect();
 
+    // Generic parameters of the struct, repeated on the builder and all generated impls
+    let generics = BundleGenerics::new(&input.generics);
+    for param in input.generics.type_params() {
+        if let Some(field_name) = type_params
+            .iter()
+            .zip(&field_names)
+            .find_map(|(type_param, name)| (*type_param == param.ident).then_some(name))
+        {
+            return Err(syn::Error::new_spanned(
+                &param.ident,
+                format!(
+                    "fundle::bundle generic parameter `{}` conflicts with the builder state of field `{field_name}`, rename one of them",
+                    param.ident
+                ),
+            ));
+        }
+    }
+    let impl_generics = generics.impl_generics();
+    let ty_generics = generics.ty_generics();
+    let where_clause = generics.where_clause();
+
     // Count occurrences of each type
     let mut type_counts = HashMap::new();
     for field_type in &field_types {
-        let type_string = quote!(#field_type).to_string();
+        let type_string = type_key(field_type);
         *type_counts.entry(type_string).or_insert(0) += 1;
     }
+    let unique_cfgs = unique_type_cfgs(&field_types, &cfg_predicates);
 
     // Generate original struct without forward attributes
     let filtered_fields = field_info.iter().map(|field| {
@@ -59,8 +135,12 @@ pub fn bundle(_attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream>
         let field_type = &field.ty;
         let field_vis = &field.vis;
 
-        // Filter out forward attributes
-        let filtered_attrs: Vec<_> = field.attrs.iter().filter(|attr| !attr.path().is_ident("forward")).collect();
+        // Filter out forward and field option attributes
+        let filtered_attrs: Vec<_> = field
+            .attrs
+            .iter()
+            .filter(|attr| !attr.path().is_ident("forward") && !attr.path().is_ident("bundle"))
+            .collect();
 
         quote! {
             #(#filtered_attrs)*
@@ -70,66 +150,116 @@ pub fn bundle(_attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream>
 
     let struct_vis = &input.vis;
     let struct_attrs = &input.attrs;
+    let struct_generics = &input.generics;
+    let struct_where_clause = &input.generics.where_clause;
+    let init_order_field = init_order.field();
     let original_struct = quote! {
         #(#struct_attrs)*
         #[allow(non_camel_case_types, non_snake_case)]
-        #struct_vis struct #struct_name {
-            #(#filtered_fields),*
+        #struct_vis struct #struct_name #struct_generics #struct_where_clause {
+            #(#filtered_fields,)*
+            #init_order_field
         }
     };
 
     // Generate builder struct
-    let builder_struct = generate_builder_struct(struct_vis, &builder_name, &field_names, &field_types, &type_params);
+    let builder_struct = generate_builder_struct(
+        struct_vis,
+        &builder_name,
+        &generics,
+        &field_names,
+        &field_types,
+        &type_params,
+        &cfgs,
+        &init_order,
+    );
 
     // Generate Default impl for builder
-    let default_impl = generate_default_impl(&builder_name, &field_names, &type_params);
+    let default_impl = generate_default_impl(&builder_name, &generics, &field_names, &type_params, &cfgs, &init_order);
 
     // Generate build method for original struct
-    let struct_build_method = generate_struct_build_method(struct_name, &builder_name, &type_params);
+    let struct_build_method = generate_struct_build_method(struct_name, &builder_name, &generics, &type_params);
 
     // Generate setter methods
-    let setter_impls = generate_setter_impls(&builder_name, &field_names, &field_types, &type_params);
+    let setter_impls = generate_setter_impls(
+        &builder_name,
+        &generics,
+        &field_names,
+        &field_types,
+        &type_params,
+        &cfgs,
+        &lazy_types,
+        &if_enabled,
+        &init_order,
+    );
 
     // Generate AsRef impls for unique types
-    let as_ref_impls = generate_as_ref_impls(&builder_name, &field_names, &field_types, &type_params, &type_counts);
+    let as_ref_impls = generate_as_ref_impls(&builder_name, &generics, &field_names, &field_types, &type_params, &unique_cfgs);
 
     // Generate build method
-    let build_impl = generate_build_impl(&builder_name, struct_name, &field_names, &type_params);
+    let build_impl = generate_build_impl(
+        &builder_name,
+        struct_name,
+        &generics,
+        &field_names,
+        &type_params,
+        &cfg_predicates,
+        &optional,
+        &init_order,
+    );
+
+    // Generate configuration binding method
+    let config_impl = generate_config_impl(&builder_name, &generics, &field_names, &type_params, &cfgs, &config, &init_order);
+
+    // Generate constructor from a parent bundle
+    let from_parent_impl = bundle_options.parent.as_ref().map(|parent| {
+        generate_from_parent_impl(
+            struct_name,
+            &generics,
+            parent,
+            &field_names,
+            &field_types,
+            &cfgs,
+            &from_parent,
+            &init_order,
+        )
+    });
+
+    // Generate shutdown method
+    let shutdown_impl = generate_shutdown_impl(struct_name, &generics, &field_names, &cfgs, &shutdown);
 
     // Generate forwarded AsRef implementations (split into struct and builder parts)
     let (forwarded_struct_as_ref_impls, forwarded_builder_as_ref_impls) =
-        generate_forwarded_as_ref_impls(struct_name, &builder_name, &type_params, &forward_info);
+        generate_forwarded_as_ref_impls(struct_name, &builder_name, &generics, &type_params, &cfgs, &forward_info);
 
     // Generate Export trait implementations
-    let export_impls = generate_export_impls(struct_name, &field_types, &field_names);
+    let export_impls = generate_export_impls(struct_name, &generics, &field_types, &field_names, &cfgs);
 
     // Generate Export trait implementations for builder variants
-    let builder_export_impls = generate_builder_export_impls(&builder_name, &field_names, &field_types, &type_params);
+    let builder_export_impls = generate_builder_export_impls(&builder_name, &generics, &field_names, &field_types, &type_params, &cfgs);
 
     // Generate Writer and Reader trait implementations
-    let reader_writer_impls = generate_reader_writer_impls(&builder_name, &type_params);
+    let reader_writer_impls = generate_reader_writer_impls(&builder_name, &generics, &type_params);
 
     // Generate read() toggle method
-    let read_toggle_impl = generate_read_toggle(&builder_name, &field_names, &type_params);
+    let read_toggle_impl = generate_read_toggle(&builder_name, &generics, &field_names, &type_params, &cfgs, &init_order);
 
     // Generate getter methods for Read mode
-    let reader_getters = generate_reader_getters(&builder_name, &field_names, &field_types, &type_params);
+    let reader_getters = generate_reader_getters(&builder_name, &generics, &field_names, &field_types, &type_params, &cfgs);
 
     // Generate AsRef implementations for unique field types on the main struct
     let main_struct_as_ref_impls = field_names
         .iter()
         .zip(field_types.iter())
-        .filter_map(|(field_name, field_type)| {
-            let type_string = quote!(#field_type).to_string();
-
+        .zip(&unique_cfgs)
+        .filter_map(|((field_name, field_type), unique_cfg)| {
             // Only generate AsRef for types that appear exactly once
-            if type_counts.get(&type_string) != Some(&1) {
-                return None;
-            }
+            let cfg = unique_cfg.as_ref()?;
 
             Some(quote! {
+                #cfg
                 #[allow(non_camel_case_types, non_snake_case, clippy::items_after_statements)]
-                impl ::std::convert::AsRef<#field_type> for #struct_name {
+                impl #impl_generics ::std::convert::AsRef<#field_type> for #struct_name #ty_generics #where_clause {
                     fn as_ref(&self) -> &#field_type {
                         &self.#field_name
                     }
@@ -138,8 +268,35 @@ pub fn bundle(_attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream>
         })
         .collect::<Vec<_>>();
 
+    // Generate AsRef implementations forcing lazy fields with unique value types on the main struct
+    let lazy_as_ref_impls = field_names
+        .iter()
+        .zip(&lazy_types)
+        .zip(&cfgs)
+        .filter_map(|((field_name, lazy_type), cfg)| {
+            let lazy_type = (*lazy_type)?;
+            let type_string = type_key(lazy_type);
+
+            // Skip value types that are also field types, or the values of other lazy fields
+            let lazy_count = lazy_types.iter().flatten().filter(|other| type_key(other) == type_string).count();
+            if type_counts.contains_key(&type_string) || lazy_count != 1 {
+                return None;
+            }
+
+            Some(quote! {
+                #cfg
+                #[allow(non_camel_case_types, non_snake_case, clippy::items_after_statements)]
+                impl #impl_generics ::std::convert::AsRef<#lazy_type> for #struct_name #ty_generics #where_clause {
+                    fn as_ref(&self) -> &#lazy_type {
+                        ::fundle::Lazy::force(&self.#field_name)
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
     // Generate the select macro
-    let select_macro = generate_select_macro(struct_name, &builder_name, &field_names, &field_types, &type_params);
+    let select_macro = generate_select_macro(struct_name, &builder_name, &generics, &field_names, &field_types, &unique_cfgs);
 
     // Create module name (underscore-prefixed struct name)
     let module_name = Ident::new(&format!("_{struct_name}"), struct_name.span());
@@ -149,8 +306,14 @@ pub fn bundle(_attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream>
 
         #struct_build_method
 
+        #from_parent_impl
+
+        #shutdown_impl
+
         #(#main_struct_as_ref_impls)*
 
+        #(#lazy_as_ref_impls)*
+
         #(#forwarded_struct_as_ref_impls)*
 
         #export_impls
@@ -169,6 +332,8 @@ pub fn bundle(_attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream>
 
             #(#setter_impls)*
 
+            #config_impl
+
             #(#reader_getters)*
 
             #(#as_ref_impls)*
@@ -188,36 +353,53 @@ pub fn bundle(_attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream>
     Ok(expanded)
 }
 
+#[expect(clippy::too_many_arguments, reason = "The builder depends on all kinds of field information")]
 #[cfg_attr(test, mutants::skip)]
 fn generate_builder_struct(
     _vis: &Visibility,
     builder_name: &Ident,
+    generics: &BundleGenerics,
     field_names: &[&Ident],
     field_types: &[&Type],
     type_params: &[Ident],
+    cfgs: &[TokenStream],
+    init_order: &InitOrder,
 ) -> proc_macro2::TokenStream {
-    let builder_fields = field_names.iter().zip(field_types.iter()).map(|(name, ty)| {
-        quote! { #name: ::std::option::Option<#ty> }
+    let builder_fields = field_names.iter().zip(field_types.iter()).zip(cfgs).map(|((name, ty), cfg)| {
+        quote! { #cfg #name: ::std::option::Option<#ty> }
     });
 
     let phantom_types = type_params.iter().map(|param| quote!(#param));
+    let init_order_field = init_order.field();
+    let generic_params = &generics.params;
+    let where_clause = generics.where_clause();
 
     quote! {
         #[allow(non_camel_case_types, dead_code, non_snake_case, clippy::items_after_statements)]
-        pub struct #builder_name<RW, #(#type_params),*> {
+        pub struct #builder_name<#(#generic_params,)* RW, #(#type_params),*> #where_clause {
             #(#builder_fields,)*
+            #init_order_field
             _phantom: ::std::marker::PhantomData<(RW, #(#phantom_types),*)>,
         }
     }
 }
 
-fn generate_struct_build_method(struct_name: &Ident, builder_name: &Ident, type_params: &[Ident]) -> proc_macro2::TokenStream {
+fn generate_struct_build_method(
+    struct_name: &Ident,
+    builder_name: &Ident,
+    generics: &BundleGenerics,
+    type_params: &[Ident],
+) -> proc_macro2::TokenStream {
     let not_set_params = type_params.iter().map(|_| quote!(::fundle::NotSet));
+    let impl_generics = generics.impl_generics();
+    let ty_generics = generics.ty_generics();
+    let generic_args = &generics.args;
+    let where_clause = generics.where_clause();
 
     quote! {
         #[allow(non_camel_case_types, non_snake_case, clippy::items_after_statements)]
-        impl #struct_name {
-            pub fn builder() -> #builder_name<::fundle::Write, #(#not_set_params),*> {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            pub fn builder() -> #builder_name<#(#generic_args,)* ::fundle::Write, #(#not_set_params),*> {
                 #builder_name::default()
             }
         }
@@ -225,16 +407,31 @@ fn generate_struct_build_method(struct_name: &Ident, builder_name: &Ident, type_
 }
 
 #[cfg_attr(test, mutants::skip)]
-fn generate_default_impl(builder_name: &Ident, field_names: &[&Ident], type_params: &[Ident]) -> proc_macro2::TokenStream {
+fn generate_default_impl(
+    builder_name: &Ident,
+    generics: &BundleGenerics,
+    field_names: &[&Ident],
+    type_params: &[Ident],
+    cfgs: &[TokenStream],
+    init_order: &InitOrder,
+) -> proc_macro2::TokenStream {
     let not_set_params = type_params.iter().map(|_| quote!(::fundle::NotSet));
-    let none_fields = field_names.iter().map(|name| quote!(#name: ::std::option::Option::None));
+    let none_fields = field_names
+        .iter()
+        .zip(cfgs)
+        .map(|(name, cfg)| quote!(#cfg #name: ::std::option::Option::None));
+    let init_order_value = init_order.empty();
+    let impl_generics = generics.impl_generics();
+    let generic_args = &generics.args;
+    let where_clause = generics.where_clause();
 
     quote! {
         #[allow(non_camel_case_types, non_snake_case, clippy::items_after_statements)]
-        impl ::std::default::Default for #builder_name<::fundle::Write, #(#not_set_params),*> {
+        impl #impl_generics ::std::default::Default for #builder_name<#(#generic_args,)* ::fundle::Write, #(#not_set_params),*> #where_clause {
             fn default() -> Self {
                 Self {
                     #(#none_fields,)*
+                    #init_order_value
                     _phantom: ::std::marker::PhantomData,
                 }
             }
@@ -243,16 +440,27 @@ fn generate_default_impl(builder_name: &Ident, field_names: &[&Ident], type_para
 }
 
 #[expect(clippy::cognitive_complexity, reason = "Complex builder generation logic")]
+#[expect(clippy::too_many_arguments, reason = "Setters depend on all kinds of field information")]
 #[cfg_attr(test, mutants::skip)]
 fn generate_setter_impls(
     builder_name: &Ident,
+    generics: &BundleGenerics,
     field_names: &[&Ident],
     field_types: &[&Type],
     type_params: &[Ident],
+    cfgs: &[TokenStream],
+    lazy_types: &[Option<&Type>],
+    if_enabled: &[Option<&Type>],
+    init_order: &InitOrder,
 ) -> Vec<proc_macro2::TokenStream> {
     let mut impls = Vec::new();
+    let generic_params = &generics.params;
+    let generic_args = &generics.args;
+    let where_clause = generics.where_clause();
 
     for (i, (field_name, field_type)) in field_names.iter().zip(field_types.iter()).enumerate() {
+        let cfg = &cfgs[i];
+
         // Create type parameter list with current one as NotSet, others as generic
         let impl_params: Vec<_> = type_params
             .iter()
@@ -278,60 +486,81 @@ fn generate_setter_impls(
         let try_method_name = Ident::new(&format!("{field_name}_try"), field_name.span());
         let try_async_method_name = Ident::new(&format!("{field_name}_try_async"), field_name.span());
         let async_method_name = Ident::new(&format!("{field_name}_async"), field_name.span());
+        let override_method_name = Ident::new(&format!("override_{field_name}"), field_name.span());
 
         // Field assignments for rebuilding (using read.field instead of self.field)
         let field_assignments_from_read: Vec<_> = field_names
             .iter()
+            .zip(cfgs)
             .enumerate()
-            .map(|(j, name)| {
+            .map(|(j, (name, cfg))| {
                 if i == j {
                     quote!(#name: ::std::option::Option::Some(#field_name))
                 } else {
-                    quote!(#name: read.#name)
+                    quote!(#cfg #name: read.#name)
                 }
             })
+            .chain(init_order.push(&quote!(read), i))
             .collect();
 
         // Regular setter
         let setter = quote! {
+            #cfg
             #[allow(non_camel_case_types, non_snake_case)]
-            impl<#(#other_params),*> #builder_name<::fundle::Write, #(#impl_params),*> {
-                pub fn #field_name(self, f: impl ::std::ops::Fn(&<Self as ::fundle::Writer>::Reader) -> #field_type) -> #builder_name<::fundle::Write, #(#return_params),*> {
-                    let read = self.read();
-                    let #field_name = f(&read);
+            impl<#(#generic_params,)* #(#other_params),*> #builder_name<#(#generic_args,)* ::fundle::Write, #(#impl_params),*> #where_clause {
+                pub fn #override_method_name(mut self, value: #field_type) -> Self {
+                    self.#field_name = ::std::option::Option::Some(value);
+                    self
+                }
+
+                pub fn #field_name(self, f: impl ::std::ops::Fn(&<Self as ::fundle::Writer>::Reader) -> #field_type) -> #builder_name<#(#generic_args,)* ::fundle::Write, #(#return_params),*> {
+                    let mut read = self.read();
+                    let #field_name = match read.#field_name.take() {
+                        ::std::option::Option::Some(overridden) => overridden,
+                        ::std::option::Option::None => f(&read),
+                    };
                     #builder_name {
                         #(#field_assignments_from_read,)*
                         _phantom: ::std::marker::PhantomData,
                     }
                 }
 
-                pub fn #try_method_name<R: ::std::error::Error>(self, f: impl ::std::ops::Fn(&<Self as ::fundle::Writer>::Reader) -> ::std::result::Result<#field_type, R>) -> ::std::result::Result<#builder_name<::fundle::Write, #(#return_params),*>, R> {
-                    let read = self.read();
-                    let #field_name = f(&read)?;
+                pub fn #try_method_name<__FundleE: ::std::error::Error>(self, f: impl ::std::ops::Fn(&<Self as ::fundle::Writer>::Reader) -> ::std::result::Result<#field_type, __FundleE>) -> ::std::result::Result<#builder_name<#(#generic_args,)* ::fundle::Write, #(#return_params),*>, __FundleE> {
+                    let mut read = self.read();
+                    let #field_name = match read.#field_name.take() {
+                        ::std::option::Option::Some(overridden) => overridden,
+                        ::std::option::Option::None => f(&read)?,
+                    };
                     ::std::result::Result::Ok(#builder_name {
                         #(#field_assignments_from_read,)*
                         _phantom: ::std::marker::PhantomData,
                     })
                 }
 
-                pub async fn #try_async_method_name<F, R: ::std::error::Error>(self, f: F) -> ::std::result::Result<#builder_name<::fundle::Write, #(#return_params),*>, R>
+                pub async fn #try_async_method_name<__FundleF, __FundleE: ::std::error::Error>(self, f: __FundleF) -> ::std::result::Result<#builder_name<#(#generic_args,)* ::fundle::Write, #(#return_params),*>, __FundleE>
                 where
-                    F: AsyncFn(&<Self as ::fundle::Writer>::Reader) -> ::std::result::Result<#field_type, R>,
+                    __FundleF: AsyncFn(&<Self as ::fundle::Writer>::Reader) -> ::std::result::Result<#field_type, __FundleE>,
                 {
-                    let read = self.read();
-                    let #field_name = f(&read).await?;
+                    let mut read = self.read();
+                    let #field_name = match read.#field_name.take() {
+                        ::std::option::Option::Some(overridden) => overridden,
+                        ::std::option::Option::None => f(&read).await?,
+                    };
                     ::std::result::Result::Ok(#builder_name {
                         #(#field_assignments_from_read,)*
                         _phantom: ::std::marker::PhantomData,
                     })
                 }
 
-                pub async fn #async_method_name<F>(self, f: F) -> #builder_name<::fundle::Write, #(#return_params),*>
+                pub async fn #async_method_name<__FundleF>(self, f: __FundleF) -> #builder_name<#(#generic_args,)* ::fundle::Write, #(#return_params),*>
                 where
-                    F: AsyncFn(&<Self as ::fundle::Writer>::Reader) -> #field_type,
+                    __FundleF: AsyncFn(&<Self as ::fundle::Writer>::Reader) -> #field_type,
                 {
-                    let read = self.read();
-                    let #field_name = f(&read).await;
+                    let mut read = self.read();
+                    let #field_name = match read.#field_name.take() {
+                        ::std::option::Option::Some(overridden) => overridden,
+                        ::std::option::Option::None => f(&read).await,
+                    };
                     #builder_name {
                         #(#field_assignments_from_read,)*
                         _phantom: ::std::marker::PhantomData,
@@ -341,6 +570,55 @@ fn generate_setter_impls(
         };
 
         impls.extend([setter]);
+
+        // Conditional setter, only invoking the closure if the field is enabled
+        if let Some(enabled_type) = if_enabled[i] {
+            let if_enabled_method_name = Ident::new(&format!("{field_name}_if_enabled"), field_name.span());
+
+            impls.push(quote! {
+                #cfg
+                #[allow(non_camel_case_types, non_snake_case)]
+                impl<#(#generic_params,)* #(#other_params),*> #builder_name<#(#generic_args,)* ::fundle::Write, #(#impl_params),*> #where_clause {
+                    pub fn #if_enabled_method_name(self, enabled: bool, f: impl ::std::ops::Fn(&<Self as ::fundle::Writer>::Reader) -> #enabled_type) -> #builder_name<#(#generic_args,)* ::fundle::Write, #(#return_params),*> {
+                        let mut read = self.read();
+                        let #field_name = match read.#field_name.take() {
+                            ::std::option::Option::Some(overridden) => overridden,
+                            ::std::option::Option::None => enabled.then(|| f(&read)),
+                        };
+                        #builder_name {
+                            #(#field_assignments_from_read,)*
+                            _phantom: ::std::marker::PhantomData,
+                        }
+                    }
+                }
+            });
+        }
+
+        // Lazy setter, storing the initializer returned by the closure
+        if let Some(lazy_type) = lazy_types[i] {
+            let lazy_method_name = Ident::new(&format!("{field_name}_lazy"), field_name.span());
+
+            impls.push(quote! {
+                #cfg
+                #[allow(non_camel_case_types, non_snake_case)]
+                impl<#(#generic_params,)* #(#other_params),*> #builder_name<#(#generic_args,)* ::fundle::Write, #(#impl_params),*> #where_clause {
+                    pub fn #lazy_method_name<__FundleI>(self, f: impl ::std::ops::Fn(&<Self as ::fundle::Writer>::Reader) -> __FundleI) -> #builder_name<#(#generic_args,)* ::fundle::Write, #(#return_params),*>
+                    where
+                        __FundleI: ::std::ops::FnOnce() -> #lazy_type + ::std::marker::Send + 'static,
+                    {
+                        let mut read = self.read();
+                        let #field_name = match read.#field_name.take() {
+                            ::std::option::Option::Some(overridden) => overridden,
+                            ::std::option::Option::None => ::fundle::Lazy::new(f(&read)),
+                        };
+                        #builder_name {
+                            #(#field_assignments_from_read,)*
+                            _phantom: ::std::marker::PhantomData,
+                        }
+                    }
+                }
+            });
+        }
     }
 
     impls
@@ -349,18 +627,20 @@ fn generate_setter_impls(
 #[cfg_attr(test, mutants::skip)]
 fn generate_as_ref_impls(
     builder_name: &Ident,
+    generics: &BundleGenerics,
     field_names: &[&Ident],
     field_types: &[&Type],
     type_params: &[Ident],
-    #[expect(clippy::used_underscore_binding, reason = "Parameter used conditionally")] _type_counts: &HashMap<String, usize>,
+    unique_cfgs: &[Option<TokenStream>],
 ) -> Vec<proc_macro2::TokenStream> {
     let mut impls = Vec::new();
+    let generic_params = &generics.params;
+    let generic_args = &generics.args;
+    let where_clause = generics.where_clause();
 
     for (i, (field_name, field_type)) in field_names.iter().zip(field_types.iter()).enumerate() {
-        let type_string = quote!(#field_type).to_string();
-
         // Only generate AsRef for types that appear exactly once
-        if _type_counts.get(&type_string) == Some(&1) {
+        if let Some(cfg) = &unique_cfgs[i] {
             // Create type parameter list with current one as Set, others as generic
             let impl_params: Vec<_> = type_params
                 .iter()
@@ -376,8 +656,9 @@ fn generate_as_ref_impls(
                 .collect();
 
             let as_ref_impl = quote! {
+                #cfg
                 #[allow(non_camel_case_types, non_snake_case, clippy::items_after_statements)]
-                impl<RW, #(#other_params),*> ::std::convert::AsRef<#field_type> for #builder_name<RW, #(#impl_params),*> {
+                impl<#(#generic_params,)* RW, #(#other_params),*> ::std::convert::AsRef<#field_type> for #builder_name<#(#generic_args,)* RW, #(#impl_params),*> #where_clause {
                     fn as_ref(&self) -> &#field_type {
                         self.#field_name.as_ref().unwrap()
                     }
@@ -392,40 +673,618 @@ fn generate_as_ref_impls(
 }
 
 #[cfg_attr(test, mutants::skip)]
+#[expect(clippy::too_many_arguments, reason = "Building depends on all kinds of field information")]
 fn generate_build_impl(
     builder_name: &Ident,
     struct_name: &Ident,
+    generics: &BundleGenerics,
     field_names: &[&Ident],
     type_params: &[Ident],
+    cfg_predicates: &[Option<TokenStream>],
+    optional: &[bool],
+    init_order: &InitOrder,
 ) -> proc_macro2::TokenStream {
-    let set_params: Vec<_> = type_params.iter().map(|_| quote!(::fundle::Set)).collect();
-    let field_moves: Vec<_> = field_names.iter().map(|name| quote!(#name: self.#name.unwrap())).collect();
+    let cfgs: Vec<_> = cfg_predicates
+        .iter()
+        .map(|predicate| predicate.as_ref().map(|predicate| quote!(#[cfg(#predicate)])))
+        .collect();
+
+    // Required fields that are compiled out can't be set, so `build()` only requires them to be
+    // set through a marker trait implemented for any state if they are compiled out
+    let required_traits: Vec<_> = field_names
+        .iter()
+        .zip(cfg_predicates)
+        .zip(optional)
+        .map(|((name, predicate), optional)| {
+            predicate
+                .as_ref()
+                .filter(|_| !optional)
+                .map(|predicate| (Ident::new(&format!("__fundle_required_{name}"), name.span()), predicate))
+        })
+        .collect();
+    let required_trait_items = required_traits.iter().flatten().map(|(trait_name, predicate)| {
+        quote! {
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            pub trait #trait_name {}
+
+            #[cfg(#predicate)]
+            impl #trait_name for ::fundle::Set {}
+
+            #[cfg(not(#predicate))]
+            impl<T> #trait_name for T {}
+        }
+    });
+
+    // Optional fields may be left unset, so they stay generic
+    let build_params: Vec<_> = type_params
+        .iter()
+        .zip(optional)
+        .zip(&required_traits)
+        .map(|((param, optional), required)| {
+            if *optional || required.is_some() {
+                quote!(#param)
+            } else {
+                quote!(::fundle::Set)
+            }
+        })
+        .collect();
+    let generic_params: Vec<_> = type_params
+        .iter()
+        .zip(optional)
+        .zip(&required_traits)
+        .filter_map(|((param, optional), required)| match required {
+            Some((trait_name, _)) => Some(quote!(#param: #trait_name)),
+            None => optional.then(|| quote!(#param)),
+        })
+        .collect();
+    let field_moves: Vec<_> = field_names
+        .iter()
+        .zip(optional)
+        .zip(&cfgs)
+        .map(|((name, optional), cfg)| {
+            if *optional {
+                quote!(#cfg #name: self.#name.unwrap_or_default())
+            } else {
+                quote!(#cfg #name: self.#name.unwrap())
+            }
+        })
+        .collect();
+
+    // Optional fields left unset are initialized last, by `build()` itself
+    let init_order_build = init_order.enabled.then(|| {
+        let unset_optional = field_names
+            .iter()
+            .zip(optional)
+            .zip(&cfgs)
+            .enumerate()
+            .filter(|(_, ((_, optional), _))| **optional)
+            .map(|(i, (_, cfg))| quote!(#cfg if !__fundle_init_order.contains(&#i) { __fundle_init_order.push(#i); }));
+
+        quote! {
+            let mut __fundle_init_order = self.__fundle_init_order;
+            #(#unset_optional)*
+        }
+    });
+    let init_order_move = init_order.enabled.then(|| quote!(__fundle_init_order,));
+    let struct_generic_params = &generics.params;
+    let ty_generics = generics.ty_generics();
+    let generic_args = &generics.args;
+    let where_clause = generics.where_clause();
 
     quote! {
+        #(#required_trait_items)*
+
         #[allow(non_camel_case_types, non_snake_case, clippy::items_after_statements)]
-        impl #builder_name<::fundle::Write, #(#set_params),*> {
-            pub fn build(self) -> #struct_name {
+        impl<#(#struct_generic_params,)* #(#generic_params),*> #builder_name<#(#generic_args,)* ::fundle::Write, #(#build_params),*> #where_clause {
+            pub fn build(self) -> #struct_name #ty_generics {
+                #init_order_build
                 #struct_name {
-                    #(#field_moves),*
+                    #(#field_moves,)*
+                    #init_order_move
+                }
+            }
+        }
+    }
+}
+
+#[cfg_attr(test, mutants::skip)]
+fn generate_config_impl(
+    builder_name: &Ident,
+    generics: &BundleGenerics,
+    field_names: &[&Ident],
+    type_params: &[Ident],
+    cfgs: &[TokenStream],
+    config: &[bool],
+    init_order: &InitOrder,
+) -> Option<TokenStream> {
+    if !config.contains(&true) {
+        return None;
+    }
+
+    let impl_params = type_params
+        .iter()
+        .zip(config)
+        .map(|(param, config)| if *config { quote!(::fundle::NotSet) } else { quote!(#param) });
+    let return_params = type_params
+        .iter()
+        .zip(config)
+        .map(|(param, config)| if *config { quote!(::fundle::Set) } else { quote!(#param) });
+    let other_params = type_params
+        .iter()
+        .zip(config)
+        .filter_map(|(param, config)| (!config).then_some(param));
+
+    let config_fields: Vec<_> = field_names
+        .iter()
+        .zip(cfgs)
+        .zip(config)
+        .filter_map(|(field, config)| config.then_some(field))
+        .collect();
+    let sections = config_fields.iter().map(|(name, cfg)| {
+        let key = name.to_string();
+        quote! {
+            #cfg
+            let #name = match self.#name {
+                ::std::option::Option::Some(overridden) => overridden,
+                ::std::option::Option::None => ::fundle::config::ConfigSource::section(source, #key)?,
+            };
+        }
+    });
+    let config_indexes: Vec<_> = config.iter().enumerate().filter_map(|(i, config)| config.then_some(i)).collect();
+    let field_assignments = field_names
+        .iter()
+        .zip(cfgs)
+        .zip(config)
+        .map(|((name, cfg), config)| {
+            if *config {
+                quote!(#cfg #name: ::std::option::Option::Some(#name))
+            } else {
+                quote!(#cfg #name: self.#name)
+            }
+        })
+        .chain(init_order.extend(&quote!(self), &config_indexes));
+    let generic_params = &generics.params;
+    let generic_args = &generics.args;
+    let where_clause = generics.where_clause();
+
+    Some(quote! {
+        #[allow(non_camel_case_types, non_snake_case)]
+        impl<#(#generic_params,)* #(#other_params),*> #builder_name<#(#generic_args,)* ::fundle::Write, #(#impl_params),*> #where_clause {
+            /// Sets the fields marked `#[bundle(config)]` by deserializing them from `source`, each
+            /// from the section named after the field.
+            ///
+            /// # Errors
+            ///
+            /// If a section can't be deserialized.
+            pub fn with_config(self, source: &impl ::fundle::config::ConfigSource) -> ::std::result::Result<#builder_name<#(#generic_args,)* ::fundle::Write, #(#return_params),*>, ::fundle::config::ConfigError> {
+                #(#sections)*
+                ::std::result::Result::Ok(#builder_name {
+                    #(#field_assignments,)*
+                    _phantom: ::std::marker::PhantomData,
+                })
+            }
+        }
+    })
+}
+
+/// Tracks the order in which the builder initializes fields, needed to shut them down in reverse.
+struct InitOrder {
+    enabled: bool,
+}
+
+impl InitOrder {
+    const fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    /// The field holding the indexes of initialized fields, in the order they were initialized.
+    fn field(&self) -> Option<TokenStream> {
+        self.enabled.then(|| {
+            quote! {
+                #[doc(hidden)]
+                __fundle_init_order: ::std::vec::Vec<usize>,
+            }
+        })
+    }
+
+    fn empty(&self) -> Option<TokenStream> {
+        self.enabled.then(|| quote!(__fundle_init_order: ::std::vec::Vec::new(),))
+    }
+
+    fn forward(&self) -> Option<TokenStream> {
+        self.enabled.then(|| quote!(__fundle_init_order: self.__fundle_init_order))
+    }
+
+    /// Moves the order out of `source`, recording that field `index` was initialized.
+    fn push(&self, source: &TokenStream, index: usize) -> Option<TokenStream> {
+        self.enabled.then(|| {
+            quote! {
+                __fundle_init_order: {
+                    let mut init_order = #source.__fundle_init_order;
+                    init_order.push(#index);
+                    init_order
+                }
+            }
+        })
+    }
+
+    /// Moves the order out of `source`, recording that the fields `indexes` were initialized.
+    fn extend(&self, source: &TokenStream, indexes: &[usize]) -> Option<TokenStream> {
+        self.enabled.then(|| {
+            quote! {
+                __fundle_init_order: {
+                    let mut init_order = #source.__fundle_init_order;
+                    init_order.extend([#(#indexes),*]);
+                    init_order
+                }
+            }
+        })
+    }
+}
+
+#[cfg_attr(test, mutants::skip)]
+fn generate_shutdown_impl(
+    struct_name: &Ident,
+    generics: &BundleGenerics,
+    field_names: &[&Ident],
+    cfgs: &[TokenStream],
+    shutdown: &[bool],
+) -> Option<TokenStream> {
+    if !shutdown.contains(&true) {
+        return None;
+    }
+
+    let components = field_names
+        .iter()
+        .zip(cfgs)
+        .zip(shutdown)
+        .enumerate()
+        .filter(|(_, (_, shutdown))| **shutdown)
+        .map(|(i, ((name, cfg), _))| {
+            let name_string = name.to_string();
+            quote!(#cfg #i => sequence.component(#name_string, &self.#name).await,)
+        });
+    let impl_generics = generics.impl_generics();
+    let ty_generics = generics.ty_generics();
+    let where_clause = generics.where_clause();
+
+    Some(quote! {
+        #[allow(non_camel_case_types, non_snake_case, clippy::items_after_statements)]
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            /// Shuts down the fields marked `#[bundle(shutdown)]`, in reverse initialization order.
+            ///
+            /// Each field gets `timeout` to shut down. Fields that exceed it are abandoned so
+            /// the remaining fields still get their turn.
+            ///
+            /// # Errors
+            ///
+            /// Reports the fields that timed out, after all other fields were shut down.
+            pub async fn shutdown(&self, clock: &::fundle::shutdown::Clock, timeout: ::std::time::Duration) -> ::std::result::Result<(), ::fundle::shutdown::ShutdownError> {
+                let mut sequence = ::fundle::shutdown::ShutdownSequence::new(clock, timeout);
+                for index in self.__fundle_init_order.iter().rev() {
+                    match index {
+                        #(#components)*
+                        _ => {}
+                    }
+                }
+                sequence.finish()
+            }
+        }
+    })
+}
+
+#[cfg_attr(test, mutants::skip)]
+#[expect(clippy::too_many_arguments, reason = "The constructor depends on all kinds of field information")]
+fn generate_from_parent_impl(
+    struct_name: &Ident,
+    generics: &BundleGenerics,
+    parent: &Type,
+    field_names: &[&Ident],
+    field_types: &[&Type],
+    cfgs: &[TokenStream],
+    from_parent: &[bool],
+    init_order: &InitOrder,
+) -> TokenStream {
+    let (inherited, extras): (Vec<_>, Vec<_>) = field_names
+        .iter()
+        .zip(field_types)
+        .zip(cfgs)
+        .zip(from_parent)
+        .partition(|(_, from_parent)| **from_parent);
+
+    let inherited_fields = inherited.iter().map(
+        |(((name, ty), cfg), _)| quote!(#cfg #name: ::std::clone::Clone::clone(<#parent as ::std::convert::AsRef<#ty>>::as_ref(parent))),
+    );
+    let extra_params = extras.iter().map(|(((name, ty), cfg), _)| quote!(#cfg #name: #ty));
+    let extra_fields = extras.iter().map(|(((name, _), cfg), _)| quote!(#cfg #name));
+
+    // Fields are considered initialized in declaration order
+    let field_count = field_names.len();
+    let init_order_value = init_order
+        .enabled
+        .then(|| quote!(__fundle_init_order: (0..#field_count).collect(),));
+    let impl_generics = generics.impl_generics();
+    let ty_generics = generics.ty_generics();
+    let where_clause = generics.where_clause();
+
+    quote! {
+        #[allow(non_camel_case_types, non_snake_case, clippy::items_after_statements, clippy::too_many_arguments)]
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            /// Creates the bundle for a narrower scope of `parent`.
+            ///
+            /// Fields marked `#[bundle(from_parent)]` are cloned from `parent`, all other fields
+            /// are given as arguments, in declaration order.
+            pub fn from_parent(parent: &#parent, #(#extra_params),*) -> Self {
+                Self {
+                    #(#inherited_fields,)*
+                    #(#extra_fields,)*
+                    #init_order_value
                 }
             }
         }
     }
 }
 
+/// Generic parameters of a bundle, which the builder and all generated impls repeat.
+struct BundleGenerics {
+    /// Declarations of the parameters, with their bounds but without defaults.
+    params: Vec<TokenStream>,
+    /// The parameters as arguments, for example `'a` and `C` of `<'a, C: Clock>`.
+    args: Vec<TokenStream>,
+    /// Predicates of the `where` clause.
+    predicates: Vec<TokenStream>,
+}
+
+impl BundleGenerics {
+    fn new(generics: &Generics) -> Self {
+        let (params, args) = generics
+            .params
+            .iter()
+            .map(|param| match param {
+                GenericParam::Lifetime(param) => {
+                    let lifetime = &param.lifetime;
+                    (quote!(#param), quote!(#lifetime))
+                }
+                GenericParam::Type(param) => {
+                    let ident = &param.ident;
+                    let param = syn::TypeParam {
+                        eq_token: None,
+                        default: None,
+                        ..param.clone()
+                    };
+                    (quote!(#param), quote!(#ident))
+                }
+                GenericParam::Const(param) => {
+                    let ident = &param.ident;
+                    let param = syn::ConstParam {
+                        eq_token: None,
+                        default: None,
+                        ..param.clone()
+                    };
+                    (quote!(#param), quote!(#ident))
+                }
+            })
+            .unzip();
+        let predicates = generics
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| &where_clause.predicates)
+            .map(|predicate| quote!(#predicate))
+            .collect();
+
+        Self { params, args, predicates }
+    }
+
+    /// The parameters of impls on the bundle itself, empty if it is not generic.
+    fn impl_generics(&self) -> Option<TokenStream> {
+        let params = &self.params;
+        (!params.is_empty()).then(|| quote!(<#(#params),*>))
+    }
+
+    /// The arguments of the bundle type, empty if it is not generic.
+    fn ty_generics(&self) -> Option<TokenStream> {
+        let args = &self.args;
+        (!args.is_empty()).then(|| quote!(<#(#args),*>))
+    }
+
+    fn where_clause(&self) -> Option<TokenStream> {
+        let predicates = &self.predicates;
+        (!predicates.is_empty()).then(|| quote!(where #(#predicates),*))
+    }
+}
+
+/// Options of a bundle, given with `#[bundle(...)]` on the struct.
+#[derive(Debug, Default)]
+struct BundleOptions {
+    /// The bundle `from_parent` fields are cloned from.
+    parent: Option<Type>,
+}
+
 #[cfg_attr(test, mutants::skip)]
-fn parse_forward_attribute(attrs: &[Attribute]) -> syn::Result<Option<Vec<Path>>> {
+fn parse_bundle_options(attr: TokenStream) -> syn::Result<BundleOptions> {
+    let mut options = BundleOptions::default();
+
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("parent") {
+            if options.parent.is_some() {
+                return Err(meta.error("fundle::bundle duplicate `parent` option"));
+            }
+            options.parent = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("fundle::bundle unknown option, expected `parent`"))
+        }
+    });
+    parser.parse2(attr)?;
+
+    Ok(options)
+}
+
+/// Options of a bundle field, given with `#[bundle(...)]`.
+#[derive(Debug, Default)]
+#[expect(clippy::struct_excessive_bools, reason = "Field options are independent flags")]
+struct FieldOptions {
+    /// The field is deserialized from a configuration source by the generated `with_config()`.
+    config: bool,
+    /// The field is set to its `Default` value if no setter is called.
+    default: bool,
+    /// The field of type `Option<T>` gets a setter only invoking its closure if enabled.
+    if_enabled: bool,
+    /// The field is cloned from the parent bundle by the generated `from_parent()` constructor.
+    from_parent: bool,
+    /// The field is shut down by the generated `shutdown()` method.
+    shutdown: bool,
+}
+
+#[cfg_attr(test, mutants::skip)]
+fn parse_field_options(attrs: &[Attribute]) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("bundle")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("config") {
+                if options.config {
+                    return Err(meta.error("fundle::bundle duplicate `config` field option"));
+                }
+                options.config = true;
+                Ok(())
+            } else if meta.path.is_ident("default") {
+                if options.default {
+                    return Err(meta.error("fundle::bundle duplicate `default` field option"));
+                }
+                options.default = true;
+                Ok(())
+            } else if meta.path.is_ident("from_parent") {
+                if options.from_parent {
+                    return Err(meta.error("fundle::bundle duplicate `from_parent` field option"));
+                }
+                options.from_parent = true;
+                Ok(())
+            } else if meta.path.is_ident("if_enabled") {
+                if options.if_enabled {
+                    return Err(meta.error("fundle::bundle duplicate `if_enabled` field option"));
+                }
+                options.if_enabled = true;
+                Ok(())
+            } else if meta.path.is_ident("shutdown") {
+                if options.shutdown {
+                    return Err(meta.error("fundle::bundle duplicate `shutdown` field option"));
+                }
+                options.shutdown = true;
+                Ok(())
+            } else {
+                Err(meta
+                    .error("fundle::bundle unknown field option, expected `config`, `default`, `from_parent`, `if_enabled`, or `shutdown`"))
+            }
+        })?;
+    }
+
+    Ok(options)
+}
+
+/// Returns the condition of the `#[cfg(...)]` attributes of a field, if it has any.
+#[cfg_attr(test, mutants::skip)]
+fn parse_cfg_predicate(attrs: &[Attribute]) -> syn::Result<Option<TokenStream>> {
+    let predicates = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .map(|attr| Ok(attr.meta.require_list()?.tokens.clone()))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(match predicates.as_slice() {
+        [] => None,
+        [predicate] => Some(predicate.clone()),
+        predicates => Some(quote!(all(#(#predicates),*))),
+    })
+}
+
+/// Returns the `#[cfg(...)]` attribute under which each field has a unique type, if it can have one.
+///
+/// Fields of the same type under mutually exclusive conditions, such as `cfg(feature = "a")` and
+/// `cfg(not(feature = "a"))`, are each unique when they are compiled in.
+fn unique_type_cfgs(field_types: &[&Type], cfg_predicates: &[Option<TokenStream>]) -> Vec<Option<TokenStream>> {
+    let type_keys: Vec<_> = field_types.iter().map(|ty| type_key(ty)).collect();
+
+    (0..field_types.len())
+        .map(|i| {
+            let others: Vec<_> = (0..field_types.len())
+                .filter(|&j| j != i && type_keys[j] == type_keys[i])
+                .map(|j| cfg_predicates[j].as_ref())
+                .collect::<Option<_>>()?;
+            let predicate = cfg_predicates[i].iter();
+
+            Some(match (cfg_predicates[i].is_some(), others.is_empty()) {
+                (false, true) => quote!(),
+                (true, true) => quote!(#[cfg(#(#predicate)*)]),
+                (_, false) => quote!(#[cfg(all(#(#predicate,)* not(any(#(#others),*))))]),
+            })
+        })
+        .collect()
+}
+
+/// Returns a string identifying the type, used to find fields of the same type.
+///
+/// Trait objects are normalized, as their bounds can be spelled in any order, and with or
+/// without the default `'static` lifetime.
+fn type_key(ty: &Type) -> String {
+    struct Normalize;
+
+    impl VisitMut for Normalize {
+        fn visit_type_trait_object_mut(&mut self, i: &mut TypeTraitObject) {
+            visit_mut::visit_type_trait_object_mut(self, i);
+
+            let mut bounds: Vec<_> = i
+                .bounds
+                .iter()
+                .filter(|bound| !matches!(bound, TypeParamBound::Lifetime(lifetime) if lifetime.ident == "static"))
+                .cloned()
+                .collect();
+            bounds.sort_by_cached_key(|bound| quote!(#bound).to_string());
+
+            i.dyn_token = Some(syn::Token![dyn](proc_macro2::Span::call_site()));
+            i.bounds = bounds.into_iter().collect();
+        }
+    }
+
+    let mut ty = ty.clone();
+    Normalize.visit_type_mut(&mut ty);
+    quote!(#ty).to_string()
+}
+
+/// Returns `T` if the type is spelled as `Wrapper<T>`, for example `Option<T>` or `Lazy<T>`.
+fn wrapped_type<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    if type_path.qself.is_some() {
+        return None;
+    }
+
+    let segment = type_path.path.segments.last()?;
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+
+    match (segment.ident == wrapper, arguments.args.first(), arguments.args.len()) {
+        (true, Some(syn::GenericArgument::Type(inner)), 1) => Some(inner),
+        _ => None,
+    }
+}
+
+#[cfg_attr(test, mutants::skip)]
+fn parse_forward_attribute(attrs: &[Attribute]) -> syn::Result<Option<Vec<Type>>> {
     for attr in attrs {
         if attr.path().is_ident("forward")
             && let Ok(meta_list) = attr.meta.require_list()
         {
             let tokens = &meta_list.tokens;
             // Parse as a comma-separated list using syn's punctuated parsing
-            let parser = syn::punctuated::Punctuated::<Path, syn::Token![,]>::parse_terminated;
+            let parser = syn::punctuated::Punctuated::<Type, syn::Token![,]>::parse_terminated;
             let punctuated = parser.parse2(tokens.clone()).map_err(|e| {
                 syn::Error::new_spanned(
                     attr,
-                    format!("fundle::bundle #[forward(...)] attribute must contain valid type paths: {e}"),
+                    format!("fundle::bundle #[forward(...)] attribute must contain valid types: {e}"),
                 )
             })?;
 
@@ -436,7 +1295,7 @@ fn parse_forward_attribute(attrs: &[Attribute]) -> syn::Result<Option<Vec<Path>>
                 ));
             }
 
-            let forward_types: Vec<Path> = punctuated.into_iter().collect();
+            let forward_types: Vec<Type> = punctuated.into_iter().collect();
             return Ok(Some(forward_types));
         }
     }
@@ -447,18 +1306,27 @@ fn parse_forward_attribute(attrs: &[Attribute]) -> syn::Result<Option<Vec<Path>>
 fn generate_forwarded_as_ref_impls(
     struct_name: &Ident,
     builder_name: &Ident,
+    generics: &BundleGenerics,
     type_params: &[Ident],
-    forward_info: &[(usize, &Ident, Vec<Path>)],
+    cfgs: &[TokenStream],
+    forward_info: &[(usize, &Ident, Vec<Type>)],
 ) -> (Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>) {
     let mut struct_impls = Vec::new();
     let mut builder_impls = Vec::new();
+    let impl_generics = generics.impl_generics();
+    let ty_generics = generics.ty_generics();
+    let generic_params = &generics.params;
+    let generic_args = &generics.args;
+    let where_clause = generics.where_clause();
 
     // Generate AsRef impls for the final struct (all fields Set)
-    for (_, field_name, forward_types) in forward_info {
+    for (field_idx, field_name, forward_types) in forward_info {
+        let cfg = &cfgs[*field_idx];
         for forward_type in forward_types {
             let as_ref_impl = quote! {
+                #cfg
                 #[allow(non_camel_case_types, non_snake_case)]
-                impl ::std::convert::AsRef<#forward_type> for #struct_name {
+                impl #impl_generics ::std::convert::AsRef<#forward_type> for #struct_name #ty_generics #where_clause {
                     fn as_ref(&self) -> &#forward_type {
                         self.#field_name.as_ref()
                     }
@@ -470,6 +1338,7 @@ fn generate_forwarded_as_ref_impls(
 
     // Generate AsRef impls for the builder when the forwarded field is Set
     for (field_idx, field_name, forward_types) in forward_info {
+        let cfg = &cfgs[*field_idx];
         for forward_type in forward_types {
             // Create type parameter list with the forwarded field as Set, others as generic
             let impl_params: Vec<_> = type_params
@@ -486,8 +1355,9 @@ fn generate_forwarded_as_ref_impls(
                 .collect();
 
             let as_ref_impl = quote! {
+                #cfg
                 #[allow(non_camel_case_types, non_snake_case, clippy::items_after_statements)]
-                impl<RW, #(#other_params),*> ::std::convert::AsRef<#forward_type> for #builder_name<RW, #(#impl_params),*> {
+                impl<#(#generic_params,)* RW, #(#other_params),*> ::std::convert::AsRef<#forward_type> for #builder_name<#(#generic_args,)* RW, #(#impl_params),*> #where_clause {
                     fn as_ref(&self) -> &#forward_type {
                         self.#field_name.as_ref().unwrap().as_ref()
                     }
@@ -501,13 +1371,39 @@ fn generate_forwarded_as_ref_impls(
 }
 
 #[cfg_attr(test, mutants::skip)]
-fn generate_export_impls(struct_name: &Ident, field_types: &[&Type], field_names: &[&Ident]) -> proc_macro2::TokenStream {
-    // Export all types, not just unique ones
-    let num_exports = field_types.len();
+fn generate_export_impls(
+    struct_name: &Ident,
+    generics: &BundleGenerics,
+    field_types: &[&Type],
+    field_names: &[&Ident],
+    cfgs: &[TokenStream],
+) -> proc_macro2::TokenStream {
+    // Export all types, not just unique ones, but only count fields that are compiled in
+    let unconditional_exports = cfgs.iter().filter(|cfg| cfg.is_empty()).count();
+    let conditional_exports = cfgs.iter().filter(|cfg| !cfg.is_empty());
+    let num_exports = if unconditional_exports == field_types.len() {
+        quote!(#unconditional_exports)
+    } else {
+        quote! {
+            {
+                let mut num_exports = #unconditional_exports;
+                #(
+                    #conditional_exports
+                    {
+                        num_exports += 1;
+                    }
+                )*
+                num_exports
+            }
+        }
+    };
 
     // Generate Exports implementation
+    let impl_generics = generics.impl_generics();
+    let ty_generics = generics.ty_generics();
+    let where_clause = generics.where_clause();
     let exports_impl = quote! {
-        impl ::fundle::exports::Exports for #struct_name {
+        impl #impl_generics ::fundle::exports::Exports for #struct_name #ty_generics #where_clause {
             const NUM_EXPORTS: usize = #num_exports;
         }
     };
@@ -517,10 +1413,12 @@ fn generate_export_impls(struct_name: &Ident, field_types: &[&Type], field_names
         .iter()
         .enumerate()
         .zip(field_names.iter())
-        .map(|((index, ty), field_name)| {
+        .zip(cfgs)
+        .map(|(((index, ty), field_name), cfg)| {
             quote! {
+                #cfg
                 #[allow(clippy::items_after_statements)]
-                impl ::fundle::exports::Export<#index> for #struct_name {
+                impl #impl_generics ::fundle::exports::Export<#index> for #struct_name #ty_generics #where_clause {
                     type T = #ty;
 
                     fn get(&self) -> &Self::T {
@@ -541,14 +1439,20 @@ fn generate_export_impls(struct_name: &Ident, field_types: &[&Type], field_names
 #[cfg_attr(test, mutants::skip)]
 fn generate_builder_export_impls(
     builder_name: &Ident,
+    generics: &BundleGenerics,
     field_names: &[&Ident],
     field_types: &[&Type],
     type_params: &[Ident],
+    cfgs: &[TokenStream],
 ) -> Vec<proc_macro2::TokenStream> {
     let mut impls = Vec::new();
+    let generic_params = &generics.params;
+    let generic_args = &generics.args;
+    let where_clause = generics.where_clause();
 
     // For each field, generate Export impl only for that specific field when it's Set
     for (field_idx, (field_name, field_type)) in field_names.iter().zip(field_types.iter()).enumerate() {
+        let cfg = &cfgs[field_idx];
         // Create type parameter list with this field as Set, others as generic
         let impl_params: Vec<_> = type_params
             .iter()
@@ -564,8 +1468,9 @@ fn generate_builder_export_impls(
             .collect();
 
         let export_impl = quote! {
+            #cfg
             #[allow(non_camel_case_types, non_snake_case)]
-            impl<RW, #(#other_params),*> ::fundle::exports::Export<#field_idx> for #builder_name<RW, #(#impl_params),*> {
+            impl<#(#generic_params,)* RW, #(#other_params),*> ::fundle::exports::Export<#field_idx> for #builder_name<#(#generic_args,)* RW, #(#impl_params),*> #where_clause {
                 type T = #field_type;
 
                 fn get(&self) -> &Self::T {
@@ -584,19 +1489,17 @@ fn generate_builder_export_impls(
 fn generate_select_macro(
     struct_name: &Ident,
     builder_name: &Ident,
+    generics: &BundleGenerics,
     field_names: &[&Ident],
     field_types: &[&Type],
-    _type_params: &[Ident],
+    unique_cfgs: &[Option<TokenStream>],
 ) -> proc_macro2::TokenStream {
     let macro_name = struct_name;
     let num_fields = field_names.len();
-
-    // Count occurrences of each type
-    let mut type_counts = std::collections::HashMap::new();
-    for field_type in field_types {
-        let type_string = quote!(#field_type).to_string();
-        *type_counts.entry(type_string).or_insert(0) += 1;
-    }
+    let generic_params = &generics.params;
+    let generic_args = &generics.args;
+    let predicates = &generics.predicates;
+    let where_clause = generics.where_clause();
 
     // Generate type parameters for the Select struct
     let select_type_params = (1..=num_fields)
@@ -608,13 +1511,10 @@ fn generate_select_macro(
         .iter()
         .enumerate()
         .zip(field_types.iter())
-        .filter_map(|((field_idx, _field_name), field_type)| {
-            let type_string = quote!(#field_type).to_string();
-
+        .zip(unique_cfgs)
+        .filter_map(|(((field_idx, _field_name), field_type), unique_cfg)| {
             // Only generate AsRef for types that appear exactly once
-            if type_counts.get(&type_string) != Some(&1) {
-                return None;
-            }
+            let cfg = unique_cfg.as_ref()?;
 
             // Create type parameter list for the impl where this field is Set, others are generic
             let impl_type_params = select_type_params
@@ -637,10 +1537,12 @@ fn generate_select_macro(
                 .collect::<Vec<_>>();
 
             Some(quote! {
-                impl<'a, RW, #(#other_type_params),*> ::std::convert::AsRef<#field_type>
-                    for Select<'a, RW, #(#impl_type_params),*>
+                #cfg
+                impl<'select, #(#generic_params,)* RW, #(#other_type_params),*> ::std::convert::AsRef<#field_type>
+                    for Select<'select, #(#generic_args,)* RW, #(#impl_type_params),*>
                 where
-                    #builder_name<RW, #(#impl_type_params),*>: ::std::convert::AsRef<#field_type>,
+                    #(#predicates,)*
+                    #builder_name<#(#generic_args,)* RW, #(#impl_type_params),*>: ::std::convert::AsRef<#field_type>,
                 {
                     fn as_ref(&self) -> &#field_type {
                         self.builder.as_ref()
@@ -651,6 +1553,7 @@ fn generate_select_macro(
         .collect::<Vec<_>>();
 
     // Generate individual verification patterns for each field
+    let struct_generic_params = generic_params;
     let verification_patterns = field_names
         .iter()
         .enumerate()
@@ -679,7 +1582,7 @@ fn generate_select_macro(
             quote! {
                 (verify_field $builder_var:ident #field_name) => {
                     {
-                        fn verify_exists<RW, #(#generic_params),*>(_: &#builder_name<RW, #(#verification_params),*>) {}
+                        fn verify_exists<#(#struct_generic_params,)* RW, #(#generic_params),*>(_: &#builder_name<#(#generic_args,)* RW, #(#verification_params),*>) #where_clause {}
                         verify_exists($builder_var);
                     }
                 };
@@ -702,17 +1605,18 @@ fn generate_select_macro(
                     )*
 
                     #[allow(non_camel_case_types, non_snake_case, clippy::items_after_statements)]
-                    struct Select<'a, RW, #(#select_type_params),*> {
-                        builder: &'a #builder_name<RW, #(#select_type_params),*>,
-                        $($forward_type: &'a $forward_type,)*
+                    struct Select<'select, #(#generic_params,)* RW, #(#select_type_params),*> #where_clause {
+                        builder: &'select #builder_name<#(#generic_args,)* RW, #(#select_type_params),*>,
+                        $($forward_type: &'select $forward_type,)*
                     }
 
                     #(#builder_as_ref_impls)*
 
                     $(
                         #[allow(non_camel_case_types, non_snake_case, clippy::items_after_statements)]
-                        impl<'a, RW, #(#select_type_params),*> ::std::convert::AsRef<$forward_type>
-                            for Select<'a, RW, #(#select_type_params),*>
+                        impl<'select, #(#generic_params,)* RW, #(#select_type_params),*> ::std::convert::AsRef<$forward_type>
+                            for Select<'select, #(#generic_args,)* RW, #(#select_type_params),*>
+                        #where_clause
                         {
                             fn as_ref(&self) -> &$forward_type {
                                 self.$forward_type
@@ -731,30 +1635,48 @@ fn generate_select_macro(
 }
 
 #[cfg_attr(test, mutants::skip)]
-fn generate_reader_writer_impls(builder_name: &Ident, type_params: &[Ident]) -> proc_macro2::TokenStream {
+fn generate_reader_writer_impls(builder_name: &Ident, generics: &BundleGenerics, type_params: &[Ident]) -> proc_macro2::TokenStream {
+    let generic_params = &generics.params;
+    let generic_args = &generics.args;
+    let where_clause = generics.where_clause();
+
     quote! {
         #[allow(non_camel_case_types, non_snake_case, clippy::items_after_statements)]
-        impl<#(#type_params),*> ::fundle::Writer for #builder_name<::fundle::Write, #(#type_params),*> {
-            type Reader = #builder_name<::fundle::Read, #(#type_params),*>;
+        impl<#(#generic_params,)* #(#type_params),*> ::fundle::Writer for #builder_name<#(#generic_args,)* ::fundle::Write, #(#type_params),*> #where_clause {
+            type Reader = #builder_name<#(#generic_args,)* ::fundle::Read, #(#type_params),*>;
         }
 
         #[allow(non_camel_case_types, non_snake_case, clippy::items_after_statements)]
-        impl<#(#type_params),*> ::fundle::Reader for #builder_name<::fundle::Read, #(#type_params),*> {
-            type Writer = #builder_name<::fundle::Write, #(#type_params),*>;
+        impl<#(#generic_params,)* #(#type_params),*> ::fundle::Reader for #builder_name<#(#generic_args,)* ::fundle::Read, #(#type_params),*> #where_clause {
+            type Writer = #builder_name<#(#generic_args,)* ::fundle::Write, #(#type_params),*>;
         }
     }
 }
 
 #[cfg_attr(test, mutants::skip)]
-fn generate_read_toggle(builder_name: &Ident, field_names: &[&Ident], type_params: &[Ident]) -> proc_macro2::TokenStream {
-    let field_assignments = field_names.iter().map(|name| {
-        quote! { #name: self.#name }
-    });
+fn generate_read_toggle(
+    builder_name: &Ident,
+    generics: &BundleGenerics,
+    field_names: &[&Ident],
+    type_params: &[Ident],
+    cfgs: &[TokenStream],
+    init_order: &InitOrder,
+) -> proc_macro2::TokenStream {
+    let field_assignments = field_names
+        .iter()
+        .zip(cfgs)
+        .map(|(name, cfg)| {
+            quote! { #cfg #name: self.#name }
+        })
+        .chain(init_order.forward());
+    let generic_params = &generics.params;
+    let generic_args = &generics.args;
+    let where_clause = generics.where_clause();
 
     quote! {
         #[allow(non_camel_case_types, non_snake_case, clippy::items_after_statements)]
-        impl<#(#type_params),*> #builder_name<::fundle::Write, #(#type_params),*> {
-            pub fn read(self) -> #builder_name<::fundle::Read, #(#type_params),*> {
+        impl<#(#generic_params,)* #(#type_params),*> #builder_name<#(#generic_args,)* ::fundle::Write, #(#type_params),*> #where_clause {
+            pub fn read(self) -> #builder_name<#(#generic_args,)* ::fundle::Read, #(#type_params),*> {
                 #builder_name {
                     #(#field_assignments,)*
                     _phantom: ::std::marker::PhantomData,
@@ -767,13 +1689,20 @@ fn generate_read_toggle(builder_name: &Ident, field_names: &[&Ident], type_param
 #[cfg_attr(test, mutants::skip)]
 fn generate_reader_getters(
     builder_name: &Ident,
+    generics: &BundleGenerics,
     field_names: &[&Ident],
     field_types: &[&Type],
     type_params: &[Ident],
+    cfgs: &[TokenStream],
 ) -> Vec<proc_macro2::TokenStream> {
     let mut impls = Vec::new();
+    let generic_params = &generics.params;
+    let generic_args = &generics.args;
+    let where_clause = generics.where_clause();
 
     for (i, (field_name, field_type)) in field_names.iter().zip(field_types.iter()).enumerate() {
+        let cfg = &cfgs[i];
+
         // Create type parameter list with current one as Set, others as generic
         let impl_params: Vec<_> = type_params
             .iter()
@@ -789,8 +1718,9 @@ fn generate_reader_getters(
             .collect();
 
         let getter = quote! {
+            #cfg
             #[allow(non_camel_case_types, non_snake_case)]
-            impl<#(#other_params),*> #builder_name<::fundle::Read, #(#impl_params),*> {
+            impl<#(#generic_params,)* #(#other_params),*> #builder_name<#(#generic_args,)* ::fundle::Read, #(#impl_params),*> #where_clause {
                 pub fn #field_name(&self) -> &#field_type {
                     self.#field_name.as_ref().unwrap()
                 }
@@ -809,6 +1739,33 @@ mod tests {
 
     use super::bundle;
 
+    #[test]
+    fn recognizes_wrapped_types() {
+        use super::wrapped_type;
+
+        let inner: syn::Type = syn::parse_quote!(u8);
+        assert_eq!(wrapped_type(&syn::parse_quote!(Option<u8>), "Option"), Some(&inner));
+        assert_eq!(wrapped_type(&syn::parse_quote!(::fundle::Lazy<u8>), "Lazy"), Some(&inner));
+        assert_eq!(wrapped_type(&syn::parse_quote!(Option<u8>), "Lazy"), None);
+        assert_eq!(wrapped_type(&syn::parse_quote!(Option), "Option"), None);
+        assert_eq!(wrapped_type(&syn::parse_quote!(Option<'a>), "Option"), None);
+        assert_eq!(wrapped_type(&syn::parse_quote!(Option<u8, u16>), "Option"), None);
+        assert_eq!(wrapped_type(&syn::parse_quote!(<T as Trait>::Option<u8>), "Option"), None);
+        assert_eq!(wrapped_type(&syn::parse_quote!(&Option<u8>), "Option"), None);
+    }
+
+    #[test]
+    fn normalizes_trait_object_keys() {
+        use super::type_key;
+
+        let key = type_key(&syn::parse_quote!(Arc<dyn Log + Send + Sync>));
+        assert_eq!(type_key(&syn::parse_quote!(Arc<dyn Log + Sync + Send>)), key);
+        assert_eq!(type_key(&syn::parse_quote!(Arc<dyn Send + Log + Sync + 'static>)), key);
+        assert_ne!(type_key(&syn::parse_quote!(Arc<dyn Log + Send>)), key);
+        assert_ne!(type_key(&syn::parse_quote!(Arc<dyn Log>)), key);
+        assert_eq!(type_key(&syn::parse_quote!(u32)), "u32");
+    }
+
     #[test]
     fn rejects_non_named_fields() {
         let tokens = bundle(quote! {}, quote! { struct Tuple(u32); })
@@ -2,7 +2,8 @@
 // Licensed under the MIT License.
 
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
 use syn::{Fields, FieldsNamed, ItemStruct, Type, parse2};
 
 /// Attribute macro to generate dependency structs with `From` implementations.
@@ -63,12 +64,11 @@ fn generate_args_from_impl(
     // We'll use __FundleFromT as it's unlikely to conflict with user types
     let from_param = quote::format_ident!("__FundleFromT");
 
-    let field_assignments = field_names
-        .iter()
-        .zip(field_types.iter())
-        .map(|(name, ty)| quote!(#name: <#from_param as ::std::convert::AsRef<#ty>>::as_ref(&value).to_owned()));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let (_impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let field_assignments = field_names.iter().zip(field_types.iter()).map(|(name, ty)| {
+        quote!(#name: <#from_param as ::fundle::exports::Provides<#ty, #struct_name #ty_generics>>::provide(&value).to_owned())
+    });
 
     // Add the From parameter to the generics
     let impl_generics_with_from = if generics.params.is_empty() {
@@ -78,7 +78,11 @@ fn generate_args_from_impl(
         quote!(<#params, #from_param>)
     };
 
-    let as_ref_bounds = field_types.iter().map(|ty| quote!(::std::convert::AsRef<#ty>));
+    // Bounds on the source type, reporting each missing dependency by its type
+    let provides_bounds: Vec<_> = field_types
+        .iter()
+        .map(|ty| quote_spanned!(ty.span()=> ::fundle::exports::Provides<#ty, #struct_name #ty_generics>))
+        .collect();
 
     // Handle where clause properly - extract just the predicates without the 'where' keyword
     let additional_predicates = where_clause.map_or_else(
@@ -93,7 +97,7 @@ fn generate_args_from_impl(
         #[allow(private_bounds)]
         impl #impl_generics_with_from ::std::convert::From<#from_param> for #struct_name #ty_generics
         where
-            #from_param: #(#as_ref_bounds)+*
+            #from_param: #(#provides_bounds)+*
             #additional_predicates
         {
             fn from(value: #from_param) -> Self {
@@ -102,6 +106,13 @@ fn generate_args_from_impl(
                 }
             }
         }
+
+        #[allow(private_bounds)]
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            /// Fails to compile for each dependency the source type does not provide, used by `fundle::check_deps!`.
+            #[doc(hidden)]
+            pub const fn __fundle_check_deps<#from_param: ?::std::marker::Sized + #(#provides_bounds)+*>() {}
+        }
     }
 }
 
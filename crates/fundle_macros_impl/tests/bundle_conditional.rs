@@ -0,0 +1,29 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![expect(missing_docs, reason = "Test code")]
+
+use syn::{ItemStruct, parse_quote};
+
+mod util;
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn conditional_expansion() {
+    let item: ItemStruct = parse_quote! {
+        #[bundle]
+        struct Foo {
+            #[cfg(feature = "a")]
+            x: Bar,
+            #[cfg(feature = "a")]
+            #[bundle(shutdown)]
+            y: Baz,
+            #[cfg(not(feature = "a"))]
+            z: Baz,
+            #[bundle(if_enabled)]
+            w: Option<Qux>
+        }
+    };
+
+    insta::assert_snapshot!(expand_fundle_bundle!(item));
+}
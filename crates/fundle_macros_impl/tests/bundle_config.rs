@@ -0,0 +1,26 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![expect(missing_docs, reason = "Test code")]
+
+use syn::{ItemStruct, parse_quote};
+
+mod util;
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn config_expansion() {
+    let item: ItemStruct = parse_quote! {
+        #[bundle]
+        struct Foo {
+            #[bundle(config)]
+            x: Bar,
+            #[bundle(shutdown)]
+            y: Baz,
+            #[bundle(config)]
+            z: Qux
+        }
+    };
+
+    insta::assert_snapshot!(expand_fundle_bundle!(item));
+}
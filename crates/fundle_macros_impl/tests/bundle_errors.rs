@@ -34,3 +34,99 @@ fn forward_invalid_syntax() {
 
     insta::assert_snapshot!(expand_fundle_bundle!(item));
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn field_option_unknown() {
+    let item: ItemStruct = parse_quote! {
+        #[bundle]
+        struct Foo {
+            #[bundle(lazy)]
+            x: Bar
+        }
+    };
+
+    insta::assert_snapshot!(expand_fundle_bundle!(item));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn field_option_duplicate() {
+    let item: ItemStruct = parse_quote! {
+        #[bundle]
+        struct Foo {
+            #[bundle(default, default)]
+            x: Bar
+        }
+    };
+
+    insta::assert_snapshot!(expand_fundle_bundle!(item));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn from_parent_without_parent() {
+    let item: ItemStruct = parse_quote! {
+        #[bundle]
+        struct Foo {
+            #[bundle(from_parent)]
+            x: Bar
+        }
+    };
+
+    insta::assert_snapshot!(expand_fundle_bundle!(item));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn from_parent_with_shutdown() {
+    let item: ItemStruct = parse_quote! {
+        #[bundle(parent = AppState)]
+        struct Foo {
+            #[bundle(from_parent, shutdown)]
+            x: Bar
+        }
+    };
+
+    insta::assert_snapshot!(expand_fundle_bundle!(item));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn option_unknown() {
+    let item: ItemStruct = parse_quote! {
+        #[bundle(scope = Request)]
+        struct Foo {
+            x: Bar
+        }
+    };
+
+    insta::assert_snapshot!(expand_fundle_bundle!(item));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn if_enabled_not_option() {
+    let item: ItemStruct = parse_quote! {
+        #[bundle]
+        struct Foo {
+            #[bundle(if_enabled)]
+            x: Bar
+        }
+    };
+
+    insta::assert_snapshot!(expand_fundle_bundle!(item));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn generic_conflicts_with_field() {
+    let item: ItemStruct = parse_quote! {
+        #[bundle]
+        struct Foo<X> {
+            x: X
+        }
+    };
+
+    insta::assert_snapshot!(expand_fundle_bundle!(item));
+}
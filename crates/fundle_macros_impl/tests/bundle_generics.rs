@@ -0,0 +1,26 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![expect(missing_docs, reason = "Test code")]
+
+use syn::{ItemStruct, parse_quote};
+
+mod util;
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn generics_expansion() {
+    let item: ItemStruct = parse_quote! {
+        #[bundle]
+        struct Foo<'a, C: Clock = SystemClock>
+        where
+            C: Send,
+        {
+            x: &'a Bar,
+            #[bundle(shutdown)]
+            y: C,
+        }
+    };
+
+    insta::assert_snapshot!(expand_fundle_bundle!(item));
+}
@@ -0,0 +1,22 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![expect(missing_docs, reason = "Test code")]
+
+use syn::{ItemStruct, parse_quote};
+
+mod util;
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn lazy_expansion() {
+    let item: ItemStruct = parse_quote! {
+        #[bundle]
+        struct Foo {
+            x: Bar,
+            y: fundle::Lazy<Baz>
+        }
+    };
+
+    insta::assert_snapshot!(expand_fundle_bundle!(item));
+}
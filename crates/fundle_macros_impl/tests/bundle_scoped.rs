@@ -0,0 +1,23 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![expect(missing_docs, reason = "Test code")]
+
+use syn::{ItemStruct, parse_quote};
+
+mod util;
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn from_parent_expansion() {
+    let item: ItemStruct = parse_quote! {
+        #[bundle(parent = AppState)]
+        struct Foo {
+            #[bundle(from_parent)]
+            x: Bar,
+            y: Baz
+        }
+    };
+
+    insta::assert_snapshot!(expand_fundle_bundle!(item));
+}
@@ -0,0 +1,24 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![expect(missing_docs, reason = "Test code")]
+
+use syn::{ItemStruct, parse_quote};
+
+mod util;
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn shutdown_expansion() {
+    let item: ItemStruct = parse_quote! {
+        #[bundle]
+        struct Foo {
+            #[bundle(shutdown)]
+            x: Bar,
+            #[bundle(default, shutdown)]
+            y: Baz
+        }
+    };
+
+    insta::assert_snapshot!(expand_fundle_bundle!(item));
+}
@@ -316,7 +316,7 @@ mod tests {
     #[test]
     fn assert_size_small() {
         // Keep the size of HttpError small to avoid excessive stack usage.
-        assert_eq!(size_of::<HttpError>(), 64);
+        assert_eq!(size_of::<HttpError>(), 80);
     }
 
     #[test]
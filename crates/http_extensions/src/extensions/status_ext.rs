@@ -141,11 +141,7 @@ impl StatusExt for StatusCode {
 
     #[cfg_attr(test, mutants::skip)] // Causes test timeouts, but it's well tested.
     fn recovery(&self) -> RecoveryInfo {
-        match self {
-            s if s >= &Self::INTERNAL_SERVER_ERROR => RecoveryInfo::retry(),
-            &Self::TOO_MANY_REQUESTS | &Self::REQUEST_TIMEOUT => RecoveryInfo::retry(),
-            _ => RecoveryInfo::never(),
-        }
+        RecoveryInfo::from_status(self.as_u16())
     }
 }
 
@@ -0,0 +1,65 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Private module for code generated by the `Recovery` derive macro.
+//!
+//! **Note**: This module is not part of the public API and may change without notice.
+
+use std::time::{Duration, SystemTime};
+
+use crate::RecoveryInfo;
+
+/// A field that can provide the delay hint of a derived [`Recovery`][crate::Recovery] implementation.
+pub trait DelayHint {
+    /// Attaches the delay hint to `info`, if there is one.
+    fn attach(&self, info: RecoveryInfo) -> RecoveryInfo;
+}
+
+impl DelayHint for Duration {
+    fn attach(&self, info: RecoveryInfo) -> RecoveryInfo {
+        info.delay(*self)
+    }
+}
+
+impl DelayHint for SystemTime {
+    fn attach(&self, info: RecoveryInfo) -> RecoveryInfo {
+        info.delay_until(*self)
+    }
+}
+
+impl<T: DelayHint> DelayHint for Option<T> {
+    fn attach(&self, info: RecoveryInfo) -> RecoveryInfo {
+        match self {
+            Some(delay) => delay.attach(info),
+            None => info,
+        }
+    }
+}
+
+/// Attaches the delay hint of `delay` to `info`, if there is one.
+#[must_use]
+pub fn with_delay(info: RecoveryInfo, delay: &impl DelayHint) -> RecoveryInfo {
+    delay.attach(info)
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RecoveryKind;
+
+    #[test]
+    fn with_delay_ok() {
+        let delay = Duration::from_secs(5);
+
+        assert_eq!(with_delay(RecoveryInfo::retry(), &delay).get_delay(), Some(delay));
+        assert_eq!(with_delay(RecoveryInfo::retry(), &Some(delay)).get_delay(), Some(delay));
+        assert_eq!(with_delay(RecoveryInfo::retry(), &None::<Duration>).get_delay(), None);
+        assert_eq!(with_delay(RecoveryInfo::unavailable(), &delay).kind(), RecoveryKind::Unavailable);
+
+        let time = SystemTime::UNIX_EPOCH + delay;
+        assert_eq!(with_delay(RecoveryInfo::retry(), &time).get_delay_until(), Some(time));
+        assert_eq!(with_delay(RecoveryInfo::retry(), &Some(time)).get_delay_until(), Some(time));
+        assert_eq!(with_delay(RecoveryInfo::retry(), &None::<SystemTime>), RecoveryInfo::retry());
+    }
+}
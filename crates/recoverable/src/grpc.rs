@@ -0,0 +1,208 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Recovery information for gRPC status codes.
+//!
+//! This module classifies the integer status codes defined by gRPC, so it can be used with
+//! any gRPC library without depending on it. Use [`RecoveryInfo::from_grpc_code`] for the
+//! conventional classification, or a [`CodeMapping`] to override the classification of
+//! individual codes and to account for operations that are not idempotent.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use recoverable::grpc::CodeMapping;
+//! use recoverable::{RecoveryInfo, RecoveryKind};
+//!
+//! // UNAVAILABLE
+//! assert_eq!(RecoveryInfo::from_grpc_code(14).kind(), RecoveryKind::Retry);
+//!
+//! // DEADLINE_EXCEEDED may have been processed, so don't retry a non-idempotent call
+//! let mapping = CodeMapping::new().idempotent(false);
+//! assert_eq!(mapping.recovery(4).kind(), RecoveryKind::Never);
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::RecoveryInfo;
+
+/// `UNKNOWN`: an error without more specific information.
+const UNKNOWN: i32 = 2;
+
+/// `DEADLINE_EXCEEDED`: the deadline expired, possibly after the operation completed.
+const DEADLINE_EXCEEDED: i32 = 4;
+
+/// `RESOURCE_EXHAUSTED`: a quota or rate limit was hit.
+const RESOURCE_EXHAUSTED: i32 = 8;
+
+/// `ABORTED`: the operation was aborted, typically by a concurrency conflict.
+const ABORTED: i32 = 10;
+
+/// `UNAVAILABLE`: the service is currently unavailable.
+const UNAVAILABLE: i32 = 14;
+
+/// `UNAUTHENTICATED`: the highest status code defined by gRPC.
+const UNAUTHENTICATED: i32 = 16;
+
+impl RecoveryInfo {
+    /// Creates recovery information from a gRPC status code.
+    ///
+    /// This is an opinionated default classification following the conventional gRPC retry
+    /// semantics:
+    ///
+    /// - `DEADLINE_EXCEEDED` (4), `RESOURCE_EXHAUSTED` (8), `ABORTED` (10), and
+    ///   `UNAVAILABLE` (14) return [`RecoveryInfo::retry`].
+    /// - `UNKNOWN` (2) and codes not defined by gRPC return [`RecoveryInfo::unknown`].
+    /// - All other codes, including `OK` (0), return [`RecoveryInfo::never`].
+    ///
+    /// `DEADLINE_EXCEEDED` and `ABORTED` are only safe to retry for idempotent operations;
+    /// use a [`CodeMapping`] with [`idempotent(false)`](CodeMapping::idempotent) otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use recoverable::{RecoveryInfo, RecoveryKind};
+    ///
+    /// assert_eq!(RecoveryInfo::from_grpc_code(8).kind(), RecoveryKind::Retry);
+    /// assert_eq!(RecoveryInfo::from_grpc_code(5).kind(), RecoveryKind::Never);
+    /// assert_eq!(
+    ///     RecoveryInfo::from_grpc_code(2).kind(),
+    ///     RecoveryKind::Unknown
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_grpc_code(code: i32) -> Self {
+        match code {
+            DEADLINE_EXCEEDED | RESOURCE_EXHAUSTED | ABORTED | UNAVAILABLE => Self::retry(),
+            UNKNOWN => Self::unknown(),
+            0..=UNAUTHENTICATED => Self::never(),
+            _ => Self::unknown(),
+        }
+    }
+}
+
+/// A customizable classification of gRPC status codes.
+///
+/// Codes without an override are classified by [`RecoveryInfo::from_grpc_code`], adjusted
+/// for non-idempotent operations when [`idempotent(false)`](Self::idempotent) is set.
+///
+/// # Examples
+///
+/// ```rust
+/// use recoverable::grpc::CodeMapping;
+/// use recoverable::{RecoveryInfo, RecoveryKind};
+///
+/// // Treat INTERNAL as transient for a flaky dependency
+/// let mapping = CodeMapping::new().code(13, RecoveryInfo::retry());
+/// assert_eq!(mapping.recovery(13).kind(), RecoveryKind::Retry);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeMapping {
+    overrides: BTreeMap<i32, RecoveryInfo>,
+    idempotent: bool,
+}
+
+impl Default for CodeMapping {
+    fn default() -> Self {
+        Self {
+            overrides: BTreeMap::new(),
+            idempotent: true,
+        }
+    }
+}
+
+impl CodeMapping {
+    /// Creates a mapping with the conventional classification of
+    /// [`RecoveryInfo::from_grpc_code`] for idempotent operations.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the recovery information returned for the given status code.
+    ///
+    /// Overrides take precedence over [`idempotent`](Self::idempotent).
+    #[must_use]
+    pub fn code(mut self, code: i32, recovery: RecoveryInfo) -> Self {
+        self.overrides.insert(code, recovery);
+        self
+    }
+
+    /// Sets whether the classified operations are idempotent.
+    ///
+    /// A call that failed with `DEADLINE_EXCEEDED` or `ABORTED` may have been partially or
+    /// fully processed by the server. Repeating it is only safe if the operation is
+    /// idempotent, so for non-idempotent operations both codes return
+    /// [`RecoveryInfo::never`]. `UNAVAILABLE` and `RESOURCE_EXHAUSTED` indicate that the call
+    /// was not processed and remain retryable.
+    ///
+    /// Defaults to `true`.
+    #[must_use]
+    pub fn idempotent(mut self, idempotent: bool) -> Self {
+        self.idempotent = idempotent;
+        self
+    }
+
+    /// Returns the recovery information for the given status code.
+    #[must_use]
+    pub fn recovery(&self, code: i32) -> RecoveryInfo {
+        if let Some(recovery) = self.overrides.get(&code) {
+            return recovery.clone();
+        }
+
+        match code {
+            DEADLINE_EXCEEDED | ABORTED if !self.idempotent => RecoveryInfo::never(),
+            _ => RecoveryInfo::from_grpc_code(code),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_grpc_code_ok() {
+        for code in [4, 8, 10, 14] {
+            assert_eq!(RecoveryInfo::from_grpc_code(code), RecoveryInfo::retry(), "{code}");
+        }
+        for code in [0, 1, 3, 5, 6, 7, 9, 11, 12, 13, 15, 16] {
+            assert_eq!(RecoveryInfo::from_grpc_code(code), RecoveryInfo::never(), "{code}");
+        }
+        for code in [2, -1, 17, 100] {
+            assert_eq!(RecoveryInfo::from_grpc_code(code), RecoveryInfo::unknown(), "{code}");
+        }
+    }
+
+    #[test]
+    fn mapping_uses_defaults() {
+        let mapping = CodeMapping::new();
+
+        for code in -1..=17 {
+            assert_eq!(mapping.recovery(code), RecoveryInfo::from_grpc_code(code), "{code}");
+        }
+    }
+
+    #[test]
+    fn mapping_for_non_idempotent_operations() {
+        let mapping = CodeMapping::new().idempotent(false);
+
+        assert_eq!(mapping.recovery(DEADLINE_EXCEEDED), RecoveryInfo::never());
+        assert_eq!(mapping.recovery(ABORTED), RecoveryInfo::never());
+        assert_eq!(mapping.recovery(UNAVAILABLE), RecoveryInfo::retry());
+        assert_eq!(mapping.recovery(RESOURCE_EXHAUSTED), RecoveryInfo::retry());
+    }
+
+    #[test]
+    fn mapping_applies_overrides() {
+        let mapping = CodeMapping::new()
+            .idempotent(false)
+            .code(ABORTED, RecoveryInfo::retry())
+            .code(UNAVAILABLE, RecoveryInfo::unavailable());
+
+        assert_eq!(mapping.recovery(ABORTED), RecoveryInfo::retry());
+        assert_eq!(mapping.recovery(UNAVAILABLE), RecoveryInfo::unavailable());
+        assert_eq!(mapping.recovery(DEADLINE_EXCEEDED), RecoveryInfo::never());
+    }
+}
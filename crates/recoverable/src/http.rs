@@ -0,0 +1,203 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Recovery information for HTTP status codes.
+//!
+//! HTTP clients classify responses the same way over and over: request timeouts, throttling,
+//! and server errors are worth retrying, everything else is not. This module provides that
+//! classification once, working on plain status codes so that it does not depend on any
+//! particular HTTP library.
+//!
+//! Use [`RecoveryInfo::from_status`] for the conventional classification, or a
+//! [`StatusMapping`] to override the classification of individual status codes and to
+//! honor `Retry-After` hints.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use recoverable::http::StatusMapping;
+//! use recoverable::{RecoveryInfo, RecoveryKind};
+//!
+//! assert_eq!(RecoveryInfo::from_status(503).kind(), RecoveryKind::Retry);
+//! assert_eq!(RecoveryInfo::from_status(404).kind(), RecoveryKind::Never);
+//!
+//! // Treat 501 Not Implemented as permanent and honor a parsed `Retry-After` header
+//! let mapping = StatusMapping::new().status(501, RecoveryInfo::never());
+//! assert_eq!(mapping.recovery(501, None).kind(), RecoveryKind::Never);
+//! assert_eq!(
+//!     mapping.recovery(429, Some(Duration::from_secs(10))),
+//!     RecoveryInfo::retry().delay(Duration::from_secs(10))
+//! );
+//! ```
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::{RecoveryInfo, RecoveryKind};
+
+/// `408 Request Timeout`.
+const REQUEST_TIMEOUT: u16 = 408;
+
+/// `429 Too Many Requests`.
+const TOO_MANY_REQUESTS: u16 = 429;
+
+/// `503 Service Unavailable`.
+const SERVICE_UNAVAILABLE: u16 = 503;
+
+impl RecoveryInfo {
+    /// Creates recovery information from an HTTP status code.
+    ///
+    /// This is an opinionated default classification following common HTTP semantics:
+    ///
+    /// - `408 Request Timeout`, `429 Too Many Requests`, and all `5xx` server errors return
+    ///   [`RecoveryInfo::retry`].
+    /// - All other status codes, including successful responses, return
+    ///   [`RecoveryInfo::never`].
+    ///
+    /// Use a [`StatusMapping`] to customize the classification or to attach the delay of a
+    /// `Retry-After` header.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use recoverable::{RecoveryInfo, RecoveryKind};
+    ///
+    /// assert_eq!(RecoveryInfo::from_status(429).kind(), RecoveryKind::Retry);
+    /// assert_eq!(RecoveryInfo::from_status(500).kind(), RecoveryKind::Retry);
+    /// assert_eq!(RecoveryInfo::from_status(400).kind(), RecoveryKind::Never);
+    /// assert_eq!(RecoveryInfo::from_status(200).kind(), RecoveryKind::Never);
+    /// ```
+    #[must_use]
+    pub fn from_status(status: u16) -> Self {
+        match status {
+            REQUEST_TIMEOUT | TOO_MANY_REQUESTS | 500..=599 => Self::retry(),
+            _ => Self::never(),
+        }
+    }
+}
+
+/// A customizable classification of HTTP status codes.
+///
+/// Status codes without an override are classified by [`RecoveryInfo::from_status`].
+/// For `429 Too Many Requests` and `503 Service Unavailable`, a `Retry-After` delay passed to
+/// [`recovery`](Self::recovery) is attached to the recovery information, as long as the
+/// status is classified as [`RecoveryKind::Retry`] or [`RecoveryKind::Unavailable`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use recoverable::http::StatusMapping;
+/// use recoverable::{RecoveryInfo, RecoveryKind};
+///
+/// let mapping = StatusMapping::new()
+///     .status(503, RecoveryInfo::unavailable())
+///     .status(409, RecoveryInfo::retry());
+///
+/// assert_eq!(mapping.recovery(409, None).kind(), RecoveryKind::Retry);
+/// assert_eq!(
+///     mapping.recovery(503, Some(Duration::from_secs(30))),
+///     RecoveryInfo::unavailable().delay(Duration::from_secs(30))
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusMapping {
+    overrides: BTreeMap<u16, RecoveryInfo>,
+}
+
+impl StatusMapping {
+    /// Creates a mapping with the conventional classification of [`RecoveryInfo::from_status`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the recovery information returned for the given status code.
+    #[must_use]
+    pub fn status(mut self, status: u16, recovery: RecoveryInfo) -> Self {
+        self.overrides.insert(status, recovery);
+        self
+    }
+
+    /// Returns the recovery information for the given status code.
+    ///
+    /// `retry_after` is the delay of the response's `Retry-After` header, if it has one. It
+    /// replaces any delay of the classification for `429 Too Many Requests` and
+    /// `503 Service Unavailable` responses that are worth recovering from.
+    #[must_use]
+    pub fn recovery(&self, status: u16, retry_after: Option<Duration>) -> RecoveryInfo {
+        let recovery = self
+            .overrides
+            .get(&status)
+            .cloned()
+            .unwrap_or_else(|| RecoveryInfo::from_status(status));
+
+        match retry_after {
+            Some(delay)
+                if matches!(status, TOO_MANY_REQUESTS | SERVICE_UNAVAILABLE)
+                    && matches!(recovery.kind(), RecoveryKind::Retry | RecoveryKind::Unavailable) =>
+            {
+                recovery.delay(delay)
+            }
+            _ => recovery,
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_status_ok() {
+        for status in [408, 429, 500, 502, 503, 504, 599] {
+            assert_eq!(RecoveryInfo::from_status(status), RecoveryInfo::retry(), "{status}");
+        }
+        for status in [100, 200, 204, 301, 400, 401, 403, 404, 409, 499, 600] {
+            assert_eq!(RecoveryInfo::from_status(status), RecoveryInfo::never(), "{status}");
+        }
+    }
+
+    #[test]
+    fn mapping_uses_defaults() {
+        let mapping = StatusMapping::new();
+
+        assert_eq!(mapping.recovery(500, None), RecoveryInfo::retry());
+        assert_eq!(mapping.recovery(404, None), RecoveryInfo::never());
+    }
+
+    #[test]
+    fn mapping_applies_overrides() {
+        let mapping = StatusMapping::new()
+            .status(500, RecoveryInfo::never())
+            .status(404, RecoveryInfo::retry());
+
+        assert_eq!(mapping.recovery(500, None), RecoveryInfo::never());
+        assert_eq!(mapping.recovery(404, None), RecoveryInfo::retry());
+        assert_eq!(mapping.recovery(502, None), RecoveryInfo::retry());
+    }
+
+    #[test]
+    fn retry_after_is_honored_for_throttling_and_unavailability() {
+        let delay = Duration::from_secs(5);
+        let mapping = StatusMapping::new();
+
+        assert_eq!(mapping.recovery(429, Some(delay)), RecoveryInfo::retry().delay(delay));
+        assert_eq!(mapping.recovery(503, Some(delay)), RecoveryInfo::retry().delay(delay));
+
+        // Other statuses ignore the header
+        assert_eq!(mapping.recovery(500, Some(delay)), RecoveryInfo::retry());
+        assert_eq!(mapping.recovery(404, Some(delay)), RecoveryInfo::never());
+    }
+
+    #[test]
+    fn retry_after_is_ignored_when_not_recoverable() {
+        let mapping = StatusMapping::new().status(503, RecoveryInfo::never());
+
+        assert_eq!(mapping.recovery(503, Some(Duration::from_secs(5))), RecoveryInfo::never());
+    }
+}
@@ -3,9 +3,9 @@
 
 //! IO recovery information for [`RecoveryInfo`].
 
-use std::io::ErrorKind;
+use std::io::{Error, ErrorKind};
 
-use crate::RecoveryInfo;
+use crate::{Recovery, RecoveryInfo};
 
 impl From<ErrorKind> for RecoveryInfo {
     /// Creates recovery information from an IO error kind.
@@ -84,6 +84,29 @@ impl From<ErrorKind> for RecoveryInfo {
     }
 }
 
+impl Recovery for Error {
+    /// Returns the recovery information of the error's [`ErrorKind`].
+    ///
+    /// See the conversion from [`ErrorKind`] to [`RecoveryInfo`] for the classification.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use recoverable::{Recovery, RecoveryKind};
+    ///
+    /// let timeout = Error::from(ErrorKind::TimedOut);
+    /// assert_eq!(timeout.recovery().kind(), RecoveryKind::Retry);
+    ///
+    /// let missing = Error::new(ErrorKind::NotFound, "config.toml");
+    /// assert_eq!(missing.recovery().kind(), RecoveryKind::Never);
+    /// ```
+    fn recovery(&self) -> RecoveryInfo {
+        self.kind().into()
+    }
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
@@ -98,4 +121,19 @@ mod tests {
 
         insta::assert_debug_snapshot!(classifications);
     }
+
+    #[test]
+    fn io_error_recovery() {
+        for kind in [
+            ErrorKind::TimedOut,
+            ErrorKind::ConnectionReset,
+            ErrorKind::Interrupted,
+            ErrorKind::WouldBlock,
+        ] {
+            assert_eq!(Error::from(kind).recovery(), RecoveryInfo::retry(), "{kind:?}");
+        }
+        for kind in [ErrorKind::NotFound, ErrorKind::PermissionDenied] {
+            assert_eq!(Error::new(kind, "test").recovery(), RecoveryInfo::never(), "{kind:?}");
+        }
+    }
 }
@@ -21,6 +21,17 @@
 //! - [`Recovery`]: A trait for types that can provide their own recovery information.
 //! - [`RecoveryKind`]: An enum representing the kind of recovery that can be attempted.
 //!
+//! # Features
+//!
+//! * **`derive`** *(default)*: Re-exports the `#[derive(Recovery)]` macro from the companion
+//!   `recoverable_macros` crate, which implements [`Recovery`] for error enums from
+//!   `#[recovery(...)]` attributes. Disable to avoid pulling in proc-macro code in minimal
+//!   environments: `default-features = false`.
+//! * **`grpc`**: Enables the `grpc` module, which classifies gRPC status codes.
+//! * **`http`**: Enables the `http` module, which classifies HTTP status codes.
+//! * **`serde`**: Implements `Serialize` and `Deserialize` for [`RecoveryInfo`] and
+//!   [`RecoveryKind`].
+//!
 //! # Examples
 //!
 //! ## Classifying Errors
@@ -73,13 +84,89 @@
 //! assert_eq!(immediate.get_delay(), Some(Duration::ZERO));
 //! ```
 
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
+#[cfg(feature = "grpc")]
+pub mod grpc;
+#[cfg(feature = "http")]
+pub mod http;
 mod io;
+#[cfg(feature = "serde")]
+mod serde;
 
+#[doc(hidden)]
+pub mod __private;
 pub mod _documentation;
 
+// Re-export the derive macro (behind the `derive` feature) so users can
+// simply `use recoverable::Recovery;`. Disable the feature to avoid the
+// proc-macro dependency in minimal builds.
+/// Derive macro implementing [`Recovery`] for structs and enums.
+///
+/// The recovery information of each variant is declared with a `#[recovery(...)]`
+/// attribute. Every variant has to be classified, either by its own attribute or by a
+/// default attribute on the enum, so a new variant can't be added without deciding how it
+/// is recovered from. Structs take the attribute on the type.
+///
+/// # Attributes
+/// * `#[recovery(unknown)]`, `#[recovery(never)]`, `#[recovery(retry)]`,
+///   `#[recovery(unavailable)]`: Returns [`RecoveryInfo`] of the given kind.
+/// * `#[recovery(retry, delay_field = "retry_after")]`: Additionally attaches a
+///   delay hint read from the given field. A [`Duration`] or an `Option<Duration>` is
+///   attached with [`delay`](RecoveryInfo::delay), a [`SystemTime`] or an
+///   `Option<SystemTime>` with [`delay_until`](RecoveryInfo::delay_until). Use the field
+///   index for tuple variants, such as `delay_field = "0"`.
+/// * `#[recovery(forward)]`: Returns the recovery information of the only field, which has
+///   to implement [`Recovery`] itself.
+///
+/// On an enum, an attribute with just a recovery kind sets the default for variants without
+/// their own attribute. Unions are not supported.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+///
+/// use recoverable::{Recovery, RecoveryKind};
+///
+/// #[derive(Debug, Recovery)]
+/// #[recovery(unavailable)]
+/// struct PoolExhausted;
+///
+/// #[derive(Debug, Recovery)]
+/// #[recovery(never)]
+/// enum DatabaseError {
+///     #[recovery(retry)]
+///     ConnectionTimeout,
+///     #[recovery(retry, delay_field = "retry_after")]
+///     Throttled {
+///         retry_after: Duration,
+///     },
+///     #[recovery(forward)]
+///     Pool(PoolExhausted),
+///     InvalidCredentials,
+/// }
+///
+/// let error = DatabaseError::Throttled {
+///     retry_after: Duration::from_secs(5),
+/// };
+/// assert_eq!(error.recovery().kind(), RecoveryKind::Retry);
+/// assert_eq!(error.recovery().get_delay(), Some(Duration::from_secs(5)));
+/// assert_eq!(
+///     DatabaseError::Pool(PoolExhausted).recovery().kind(),
+///     RecoveryKind::Unavailable
+/// );
+/// assert_eq!(
+///     DatabaseError::InvalidCredentials.recovery().kind(),
+///     RecoveryKind::Never
+/// );
+/// ```
+#[cfg(feature = "derive")]
+pub use ::recoverable_macros::Recovery;
+
 // Naming Convention for Get/Set:
 //
 // This type uses an unconventional naming pattern where setters use plain names (e.g., `delay()`)
@@ -102,10 +189,21 @@ pub mod _documentation;
 /// let recovery = RecoveryInfo::retry();
 /// assert_eq!(recovery.kind(), RecoveryKind::Retry);
 /// ```
+///
+/// # Serialization
+///
+/// With the `serde` feature, recovery information serializes as a struct with a `kind` field
+/// holding the [`RecoveryKind`], optional `delay` and `delay_until` fields holding a relative
+/// or absolute delay hint, and an optional `reason` field holding the reason. As reasons are static strings, the reason is not restored when
+/// deserializing.
 #[derive(Debug, PartialEq, Clone, Eq, Hash)]
 pub struct RecoveryInfo {
     kind: RecoveryKind,
     delay: Option<Duration>,
+    // Whether `delay` is measured from the Unix epoch rather than from the time of
+    // classification. Stored as a flag to keep the type small.
+    delay_is_absolute: bool,
+    reason: Option<&'static str>,
 }
 
 /// Kind of recovery that can be attempted.
@@ -140,6 +238,12 @@ pub struct RecoveryInfo {
 /// let recovery = RecoveryInfo::unknown();
 /// assert_eq!(recovery.kind(), RecoveryKind::Unknown);
 /// ```
+///
+/// # Serialization
+///
+/// With the `serde` feature, a recovery kind serializes as the string returned by
+/// [`as_str`](Self::as_str). Strings that don't name a known kind deserialize as
+/// [`RecoveryKind::Unknown`], so payloads from newer versions remain readable.
 #[derive(Debug, PartialEq, Clone, Eq, Copy, Hash)]
 #[non_exhaustive]
 pub enum RecoveryKind {
@@ -186,6 +290,8 @@ impl RecoveryInfo {
         Self {
             kind: RecoveryKind::Unknown,
             delay: None,
+            delay_is_absolute: false,
+            reason: None,
         }
     }
 
@@ -220,6 +326,8 @@ impl RecoveryInfo {
         Self {
             kind: RecoveryKind::Never,
             delay: None,
+            delay_is_absolute: false,
+            reason: None,
         }
     }
 
@@ -248,6 +356,8 @@ impl RecoveryInfo {
         Self {
             kind: RecoveryKind::Retry,
             delay: None,
+            delay_is_absolute: false,
+            reason: None,
         }
     }
 
@@ -279,6 +389,8 @@ impl RecoveryInfo {
         Self {
             kind: RecoveryKind::Unavailable,
             delay: None,
+            delay_is_absolute: false,
+            reason: None,
         }
     }
 
@@ -313,12 +425,67 @@ impl RecoveryInfo {
     /// assert_eq!(recovery.get_delay(), Some(Duration::from_secs(300)));
     /// ```
     #[must_use]
-    pub const fn delay(self, delay: Duration) -> Self {
+    pub const fn delay(mut self, delay: Duration) -> Self {
         // See file-level "Naming Convention" comment for why this uses a plain name.
-        Self {
-            kind: self.kind,
-            delay: Some(delay),
-        }
+        self.delay = Some(delay);
+        self.delay_is_absolute = false;
+        self
+    }
+
+    /// Attaches a delay hint that ends at the given point in time.
+    ///
+    /// Unlike [`delay`](Self::delay), which is relative to the time of classification, the
+    /// hint stays accurate no matter how long the recovery information is kept before it is
+    /// acted upon, such as while it sits in a queue. Use this for absolute timing guidance,
+    /// such as a `Retry-After` header carrying an HTTP date.
+    ///
+    /// Retrieve the hint with [`get_delay_until`](Self::get_delay_until), or the remaining
+    /// wait with [`get_delay_from`](Self::get_delay_from). Replaces any previous delay hint.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// use recoverable::RecoveryInfo;
+    ///
+    /// let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+    /// let recovery = RecoveryInfo::retry().delay_until(now + Duration::from_secs(30));
+    ///
+    /// assert_eq!(recovery.get_delay_from(now), Some(Duration::from_secs(30)));
+    /// assert_eq!(recovery.get_delay(), None);
+    /// ```
+    #[must_use]
+    pub fn delay_until(mut self, time: SystemTime) -> Self {
+        // See file-level "Naming Convention" comment for why this uses a plain name.
+        // Points in time before the epoch have passed anyway, so clamping them is lossless
+        // for the remaining wait.
+        self.delay = Some(time.duration_since(SystemTime::UNIX_EPOCH).unwrap_or(Duration::ZERO));
+        self.delay_is_absolute = true;
+        self
+    }
+
+    /// Attaches the reason for this recovery information.
+    ///
+    /// The reason is a short, stable identifier for the cause of the condition, such as
+    /// `"throttled"` or `"connection_reset"`. It does not influence how the condition is
+    /// handled, but lets telemetry break down recovery attempts by cause. Reasons are static
+    /// strings, which keeps attaching them free and the cardinality of telemetry dimensions low.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use recoverable::{RecoveryInfo, RecoveryKind};
+    ///
+    /// let recovery = RecoveryInfo::retry().reason("throttled");
+    /// assert_eq!(recovery.kind(), RecoveryKind::Retry);
+    /// assert_eq!(recovery.get_reason(), Some("throttled"));
+    /// ```
+    #[must_use]
+    pub const fn reason(mut self, reason: &'static str) -> Self {
+        // See file-level "Naming Convention" comment for why this uses a plain name.
+        self.reason = Some(reason);
+        self
     }
 
     /// Returns the recovery kind for this instance.
@@ -345,7 +512,9 @@ impl RecoveryInfo {
     ///
     /// Use together with [`kind`](Self::kind) to determine both whether a condition is
     /// recoverable and whether an explicit delay is provided. Returns `Some(duration)` when
-    /// a delay has been set, and `None` otherwise.
+    /// a delay has been set, and `None` otherwise. Delay hints set via
+    /// [`delay_until`](Self::delay_until) are not relative to a known point in time, so
+    /// they return `None`; use [`get_delay_from`](Self::get_delay_from) to handle both.
     ///
     /// The meaning of the returned delay depends on the recovery kind:
     /// - For [`RecoveryInfo::retry`]: High-confidence timing guidance indicating when recovery will likely succeed.
@@ -383,8 +552,166 @@ impl RecoveryInfo {
     /// ```
     #[must_use]
     pub const fn get_delay(&self) -> Option<Duration> {
+        // See file-level "Naming Convention" comment for why this uses the `get_` prefix.
+        if self.delay_is_absolute { None } else { self.delay }
+    }
+
+    /// Returns the point in time at which the delay hint ends, if one was set via
+    /// [`delay_until`](Self::delay_until).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// use recoverable::RecoveryInfo;
+    ///
+    /// let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+    /// assert_eq!(
+    ///     RecoveryInfo::retry().delay_until(time).get_delay_until(),
+    ///     Some(time)
+    /// );
+    /// assert_eq!(
+    ///     RecoveryInfo::retry()
+    ///         .delay(Duration::from_secs(1))
+    ///         .get_delay_until(),
+    ///     None
+    /// );
+    /// ```
+    #[must_use]
+    pub fn get_delay_until(&self) -> Option<SystemTime> {
         // See file-level "Naming Convention" comment for why this uses the `get_` prefix.
         self.delay
+            .filter(|_| self.delay_is_absolute)
+            .and_then(|delay| SystemTime::UNIX_EPOCH.checked_add(delay))
+    }
+
+    /// Returns the remaining delay at `now`, for delay hints set via either
+    /// [`delay`](Self::delay) or [`delay_until`](Self::delay_until).
+    ///
+    /// Relative delay hints are returned as they are. For absolute delay hints, the time left
+    /// until the hint ends is returned, or [`Duration::ZERO`] if it has already passed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// use recoverable::RecoveryInfo;
+    ///
+    /// let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+    ///
+    /// let relative = RecoveryInfo::retry().delay(Duration::from_secs(5));
+    /// assert_eq!(relative.get_delay_from(now), Some(Duration::from_secs(5)));
+    ///
+    /// let passed = RecoveryInfo::retry().delay_until(now - Duration::from_secs(5));
+    /// assert_eq!(passed.get_delay_from(now), Some(Duration::ZERO));
+    /// ```
+    #[must_use]
+    pub fn get_delay_from(&self, now: SystemTime) -> Option<Duration> {
+        // See file-level "Naming Convention" comment for why this uses the `get_` prefix.
+        let delay = self.delay?;
+        if !self.delay_is_absolute {
+            return Some(delay);
+        }
+
+        let now = now.duration_since(SystemTime::UNIX_EPOCH).unwrap_or(Duration::ZERO);
+        Some(delay.saturating_sub(now))
+    }
+
+    /// Returns the reason, if one was set via [`reason`](Self::reason).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use recoverable::RecoveryInfo;
+    ///
+    /// assert_eq!(
+    ///     RecoveryInfo::unavailable()
+    ///         .reason("maintenance")
+    ///         .get_reason(),
+    ///     Some("maintenance")
+    /// );
+    /// assert_eq!(RecoveryInfo::unavailable().get_reason(), None);
+    /// ```
+    #[must_use]
+    pub const fn get_reason(&self) -> Option<&'static str> {
+        // See file-level "Naming Convention" comment for why this uses the `get_` prefix.
+        self.reason
+    }
+
+    /// Combines this recovery information with the recovery information of another condition.
+    ///
+    /// Use this to classify composite operations, such as a batch of requests, where each
+    /// part has its own recovery information. The most restrictive kind wins, in the
+    /// following order of precedence:
+    ///
+    /// 1. [`RecoveryKind::Never`]: retrying cannot fix the permanently failed part.
+    /// 2. [`RecoveryKind::Unavailable`]
+    /// 3. [`RecoveryKind::Retry`]
+    /// 4. [`RecoveryKind::Unknown`]
+    ///
+    /// When both sides have the same kind, the longer delay is kept, so a retry with a delay
+    /// takes precedence over a retry without one. A relative and an absolute delay hint can't
+    /// be compared without knowing the current time, so in that case the absolute one is kept. The [reason](Self::reason) of the winning
+    /// side is kept; for the same kind, the reason of `self` takes precedence over the reason
+    /// of `other`.
+    ///
+    /// Combining is associative, and [`RecoveryInfo::unknown`] is its identity. Collecting an
+    /// iterator of recovery information combines all items the same way and returns
+    /// [`RecoveryInfo::unknown`] for an empty iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use recoverable::{RecoveryInfo, RecoveryKind};
+    ///
+    /// let retry = RecoveryInfo::retry().delay(Duration::from_secs(5));
+    /// assert_eq!(retry.clone().combine(RecoveryInfo::retry()), retry);
+    /// assert_eq!(
+    ///     retry.combine(RecoveryInfo::never()).kind(),
+    ///     RecoveryKind::Never
+    /// );
+    ///
+    /// let batch: RecoveryInfo = [
+    ///     RecoveryInfo::retry().delay(Duration::from_secs(1)),
+    ///     RecoveryInfo::retry().delay(Duration::from_secs(10)),
+    ///     RecoveryInfo::unknown(),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// assert_eq!(batch, RecoveryInfo::retry().delay(Duration::from_secs(10)));
+    /// ```
+    #[must_use]
+    pub fn combine(self, other: Self) -> Self {
+        match self.kind.precedence().cmp(&other.kind.precedence()) {
+            Ordering::Greater => self,
+            Ordering::Less => other,
+            Ordering::Equal => {
+                let (delay, delay_is_absolute) = if self.delay_is_absolute == other.delay_is_absolute {
+                    (self.delay.max(other.delay), self.delay_is_absolute)
+                } else if self.delay_is_absolute {
+                    (self.delay, true)
+                } else {
+                    (other.delay, true)
+                };
+                Self {
+                    kind: self.kind,
+                    delay,
+                    delay_is_absolute,
+                    reason: self.reason.or(other.reason),
+                }
+            }
+        }
+    }
+}
+
+impl FromIterator<Self> for RecoveryInfo {
+    /// Combines all recovery information with [`RecoveryInfo::combine`].
+    fn from_iter<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::unknown(), Self::combine)
     }
 }
 
@@ -459,10 +786,46 @@ where
     }
 }
 
+impl<T: Recovery + ?Sized> Recovery for &T {
+    fn recovery(&self) -> RecoveryInfo {
+        (**self).recovery()
+    }
+}
+
+impl<T: Recovery + ?Sized> Recovery for Box<T> {
+    fn recovery(&self) -> RecoveryInfo {
+        (**self).recovery()
+    }
+}
+
+impl<T: Recovery + ?Sized> Recovery for Arc<T> {
+    fn recovery(&self) -> RecoveryInfo {
+        (**self).recovery()
+    }
+}
+
+impl<T: Recovery + ToOwned + ?Sized> Recovery for Cow<'_, T> {
+    fn recovery(&self) -> RecoveryInfo {
+        (**self).recovery()
+    }
+}
+
+/// Returns the recovery information of the value, or [`RecoveryInfo::never`] for `None`, as
+/// there is nothing to recover from.
+impl<T: Recovery> Recovery for Option<T> {
+    fn recovery(&self) -> RecoveryInfo {
+        self.as_ref().map_or_else(RecoveryInfo::never, Recovery::recovery)
+    }
+}
+
 impl Display for RecoveryInfo {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if let Some(delay) = self.delay {
-            return write!(f, "{} (delay {:?})", self.kind, delay);
+        match self.delay {
+            Some(delay) if self.delay_is_absolute => {
+                return write!(f, "{} (delay until {:?} since the Unix epoch)", self.kind, delay);
+            }
+            Some(delay) => return write!(f, "{} (delay {:?})", self.kind, delay),
+            None => {}
         }
 
         Display::fmt(&self.kind, f)
@@ -489,6 +852,15 @@ impl RecoveryKind {
             Self::Unavailable => "unavailable",
         }
     }
+    /// Returns the precedence of this kind when combining recovery information.
+    const fn precedence(self) -> u8 {
+        match self {
+            Self::Unknown => 0,
+            Self::Retry => 1,
+            Self::Unavailable => 2,
+            Self::Never => 3,
+        }
+    }
 }
 
 impl Display for RecoveryKind {
@@ -587,6 +959,121 @@ mod tests {
         assert_eq!(recovery.get_delay(), Some(Duration::from_secs(1)));
     }
 
+    #[test]
+    fn combine_precedence() {
+        let ordered = [
+            RecoveryInfo::unknown(),
+            RecoveryInfo::retry(),
+            RecoveryInfo::retry().delay(Duration::from_secs(1)),
+            RecoveryInfo::unavailable(),
+            RecoveryInfo::never(),
+        ];
+
+        for (low, lower) in ordered.iter().enumerate() {
+            for higher in &ordered[low..] {
+                assert_eq!(&lower.clone().combine(higher.clone()), higher);
+                assert_eq!(&higher.clone().combine(lower.clone()), higher);
+            }
+        }
+    }
+
+    #[test]
+    fn combine_keeps_longest_delay() {
+        let short = RecoveryInfo::retry().delay(Duration::from_secs(1));
+        let long = RecoveryInfo::retry().delay(Duration::from_secs(10));
+        assert_eq!(short.clone().combine(long.clone()), long);
+        assert_eq!(long.clone().combine(short), long);
+
+        let unavailable = RecoveryInfo::unavailable().delay(Duration::from_mins(1));
+        assert_eq!(unavailable.clone().combine(RecoveryInfo::unavailable()), unavailable);
+
+        // The delay of a lower precedence kind is discarded
+        assert_eq!(
+            RecoveryInfo::unavailable().combine(RecoveryInfo::retry().delay(Duration::from_secs(1))),
+            RecoveryInfo::unavailable()
+        );
+    }
+
+    #[test]
+    fn delay_until_ok() {
+        let epoch = SystemTime::UNIX_EPOCH;
+        let now = epoch + Duration::from_secs(100);
+        let recovery = RecoveryInfo::retry().delay_until(now + Duration::from_secs(10));
+
+        assert_eq!(recovery.get_delay(), None);
+        assert_eq!(recovery.get_delay_until(), Some(now + Duration::from_secs(10)));
+        assert_eq!(recovery.get_delay_from(now), Some(Duration::from_secs(10)));
+        assert_eq!(recovery.get_delay_from(now + Duration::from_mins(1)), Some(Duration::ZERO));
+        assert_eq!(recovery.to_string(), "retry (delay until 110s since the Unix epoch)");
+
+        // Points in time before the epoch are clamped to the epoch
+        let before_epoch = RecoveryInfo::retry().delay_until(epoch - Duration::from_secs(1));
+        assert_eq!(before_epoch.get_delay_until(), Some(epoch));
+        assert_eq!(before_epoch.get_delay_from(now), Some(Duration::ZERO));
+
+        // Setting a relative delay replaces the absolute one and vice versa
+        let relative = recovery.delay(Duration::from_secs(5));
+        assert_eq!(relative.get_delay(), Some(Duration::from_secs(5)));
+        assert_eq!(relative.get_delay_until(), None);
+        assert_eq!(relative.get_delay_from(now), Some(Duration::from_secs(5)));
+        let absolute = relative.delay_until(now);
+        assert_eq!(absolute.get_delay(), None);
+        assert_eq!(absolute.get_delay_until(), Some(now));
+
+        assert_eq!(RecoveryInfo::retry().get_delay_from(now), None);
+        assert_eq!(RecoveryInfo::retry().get_delay_until(), None);
+    }
+
+    #[test]
+    fn combine_prefers_absolute_delay() {
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(100);
+        let absolute = RecoveryInfo::retry().delay_until(time);
+        let later = RecoveryInfo::retry().delay_until(time + Duration::from_secs(1));
+        let relative = RecoveryInfo::retry().delay(Duration::from_secs(1_000));
+
+        assert_eq!(absolute.clone().combine(relative.clone()), absolute);
+        assert_eq!(relative.combine(absolute.clone()), absolute);
+        assert_eq!(absolute.clone().combine(RecoveryInfo::retry()), absolute);
+        assert_eq!(absolute.combine(later.clone()), later);
+    }
+
+    #[test]
+    fn reason_ok() {
+        assert_eq!(RecoveryInfo::retry().get_reason(), None);
+
+        let recovery = RecoveryInfo::retry().reason("throttled").delay(Duration::from_secs(1));
+        assert_eq!(recovery.get_reason(), Some("throttled"));
+        assert_eq!(recovery.get_delay(), Some(Duration::from_secs(1)));
+        assert_eq!(recovery.kind(), RecoveryKind::Retry);
+
+        // Setting the reason again replaces the previous reason
+        let updated = recovery.reason("connection_reset");
+        assert_eq!(updated.get_reason(), Some("connection_reset"));
+    }
+
+    #[test]
+    fn combine_keeps_reason_of_winner() {
+        let throttled = RecoveryInfo::retry().reason("throttled");
+        let maintenance = RecoveryInfo::unavailable().reason("maintenance");
+        assert_eq!(throttled.clone().combine(maintenance.clone()), maintenance);
+
+        let reset = RecoveryInfo::retry().reason("connection_reset");
+        assert_eq!(throttled.clone().combine(reset.clone()).get_reason(), Some("throttled"));
+        assert_eq!(reset.clone().combine(throttled).get_reason(), Some("connection_reset"));
+        assert_eq!(RecoveryInfo::retry().combine(reset).get_reason(), Some("connection_reset"));
+    }
+
+    #[test]
+    fn collect_combines_all() {
+        assert_eq!(std::iter::empty().collect::<RecoveryInfo>(), RecoveryInfo::unknown());
+        assert_eq!(
+            [RecoveryInfo::retry(), RecoveryInfo::unknown(), RecoveryInfo::unavailable()]
+                .into_iter()
+                .collect::<RecoveryInfo>(),
+            RecoveryInfo::unavailable()
+        );
+    }
+
     #[test]
     fn assert_result_implements_recover() {
         assert_impl_all!(Result<TestType, TestType>: Recovery);
@@ -621,6 +1108,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wrapper_implementations() {
+        #[derive(Debug, Clone)]
+        struct Throttled;
+        impl Recovery for Throttled {
+            fn recovery(&self) -> RecoveryInfo {
+                RecoveryInfo::retry().reason("throttled")
+            }
+        }
+
+        let expected = Throttled.recovery();
+        assert_eq!(<&Throttled as Recovery>::recovery(&&Throttled), expected);
+        assert_eq!(Box::new(Throttled).recovery(), expected);
+        assert_eq!(Arc::new(Throttled).recovery(), expected);
+        assert_eq!(Cow::Borrowed(&Throttled).recovery(), expected);
+        assert_eq!(Cow::<Throttled>::Owned(Throttled).recovery(), expected);
+        assert_eq!(Some(Throttled).recovery(), expected);
+        assert_eq!(None::<Throttled>.recovery(), RecoveryInfo::never());
+
+        let boxed: Box<dyn Recovery> = Box::new(Throttled);
+        assert_eq!(boxed.recovery(), expected);
+        let shared: Arc<dyn Recovery + Send + Sync> = Arc::new(Throttled);
+        assert_eq!(shared.recovery(), expected);
+    }
+
     // Result implements Recovery
     #[derive(Debug)]
     struct TestType;
@@ -0,0 +1,261 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Serialization of [`RecoveryInfo`] and [`RecoveryKind`].
+
+use std::fmt::Formatter;
+use std::time::{Duration, SystemTime};
+
+use serde_core::de::{self, MapAccess, SeqAccess, Visitor};
+use serde_core::ser::SerializeStruct;
+use serde_core::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{RecoveryInfo, RecoveryKind};
+
+const FIELDS: &[&str] = &["kind", "delay", "reason", "delay_until"];
+
+impl Serialize for RecoveryKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RecoveryKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct KindVisitor;
+
+        impl Visitor<'_> for KindVisitor {
+            type Value = RecoveryKind;
+
+            fn expecting(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a recovery kind string")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(match v {
+                    "retry" => RecoveryKind::Retry,
+                    "never" => RecoveryKind::Never,
+                    "unavailable" => RecoveryKind::Unavailable,
+                    // Kinds added by newer versions are handled as unknown, as recommended for
+                    // matching on the non-exhaustive enum
+                    _ => RecoveryKind::Unknown,
+                })
+            }
+        }
+
+        deserializer.deserialize_str(KindVisitor)
+    }
+}
+
+impl Serialize for RecoveryInfo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("RecoveryInfo", FIELDS.len())?;
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field("delay", &self.get_delay())?;
+        state.serialize_field("reason", &self.reason)?;
+        state.serialize_field("delay_until", &self.get_delay_until())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for RecoveryInfo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("RecoveryInfo", FIELDS, InfoVisitor)
+    }
+}
+
+struct InfoVisitor;
+
+impl<'de> Visitor<'de> for InfoVisitor {
+    type Value = RecoveryInfo;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("recovery information")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let kind = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let delay = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        // Reasons are static strings, so a serialized reason can't be restored
+        seq.next_element::<de::IgnoredAny>()?;
+        let delay_until = seq.next_element()?.flatten();
+        Ok(info(kind, delay, delay_until))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut kind: Option<RecoveryKind> = None;
+        let mut delay: Option<Option<Duration>> = None;
+        let mut delay_until: Option<Option<SystemTime>> = None;
+
+        while let Some(field) = map.next_key::<Field>()? {
+            match field {
+                Field::Kind if kind.is_some() => return Err(de::Error::duplicate_field("kind")),
+                Field::Kind => kind = Some(map.next_value()?),
+                Field::Delay if delay.is_some() => return Err(de::Error::duplicate_field("delay")),
+                Field::Delay => delay = Some(map.next_value()?),
+                Field::DelayUntil if delay_until.is_some() => return Err(de::Error::duplicate_field("delay_until")),
+                Field::DelayUntil => delay_until = Some(map.next_value()?),
+                // Reasons are static strings, so a serialized reason is ignored like any other field
+                Field::Other => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+
+        let kind = kind.ok_or_else(|| de::Error::missing_field("kind"))?;
+        Ok(info(kind, delay.flatten(), delay_until.flatten()))
+    }
+}
+
+fn info(kind: RecoveryKind, delay: Option<Duration>, delay_until: Option<SystemTime>) -> RecoveryInfo {
+    let info = RecoveryInfo {
+        kind,
+        ..RecoveryInfo::unknown()
+    };
+    match (delay, delay_until) {
+        // An absolute hint is not affected by the time the payload spent in transit
+        (_, Some(time)) => info.delay_until(time),
+        (Some(delay), None) => info.delay(delay),
+        (None, None) => info,
+    }
+}
+
+/// A field of serialized [`RecoveryInfo`].
+enum Field {
+    Kind,
+    Delay,
+    DelayUntil,
+    Other,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl Visitor<'_> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a field identifier")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(match v {
+                    "kind" => Field::Kind,
+                    "delay" => Field::Delay,
+                    "delay_until" => Field::DelayUntil,
+                    _ => Field::Other,
+                })
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_round_trip() {
+        for kind in [
+            RecoveryKind::Unknown,
+            RecoveryKind::Retry,
+            RecoveryKind::Never,
+            RecoveryKind::Unavailable,
+        ] {
+            let serialized = serde_json::to_string(&kind).expect("recovery kinds are always serializable");
+            assert_eq!(serialized, format!("\"{kind}\""));
+            assert_eq!(serde_json::from_str::<RecoveryKind>(&serialized).expect("round trip"), kind);
+        }
+    }
+
+    #[test]
+    fn unrecognized_kind_is_unknown() {
+        let kind: RecoveryKind = serde_json::from_str("\"reticulating\"").expect("any string is accepted");
+        assert_eq!(kind, RecoveryKind::Unknown);
+    }
+
+    #[test]
+    fn info_serialize() {
+        assert_eq!(
+            serde_json::to_string(&RecoveryInfo::never()).expect("serializable"),
+            r#"{"kind":"never","delay":null,"reason":null,"delay_until":null}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&RecoveryInfo::retry().delay(Duration::from_millis(1500)).reason("throttled")).expect("serializable"),
+            r#"{"kind":"retry","delay":{"secs":1,"nanos":500000000},"reason":"throttled","delay_until":null}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&RecoveryInfo::retry().delay_until(SystemTime::UNIX_EPOCH + Duration::from_secs(30)))
+                .expect("serializable"),
+            r#"{"kind":"retry","delay":null,"reason":null,"delay_until":{"secs_since_epoch":30,"nanos_since_epoch":0}}"#
+        );
+    }
+
+    #[test]
+    fn info_deserialize() {
+        let info: RecoveryInfo =
+            serde_json::from_str(r#"{"kind":"unavailable","delay":{"secs":30,"nanos":0},"reason":"maintenance"}"#).expect("valid");
+        assert_eq!(info, RecoveryInfo::unavailable().delay(Duration::from_secs(30)));
+
+        // The delay and any unrecognized fields are optional
+        let info: RecoveryInfo = serde_json::from_str(r#"{"kind":"retry","attempt":3}"#).expect("valid");
+        assert_eq!(info, RecoveryInfo::retry());
+
+        // Sequences are accepted for formats that serialize structs as tuples
+        let info: RecoveryInfo = serde_json::from_str(r#"["never",null]"#).expect("valid");
+        assert_eq!(info, RecoveryInfo::never());
+        let info: RecoveryInfo = serde_json::from_str(r#"["retry",null,"throttled"]"#).expect("valid");
+        assert_eq!(info, RecoveryInfo::retry());
+        let info: RecoveryInfo =
+            serde_json::from_str(r#"["retry",null,null,{"secs_since_epoch":30,"nanos_since_epoch":0}]"#).expect("valid");
+        assert_eq!(
+            info,
+            RecoveryInfo::retry().delay_until(SystemTime::UNIX_EPOCH + Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn info_round_trip_absolute_delay() {
+        let info = RecoveryInfo::unavailable().delay_until(SystemTime::UNIX_EPOCH + Duration::from_millis(1500));
+        let serialized = serde_json::to_string(&info).expect("serializable");
+        assert_eq!(serde_json::from_str::<RecoveryInfo>(&serialized).expect("round trip"), info);
+
+        // The absolute hint takes precedence over a relative one
+        let info: RecoveryInfo = serde_json::from_str(
+            r#"{"kind":"retry","delay":{"secs":5,"nanos":0},"delay_until":{"secs_since_epoch":30,"nanos_since_epoch":0}}"#,
+        )
+        .expect("valid");
+        assert_eq!(
+            info,
+            RecoveryInfo::retry().delay_until(SystemTime::UNIX_EPOCH + Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn info_deserialize_invalid() {
+        let error = serde_json::from_str::<RecoveryInfo>(r#"{"delay":null}"#).unwrap_err();
+        assert!(error.to_string().contains("missing field `kind`"), "{error}");
+
+        let error = serde_json::from_str::<RecoveryInfo>(r#"{"kind":"retry","kind":"never"}"#).unwrap_err();
+        assert!(error.to_string().contains("duplicate field `kind`"), "{error}");
+
+        let error = serde_json::from_str::<RecoveryInfo>(r#"{"kind":"retry","delay":null,"delay":null}"#).unwrap_err();
+        assert!(error.to_string().contains("duplicate field `delay`"), "{error}");
+
+        let error = serde_json::from_str::<RecoveryInfo>(r#"{"kind":"retry","delay_until":null,"delay_until":null}"#).unwrap_err();
+        assert!(error.to_string().contains("duplicate field `delay_until`"), "{error}");
+
+        let error = serde_json::from_str::<RecoveryInfo>(r#"["retry"]"#).unwrap_err();
+        assert!(error.to_string().contains("invalid length 1"), "{error}");
+
+        let error = serde_json::from_str::<RecoveryInfo>("[]").unwrap_err();
+        assert!(error.to_string().contains("invalid length 0"), "{error}");
+
+        let error = serde_json::from_str::<RecoveryInfo>("42").unwrap_err();
+        assert!(error.to_string().contains("expected recovery information"), "{error}");
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Integration tests for `#[derive(Recovery)]`.
+
+#![cfg(feature = "derive")]
+
+use std::time::Duration;
+
+use recoverable::{Recovery, RecoveryInfo, RecoveryKind};
+
+#[derive(Debug, Recovery)]
+#[recovery(unavailable)]
+struct Outage;
+
+#[derive(Debug, Recovery)]
+#[recovery(never)]
+enum TestError {
+    #[recovery(retry)]
+    Timeout,
+    #[recovery(retry, delay_field = "retry_after")]
+    Throttled {
+        retry_after: Duration,
+    },
+    #[recovery(unavailable, delay_field = "1")]
+    Down(
+        #[expect(dead_code, reason = "only the delay is read")] &'static str,
+        Option<Duration>,
+    ),
+    #[recovery(forward)]
+    Outage(Outage),
+    #[recovery(unknown)]
+    Unclassified,
+    NotFound,
+}
+
+#[derive(Debug, Recovery)]
+enum Wrapper<E> {
+    #[recovery(forward)]
+    Inner(E),
+}
+
+#[derive(Debug, Recovery)]
+enum Never {}
+
+#[test]
+fn variants_are_classified() {
+    assert_eq!(TestError::Timeout.recovery(), RecoveryInfo::retry());
+    assert_eq!(TestError::Unclassified.recovery(), RecoveryInfo::unknown());
+    assert_eq!(TestError::NotFound.recovery(), RecoveryInfo::never());
+    assert_eq!(TestError::Outage(Outage).recovery(), RecoveryInfo::unavailable());
+}
+
+#[test]
+fn delay_is_read_from_field() {
+    let throttled = TestError::Throttled {
+        retry_after: Duration::from_secs(5),
+    };
+    assert_eq!(throttled.recovery(), RecoveryInfo::retry().delay(Duration::from_secs(5)));
+
+    let down = TestError::Down("maintenance", Some(Duration::from_mins(1)));
+    assert_eq!(down.recovery(), RecoveryInfo::unavailable().delay(Duration::from_mins(1)));
+
+    let down = TestError::Down("maintenance", None);
+    assert_eq!(down.recovery(), RecoveryInfo::unavailable());
+}
+
+#[test]
+fn generic_variant_is_forwarded() {
+    assert_eq!(Wrapper::Inner(TestError::Timeout).recovery().kind(), RecoveryKind::Retry);
+    assert_eq!(Wrapper::Inner(Outage).recovery().kind(), RecoveryKind::Unavailable);
+}
+
+#[test]
+fn empty_enum_implements_recovery() {
+    fn assert_recovery<T: Recovery>() {}
+
+    assert_recovery::<Never>();
+}
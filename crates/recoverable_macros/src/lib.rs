@@ -0,0 +1,25 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+//! Macros for the [`recoverable`](https://docs.rs/recoverable) crate.
+//!
+//! # Provided Derives
+//!
+//! * `#[derive(Recovery)]`: Implements the `recoverable::Recovery` trait from
+//!   `#[recovery(...)]` attributes on the type and its variants.
+
+#![doc(html_logo_url = "https://media.githubusercontent.com/media/microsoft/oxidizer/refs/heads/main/crates/recoverable/logo.png")]
+#![doc(html_favicon_url = "https://media.githubusercontent.com/media/microsoft/oxidizer/refs/heads/main/crates/recoverable/favicon.ico")]
+
+use proc_macro::TokenStream;
+use syn::{Path, parse_quote};
+
+#[proc_macro_derive(Recovery, attributes(recovery))]
+#[cfg_attr(test, mutants::skip)]
+#[expect(missing_docs, reason = "Documented in the recoverable crate's reexport")]
+pub fn derive_recovery(input: TokenStream) -> TokenStream {
+    let root_path: Path = parse_quote!(::recoverable);
+    recoverable_macros_impl::derive_recovery(input.into(), &root_path).into()
+}
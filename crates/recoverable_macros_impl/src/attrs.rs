@@ -0,0 +1,117 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Parsing of `#[recovery(...)]` attributes.
+
+use proc_macro2::Span;
+use syn::spanned::Spanned;
+use syn::{Attribute, Fields, Ident, LitStr, Member};
+
+/// The recovery kinds that map to a `RecoveryInfo` constructor of the same name.
+const KINDS: [&str; 4] = ["unknown", "never", "retry", "unavailable"];
+
+/// A parsed `#[recovery(...)]` attribute, before it is checked against the fields it applies to.
+#[derive(Clone)]
+pub(crate) struct RecoveryAttr {
+    span: Span,
+    kind: Option<Ident>,
+    delay_field: Option<LitStr>,
+    forward: bool,
+}
+
+/// How the recovery information of a struct or variant is produced.
+pub(crate) enum Classification {
+    /// A fixed recovery kind, optionally with a delay taken from a field.
+    Kind { kind: Ident, delay: Option<Member> },
+
+    /// The recovery information of the only field.
+    Forward(Member),
+}
+
+/// Parses the `#[recovery(...)]` attribute among `attrs`, if any.
+pub(crate) fn parse_attrs(attrs: &[Attribute]) -> syn::Result<Option<RecoveryAttr>> {
+    let mut result: Option<RecoveryAttr> = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("recovery")) {
+        if result.is_some() {
+            return Err(syn::Error::new_spanned(attr, "duplicate #[recovery(...)] attribute"));
+        }
+        let mut parsed = RecoveryAttr {
+            span: attr.span(),
+            kind: None,
+            delay_field: None,
+            forward: false,
+        };
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("delay_field") {
+                if parsed.delay_field.is_some() {
+                    return Err(meta.error("duplicate `delay_field`"));
+                }
+                parsed.delay_field = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("forward") {
+                parsed.forward = true;
+            } else if let Some(ident) = meta.path.get_ident().filter(|ident| KINDS.iter().any(|kind| ident == kind)) {
+                if parsed.kind.is_some() {
+                    return Err(meta.error("only one recovery kind can be specified"));
+                }
+                parsed.kind = Some(ident.clone());
+            } else {
+                return Err(meta.error(
+                    "unknown recovery attribute, expected one of `unknown`, `never`, `retry`, `unavailable`, `delay_field`, or `forward`",
+                ));
+            }
+            Ok(())
+        })?;
+        result = Some(parsed);
+    }
+    Ok(result)
+}
+
+impl RecoveryAttr {
+    /// Checks that the attribute can be used as the default for all variants of an enum.
+    pub(crate) fn ensure_default(&self) -> syn::Result<()> {
+        if self.delay_field.is_some() || self.forward {
+            return Err(syn::Error::new(
+                self.span,
+                "the default #[recovery(...)] attribute of an enum only accepts a recovery kind",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks the attribute against the fields it applies to.
+    pub(crate) fn resolve(&self, fields: &Fields) -> syn::Result<Classification> {
+        match (&self.kind, self.forward) {
+            (Some(_), true) => Err(syn::Error::new(self.span, "`forward` can't be combined with a recovery kind")),
+            (None, false) => Err(syn::Error::new(
+                self.span,
+                "expected a recovery kind: `unknown`, `never`, `retry`, or `unavailable`",
+            )),
+            (None, true) => {
+                if self.delay_field.is_some() {
+                    return Err(syn::Error::new(self.span, "`delay_field` can't be combined with `forward`"));
+                }
+                let mut members = fields.members();
+                match (members.next(), members.next()) {
+                    (Some(member), None) => Ok(Classification::Forward(member)),
+                    _ => Err(syn::Error::new(self.span, "`forward` requires exactly one field")),
+                }
+            }
+            (Some(kind), false) => {
+                let delay = self.delay_field.as_ref().map(|name| find_member(fields, name)).transpose()?;
+                Ok(Classification::Kind { kind: kind.clone(), delay })
+            }
+        }
+    }
+}
+
+/// Finds the field named by `name`, which is a field name or the index of a tuple field.
+fn find_member(fields: &Fields, name: &LitStr) -> syn::Result<Member> {
+    let value = name.value();
+    fields
+        .members()
+        .find(|member| match member {
+            Member::Named(ident) => *ident == value,
+            Member::Unnamed(index) => index.index.to_string() == value,
+        })
+        .ok_or_else(|| syn::Error::new(name.span(), format!("no field named `{value}`")))
+}
@@ -0,0 +1,139 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+//! Macros for the [`recoverable`](https://docs.rs/recoverable) crate.
+
+#![doc(html_logo_url = "https://media.githubusercontent.com/media/microsoft/oxidizer/refs/heads/main/crates/recoverable/logo.png")]
+#![doc(html_favicon_url = "https://media.githubusercontent.com/media/microsoft/oxidizer/refs/heads/main/crates/recoverable/favicon.ico")]
+
+// Internal implementation crate (no proc-macro entrypoints).
+// Provides a parameterized function to generate a Recovery derive impl
+// using an arbitrary crate root path
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Path, Type, WherePredicate, parse_quote};
+
+mod attrs;
+
+use attrs::{Classification, parse_attrs};
+
+/// Core implementation used by `recoverable_macros`.
+///
+/// This crate is a normal library crate (not `proc-macro`), so we operate purely
+/// on `proc_macro2::TokenStream` and let the wrapper perform the conversion.
+#[must_use]
+pub fn derive_recovery(input: TokenStream2, root_path: &Path) -> TokenStream2 {
+    let parsed: syn::Result<DeriveInput> = syn::parse2(input);
+    parsed
+        .and_then(|di| impl_recovery(&di, root_path))
+        .unwrap_or_else(|e| e.to_compile_error())
+}
+
+fn impl_recovery(input: &DeriveInput, root_path: &Path) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let container = parse_attrs(&input.attrs)?;
+    let mut bounds: Vec<Type> = Vec::new();
+
+    let arms = match &input.data {
+        Data::Struct(s) => {
+            let Some(attr) = container else {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "#[derive(Recovery)] on a struct requires a #[recovery(...)] attribute",
+                ));
+            };
+            let classification = attr.resolve(&s.fields)?;
+            bounds.extend(classification.forwarded_type(&s.fields));
+            vec![classification.arm(&quote!(Self), root_path)]
+        }
+        Data::Enum(e) => {
+            if let Some(container) = &container {
+                container.ensure_default()?;
+            }
+            let mut arms = Vec::with_capacity(e.variants.len());
+            for variant in &e.variants {
+                let attr = match (parse_attrs(&variant.attrs)?, &container) {
+                    (Some(attr), _) => attr,
+                    (None, Some(default)) => default.clone(),
+                    (None, None) => {
+                        return Err(syn::Error::new_spanned(
+                            &variant.ident,
+                            "variant requires a #[recovery(...)] attribute, or the enum requires a default #[recovery(...)] attribute",
+                        ));
+                    }
+                };
+                let classification = attr.resolve(&variant.fields)?;
+                bounds.extend(classification.forwarded_type(&variant.fields));
+                let ident = &variant.ident;
+                arms.push(classification.arm(&quote!(Self::#ident), root_path));
+            }
+            arms
+        }
+        Data::Union(_u) => {
+            return Err(syn::Error::new_spanned(
+                input.ident.clone(),
+                "#[derive(Recovery)] does not support unions",
+            ));
+        }
+    };
+
+    let mut generics = input.generics.clone();
+    // Forwarded fields of generic types need to implement Recovery themselves. Concrete types
+    // are checked by the generated code, so only generic items receive the extra bounds.
+    if !generics.params.is_empty() && !bounds.is_empty() {
+        let where_clause = generics.make_where_clause();
+        for ty in bounds {
+            let predicate: WherePredicate = parse_quote!(#ty: #root_path::Recovery);
+            where_clause.predicates.push(predicate);
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // A reference to an empty enum is not considered uninhabited, so match on the value itself
+    let scrutinee = if arms.is_empty() { quote!(*self) } else { quote!(self) };
+
+    Ok(quote! {
+        impl #impl_generics #root_path::Recovery for #name #ty_generics #where_clause {
+            fn recovery(&self) -> #root_path::RecoveryInfo {
+                match #scrutinee {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+/// Returns the type of the field with the given member.
+fn field_type<'a>(fields: &'a Fields, member: &syn::Member) -> Option<&'a Type> {
+    fields
+        .members()
+        .zip(fields.iter())
+        .find_map(|(candidate, field)| (candidate == *member).then_some(&field.ty))
+}
+
+impl Classification {
+    fn forwarded_type(&self, fields: &Fields) -> Option<Type> {
+        match self {
+            Self::Forward(member) => field_type(fields, member).cloned(),
+            Self::Kind { .. } => None,
+        }
+    }
+
+    fn arm(&self, path: &TokenStream2, root_path: &Path) -> TokenStream2 {
+        match self {
+            Self::Kind { kind, delay: None } => quote! {
+                #path { .. } => #root_path::RecoveryInfo::#kind(),
+            },
+            Self::Kind { kind, delay: Some(member) } => quote! {
+                #path { #member: delay, .. } => #root_path::__private::with_delay(#root_path::RecoveryInfo::#kind(), delay),
+            },
+            Self::Forward(member) => quote! {
+                #path { #member: source, .. } => #root_path::Recovery::recovery(source),
+            },
+        }
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+#![expect(missing_docs, reason = "This is a test module")]
+
+use insta::assert_snapshot;
+use quote::quote;
+use recoverable_macros_impl::derive_recovery;
+
+fn expand(input: proc_macro2::TokenStream) -> String {
+    // Use the canonical ::recoverable root in test snapshots.
+    let root: syn::Path = syn::parse_quote!(::recoverable);
+    let ts = derive_recovery(input, &root);
+    // Pretty print if it parses as a file; fall back to raw tokens.
+    syn::parse_file(&ts.to_string()).map_or_else(|_| ts.to_string(), |f| prettyplease::unparse(&f))
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn enum_kinds() {
+    let input = quote! {
+        #[derive(Recovery)]
+        enum E {
+            #[recovery(unknown)]
+            A,
+            #[recovery(never)]
+            B(u32),
+            #[recovery(retry)]
+            C { x: u32 },
+            #[recovery(unavailable)]
+            D,
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn enum_default() {
+    let input = quote! {
+        #[derive(Recovery)]
+        #[recovery(never)]
+        enum E {
+            #[recovery(retry)]
+            Timeout,
+            NotFound,
+            Invalid(String),
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn enum_delay_field() {
+    let input = quote! {
+        #[derive(Recovery)]
+        enum E {
+            #[recovery(retry, delay_field = "retry_after")]
+            Throttled { retry_after: Duration, message: String },
+            #[recovery(unavailable, delay_field = "1")]
+            Down(String, Option<Duration>),
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn enum_forward() {
+    let input = quote! {
+        #[derive(Recovery)]
+        enum E {
+            #[recovery(forward)]
+            Inner(InnerError),
+            #[recovery(forward)]
+            Named { source: OtherError },
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn enum_empty() {
+    let input = quote! {
+        #[derive(Recovery)]
+        enum E {}
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn struct_kind() {
+    let input = quote! {
+        #[derive(Recovery)]
+        #[recovery(retry, delay_field = "retry_after")]
+        struct Throttled { retry_after: Duration }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn generics_forward_adds_bound() {
+    let input = quote! {
+        #[derive(Recovery)]
+        enum E<T, U> where U: Clone {
+            #[recovery(forward)]
+            Inner(T),
+            #[recovery(never)]
+            Other(U),
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn error_missing_variant_attr() {
+    let input = quote! {
+        #[derive(Recovery)]
+        enum E {
+            #[recovery(retry)]
+            A,
+            B,
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn error_missing_struct_attr() {
+    let input = quote! {
+        #[derive(Recovery)]
+        struct S;
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn error_unknown_attr() {
+    let input = quote! {
+        #[derive(Recovery)]
+        enum E {
+            #[recovery(sometimes)]
+            A,
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn error_conflicting_kinds() {
+    let input = quote! {
+        #[derive(Recovery)]
+        enum E {
+            #[recovery(retry, never)]
+            A,
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn error_forward_with_kind() {
+    let input = quote! {
+        #[derive(Recovery)]
+        enum E {
+            #[recovery(retry, forward)]
+            A(InnerError),
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn error_forward_requires_single_field() {
+    let input = quote! {
+        #[derive(Recovery)]
+        enum E {
+            #[recovery(forward)]
+            A(InnerError, u32),
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn error_unknown_delay_field() {
+    let input = quote! {
+        #[derive(Recovery)]
+        enum E {
+            #[recovery(retry, delay_field = "retry_after")]
+            A { delay: Duration },
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn error_default_with_delay_field() {
+    let input = quote! {
+        #[derive(Recovery)]
+        #[recovery(retry, delay_field = "retry_after")]
+        enum E {
+            A { retry_after: Duration },
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn error_duplicate_attr() {
+    let input = quote! {
+        #[derive(Recovery)]
+        enum E {
+            #[recovery(retry)]
+            #[recovery(never)]
+            A,
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn union_not_supported() {
+    let input = quote! {
+        #[derive(Recovery)]
+        #[recovery(never)]
+        union U { a: u32 }
+    };
+    assert_snapshot!(expand(input));
+}
@@ -5,6 +5,9 @@
 
 use std::fmt::Display;
 
+/// Common prefix of all attempt-related attribute keys.
+pub(crate) const ATTEMPT_PREFIX: &str = "resilience.attempt.";
+
 /// Attribute key for the attempt index.
 pub(crate) const ATTEMPT_INDEX: &str = "resilience.attempt.index";
 
@@ -1,15 +1,18 @@
 // Copyright (c) Microsoft Corporation.
 // Licensed under the MIT License.
 
-use std::sync::Mutex;
-use std::time::{Duration, Instant};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
 
 use tick::Clock;
 
 use super::{EngineOptions, EnterCircuitResult, ExitCircuitResult};
 use crate::breaker::constants::ERR_POISONED_LOCK;
 use crate::breaker::engine::probing::{AllowProbeResult, Probes, ProbingResult};
-use crate::breaker::{CircuitEngine, ExecutionInfo, ExecutionMode, ExecutionResult, HealthMetrics, HealthStatus};
+use crate::breaker::{
+    BreakerId, CircuitEngine, CircuitSnapshot, CircuitStateStore, ExecutionInfo, ExecutionMode, ExecutionResult, HealthMetrics,
+    HealthStatus,
+};
 
 /// Engine that manages the state of the circuit breaker.
 #[derive(Debug)]
@@ -17,6 +20,14 @@ pub(crate) struct EngineCore {
     state: Mutex<State>,
     options: EngineOptions,
     clock: Clock,
+    persistence: Option<Persistence>,
+}
+
+/// The store that the state transitions of the circuit are saved to.
+#[derive(Debug)]
+struct Persistence {
+    store: Arc<dyn CircuitStateStore>,
+    id: BreakerId,
 }
 
 impl EngineCore {
@@ -27,8 +38,36 @@ impl EngineCore {
             }),
             options,
             clock,
+            persistence: None,
         }
     }
+
+    /// Restores the state of the circuit from the store and saves all subsequent transitions to it.
+    pub(crate) fn with_state_store(mut self, store: Arc<dyn CircuitStateStore>, id: BreakerId) -> Self {
+        if let Some(snapshot) = store.load(&id) {
+            let state = State::restore(snapshot, self.clock.instant(), self.clock.system_time(), &self.options);
+            *self.state.get_mut().expect(ERR_POISONED_LOCK) = state;
+        }
+
+        self.persistence = Some(Persistence { store, id });
+        self
+    }
+
+    fn persist(&self, result: &ExitCircuitResult) {
+        let Some(persistence) = &self.persistence else {
+            return;
+        };
+
+        let snapshot = match result {
+            ExitCircuitResult::Unchanged => return,
+            ExitCircuitResult::Opened(_) | ExitCircuitResult::Reopened => CircuitSnapshot::Open {
+                until: self.clock.system_time() + self.options.break_duration,
+            },
+            ExitCircuitResult::Closed(_) => CircuitSnapshot::Closed,
+        };
+
+        persistence.store.save(&persistence.id, snapshot);
+    }
 }
 
 impl CircuitEngine for EngineCore {
@@ -43,7 +82,10 @@ impl CircuitEngine for EngineCore {
         let now = self.clock.instant();
 
         // NOTE: Remember to execute all expensive operations (like time checks) outside the lock.
-        self.state.lock().expect(ERR_POISONED_LOCK).exit(result, mode, now, &self.options)
+        let result = self.state.lock().expect(ERR_POISONED_LOCK).exit(result, mode, now, &self.options);
+
+        self.persist(&result);
+        result
     }
 }
 
@@ -55,6 +97,27 @@ enum State {
 }
 
 impl State {
+    fn restore(snapshot: CircuitSnapshot, now: Instant, system_now: SystemTime, settings: &EngineOptions) -> Self {
+        match snapshot {
+            CircuitSnapshot::Closed => Self::Closed {
+                health: settings.health_metrics_builder.build(),
+            },
+            CircuitSnapshot::Open { until } => {
+                // Never stay open longer than the configured break duration, so a snapshot with
+                // a skewed or invalid deadline cannot keep the circuit open indefinitely.
+                let remaining = until
+                    .duration_since(system_now)
+                    .unwrap_or(Duration::ZERO)
+                    .min(settings.break_duration);
+
+                Self::Open {
+                    open_until: now + remaining,
+                    stats: Stats::new(now),
+                }
+            }
+        }
+    }
+
     fn enter(&mut self, now: Instant, settings: &EngineOptions) -> EnterCircuitResult {
         match self {
             Self::Closed { .. } => EnterCircuitResult::Accepted {
@@ -969,4 +1032,99 @@ mod tests {
         }
         assert!(matches!(engine.state.lock().unwrap().deref(), State::Closed { .. }));
     }
+
+    #[test]
+    fn state_store_restores_open_circuit() {
+        let control = ClockControl::new();
+        let clock = control.to_clock();
+        let store = crate::breaker::InMemoryStateStore::new();
+        let id = BreakerId::from("test");
+        store.save(
+            &id,
+            CircuitSnapshot::Open {
+                until: clock.system_time() + Duration::from_secs(3),
+            },
+        );
+
+        let engine = EngineCore::new(create_test_settings(), clock).with_state_store(Arc::new(store), id);
+
+        assert!(matches!(engine.enter(), EnterCircuitResult::Rejected));
+        control.advance(Duration::from_secs(3));
+        assert!(matches!(
+            engine.enter(),
+            EnterCircuitResult::Accepted {
+                mode: ExecutionMode::Probe
+            }
+        ));
+    }
+
+    #[test]
+    fn state_store_restore_caps_open_duration_to_break_duration() {
+        let control = ClockControl::new();
+        let clock = control.to_clock();
+        let store = crate::breaker::InMemoryStateStore::new();
+        let id = BreakerId::from("test");
+        store.save(
+            &id,
+            CircuitSnapshot::Open {
+                until: clock.system_time() + Duration::from_hours(1),
+            },
+        );
+
+        let engine = EngineCore::new(create_test_settings(), clock).with_state_store(Arc::new(store), id);
+
+        control.advance(Duration::from_secs(5));
+        assert!(matches!(
+            engine.enter(),
+            EnterCircuitResult::Accepted {
+                mode: ExecutionMode::Probe
+            }
+        ));
+    }
+
+    #[test]
+    fn state_store_restore_expired_open_circuit_probes() {
+        let control = ClockControl::new();
+        let clock = control.to_clock();
+        let store = crate::breaker::InMemoryStateStore::new();
+        let id = BreakerId::from("test");
+        store.save(
+            &id,
+            CircuitSnapshot::Open {
+                until: clock.system_time() - Duration::from_secs(1),
+            },
+        );
+
+        let engine = EngineCore::new(create_test_settings(), clock).with_state_store(Arc::new(store), id);
+
+        assert!(matches!(
+            engine.enter(),
+            EnterCircuitResult::Accepted {
+                mode: ExecutionMode::Probe
+            }
+        ));
+    }
+
+    #[test]
+    fn state_store_saves_transitions() {
+        let control = ClockControl::new();
+        let clock = control.to_clock();
+        let store = crate::breaker::InMemoryStateStore::new();
+        let id = BreakerId::from("test");
+        let engine = EngineCore::new(create_test_settings(), clock.clone()).with_state_store(Arc::new(store.clone()), id.clone());
+        assert_eq!(store.load(&id), None);
+
+        open_engine(&engine);
+        assert_eq!(
+            store.load(&id),
+            Some(CircuitSnapshot::Open {
+                until: clock.system_time() + Duration::from_secs(5),
+            })
+        );
+
+        control.advance(Duration::from_secs(5));
+        engine.enter();
+        engine.exit(ExecutionResult::Success, ExecutionMode::Probe);
+        assert_eq!(store.load(&id), Some(CircuitSnapshot::Closed));
+    }
 }
@@ -300,7 +300,13 @@ mod tests {
         let telemetry = TelemetryHelper {
             pipeline_name: "test_pipeline".into(),
             strategy_name: "test_strategy".into(),
-            event_reporter: Some(create_resilience_event_counter(&create_meter(tester.meter_provider()))),
+            event_reporter: Some(create_resilience_event_counter(
+                &create_meter(tester.meter_provider()),
+                &crate::telemetry::MetricsOptions::default(),
+            )),
+            duration_reporter: None,
+            common_attributes: std::sync::Arc::from([]),
+            attempt_labels: true,
             logs_enabled: true,
         };
         let telemetry_engine = EngineTelemetry::new(engine, telemetry, "test_id".into(), Clock::new_frozen());
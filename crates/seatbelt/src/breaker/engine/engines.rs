@@ -7,7 +7,7 @@ use std::sync::{Arc, RwLock};
 use tick::Clock;
 
 use crate::breaker::constants::ERR_POISONED_LOCK;
-use crate::breaker::{BreakerId, Engine, EngineCore, EngineOptions, EngineTelemetry};
+use crate::breaker::{BreakerId, CircuitStateStore, Engine, EngineCore, EngineOptions, EngineTelemetry};
 use crate::utils::TelemetryHelper;
 
 /// Manages circuit breaker engines for different breaker IDs.
@@ -18,17 +18,30 @@ pub(crate) struct Engines {
     engine_options: EngineOptions,
     clock: Clock,
     telemetry: TelemetryHelper,
+    state_store: Option<Arc<dyn CircuitStateStore>>,
 }
 
 impl Engines {
-    pub(crate) fn new(engine_options: EngineOptions, clock: Clock, telemetry: TelemetryHelper) -> Self {
-        let default_engine = Arc::new(create_engine(&engine_options, &clock, &telemetry, &BreakerId::default()));
+    pub(crate) fn new(
+        engine_options: EngineOptions,
+        clock: Clock,
+        telemetry: TelemetryHelper,
+        state_store: Option<Arc<dyn CircuitStateStore>>,
+    ) -> Self {
+        let default_engine = Arc::new(create_engine(
+            &engine_options,
+            &clock,
+            &telemetry,
+            state_store.as_ref(),
+            &BreakerId::default(),
+        ));
         Self {
             default_engine,
             map: RwLock::new(BTreeMap::new()),
             engine_options,
             clock,
             telemetry,
+            state_store,
         }
     }
 
@@ -53,9 +66,15 @@ impl Engines {
 
         // Slow path: acquire write lock to insert a new engine.
         let mut map = self.map.write().expect(ERR_POISONED_LOCK);
-        let engine = map
-            .entry(key.clone())
-            .or_insert_with(|| Arc::new(create_engine(&self.engine_options, &self.clock, &self.telemetry, key)));
+        let engine = map.entry(key.clone()).or_insert_with(|| {
+            Arc::new(create_engine(
+                &self.engine_options,
+                &self.clock,
+                &self.telemetry,
+                self.state_store.as_ref(),
+                key,
+            ))
+        });
 
         Arc::clone(engine)
     }
@@ -67,13 +86,19 @@ impl Engines {
     }
 }
 
-fn create_engine(engine_options: &EngineOptions, clock: &Clock, telemetry: &TelemetryHelper, key: &BreakerId) -> Engine {
-    EngineTelemetry::new(
-        EngineCore::new(engine_options.clone(), clock.clone()),
-        telemetry.clone(),
-        key.clone().into(),
-        clock.clone(),
-    )
+fn create_engine(
+    engine_options: &EngineOptions,
+    clock: &Clock,
+    telemetry: &TelemetryHelper,
+    state_store: Option<&Arc<dyn CircuitStateStore>>,
+    key: &BreakerId,
+) -> Engine {
+    let mut core = EngineCore::new(engine_options.clone(), clock.clone());
+    if let Some(store) = state_store {
+        core = core.with_state_store(Arc::clone(store), key.clone());
+    }
+
+    EngineTelemetry::new(core, telemetry.clone(), key.clone().into(), clock.clone())
 }
 
 #[cfg_attr(coverage_nightly, coverage(off))]
@@ -91,7 +116,13 @@ mod tests {
         let telemetry = TelemetryHelper {
             pipeline_name: "pipeline".into(),
             strategy_name: "strategy".into(),
-            event_reporter: Some(create_resilience_event_counter(&opentelemetry::global::meter("test"))),
+            event_reporter: Some(create_resilience_event_counter(
+                &opentelemetry::global::meter("test"),
+                &crate::telemetry::MetricsOptions::default(),
+            )),
+            duration_reporter: None,
+            common_attributes: Arc::from([]),
+            attempt_labels: true,
             logs_enabled: false,
         };
         let engines = Engines::new(
@@ -102,6 +133,7 @@ mod tests {
             },
             Clock::new_frozen(),
             telemetry,
+            None,
         );
 
         assert!(Arc::ptr_eq(
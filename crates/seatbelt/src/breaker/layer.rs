@@ -46,6 +46,7 @@ pub struct BreakerLayer<In, Out, S1 = Set, S2 = Set> {
     break_duration: Duration,
     half_open_mode: HalfOpenMode,
     abandoned_policy: AbandonedPolicy,
+    state_store: Option<Arc<dyn CircuitStateStore>>,
     _state: PhantomData<fn(In, S1, S2) -> Out>,
 }
 
@@ -68,6 +69,7 @@ impl<In, Out> BreakerLayer<In, Out, NotSet, NotSet> {
             break_duration: DEFAULT_BREAK_DURATION,
             half_open_mode: HalfOpenMode::progressive(None),
             abandoned_policy: AbandonedPolicy::default(),
+            state_store: None,
             _state: PhantomData,
         }
     }
@@ -335,6 +337,20 @@ impl<In, Out, S1, S2> BreakerLayer<In, Out, S1, S2> {
         self
     }
 
+    /// Sets the store used to persist and restore the state of the circuits.
+    ///
+    /// When a circuit is created, its last snapshot is loaded from the store so that a
+    /// restarted process, or another replica sharing the store, does not immediately send
+    /// traffic to a dependency whose circuit is known to be open. Every subsequent state
+    /// transition is saved back to the store. See [`CircuitStateStore`] for details.
+    ///
+    /// **Default**: None (circuits always start closed)
+    #[must_use]
+    pub fn state_store(mut self, store: impl CircuitStateStore) -> Self {
+        self.state_store = Some(Arc::new(store));
+        self
+    }
+
     /// Optionally enables the circuit breaker middleware based on a condition.
     ///
     /// When disabled, inputs pass through without circuit breaker protection.
@@ -426,6 +442,7 @@ impl<In, Out, S1, S2> BreakerLayer<In, Out, S1, S2> {
             },
             self.context.get_clock().clone(),
             self.telemetry.clone(),
+            self.state_store.clone(),
         )
     }
 
@@ -446,6 +463,7 @@ impl<In, Out, S1, S2> BreakerLayer<In, Out, S1, S2> {
             break_duration: self.break_duration,
             half_open_mode: self.half_open_mode,
             abandoned_policy: self.abandoned_policy,
+            state_store: self.state_store,
             _state: PhantomData,
         }
     }
@@ -292,6 +292,15 @@
 //! # fn detect_breaker_id(input: &String) -> String  { input.to_string() }
 //! # async fn execute_unreliable_operation(input: String) -> Result<String, String> { Ok(input) }
 //! ```
+//!
+//! # State Persistence
+//!
+//! By default, circuits live in memory and start closed. A restarted process would therefore
+//! immediately send traffic to a dependency that was known to be failing. Configure a
+//! [`CircuitStateStore`] with [`BreakerLayer::state_store`] to restore circuits from their last
+//! snapshot and keep them open until the persisted break elapses. The store can be backed by
+//! local storage to survive restarts, or by a shared cache so that replicas warm start from each
+//! other. [`InMemoryStateStore`] shares state between pipelines within a single process.
 
 mod args;
 mod callbacks;
@@ -334,3 +343,6 @@ pub use half_open_mode::HalfOpenMode;
 
 mod abandoned_policy;
 pub use abandoned_policy::AbandonedPolicy;
+
+mod state_store;
+pub use state_store::{CircuitSnapshot, CircuitStateStore, InMemoryStateStore};
@@ -0,0 +1,135 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use super::BreakerId;
+use super::constants::ERR_POISONED_LOCK;
+
+/// A point-in-time snapshot of the state of a single circuit.
+///
+/// Snapshots are produced by the circuit breaker whenever a circuit transitions between
+/// states and are handed to the configured [`CircuitStateStore`]. The open deadline is
+/// expressed as wall-clock time so that a snapshot remains meaningful after a process
+/// restart or when it is shared with another replica.
+///
+/// A circuit that is half-open is reported as open: the last persisted transition is the
+/// one that opened the circuit, and restoring it resumes probing once the break elapses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(feature = "serde", test), derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum CircuitSnapshot {
+    /// The circuit is closed and inputs flow normally.
+    Closed,
+
+    /// The circuit is open and rejects inputs until the given time.
+    Open {
+        /// The wall-clock time at which the circuit starts probing.
+        until: SystemTime,
+    },
+}
+
+/// Storage for circuit snapshots, used to warm start circuit breakers.
+///
+/// Configure a store with [`BreakerLayer::state_store`][super::BreakerLayer::state_store].
+/// When a circuit is created, the breaker loads its last snapshot from the store and
+/// resumes from it; an open circuit keeps rejecting inputs until its persisted deadline
+/// instead of immediately sending traffic to a dependency that is known to be failing.
+/// Every subsequent state transition of the circuit is saved back to the store.
+///
+/// Implementations can persist snapshots to disk for restarts, or to a shared cache for
+/// coordination between replicas. Circuits are keyed by their [`BreakerId`]; use its
+/// [`Display`][std::fmt::Display] representation when the store needs a string key.
+///
+/// Both methods are called synchronously on the execution path. [`load`][Self::load] is
+/// called once per circuit, but [`save`][Self::save] is called on every state transition
+/// and should be cheap; implementations backed by remote storage should hand the snapshot
+/// off to a background writer rather than block.
+///
+/// The health window of a closed circuit is not part of the snapshot, so a restored
+/// closed circuit starts with fresh failure statistics.
+pub trait CircuitStateStore: Debug + Send + Sync + 'static {
+    /// Returns the last snapshot saved for the circuit, if any.
+    fn load(&self, id: &BreakerId) -> Option<CircuitSnapshot>;
+
+    /// Saves the snapshot of the circuit, replacing any previous snapshot.
+    fn save(&self, id: &BreakerId, snapshot: CircuitSnapshot);
+}
+
+/// A [`CircuitStateStore`] that keeps snapshots in memory.
+///
+/// Clones share the same snapshots, so a single store can be used by multiple circuit
+/// breaker layers, for example to share circuit state between pipelines that target the
+/// same dependency or to keep it across re-creations of a pipeline.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryStateStore {
+    snapshots: Arc<Mutex<BTreeMap<BreakerId, CircuitSnapshot>>>,
+}
+
+impl InMemoryStateStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CircuitStateStore for InMemoryStateStore {
+    fn load(&self, id: &BreakerId) -> Option<CircuitSnapshot> {
+        self.snapshots.lock().expect(ERR_POISONED_LOCK).get(id).copied()
+    }
+
+    fn save(&self, id: &BreakerId, snapshot: CircuitSnapshot) {
+        self.snapshots.lock().expect(ERR_POISONED_LOCK).insert(id.clone(), snapshot);
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn in_memory_store_roundtrip() {
+        let store = InMemoryStateStore::new();
+        let id = BreakerId::from("test");
+        assert_eq!(store.load(&id), None);
+
+        let snapshot = CircuitSnapshot::Open {
+            until: SystemTime::UNIX_EPOCH + Duration::from_secs(10),
+        };
+        store.save(&id, snapshot);
+        assert_eq!(store.load(&id), Some(snapshot));
+
+        store.save(&id, CircuitSnapshot::Closed);
+        assert_eq!(store.load(&id), Some(CircuitSnapshot::Closed));
+        assert_eq!(store.load(&BreakerId::from("other")), None);
+    }
+
+    #[test]
+    fn in_memory_store_clones_share_snapshots() {
+        let store = InMemoryStateStore::new();
+        let clone = store.clone();
+
+        clone.save(&BreakerId::from("test"), CircuitSnapshot::Closed);
+
+        assert_eq!(store.load(&BreakerId::from("test")), Some(CircuitSnapshot::Closed));
+    }
+
+    #[test]
+    fn snapshot_serde_roundtrip() {
+        let snapshot = CircuitSnapshot::Open {
+            until: SystemTime::UNIX_EPOCH + Duration::from_secs(10),
+        };
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let deserialized: CircuitSnapshot = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, snapshot);
+    }
+}
@@ -22,6 +22,8 @@ pub struct ResilienceContext<In, Out> {
     name: TelemetryString,
     #[cfg(any(feature = "metrics", test))]
     meter: Option<opentelemetry::metrics::Meter>,
+    #[cfg(any(feature = "metrics", test))]
+    metrics_options: crate::telemetry::MetricsOptions,
     logs_enabled: bool,
     _in: std::marker::PhantomData<fn() -> In>,
     _out: std::marker::PhantomData<fn() -> Out>,
@@ -44,6 +46,8 @@ impl<In, Out> ResilienceContext<In, Out> {
             name: Cow::Borrowed(DEFAULT_CONTEXT_NAME),
             #[cfg(any(feature = "metrics", test))]
             meter: None,
+            #[cfg(any(feature = "metrics", test))]
+            metrics_options: crate::telemetry::MetricsOptions::new(),
             logs_enabled: false,
             _in: std::marker::PhantomData,
             _out: std::marker::PhantomData,
@@ -81,6 +85,19 @@ impl<In, Out> ResilienceContext<In, Out> {
         }
     }
 
+    /// Configure metric naming and labels for all middleware created from this context.
+    ///
+    /// Options only take effect when metrics are enabled via [`use_metrics`][Self::use_metrics].
+    /// See the [`telemetry`][crate::telemetry] module for details.
+    #[must_use]
+    #[cfg(any(feature = "metrics", test))]
+    pub fn metrics_options(self, options: crate::telemetry::MetricsOptions) -> Self {
+        Self {
+            metrics_options: options,
+            ..self
+        }
+    }
+
     /// Enable structured logging for resilience events.
     #[must_use]
     #[cfg(any(feature = "logs", test))]
@@ -113,7 +130,20 @@ impl<In, Out> ResilienceContext<In, Out> {
     pub(crate) fn create_telemetry(&self, strategy_name: Cow<'static, str>) -> crate::utils::TelemetryHelper {
         crate::utils::TelemetryHelper {
             #[cfg(any(feature = "metrics", test))]
-            event_reporter: self.meter.as_ref().map(crate::metrics::create_resilience_event_counter),
+            event_reporter: self
+                .meter
+                .as_ref()
+                .map(|meter| crate::metrics::create_resilience_event_counter(meter, &self.metrics_options)),
+            #[cfg(any(feature = "metrics", test))]
+            duration_reporter: self
+                .meter
+                .as_ref()
+                .filter(|_| self.metrics_options.durations_enabled())
+                .map(|meter| crate::metrics::create_resilience_event_duration_histogram(meter, &self.metrics_options)),
+            #[cfg(any(feature = "metrics", test))]
+            common_attributes: self.metrics_options.common_attributes(),
+            #[cfg(any(feature = "metrics", test))]
+            attempt_labels: self.metrics_options.attempt_labels_enabled(),
             #[cfg(any(feature = "metrics", feature = "logs", test))]
             pipeline_name: self.name.clone(),
             #[cfg(any(feature = "metrics", feature = "logs", test))]
@@ -131,6 +161,8 @@ impl<In, Out> Clone for ResilienceContext<In, Out> {
             name: self.name.clone(),
             #[cfg(any(feature = "metrics", test))]
             meter: self.meter.clone(),
+            #[cfg(any(feature = "metrics", test))]
+            metrics_options: self.metrics_options.clone(),
             _in: std::marker::PhantomData,
             _out: std::marker::PhantomData,
             logs_enabled: self.logs_enabled,
@@ -191,6 +223,42 @@ mod tests {
         assert!(dump.contains('3'));
     }
 
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn test_metrics_options_applied_to_telemetry() {
+        let clock = tick::Clock::new_frozen();
+        let (provider, exporter) = test_meter_provider();
+
+        let ctx = ResilienceContext::<(), ()>::new(clock).use_metrics(&provider).metrics_options(
+            crate::telemetry::MetricsOptions::new()
+                .prefix("contoso")
+                .service_name("svc")
+                .record_durations(true),
+        );
+        let telemetry = ctx.create_telemetry("test".into());
+        telemetry.report_metrics(&[]);
+        telemetry.report_duration(std::time::Duration::from_millis(10), &[]);
+
+        assert!(!telemetry.common_attributes.is_empty());
+
+        provider.force_flush().unwrap();
+        let dump = format!("{:?}", exporter.get_finished_metrics().unwrap());
+        assert!(dump.contains("contoso.resilience.event"));
+        assert!(dump.contains("contoso.resilience.event.duration"));
+        assert!(dump.contains("svc"));
+    }
+
+    #[test]
+    fn test_durations_disabled_by_default() {
+        let (provider, _exporter) = test_meter_provider();
+        let ctx = ResilienceContext::<(), ()>::new(tick::Clock::new_frozen()).use_metrics(&provider);
+
+        let telemetry = ctx.create_telemetry("test".into());
+
+        assert!(telemetry.event_reporter.is_some());
+        assert!(telemetry.duration_reporter.is_none());
+    }
+
     #[test]
     fn relocate_ok() {
         let mut ctx = ResilienceContext::<(), ()>::new(tick::Clock::new_frozen());
@@ -122,6 +122,9 @@
 //!   - `resilience.attempt.is_last`: Whether this is the last attempt
 //!   - `resilience.attempt.recovery.kind`: The recovery classification (`retry`, `unavailable`, or `abandoned`)
 //!
+//! When enabled through [`MetricsOptions`][crate::telemetry::MetricsOptions], the hedging delay
+//! is also recorded to the `resilience.event.duration` histogram with the same attributes.
+//!
 //! ## Logs
 //!
 //! Log events include all metric attributes plus `resilience.hedging.delay` (the delay
@@ -87,14 +87,17 @@ impl TelemetryGuard {
             use crate::attempt::{ATTEMPT_INDEX, ATTEMPT_IS_LAST, ATTEMPT_RECOVERY_KIND};
             use crate::utils::{EVENT_NAME, PIPELINE_NAME, STRATEGY_NAME};
 
-            self.telemetry.report_metrics(&[
+            let attributes = [
                 opentelemetry::KeyValue::new(PIPELINE_NAME, self.telemetry.pipeline_name.clone()),
                 opentelemetry::KeyValue::new(STRATEGY_NAME, self.telemetry.strategy_name.clone()),
                 opentelemetry::KeyValue::new(EVENT_NAME, HEDGING_EVENT),
                 opentelemetry::KeyValue::new(ATTEMPT_INDEX, i64::from(self.attempt.index())),
                 opentelemetry::KeyValue::new(ATTEMPT_IS_LAST, self.attempt.is_last()),
                 opentelemetry::KeyValue::new(ATTEMPT_RECOVERY_KIND, self.recovery_kind_str()),
-            ]);
+            ];
+
+            self.telemetry.report_metrics(&attributes);
+            self.telemetry.report_duration(self.hedging_delay, &attributes);
         }
     }
 }
@@ -215,12 +215,15 @@
 //!   with a configurable probability.
 //! - **`chaos-latency`** - Enables the [`chaos::latency`] middleware for injecting artificial
 //!   delay with a configurable probability.
-//! - **`metrics`** - Exposes the OpenTelemetry metrics API for collecting and reporting metrics.
+//! - **`metrics`** - Exposes the OpenTelemetry metrics API for collecting and reporting metrics. See the
+//!   [`telemetry`] module for configuring metric names and labels.
 //! - **`logs`** - Enables structured logging for resilience middleware using the `tracing` crate.
 //! - **`serde`** - Enables `serde::Serialize` and `serde::Deserialize` implementations for
 //!   configuration types.
 //! - **`tower-service`** - Enables [`tower_service::Service`] trait implementations for all
 //!   resilience middleware.
+//! - **`test-util`** - Enables the [`test_util`] module with a deterministic harness for
+//!   testing resilience configurations.
 
 #[doc(inline)]
 pub use recoverable::{Recovery, RecoveryInfo, RecoveryKind};
@@ -275,6 +278,12 @@ pub(crate) mod utils;
 #[cfg(any(feature = "metrics", test))]
 mod metrics;
 
+#[cfg(any(feature = "metrics", test))]
+pub mod telemetry;
+
+#[cfg(any(feature = "test-util", test))]
+pub mod test_util;
+
 #[cfg_attr(coverage_nightly, coverage(off))]
 #[cfg(test)]
 pub(crate) mod testing;
@@ -4,6 +4,17 @@
 use opentelemetry::InstrumentationScope;
 use opentelemetry::metrics::{Meter, MeterProvider};
 
+#[cfg(any(
+    feature = "retry",
+    feature = "breaker",
+    feature = "timeout",
+    feature = "fallback",
+    feature = "hedging",
+    feature = "chaos-injection",
+    test
+))]
+use crate::telemetry::{EVENT_COUNTER_NAME, EVENT_DURATION_NAME, MetricsOptions};
+
 const METER_NAME: &str = "seatbelt";
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const SCHEMA_URL: &str = "https://opentelemetry.io/schemas/1.47.0";
@@ -26,14 +37,38 @@ pub(crate) fn create_meter(meter_provider: &dyn MeterProvider) -> Meter {
     feature = "chaos-injection",
     test
 ))]
-pub(crate) fn create_resilience_event_counter(meter: &Meter) -> opentelemetry::metrics::Counter<u64> {
+pub(crate) fn create_resilience_event_counter(meter: &Meter, options: &MetricsOptions) -> opentelemetry::metrics::Counter<u64> {
     meter
-        .u64_counter("resilience.event")
+        .u64_counter(options.metric_name(EVENT_COUNTER_NAME))
         .with_description("Emitted upon the occurrence of a resilience event.")
         .with_unit("u64")
         .build()
 }
 
+#[cfg(any(
+    feature = "retry",
+    feature = "breaker",
+    feature = "timeout",
+    feature = "fallback",
+    feature = "hedging",
+    feature = "chaos-injection",
+    test
+))]
+pub(crate) fn create_resilience_event_duration_histogram(
+    meter: &Meter,
+    options: &MetricsOptions,
+) -> opentelemetry::metrics::Histogram<f64> {
+    let builder = meter
+        .f64_histogram(options.metric_name(EVENT_DURATION_NAME))
+        .with_description("Duration associated with the occurrence of a resilience event.")
+        .with_unit("s");
+
+    match options.buckets() {
+        Some(buckets) => builder.with_boundaries(buckets.to_vec()).build(),
+        None => builder.build(),
+    }
+}
+
 #[cfg_attr(coverage_nightly, coverage(off))]
 #[cfg(test)]
 mod tests {
@@ -48,7 +83,7 @@ mod tests {
         let meter_provider = SdkMeterProvider::builder().with_periodic_exporter(exporter.clone()).build();
 
         let meter = create_meter(&meter_provider);
-        let resilience_events = create_resilience_event_counter(&meter);
+        let resilience_events = create_resilience_event_counter(&meter, &MetricsOptions::default());
         resilience_events.add(1, &[]);
 
         meter_provider.force_flush().unwrap();
@@ -62,4 +97,25 @@ mod tests {
         assert!(str.contains(env!("CARGO_PKG_VERSION")));
         assert!(str.contains("https://opentelemetry.io/schemas/1.47"));
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn assert_prefixed_definitions() {
+        let exporter = InMemoryMetricExporter::default();
+        let meter_provider = SdkMeterProvider::builder().with_periodic_exporter(exporter.clone()).build();
+        let options = MetricsOptions::new().prefix("contoso").duration_buckets([0.5, 1.0]);
+
+        let meter = create_meter(&meter_provider);
+        create_resilience_event_counter(&meter, &options).add(1, &[]);
+        create_resilience_event_duration_histogram(&meter, &options).record(0.7, &[]);
+
+        meter_provider.force_flush().unwrap();
+
+        let metrics = exporter.get_finished_metrics().unwrap();
+        let str = format!("{metrics:?}");
+
+        assert!(str.contains("contoso.resilience.event"));
+        assert!(str.contains("contoso.resilience.event.duration"));
+        assert!(str.contains("0.5"));
+    }
 }
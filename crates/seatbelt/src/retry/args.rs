@@ -107,6 +107,31 @@ impl RestoreInputArgs {
     }
 }
 
+/// Arguments for the [`timeout_output`][super::RetryLayer::timeout_output] callback function.
+///
+/// Provides context about the timeout that elapsed.
+#[derive(Debug)]
+pub struct TimeoutOutputArgs {
+    pub(super) timeout: Duration,
+    pub(super) attempt: Option<Attempt>,
+}
+
+impl TimeoutOutputArgs {
+    /// Returns the timeout duration that was exceeded.
+    #[must_use]
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the attempt that exceeded the [per-attempt timeout][super::RetryLayer::per_attempt_timeout].
+    ///
+    /// Returns `None` when the [total timeout][super::RetryLayer::total_timeout] elapsed.
+    #[must_use]
+    pub fn attempt(&self) -> Option<Attempt> {
+        self.attempt
+    }
+}
+
 #[cfg_attr(coverage_nightly, coverage(off))]
 #[cfg(test)]
 mod tests {
@@ -159,4 +184,15 @@ mod tests {
         assert_eq!(args.attempt(), Attempt::new(2, true));
         assert_eq!(*args.recovery(), RecoveryInfo::retry());
     }
+
+    #[test]
+    fn timeout_output_args() {
+        let args = TimeoutOutputArgs {
+            timeout: Duration::from_secs(2),
+            attempt: Some(Attempt::new(1, false)),
+        };
+
+        assert_eq!(args.timeout(), Duration::from_secs(2));
+        assert_eq!(args.attempt(), Some(Attempt::new(1, false)));
+    }
 }
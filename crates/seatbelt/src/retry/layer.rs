@@ -40,6 +40,9 @@ pub struct RetryLayer<In, Out, S1 = Set, S2 = Set> {
     telemetry: TelemetryHelper,
     restore_input: Option<RestoreInput<In, Out>>,
     handle_unavailable: bool,
+    per_attempt_timeout: Option<Duration>,
+    total_timeout: Option<Duration>,
+    timeout_output: Option<TimeoutOutput<Out>>,
     _state: PhantomData<fn(In, S1, S2) -> Out>,
 }
 
@@ -57,6 +60,9 @@ impl<In, Out> RetryLayer<In, Out, NotSet, NotSet> {
             telemetry: context.create_telemetry(name),
             restore_input: None,
             handle_unavailable: false,
+            per_attempt_timeout: None,
+            total_timeout: None,
+            timeout_output: None,
             _state: PhantomData,
         }
     }
@@ -282,6 +288,55 @@ impl<In, Out, S1, S2> RetryLayer<In, Out, S1, S2> {
         self.enable(false)
     }
 
+    /// Sets the maximum duration of each individual attempt.
+    ///
+    /// An attempt that does not complete in time is canceled and replaced by the output produced
+    /// by [`timeout_output`][RetryLayer::timeout_output]. That output is classified by the
+    /// [`recovery`][RetryLayer::recovery] function like any other output, so a timed out attempt is
+    /// retried only when its output is recoverable.
+    ///
+    /// Combine with [`total_timeout`][RetryLayer::total_timeout] to bound both each attempt and the
+    /// whole operation, for example "each attempt gets 2 seconds, the operation gets 10 seconds".
+    ///
+    /// **Default**: None (attempts are not bounded)
+    ///
+    /// # Panics
+    ///
+    /// Building the service panics if no timeout output is configured.
+    #[must_use]
+    pub fn per_attempt_timeout(mut self, timeout: Duration) -> Self {
+        self.per_attempt_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum duration of the whole operation, including all attempts and retry delays.
+    ///
+    /// When the timeout elapses, the current attempt or retry delay is canceled, no further
+    /// attempts are made, and the output produced by [`timeout_output`][RetryLayer::timeout_output]
+    /// is returned.
+    ///
+    /// **Default**: None (the operation is not bounded)
+    ///
+    /// # Panics
+    ///
+    /// Building the service panics if no timeout output is configured.
+    #[must_use]
+    pub fn total_timeout(mut self, timeout: Duration) -> Self {
+        self.total_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the function that produces the output when a timeout elapses.
+    ///
+    /// The `output` function is required when [`per_attempt_timeout`][RetryLayer::per_attempt_timeout]
+    /// or [`total_timeout`][RetryLayer::total_timeout] is configured. It receives [`TimeoutOutputArgs`]
+    /// describing which timeout elapsed.
+    #[must_use]
+    pub fn timeout_output(mut self, output: impl Fn(TimeoutOutputArgs) -> Out + Send + Sync + 'static) -> Self {
+        self.timeout_output = Some(TimeoutOutput::new(output));
+        self
+    }
+
     /// Configures whether the retry middleware should attempt to recover from unavailable services.
     ///
     /// When enabled, the retry middleware will treat [`RecoveryInfo::unavailable`] classifications
@@ -401,6 +456,11 @@ impl<In, Out, S> Layer<S> for RetryLayer<In, Out, Set, Set> {
     type Service = Retry<In, Out, S>;
 
     fn layer(&self, inner: S) -> Self::Service {
+        assert!(
+            self.timeout_output.is_some() || (self.per_attempt_timeout.is_none() && self.total_timeout.is_none()),
+            "retry timeouts require a timeout output, call `timeout_output` or `timeout_error` when configuring `per_attempt_timeout` or `total_timeout`"
+        );
+
         let shared = RetryShared {
             clock: self.context.get_clock().clone(),
             max_attempts: self.max_attempts,
@@ -413,6 +473,9 @@ impl<In, Out, S> Layer<S> for RetryLayer<In, Out, Set, Set> {
             telemetry: self.telemetry.clone(),
             restore_input: self.restore_input.clone(),
             handle_unavailable: self.handle_unavailable,
+            per_attempt_timeout: self.per_attempt_timeout,
+            total_timeout: self.total_timeout,
+            timeout_output: self.timeout_output.clone(),
         };
 
         Retry {
@@ -423,6 +486,15 @@ impl<In, Out, S> Layer<S> for RetryLayer<In, Out, Set, Set> {
 }
 
 impl<In, Res, Error, S1, S2> RetryLayer<In, Result<Res, Error>, S1, S2> {
+    /// Sets the error to return when a timeout elapses for Result-returning services.
+    ///
+    /// This is a convenience method for [`timeout_output`][RetryLayer::timeout_output] that wraps
+    /// the produced error in `Result::Err`.
+    #[must_use]
+    pub fn timeout_error(self, error: impl Fn(TimeoutOutputArgs) -> Error + Send + Sync + 'static) -> Self {
+        self.timeout_output(move |args| Err(error(args)))
+    }
+
     /// Sets a specialized input restoration callback that operates only on error cases.
     ///
     /// This is a convenience method for working with `Result<Res, Error>` outputs, where you
@@ -499,6 +571,9 @@ impl<In, Out, S1, S2> RetryLayer<In, Out, S1, S2> {
             telemetry: self.telemetry,
             restore_input: self.restore_input,
             handle_unavailable: self.handle_unavailable,
+            per_attempt_timeout: self.per_attempt_timeout,
+            total_timeout: self.total_timeout,
+            timeout_output: self.timeout_output,
             _state: PhantomData,
         }
     }
@@ -771,6 +846,26 @@ mod tests {
         static_assertions::assert_impl_all!(RetryLayer<String, String, Set, Set>: Debug);
     }
 
+    #[test]
+    fn timeouts_set_correctly() {
+        let layer = create_ready_layer()
+            .per_attempt_timeout(Duration::from_secs(2))
+            .total_timeout(Duration::from_secs(10))
+            .timeout_output(|args| format!("timed out after {:?}", args.timeout()));
+
+        assert_eq!(layer.per_attempt_timeout, Some(Duration::from_secs(2)));
+        assert_eq!(layer.total_timeout, Some(Duration::from_secs(10)));
+        assert!(layer.timeout_output.is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "retry timeouts require a timeout output")]
+    fn timeout_without_output_panics() {
+        let _service = create_ready_layer()
+            .per_attempt_timeout(Duration::from_secs(2))
+            .layer(Execute::new(|input: String| async move { input }));
+    }
+
     fn create_test_context() -> ResilienceContext<String, String> {
         ResilienceContext::new(Clock::new_frozen()).name("test_pipeline")
     }
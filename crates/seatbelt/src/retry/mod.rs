@@ -67,6 +67,42 @@
 //! 2. **Backoff Strategy**: When no recovery delay is specified, delays are calculated using
 //!    the configured backoff strategy (Constant, Linear, or Exponential with default `10ms` base delay).
 //!
+//! # Timeouts
+//!
+//! Wrapping a single `Timeout` middleware inside or outside of the retry middleware bounds
+//! either each attempt or the whole operation, but not both. The retry middleware can bound both
+//! directly:
+//!
+//! - [`per_attempt_timeout`][RetryLayer::per_attempt_timeout]: Cancels an individual attempt that
+//!   takes too long. The timeout output is classified by the recovery function, so the attempt
+//!   is retried when the output is recoverable.
+//! - [`total_timeout`][RetryLayer::total_timeout]: Cancels the whole operation, including retry
+//!   delays, and stops retrying.
+//!
+//! Both require [`timeout_output`][RetryLayer::timeout_output] (or [`timeout_error`][RetryLayer::timeout_error]
+//! for `Result` outputs) to produce the output returned when a timeout elapses.
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use tick::Clock;
+//! # use seatbelt::retry::Retry;
+//! # use seatbelt::{RecoveryInfo, ResilienceContext};
+//! # fn example(clock: Clock) {
+//! # let context = ResilienceContext::<String, Result<String, String>>::new(&clock);
+//! // Each attempt gets 2 seconds, the whole operation gets 10 seconds.
+//! let layer = Retry::layer("retry", &context)
+//!     .clone_input()
+//!     .recovery_with(|result, _| match result {
+//!         Ok(_) => RecoveryInfo::never(),
+//!         Err(_) => RecoveryInfo::retry(),
+//!     })
+//!     .per_attempt_timeout(Duration::from_secs(2))
+//!     .total_timeout(Duration::from_secs(10))
+//!     .timeout_error(|args| format!("timed out after {:?}", args.timeout()));
+//! # let _layer = layer;
+//! # }
+//! ```
+//!
 //! # Defaults
 //!
 //! The retry middleware uses the following default values when optional configuration is not provided:
@@ -78,6 +114,8 @@
 //! | Backoff strategy | `Exponential` | Exponential backoff with base multiplier of 2 | [`backoff`][RetryLayer::backoff] |
 //! | Jitter | `Enabled` | Adds randomness to delays to prevent thundering herds | [`use_jitter`][RetryLayer::use_jitter] |
 //! | Max delay | `None` | No limit on maximum delay between retries | [`max_delay`][RetryLayer::max_delay] |
+//! | Per-attempt timeout | `None` | Attempts are not bounded | [`per_attempt_timeout`][RetryLayer::per_attempt_timeout] |
+//! | Total timeout | `None` | The whole operation is not bounded | [`total_timeout`][RetryLayer::total_timeout] |
 //! | Enable condition | Always enabled | Retry protection is applied to all requests | [`enable_if`][RetryLayer::enable_if], [`enable_always`][RetryLayer::enable_always], [`disable`][RetryLayer::disable] |
 //!
 //! The default base delay is optimized for **service-to-service** communication where low latency
@@ -100,6 +138,9 @@
 //!   - `resilience.attempt.recovery.kind`: The [`RecoveryKind`][crate::RecoveryKind] that triggered
 //!     the retry (e.g., `retry` or `unavailable`)
 //!
+//! When enabled through [`MetricsOptions`][crate::telemetry::MetricsOptions], the retry delay
+//! is also recorded to the `resilience.event.duration` histogram with the same attributes.
+//!
 //! # Examples
 //!
 //! ## Basic Usage
@@ -201,10 +242,10 @@ mod service;
 #[cfg(any(feature = "metrics", test))]
 mod telemetry;
 
-pub use args::{CloneArgs, OnRetryArgs, RecoveryArgs, RestoreInputArgs};
+pub use args::{CloneArgs, OnRetryArgs, RecoveryArgs, RestoreInputArgs, TimeoutOutputArgs};
 pub use backoff::Backoff;
 pub(crate) use backoff::DelayBackoff;
-pub(crate) use callbacks::{CloneInput, OnRetry, RestoreInput, ShouldRecover};
+pub(crate) use callbacks::{CloneInput, OnRetry, RestoreInput, ShouldRecover, TimeoutOutput};
 pub use config::RetryConfig;
 pub use layer::RetryLayer;
 pub use service::Retry;
@@ -11,7 +11,7 @@ use std::task::{Context, Poll};
 use std::time::Duration;
 
 use layered::Service;
-use tick::Clock;
+use tick::{Clock, FutureExt};
 
 use super::*;
 use crate::typestates::NotSet;
@@ -54,6 +54,9 @@ pub(crate) struct RetryShared<In, Out> {
     pub(crate) telemetry: crate::utils::TelemetryHelper,
     pub(crate) restore_input: Option<RestoreInput<In, Out>>,
     pub(crate) handle_unavailable: bool,
+    pub(crate) per_attempt_timeout: Option<Duration>,
+    pub(crate) total_timeout: Option<Duration>,
+    pub(crate) timeout_output: Option<TimeoutOutput<Out>>,
 }
 
 impl<In, Out, S: Clone> Clone for Retry<In, Out, S> {
@@ -89,36 +92,68 @@ where
     type Out = Out;
 
     #[cfg_attr(test, mutants::skip)] // Mutating enable_if check causes infinite loops
-    async fn execute(&self, mut input: In) -> Self::Out {
+    async fn execute(&self, input: In) -> Self::Out {
         if !self.shared.enable_if.call(&input) {
             return self.inner.execute(input).await;
         }
 
-        let mut attempt = Attempt::first(self.shared.max_attempts);
-        let mut delays = self.shared.backoff.delays();
-        let mut previous_recovery = None;
+        self.shared
+            .with_total_timeout(async move {
+                let mut input = input;
+                let mut attempt = Attempt::first(self.shared.max_attempts);
+                let mut delays = self.shared.backoff.delays();
+                let mut previous_recovery = None;
 
-        loop {
-            let (original_input, attempt_input) = self.shared.clone_input(input, attempt, previous_recovery.clone());
+                loop {
+                    let (original_input, attempt_input) = self.shared.clone_input(input, attempt, previous_recovery.clone());
 
-            // execute inner service
-            let out = self.inner.execute(attempt_input).await;
+                    // execute inner service
+                    let out = self.shared.with_attempt_timeout(self.inner.execute(attempt_input), attempt).await;
 
-            // evaluate whether to retry
-            match self.shared.evaluate_attempt(original_input, out, attempt, &mut delays) {
-                ControlFlow::Continue(state) => {
-                    self.shared.clock.delay(state.delay).await;
-                    input = state.input;
-                    attempt = state.attempt;
-                    previous_recovery = Some(state.recovery);
+                    // evaluate whether to retry
+                    match self.shared.evaluate_attempt(original_input, out, attempt, &mut delays) {
+                        ControlFlow::Continue(state) => {
+                            self.shared.clock.delay(state.delay).await;
+                            input = state.input;
+                            attempt = state.attempt;
+                            previous_recovery = Some(state.recovery);
+                        }
+                        ControlFlow::Break(out) => return out,
+                    }
                 }
-                ControlFlow::Break(out) => return out,
-            }
-        }
+            })
+            .await
     }
 }
 
 impl<In, Out> RetryShared<In, Out> {
+    async fn with_attempt_timeout(&self, future: impl Future<Output = Out>, attempt: Attempt) -> Out {
+        match self.per_attempt_timeout {
+            Some(timeout) => match future.timeout(&self.clock, timeout).await {
+                Ok(out) => out,
+                Err(_error) => self.timeout_output(timeout, Some(attempt)),
+            },
+            None => future.await,
+        }
+    }
+
+    async fn with_total_timeout(&self, future: impl Future<Output = Out>) -> Out {
+        match self.total_timeout {
+            Some(timeout) => match future.timeout(&self.clock, timeout).await {
+                Ok(out) => out,
+                Err(_error) => self.timeout_output(timeout, None),
+            },
+            None => future.await,
+        }
+    }
+
+    fn timeout_output(&self, timeout: Duration, attempt: Option<Attempt>) -> Out {
+        self.timeout_output
+            .as_ref()
+            .expect("timeout output is validated when the layer is built")
+            .call(TimeoutOutputArgs { timeout, attempt })
+    }
+
     fn clone_input(&self, mut input: In, attempt: Attempt, previous_recovery: Option<RecoveryInfo>) -> (Option<In>, In) {
         let args = CloneArgs {
             attempt,
@@ -155,7 +190,7 @@ impl<In, Out> RetryShared<In, Out> {
             return ControlFlow::Break(out);
         };
 
-        let retry_delay = compute_retry_delay(&recovery, delays);
+        let retry_delay = compute_retry_delay(&recovery, delays, &self.clock);
 
         self.emit_telemetry(attempt, retry_delay, recovery.kind());
 
@@ -217,8 +252,8 @@ impl<In, Out> RetryShared<In, Out> {
     }
 
     #[cfg_attr(
-        not(any(feature = "logs", test)),
-        expect(unused_variables, clippy::unused_self, reason = "unused when logs feature not used")
+        not(any(feature = "logs", feature = "metrics", test)),
+        expect(unused_variables, clippy::unused_self, reason = "unused when logs nor metrics features are used")
     )]
     fn emit_telemetry(&self, attempt: Attempt, retry_delay: Duration, recovery_kind: RecoveryKind) {
         #[cfg(any(feature = "logs", test))]
@@ -241,21 +276,24 @@ impl<In, Out> RetryShared<In, Out> {
             use crate::attempt::{ATTEMPT_INDEX, ATTEMPT_IS_LAST, ATTEMPT_RECOVERY_KIND};
             use crate::utils::{EVENT_NAME, PIPELINE_NAME, STRATEGY_NAME};
 
-            self.telemetry.report_metrics(&[
+            let attributes = [
                 opentelemetry::KeyValue::new(PIPELINE_NAME, self.telemetry.pipeline_name.clone()),
                 opentelemetry::KeyValue::new(STRATEGY_NAME, self.telemetry.strategy_name.clone()),
                 opentelemetry::KeyValue::new(EVENT_NAME, RETRY_EVENT),
                 opentelemetry::KeyValue::new(ATTEMPT_INDEX, i64::from(attempt.index())),
                 opentelemetry::KeyValue::new(ATTEMPT_IS_LAST, attempt.is_last()),
                 opentelemetry::KeyValue::new(ATTEMPT_RECOVERY_KIND, recovery_kind.as_str()),
-            ]);
+            ];
+
+            self.telemetry.report_metrics(&attributes);
+            self.telemetry.report_duration(retry_delay, &attributes);
         }
     }
 }
 
-fn compute_retry_delay(recovery: &RecoveryInfo, delays: &mut impl Iterator<Item = Duration>) -> Duration {
+fn compute_retry_delay(recovery: &RecoveryInfo, delays: &mut impl Iterator<Item = Duration>, clock: &Clock) -> Duration {
     let backoff_delay = delays.next().unwrap_or(Duration::ZERO);
-    recovery.get_delay().unwrap_or(backoff_delay)
+    recovery.get_delay_from(clock.system_time()).unwrap_or(backoff_delay)
 }
 
 /// State passed between retry attempts when continuing the retry loop.
@@ -320,28 +358,32 @@ where
 
         RetryFuture {
             inner: Box::pin(async move {
-                let mut input = req;
-                let mut inner = inner;
-                let mut attempt = Attempt::first(shared.max_attempts);
-                let mut delays = shared.backoff.delays();
-                let mut previous_recovery = None;
-
-                loop {
-                    let (original_input, attempt_input) = shared.clone_input(input, attempt, previous_recovery.clone());
-
-                    let out = inner.call(attempt_input).await;
-
-                    // evaluate whether to retry
-                    match shared.evaluate_attempt(original_input, out, attempt, &mut delays) {
-                        ControlFlow::Continue(state) => {
-                            shared.clock.delay(state.delay).await;
-                            input = state.input;
-                            attempt = state.attempt;
-                            previous_recovery = Some(state.recovery);
+                shared
+                    .with_total_timeout(async {
+                        let mut input = req;
+                        let mut inner = inner;
+                        let mut attempt = Attempt::first(shared.max_attempts);
+                        let mut delays = shared.backoff.delays();
+                        let mut previous_recovery = None;
+
+                        loop {
+                            let (original_input, attempt_input) = shared.clone_input(input, attempt, previous_recovery.clone());
+
+                            let out = shared.with_attempt_timeout(inner.call(attempt_input), attempt).await;
+
+                            // evaluate whether to retry
+                            match shared.evaluate_attempt(original_input, out, attempt, &mut delays) {
+                                ControlFlow::Continue(state) => {
+                                    shared.clock.delay(state.delay).await;
+                                    input = state.input;
+                                    attempt = state.attempt;
+                                    previous_recovery = Some(state.recovery);
+                                }
+                                ControlFlow::Break(out) => return out,
+                            }
                         }
-                        ControlFlow::Break(out) => return out,
-                    }
-                }
+                    })
+                    .await
             }),
         }
     }
@@ -402,6 +444,43 @@ mod tests {
         );
     }
 
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn retries_exhausted_ensure_metrics_options_applied() {
+        let tester = MetricTester::new();
+        let context = ResilienceContext::<String, String>::new(ClockControl::default().auto_advance_timers(true).to_clock())
+            .name("test_pipeline")
+            .use_metrics(tester.meter_provider())
+            .metrics_options(
+                crate::telemetry::MetricsOptions::new()
+                    .service_name("test_service")
+                    .dependency_name("test_dependency")
+                    .attempt_labels(false)
+                    .record_durations(true),
+            );
+
+        let service = create_ready_retry_layer_core(RecoveryInfo::retry(), &context)
+            .clone_input_with(move |input, _args| Some(input.clone()))
+            .max_retry_attempts(1)
+            .recovery_with(move |_input, _args| RecoveryInfo::retry())
+            .layer(Execute::new(move |v: String| async move { v }));
+
+        let _result = service.execute("test".to_string()).await;
+
+        // Without attempt labels both attempts aggregate into a single data point of the counter
+        // and of the duration histogram, each carrying 5 attributes.
+        tester.assert_attributes(
+            &[
+                KeyValue::new("resilience.pipeline.name", "test_pipeline"),
+                KeyValue::new("resilience.strategy.name", "test_retry"),
+                KeyValue::new("resilience.event.name", "retry"),
+                KeyValue::new("service.name", "test_service"),
+                KeyValue::new("resilience.dependency.name", "test_dependency"),
+            ],
+            Some(10),
+        );
+    }
+
     // The tokio runtime builds an IO driver (mio IOCP/epoll) under `--all-features`
     // feature unification (tokio `net`), which is unsupported under Miri.
     #[cfg_attr(miri, ignore)]
@@ -0,0 +1,275 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Metric naming and label configuration shared by all resilience middleware.
+//!
+//! By default, every middleware in a pipeline reports to the `resilience.event` counter with
+//! a fixed set of attributes. [`MetricsOptions`] lets you adjust the emitted series so they
+//! line up with the naming conventions of your dashboards:
+//!
+//! - A metric name prefix, e.g. `contoso` turns `resilience.event` into `contoso.resilience.event`.
+//! - Static labels for the service and the dependency the pipeline protects.
+//! - Whether per-attempt labels are attached to retry and hedging events.
+//! - An opt-in duration histogram with configurable bucket boundaries.
+//!
+//! The options are applied to a pipeline through [`ResilienceContext::metrics_options`][crate::ResilienceContext::metrics_options]
+//! and apply uniformly to every middleware created from that context.
+//!
+//! # Metrics
+//!
+//! | Metric | Kind | Unit | Description |
+//! |--------|------|------|-------------|
+//! | `resilience.event` | counter | `u64` | Emitted upon the occurrence of a resilience event. |
+//! | `resilience.event.duration` | histogram | `s` | Duration associated with a resilience event (opt-in). |
+//!
+//! The duration histogram records the retry delay for `retry` events, the hedging delay for
+//! `hedging` events, and the elapsed timeout for `timeout` events.
+//!
+//! # Attributes
+//!
+//! In addition to the attributes documented by each middleware, the following attributes are
+//! attached to every reported measurement when configured:
+//!
+//! - `service.name`: Set by [`MetricsOptions::service_name`].
+//! - `resilience.dependency.name`: Set by [`MetricsOptions::dependency_name`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use tick::Clock;
+//! # use seatbelt::ResilienceContext;
+//! # use seatbelt::telemetry::MetricsOptions;
+//! # fn example(clock: Clock, provider: &dyn opentelemetry::metrics::MeterProvider) {
+//! let context = ResilienceContext::<String, String>::new(&clock)
+//!     .name("user_auth")
+//!     .use_metrics(provider)
+//!     .metrics_options(
+//!         MetricsOptions::new()
+//!             .prefix("contoso")
+//!             .service_name("auth_frontend")
+//!             .dependency_name("user_store")
+//!             .duration_buckets([0.01, 0.05, 0.1, 0.5, 1.0, 5.0]),
+//!     );
+//! # let _context = context;
+//! # }
+//! ```
+
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use opentelemetry::KeyValue;
+
+use crate::TelemetryString;
+
+/// Name of the counter that records resilience events.
+pub const EVENT_COUNTER_NAME: &str = "resilience.event";
+
+/// Name of the histogram that records durations associated with resilience events.
+pub const EVENT_DURATION_NAME: &str = "resilience.event.duration";
+
+/// Attribute key used to annotate the service that reports the event.
+pub(crate) const SERVICE_NAME: &str = "service.name";
+
+/// Attribute key used to annotate the dependency that the pipeline protects.
+pub(crate) const DEPENDENCY_NAME: &str = "resilience.dependency.name";
+
+/// Naming and label configuration for resilience metrics.
+///
+/// Use [`ResilienceContext::metrics_options`][crate::ResilienceContext::metrics_options] to
+/// apply the options to a pipeline. See the [module documentation][self] for details.
+///
+/// # Defaults
+///
+/// | Option | Default Value |
+/// |--------|---------------|
+/// | Prefix | None |
+/// | Service name | None |
+/// | Dependency name | None |
+/// | Attempt labels | Enabled |
+/// | Duration histogram | Disabled |
+/// | Duration buckets | OpenTelemetry SDK defaults |
+#[derive(Debug, Clone)]
+pub struct MetricsOptions {
+    prefix: Option<TelemetryString>,
+    service_name: Option<TelemetryString>,
+    dependency_name: Option<TelemetryString>,
+    attempt_labels: bool,
+    record_durations: bool,
+    duration_buckets: Option<Vec<f64>>,
+}
+
+impl Default for MetricsOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetricsOptions {
+    /// Creates options that reproduce the default metric naming and labels.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            prefix: None,
+            service_name: None,
+            dependency_name: None,
+            attempt_labels: true,
+            record_durations: false,
+            duration_buckets: None,
+        }
+    }
+
+    /// Sets a prefix that is prepended, separated by a dot, to every metric name.
+    ///
+    /// For example, the prefix `contoso` produces the `contoso.resilience.event` counter.
+    /// An empty prefix is equivalent to no prefix.
+    #[must_use]
+    pub fn prefix(mut self, prefix: impl Into<Cow<'static, str>>) -> Self {
+        self.prefix = Some(prefix.into()).filter(|p| !p.is_empty());
+        self
+    }
+
+    /// Attaches the `service.name` attribute to every measurement.
+    #[must_use]
+    pub fn service_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.service_name = Some(name.into());
+        self
+    }
+
+    /// Attaches the `resilience.dependency.name` attribute to every measurement.
+    ///
+    /// Use this to identify the downstream dependency protected by the pipeline,
+    /// for example `user_store` or `payments_api`.
+    #[must_use]
+    pub fn dependency_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.dependency_name = Some(name.into());
+        self
+    }
+
+    /// Controls whether the `resilience.attempt.*` attributes are attached to measurements.
+    ///
+    /// Attempt attributes are enabled by default. Disabling them reduces the cardinality of
+    /// the reported series when per-attempt breakdowns are not needed.
+    #[must_use]
+    pub fn attempt_labels(mut self, enabled: bool) -> Self {
+        self.attempt_labels = enabled;
+        self
+    }
+
+    /// Controls whether the `resilience.event.duration` histogram is reported.
+    ///
+    /// The histogram is disabled by default.
+    #[must_use]
+    pub fn record_durations(mut self, enabled: bool) -> Self {
+        self.record_durations = enabled;
+        self
+    }
+
+    /// Sets the bucket boundaries, in seconds, of the `resilience.event.duration` histogram.
+    ///
+    /// Setting the bucket boundaries also enables the histogram, see [`record_durations`][Self::record_durations].
+    #[must_use]
+    pub fn duration_buckets(mut self, boundaries: impl IntoIterator<Item = f64>) -> Self {
+        self.duration_buckets = Some(boundaries.into_iter().collect());
+        self.record_durations = true;
+        self
+    }
+}
+
+#[cfg_attr(
+    not(any(
+        feature = "retry",
+        feature = "breaker",
+        feature = "timeout",
+        feature = "fallback",
+        feature = "hedging",
+        feature = "chaos-injection",
+        feature = "chaos-latency",
+        test
+    )),
+    expect(dead_code, reason = "options are only consumed when at least one middleware is enabled")
+)]
+impl MetricsOptions {
+    pub(crate) fn metric_name(&self, name: &'static str) -> Cow<'static, str> {
+        match &self.prefix {
+            Some(prefix) => Cow::Owned(format!("{prefix}.{name}")),
+            None => Cow::Borrowed(name),
+        }
+    }
+
+    pub(crate) fn attempt_labels_enabled(&self) -> bool {
+        self.attempt_labels
+    }
+
+    pub(crate) fn durations_enabled(&self) -> bool {
+        self.record_durations
+    }
+
+    pub(crate) fn buckets(&self) -> Option<&[f64]> {
+        self.duration_buckets.as_deref()
+    }
+
+    pub(crate) fn common_attributes(&self) -> Arc<[KeyValue]> {
+        self.service_name
+            .iter()
+            .map(|name| KeyValue::new(SERVICE_NAME, name.clone()))
+            .chain(self.dependency_name.iter().map(|name| KeyValue::new(DEPENDENCY_NAME, name.clone())))
+            .collect()
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_ok() {
+        let options = MetricsOptions::default();
+
+        assert_eq!(options.metric_name(EVENT_COUNTER_NAME), "resilience.event");
+        assert!(options.attempt_labels_enabled());
+        assert!(!options.durations_enabled());
+        assert!(options.buckets().is_none());
+        assert!(options.common_attributes().is_empty());
+    }
+
+    #[test]
+    fn prefix_applied_to_metric_name() {
+        let options = MetricsOptions::new().prefix("contoso");
+
+        assert_eq!(options.metric_name(EVENT_COUNTER_NAME), "contoso.resilience.event");
+        assert_eq!(options.metric_name(EVENT_DURATION_NAME), "contoso.resilience.event.duration");
+    }
+
+    #[test]
+    fn empty_prefix_ignored() {
+        let options = MetricsOptions::new().prefix("");
+
+        assert_eq!(options.metric_name(EVENT_COUNTER_NAME), "resilience.event");
+    }
+
+    #[test]
+    fn common_attributes_ok() {
+        let options = MetricsOptions::new().service_name("svc").dependency_name("dep");
+
+        assert_eq!(
+            options.common_attributes().as_ref(),
+            &[KeyValue::new(SERVICE_NAME, "svc"), KeyValue::new(DEPENDENCY_NAME, "dep")]
+        );
+    }
+
+    #[test]
+    fn duration_buckets_enable_durations() {
+        let options = MetricsOptions::new().duration_buckets([0.1, 1.0]);
+
+        assert!(options.durations_enabled());
+        assert_eq!(options.buckets(), Some([0.1, 1.0].as_slice()));
+    }
+
+    #[test]
+    fn attempt_labels_can_be_disabled() {
+        let options = MetricsOptions::new().attempt_labels(false);
+
+        assert!(!options.attempt_labels_enabled());
+    }
+}
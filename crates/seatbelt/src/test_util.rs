@@ -0,0 +1,737 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Utilities for deterministically testing resilience configurations.
+//!
+//! This module is only available when the `test-util` feature is enabled.
+//!
+//! Resilience middleware is driven by time: retries back off, timeouts elapse, circuits stay
+//! open for a break duration. Testing such configurations against the system clock is slow
+//! and non-deterministic. The types in this module use [`ClockControl`] instead, so time advances
+//! instantly and every run observes exactly the same delays:
+//!
+//! - [`TestHarness`] bundles a [`ResilienceContext`] wired to a [`ClockControl`] that
+//!   automatically advances timers.
+//! - [`ScriptedService`] is an inner service that returns a scripted sequence of outputs,
+//!   optionally after a delay, and records when each attempt started.
+//! - [`FlakyService`] wraps an inner service and makes it fail or slow down, following a
+//!   script of [`Fault`]s or at random.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(not(all(feature = "test-util", feature = "retry")))] fn main() {}
+//! # #[cfg(all(feature = "test-util", feature = "retry"))]
+//! # fn main() {
+//! # use std::time::Duration;
+//! # use layered::{Service, Stack};
+//! # use seatbelt::RecoveryInfo;
+//! # use seatbelt::retry::{Backoff, Retry};
+//! use seatbelt::test_util::TestHarness;
+//!
+//! # futures::executor::block_on(async {
+//! let harness = TestHarness::<String, Result<String, String>>::new();
+//! let inner = harness
+//!     .scripted_service()
+//!     .respond(Err("transient".to_string()))
+//!     .respond(Err("transient".to_string()))
+//!     .respond(Ok("done".to_string()));
+//!
+//! let service = (
+//!     Retry::layer("retry", harness.context())
+//!         .clone_input()
+//!         .recovery_with(|output: &Result<String, String>, _| match output {
+//!             Ok(_) => RecoveryInfo::never(),
+//!             Err(_) => RecoveryInfo::retry(),
+//!         })
+//!         .backoff(Backoff::Constant)
+//!         .use_jitter(false)
+//!         .base_delay(Duration::from_millis(100)),
+//!     inner.clone(),
+//! )
+//!     .into_service();
+//!
+//! assert_eq!(
+//!     service.execute("input".to_string()).await,
+//!     Ok("done".to_string())
+//! );
+//!
+//! inner.assert_attempts(3);
+//! inner.assert_delays(&[Duration::from_millis(100), Duration::from_millis(100)]);
+//! # });
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use layered::Service;
+use tick::{Clock, ClockControl};
+
+use crate::ResilienceContext;
+
+/// A [`ResilienceContext`] wired to a controllable clock.
+///
+/// By default, the clock automatically advances timers, so delays introduced by the middleware
+/// under test (retry backoff, hedging delays) complete instantly while still being observed with
+/// their configured duration. Use [`clock_control`][Self::clock_control] to move time manually,
+/// for example to let a circuit breaker's break duration elapse.
+///
+/// Automatically advanced timers fire in the order they are polled, so a delayed response
+/// completes before a timeout that wraps it. To exercise timeouts, create the harness with a
+/// manual clock through [`with_clock_control`][Self::with_clock_control] and advance it explicitly.
+///
+/// See the [module documentation][self] for an example.
+#[derive(Debug)]
+pub struct TestHarness<In, Out> {
+    control: ClockControl,
+    clock: Clock,
+    context: ResilienceContext<In, Out>,
+}
+
+impl<In, Out> Default for TestHarness<In, Out> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<In, Out> TestHarness<In, Out> {
+    /// Creates a harness whose clock automatically advances timers.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_clock_control(ClockControl::new().auto_advance_timers(true))
+    }
+
+    /// Creates a harness driven by the given clock control.
+    ///
+    /// Use this when the test needs a different time-advancing strategy, such as
+    /// [`ClockControl::auto_advance`].
+    #[must_use]
+    pub fn with_clock_control(control: ClockControl) -> Self {
+        let clock = control.to_clock();
+
+        Self {
+            context: ResilienceContext::new(&clock).name("test_harness"),
+            control,
+            clock,
+        }
+    }
+
+    /// Returns the resilience context to create the middleware under test with.
+    #[must_use]
+    pub fn context(&self) -> &ResilienceContext<In, Out> {
+        &self.context
+    }
+
+    /// Returns the clock control that drives the context.
+    #[must_use]
+    pub fn clock_control(&self) -> &ClockControl {
+        &self.control
+    }
+
+    /// Returns the clock used by the context.
+    #[must_use]
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Creates an empty [`ScriptedService`] that uses the clock of this harness.
+    #[must_use]
+    pub fn scripted_service(&self) -> ScriptedService<In, Out> {
+        ScriptedService::new(&self.clock)
+    }
+
+    /// Creates a [`FlakyService`] that wraps `inner` and uses the clock of this harness.
+    ///
+    /// Failed executions return the output of `failure`.
+    #[must_use]
+    pub fn flaky_service<S>(&self, inner: S, failure: impl Fn() -> Out + Send + Sync + 'static) -> FlakyService<S, Out> {
+        FlakyService::new(&self.clock, inner, failure)
+    }
+}
+
+/// An inner service that returns a scripted sequence of outputs.
+///
+/// Each call to the service consumes the next response of the script; once the script is
+/// exhausted, the last response is repeated. Responses can be delayed to simulate slow
+/// dependencies, which is useful for exercising timeouts and hedging.
+/// See [`TestHarness`] for how delayed responses interact with the clock.
+///
+/// Clones share the script and the recorded attempts, so keep a clone around to make
+/// assertions after the service has been moved into a pipeline.
+///
+/// # Panics
+///
+/// Executing the service panics if no response has been scripted.
+pub struct ScriptedService<In, Out> {
+    shared: Arc<Shared<Out>>,
+    _input: PhantomData<fn(In)>,
+}
+
+struct Shared<Out> {
+    clock: Clock,
+    state: Mutex<State<Out>>,
+}
+
+impl<Out> Shared<Out> {
+    // The state is only mutated by pushing complete entries, so it remains consistent even if
+    // a panicking test poisoned the lock.
+    fn state(&self) -> MutexGuard<'_, State<Out>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+struct State<Out> {
+    responses: Vec<(Duration, Out)>,
+    attempts: Vec<Instant>,
+}
+
+impl<In, Out> ScriptedService<In, Out> {
+    /// Creates a service without any scripted responses.
+    #[must_use]
+    pub fn new(clock: &Clock) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                clock: clock.clone(),
+                state: Mutex::new(State {
+                    responses: Vec::new(),
+                    attempts: Vec::new(),
+                }),
+            }),
+            _input: PhantomData,
+        }
+    }
+
+    /// Appends a response that is returned immediately.
+    #[must_use]
+    pub fn respond(self, output: Out) -> Self {
+        self.respond_after(Duration::ZERO, output)
+    }
+
+    /// Appends a response that is returned after the given delay.
+    #[must_use]
+    pub fn respond_after(self, delay: Duration, output: Out) -> Self {
+        self.shared.state().responses.push((delay, output));
+        self
+    }
+
+    /// Returns the number of times the service has been executed.
+    #[must_use]
+    pub fn attempts(&self) -> usize {
+        self.shared.state().attempts.len()
+    }
+
+    /// Returns the time elapsed between the starts of consecutive attempts.
+    ///
+    /// For a retry pipeline without delayed responses, these are the retry delays.
+    #[must_use]
+    pub fn delays(&self) -> Vec<Duration> {
+        let state = self.shared.state();
+
+        state
+            .attempts
+            .windows(2)
+            .map(|pair| pair[1].saturating_duration_since(pair[0]))
+            .collect()
+    }
+
+    /// Asserts that the service has been executed exactly `expected` times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of attempts differs.
+    #[track_caller]
+    pub fn assert_attempts(&self, expected: usize) {
+        let actual = self.attempts();
+        assert_eq!(actual, expected, "expected {expected} attempts, got {actual}");
+    }
+
+    /// Asserts that the delays between consecutive attempts match `expected`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the delays differ, see [`delays`][Self::delays].
+    #[track_caller]
+    pub fn assert_delays(&self, expected: &[Duration]) {
+        let actual = self.delays();
+        assert_eq!(actual, expected, "expected delays {expected:?}, got {actual:?}");
+    }
+}
+
+impl<In, Out: Clone> ScriptedService<In, Out> {
+    fn next_response(&self) -> (Duration, Out) {
+        let mut state = self.shared.state();
+        let index = state.attempts.len().min(state.responses.len().saturating_sub(1));
+        let response = state
+            .responses
+            .get(index)
+            .cloned()
+            .expect("no responses are scripted, call `respond` before executing the service");
+
+        state.attempts.push(self.shared.clock.instant());
+        response
+    }
+}
+
+impl<In, Out> Clone for ScriptedService<In, Out> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+            _input: PhantomData,
+        }
+    }
+}
+
+impl<In, Out> Debug for ScriptedService<In, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.shared.state();
+
+        f.debug_struct("ScriptedService")
+            .field("responses", &state.responses.len())
+            .field("attempts", &state.attempts.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<In, Out> Service<In> for ScriptedService<In, Out>
+where
+    In: Send,
+    Out: Clone + Send + 'static,
+{
+    type Out = Out;
+
+    async fn execute(&self, _input: In) -> Self::Out {
+        let (delay, output) = self.next_response();
+
+        if !delay.is_zero() {
+            self.shared.clock.delay(delay).await;
+        }
+
+        output
+    }
+}
+
+#[cfg(any(feature = "tower-service", test))]
+impl<In, T, E> tower_service::Service<In> for ScriptedService<In, Result<T, E>>
+where
+    In: Send,
+    T: Clone + Send + 'static,
+    E: Clone + Send + 'static,
+{
+    type Response = T;
+    type Error = E;
+    type Future = std::pin::Pin<Box<dyn Future<Output = Result<T, E>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut std::task::Context<'_>) -> std::task::Poll<Result<(), Self::Error>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _req: In) -> Self::Future {
+        let (delay, output) = self.next_response();
+        let clock = self.shared.clock.clone();
+
+        Box::pin(async move {
+            if !delay.is_zero() {
+                clock.delay(delay).await;
+            }
+
+            output
+        })
+    }
+}
+
+/// A fault injected by [`FlakyService`] into a single execution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fault {
+    /// The input is passed to the inner service right away.
+    None,
+    /// The execution returns the failure output without reaching the inner service.
+    Fail,
+    /// The input is passed to the inner service after the given delay.
+    Delay(Duration),
+}
+
+/// A service that wraps an inner service and makes it fail or slow down.
+///
+/// Each execution takes the next [`Fault`] of the script. Once the script is exhausted, faults
+/// are picked at random according to [`failure_rate`][Self::failure_rate] and
+/// [`delay_rate`][Self::delay_rate], which are zero by default. Set a [`seed`][Self::seed] to
+/// reproduce a random sequence of faults.
+///
+/// Clones share the script, the random number generator, and the recorded faults, so keep a
+/// clone around to make assertions after the service has been moved into a pipeline.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(not(feature = "test-util"))] fn main() {}
+/// # #[cfg(feature = "test-util")]
+/// # fn main() {
+/// # use std::time::Duration;
+/// # use layered::Service;
+/// use seatbelt::test_util::{Fault, TestHarness};
+///
+/// # futures::executor::block_on(async {
+/// let harness = TestHarness::<String, Result<String, String>>::new();
+/// let inner = harness.scripted_service().respond(Ok("done".to_string()));
+/// let service = harness
+///     .flaky_service(inner, || Err("injected".to_string()))
+///     .script([Fault::Fail, Fault::Delay(Duration::from_secs(1))]);
+///
+/// assert_eq!(
+///     service.execute("input".to_string()).await,
+///     Err("injected".to_string())
+/// );
+/// assert_eq!(
+///     service.execute("input".to_string()).await,
+///     Ok("done".to_string())
+/// );
+/// assert_eq!(
+///     service.faults(),
+///     [Fault::Fail, Fault::Delay(Duration::from_secs(1))]
+/// );
+/// # });
+/// # }
+/// ```
+pub struct FlakyService<S, Out> {
+    inner: S,
+    shared: Arc<FlakyShared<Out>>,
+}
+
+struct FlakyShared<Out> {
+    clock: Clock,
+    failure: Box<dyn Fn() -> Out + Send + Sync>,
+    state: Mutex<FlakyState>,
+}
+
+impl<Out> FlakyShared<Out> {
+    // The state is only mutated by assigning complete values, so it remains consistent even if
+    // a panicking test poisoned the lock.
+    fn state(&self) -> MutexGuard<'_, FlakyState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+struct FlakyState {
+    script: VecDeque<Fault>,
+    failure_rate: f64,
+    delay_rate: f64,
+    delay: Duration,
+    rng: fastrand::Rng,
+    faults: Vec<Fault>,
+}
+
+impl FlakyState {
+    fn next_fault(&mut self) -> Fault {
+        let fault = self.script.pop_front().unwrap_or_else(|| {
+            if self.rng.f64() < self.failure_rate {
+                Fault::Fail
+            } else if self.rng.f64() < self.delay_rate {
+                Fault::Delay(self.delay)
+            } else {
+                Fault::None
+            }
+        });
+
+        self.faults.push(fault);
+        fault
+    }
+}
+
+impl<S, Out> FlakyService<S, Out> {
+    /// Creates a service that wraps `inner` without injecting any faults.
+    ///
+    /// Failed executions return the output of `failure`.
+    #[must_use]
+    pub fn new(clock: &Clock, inner: S, failure: impl Fn() -> Out + Send + Sync + 'static) -> Self {
+        Self {
+            inner,
+            shared: Arc::new(FlakyShared {
+                clock: clock.clone(),
+                failure: Box::new(failure),
+                state: Mutex::new(FlakyState {
+                    script: VecDeque::new(),
+                    failure_rate: 0.0,
+                    delay_rate: 0.0,
+                    delay: Duration::ZERO,
+                    rng: fastrand::Rng::new(),
+                    faults: Vec::new(),
+                }),
+            }),
+        }
+    }
+
+    /// Appends faults to the script, to be injected into the next executions in order.
+    #[must_use]
+    pub fn script(self, faults: impl IntoIterator<Item = Fault>) -> Self {
+        self.shared.state().script.extend(faults);
+        self
+    }
+
+    /// Sets the probability of an unscripted execution to fail.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not between 0.0 and 1.0.
+    #[must_use]
+    pub fn failure_rate(self, rate: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&rate),
+            "the failure rate must be between 0.0 and 1.0, got {rate}"
+        );
+        self.shared.state().failure_rate = rate;
+        self
+    }
+
+    /// Sets the probability of an unscripted execution that does not fail to be delayed by `delay`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not between 0.0 and 1.0.
+    #[must_use]
+    pub fn delay_rate(self, rate: f64, delay: Duration) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&rate),
+            "the delay rate must be between 0.0 and 1.0, got {rate}"
+        );
+        let mut state = self.shared.state();
+        state.delay_rate = rate;
+        state.delay = delay;
+        drop(state);
+        self
+    }
+
+    /// Seeds the random number generator that picks the unscripted faults.
+    #[must_use]
+    pub fn seed(self, seed: u64) -> Self {
+        self.shared.state().rng.seed(seed);
+        self
+    }
+
+    /// Returns the faults injected so far, one for each execution.
+    #[must_use]
+    pub fn faults(&self) -> Vec<Fault> {
+        self.shared.state().faults.clone()
+    }
+
+    /// Returns the wrapped service.
+    #[must_use]
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<S: Clone, Out> Clone for FlakyService<S, Out> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<S: Debug, Out> Debug for FlakyService<S, Out> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.shared.state();
+
+        f.debug_struct("FlakyService")
+            .field("inner", &self.inner)
+            .field("script", &state.script)
+            .field("failure_rate", &state.failure_rate)
+            .field("delay_rate", &state.delay_rate)
+            .field("faults", &state.faults.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, In, Out> Service<In> for FlakyService<S, Out>
+where
+    S: Service<In, Out = Out>,
+    In: Send,
+    Out: Send,
+{
+    type Out = Out;
+
+    async fn execute(&self, input: In) -> Self::Out {
+        let fault = self.shared.state().next_fault();
+
+        match fault {
+            Fault::None => self.inner.execute(input).await,
+            Fault::Fail => (self.shared.failure)(),
+            Fault::Delay(delay) => {
+                self.shared.clock.delay(delay).await;
+                self.inner.execute(input).await
+            }
+        }
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use std::future::poll_fn;
+
+    use futures::executor::block_on;
+
+    use super::*;
+
+    #[test]
+    fn scripted_service_returns_responses_in_order() {
+        let harness = TestHarness::<(), u32>::new();
+        let service = harness.scripted_service().respond(1).respond(2);
+
+        assert_eq!(block_on(service.execute(())), 1);
+        assert_eq!(block_on(service.execute(())), 2);
+        assert_eq!(block_on(service.execute(())), 2);
+        service.assert_attempts(3);
+    }
+
+    #[test]
+    fn scripted_service_records_delays() {
+        let harness = TestHarness::<(), u32>::new();
+        let service = harness.scripted_service().respond_after(Duration::from_secs(1), 1).respond(2);
+
+        block_on(service.execute(()));
+        harness.clock_control().advance(Duration::from_secs(2));
+        block_on(service.execute(()));
+
+        // the first attempt took 1s, followed by 2s of manually advanced time
+        service.assert_delays(&[Duration::from_secs(3)]);
+    }
+
+    #[test]
+    fn clones_share_attempts() {
+        let harness = TestHarness::<(), u32>::default();
+        let service = harness.scripted_service().respond(1);
+        let clone = service.clone();
+
+        block_on(clone.execute(()));
+
+        service.assert_attempts(1);
+        assert!(service.delays().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "no responses are scripted")]
+    fn empty_script_panics() {
+        let service = ScriptedService::<(), u32>::new(&Clock::new_frozen());
+
+        block_on(service.execute(()));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 2 attempts, got 0")]
+    fn assert_attempts_panics_on_mismatch() {
+        let service = ScriptedService::<(), u32>::new(&Clock::new_frozen());
+
+        service.assert_attempts(2);
+    }
+
+    #[test]
+    fn tower_service_ok() {
+        let harness = TestHarness::<(), Result<u32, String>>::new();
+        let mut service = harness.scripted_service().respond(Err("error".to_string())).respond(Ok(1));
+
+        block_on(poll_fn(|cx| tower_service::Service::poll_ready(&mut service, cx))).unwrap();
+        assert_eq!(block_on(tower_service::Service::call(&mut service, ())), Err("error".to_string()));
+        assert_eq!(block_on(tower_service::Service::call(&mut service, ())), Ok(1));
+    }
+
+    #[test]
+    fn debug_ok() {
+        let service = ScriptedService::<(), u32>::new(&Clock::new_frozen()).respond(1);
+
+        assert_eq!(format!("{service:?}"), "ScriptedService { responses: 1, attempts: 0, .. }");
+    }
+
+    #[test]
+    fn harness_accessors_ok() {
+        let harness = TestHarness::<(), u32>::with_clock_control(ClockControl::new());
+        let start = harness.clock().instant();
+
+        harness.clock_control().advance(Duration::from_secs(1));
+
+        assert_eq!(harness.clock().instant() - start, Duration::from_secs(1));
+        let _context = harness.context();
+    }
+
+    #[test]
+    fn flaky_service_follows_script() {
+        let harness = TestHarness::<(), Result<u32, String>>::new();
+        let inner = harness.scripted_service().respond(Ok(1));
+        let service = harness.flaky_service(inner.clone(), || Err("injected".to_string())).script([
+            Fault::Fail,
+            Fault::Delay(Duration::from_secs(1)),
+            Fault::None,
+        ]);
+
+        assert_eq!(block_on(service.execute(())), Err("injected".to_string()));
+
+        let start = harness.clock().instant();
+        assert_eq!(block_on(service.execute(())), Ok(1));
+        assert_eq!(harness.clock().instant() - start, Duration::from_secs(1));
+
+        assert_eq!(block_on(service.execute(())), Ok(1));
+
+        // unscripted executions pass through, as the rates default to zero
+        assert_eq!(block_on(service.execute(())), Ok(1));
+        assert_eq!(
+            service.faults(),
+            [Fault::Fail, Fault::Delay(Duration::from_secs(1)), Fault::None, Fault::None]
+        );
+        inner.assert_attempts(3);
+    }
+
+    #[test]
+    fn flaky_service_random_faults_are_reproducible() {
+        let harness = TestHarness::<(), Result<u32, String>>::new();
+        let run = |seed| {
+            let service = harness
+                .flaky_service(harness.scripted_service().respond(Ok(1)), || Err("injected".to_string()))
+                .failure_rate(0.3)
+                .delay_rate(0.5, Duration::from_millis(10))
+                .seed(seed);
+
+            for _ in 0..50 {
+                _ = block_on(service.execute(()));
+            }
+
+            service.faults()
+        };
+
+        let faults = run(42);
+        assert_eq!(faults, run(42));
+        assert!(faults.contains(&Fault::Fail));
+        assert!(faults.contains(&Fault::Delay(Duration::from_millis(10))));
+        assert!(faults.contains(&Fault::None));
+    }
+
+    #[test]
+    fn flaky_service_always_fails_at_full_rate() {
+        let harness = TestHarness::<(), Result<u32, String>>::new();
+        let inner = harness.scripted_service().respond(Ok(1));
+        let service = harness.flaky_service(inner, || Err("injected".to_string())).failure_rate(1.0);
+
+        assert_eq!(block_on(service.execute(())), Err("injected".to_string()));
+        service.inner().assert_attempts(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the failure rate must be between 0.0 and 1.0, got 1.5")]
+    fn flaky_service_rejects_invalid_rate() {
+        let _service = FlakyService::new(&Clock::new_frozen(), (), || ()).failure_rate(1.5);
+    }
+
+    #[test]
+    fn flaky_service_debug_ok() {
+        let service = FlakyService::new(&Clock::new_frozen(), (), || ()).script([Fault::Fail]);
+
+        assert_eq!(
+            format!("{service:?}"),
+            "FlakyService { inner: (), script: [Fail], failure_rate: 0.0, delay_rate: 0.0, faults: 0, .. }"
+        );
+    }
+}
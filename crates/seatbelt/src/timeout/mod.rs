@@ -84,6 +84,9 @@
 //!   - `resilience.strategy.name`: Timeout identifier from [`Timeout::layer`]
 //!   - `resilience.event.name`: Always `timeout`
 //!
+//! When enabled through [`MetricsOptions`][crate::telemetry::MetricsOptions], the elapsed timeout
+//! is also recorded to the `resilience.event.duration` histogram with the same attributes.
+//!
 //! # Examples
 //!
 //! ## Basic Usage
@@ -202,11 +202,14 @@ impl<In, Out> TimeoutShared<In, Out> {
         if self.telemetry.metrics_enabled() {
             use crate::utils::{EVENT_NAME, PIPELINE_NAME, STRATEGY_NAME};
 
-            self.telemetry.report_metrics(&[
+            let attributes = [
                 opentelemetry::KeyValue::new(PIPELINE_NAME, self.telemetry.pipeline_name.clone()),
                 opentelemetry::KeyValue::new(STRATEGY_NAME, self.telemetry.strategy_name.clone()),
                 opentelemetry::KeyValue::new(EVENT_NAME, super::telemetry::TIMEOUT_EVENT_NAME),
-            ]);
+            ];
+
+            self.telemetry.report_metrics(&attributes);
+            self.telemetry.report_duration(timeout, &attributes);
         }
 
         let output = self.timeout_output.call(TimeoutOutputArgs { timeout });
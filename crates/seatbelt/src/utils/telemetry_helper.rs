@@ -9,6 +9,16 @@ pub(crate) struct TelemetryHelper {
     pub(crate) strategy_name: crate::TelemetryString,
     #[cfg(any(feature = "metrics", test))]
     pub(crate) event_reporter: Option<opentelemetry::metrics::Counter<u64>>,
+    #[cfg(any(feature = "metrics", test))]
+    #[cfg_attr(
+        not(any(feature = "retry", feature = "timeout", feature = "hedging", test)),
+        expect(dead_code, reason = "durations are only reported by retry, timeout and hedging")
+    )]
+    pub(crate) duration_reporter: Option<opentelemetry::metrics::Histogram<f64>>,
+    #[cfg(any(feature = "metrics", test))]
+    pub(crate) common_attributes: std::sync::Arc<[opentelemetry::KeyValue]>,
+    #[cfg(any(feature = "metrics", test))]
+    pub(crate) attempt_labels: bool,
     #[cfg(any(feature = "logs", test))]
     pub(crate) logs_enabled: bool,
 }
@@ -22,23 +32,97 @@ impl TelemetryHelper {
     #[cfg(any(feature = "metrics", test))]
     pub(crate) fn report_metrics(&self, attributes: &[opentelemetry::KeyValue]) {
         if let Some(reporter) = &self.event_reporter {
-            reporter.add(1, attributes);
+            reporter.add(1, &self.attributes(attributes));
         }
     }
+
+    /// Records the duration associated with an event, if the duration histogram is enabled.
+    #[cfg(all(
+        any(feature = "metrics", test),
+        any(feature = "retry", feature = "timeout", feature = "hedging", test)
+    ))]
+    pub(crate) fn report_duration(&self, duration: std::time::Duration, attributes: &[opentelemetry::KeyValue]) {
+        if let Some(reporter) = &self.duration_reporter {
+            reporter.record(duration.as_secs_f64(), &self.attributes(attributes));
+        }
+    }
+
+    /// Applies the configured label set to the attributes reported by a middleware.
+    ///
+    /// Borrows the attributes as-is when the default label set is used so that the
+    /// common case does not allocate.
+    #[cfg(any(feature = "metrics", test))]
+    fn attributes<'a>(&self, attributes: &'a [opentelemetry::KeyValue]) -> std::borrow::Cow<'a, [opentelemetry::KeyValue]> {
+        if self.attempt_labels && self.common_attributes.is_empty() {
+            return std::borrow::Cow::Borrowed(attributes);
+        }
+
+        attributes
+            .iter()
+            .filter(|kv| self.attempt_labels || !kv.key.as_str().starts_with(crate::attempt::ATTEMPT_PREFIX))
+            .chain(self.common_attributes.iter())
+            .cloned()
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use opentelemetry::KeyValue;
+
     use super::*;
+    use crate::attempt::ATTEMPT_INDEX;
+    use crate::testing::MetricTester;
 
-    #[test]
-    fn metrics_enabled_returns_false_when_no_reporter() {
-        let helper = TelemetryHelper {
+    fn helper(event_reporter: Option<opentelemetry::metrics::Counter<u64>>) -> TelemetryHelper {
+        TelemetryHelper {
             pipeline_name: "test".into(),
             strategy_name: "test".into(),
-            event_reporter: None,
+            event_reporter,
+            duration_reporter: None,
+            common_attributes: std::sync::Arc::from([]),
+            attempt_labels: true,
             logs_enabled: false,
-        };
-        assert!(!helper.metrics_enabled());
+        }
+    }
+
+    #[test]
+    fn metrics_enabled_returns_false_when_no_reporter() {
+        assert!(!helper(None).metrics_enabled());
+    }
+
+    #[test]
+    fn attributes_borrowed_by_default() {
+        let attributes = [KeyValue::new("a", "b")];
+
+        assert!(matches!(helper(None).attributes(&attributes), std::borrow::Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn attributes_apply_common_and_drop_attempt_labels() {
+        let mut helper = helper(None);
+        helper.attempt_labels = false;
+        helper.common_attributes = std::sync::Arc::from([KeyValue::new("service.name", "svc")]);
+
+        let attributes = [KeyValue::new("a", "b"), KeyValue::new(ATTEMPT_INDEX, 1)];
+
+        assert_eq!(
+            helper.attributes(&attributes).as_ref(),
+            &[KeyValue::new("a", "b"), KeyValue::new("service.name", "svc")]
+        );
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn report_duration_records_when_enabled() {
+        let tester = MetricTester::new();
+        let meter = crate::metrics::create_meter(tester.meter_provider());
+        let options = crate::telemetry::MetricsOptions::new().record_durations(true);
+        let mut helper = helper(None);
+        helper.duration_reporter = Some(crate::metrics::create_resilience_event_duration_histogram(&meter, &options));
+
+        helper.report_duration(std::time::Duration::from_secs(1), &[KeyValue::new("a", "b")]);
+
+        tester.assert_attributes(&[KeyValue::new("a", "b")], Some(1));
     }
 }
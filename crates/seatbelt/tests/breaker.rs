@@ -15,7 +15,7 @@ use std::time::Duration;
 
 use layered::{Execute, Service, Stack};
 use rstest::rstest;
-use seatbelt::breaker::{Breaker, BreakerId, HalfOpenMode, OnClosedArgs, RejectedInputArgs};
+use seatbelt::breaker::{Breaker, BreakerId, HalfOpenMode, InMemoryStateStore, OnClosedArgs, RejectedInputArgs};
 use seatbelt::{RecoveryInfo, ResilienceContext};
 use tick::{Clock, ClockControl};
 use tower_service::Service as TowerService;
@@ -388,3 +388,54 @@ async fn clone_service_shares_circuit_state(#[case] use_tower: bool) {
     assert_eq!(result1, Ok("circuit is open".to_string()));
     assert_eq!(result2, Ok("circuit is open".to_string()));
 }
+
+#[rstest]
+#[case::layered(false)]
+#[case::tower(true)]
+#[tokio::test]
+async fn state_store_warm_starts_restarted_service(#[case] use_tower: bool) {
+    let clock_control = ClockControl::new();
+    let context: ResilienceContext<String, Result<String, String>> = ResilienceContext::new(clock_control.to_clock()).name("test_pipeline");
+    let store = InMemoryStateStore::new();
+
+    let create_service = || {
+        (
+            Breaker::layer("test_breaker", &context)
+                .min_throughput(3)
+                .half_open_mode(HalfOpenMode::quick())
+                .state_store(store.clone())
+                .recovery_with(|output: &Result<String, String>, _| {
+                    if output.as_ref().is_ok_and(|s| s.contains("error")) {
+                        RecoveryInfo::retry()
+                    } else {
+                        RecoveryInfo::never()
+                    }
+                })
+                .rejected_input(|_: String, _| Ok("circuit is open".to_string())),
+            Execute::new(|input: String| async move { Ok::<_, String>(input) }),
+        )
+            .into_service()
+    };
+
+    // Trip the circuit, then drop the service to simulate a restart
+    let mut service = create_service();
+    for _ in 0..3 {
+        let _ = execute_service(&mut service, "error".to_string(), use_tower).await;
+    }
+    drop(service);
+
+    // The re-created service resumes with the open circuit
+    let mut service = create_service();
+    let result = execute_service(&mut service, "test".to_string(), use_tower).await;
+    assert_eq!(result, Ok("circuit is open".to_string()));
+
+    // Once the persisted break elapses, the circuit probes and closes
+    clock_control.advance(DEFAULT_BREAK_DURATION);
+    let result = execute_service(&mut service, "test".to_string(), use_tower).await;
+    assert_eq!(result, Ok("test".to_string()));
+
+    // The closed state is persisted as well
+    let mut service = create_service();
+    let result = execute_service(&mut service, "test".to_string(), use_tower).await;
+    assert_eq!(result, Ok("test".to_string()));
+}
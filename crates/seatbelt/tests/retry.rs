@@ -9,8 +9,10 @@
 //! Integration tests for retry middleware using only public API.
 
 use std::future::poll_fn;
+use std::pin::{Pin, pin};
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
+use std::task::Poll;
 use std::time::Duration;
 
 use layered::{Execute, Service, Stack};
@@ -395,6 +397,40 @@ async fn outage_handling_with_recovery_hint(#[case] use_tower: bool) {
     assert_eq!(delays.lock().unwrap().to_vec(), vec![Duration::from_secs(10)]);
 }
 
+#[rstest]
+#[case::layered(false)]
+#[case::tower(true)]
+#[tokio::test]
+async fn absolute_recovery_hint(#[case] use_tower: bool) {
+    let clock = ClockControl::default().auto_advance_timers(true).to_clock();
+    let delays = Arc::new(Mutex::new(vec![]));
+    let delays_clone = Arc::clone(&delays);
+
+    let context: ResilienceContext<String, Result<String, String>> = ResilienceContext::new(&clock).name("test_pipeline");
+    let stack = (
+        Retry::layer("test_retry", &context)
+            .clone_input_with(move |input: &mut String, _args| Some(input.clone()))
+            .recovery_with(|_output: &Result<String, String>, args: RecoveryArgs| {
+                if args.attempt().index() == 0 {
+                    RecoveryInfo::retry().delay_until(args.clock().system_time() + Duration::from_secs(10))
+                } else {
+                    RecoveryInfo::never()
+                }
+            })
+            .max_retry_attempts(1)
+            .on_retry(move |_output: &Result<String, String>, args: OnRetryArgs| {
+                delays_clone.lock().unwrap().push(args.retry_delay());
+            }),
+        Execute::new(move |v: String| async move { Ok::<_, String>(v) }),
+    );
+
+    let mut service = stack.into_service();
+    let _result = execute_service(&mut service, "test".to_string(), use_tower).await;
+
+    // Should wait until the point in time of the recovery hint
+    assert_eq!(delays.lock().unwrap().to_vec(), vec![Duration::from_secs(10)]);
+}
+
 #[rstest]
 #[case::layered(false)]
 #[case::tower(true)]
@@ -434,3 +470,106 @@ async fn clone_service_works_independently(#[case] use_tower: bool) {
     // Each service ran through retry cycle: 3 attempts each = 6 total
     assert_eq!(call_count.load(Ordering::SeqCst), 6);
 }
+
+/// Polls the future once without a runtime, so that time can be advanced manually between polls.
+fn poll_once<F: Future>(future: Pin<&mut F>) -> Poll<F::Output> {
+    future.poll(&mut std::task::Context::from_waker(std::task::Waker::noop()))
+}
+
+#[rstest]
+#[case::layered(false)]
+#[case::tower(true)]
+#[test]
+fn per_attempt_timeout_retries_slow_attempts(#[case] use_tower: bool) {
+    let control = ClockControl::new();
+    let clock = control.to_clock();
+    let counter = Arc::new(AtomicU32::new(0));
+    let counter_clone = Arc::clone(&counter);
+    let inner_clock = clock.clone();
+
+    let context: ResilienceContext<String, Result<String, String>> = ResilienceContext::new(&clock).name("test_pipeline");
+    let stack = (
+        Retry::layer("test_retry", &context)
+            .clone_input()
+            .recovery_with(|output: &Result<String, String>, _args| match output {
+                Ok(_) => RecoveryInfo::never(),
+                Err(_) => RecoveryInfo::retry(),
+            })
+            .base_delay(Duration::ZERO)
+            .per_attempt_timeout(Duration::from_secs(2))
+            .timeout_error(|args| format!("attempt {:?} timed out after {:?}", args.attempt(), args.timeout())),
+        Execute::new(move |v: String| {
+            let clock = inner_clock.clone();
+            let call = counter_clone.fetch_add(1, Ordering::SeqCst);
+            async move {
+                // the first attempt hangs, the second one completes in time
+                if call == 0 {
+                    clock.delay(Duration::from_mins(1)).await;
+                }
+                Ok::<_, String>(v)
+            }
+        }),
+    );
+
+    let mut service = stack.into_service();
+    let mut future = pin!(execute_service(&mut service, "test".to_string(), use_tower));
+
+    assert!(poll_once(future.as_mut()).is_pending());
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+
+    control.advance(Duration::from_secs(2));
+
+    assert_eq!(poll_once(future.as_mut()), Poll::Ready(Ok("test".to_string())));
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
+
+#[rstest]
+#[case::layered(false)]
+#[case::tower(true)]
+#[test]
+fn total_timeout_bounds_all_attempts(#[case] use_tower: bool) {
+    let control = ClockControl::new();
+    let clock = control.to_clock();
+    let counter = Arc::new(AtomicU32::new(0));
+    let counter_clone = Arc::clone(&counter);
+    let inner_clock = clock.clone();
+
+    let context: ResilienceContext<String, Result<String, String>> = ResilienceContext::new(&clock).name("test_pipeline");
+    let stack = (
+        Retry::layer("test_retry", &context)
+            .clone_input()
+            .recovery_with(|_: &Result<String, String>, _args| RecoveryInfo::retry())
+            .max_retry_attempts(100)
+            .base_delay(Duration::ZERO)
+            .per_attempt_timeout(Duration::from_secs(2))
+            .total_timeout(Duration::from_secs(5))
+            .timeout_error(|args| match args.attempt() {
+                Some(_) => "attempt timeout".to_string(),
+                None => "total timeout".to_string(),
+            }),
+        Execute::new(move |_v: String| {
+            let clock = inner_clock.clone();
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+            async move {
+                clock.delay(Duration::from_mins(1)).await;
+                Ok::<String, String>("completed".to_string())
+            }
+        }),
+    );
+
+    let mut service = stack.into_service();
+    let mut future = pin!(execute_service(&mut service, "test".to_string(), use_tower));
+
+    // attempts started at 0s and 2s time out individually
+    assert!(poll_once(future.as_mut()).is_pending());
+    control.advance(Duration::from_secs(2));
+    assert!(poll_once(future.as_mut()).is_pending());
+    control.advance(Duration::from_secs(2));
+    assert!(poll_once(future.as_mut()).is_pending());
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+
+    // the attempt started at 4s is cut short by the total timeout at 5s
+    control.advance(Duration::from_secs(1));
+    assert_eq!(poll_once(future.as_mut()), Poll::Ready(Err("total timeout".to_string())));
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+}
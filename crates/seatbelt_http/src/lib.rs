@@ -32,6 +32,13 @@
 //! | `hedging` | `hedging` | Recovery classification and request cloning for tail-latency reduction. |
 //! | `breaker` | `breaker` | Recovery classification and rejected-request error handling. |
 //!
+//! # Presets
+//!
+//! When the `retry`, `timeout`, and `breaker` features are enabled, the
+//! `presets` module provides ready-to-use resilience stacks such as
+//! `presets::standard_http_client`, which combines retry, circuit breaker,
+//! and per-attempt timeout middleware with sensible defaults.
+//!
 //! # Shared types
 //!
 //! - [`HttpRecovery`]: classifies HTTP responses as recoverable. By default,
@@ -61,6 +68,9 @@ pub mod hedging;
 #[cfg(feature = "breaker")]
 pub mod breaker;
 
+#[cfg(all(feature = "retry", feature = "timeout", feature = "breaker"))]
+pub mod presets;
+
 #[cfg(any(feature = "retry", feature = "hedging", feature = "breaker"))]
 mod http_recovery;
 #[cfg(any(feature = "retry", feature = "hedging", feature = "breaker"))]
@@ -0,0 +1,228 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Opinionated resilience stacks for HTTP clients.
+//!
+//! The factory functions in this module assemble the individual HTTP
+//! middleware of this crate into a ready-to-use stack with sensible defaults,
+//! so that teams get a consistent baseline without having to configure each
+//! middleware separately.
+//!
+//! Every preset implements [`Layer`], so it can be stacked in front of an HTTP
+//! handler directly. Each middleware of the preset remains fully configurable
+//! through the builder methods of its layer.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use http_extensions::{FakeHandler, HttpRequestBuilder};
+//! # use layered::{Service, Stack};
+//! # use seatbelt_http::HttpResilienceContext;
+//! use seatbelt_http::presets;
+//! # async fn example(context: &HttpResilienceContext, handler: FakeHandler) {
+//! let service = (
+//!     presets::standard_http_client(context)
+//!         .retry(|retry| retry.max_retry_attempts(5))
+//!         .timeout(|timeout| timeout.timeout(Duration::from_secs(2))),
+//!     handler,
+//! )
+//!     .into_service();
+//!
+//! let request = HttpRequestBuilder::new_fake()
+//!     .uri("https://example.com")
+//!     .build()
+//!     .unwrap();
+//! let response = service.execute(request).await;
+//! # let _response = response;
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use layered::Layer;
+
+use crate::HttpResilienceContext;
+use crate::breaker::{HttpBreaker, HttpBreakerLayer, HttpBreakerLayerExt};
+use crate::retry::{HttpRetry, HttpRetryLayer, HttpRetryLayerExt};
+use crate::timeout::{HttpTimeout, HttpTimeoutLayer, HttpTimeoutLayerExt};
+
+/// The default timeout applied to each attempt by [`standard_http_client`].
+pub const DEFAULT_ATTEMPT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A standard resilience stack for HTTP clients.
+///
+/// Created by [`standard_http_client`]. The middleware are applied in the
+/// following order:
+///
+/// ```text
+/// Request → [Retry → [Breaker → [Timeout → Handler]]]
+/// ```
+///
+/// - **Retry** (`retry`): retries transient failures of safe HTTP methods, see
+///   [`HttpRetryLayerExt::http_configure_defaults`].
+/// - **Breaker** (`breaker`): tracks failures per origin and rejects requests
+///   while the circuit is open, see [`HttpBreakerLayerExt::http_configure_defaults`].
+/// - **Timeout** (`timeout`): bounds each individual attempt to
+///   [`DEFAULT_ATTEMPT_TIMEOUT`], see [`HttpTimeoutLayerExt::http_timeout_error`].
+///
+/// The names in parentheses are the strategy names reported in telemetry.
+#[derive(Debug)]
+pub struct HttpClientPreset {
+    retry: HttpRetryLayer,
+    breaker: HttpBreakerLayer,
+    timeout: HttpTimeoutLayer,
+}
+
+/// Creates the [`HttpClientPreset`] with retry, circuit breaker, and timeout
+/// middleware configured with sensible defaults.
+///
+/// Use the methods of the returned preset to override individual settings.
+#[must_use]
+pub fn standard_http_client(context: &HttpResilienceContext) -> HttpClientPreset {
+    HttpClientPreset {
+        retry: HttpRetry::layer("retry", context).http_configure_defaults(),
+        breaker: HttpBreaker::layer("breaker", context).http_configure_defaults(),
+        timeout: HttpTimeout::layer("timeout", context)
+            .http_timeout_error()
+            .timeout(DEFAULT_ATTEMPT_TIMEOUT),
+    }
+}
+
+impl HttpClientPreset {
+    /// Customizes the retry middleware of the preset.
+    #[must_use]
+    pub fn retry(mut self, configure: impl FnOnce(HttpRetryLayer) -> HttpRetryLayer) -> Self {
+        self.retry = configure(self.retry);
+        self
+    }
+
+    /// Customizes the circuit breaker middleware of the preset.
+    #[must_use]
+    pub fn breaker(mut self, configure: impl FnOnce(HttpBreakerLayer) -> HttpBreakerLayer) -> Self {
+        self.breaker = configure(self.breaker);
+        self
+    }
+
+    /// Customizes the timeout middleware of the preset.
+    #[must_use]
+    pub fn timeout(mut self, configure: impl FnOnce(HttpTimeoutLayer) -> HttpTimeoutLayer) -> Self {
+        self.timeout = configure(self.timeout);
+        self
+    }
+
+    /// Splits the preset into its individual layers, outermost first.
+    ///
+    /// Use this to interleave additional middleware between the layers of the preset.
+    #[must_use]
+    pub fn into_layers(self) -> (HttpRetryLayer, HttpBreakerLayer, HttpTimeoutLayer) {
+        (self.retry, self.breaker, self.timeout)
+    }
+}
+
+impl<S> Layer<S> for HttpClientPreset {
+    type Service = HttpRetry<HttpBreaker<HttpTimeout<S>>>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        self.retry.layer(self.breaker.layer(self.timeout.layer(inner)))
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use futures::executor::block_on;
+    use http::{Method, StatusCode};
+    use http_extensions::{FakeHandler, HttpRequestBuilder, HttpResponseBuilder};
+    use layered::{Service, Stack};
+    use seatbelt::{Recovery, RecoveryKind};
+    use tick::ClockControl;
+
+    use super::*;
+
+    fn context() -> HttpResilienceContext {
+        HttpResilienceContext::new(ClockControl::default().auto_advance_timers(true).to_clock())
+    }
+
+    #[test]
+    fn retries_transient_failures() {
+        let service = (
+            standard_http_client(&context()),
+            FakeHandler::from_status_codes([StatusCode::SERVICE_UNAVAILABLE, StatusCode::OK]),
+        )
+            .into_service();
+
+        let request = HttpRequestBuilder::new_fake().uri("https://example.com").build().unwrap();
+
+        let response = block_on(service.execute(request)).unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn does_not_retry_unsafe_methods() {
+        let service = (
+            standard_http_client(&context()),
+            FakeHandler::from_status_codes([StatusCode::SERVICE_UNAVAILABLE, StatusCode::OK]),
+        )
+            .into_service();
+
+        let request = HttpRequestBuilder::new_fake()
+            .uri("https://example.com")
+            .method(Method::POST)
+            .build()
+            .unwrap();
+
+        let response = block_on(service.execute(request)).unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[test]
+    fn timeout_applies_per_attempt() {
+        let service = (standard_http_client(&context()), FakeHandler::never_completes()).into_service();
+
+        let request = HttpRequestBuilder::new_fake().uri("https://example.com").build().unwrap();
+
+        let error = block_on(service.execute(request)).unwrap_err();
+        assert_eq!(error.recovery().kind(), RecoveryKind::Retry);
+    }
+
+    #[test]
+    fn overrides_are_applied() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&calls);
+        let handler = FakeHandler::from_fn(move |_req| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            HttpResponseBuilder::new_fake().status(StatusCode::INTERNAL_SERVER_ERROR).build()
+        });
+
+        let service = (
+            standard_http_client(&context())
+                .retry(|retry| retry.max_retry_attempts(1))
+                .breaker(|breaker| breaker.min_throughput(100))
+                .timeout(|timeout| timeout.timeout(Duration::from_secs(1))),
+            handler,
+        )
+            .into_service();
+
+        let request = HttpRequestBuilder::new_fake().uri("https://example.com").build().unwrap();
+
+        let response = block_on(service.execute(request)).unwrap();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn into_layers_preserves_order() {
+        let (retry, breaker, timeout) = standard_http_client(&context()).into_layers();
+
+        let service = (retry, breaker, timeout, FakeHandler::from(StatusCode::OK)).into_service();
+
+        let request = HttpRequestBuilder::new_fake().uri("https://example.com").build().unwrap();
+
+        let response = block_on(service.execute(request)).unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -5,3 +5,15 @@
 pub use data_privacy::{RedactedDisplay, Redactor};
 #[doc(hidden)]
 pub use http;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
+
+#[doc(hidden)]
+pub use crate::expansion::{
+    ExplodeSpec, display_classified, display_prefix, escape_prefix_into, escape_prefix_write, explode_classified, explode_display,
+    explode_escaped_into, explode_escaped_write, explode_raw_into, explode_raw_write, explode_redacted, raw_prefix_into, raw_prefix_write,
+};
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use crate::template::rendered::{deserialize_variable, match_rendered, parse_template, serialize_rendered};
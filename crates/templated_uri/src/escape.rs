@@ -2,8 +2,11 @@
 // Licensed under the MIT License.
 
 use std::fmt::Display;
-use std::net::IpAddr;
-use std::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128,
+    NonZeroUsize,
+};
 
 use data_privacy::Sensitive;
 #[cfg(feature = "uuid")]
@@ -115,13 +118,28 @@ impl_escape!(u16);
 impl_escape!(u32);
 impl_escape!(u64);
 impl_escape!(u128);
+impl_escape!(isize);
+impl_escape!(i8);
+impl_escape!(i16);
+impl_escape!(i32);
+impl_escape!(i64);
+impl_escape!(i128);
 impl_escape!(NonZeroU8);
 impl_escape!(NonZeroU16);
 impl_escape!(NonZeroU32);
 impl_escape!(NonZeroU64);
 impl_escape!(NonZeroU128);
 impl_escape!(NonZeroUsize);
+impl_escape!(NonZeroI8);
+impl_escape!(NonZeroI16);
+impl_escape!(NonZeroI32);
+impl_escape!(NonZeroI64);
+impl_escape!(NonZeroI128);
+impl_escape!(NonZeroIsize);
+impl_escape!(bool);
 impl_escape!(IpAddr);
+impl_escape!(Ipv4Addr);
+impl_escape!(Ipv6Addr);
 #[cfg(feature = "uuid")]
 impl_escape!(Uuid);
 
@@ -180,6 +198,26 @@ mod tests {
         assert_eq!(format!("{}", ip.escape()), "127.0.0.1");
     }
 
+    #[test]
+    fn uri_param_signed_and_other_primitives() {
+        assert_eq!(format!("{}", (-1i8).escape()), "-1");
+        assert_eq!(format!("{}", (-2i16).escape()), "-2");
+        assert_eq!(format!("{}", 3i32.escape()), "3");
+        assert_eq!(format!("{}", (-4i64).escape()), "-4");
+        assert_eq!(format!("{}", 5i128.escape()), "5");
+        assert_eq!(format!("{}", (-6isize).escape()), "-6");
+        assert_eq!(format!("{}", NonZeroI8::new(-1).unwrap().escape()), "-1");
+        assert_eq!(format!("{}", NonZeroI16::new(2).unwrap().escape()), "2");
+        assert_eq!(format!("{}", NonZeroI32::new(-3).unwrap().escape()), "-3");
+        assert_eq!(format!("{}", NonZeroI64::new(4).unwrap().escape()), "4");
+        assert_eq!(format!("{}", NonZeroI128::new(-5).unwrap().escape()), "-5");
+        assert_eq!(format!("{}", NonZeroIsize::new(6).unwrap().escape()), "6");
+        assert_eq!(format!("{}", true.escape()), "true");
+        assert_eq!(format!("{}", false.escape()), "false");
+        assert_eq!(format!("{}", Ipv4Addr::LOCALHOST.escape()), "127.0.0.1");
+        assert_eq!(format!("{}", Ipv6Addr::LOCALHOST.escape()), "::1");
+    }
+
     #[test]
     fn uri_param_uri_escaped_string() {
         let s = EscapedString::escape("hello");
@@ -5,8 +5,11 @@ use std::borrow::{Borrow, Cow};
 use std::error::Error;
 use std::fmt;
 use std::fmt::{Debug, Display};
-use std::net::IpAddr;
-use std::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128,
+    NonZeroUsize,
+};
 
 #[cfg(feature = "uuid")]
 use uuid::Uuid;
@@ -16,11 +19,11 @@ use uuid::Uuid;
 /// The invariant is enforced via constructors - only types whose [`Display`] output
 /// is already safe to splice into a URI verbatim (no *unescaped* RFC 6570 reserved
 /// characters; well-formed `%XX` percent-escapes are allowed) can be wrapped. For
-/// inherently-safe types (integers, [`IpAddr`]) an infallible [`From`] impl is provided.
+/// inherently-safe types (integers, `bool`, [`IpAddr`]) an infallible [`From`] impl is provided.
 /// With the `uuid` feature (enabled by default), `Uuid` is also supported.
 /// For strings, use the encoding/validating constructors on [`Escaped<Cow<'static, str>>`]
 /// (aliased as [`EscapedString`]).
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Escaped<T>(T);
 
@@ -68,13 +71,28 @@ impl_uri_escaped_from!(
     u32,
     u64,
     u128,
+    isize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
     NonZeroU8,
     NonZeroU16,
     NonZeroU32,
     NonZeroU64,
     NonZeroU128,
     NonZeroUsize,
-    IpAddr
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+    bool,
+    IpAddr,
+    Ipv4Addr,
+    Ipv6Addr
 );
 
 #[cfg(feature = "uuid")]
@@ -83,8 +101,32 @@ impl_uri_escaped_from!(Uuid);
 /// A URI-escaped string whose content is guaranteed to contain only characters
 /// permitted in URI templates as defined by RFC 6570 (anything else is percent-encoded).
 ///
-/// This is a type alias for `Escaped<Cow<'static, str>>`. Use its constructors
-/// (`escape`, `try_new`, `from_static`) to create instances.
+/// This is a type alias for `Escaped<Cow<'static, str>>`. Use its constructors to create
+/// instances, which cover both raw and already-encoded input without an external
+/// percent-encoding crate:
+///
+/// - [`escape`](EscapedString::escape) percent-encodes any reserved character and never fails.
+/// - [`try_new`](EscapedString::try_new) validates input that is already percent-encoded,
+///   rejecting reserved characters and malformed `%XX` sequences.
+/// - [`from_static`](EscapedString::from_static) validates a literal, at compile time in a
+///   `const` context.
+///
+/// ```
+/// use templated_uri::EscapedString;
+///
+/// let encoded = EscapedString::escape("name desc & size");
+/// assert_eq!(encoded.as_str(), "name%20desc%20%26%20size");
+///
+/// // Already-encoded input is kept as is, while raw reserved characters or a truncated
+/// // escape are rejected.
+/// assert_eq!(
+///     EscapedString::try_new(encoded.as_str().to_owned())?,
+///     encoded
+/// );
+/// assert!(EscapedString::try_new("a&b").is_err());
+/// assert!(EscapedString::try_new("100%2").is_err());
+/// # Ok::<_, templated_uri::EscapeError>(())
+/// ```
 pub type EscapedString = Escaped<Cow<'static, str>>;
 
 /// Error returned when a string is not a valid URI-escaped string.
@@ -254,7 +296,7 @@ const fn validate_escaped(bytes: &[u8]) -> Option<&'static str> {
 /// This is the exact complement of the set [`EscapedString::escape`] encodes: the
 /// unreserved characters are all ASCII, so any byte outside this set - including every
 /// byte of a multi-byte UTF-8 sequence (all `>= 0x80`) and `%` itself - is encoded.
-const fn is_unreserved_byte(b: u8) -> bool {
+pub(crate) const fn is_unreserved_byte(b: u8) -> bool {
     matches!(b, b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~')
 }
 
@@ -0,0 +1,571 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Runtime support for the RFC 6570 Level 4 value modifiers of `#[templated]` templates.
+
+use std::collections::{BTreeMap, HashMap};
+use std::convert::Infallible;
+use std::fmt::{self, Display, Formatter, Write as _};
+use std::hash::BuildHasher;
+
+use data_privacy::{DataClass, RedactedDisplay, Redactor};
+
+use crate::{Escape, Raw};
+
+/// Marks composite values - lists and associative arrays - that can fill RFC 6570 explode
+/// expansions such as `{list*}`, `{/list*}` or `{?keys*}`.
+///
+/// An exploded list expands each item as a separate value, and an exploded associative array
+/// expands each entry as a separate `key=value` pair, delimited as the expression's operator
+/// prescribes. Without the explode modifier, a `Vec<T>` field expands to a single value with
+/// its items separated by commas, such as `{?list}` expanding to `?list=red,green`. As with scalar values, the keys and values must implement [`Escape`] (or [`Raw`]
+/// in reserved expansions such as `{+list*}`) to ensure the resulting URI is valid. An empty
+/// list or associative array is undefined and omitted from the rendered URI, along with its
+/// prefix.
+///
+/// The entries of a [`HashMap`] are expanded in its iteration order, which is unspecified.
+/// Use a [`BTreeMap`] for a stable order.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use templated_uri::{EscapedString, PathAndQueryTemplate, templated};
+///
+/// #[templated(template = "/files{/path*}{?filters*}", unredacted)]
+/// struct FileSearch {
+///     path: Vec<EscapedString>,
+///     filters: BTreeMap<EscapedString, u32>,
+/// }
+///
+/// let search = FileSearch {
+///     path: vec![
+///         EscapedString::from_static("docs"),
+///         EscapedString::escape("my notes"),
+///     ],
+///     filters: BTreeMap::from([
+///         (EscapedString::from_static("size"), 10),
+///         (EscapedString::from_static("year"), 2024),
+///     ]),
+/// };
+/// assert_eq!(search.render(), "/files/docs/my%20notes?size=10&year=2024");
+/// ```
+pub trait Explode {
+    /// The type of the keys of an associative array.
+    ///
+    /// Lists have no keys and use their item type here.
+    type Key: ?Sized;
+
+    /// The type of the values.
+    type Value: ?Sized;
+
+    /// Returns the items to expand, in order: values without keys for lists, and values with
+    /// their keys for associative arrays.
+    fn items(&self) -> impl Iterator<Item = (Option<&Self::Key>, &Self::Value)>;
+}
+
+impl<T: Explode + ?Sized> Explode for &T {
+    type Key = T::Key;
+    type Value = T::Value;
+
+    fn items(&self) -> impl Iterator<Item = (Option<&Self::Key>, &Self::Value)> {
+        (**self).items()
+    }
+}
+
+impl<T> Explode for [T] {
+    type Key = T;
+    type Value = T;
+
+    fn items(&self) -> impl Iterator<Item = (Option<&Self::Key>, &Self::Value)> {
+        self.iter().map(|value| (None, value))
+    }
+}
+
+impl<T, const N: usize> Explode for [T; N] {
+    type Key = T;
+    type Value = T;
+
+    fn items(&self) -> impl Iterator<Item = (Option<&Self::Key>, &Self::Value)> {
+        self.as_slice().items()
+    }
+}
+
+impl<T> Explode for Vec<T> {
+    type Key = T;
+    type Value = T;
+
+    fn items(&self) -> impl Iterator<Item = (Option<&Self::Key>, &Self::Value)> {
+        self.as_slice().items()
+    }
+}
+
+impl<K, V> Explode for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn items(&self) -> impl Iterator<Item = (Option<&Self::Key>, &Self::Value)> {
+        self.iter().map(|(key, value)| (Some(key), value))
+    }
+}
+
+impl<K, V, S: BuildHasher> Explode for HashMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+
+    fn items(&self) -> impl Iterator<Item = (Option<&Self::Key>, &Self::Value)> {
+        self.iter().map(|(key, value)| (Some(key), value))
+    }
+}
+
+/// How the items of a list or associative array variable are delimited, as determined by the
+/// operator of its expression and the explode modifier. Generated by the `#[templated]` macro.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct ExplodeSpec {
+    /// The name of the variable, used as the key of list items in named expansions.
+    pub name: &'static str,
+    /// Written before the first defined value of the expression.
+    pub prefix: &'static str,
+    /// Written between the defined values of the expression.
+    pub separator: &'static str,
+    /// Whether values are written as `key=value` pairs, as in `{?list*}` and `{;list*}`.
+    pub named: bool,
+    /// Whether each item is expanded as a separate value, as in `{/list*}`, rather than all
+    /// items being joined with commas into a single value, as in `{/list}`.
+    pub exploded: bool,
+}
+
+/// A piece of an exploded expansion, written by the callback of [`explode`].
+enum Piece<'a, K: ?Sized, V: ?Sized> {
+    Literal(&'static str),
+    Key(&'a K),
+    Value(&'a V),
+}
+
+/// Walks the items of `value`, passing each piece of the expansion to `write`.
+///
+/// A non-exploded value is written once, with its items - and the keys of an associative
+/// array - separated by commas, so that `{?keys}` expands to `?keys=semi,%3B,dot,.`.
+///
+/// `first` tracks whether a value of the expression has been written yet, shared with the
+/// other variables of the expression so that only the first defined value gets the prefix.
+fn explode<T, E>(
+    value: &T,
+    spec: &ExplodeSpec,
+    first: &mut bool,
+    mut write: impl FnMut(Piece<'_, T::Key, T::Value>) -> Result<(), E>,
+) -> Result<(), E>
+where
+    T: Explode + ?Sized,
+{
+    if !spec.exploded {
+        return join(value, spec, first, write);
+    }
+
+    for (key, value) in value.items() {
+        write(Piece::Literal(if *first { spec.prefix } else { spec.separator }))?;
+        *first = false;
+
+        match key {
+            Some(key) => {
+                write(Piece::Key(key))?;
+                write(Piece::Literal("="))?;
+            }
+            None if spec.named => {
+                write(Piece::Literal(spec.name))?;
+                write(Piece::Literal("="))?;
+            }
+            None => {}
+        }
+
+        write(Piece::Value(value))?;
+    }
+
+    Ok(())
+}
+
+/// Walks the items of non-exploded `value` for [`explode`], joining them with commas.
+fn join<T, E>(
+    value: &T,
+    spec: &ExplodeSpec,
+    first: &mut bool,
+    mut write: impl FnMut(Piece<'_, T::Key, T::Value>) -> Result<(), E>,
+) -> Result<(), E>
+where
+    T: Explode + ?Sized,
+{
+    let mut items = value.items().peekable();
+    if items.peek().is_none() {
+        return Ok(());
+    }
+
+    write(Piece::Literal(if *first { spec.prefix } else { spec.separator }))?;
+    *first = false;
+
+    if spec.named {
+        write(Piece::Literal(spec.name))?;
+        write(Piece::Literal("="))?;
+    }
+
+    for (index, (key, value)) in items.enumerate() {
+        if index > 0 {
+            write(Piece::Literal(","))?;
+        }
+        if let Some(key) = key {
+            write(Piece::Key(key))?;
+            write(Piece::Literal(","))?;
+        }
+        write(Piece::Value(value))?;
+    }
+
+    Ok(())
+}
+
+/// Appends the exploded expansion of `value` to `out`, escaping its keys and values.
+#[doc(hidden)]
+pub fn explode_escaped_into<T>(value: &T, spec: &ExplodeSpec, first: &mut bool, out: &mut String)
+where
+    T: Explode + ?Sized,
+    T::Key: Escape,
+    T::Value: Escape,
+{
+    let result: Result<(), Infallible> = explode(value, spec, first, |piece| {
+        match piece {
+            Piece::Literal(literal) => out.push_str(literal),
+            Piece::Key(key) => key.escape_into(out),
+            Piece::Value(value) => value.escape_into(out),
+        }
+        Ok(())
+    });
+    let Ok(()) = result;
+}
+
+/// Appends the exploded expansion of `value` to `out`, with its keys and values verbatim.
+#[doc(hidden)]
+pub fn explode_raw_into<T>(value: &T, spec: &ExplodeSpec, first: &mut bool, out: &mut String)
+where
+    T: Explode + ?Sized,
+    T::Key: Raw,
+    T::Value: Raw,
+{
+    let result: Result<(), Infallible> = explode(value, spec, first, |piece| {
+        match piece {
+            Piece::Literal(literal) => out.push_str(literal),
+            Piece::Key(key) => key.raw_into(out),
+            Piece::Value(value) => value.raw_into(out),
+        }
+        Ok(())
+    });
+    let Ok(()) = result;
+}
+
+/// Writes the exploded expansion of `value` to `out`, escaping its keys and values.
+#[doc(hidden)]
+pub fn explode_escaped_write<T>(value: &T, spec: &ExplodeSpec, first: &mut bool, out: &mut dyn fmt::Write) -> fmt::Result
+where
+    T: Explode + ?Sized,
+    T::Key: Escape,
+    T::Value: Escape,
+{
+    explode(value, spec, first, |piece| match piece {
+        Piece::Literal(literal) => out.write_str(literal),
+        Piece::Key(key) => write!(out, "{}", key.escape()),
+        Piece::Value(value) => write!(out, "{}", value.escape()),
+    })
+}
+
+/// Writes the exploded expansion of `value` to `out`, with its keys and values verbatim.
+#[doc(hidden)]
+pub fn explode_raw_write<T>(value: &T, spec: &ExplodeSpec, first: &mut bool, out: &mut dyn fmt::Write) -> fmt::Result
+where
+    T: Explode + ?Sized,
+    T::Key: Raw,
+    T::Value: Raw,
+{
+    explode(value, spec, first, |piece| match piece {
+        Piece::Literal(literal) => out.write_str(literal),
+        Piece::Key(key) => write!(out, "{}", key.raw()),
+        Piece::Value(value) => write!(out, "{}", value.raw()),
+    })
+}
+
+/// Displays the exploded expansion of `value` unredacted.
+#[doc(hidden)]
+pub fn explode_display<T>(value: &T, spec: &ExplodeSpec, first: &mut bool, f: &mut Formatter<'_>) -> fmt::Result
+where
+    T: Explode + ?Sized,
+    T::Key: Display,
+    T::Value: Display,
+{
+    explode(value, spec, first, |piece| match piece {
+        Piece::Literal(literal) => f.write_str(literal),
+        Piece::Key(key) => write!(f, "{key}"),
+        Piece::Value(value) => write!(f, "{value}"),
+    })
+}
+
+/// Displays the exploded expansion of `value`, redacting its keys and values.
+#[doc(hidden)]
+pub fn explode_redacted<T>(value: &T, spec: &ExplodeSpec, first: &mut bool, redactor: &dyn Redactor, f: &mut Formatter<'_>) -> fmt::Result
+where
+    T: Explode + ?Sized,
+    T::Key: RedactedDisplay,
+    T::Value: RedactedDisplay,
+{
+    explode(value, spec, first, |piece| match piece {
+        Piece::Literal(literal) => f.write_str(literal),
+        Piece::Key(key) => key.fmt(redactor, f),
+        Piece::Value(value) => value.fmt(redactor, f),
+    })
+}
+
+/// Displays the exploded expansion of `value`, redacting its keys and values as `data_class`.
+#[doc(hidden)]
+pub fn explode_classified<T, C>(
+    value: &T,
+    spec: &ExplodeSpec,
+    first: &mut bool,
+    data_class: &C,
+    redactor: &dyn Redactor,
+    f: &mut Formatter<'_>,
+) -> fmt::Result
+where
+    T: Explode + ?Sized,
+    T::Key: Display,
+    T::Value: Display,
+    C: AsRef<DataClass> + ?Sized,
+{
+    explode(value, spec, first, |piece| match piece {
+        Piece::Literal(literal) => f.write_str(literal),
+        Piece::Key(key) => display_classified(key, data_class, redactor, f),
+        Piece::Value(value) => display_classified(value, data_class, redactor, f),
+    })
+}
+
+/// Returns the first `max_chars` characters of a rendered value, as the prefix modifier
+/// (`{var:3}`) prescribes.
+///
+/// Characters are counted before percent-encoding: a `%XX` escape sequence is part of
+/// the same character as the escape sequences encoding the rest of its UTF-8 sequence, so
+/// the value is never cut inside an escaped character.
+pub(crate) fn prefix_of(rendered: &str, max_chars: usize) -> &str {
+    let bytes = rendered.as_bytes();
+    let mut chars = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let (byte, len) = match bytes.get(index..index + 3) {
+            Some([b'%', high, low]) if high.is_ascii_hexdigit() && low.is_ascii_hexdigit() => {
+                let byte = u8::from_str_radix(&rendered[index + 1..index + 3], 16).expect("two hex digits always form a byte");
+                (byte, 3)
+            }
+            _ => {
+                let len = rendered[index..].chars().next().map_or(1, char::len_utf8);
+                (bytes[index], len)
+            }
+        };
+
+        // A UTF-8 continuation byte belongs to the character started before it.
+        let starts_char = byte & 0b1100_0000 != 0b1000_0000;
+        if starts_char {
+            if chars == max_chars {
+                break;
+            }
+            chars += 1;
+        }
+
+        index += len;
+    }
+
+    &rendered[..index]
+}
+
+/// Appends at most the first `max_chars` characters of the escaped `value` to `out`.
+#[doc(hidden)]
+pub fn escape_prefix_into<T: Escape + ?Sized>(value: &T, max_chars: usize, out: &mut String) {
+    let mut rendered = String::new();
+    value.escape_into(&mut rendered);
+    out.push_str(prefix_of(&rendered, max_chars));
+}
+
+/// Appends at most the first `max_chars` characters of the raw `value` to `out`.
+#[doc(hidden)]
+pub fn raw_prefix_into<T: Raw + ?Sized>(value: &T, max_chars: usize, out: &mut String) {
+    let mut rendered = String::new();
+    value.raw_into(&mut rendered);
+    out.push_str(prefix_of(&rendered, max_chars));
+}
+
+/// Writes at most the first `max_chars` characters of the escaped `value` to `out`.
+#[doc(hidden)]
+pub fn escape_prefix_write<T: Escape + ?Sized>(value: &T, max_chars: usize, out: &mut dyn fmt::Write) -> fmt::Result {
+    let mut rendered = String::new();
+    value.escape_into(&mut rendered);
+    out.write_str(prefix_of(&rendered, max_chars))
+}
+
+/// Writes at most the first `max_chars` characters of the raw `value` to `out`.
+#[doc(hidden)]
+pub fn raw_prefix_write<T: Raw + ?Sized>(value: &T, max_chars: usize, out: &mut dyn fmt::Write) -> fmt::Result {
+    let mut rendered = String::new();
+    value.raw_into(&mut rendered);
+    out.write_str(prefix_of(&rendered, max_chars))
+}
+
+/// Displays at most the first `max_chars` characters of the unredacted `value`.
+#[doc(hidden)]
+pub fn display_prefix<T: Display + ?Sized>(value: &T, max_chars: usize, f: &mut Formatter<'_>) -> fmt::Result {
+    let mut rendered = String::new();
+    write!(rendered, "{value}")?;
+    f.write_str(prefix_of(&rendered, max_chars))
+}
+
+/// Displays `value` redacted as `data_class`, regardless of the classification of its type.
+#[doc(hidden)]
+pub fn display_classified<T, C>(value: &T, data_class: &C, redactor: &dyn Redactor, f: &mut Formatter<'_>) -> fmt::Result
+where
+    T: Display + ?Sized,
+    C: AsRef<DataClass> + ?Sized,
+{
+    let mut rendered = String::new();
+    write!(rendered, "{value}")?;
+    redactor.redact(data_class.as_ref(), &rendered, f)
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EscapedString;
+
+    const LIST: ExplodeSpec = ExplodeSpec {
+        name: "list",
+        prefix: "/",
+        separator: "/",
+        named: false,
+        exploded: true,
+    };
+
+    const QUERY: ExplodeSpec = ExplodeSpec {
+        name: "list",
+        prefix: "?",
+        separator: "&",
+        named: true,
+        exploded: true,
+    };
+
+    fn escaped(value: &str) -> EscapedString {
+        EscapedString::escape(value.to_string())
+    }
+
+    #[test]
+    fn prefix_counts_characters_before_encoding() {
+        assert_eq!(prefix_of("value", 3), "val");
+        assert_eq!(prefix_of("value", 5), "value");
+        assert_eq!(prefix_of("value", 10), "value");
+        assert_eq!(prefix_of("a%20b", 2), "a%20");
+        assert_eq!(prefix_of("%C3%A9t%C3%A9", 1), "%C3%A9");
+        assert_eq!(prefix_of("été", 2), "ét");
+        assert_eq!(prefix_of("100%", 4), "100%");
+        assert_eq!(prefix_of("%zz", 2), "%z");
+        assert_eq!(prefix_of("%é", 1), "%");
+    }
+
+    #[test]
+    fn prefix_helpers_truncate() {
+        let mut out = String::new();
+        escape_prefix_into(&escaped("hello world"), 6, &mut out);
+        raw_prefix_into(&"a/b/c".to_string(), 3, &mut out);
+        assert_eq!(out, "hello%20a/b");
+
+        let display = fmt::from_fn(|f| display_prefix(&12345, 2, f)).to_string();
+        assert_eq!(display, "12");
+    }
+
+    #[test]
+    fn lists_explode_into_values() {
+        let mut out = String::new();
+        let mut first = true;
+        explode_escaped_into(&vec![escaped("red"), escaped("a b")], &LIST, &mut first, &mut out);
+        assert_eq!(out, "/red/a%20b");
+        assert!(!first);
+
+        let mut out = String::new();
+        let mut first = true;
+        explode_escaped_into(&[1_u32, 2], &QUERY, &mut first, &mut out);
+        assert_eq!(out, "?list=1&list=2");
+    }
+
+    #[test]
+    fn maps_explode_into_pairs() {
+        let map = BTreeMap::from([(escaped("dot"), escaped(".")), (escaped("semi"), escaped(";"))]);
+
+        let mut out = String::from("/items");
+        let mut first = false;
+        explode_escaped_into(&map, &QUERY, &mut first, &mut out);
+        assert_eq!(out, "/items&dot=.&semi=%3B");
+
+        let mut out = String::new();
+        let mut first = true;
+        let map = HashMap::from([("path".to_string(), "a/b".to_string())]);
+        explode_raw_into(&map, &LIST, &mut first, &mut out);
+        assert_eq!(out, "/path=a/b");
+    }
+
+    #[test]
+    fn non_exploded_composites_join_with_commas() {
+        let spec = ExplodeSpec { exploded: false, ..QUERY };
+
+        let mut out = String::new();
+        let mut first = true;
+        explode_escaped_into(&vec![escaped("red"), escaped("a b")], &spec, &mut first, &mut out);
+        explode_escaped_into(&Vec::<u32>::new(), &spec, &mut first, &mut out);
+        explode_escaped_into(&[1_u32, 2], &spec, &mut first, &mut out);
+        assert_eq!(out, "?list=red,a%20b&list=1,2");
+
+        let map = BTreeMap::from([(escaped("dot"), escaped(".")), (escaped("semi"), escaped(";"))]);
+        let mut out = String::new();
+        explode_escaped_into(&map, &ExplodeSpec { exploded: false, ..LIST }, &mut true, &mut out);
+        assert_eq!(out, "/dot,.,semi,%3B");
+
+        let display = fmt::from_fn(|f| explode_display(&["a/b", "c"], &ExplodeSpec { exploded: false, ..LIST }, &mut true, f)).to_string();
+        assert_eq!(display, "/a/b,c");
+    }
+
+    #[test]
+    fn empty_composites_are_undefined() {
+        let mut out = String::new();
+        let mut first = true;
+        explode_escaped_into(&Vec::<u32>::new(), &QUERY, &mut first, &mut out);
+        explode_escaped_into(&BTreeMap::<u32, u32>::new(), &QUERY, &mut first, &mut out);
+        assert_eq!(out, "");
+        assert!(first);
+    }
+
+    #[test]
+    fn explode_display_writes_values() {
+        let display = fmt::from_fn(|f| explode_display(&[1_u32, 2, 3], &QUERY, &mut true, f)).to_string();
+        assert_eq!(display, "?list=1&list=2&list=3");
+    }
+
+    #[test]
+    fn classified_values_are_redacted_as_their_data_class() {
+        use data_privacy::RedactionEngine;
+        use data_privacy::simple_redactor::{SimpleRedactor, SimpleRedactorMode};
+
+        const SECRET: DataClass = DataClass::new("test", "secret");
+
+        let engine = RedactionEngine::builder()
+            .add_class_redactor(SECRET, SimpleRedactor::with_mode(SimpleRedactorMode::Replace('x')))
+            .build();
+        let redactor: &dyn Redactor = &engine;
+
+        let display = fmt::from_fn(|f| display_classified(&12345, &SECRET, redactor, f)).to_string();
+        assert_eq!(display, "xxxxx");
+
+        let display = fmt::from_fn(|f| explode_classified(&[12_u32, 345], &QUERY, &mut true, &SECRET, redactor, f)).to_string();
+        assert_eq!(display, "?list=xx&list=xxx");
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Converts internationalized resource identifiers (IRIs) into a [`BaseUri`].
+
+use std::borrow::Cow;
+
+use idna::AsciiDenyList;
+
+use crate::{BaseUri, UriError};
+
+impl BaseUri {
+    /// Creates a [`BaseUri`] from an IRI, a URI that may contain non-ASCII characters, such as
+    /// `https://bücher.example/café/`.
+    ///
+    /// The host is converted to its ASCII form with IDNA (Unicode normalization and Punycode),
+    /// and the non-ASCII characters of the user information and path are percent-encoded as
+    /// UTF-8. The result is then validated as [`BaseUri::from_str`](std::str::FromStr::from_str)
+    /// does, so a pure ASCII IRI gives the same [`BaseUri`] as parsing it as a URI. As with
+    /// parsing, any query string or fragment is discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UriError`] if the IRI does not contain both a scheme and an authority, if
+    /// its host is not a valid internationalized domain name, or if the converted URI is not a
+    /// valid [`BaseUri`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use templated_uri::BaseUri;
+    ///
+    /// let base_uri = BaseUri::from_iri("https://Bücher.example:8443/café/")?;
+    /// assert_eq!(
+    ///     base_uri.to_string(),
+    ///     "https://xn--bcher-kva.example:8443/caf%C3%A9/"
+    /// );
+    ///
+    /// assert!(BaseUri::from_iri("/café/").is_err());
+    /// # Ok::<(), templated_uri::UriError>(())
+    /// ```
+    pub fn from_iri(iri: &str) -> Result<Self, UriError> {
+        let Some((scheme, rest)) = iri.split_once("://") else {
+            return Err(UriError::invalid_uri("IRI must have both scheme and authority components"));
+        };
+        let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+        let (authority, path) = rest.split_at(authority_end);
+        let path = &path[..path.find(['?', '#']).unwrap_or(path.len())];
+
+        let (userinfo, host_and_port) = match authority.rsplit_once('@') {
+            Some((userinfo, host_and_port)) => (Some(userinfo), host_and_port),
+            None => (None, authority),
+        };
+        // IP literals are ASCII, and their colons are not a port separator.
+        let (host, port) = match host_and_port.rsplit_once(':') {
+            Some((host, port)) if !host_and_port.ends_with(']') => (host, Some(port)),
+            _ => (host_and_port, None),
+        };
+
+        let mut uri = String::with_capacity(iri.len() + 16);
+        uri.push_str(scheme);
+        uri.push_str("://");
+        if let Some(userinfo) = userinfo {
+            uri.push_str(&encode_non_ascii(userinfo));
+            uri.push('@');
+        }
+        uri.push_str(&to_ascii_host(host)?);
+        if let Some(port) = port {
+            uri.push(':');
+            uri.push_str(port);
+        }
+        uri.push_str(&encode_non_ascii(path));
+
+        uri.parse()
+    }
+}
+
+/// Converts an internationalized host to its ASCII form, leaving IP literals as they are.
+fn to_ascii_host(host: &str) -> Result<Cow<'_, str>, UriError> {
+    if host.starts_with('[') {
+        return Ok(Cow::Borrowed(host));
+    }
+    // The IDNA errors carry no details beyond the failure itself.
+    idna::domain_to_ascii_cow(host.as_bytes(), AsciiDenyList::URL)
+        .map_err(|_errors| UriError::invalid_uri("the host of the IRI is not a valid internationalized domain name"))
+}
+
+/// Percent-encodes the UTF-8 bytes of the non-ASCII characters of `s`.
+fn encode_non_ascii(s: &str) -> Cow<'_, str> {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+
+    let mut out = String::with_capacity(s.len() * 2);
+    for c in s.chars() {
+        if c.is_ascii() {
+            out.push(c);
+        } else {
+            let mut buf = [0; 4];
+            for b in c.encode_utf8(&mut buf).bytes() {
+                out.push('%');
+                out.push(HEX[(b >> 4) as usize] as char);
+                out.push(HEX[(b & 0x0f) as usize] as char);
+            }
+        }
+    }
+    Cow::Owned(out)
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use ohno::ErrorExt;
+
+    use super::*;
+
+    #[test]
+    fn converts_host_and_path() {
+        let base_uri = BaseUri::from_iri("https://münchen.example/straße/").unwrap();
+        assert_eq!(base_uri.host(), "xn--mnchen-3ya.example");
+        assert_eq!(base_uri.path().to_string(), "/stra%C3%9Fe/");
+        assert_eq!(base_uri.port(), None);
+    }
+
+    #[test]
+    fn keeps_ascii_uris_unchanged() {
+        for uri in ["https://example.com/", "http://example.com:8080/api/", "http://[::1]:8080/"] {
+            assert_eq!(BaseUri::from_iri(uri).unwrap(), uri.parse::<BaseUri>().unwrap(), "{uri}");
+        }
+    }
+
+    #[test]
+    fn keeps_port_and_userinfo() {
+        let base_uri = BaseUri::from_iri("https://jürgen@例え.テスト:8443").unwrap();
+        assert_eq!(base_uri.authority().as_str(), "j%C3%BCrgen@xn--r8jz45g.xn--zckzah:8443");
+        assert_eq!(base_uri.port(), Some(8443));
+    }
+
+    #[test]
+    fn discards_query_and_fragment() {
+        let base_uri = BaseUri::from_iri("https://bücher.example/über/?q=é#frag").unwrap();
+        assert_eq!(base_uri.to_string(), "https://xn--bcher-kva.example/%C3%BCber/");
+    }
+
+    #[test]
+    fn rejects_invalid_iris() {
+        let err = BaseUri::from_iri("bücher.example/").unwrap_err();
+        assert_eq!(err.message(), "IRI must have both scheme and authority components");
+
+        let err = BaseUri::from_iri("https://bü<cher.example/").unwrap_err();
+        assert_eq!(err.message(), "the host of the IRI is not a valid internationalized domain name");
+
+        BaseUri::from_iri("https://bücher.example/no-trailing-slash").unwrap_err();
+    }
+}
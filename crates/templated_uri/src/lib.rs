@@ -18,7 +18,11 @@
 //!
 //! - [`Uri`] - Flexible URI type composed of an optional [`BaseUri`] and an optional path/query
 //! - [`BaseUri`] - Lightweight type representing scheme, authority, and optional base path ([`BasePath`])
-//! - [`PathAndQueryTemplate`] - RFC 6570 Level 3 compliant URI templating
+//! - [`PathAndQueryTemplate`] - RFC 6570 Level 4 compliant URI templating
+//! - [`Template`] - The same templating for templates only known at runtime, such as templates from configuration,
+//!   which can also match paths to extract their variables
+//! - [`QueryParams`] - Query parameters only known at runtime, appended to a [`PathAndQuery`] or [`Uri`]
+//! - [`TemplateRegistry`] - The telemetry labels of known templates, to validate that requests keep metric cardinality bounded
 //! - [`Escaped`] and [`EscapedString`] - Generic newtype wrapper proving a value is properly escaped for URI components
 //!   by not containing any reserved characters
 //!
@@ -66,6 +70,37 @@
 //!     .with_path_and_query(path);
 //! ```
 //!
+//! ## Generic Templates
+//!
+//! Templated structs can have type and const parameters, so that a path type can be shared
+//! across services that identify resources differently. The struct declares the bounds its
+//! fields need, such as [`Escape`] and `Display` for the unredacted `{id}` below, along with
+//! `Send + Sync + 'static` as required by [`PathAndQueryTemplate`]. Lifetime parameters are
+//! not supported.
+//!
+//! ```rust
+//! use std::fmt::Display;
+//!
+//! use templated_uri::{Escape, EscapedString, PathAndQueryTemplate, templated};
+//!
+//! #[templated(template = "/items/{id}", unredacted)]
+//! struct ItemPath<T>
+//! where
+//!     T: Escape + Display + Send + Sync + 'static,
+//! {
+//!     id: T,
+//! }
+//!
+//! assert_eq!(ItemPath { id: 42_u32 }.render(), "/items/42");
+//! assert_eq!(
+//!     ItemPath {
+//!         id: EscapedString::escape("a b")
+//!     }
+//!     .render(),
+//!     "/items/a%20b"
+//! );
+//! ```
+//!
 //! # URI Escaping Guarantees
 //!
 //! The [`Escaped<T>`](Escaped) newtype wraps values that are guaranteed
@@ -87,8 +122,10 @@
 //! assert!(invalid.is_err());
 //! ```
 //!
-//! Built-in valid types include numeric types (`u32`, `u64`, etc.), `Uuid` (with the `uuid` feature),
-//! IP addresses, and validated [`EscapedString`] instances.
+//! Built-in valid types include integer types (`u32`, `i64`, etc.), `bool`, `Uuid` (with the `uuid`
+//! feature), IP addresses, and validated [`EscapedString`] instances. With the `chrono` and `time`
+//! features, the UTC timestamps `chrono::DateTime<Utc>` and `time::UtcDateTime` are written as RFC 3339
+//! timestamps with escaped colons, such as `2024-05-01T12%3A30%3A00Z`.
 //!
 //! # Telemetry Labels
 //!
@@ -110,6 +147,10 @@
 //! }
 //! ```
 //!
+//! To keep the cardinality of per-route metrics bounded, a [`TemplateRegistry`] collects the
+//! labels of the templated types of an application at startup, and validates that requests
+//! use one of them.
+//!
 //! # Data Classification
 //!
 //! The crate integrates with `data_privacy` to track data sensitivity levels
@@ -131,19 +172,24 @@
 //! # RFC 6570 Template Compliance
 //!
 //! The templating system implements [RFC 6570](https://datatracker.ietf.org/doc/html/rfc6570)
-//! Level 3 URI Template specification. Supported expansions include:
+//! Level 4 URI Template specification. Supported expansions include:
 //!
 //! - Simple string expansion: `{var}`
 //! - Reserved string expansion: `{+var}`
 //! - Path segments: `{/var}`
 //! - Query parameters: `{?var}`
-//! - Query continuation: `{&var}`
+//! - Query continuation: `{&var}`, which starts the query with `?` if there is none to continue
+//! - Prefix modifiers, expanding the first characters of a value: `{var:3}`
+//! - Explode modifiers, expanding each item of a list or associative array: `{/list*}`, `{?keys*}`
 //!
 //! Note: Fragment expansion (`{#var}`) from RFC 6570 is **not supported** because URI
 //! fragments are stripped by the `http` crate and ignored by HTTP clients.
 //!
 //! Template variables must implement [`Escape`] (except for reserved expansions,
-//! which use [`Raw`]) to ensure the resulting URI is valid.
+//! which use [`Raw`]) to ensure the resulting URI is valid. Exploded variables must
+//! implement [`Explode`], with keys and values that implement [`Escape`] or [`Raw`].
+//! Variables backed by a `Vec<T>` field are lists even without the explode modifier, and
+//! expand to their items separated by commas: `{?ids}` expands to `?ids=1,2,3`.
 //!
 //! ## Undefined Values (`Option<T>`)
 //!
@@ -176,12 +222,50 @@
 //! assert_eq!(path.render(), "/items?query=rust");
 //! ```
 //!
+//! ## Runtime Templates
+//!
+//! Templates that are only known at runtime, such as templates read from configuration or from an
+//! `OpenAPI` document, are parsed into a [`Template`]. A parsed template can be expanded any number
+//! of times with a map of [`UriSafe`] values, or with the fields of a serializable struct when the
+//! `serde` feature is enabled.
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//!
+//! use templated_uri::{Template, UriSafe};
+//!
+//! let template = Template::parse("/items/{id}{?limit}")?;
+//!
+//! let values: HashMap<&str, &dyn UriSafe> = HashMap::from([("id", &42_u32 as &dyn UriSafe)]);
+//! let path = template.expand(&values)?;
+//! assert_eq!(path.to_string().declassify_ref(), "/items/42");
+//!
+//! // Servers can match incoming paths against the same template to extract the values.
+//! let captures = template.match_path("/items/42?limit=10").unwrap();
+//! assert_eq!(captures.get("id"), Some("42"));
+//! assert_eq!(captures.get("limit"), Some("10"));
+//! # Ok::<(), templated_uri::UriError>(())
+//! ```
+//!
+//! `OpenAPI` path templates such as `/users/{id}` are valid templates as they are, both for
+//! [`Template::parse`] and for the `openapi` option of [`#[templated]`](macro@templated), and
+//! [`Template::to_openapi`] converts a template back into one.
+//!
 //! # Integration with HTTP Ecosystem
 //!
 //! This crate seamlessly integrates with the broader Rust HTTP ecosystem by re-exporting
 //! and building upon the standard [`http`](https://docs.rs/http/latest/http/) crate types. The resulting [`Uri`] can be converted
 //! to an [`http::Uri`] for use with HTTP clients
 //! and servers based on [`hyper`](https://docs.rs/hyper/latest/hyper/) like [`reqwest`](https://docs.rs/reqwest/latest/reqwest/).
+//!
+//! [`UriRequestBuilderExt::templated_uri`] sets the URI of an [`http::request::Builder`] from a
+//! [`Uri`], and attaches the [`Uri`] and its [`PathAndQuery`] to the request as extensions, so
+//! the template label stays available for metrics. [`UriRequestExt::uri_classified`] reads the
+//! URI of the request back as a [`Uri`], keeping its values classified.
+//!
+//! With the `idna` feature, `BaseUri::from_iri` accepts base URIs with non-ASCII characters, such
+//! as tenant domains with internationalized host names, converting the host with IDNA and
+//! percent-encoding the path.
 
 #[doc(hidden)]
 pub mod __private;
@@ -192,10 +276,20 @@ mod base_uri;
 mod error;
 mod escape;
 mod escaped;
+mod expansion;
+#[cfg(feature = "idna")]
+mod iri;
 mod macros;
 mod origin;
 mod path_and_query;
 mod path_and_query_template;
+mod query_params;
+mod reference;
+mod request_ext;
+mod template;
+mod template_registry;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod timestamp;
 mod uri;
 
 pub use base_path::BasePath;
@@ -203,9 +297,14 @@ pub use base_uri::BaseUri;
 pub use error::UriError;
 pub use escape::{Escape, Raw};
 pub use escaped::{EscapeError, Escaped, EscapedString};
+pub use expansion::Explode;
 pub use http::uri::{Authority, Scheme};
-pub use macros::{Escape, Raw, templated};
+pub use macros::{Escape, Raw, templated, templated_catalog};
 pub use origin::Origin;
 pub use path_and_query::PathAndQuery;
 pub use path_and_query_template::PathAndQueryTemplate;
+pub use query_params::QueryParams;
+pub use request_ext::{UriRequestBuilderExt, UriRequestExt};
+pub use template::{Captures, Template, UriSafe};
+pub use template_registry::TemplateRegistry;
 pub use uri::Uri;
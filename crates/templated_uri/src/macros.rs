@@ -50,6 +50,7 @@ pub use templated_uri_macros::Raw;
 /// - `Debug`: Custom debug representation showing the template
 /// - `RedactedDisplay`: Data privacy-aware display with selective field redaction
 /// - `From<T> for PathAndQuery`: Conversion to a URI path
+/// - `Serialize` and `Deserialize` with the `serde` option: Round-trips through the rendered URI path
 ///
 /// # Struct Usage
 ///
@@ -71,6 +72,25 @@ pub use templated_uri_macros::Raw;
 /// - `{/param1,param2}`: Path segment expansion (`/value1/value2`)
 /// - `{?param1,param2}`: Query parameter expansion (`?param1=value1&param2=value2`)
 ///
+/// ## `OpenAPI` Paths
+///
+/// Paths copied from an `OpenAPI` document can be used with the `openapi` option instead of
+/// `template`. An `OpenAPI` path is an RFC 6570 template whose expressions are all `{name}`, so it
+/// has the same meaning with either option; `openapi` additionally rejects operators, modifiers
+/// and queries at compile time. Query parameters can be added at runtime with
+/// [`QueryParams`](crate::QueryParams).
+///
+/// ```
+/// # use templated_uri::templated;
+/// #[templated(openapi = "/users/{user_id}/posts/{post_id}", unredacted)]
+/// struct GetPost {
+///     user_id: u32,
+///     post_id: u32,
+/// }
+/// ```
+///
+/// [`Template::to_openapi()`](crate::Template::to_openapi) converts the other way.
+///
 /// ## Undefined Values (`Option<T>`)
 ///
 /// Fields may be wrapped in `Option<T>` to represent RFC 6570 *undefined* variables.
@@ -92,6 +112,25 @@ pub use templated_uri_macros::Raw;
 /// }
 /// ```
 ///
+/// A query continuation (`{&var}`) continues the query with `&` once a literal `?` or a
+/// defined `{?var}` has started it. Otherwise, unlike in RFC 6570, it starts the query with
+/// `?`, so an undefined value before it does not leave a dangling `&`:
+///
+/// ```rust
+/// # use templated_uri::{templated, EscapedString, PathAndQueryTemplate};
+/// #[templated(template = "/items{?query}{&page}", unredacted)]
+/// struct Items {
+///     query: Option<EscapedString>,
+///     page: u32,
+/// }
+///
+/// let items = Items {
+///     query: None,
+///     page: 2,
+/// };
+/// assert_eq!(items.render(), "/items?page=2");
+/// ```
+///
 /// ## Data Privacy
 ///
 /// By default, all fields use `RedactedDisplay` for privacy protection. Use attributes to control:
@@ -108,9 +147,94 @@ pub use templated_uri_macros::Raw;
 /// }
 /// ```
 ///
+/// Fields of types that aren't classified, such as [`EscapedString`] or numbers, can still be
+/// redacted with `#[templated(classify = ...)]`, which names the data class of the field, for
+/// example a taxonomy variant. The redacted output then uses the redactor registered for that
+/// class, which takes precedence over a struct-level `unredacted`. Such fields must implement
+/// `Display`, and are redacted as a whole even when a prefix modifier, such as `{id:3}`,
+/// truncates them.
+///
+/// ```rust
+/// use data_privacy::simple_redactor::{SimpleRedactor, SimpleRedactorMode};
+/// use data_privacy::{RedactedToString, RedactionEngine, taxonomy};
+/// use templated_uri::{EscapedString, PathAndQueryTemplate, templated};
+///
+/// #[taxonomy(example)]
+/// enum ExampleTaxonomy {
+///     PersonalData,
+/// }
+///
+/// #[templated(template = "/{org}/users/{user_id}", unredacted)]
+/// struct UserPath {
+///     org: EscapedString,
+///     #[templated(classify = ExampleTaxonomy::PersonalData)]
+///     user_id: u32,
+/// }
+///
+/// let path = UserPath {
+///     org: EscapedString::from_static("contoso"),
+///     user_id: 42,
+/// };
+/// let redaction_engine = RedactionEngine::builder()
+///     .set_fallback_redactor(SimpleRedactor::with_mode(SimpleRedactorMode::EraseAndTag))
+///     .build();
+/// assert_eq!(
+///     path.to_redacted_string(&redaction_engine),
+///     "/contoso/users/<example/personal_data:>"
+/// );
+/// ```
+///
+/// ## Serialization
+///
+/// With the `serde` feature enabled, `#[templated(template = "...", serde)]` also implements
+/// `Serialize` and `Deserialize`, so that templated structs can be persisted, for example in
+/// job queues, and replayed. A struct serializes as its rendered path and query, and
+/// deserializes by matching the string against the template, as [`Template::match_path()`]
+/// does, and deserializing each field from the value captured for it.
+///
+/// Field values are deserialized as they were rendered: still percent-encoded, with numbers
+/// and booleans parsed from their text, missing values as `None` and the items of a list
+/// split at commas. Fields must implement `Deserialize` to restore the value they rendered,
+/// which is the case for [`EscapedString`], numbers and `String` in reserved expansions.
+/// Exploded associative arrays, such as `{?keys*}`, cannot be deserialized.
+///
+/// The serialized string contains the unredacted values of all fields, so only persist it
+/// where the data is allowed to be stored. Deserialization errors never include the string
+/// or the values captured from it.
+///
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// use templated_uri::{EscapedString, PathAndQueryTemplate, templated};
+///
+/// #[templated(template = "/users/{user_id}/files{?tags,limit}", unredacted, serde)]
+/// struct UserFiles {
+///     user_id: u32,
+///     tags: Vec<EscapedString>,
+///     limit: Option<u32>,
+/// }
+///
+/// let files = UserFiles {
+///     user_id: 42,
+///     tags: vec![
+///         EscapedString::from_static("red"),
+///         EscapedString::from_static("blue"),
+///     ],
+///     limit: None,
+/// };
+/// let json = serde_json::to_string(&files).unwrap();
+/// assert_eq!(json, r#""/users/42/files?tags=red,blue""#);
+///
+/// let replayed: UserFiles = serde_json::from_str(&json).unwrap();
+/// assert_eq!(replayed.render(), files.render());
+/// # }
+/// ```
+///
+/// [`Template::match_path()`]: crate::Template::match_path
+/// [`EscapedString`]: crate::EscapedString
+///
 /// # Enum Usage
 ///
-/// For enums, each variant must contain exactly one field that implements `PathAndQueryTemplate`.
+/// For enums, tuple variants must contain exactly one field that implements `PathAndQueryTemplate`.
 /// The macro generates delegating implementations that forward to the inner type.
 ///
 /// ```ignore
@@ -124,4 +248,75 @@ pub use templated_uri_macros::Raw;
 ///
 /// The enum will delegate all trait methods to whichever variant is active, and also generates
 /// `From<VariantType>` implementations for easy construction.
+///
+/// Unit and struct variants instead take their own `#[templated(...)]` options, so a whole
+/// API surface can be modeled in one enum. A unit variant maps to a fixed path, and the fields
+/// of a struct variant fill in its template the way the fields of a struct do, with the same
+/// options for templates, labels and redaction, apart from `serde`.
+///
+/// ```rust
+/// use templated_uri::{EscapedString, PathAndQueryTemplate, templated};
+///
+/// #[templated]
+/// enum ServiceApi {
+///     #[templated(template = "/healthz")]
+///     HealthCheck,
+///     #[templated(template = "/users/{user_id}/files{?tag}", unredacted)]
+///     UserFiles {
+///         user_id: u32,
+///         tag: Option<EscapedString>,
+///     },
+/// }
+///
+/// assert_eq!(ServiceApi::HealthCheck.render(), "/healthz");
+/// let files = ServiceApi::UserFiles {
+///     user_id: 42,
+///     tag: Some(EscapedString::from_static("docs")),
+/// };
+/// assert_eq!(files.render(), "/users/42/files?tag=docs");
+/// ```
 pub use templated_uri_macros::templated;
+/// Declares the routes of a service as one enum, generating a templated struct for each.
+///
+/// A service client often sends requests with dozens of templates, each of which needs a
+/// `#[templated]` struct, along with a `#[templated]` enum delegating to them. With
+/// `#[templated_catalog]`, the routes are instead declared as the variants of a single enum,
+/// and each struct variant becomes a struct of the same name. The struct takes the
+/// `#[templated(...)]` options, the other attributes, and the fields of the variant, and its
+/// fields have the visibility of the enum. The variant instead holds the struct, as a tuple
+/// variant of a [`templated`] enum does, so it can be created from the struct with `From`.
+///
+/// Unit variants, with a fixed path, and tuple variants, holding an existing templated struct,
+/// are kept in the enum as they are. The `derive` attributes of the enum also apply to the
+/// generated structs.
+///
+/// ```rust
+/// use templated_uri::{EscapedString, PathAndQueryTemplate, templated_catalog};
+///
+/// #[templated_catalog]
+/// #[derive(Clone)]
+/// enum StorageApi {
+///     #[templated(template = "/healthz")]
+///     HealthCheck,
+///     #[templated(template = "/containers/{container}", unredacted)]
+///     Container { container: EscapedString },
+///     #[templated(
+///         template = "/containers/{container}/blobs/{+blob}{?snapshot}",
+///         unredacted
+///     )]
+///     Blob {
+///         container: EscapedString,
+///         blob: String,
+///         snapshot: Option<u64>,
+///     },
+/// }
+///
+/// let blob = StorageApi::from(Blob {
+///     container: EscapedString::from_static("logs"),
+///     blob: "2024/05/01.txt".to_string(),
+///     snapshot: None,
+/// });
+/// assert_eq!(blob.render(), "/containers/logs/blobs/2024/05/01.txt");
+/// assert_eq!(StorageApi::HealthCheck.render(), "/healthz");
+/// ```
+pub use templated_uri_macros::templated_catalog;
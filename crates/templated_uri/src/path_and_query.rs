@@ -11,7 +11,7 @@ use data_privacy::{Classified, RedactedDebug, RedactedDisplay, RedactedToString,
 use http::uri::PathAndQuery as HttpPathAndQuery;
 
 use crate::error::UriError;
-use crate::{PathAndQueryTemplate, Uri};
+use crate::{PathAndQueryTemplate, QueryParams, Uri};
 
 /// Path and query component of a [`Uri`].
 ///
@@ -20,11 +20,14 @@ use crate::{PathAndQueryTemplate, Uri};
 /// fragment, e.g. `/api/v1/users?active=true`).
 ///
 /// `PathAndQuery` wraps either a static [`http::uri::PathAndQuery`] or a dynamic value
-/// produced by a [`PathAndQueryTemplate`] implementation. Use the `from_*` constructors
-/// or `From` impls to build one; the internal representation is intentionally
-/// not exposed.
+/// produced by a [`PathAndQueryTemplate`] implementation, optionally followed by
+/// [`QueryParams`] only known at runtime. Use the `from_*` constructors or `From` impls to
+/// build one; the internal representation is intentionally not exposed.
 #[derive(Clone)]
-pub struct PathAndQuery(PathAndQueryInner);
+pub struct PathAndQuery {
+    inner: PathAndQueryInner,
+    query_params: Option<Arc<QueryParams>>,
+}
 
 #[derive(Clone)]
 enum PathAndQueryInner {
@@ -35,7 +38,7 @@ enum PathAndQueryInner {
 impl PathAndQuery {
     /// Creates a new `PathAndQuery` from a [`PathAndQueryTemplate`].
     pub fn from_template(template: impl PathAndQueryTemplate) -> Self {
-        Self(PathAndQueryInner::Templated(Arc::new(template)))
+        Self::from_inner(PathAndQueryInner::Templated(Arc::new(template)))
     }
 
     /// Creates a new `PathAndQuery` from a static path and query string.
@@ -44,6 +47,47 @@ impl PathAndQuery {
         Self::from(HttpPathAndQuery::from_static(path))
     }
 
+    const fn from_inner(inner: PathAndQueryInner) -> Self {
+        Self { inner, query_params: None }
+    }
+
+    /// Appends the query parameters `params` after the existing query, and after any
+    /// parameters appended before.
+    ///
+    /// The parameters are part of the rendered path and query and of its [`RedactedDisplay`]
+    /// output, but not of its [`template`](Self::template) or [`label`](Self::label), so they
+    /// don't affect telemetry grouping.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use templated_uri::{PathAndQuery, QueryParams};
+    ///
+    /// let path = PathAndQuery::from_static("/items")
+    ///     .with_query_params(QueryParams::new().append("page", 2_u32));
+    /// assert_eq!(path.to_string().declassify_ref(), "/items?page=2");
+    /// assert_eq!(path.template(), "/items");
+    /// ```
+    #[must_use]
+    pub fn with_query_params(mut self, params: QueryParams) -> Self {
+        if params.is_empty() {
+            return self;
+        }
+        self.query_params = Some(match self.query_params.take() {
+            Some(existing) => Arc::new(Arc::unwrap_or_clone(existing).extend(params)),
+            None => Arc::new(params),
+        });
+        self
+    }
+
+    /// Returns whether the path and query, without the appended query parameters, has a query.
+    fn has_query(&self) -> bool {
+        match &self.inner {
+            PathAndQueryInner::Static(classified_pq) => classified_pq.declassify_ref().query().is_some(),
+            PathAndQueryInner::Templated(templated) => templated.render().contains('?'),
+        }
+    }
+
     /// Appends this path-and-query's rendered text to `buf`.
     ///
     /// For a static value this is a single `push_str`; for a templated value it renders
@@ -51,25 +95,32 @@ impl PathAndQuery {
     /// [`to_string`](Self::to_string) would allocate. Used by base-URI joining on the
     /// request hot path.
     pub(crate) fn render_into(&self, buf: &mut String) {
-        match &self.0 {
+        let start = buf.len();
+        match &self.inner {
             PathAndQueryInner::Static(classified_pq) => buf.push_str(classified_pq.declassify_ref().as_str()),
             PathAndQueryInner::Templated(templated) => templated.render_into(buf),
         }
+        if let Some(params) = &self.query_params {
+            let has_query = buf[start..].contains('?');
+            params.render_into(has_query, buf);
+        }
     }
 
     /// Returns a heuristic byte-capacity estimate for [`render_into`](Self::render_into),
     /// so a caller can size its buffer to avoid reallocating mid-render.
     pub(crate) fn render_capacity_hint(&self) -> usize {
-        match &self.0 {
-            PathAndQueryInner::Static(classified_pq) => classified_pq.declassify_ref().as_str().len(),
-            PathAndQueryInner::Templated(templated) => templated.render_capacity_hint(),
-        }
+        let params_len = self.query_params.as_ref().map_or(0, |params| params.rendered_len());
+        params_len
+            + match &self.inner {
+                PathAndQueryInner::Static(classified_pq) => classified_pq.declassify_ref().as_str().len(),
+                PathAndQueryInner::Templated(templated) => templated.render_capacity_hint(),
+            }
     }
 
     /// Returns the template string for this path and query.
     #[must_use]
     pub fn template(&self) -> Cow<'static, str> {
-        match &self.0 {
+        match &self.inner {
             PathAndQueryInner::Static(classified_pq) => Cow::Owned(classified_pq.declassify_ref().to_string()),
             PathAndQueryInner::Templated(templated) => Cow::Borrowed(templated.template()),
         }
@@ -80,7 +131,7 @@ impl PathAndQuery {
     /// For non-templated paths, this returns `None`.
     #[must_use]
     pub fn label(&self) -> Option<Cow<'static, str>> {
-        match &self.0 {
+        match &self.inner {
             PathAndQueryInner::Static(_) => None,
             PathAndQueryInner::Templated(templated) => templated.label().map(Cow::Borrowed),
         }
@@ -92,9 +143,14 @@ impl PathAndQuery {
     /// rather than a plain `String`. Use [`Sensitive::declassify_ref`] (or the
     /// [`RedactedDisplay`] impl) when you need access to the underlying text.
     pub fn to_string(&self) -> Sensitive<String> {
-        let s = match &self.0 {
-            PathAndQueryInner::Static(classified_pq) => classified_pq.declassify_ref().to_string(),
-            PathAndQueryInner::Templated(templated) => templated.render(),
+        let s = match (&self.inner, &self.query_params) {
+            (PathAndQueryInner::Static(classified_pq), None) => classified_pq.declassify_ref().to_string(),
+            (PathAndQueryInner::Templated(templated), None) => templated.render(),
+            (_, Some(_)) => {
+                let mut s = String::with_capacity(self.render_capacity_hint());
+                self.render_into(&mut s);
+                s
+            }
         };
         Sensitive::new(s, Uri::DATA_CLASS)
     }
@@ -103,14 +159,19 @@ impl PathAndQuery {
 impl RedactedDisplay for PathAndQuery {
     #[cfg_attr(test, mutants::skip)] // Do not mutate display output.
     fn fmt(&self, redactor: &dyn Redactor, f: &mut Formatter<'_>) -> fmt::Result {
-        match &self.0 {
+        match &self.inner {
             PathAndQueryInner::Static(classified_pq) => {
                 // We can't use to_string in redaction because it automatically prepends a slash if the path doesn't start with one.
                 // as_str doesn't do that, so we declassify to get the inner PathAndQuery and then use as_str.
                 let reclassified = Sensitive::new(classified_pq.declassify_ref().as_str(), classified_pq.data_class().clone());
-                RedactedDisplay::fmt(&reclassified, redactor, f)
+                RedactedDisplay::fmt(&reclassified, redactor, f)?;
             }
-            PathAndQueryInner::Templated(templated) => RedactedDisplay::fmt(&**templated, redactor, f),
+            PathAndQueryInner::Templated(templated) => RedactedDisplay::fmt(&**templated, redactor, f)?,
+        }
+        match &self.query_params {
+            // The redacted output can hide the `?` of the query, so look for it unredacted.
+            Some(params) => params.fmt_redacted(self.has_query(), redactor, f),
+            None => Ok(()),
         }
     }
 }
@@ -118,7 +179,7 @@ impl RedactedDisplay for PathAndQuery {
 impl fmt::Debug for PathAndQuery {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let mut tuple = f.debug_tuple("PathAndQuery");
-        match &self.0 {
+        match &self.inner {
             PathAndQueryInner::Static(_) => tuple.finish(),
             PathAndQueryInner::Templated(templated) => tuple.field(templated).finish(),
         }
@@ -129,7 +190,7 @@ impl RedactedDebug for PathAndQuery {
     #[cfg_attr(test, mutants::skip)] // Do not mutate debug output.
     fn fmt(&self, redactor: &dyn Redactor, f: &mut Formatter<'_>) -> fmt::Result {
         let mut tuple = f.debug_tuple("PathAndQuery");
-        match &self.0 {
+        match &self.inner {
             PathAndQueryInner::Static(_) => tuple.finish(),
             PathAndQueryInner::Templated(templated) => {
                 let rendered = templated.deref().to_redacted_string(redactor);
@@ -155,7 +216,7 @@ impl TryFrom<Uri> for PathAndQuery {
 
 impl From<HttpPathAndQuery> for PathAndQuery {
     fn from(value: HttpPathAndQuery) -> Self {
-        Self(PathAndQueryInner::Static(Sensitive::new(value, Uri::DATA_CLASS)))
+        Self::from_inner(PathAndQueryInner::Static(Sensitive::new(value, Uri::DATA_CLASS)))
     }
 }
 
@@ -203,9 +264,10 @@ impl TryFrom<&PathAndQuery> for HttpPathAndQuery {
     /// Returns a [`UriError`] if the underlying templated path renders to a value that
     /// is not a valid path-and-query.
     fn try_from(value: &PathAndQuery) -> Result<Self, Self::Error> {
-        match &value.0 {
-            PathAndQueryInner::Static(classified_pq) => Ok(classified_pq.declassify_ref().clone()),
-            PathAndQueryInner::Templated(templated) => templated.to_path_and_query(),
+        match (&value.inner, &value.query_params) {
+            (PathAndQueryInner::Static(classified_pq), None) => Ok(classified_pq.declassify_ref().clone()),
+            (PathAndQueryInner::Templated(templated), None) => templated.to_path_and_query(),
+            (_, Some(_)) => Ok(Self::try_from(value.to_string().declassify_into())?),
         }
     }
 }
@@ -426,6 +488,39 @@ mod tests {
         let engine = RedactionEngine::builder().build();
         assert_eq!(template.to_redacted_string(&engine), "/fixed/template");
     }
+
+    #[test]
+    fn query_params_follow_existing_query() {
+        use data_privacy::simple_redactor::SimpleRedactor;
+        use data_privacy::{RedactedToString, RedactionEngine, Sensitive};
+
+        use crate::EscapedString;
+
+        let pq = PathAndQuery::from_static("/items?limit=10")
+            .with_query_params(QueryParams::new().append("page", 2_u32))
+            .with_query_params(QueryParams::new())
+            .with_query_params(
+                QueryParams::new().append_classified("user", Sensitive::new(EscapedString::from_static("jane"), Uri::DATA_CLASS)),
+            );
+        assert_eq!(pq.template(), "/items?limit=10");
+        assert_eq!(pq.to_string().declassify_ref(), "/items?limit=10&page=2&user=jane");
+        assert_eq!(pq.render_capacity_hint(), "/items?limit=10&page=2&user=jane".len());
+        assert_eq!(HttpPathAndQuery::try_from(&pq).unwrap(), "/items?limit=10&page=2&user=jane");
+
+        let engine = RedactionEngine::builder().set_fallback_redactor(SimpleRedactor::new()).build();
+        assert_eq!(pq.to_redacted_string(&engine), "***************&page=2&user=****");
+    }
+
+    #[test]
+    fn query_params_start_query_of_templated_path() {
+        let pq = PathAndQuery::from_template(FixedTemplate).with_query_params(QueryParams::new().append("page", 2_u32));
+        let mut buf = String::from("/base");
+        pq.render_into(&mut buf);
+        assert_eq!(buf, "/base/fixed/template?page=2");
+        assert_eq!(pq.render_capacity_hint(), 42 + "?page=2".len());
+        assert_eq!(pq.to_string().declassify_ref(), "/fixed/template?page=2");
+        assert_eq!(HttpPathAndQuery::try_from(&pq).unwrap(), "/fixed/template?page=2");
+    }
 }
 
 #[cfg(all(test, feature = "serde"))]
@@ -1,7 +1,7 @@
 // Copyright (c) Microsoft Corporation.
 // Licensed under the MIT License.
 
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 
 use data_privacy::RedactedDisplay;
 use http::uri::PathAndQuery;
@@ -16,7 +16,7 @@ use crate::UriError;
 ///
 /// Use the `#[templated]` attribute macro to derive an implementation.
 ///
-/// Templates are based on [RFC 6570](https://datatracker.ietf.org/doc/html/rfc6570) Level 3,
+/// Templates are based on [RFC 6570](https://datatracker.ietf.org/doc/html/rfc6570) Level 4,
 /// with additional constraints for valid HTTP URI construction:
 ///
 /// - Variable names must be valid Rust identifiers (ASCII letters, digits, underscores)
@@ -25,7 +25,7 @@ use crate::UriError;
 ///
 /// All template values must implement [`Escape`](crate::Escape), except for
 /// unfiltered expansions (`{+foo}`). This ensures variables cannot contain reserved characters
-/// as defined by the RFC.
+/// as defined by the RFC. Exploded variables (`{foo*}`) must implement [`Explode`](crate::Explode).
 ///
 /// # Examples
 ///
@@ -110,6 +110,38 @@ where
         buf.push_str(&self.render());
     }
 
+    /// Writes the rendered path-and-query to `out`.
+    ///
+    /// Unlike [`render`](PathAndQueryTemplate::render), this doesn't allocate a `String` for
+    /// the result, so a client on the request hot path can render into a buffer it reuses, or
+    /// into any other [`fmt::Write`] implementation. The `#[templated]` macro overrides it to
+    /// write field values directly; the default writes the result of
+    /// [`render`](PathAndQueryTemplate::render).
+    ///
+    /// ```
+    /// use templated_uri::{PathAndQueryTemplate, templated};
+    ///
+    /// #[templated(template = "/users/{user_id}", unredacted)]
+    /// struct UserPath {
+    ///     user_id: u32,
+    /// }
+    ///
+    /// let mut buffer = String::new();
+    /// for user_id in [1, 2] {
+    ///     buffer.clear();
+    ///     UserPath { user_id }.write_uri(&mut buffer)?;
+    ///     assert_eq!(buffer, format!("/users/{user_id}"));
+    /// }
+    /// # Ok::<(), std::fmt::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails.
+    fn write_uri(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        out.write_str(&self.render())
+    }
+
     /// Returns a heuristic byte-capacity estimate for the rendered path-and-query.
     ///
     /// Used to size a buffer before calling [`render_into`](PathAndQueryTemplate::render_into)
@@ -144,6 +176,20 @@ where
     /// Set via `#[templated(template = "...", label = "my_label")]`.
     /// Useful for telemetry when the full template is too verbose.
     fn label(&self) -> Option<&'static str>;
+
+    /// Returns the telemetry labels of all templates of the type: the [`label`](Self::label)
+    /// of each template, or the [`template`](Self::template) itself if it has no label.
+    ///
+    /// The `#[templated]` macro returns the label of a struct, and the labels of all variants
+    /// of an enum, so that a [`TemplateRegistry`](crate::TemplateRegistry) can collect them
+    /// without an instance of the type. The default returns no labels.
+    #[must_use]
+    fn telemetry_labels() -> Vec<&'static str>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
 }
 
 impl<T: PathAndQueryTemplate> From<T> for crate::Uri {
@@ -203,6 +249,13 @@ mod tests {
         assert_eq!(buf, "/prefix/manual/path");
     }
 
+    #[test]
+    fn default_write_uri_writes_render_output() {
+        let mut buf = String::from("/prefix");
+        ManualTemplate.write_uri(&mut buf).unwrap();
+        assert_eq!(buf, "/prefix/manual/path");
+    }
+
     #[test]
     fn default_render_capacity_hint_is_zero() {
         // The default `render_capacity_hint` returns `0` (callers fall back to growth).
@@ -221,6 +274,7 @@ mod tests {
         assert_eq!(template.template(), "/manual/path");
         assert_eq!(template.format_template(), "/manual/path");
         assert_eq!(template.label(), None);
+        assert!(ManualTemplate::telemetry_labels().is_empty());
         assert_eq!(template.to_path_and_query().expect("valid path").as_str(), "/manual/path");
         assert_eq!(format!("{template:?}"), "ManualTemplate");
 
@@ -0,0 +1,232 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::fmt::{self, Formatter};
+
+use data_privacy::{Classified, DataClass, RedactedDisplay, Redactor};
+
+use crate::{EscapedString, UriSafe};
+
+/// Query parameters appended to a [`PathAndQuery`](crate::PathAndQuery) at runtime.
+///
+/// Templates bake their query parameters in at compile time. `QueryParams` covers the
+/// parameters only known at runtime, such as optional filters or pass-through parameters, and
+/// is merged onto a path with [`PathAndQuery::with_query_params`](crate::PathAndQuery::with_query_params)
+/// or [`Uri::with_query_params`](crate::Uri::with_query_params). The parameters are appended in
+/// order after the existing query, if any.
+///
+/// Keys are percent-encoded as needed, and values are escaped through [`UriSafe`]. Values
+/// appended with [`append`](Self::append) appear verbatim in the [`RedactedDisplay`] output of
+/// the URI, while values appended with [`append_classified`](Self::append_classified) are
+/// redacted according to their data class.
+///
+/// # Examples
+///
+/// ```rust
+/// use data_privacy::simple_redactor::SimpleRedactor;
+/// use data_privacy::{RedactedToString, RedactionEngine, Sensitive};
+/// use templated_uri::{EscapedString, PathAndQuery, QueryParams, Uri};
+///
+/// let params = QueryParams::new()
+///     .append("page", 2_u32)
+///     .append("sort", EscapedString::escape("name desc"))
+///     .append_classified(
+///         "user",
+///         Sensitive::new(EscapedString::from_static("jane"), Uri::DATA_CLASS),
+///     );
+///
+/// let path = PathAndQuery::from_static("/items?limit=10").with_query_params(params);
+/// assert_eq!(
+///     path.to_string().declassify_ref(),
+///     "/items?limit=10&page=2&sort=name%20desc&user=jane"
+/// );
+///
+/// let redaction_engine = RedactionEngine::builder()
+///     .set_fallback_redactor(SimpleRedactor::new())
+///     .build();
+/// let uri = Uri::from(PathAndQuery::from_static("/items")).with_query_params(
+///     QueryParams::new().append("page", 2_u32).append_classified(
+///         "user",
+///         Sensitive::new(EscapedString::from_static("jane"), Uri::DATA_CLASS),
+///     ),
+/// );
+/// assert_eq!(
+///     uri.to_redacted_string(&redaction_engine),
+///     "******?page=2&user=****"
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct QueryParams {
+    params: Vec<QueryParam>,
+}
+
+#[derive(Clone)]
+struct QueryParam {
+    key: EscapedString,
+    value: String,
+    data_class: Option<DataClass>,
+}
+
+impl QueryParams {
+    /// Creates an empty set of query parameters.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the parameter `key=value`, whose value is not redacted.
+    #[must_use]
+    #[expect(
+        clippy::needless_pass_by_value,
+        reason = "values are usually temporaries, which read better passed by value"
+    )]
+    pub fn append(self, key: &str, value: impl UriSafe) -> Self {
+        self.push(key, &value, None)
+    }
+
+    /// Appends the parameter `key=value`, whose value is redacted according to its data class.
+    #[must_use]
+    #[expect(
+        clippy::needless_pass_by_value,
+        reason = "values are usually temporaries, which read better passed by value"
+    )]
+    pub fn append_classified<T: UriSafe + Classified>(self, key: &str, value: T) -> Self {
+        let data_class = value.data_class().clone();
+        self.push(key, &value, Some(data_class))
+    }
+
+    /// Appends all parameters of `other` after the parameters of `self`.
+    #[must_use]
+    pub fn extend(mut self, other: Self) -> Self {
+        self.params.extend(other.params);
+        self
+    }
+
+    /// Returns the number of parameters.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Returns whether there are no parameters.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    fn push(mut self, key: &str, value: &dyn UriSafe, data_class: Option<DataClass>) -> Self {
+        let mut escaped = String::new();
+        value.write_escaped(&mut escaped);
+        self.params.push(QueryParam {
+            key: EscapedString::escape(key),
+            value: escaped,
+            data_class,
+        });
+        self
+    }
+
+    /// Returns the number of bytes [`render_into`](Self::render_into) appends.
+    pub(crate) fn rendered_len(&self) -> usize {
+        self.params
+            .iter()
+            .map(|param| param.key.as_str().len() + param.value.len() + 2)
+            .sum()
+    }
+
+    /// Appends the parameters to `out`, the first one after `?` unless `has_query` is set.
+    pub(crate) fn render_into(&self, has_query: bool, out: &mut String) {
+        for (index, param) in self.params.iter().enumerate() {
+            out.push_str(separator(index, has_query));
+            out.push_str(param.key.as_str());
+            out.push('=');
+            out.push_str(&param.value);
+        }
+    }
+
+    /// Displays the parameters, redacting the classified values, the first one after `?`
+    /// unless `has_query` is set.
+    pub(crate) fn fmt_redacted(&self, has_query: bool, redactor: &dyn Redactor, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, param) in self.params.iter().enumerate() {
+            f.write_str(separator(index, has_query))?;
+            f.write_str(param.key.as_str())?;
+            f.write_str("=")?;
+            match &param.data_class {
+                Some(data_class) => redactor.redact(data_class, &param.value, f)?,
+                None => f.write_str(&param.value)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn separator(index: usize, has_query: bool) -> &'static str {
+    if index == 0 && !has_query { "?" } else { "&" }
+}
+
+impl RedactedDisplay for QueryParams {
+    /// Displays the parameters as a query string starting with `?`, redacting the values
+    /// appended with [`append_classified`](QueryParams::append_classified).
+    fn fmt(&self, redactor: &dyn Redactor, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_redacted(false, redactor, f)
+    }
+}
+
+impl fmt::Debug for QueryParams {
+    /// Lists the keys of the parameters, leaving out their values as they can be sensitive.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("QueryParams")
+            .field(&self.params.iter().map(|param| param.key.as_str()).collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use data_privacy::simple_redactor::SimpleRedactor;
+    use data_privacy::{RedactedToString, RedactionEngine, Sensitive};
+
+    use super::*;
+    use crate::Uri;
+
+    fn params() -> QueryParams {
+        QueryParams::new()
+            .append("a b", EscapedString::escape("c&d"))
+            .append_classified("user", Sensitive::new(EscapedString::from_static("jane"), Uri::DATA_CLASS))
+    }
+
+    #[test]
+    fn renders_escaped_parameters() {
+        let mut out = String::from("/items");
+        params().render_into(false, &mut out);
+        assert_eq!(out, "/items?a%20b=c%26d&user=jane");
+        assert_eq!(params().rendered_len(), "?a%20b=c%26d&user=jane".len());
+
+        let mut out = String::from("/items?x=1");
+        params().render_into(true, &mut out);
+        assert_eq!(out, "/items?x=1&a%20b=c%26d&user=jane");
+    }
+
+    #[test]
+    fn redacts_classified_values() {
+        let redaction_engine = RedactionEngine::builder().set_fallback_redactor(SimpleRedactor::new()).build();
+        assert_eq!(params().to_redacted_string(&redaction_engine), "?a%20b=c%26d&user=****");
+    }
+
+    #[test]
+    fn debug_lists_keys_only() {
+        assert_eq!(format!("{:?}", params()), r#"QueryParams(["a%20b", "user"])"#);
+    }
+
+    #[test]
+    fn extend_appends_in_order() {
+        let params = QueryParams::new().append("a", 1_u32).extend(QueryParams::new().append("b", 2_u32));
+        assert_eq!(params.len(), 2);
+        assert!(!params.is_empty());
+        assert!(QueryParams::new().is_empty());
+
+        let mut out = String::new();
+        params.render_into(false, &mut out);
+        assert_eq!(out, "?a=1&b=2");
+    }
+}
@@ -0,0 +1,235 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Reference resolution as specified by [RFC 3986 section 5](https://www.rfc-editor.org/rfc/rfc3986#section-5).
+
+/// The components of a URI reference, without its fragment.
+#[derive(Debug, PartialEq, Eq)]
+struct Components<'a> {
+    scheme: Option<&'a str>,
+    authority: Option<&'a str>,
+    path: &'a str,
+    query: Option<&'a str>,
+}
+
+impl<'a> Components<'a> {
+    /// Splits `reference` into its components the way the regular expression of
+    /// [RFC 3986 appendix B](https://www.rfc-editor.org/rfc/rfc3986#appendix-B) does,
+    /// dropping the fragment.
+    fn parse(reference: &'a str) -> Self {
+        let reference = reference.split_once('#').map_or(reference, |(reference, _fragment)| reference);
+        let (reference, query) = match reference.split_once('?') {
+            Some((reference, query)) => (reference, Some(query)),
+            None => (reference, None),
+        };
+
+        // A scheme is whatever precedes the first `:`, unless a `/` comes first.
+        let (scheme, rest) = match reference.find([':', '/']) {
+            Some(end) if end > 0 && reference.as_bytes()[end] == b':' => (Some(&reference[..end]), &reference[end + 1..]),
+            _ => (None, reference),
+        };
+
+        let (authority, path) = match rest.strip_prefix("//") {
+            Some(rest) => {
+                let end = rest.find('/').unwrap_or(rest.len());
+                (Some(&rest[..end]), &rest[end..])
+            }
+            None => (None, rest),
+        };
+
+        Self {
+            scheme,
+            authority,
+            path,
+            query,
+        }
+    }
+}
+
+/// Resolves `reference` against the absolute or path-absolute `base`, returning the target
+/// URI without a fragment.
+pub(crate) fn resolve(base: &str, reference: &str) -> String {
+    let base = Components::parse(base);
+    let reference = Components::parse(reference);
+
+    let (scheme, authority, path, query) = if reference.scheme.is_some() {
+        (
+            reference.scheme,
+            reference.authority,
+            remove_dot_segments(reference.path),
+            reference.query,
+        )
+    } else if reference.authority.is_some() {
+        (
+            base.scheme,
+            reference.authority,
+            remove_dot_segments(reference.path),
+            reference.query,
+        )
+    } else if reference.path.is_empty() {
+        (base.scheme, base.authority, base.path.to_owned(), reference.query.or(base.query))
+    } else if reference.path.starts_with('/') {
+        (base.scheme, base.authority, remove_dot_segments(reference.path), reference.query)
+    } else {
+        (
+            base.scheme,
+            base.authority,
+            remove_dot_segments(&merge(&base, reference.path)),
+            reference.query,
+        )
+    };
+
+    let mut target = String::with_capacity(base.path.len() + reference.path.len() + 16);
+    if let Some(scheme) = scheme {
+        target.push_str(scheme);
+        target.push(':');
+    }
+    if let Some(authority) = authority {
+        target.push_str("//");
+        target.push_str(authority);
+    }
+    target.push_str(&path);
+    if let Some(query) = query {
+        target.push('?');
+        target.push_str(query);
+    }
+    target
+}
+
+/// Merges the relative `path` with the path of `base`, replacing its last segment.
+fn merge(base: &Components<'_>, path: &str) -> String {
+    if base.authority.is_some() && base.path.is_empty() {
+        return format!("/{path}");
+    }
+    let directory = base.path.rfind('/').map_or("", |end| &base.path[..=end]);
+    format!("{directory}{path}")
+}
+
+/// Removes the `.` and `..` segments of `path`.
+fn remove_dot_segments(path: &str) -> String {
+    let mut input = path;
+    let mut output = String::with_capacity(path.len());
+
+    while !input.is_empty() {
+        if let Some(rest) = input.strip_prefix("../").or_else(|| input.strip_prefix("./")) {
+            input = rest;
+        } else if input.starts_with("/./") {
+            input = &input[2..];
+        } else if input == "/." {
+            input = "/";
+        } else if input.starts_with("/../") {
+            input = &input[3..];
+            remove_last_segment(&mut output);
+        } else if input == "/.." {
+            input = "/";
+            remove_last_segment(&mut output);
+        } else if input == "." || input == ".." {
+            input = "";
+        } else {
+            // Move the first segment, with its leading `/` if any, to the output.
+            let start = usize::from(input.starts_with('/'));
+            let end = input[start..].find('/').map_or(input.len(), |end| end + start);
+            output.push_str(&input[..end]);
+            input = &input[end..];
+        }
+    }
+
+    output
+}
+
+fn remove_last_segment(output: &mut String) {
+    output.truncate(output.rfind('/').unwrap_or(0));
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = "http://a/b/c/d;p?q";
+
+    #[test]
+    fn parse_splits_components() {
+        assert_eq!(
+            Components::parse("http://a/b?q#f"),
+            Components {
+                scheme: Some("http"),
+                authority: Some("a"),
+                path: "/b",
+                query: Some("q"),
+            }
+        );
+        assert_eq!(
+            Components::parse("./a:b"),
+            Components {
+                scheme: None,
+                authority: None,
+                path: "./a:b",
+                query: None,
+            }
+        );
+    }
+
+    #[test]
+    fn resolves_normal_examples() {
+        // RFC 3986 section 5.4.1, without the fragments.
+        let examples = [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q"),
+            ("g#s", "http://a/b/c/g"),
+            (";x", "http://a/b/c/;x"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("./", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../", "http://a/"),
+            ("../../g", "http://a/g"),
+        ];
+        for (reference, expected) in examples {
+            assert_eq!(resolve(BASE, reference), expected, "reference `{reference}`");
+        }
+    }
+
+    #[test]
+    fn resolves_abnormal_examples() {
+        // RFC 3986 section 5.4.2, in strict mode.
+        let examples = [
+            ("../../../g", "http://a/g"),
+            ("../../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("/../g", "http://a/g"),
+            ("g.", "http://a/b/c/g."),
+            (".g", "http://a/b/c/.g"),
+            ("g..", "http://a/b/c/g.."),
+            ("..g", "http://a/b/c/..g"),
+            ("./../g", "http://a/b/g"),
+            ("./g/.", "http://a/b/c/g/"),
+            ("g/./h", "http://a/b/c/g/h"),
+            ("g/../h", "http://a/b/c/h"),
+            ("g;x=1/./y", "http://a/b/c/g;x=1/y"),
+            ("g;x=1/../y", "http://a/b/c/y"),
+            ("g?y/./x", "http://a/b/c/g?y/./x"),
+            ("g#s/../x", "http://a/b/c/g"),
+            ("http:g", "http:g"),
+        ];
+        for (reference, expected) in examples {
+            assert_eq!(resolve(BASE, reference), expected, "reference `{reference}`");
+        }
+    }
+
+    #[test]
+    fn merges_with_empty_base_path() {
+        assert_eq!(resolve("http://a", "g"), "http://a/g");
+        assert_eq!(resolve("/b/c", "g"), "/b/g");
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Extensions for setting and reading the [`Uri`] of [`http::Request`]s.
+
+use http::Request;
+use http::request::Builder;
+use http::uri::PathAndQuery as HttpPathAndQuery;
+
+use crate::{Uri, UriError};
+
+/// Extensions for [`http::request::Builder`] to set the URI of a request from a [`Uri`].
+///
+/// Besides setting the [`http::Uri`] of the request, the [`Uri`] and its
+/// [`PathAndQuery`](crate::PathAndQuery) are
+/// attached to the request as extensions, so the template and label of a templated path stay
+/// available for telemetry, and the URI can be redacted, through
+/// [`UriRequestExt::uri_classified`].
+///
+/// # Examples
+///
+/// ```rust
+/// use data_privacy::simple_redactor::SimpleRedactor;
+/// use data_privacy::{RedactedToString, RedactionEngine};
+/// use templated_uri::{
+///     BaseUri, PathAndQuery, PathAndQueryTemplate, Uri, UriRequestBuilderExt, UriRequestExt,
+///     templated,
+/// };
+///
+/// #[templated(template = "/users/{user_id}", label = "user", unredacted)]
+/// struct UserPath {
+///     user_id: u32,
+/// }
+///
+/// let uri = Uri::from(BaseUri::from_static("https://example.com"))
+///     .with_path_and_query(UserPath { user_id: 42 });
+/// let request = http::Request::builder().templated_uri(uri)?.body(())?;
+/// assert_eq!(request.uri(), "https://example.com/users/42");
+///
+/// let path = request.extensions().get::<PathAndQuery>().unwrap();
+/// assert_eq!(path.label().as_deref(), Some("user"));
+///
+/// let redaction_engine = RedactionEngine::builder()
+///     .set_fallback_redactor(SimpleRedactor::new())
+///     .build();
+/// assert_eq!(
+///     request
+///         .uri_classified()?
+///         .to_redacted_string(&redaction_engine),
+///     "https://example.com/users/42"
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait UriRequestBuilderExt: sealed::Sealed + Sized {
+    /// Sets the URI of the request to `uri`, attaching the [`Uri`] and its
+    /// [`PathAndQuery`](crate::PathAndQuery) to the request as extensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UriError`] if the URI fails to convert into an [`http::Uri`], for example when
+    /// a templated path renders into an invalid path and query.
+    fn templated_uri(self, uri: impl Into<Uri>) -> Result<Self, UriError>;
+}
+
+impl UriRequestBuilderExt for Builder {
+    fn templated_uri(self, uri: impl Into<Uri>) -> Result<Self, UriError> {
+        let uri = uri.into();
+        // Render the path once, and join it onto the base instead of rendering it again.
+        let path = uri.to_path_and_query();
+        let rendered = path.as_ref().map(HttpPathAndQuery::try_from).transpose()?;
+        let http_uri = uri.to_http_uri(rendered.as_ref())?;
+
+        let builder = self.uri(http_uri).extension(uri);
+        Ok(match path {
+            Some(path) => builder.extension(path),
+            None => builder,
+        })
+    }
+}
+
+/// Extensions for [`http::Request`] to read its URI as a [`Uri`].
+pub trait UriRequestExt: sealed::Sealed {
+    /// Returns the URI of the request as a [`Uri`], which keeps its values classified.
+    ///
+    /// This is the [`Uri`] set with [`UriRequestBuilderExt::templated_uri`] if there is one,
+    /// which keeps the template, label and data classes of a templated path. Otherwise, the
+    /// [`http::Uri`] of the request is converted, and classified as a whole as [`Uri::DATA_CLASS`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UriError`] if the request has no attached [`Uri`] and its [`http::Uri`] fails
+    /// to convert, which [`Uri::try_from`] currently never does.
+    fn uri_classified(&self) -> Result<Uri, UriError>;
+}
+
+impl<B> UriRequestExt for Request<B> {
+    fn uri_classified(&self) -> Result<Uri, UriError> {
+        match self.extensions().get::<Uri>() {
+            Some(uri) => Ok(uri.clone()),
+            None => Uri::try_from(self.uri().clone()),
+        }
+    }
+}
+
+pub(crate) mod sealed {
+    use super::{Builder, Request};
+
+    #[expect(unnameable_types, reason = "intentional, sealed trait pattern")]
+    pub trait Sealed {}
+    impl Sealed for Builder {}
+    impl<B> Sealed for Request<B> {}
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use data_privacy::simple_redactor::SimpleRedactor;
+    use data_privacy::{RedactedToString, RedactionEngine};
+
+    use super::*;
+    use crate::{BaseUri, PathAndQuery};
+
+    fn redaction_engine() -> RedactionEngine {
+        RedactionEngine::builder().set_fallback_redactor(SimpleRedactor::new()).build()
+    }
+
+    #[test]
+    fn templated_uri_attaches_uri_and_path() {
+        let uri = Uri::from(BaseUri::from_static("https://example.com/api/")).with_path_and_query(PathAndQuery::from_static("/items?x=1"));
+        let request = Request::builder().templated_uri(uri).unwrap().body(()).unwrap();
+
+        assert_eq!(request.uri(), "https://example.com/api/items?x=1");
+        assert_eq!(request.extensions().get::<PathAndQuery>().unwrap().template(), "/items?x=1");
+        assert_eq!(
+            request.uri_classified().unwrap().to_string().declassify_ref(),
+            "https://example.com/api/items?x=1"
+        );
+    }
+
+    #[test]
+    fn templated_uri_without_path() {
+        let request = Request::builder()
+            .templated_uri(BaseUri::from_static("https://example.com"))
+            .unwrap()
+            .body(())
+            .unwrap();
+
+        assert_eq!(request.uri(), "https://example.com/");
+        assert!(request.extensions().get::<PathAndQuery>().is_none());
+        assert!(request.extensions().get::<Uri>().is_some());
+    }
+
+    #[test]
+    fn uri_classified_converts_request_uri() {
+        let request = Request::get("https://example.com/users/jane").body(()).unwrap();
+
+        let uri = request.uri_classified().unwrap();
+        assert_eq!(uri.to_string().declassify_ref(), "https://example.com/users/jane");
+        assert_eq!(uri.to_redacted_string(&redaction_engine()), "https://example.com/***********");
+    }
+
+    #[test]
+    fn uri_classified_converts_path_only_request_uri() {
+        let request = Request::get("/items?x=1").body(()).unwrap();
+
+        assert_eq!(request.uri_classified().unwrap().to_string().declassify_ref(), "/items?x=1");
+    }
+}
@@ -0,0 +1,453 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Matches concrete paths against a runtime [`Template`], the reverse of expanding it.
+
+use std::borrow::Cow;
+
+use super::{Expression, Operator, Part, Template};
+
+/// The variable values captured by matching a path against a [`Template`].
+///
+/// Returned by [`Template::match_path()`]. Values are captured as they appear in the path, still
+/// percent-encoded; use [`decoded()`](Self::decoded) to decode them.
+///
+/// # Examples
+///
+/// ```rust
+/// use templated_uri::Template;
+///
+/// let template = Template::parse("/users/{user_id}/files{/path*}{?tag*}")?;
+///
+/// let captures = template
+///     .match_path("/users/42/files/docs/my%20notes?tag=red&tag=blue")
+///     .unwrap();
+/// assert_eq!(captures.get("user_id"), Some("42"));
+/// assert_eq!(
+///     captures.get_all("path").collect::<Vec<_>>(),
+///     ["docs", "my%20notes"]
+/// );
+/// assert_eq!(captures.decoded("path").as_deref(), Some("docs"));
+/// assert_eq!(captures.get_all("tag").collect::<Vec<_>>(), ["red", "blue"]);
+/// # Ok::<(), templated_uri::UriError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Captures<'a> {
+    values: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Captures<'a> {
+    /// Returns the first value captured for the variable `name`, or `None` if it was undefined.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&'a str> {
+        self.get_all(name).next()
+    }
+
+    /// Returns all values captured for the variable `name`, in order.
+    ///
+    /// An exploded variable such as `{/list*}` captures one value per item.
+    pub fn get_all(&self, name: &str) -> impl Iterator<Item = &'a str> {
+        self.values
+            .iter()
+            .filter(move |(variable, _)| *variable == name)
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns the first value captured for the variable `name`, percent-decoded.
+    ///
+    /// Decoded bytes that are not valid UTF-8 are replaced with `U+FFFD`.
+    #[must_use]
+    pub fn decoded(&self, name: &str) -> Option<Cow<'a, str>> {
+        self.get(name).map(percent_decode)
+    }
+
+    /// Returns all captured values with the names of their variables, in order of appearance
+    /// in the path.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        self.values.iter().copied()
+    }
+
+    /// Returns whether no value was captured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl Template {
+    /// Matches `path_and_query` against the template, returning the captured variable values.
+    ///
+    /// The path must match the literal text and expressions of the template before its query
+    /// part. Query expressions (`{?var}` and `{&var}`) match query parameters of the same name,
+    /// in any order; other query parameters are ignored, but literal parameters of the template
+    /// such as `?format=json` must be present.
+    ///
+    /// Variables are optional, as undefined variables are omitted from expansions. A variable
+    /// value must not be empty, except in query parameters and `{;var}` expressions, and can
+    /// only contain delimiters of its expression if it is a reserved expansion such as `{+var}`.
+    /// Exploded variables capture one value per item, and modifiers are otherwise ignored.
+    /// Where a path can match in several ways, earlier variables capture as little as possible,
+    /// so that `/users/{id}{.format}` matches `/users/42.json` with `json` as the format.
+    ///
+    /// Returns `None` if the path does not match.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use templated_uri::Template;
+    ///
+    /// let template = Template::parse("/repos/{owner}/{repo}/issues{?state,labels}")?;
+    ///
+    /// let captures = template
+    ///     .match_path("/repos/microsoft/oxidizer/issues?labels=bug&page=2")
+    ///     .unwrap();
+    /// assert_eq!(captures.get("owner"), Some("microsoft"));
+    /// assert_eq!(captures.get("repo"), Some("oxidizer"));
+    /// assert_eq!(captures.get("state"), None);
+    /// assert_eq!(captures.get("labels"), Some("bug"));
+    ///
+    /// assert!(template.match_path("/repos/microsoft").is_none());
+    /// # Ok::<(), templated_uri::UriError>(())
+    /// ```
+    #[must_use]
+    pub fn match_path<'a>(&'a self, path_and_query: &'a str) -> Option<Captures<'a>> {
+        self.match_path_with_lists(path_and_query, &[])
+    }
+
+    /// Like [`match_path()`](Self::match_path), but the values of the non-exploded variables
+    /// in `lists` can contain commas, as they are lists whose items are joined with commas.
+    pub(crate) fn match_path_with_lists<'a>(&'a self, path_and_query: &'a str, lists: &[&str]) -> Option<Captures<'a>> {
+        let (path, query) = path_and_query.split_once('?').unwrap_or((path_and_query, ""));
+
+        // The template parts before its query part match the path, the rest match the query.
+        let query_start = self.parts.iter().position(|part| match part {
+            Part::Literal(literal) => literal.contains('?'),
+            Part::Expression(expression) => matches!(expression.operator, Operator::Query | Operator::QueryContinuation),
+        });
+        let (path_parts, query_parts) = self.parts.split_at(query_start.unwrap_or(self.parts.len()));
+
+        // A literal that starts the query ends with the path it is part of.
+        let (path_tail, query_literal) = match query_parts.first() {
+            Some(Part::Literal(literal)) => literal.split_once('?').unwrap_or((literal, "")),
+            _ => ("", ""),
+        };
+
+        let mut matcher = PathMatcher {
+            parts: path_parts,
+            tail: path_tail,
+            path,
+            lists,
+            captures: Vec::new(),
+        };
+        if !matcher.match_parts(0, 0) {
+            return None;
+        }
+        let mut captures = matcher.captures;
+
+        let parameters: Vec<(&str, &str)> = query
+            .split('&')
+            .filter(|parameter| !parameter.is_empty())
+            .map(|parameter| parameter.split_once('=').unwrap_or((parameter, "")))
+            .collect();
+
+        // The literal starting the query, if any, was split into `path_tail` and `query_literal`.
+        let query_literals = query_parts
+            .iter()
+            .skip(usize::from(matches!(query_parts.first(), Some(Part::Literal(_)))));
+        let literal_parameters = std::iter::once(query_literal).chain(query_literals.filter_map(|part| match part {
+            Part::Literal(literal) => Some(literal.as_str()),
+            Part::Expression(_) => None,
+        }));
+
+        for literal in literal_parameters {
+            for parameter in literal.split(['?', '&']).filter(|parameter| !parameter.is_empty()) {
+                let parameter = parameter.split_once('=').unwrap_or((parameter, ""));
+                if !parameters.contains(&parameter) {
+                    return None;
+                }
+            }
+        }
+
+        for part in query_parts {
+            let Part::Expression(expression) = part else {
+                continue;
+            };
+            if !matches!(expression.operator, Operator::Query | Operator::QueryContinuation) {
+                return None;
+            }
+
+            for variable in &expression.variables {
+                let mut values = parameters.iter().filter(|(name, _)| *name == variable.name);
+                if variable.explode {
+                    captures.extend(values.map(|(_, value)| (variable.name.as_str(), *value)));
+                } else if let Some((_, value)) = values.next() {
+                    captures.push((variable.name.as_str(), value));
+                }
+            }
+        }
+
+        Some(Captures { values: captures })
+    }
+}
+
+/// Matches the path against the template parts before its query part, backtracking over the
+/// ways each variable can match.
+///
+/// Every method that fails to match leaves `captures` as it found it.
+struct PathMatcher<'a, 'l> {
+    parts: &'a [Part],
+    // Literal text of the template that ends the path.
+    tail: &'a str,
+    path: &'a str,
+    // Non-exploded variables whose values are lists joined with commas.
+    lists: &'l [&'l str],
+    captures: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> PathMatcher<'a, '_> {
+    fn match_parts(&mut self, part: usize, pos: usize) -> bool {
+        match self.parts.get(part) {
+            None => &self.path[pos..] == self.tail,
+            Some(Part::Literal(literal)) => {
+                self.path[pos..].starts_with(literal.as_str()) && self.match_parts(part + 1, pos + literal.len())
+            }
+            Some(Part::Expression(expression)) => self.match_expression(part, expression, 0, true, pos),
+        }
+    }
+
+    /// Matches the variables of an expression from `variable` on, each of which is either
+    /// defined or undefined.
+    fn match_expression(&mut self, part: usize, expression: &'a Expression, variable: usize, first: bool, pos: usize) -> bool {
+        if variable == expression.variables.len() {
+            return self.match_parts(part + 1, pos);
+        }
+
+        let operator = expression.operator;
+        let lead = if first { operator.prefix() } else { operator.separator() };
+
+        if self.path[pos..].starts_with(lead) && self.match_value(part, expression, variable, pos + lead.len()) {
+            return true;
+        }
+
+        self.match_expression(part, expression, variable + 1, first, pos)
+    }
+
+    /// Matches a value of a defined variable, followed by more items if it is exploded.
+    fn match_value(&mut self, part: usize, expression: &'a Expression, variable: usize, mut pos: usize) -> bool {
+        let operator = expression.operator;
+        let spec = &expression.variables[variable];
+        let name = spec.name.as_str();
+
+        if operator.is_named() {
+            let Some(rest) = self.path[pos..].strip_prefix(name) else {
+                return false;
+            };
+            pos += name.len();
+
+            // `{;var}` expands empty values without `=`.
+            if !rest.starts_with('=') {
+                self.captures.push((name, ""));
+                if self.match_next(part, expression, variable, pos) {
+                    return true;
+                }
+                self.captures.pop();
+                return false;
+            }
+            pos += 1;
+        }
+
+        let list = !spec.explode && self.lists.contains(&name);
+        let min_end = if operator.is_named() { pos } else { pos + 1 };
+        let max_end = pos
+            + self.path[pos..]
+                .find(|c| operator.delimits(c) && !(list && c == ','))
+                .unwrap_or(self.path.len() - pos);
+
+        for end in min_end..=max_end {
+            if !self.path.is_char_boundary(end) {
+                continue;
+            }
+
+            self.captures.push((name, &self.path[pos..end]));
+            if self.match_next(part, expression, variable, end) {
+                return true;
+            }
+            self.captures.pop();
+        }
+
+        false
+    }
+
+    /// Matches what follows a value: another item of an exploded variable, or the next variable.
+    fn match_next(&mut self, part: usize, expression: &'a Expression, variable: usize, pos: usize) -> bool {
+        let separator = expression.operator.separator();
+
+        if expression.variables[variable].explode
+            && self.path[pos..].starts_with(separator)
+            && self.match_value(part, expression, variable, pos + separator.len())
+        {
+            return true;
+        }
+
+        self.match_expression(part, expression, variable + 1, false, pos)
+    }
+}
+
+impl Operator {
+    /// Returns whether `c` cannot be part of a value of this operator.
+    fn delimits(self, c: char) -> bool {
+        match self {
+            Self::Reserved => matches!(c, '?' | '#'),
+            _ => matches!(c, '/' | '?' | '#' | ',') || self.separator().contains(c),
+        }
+    }
+}
+
+/// Decodes the `%XX` escape sequences of `value`.
+fn percent_decode(value: &str) -> Cow<'_, str> {
+    if !value.contains('%') {
+        return Cow::Borrowed(value);
+    }
+
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let escaped = bytes
+            .get(index + 1..index + 3)
+            .filter(|_| bytes[index] == b'%')
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        if let Some(byte) = escaped {
+            decoded.push(byte);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+
+    Cow::Owned(String::from_utf8_lossy(&decoded).into_owned())
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn captures<'a>(template: &'a Template, path: &'a str) -> Vec<(&'a str, &'a str)> {
+        template.match_path(path).expect("the path matches the template").iter().collect()
+    }
+
+    #[test]
+    fn matches_path_expressions() {
+        let template = Template::parse("/users/{id}{/path*}{.format}").unwrap();
+
+        assert_eq!(captures(&template, "/users/42"), [("id", "42")]);
+        assert_eq!(captures(&template, "/users/42/a/b"), [("id", "42"), ("path", "a"), ("path", "b")]);
+        assert_eq!(
+            captures(&template, "/users/42/a.json"),
+            [("id", "42"), ("path", "a"), ("format", "json")]
+        );
+        // Undefined variables are omitted from expansions, so they also match nothing.
+        assert!(template.match_path("/users/").unwrap().is_empty());
+        assert!(template.match_path("/users/42/").is_none());
+        assert!(template.match_path("/accounts/42").is_none());
+    }
+
+    #[test]
+    fn matches_multiple_variables() {
+        let template = Template::parse("/map/{x,y}/tiles{;zoom,empty}").unwrap();
+
+        assert_eq!(
+            captures(&template, "/map/1024,768/tiles;zoom=3;empty"),
+            [("x", "1024"), ("y", "768"), ("zoom", "3"), ("empty", "")]
+        );
+        assert_eq!(captures(&template, "/map/1024/tiles"), [("x", "1024")]);
+        assert!(template.match_path("/map/1024,768,1/tiles").is_none());
+    }
+
+    #[test]
+    fn reserved_expansions_match_delimiters() {
+        let template = Template::parse("/files{+path}/raw").unwrap();
+
+        assert_eq!(captures(&template, "/files/a/b,c/raw"), [("path", "/a/b,c")]);
+        assert!(template.match_path("/files/raw").unwrap().is_empty());
+        assert!(template.match_path("/files/a?b/raw").is_none());
+    }
+
+    #[test]
+    fn backtracks_to_match_literals() {
+        let template = Template::parse("/archive/{name}.tar.{compression}").unwrap();
+
+        assert_eq!(
+            captures(&template, "/archive/v1.2.tar.gz"),
+            [("name", "v1.2"), ("compression", "gz")]
+        );
+    }
+
+    #[test]
+    fn matches_query_parameters() {
+        let template = Template::parse("/search?format=json{&query,tags*}").unwrap();
+
+        assert_eq!(
+            captures(&template, "/search?tags=a&query=rust%20lang&tags=b&format=json&page=2"),
+            [("query", "rust%20lang"), ("tags", "a"), ("tags", "b")]
+        );
+        assert_eq!(captures(&template, "/search?format=json&query="), [("query", "")]);
+        assert!(template.match_path("/search?query=rust").is_none());
+        assert!(template.match_path("/search/more?format=json").is_none());
+    }
+
+    #[test]
+    fn ignores_query_without_query_expressions() {
+        let template = Template::parse("/users/{id}").unwrap();
+
+        assert_eq!(captures(&template, "/users/42?debug"), [("id", "42")]);
+    }
+
+    #[test]
+    fn decodes_values() {
+        let template = Template::parse("/files/{name}").unwrap();
+
+        let captures = template.match_path("/files/caf%C3%A9%20au%20lait%zz").unwrap();
+        assert_eq!(captures.decoded("name").as_deref(), Some("café au lait%zz"));
+        assert_eq!(captures.decoded("other"), None);
+        assert!(!captures.is_empty());
+
+        let captures = template.match_path("/files/plain").unwrap();
+        assert!(matches!(captures.decoded("name"), Some(Cow::Borrowed("plain"))));
+        assert_eq!(percent_decode("%FF"), "\u{FFFD}");
+    }
+
+    #[test]
+    fn list_values_can_contain_commas() {
+        let template = Template::parse("/items/{ids}{/tags}").unwrap();
+
+        assert!(template.match_path("/items/1,2/a,b").is_none());
+
+        let captures = template.match_path_with_lists("/items/1,2/a,b", &["ids", "tags"]).unwrap();
+        assert_eq!(captures.get("ids"), Some("1,2"));
+        assert_eq!(captures.get("tags"), Some("a,b"));
+    }
+
+    #[test]
+    fn matches_expanded_values() {
+        use std::collections::HashMap;
+
+        use crate::{EscapedString, UriSafe};
+
+        let template = Template::parse("/users/{user}/files{/path}{?version}").unwrap();
+        let name = EscapedString::escape("jane doe");
+        let segment = EscapedString::escape("a/b");
+        let values: HashMap<&str, &dyn UriSafe> = HashMap::from([("user", &name as &dyn UriSafe), ("path", &segment), ("version", &3_u32)]);
+
+        let expanded = template.expand(&values).unwrap().to_string().declassify_ref().clone();
+        let captures = template.match_path(&expanded).unwrap();
+        assert_eq!(captures.get("user"), Some(name.as_str()));
+        assert_eq!(captures.get("path"), Some(segment.as_str()));
+        assert_eq!(captures.get("version"), Some("3"));
+    }
+}
@@ -0,0 +1,705 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! A runtime RFC 6570 URI template engine, for templates only known at runtime.
+
+mod matching;
+mod openapi;
+#[cfg(feature = "serde")]
+pub(crate) mod rendered;
+#[cfg(feature = "serde")]
+mod serializer;
+
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::hash::BuildHasher;
+use std::str::FromStr;
+
+pub use self::matching::Captures;
+use crate::expansion::prefix_of;
+use crate::{Escape, PathAndQuery, UriError};
+
+/// Marks values that can fill the variables of a runtime [`Template`].
+///
+/// Unlike [`Escape`] and [`Raw`](crate::Raw), this trait is dyn-compatible, so values of
+/// different types can be passed to [`Template::expand()`] in a single map. It is implemented
+/// for every type that implements [`Escape`]. Strings have to be wrapped in an
+/// [`EscapedString`](crate::EscapedString) first, which decides how they are encoded.
+pub trait UriSafe {
+    /// Appends the value to `out`, with all reserved characters percent-encoded.
+    fn write_escaped(&self, out: &mut String);
+
+    /// Appends the value to `out` for a reserved expansion such as `{+var}`.
+    ///
+    /// Defaults to [`write_escaped()`](Self::write_escaped), which is always valid in a
+    /// reserved expansion. Implementers that emit reserved characters verbatim are responsible
+    /// for ensuring the output is valid in the target URI position.
+    fn write_reserved(&self, out: &mut String) {
+        self.write_escaped(out);
+    }
+}
+
+impl<T: Escape + ?Sized> UriSafe for T {
+    fn write_escaped(&self, out: &mut String) {
+        self.escape_into(out);
+    }
+}
+
+/// An RFC 6570 URI template parsed at runtime, such as a template read from configuration
+/// or from an `OpenAPI` document.
+///
+/// Where templates are known at compile time, prefer the
+/// [`#[templated]`](macro@crate::templated) macro, which validates templates and the types of
+/// their variables at compile time and supports redaction. A runtime template is parsed once
+/// and can then be expanded any number of times, either with a map of values or with the
+/// fields of a serializable struct (with the `serde` feature).
+///
+/// Runtime templates support the same syntax as the macro: all RFC 6570 operators except
+/// fragment expansion (`{#var}`), and the prefix (`{var:3}`) and explode (`{list*}`)
+/// modifiers. Templates must start with a `/` (or a `{/var}` expression). Variables that
+/// have no value are undefined and omitted from the expansion, along with their prefix or
+/// separator.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use templated_uri::{EscapedString, Template, UriSafe};
+///
+/// let template = Template::parse("/users/{user_id}/posts{?tag,limit}")?;
+///
+/// let tag = EscapedString::escape("rust lang");
+/// let values: HashMap<&str, &dyn UriSafe> =
+///     HashMap::from([("user_id", &42_u32 as &dyn UriSafe), ("tag", &tag)]);
+///
+/// let path = template.expand(&values)?;
+/// assert_eq!(
+///     path.to_string().declassify_ref(),
+///     "/users/42/posts?tag=rust%20lang"
+/// );
+/// # Ok::<(), templated_uri::UriError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Template {
+    source: String,
+    parts: Vec<Part>,
+}
+
+impl Template {
+    /// Parses a URI template.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UriError`] if `template` is not a valid RFC 6570 template, uses the
+    /// unsupported fragment operator, or does not start with a `/`.
+    pub fn parse(template: &str) -> Result<Self, UriError> {
+        let bytes = template.as_bytes();
+        let mut parts = Vec::new();
+        let mut literal_start = 0;
+        let mut index = 0;
+
+        while index < bytes.len() {
+            match bytes[index] {
+                b'{' => {
+                    if literal_start < index {
+                        parts.push(Part::Literal(template[literal_start..index].to_owned()));
+                    }
+
+                    let end = template[index..]
+                        .find('}')
+                        .map(|len| index + len)
+                        .ok_or_else(|| invalid_template(index, "the expression is not closed"))?;
+                    parts.push(Part::Expression(Expression::parse(&template[index + 1..end], index + 1)?));
+
+                    index = end + 1;
+                    literal_start = index;
+                }
+                b'}' => return Err(invalid_template(index, "unexpected '}' outside of an expression")),
+                _ => index += 1,
+            }
+        }
+
+        if literal_start < index {
+            parts.push(Part::Literal(template[literal_start..].to_owned()));
+        }
+
+        let starts_with_slash = match parts.first() {
+            Some(Part::Literal(literal)) => literal.starts_with('/'),
+            Some(Part::Expression(expression)) => expression.operator == Operator::Path,
+            None => false,
+        };
+        if !starts_with_slash {
+            return Err(invalid_template(0, "the template must start with '/'"));
+        }
+
+        Ok(Self {
+            source: template.to_owned(),
+            parts,
+        })
+    }
+
+    /// Returns the template as it was parsed.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the names of the variables of the template, in order of appearance.
+    pub fn variables(&self) -> impl Iterator<Item = &str> {
+        self.parts
+            .iter()
+            .filter_map(|part| match part {
+                Part::Expression(expression) => Some(expression.variables.iter().map(|variable| variable.name.as_str())),
+                Part::Literal(_) => None,
+            })
+            .flatten()
+    }
+
+    /// Expands the template, filling its variables with `values`.
+    ///
+    /// Variables missing from `values` are undefined. Values are scalars, which are
+    /// expanded as a single value even by the explode modifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UriError`] if the expanded template is not a valid path and query.
+    pub fn expand<S: BuildHasher>(&self, values: &HashMap<&str, &dyn UriSafe, S>) -> Result<PathAndQuery, UriError> {
+        self.expand_with(|name| values.get(name).map(|value| Value::Scalar(*value)))
+    }
+
+    /// Expands the template, filling its variables with the fields of `values`.
+    ///
+    /// `values` must serialize as a struct or a map. Each field fills the variable of the
+    /// same name:
+    ///
+    /// - Strings, numbers, booleans, characters and unit enum variants are scalars. Strings are
+    ///   percent-encoded as needed; reserved expansions such as `{+var}` keep reserved characters
+    ///   other than `#`, as URI fragments are not supported.
+    /// - Sequences are lists, and maps and structs are associative arrays, whose items must
+    ///   be scalars. Empty lists and associative arrays are undefined.
+    /// - `None` and unit values are undefined, as are variables without a field.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UriError`] if `values` does not serialize as described, if a prefix
+    /// modifier applies to a list or associative array, or if the expanded template is not a
+    /// valid path and query.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), templated_uri::UriError> {
+    /// # #[cfg(feature = "serde")] {
+    /// use templated_uri::Template;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Search {
+    ///     query: String,
+    ///     tags: Vec<String>,
+    ///     page: Option<u32>,
+    /// }
+    ///
+    /// let template = Template::parse("/search{?query,tags*,page}")?;
+    /// let search = Search {
+    ///     query: "rust & tokio".to_string(),
+    ///     tags: vec!["async".to_string(), "io".to_string()],
+    ///     page: None,
+    /// };
+    ///
+    /// let path = template.expand_serialized(&search)?;
+    /// assert_eq!(
+    ///     path.to_string().declassify_ref(),
+    ///     "/search?query=rust%20%26%20tokio&tags=async&tags=io"
+    /// );
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn expand_serialized(&self, values: &impl serde::Serialize) -> Result<PathAndQuery, UriError> {
+        let values = serializer::collect(values)?;
+        self.expand_with(|name| values.get(name).map(serializer::Collected::as_value))
+    }
+
+    fn expand_with<'v>(&self, lookup: impl Fn(&str) -> Option<Value<'v>>) -> Result<PathAndQuery, UriError> {
+        let mut out = String::with_capacity(self.source.len());
+
+        for part in &self.parts {
+            match part {
+                Part::Literal(literal) => out.push_str(literal),
+                Part::Expression(expression) => expression.expand_into(&lookup, &mut out)?,
+            }
+        }
+
+        PathAndQuery::try_from(out)
+    }
+}
+
+impl FromStr for Template {
+    type Err = UriError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Display for Template {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Template {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Template {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+fn invalid_template(offset: usize, reason: &str) -> UriError {
+    UriError::invalid_uri(format!("invalid URI template at offset {offset}: {reason}"))
+}
+
+/// The value of a variable, borrowed for the duration of an expansion.
+#[derive(Clone, Copy)]
+enum Value<'a> {
+    Scalar(&'a dyn UriSafe),
+    #[cfg(feature = "serde")]
+    List(&'a [serializer::Text]),
+    #[cfg(feature = "serde")]
+    Map(&'a [(serializer::Text, serializer::Text)]),
+}
+
+impl Value<'_> {
+    /// Returns whether the value is undefined, as empty lists and associative arrays are.
+    fn is_undefined(self) -> bool {
+        match self {
+            Self::Scalar(_) => false,
+            #[cfg(feature = "serde")]
+            Self::List(items) => items.is_empty(),
+            #[cfg(feature = "serde")]
+            Self::Map(entries) => entries.is_empty(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Part {
+    Literal(String),
+    Expression(Expression),
+}
+
+/// An expression such as `{?var,list*}`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Expression {
+    operator: Operator,
+    variables: Vec<Variable>,
+}
+
+impl Expression {
+    /// Parses the body of an expression, found at `offset` in the template.
+    fn parse(body: &str, offset: usize) -> Result<Self, UriError> {
+        let (operator, operator_len) = match body.as_bytes().first() {
+            Some(b'+') => (Operator::Reserved, 1),
+            Some(b'.') => (Operator::Label, 1),
+            Some(b'/') => (Operator::Path, 1),
+            Some(b';') => (Operator::PathParameter, 1),
+            Some(b'?') => (Operator::Query, 1),
+            Some(b'&') => (Operator::QueryContinuation, 1),
+            Some(b'#') => {
+                return Err(invalid_template(
+                    offset,
+                    "the fragment operator {#...} is not supported, as URI fragments are ignored by HTTP clients",
+                ));
+            }
+            _ => (Operator::Simple, 0),
+        };
+
+        let mut variables = Vec::new();
+        let mut variable_offset = offset + operator_len;
+
+        for spec in body[operator_len..].split(',') {
+            variables.push(Variable::parse(spec, variable_offset)?);
+            variable_offset += spec.len() + 1;
+        }
+
+        Ok(Self { operator, variables })
+    }
+
+    fn expand_into<'v>(&self, lookup: &impl Fn(&str) -> Option<Value<'v>>, out: &mut String) -> Result<(), UriError> {
+        let mut first = true;
+
+        for variable in &self.variables {
+            let Some(value) = lookup(&variable.name).filter(|value| !value.is_undefined()) else {
+                continue;
+            };
+
+            out.push_str(match (first, self.operator) {
+                // A continuation starts the query if no `?` did, such as an undefined `{?var}`.
+                (true, Operator::QueryContinuation) if !out.contains('?') => "?",
+                (true, operator) => operator.prefix(),
+                (false, operator) => operator.separator(),
+            });
+            first = false;
+
+            variable.expand_into(value, self.operator, out)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A variable of an expression, with its modifier.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Variable {
+    name: String,
+    prefix_len: Option<usize>,
+    explode: bool,
+}
+
+impl Variable {
+    /// Parses a variable such as `var`, `var:3` or `list*`, found at `offset` in the template.
+    fn parse(spec: &str, offset: usize) -> Result<Self, UriError> {
+        let name_len = spec.find(|c: char| !c.is_ascii_alphanumeric() && c != '_').unwrap_or(spec.len());
+        let (name, modifier) = spec.split_at(name_len);
+
+        if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(invalid_template(offset, "expected a variable name"));
+        }
+
+        let (prefix_len, explode) = match modifier.as_bytes() {
+            [] => (None, false),
+            [b'*'] => (None, true),
+            [b':', digits @ ..] => {
+                // RFC 6570 section 2.4.1 allows prefix lengths of up to four digits.
+                let prefix_len = Some(digits)
+                    .filter(|digits| digits.first().is_some_and(|digit| (b'1'..=b'9').contains(digit)))
+                    .filter(|digits| digits.len() <= 4 && digits.iter().all(u8::is_ascii_digit))
+                    .map(|digits| digits.iter().fold(0, |len, digit| len * 10 + usize::from(digit - b'0')))
+                    .ok_or_else(|| invalid_template(offset + name_len + 1, "prefix length must be between 1 and 9999"))?;
+                (Some(prefix_len), false)
+            }
+            _ => return Err(invalid_template(offset + name_len, "unexpected character in variable")),
+        };
+
+        Ok(Self {
+            name: name.to_owned(),
+            prefix_len,
+            explode,
+        })
+    }
+
+    #[cfg_attr(
+        not(feature = "serde"),
+        expect(clippy::unnecessary_wraps, reason = "only lists and associative arrays fail to expand")
+    )]
+    fn expand_into(&self, value: Value<'_>, operator: Operator, out: &mut String) -> Result<(), UriError> {
+        match value {
+            Value::Scalar(value) => {
+                let rendered = operator.render(value);
+                let rendered = self.prefix_len.map_or(rendered.as_str(), |len| prefix_of(&rendered, len));
+                operator.write_named(&self.name, rendered, out);
+            }
+            #[cfg(feature = "serde")]
+            Value::List(items) => {
+                self.reject_prefix()?;
+
+                if self.explode {
+                    for (index, item) in items.iter().enumerate() {
+                        if index > 0 {
+                            out.push_str(operator.separator());
+                        }
+                        operator.write_named(&self.name, &operator.render(item), out);
+                    }
+                } else {
+                    operator.write_name(&self.name, out);
+                    for (index, item) in items.iter().enumerate() {
+                        if index > 0 {
+                            out.push(',');
+                        }
+                        out.push_str(&operator.render(item));
+                    }
+                }
+            }
+            #[cfg(feature = "serde")]
+            Value::Map(entries) => {
+                self.reject_prefix()?;
+
+                if self.explode {
+                    for (index, (key, value)) in entries.iter().enumerate() {
+                        if index > 0 {
+                            out.push_str(operator.separator());
+                        }
+                        let key = operator.render(key);
+                        let value = operator.render(value);
+                        if operator.is_named() {
+                            operator.write_named(&key, &value, out);
+                        } else {
+                            out.push_str(&key);
+                            out.push('=');
+                            out.push_str(&value);
+                        }
+                    }
+                } else {
+                    operator.write_name(&self.name, out);
+                    for (index, (key, value)) in entries.iter().enumerate() {
+                        if index > 0 {
+                            out.push(',');
+                        }
+                        out.push_str(&operator.render(key));
+                        out.push(',');
+                        out.push_str(&operator.render(value));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fails for a prefix modifier on a composite value, which RFC 6570 section 2.4.1 does not allow.
+    #[cfg(feature = "serde")]
+    fn reject_prefix(&self) -> Result<(), UriError> {
+        match self.prefix_len {
+            Some(_) => Err(UriError::invalid_uri(format!(
+                "the prefix modifier of '{}' cannot be applied to a list or associative array",
+                self.name
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The operator of an expression, which determines how its values are delimited.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operator {
+    /// `{var}`
+    Simple,
+    /// `{+var}`
+    Reserved,
+    /// `{.var}`
+    Label,
+    /// `{/var}`
+    Path,
+    /// `{;var}`
+    PathParameter,
+    /// `{?var}`
+    Query,
+    /// `{&var}`
+    QueryContinuation,
+}
+
+impl Operator {
+    /// Returns the text written before the first defined value of the expression.
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Simple | Self::Reserved => "",
+            Self::Label => ".",
+            Self::Path => "/",
+            Self::PathParameter => ";",
+            Self::Query => "?",
+            Self::QueryContinuation => "&",
+        }
+    }
+
+    /// Returns the text written between the defined values of the expression.
+    fn separator(self) -> &'static str {
+        match self {
+            Self::Simple | Self::Reserved => ",",
+            Self::Label => ".",
+            Self::Path => "/",
+            Self::PathParameter => ";",
+            Self::Query | Self::QueryContinuation => "&",
+        }
+    }
+
+    /// Returns whether values are written as `name=value` pairs.
+    fn is_named(self) -> bool {
+        matches!(self, Self::PathParameter | Self::Query | Self::QueryContinuation)
+    }
+
+    fn render(self, value: &dyn UriSafe) -> String {
+        let mut rendered = String::new();
+        if self == Self::Reserved {
+            value.write_reserved(&mut rendered);
+        } else {
+            value.write_escaped(&mut rendered);
+        }
+        rendered
+    }
+
+    /// Writes `name=` if values of this operator are named.
+    #[cfg(feature = "serde")]
+    fn write_name(self, name: &str, out: &mut String) {
+        if self.is_named() {
+            out.push_str(name);
+            out.push('=');
+        }
+    }
+
+    /// Writes a rendered value, preceded by its name if values of this operator are named.
+    ///
+    /// Empty values of `{;var}` expressions are written without `=`, as RFC 6570 prescribes.
+    fn write_named(self, name: &str, rendered: &str, out: &mut String) {
+        if self.is_named() {
+            out.push_str(name);
+            if !rendered.is_empty() || self != Self::PathParameter {
+                out.push('=');
+            }
+        }
+        out.push_str(rendered);
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use ohno::ErrorExt;
+
+    use super::*;
+    use crate::EscapedString;
+
+    fn expand(template: &str, values: &[(&str, &dyn UriSafe)]) -> String {
+        let values: HashMap<_, _> = values.iter().copied().collect();
+        let path = Template::parse(template).unwrap().expand(&values).unwrap();
+        path.to_string().declassify_ref().clone()
+    }
+
+    fn parse_error(template: &str) -> String {
+        Template::parse(template).unwrap_err().message()
+    }
+
+    #[test]
+    fn expands_operators() {
+        // Named apart from the variables, so the templates are not mistaken for format strings.
+        let value = EscapedString::from_static("value");
+        let greeting = EscapedString::escape("Hello World!");
+        let nothing = EscapedString::from_static("");
+        let values: [(&str, &dyn UriSafe); 5] = [
+            ("var", &value),
+            ("hello", &greeting),
+            ("empty", &nothing),
+            ("x", &1024_u32),
+            ("y", &768_u32),
+        ];
+
+        assert_eq!(expand("/{var}", &values), "/value");
+        assert_eq!(expand("/{hello}", &values), "/Hello%20World%21");
+        assert_eq!(expand("/{+hello}", &values), "/Hello%20World%21");
+        assert_eq!(expand("/{x,y}", &values), "/1024,768");
+        assert_eq!(expand("/map{.var,x}", &values), "/map.value.1024");
+        assert_eq!(expand("{/var,x}/here", &values), "/value/1024/here");
+        assert_eq!(expand("/map{;x,y,empty}", &values), "/map;x=1024;y=768;empty");
+        assert_eq!(expand("/map{?x,y,empty}", &values), "/map?x=1024&y=768&empty=");
+        assert_eq!(expand("/map?fixed=yes{&x}", &values), "/map?fixed=yes&x=1024");
+        assert_eq!(expand("/{var:3}{/var:30}", &values), "/val/value");
+        assert_eq!(expand("{/var*}", &values), "/value");
+    }
+
+    #[test]
+    fn omits_undefined_variables() {
+        let values: [(&str, &dyn UriSafe); 1] = [("x", &1024_u32)];
+
+        assert_eq!(expand("/items{?undef,x}", &values), "/items?x=1024");
+        assert_eq!(expand("/items{?undef}{&x}", &values), "/items?x=1024");
+        assert_eq!(expand("/items{&x}", &values), "/items?x=1024");
+        assert_eq!(expand("/items?a=b{&x}", &values), "/items?a=b&x=1024");
+        assert_eq!(expand("/items{/undef}{?undef}", &values), "/items");
+    }
+
+    #[test]
+    fn rejects_invalid_expansions() {
+        let values: HashMap<&str, &dyn UriSafe> = HashMap::new();
+        Template::parse("/a b").unwrap().expand(&values).unwrap_err();
+    }
+
+    #[test]
+    fn lists_variables() {
+        let template = Template::parse("/users/{id}{?name,tags*}{&id}").unwrap();
+        assert_eq!(template.variables().collect::<Vec<_>>(), ["id", "name", "tags", "id"]);
+        assert_eq!(template.as_str(), "/users/{id}{?name,tags*}{&id}");
+        assert_eq!(template.to_string(), "/users/{id}{?name,tags*}{&id}");
+        assert_eq!("/users/{id}".parse::<Template>().unwrap(), Template::parse("/users/{id}").unwrap());
+    }
+
+    #[test]
+    fn rejects_invalid_templates() {
+        assert_eq!(
+            parse_error(""),
+            "invalid URI template at offset 0: the template must start with '/'"
+        );
+        assert_eq!(
+            parse_error("{var}"),
+            "invalid URI template at offset 0: the template must start with '/'"
+        );
+        assert_eq!(
+            parse_error("/{var"),
+            "invalid URI template at offset 1: the expression is not closed"
+        );
+        assert_eq!(
+            parse_error("/var}"),
+            "invalid URI template at offset 4: unexpected '}' outside of an expression"
+        );
+        assert_eq!(parse_error("/{}"), "invalid URI template at offset 2: expected a variable name");
+        assert_eq!(parse_error("/{a,,b}"), "invalid URI template at offset 4: expected a variable name");
+        assert_eq!(parse_error("/{1var}"), "invalid URI template at offset 2: expected a variable name");
+        assert_eq!(
+            parse_error("/{a{b}"),
+            "invalid URI template at offset 3: unexpected character in variable"
+        );
+        assert_eq!(
+            parse_error("/{var*:3}"),
+            "invalid URI template at offset 5: unexpected character in variable"
+        );
+        assert_eq!(
+            parse_error("/{#var}"),
+            "invalid URI template at offset 2: the fragment operator {#...} is not supported, as URI fragments are ignored by HTTP clients"
+        );
+
+        for template in ["/{var:}", "/{var:0}", "/{var:01}", "/{var:10000}", "/{var:3*}"] {
+            assert_eq!(
+                parse_error(template),
+                "invalid URI template at offset 6: prefix length must be between 1 and 9999",
+                "{template}"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_prefix_lengths() {
+        let template = Template::parse("/{a:1,b:9999}").unwrap();
+        let Part::Expression(expression) = &template.parts[1] else {
+            panic!("the second part is an expression");
+        };
+        assert_eq!(expression.variables[0].prefix_len, Some(1));
+        assert_eq!(expression.variables[1].prefix_len, Some(9999));
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde_tests {
+        use super::*;
+
+        #[test]
+        fn template_roundtrip() {
+            let original = Template::parse("/users/{id}{?tags*}").unwrap();
+            let json = serde_json::to_string(&original).unwrap();
+            assert_eq!(json, r#""/users/{id}{?tags*}""#);
+            let deserialized: Template = serde_json::from_str(&json).unwrap();
+            assert_eq!(original, deserialized);
+        }
+
+        #[test]
+        fn template_deserialize_rejects_invalid() {
+            serde_json::from_str::<Template>(r#""/users/{#id}""#).unwrap_err();
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Converts a runtime [`Template`] into an `OpenAPI` path template.
+
+use super::{Operator, Part, Template};
+use crate::UriError;
+
+impl Template {
+    /// Returns the path of the template as an `OpenAPI` path template, such as `/users/{id}`.
+    ///
+    /// `OpenAPI` path templates are plain RFC 6570 templates with only simple expressions, so
+    /// they can be parsed with [`Template::parse()`] as they are. This converts the other way:
+    /// `{/var}` becomes `/{var}` and `{.var}` becomes `.{var}`, while simple expressions are
+    /// kept. The query of the template is left out, as `OpenAPI` describes query parameters
+    /// separately from the path.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UriError`] if the path has an expression that `OpenAPI` path templates can't
+    /// express: reserved (`{+var}`) and path parameter (`{;var}`) expressions, simple expressions
+    /// with several variables, and variables with a prefix or explode modifier.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use templated_uri::Template;
+    ///
+    /// let template = Template::parse("/users/{user_id}/files{/file_id}{.format}{?tag*}")?;
+    /// assert_eq!(
+    ///     template.to_openapi()?,
+    ///     "/users/{user_id}/files/{file_id}.{format}"
+    /// );
+    ///
+    /// assert!(Template::parse("/files/{+path}")?.to_openapi().is_err());
+    /// # Ok::<(), templated_uri::UriError>(())
+    /// ```
+    pub fn to_openapi(&self) -> Result<String, UriError> {
+        let mut out = String::with_capacity(self.source.len());
+
+        for part in &self.parts {
+            match part {
+                Part::Literal(literal) => {
+                    if let Some((path, _query)) = literal.split_once('?') {
+                        out.push_str(path);
+                        break;
+                    }
+                    out.push_str(literal);
+                }
+                Part::Expression(expression) => {
+                    let prefix = match expression.operator {
+                        Operator::Simple if expression.variables.len() == 1 => "",
+                        Operator::Path | Operator::Label => expression.operator.prefix(),
+                        Operator::Query | Operator::QueryContinuation => break,
+                        Operator::Simple | Operator::Reserved | Operator::PathParameter => {
+                            return Err(not_expressible(&expression.variables[0].name));
+                        }
+                    };
+
+                    for variable in &expression.variables {
+                        if variable.prefix_len.is_some() || variable.explode {
+                            return Err(not_expressible(&variable.name));
+                        }
+                        out.push_str(prefix);
+                        out.push('{');
+                        out.push_str(&variable.name);
+                        out.push('}');
+                    }
+                }
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+fn not_expressible(name: &str) -> UriError {
+    UriError::invalid_uri(format!(
+        "the expression of '{name}' cannot be expressed in an OpenAPI path template"
+    ))
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use ohno::ErrorExt;
+
+    use super::*;
+
+    fn to_openapi(template: &str) -> Result<String, String> {
+        Template::parse(template).unwrap().to_openapi().map_err(|err| err.message())
+    }
+
+    #[test]
+    fn converts_path_expressions() {
+        assert_eq!(to_openapi("/users/{id}").unwrap(), "/users/{id}");
+        assert_eq!(
+            to_openapi("{/tenant}/users{/id,version}").unwrap(),
+            "/{tenant}/users/{id}/{version}"
+        );
+        assert_eq!(to_openapi("/files/{name}{.ext}").unwrap(), "/files/{name}.{ext}");
+        assert_eq!(to_openapi("/users/{id}:activate").unwrap(), "/users/{id}:activate");
+    }
+
+    #[test]
+    fn leaves_out_query() {
+        assert_eq!(to_openapi("/search{?query,page}").unwrap(), "/search");
+        assert_eq!(to_openapi("/search?api-version=1{&query}").unwrap(), "/search");
+        assert_eq!(to_openapi("/users/{id}?fields={fields}").unwrap(), "/users/{id}");
+    }
+
+    #[test]
+    fn rejects_inexpressible_expressions() {
+        for template in ["/{+path}", "/map{;x}", "/{x,y}", "{/id:3}", "{/path*}"] {
+            let err = to_openapi(template).unwrap_err();
+            assert!(err.contains("cannot be expressed in an OpenAPI path template"), "{template}: {err}");
+        }
+    }
+}
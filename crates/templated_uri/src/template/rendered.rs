@@ -0,0 +1,275 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Serializes `#[templated(serde)]` structs as their rendered path and query, and deserializes
+//! them by matching it against their template.
+//!
+//! Deserialization errors never include the rendered path and query or the values captured
+//! from it, as they can be sensitive.
+
+use serde::de::value::{Error, SeqDeserializer};
+use serde::de::{self, DeserializeOwned, Deserializer, IntoDeserializer, Unexpected, Visitor};
+use serde::{Serializer, forward_to_deserialize_any};
+
+use super::{Captures, Template};
+use crate::PathAndQueryTemplate;
+
+/// Serializes `value` as its rendered path and query.
+#[doc(hidden)]
+pub fn serialize_rendered<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: PathAndQueryTemplate + ?Sized,
+    S: Serializer,
+{
+    serializer.serialize_str(&value.render())
+}
+
+/// Parses the template of a `#[templated(serde)]` struct.
+#[doc(hidden)]
+pub fn parse_template<E: de::Error>(template: &str) -> Result<Template, E> {
+    Template::parse(template).map_err(E::custom)
+}
+
+/// Matches a rendered path and query against `template`, where the non-exploded variables in
+/// `lists` are lists whose items are joined with commas.
+#[doc(hidden)]
+pub fn match_rendered<'a, E: de::Error>(template: &'a Template, rendered: &'a str, lists: &[&str]) -> Result<Captures<'a>, E> {
+    template
+        .match_path_with_lists(rendered, lists)
+        .ok_or_else(|| E::custom(format_args!("the path and query does not match the template `{template}`")))
+}
+
+/// Deserializes the field of the variable `name` from the values captured for it.
+///
+/// The values are deserialized as they were rendered, still percent-encoded, so that an
+/// [`EscapedString`](crate::EscapedString) field is restored as it was. Numbers and booleans
+/// are parsed from their text, a missing value deserializes as `None` and the items of a
+/// list are the captured values split at commas.
+#[doc(hidden)]
+pub fn deserialize_variable<T: DeserializeOwned, E: de::Error>(captures: &Captures<'_>, name: &str) -> Result<T, E> {
+    let values = captures.get_all(name).collect();
+    // The error of the field is dropped, as it can include the captured values.
+    T::deserialize(VariableDeserializer { values })
+        .map_err(|_sensitive| E::custom(format_args!("missing or invalid value for the template variable `{name}`")))
+}
+
+/// Forwards the `deserialize_*` methods to the single captured value.
+macro_rules! forward_to_value {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                self.value()?.$method(visitor)
+            }
+        )*
+    };
+}
+
+/// The values captured for a variable: none if it is undefined, one per item if it is an
+/// exploded list.
+struct VariableDeserializer<'a> {
+    values: Vec<&'a str>,
+}
+
+impl<'a> VariableDeserializer<'a> {
+    fn value(self) -> Result<ValueDeserializer<'a>, Error> {
+        match self.values.as_slice() {
+            [value] => Ok(ValueDeserializer(value)),
+            [] => Err(de::Error::custom("missing value")),
+            _ => Err(de::Error::custom("expected a single value")),
+        }
+    }
+}
+
+impl<'de> Deserializer<'de> for VariableDeserializer<'_> {
+    type Error = Error;
+
+    forward_to_value! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64 deserialize_u128
+        deserialize_f32 deserialize_f64 deserialize_char deserialize_str deserialize_string
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.values.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let items = self.values.into_iter().flat_map(|value| value.split(',')).map(ValueDeserializer);
+        SeqDeserializer::new(items).deserialize_any(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.value()?.deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bytes byte_buf unit unit_struct tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Parses the `deserialize_*` methods of scalars from the captured text.
+macro_rules! parse_scalars {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                match self.0.parse() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(_) => Err(de::Error::invalid_value(Unexpected::Str(self.0), &visitor)),
+                }
+            }
+        )*
+    };
+}
+
+/// A single captured value, or a single item of a list.
+struct ValueDeserializer<'a>(&'a str);
+
+impl<'de> Deserializer<'de> for ValueDeserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_str(self.0)
+    }
+
+    parse_scalars! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_enum(IntoDeserializer::<Error>::into_deserializer(self.0))
+    }
+
+    forward_to_deserialize_any! {
+        char str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl IntoDeserializer<'_, Error> for ValueDeserializer<'_> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::EscapedString;
+
+    fn deserialize<T: DeserializeOwned>(template: &str, rendered: &str, name: &str) -> Result<T, Error> {
+        let template: Template = parse_template(template)?;
+        let captures = match_rendered(&template, rendered, &[name])?;
+        deserialize_variable(&captures, name)
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Order {
+        Asc,
+        Desc,
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Id(u64);
+
+    #[test]
+    fn scalars_are_parsed_from_text() {
+        assert_eq!(deserialize::<u32>("/items/{id}", "/items/42", "id").unwrap(), 42);
+        assert_eq!(deserialize::<i8>("/items/{id}", "/items/-3", "id").unwrap(), -3);
+        assert!((deserialize::<f64>("/items/{id}", "/items/1.5", "id").unwrap() - 1.5).abs() < f64::EPSILON);
+        assert!(deserialize::<bool>("/items{?all}", "/items?all=true", "all").unwrap());
+        assert_eq!(deserialize::<char>("/items/{id}", "/items/x", "id").unwrap(), 'x');
+        assert_eq!(deserialize::<Id>("/items/{id}", "/items/7", "id").unwrap(), Id(7));
+        assert_eq!(
+            deserialize::<Order>("/items{?order}", "/items?order=desc", "order").unwrap(),
+            Order::Desc
+        );
+        assert_eq!(
+            deserialize::<Order>("/items{?order}", "/items?order=asc", "order").unwrap(),
+            Order::Asc
+        );
+    }
+
+    #[test]
+    fn strings_stay_percent_encoded() {
+        let value = deserialize::<EscapedString>("/items/{id}", "/items/a%20b", "id").unwrap();
+        assert_eq!(value.as_str(), "a%20b");
+
+        let value = deserialize::<String>("/items{+rest}", "/items/a/b", "rest").unwrap();
+        assert_eq!(value, "/a/b");
+    }
+
+    #[test]
+    fn options_and_lists() {
+        assert_eq!(deserialize::<Option<u32>>("/items{?limit}", "/items", "limit").unwrap(), None);
+        assert_eq!(
+            deserialize::<Option<u32>>("/items{?limit}", "/items?limit=5", "limit").unwrap(),
+            Some(5)
+        );
+
+        assert_eq!(deserialize::<Vec<u32>>("/items/{ids}", "/items/1,2,3", "ids").unwrap(), [1, 2, 3]);
+        assert_eq!(deserialize::<Vec<u32>>("/items{/ids*}", "/items/1/2", "ids").unwrap(), [1, 2]);
+        assert_eq!(
+            deserialize::<Vec<u32>>("/items{?ids*}", "/items?ids=1&ids=2", "ids").unwrap(),
+            [1, 2]
+        );
+        assert_eq!(deserialize::<Vec<u32>>("/items{?ids}", "/items", "ids").unwrap(), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn errors_do_not_include_values() {
+        let error = deserialize::<u32>("/items/{id}", "/items/SECRET", "id").unwrap_err();
+        assert_eq!(error.to_string(), "missing or invalid value for the template variable `id`");
+
+        let error = deserialize::<u32>("/items{?id}", "/items", "id").unwrap_err();
+        assert_eq!(error.to_string(), "missing or invalid value for the template variable `id`");
+
+        let error = deserialize::<u32>("/items/{id}", "/other/SECRET", "id").unwrap_err();
+        assert_eq!(error.to_string(), "the path and query does not match the template `/items/{id}`");
+
+        deserialize::<BTreeMap<String, String>>("/items{?keys*}", "/items?a=b", "keys").unwrap_err();
+        deserialize::<u32>("/items{/ids*}", "/items/1/2", "ids").unwrap_err();
+    }
+}
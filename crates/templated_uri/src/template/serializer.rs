@@ -0,0 +1,737 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Collects the variables of a runtime [`Template`](super::Template) from a serializable value.
+
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+use serde::ser::{self, Impossible, Serialize};
+
+use super::{UriSafe, Value};
+use crate::escaped::is_unreserved_byte;
+use crate::{EscapedString, UriError};
+
+/// Collects the fields of `values`, which must serialize as a struct or a map, by name.
+///
+/// Undefined fields are left out.
+pub(super) fn collect(values: &impl Serialize) -> Result<HashMap<String, Collected>, UriError> {
+    values
+        .serialize(VariablesSerializer)
+        .map_err(|error| UriError::invalid_uri(format!("cannot expand the template with the serialized values: {error}")))
+}
+
+/// A serialized string, number or other scalar, encoded when the template is expanded.
+#[derive(Debug)]
+pub(super) struct Text(String);
+
+impl UriSafe for Text {
+    fn write_escaped(&self, out: &mut String) {
+        out.push_str(EscapedString::escape(self.0.as_str()).as_str());
+    }
+
+    /// Keeps unreserved and reserved characters as well as percent-encoded triplets, as the
+    /// reserved expansion prescribes, except for `#`: URI fragments are not supported.
+    fn write_reserved(&self, out: &mut String) {
+        const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+        let bytes = self.0.as_bytes();
+        let mut index = 0;
+
+        while index < bytes.len() {
+            let byte = bytes[index];
+
+            if byte == b'%'
+                && bytes
+                    .get(index + 1..index + 3)
+                    .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+            {
+                out.push_str(&self.0[index..index + 3]);
+                index += 3;
+                continue;
+            }
+
+            if is_unreserved_byte(byte) || b":/?[]@!$&'()*+,;=".contains(&byte) {
+                out.push(char::from(byte));
+            } else {
+                out.push('%');
+                out.push(char::from(HEX[usize::from(byte >> 4)]));
+                out.push(char::from(HEX[usize::from(byte & 0x0f)]));
+            }
+            index += 1;
+        }
+    }
+}
+
+/// The value of a serialized field.
+#[derive(Debug)]
+pub(super) enum Collected {
+    Scalar(Text),
+    List(Vec<Text>),
+    Map(Vec<(Text, Text)>),
+}
+
+impl Collected {
+    pub(super) fn as_value(&self) -> Value<'_> {
+        match self {
+            Self::Scalar(text) => Value::Scalar(text),
+            Self::List(items) => Value::List(items),
+            Self::Map(entries) => Value::Map(entries),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Error(String);
+
+impl Error {
+    fn unsupported(what: &str) -> Self {
+        Self(format!("{what} cannot fill a template variable"))
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// Implements the [`Serializer`](ser::Serializer) methods of scalar types by passing their
+/// string form to the `scalar` method of the serializer.
+macro_rules! serialize_scalars {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, value: $ty) -> Result<Self::Ok, Self::Error> {
+                self.scalar(value.to_string())
+            }
+        )*
+    };
+}
+
+macro_rules! serialize_all_scalars {
+    () => {
+        serialize_scalars!(
+            serialize_bool(bool),
+            serialize_i8(i8),
+            serialize_i16(i16),
+            serialize_i32(i32),
+            serialize_i64(i64),
+            serialize_i128(i128),
+            serialize_u8(u8),
+            serialize_u16(u16),
+            serialize_u32(u32),
+            serialize_u64(u64),
+            serialize_u128(u128),
+            serialize_f32(f32),
+            serialize_f64(f64),
+            serialize_char(char),
+            serialize_str(&str),
+        );
+
+        fn serialize_bytes(self, _value: &[u8]) -> Result<Self::Ok, Self::Error> {
+            Err(Error::unsupported("a byte array"))
+        }
+
+        fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<Self::Ok, Self::Error> {
+            self.scalar(variant.to_owned())
+        }
+
+        fn serialize_newtype_variant<T: Serialize + ?Sized>(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _value: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            Err(Error::unsupported("an enum variant with data"))
+        }
+
+        fn serialize_tuple_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+            Err(Error::unsupported("an enum variant with data"))
+        }
+
+        fn serialize_struct_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStructVariant, Self::Error> {
+            Err(Error::unsupported("an enum variant with data"))
+        }
+    };
+}
+
+/// Serializes the struct or map holding the variables.
+struct VariablesSerializer;
+
+impl VariablesSerializer {
+    #[expect(clippy::unused_self, reason = "called by the serializer methods of all scalar types")]
+    fn scalar(self, _text: String) -> Result<HashMap<String, Collected>, Error> {
+        Err(Error::expected_variables())
+    }
+}
+
+impl Error {
+    fn expected_variables() -> Self {
+        Self("the template variables must serialize as a struct or a map".to_owned())
+    }
+}
+
+impl ser::Serializer for VariablesSerializer {
+    type Ok = HashMap<String, Collected>;
+    type Error = Error;
+    type SerializeSeq = Impossible<Self::Ok, Error>;
+    type SerializeTuple = Impossible<Self::Ok, Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Error>;
+    type SerializeMap = VariablesCollector;
+    type SerializeStruct = VariablesCollector;
+    type SerializeStructVariant = Impossible<Self::Ok, Error>;
+
+    serialize_all_scalars!();
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(Error::expected_variables())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(Error::expected_variables())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(HashMap::new())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(Error::expected_variables())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(Error::expected_variables())
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(Error::expected_variables())
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(VariablesCollector {
+            variables: HashMap::with_capacity(len.unwrap_or_default()),
+            name: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeStruct, Self::Error> {
+        self.serialize_map(Some(len))
+    }
+}
+
+struct VariablesCollector {
+    variables: HashMap<String, Collected>,
+    // The name of the variable whose value is serialized next.
+    name: Option<String>,
+}
+
+impl VariablesCollector {
+    fn insert<T: Serialize + ?Sized>(&mut self, name: String, value: &T) -> Result<(), Error> {
+        if let Some(value) = value.serialize(ValueSerializer)? {
+            self.variables.insert(name, value);
+        }
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for VariablesCollector {
+    type Ok = HashMap<String, Collected>;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        let name = key
+            .serialize(ScalarSerializer)?
+            .ok_or_else(|| Error::unsupported("an undefined key"))?;
+        self.name = Some(name.0);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let name = self
+            .name
+            .take()
+            .ok_or_else(|| Error("a value was serialized before its key".to_owned()))?;
+        self.insert(name, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.variables)
+    }
+}
+
+impl ser::SerializeStruct for VariablesCollector {
+    type Ok = HashMap<String, Collected>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> {
+        self.insert(key.to_owned(), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.variables)
+    }
+}
+
+/// Serializes the value of a variable, returning `None` for undefined values.
+struct ValueSerializer;
+
+impl ValueSerializer {
+    #[expect(clippy::unnecessary_wraps, reason = "shares its signature with the other serializer methods")]
+    #[expect(clippy::unused_self, reason = "called by the serializer methods of all scalar types")]
+    fn scalar(self, text: String) -> Result<Option<Collected>, Error> {
+        Ok(Some(Collected::Scalar(Text(text))))
+    }
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Option<Collected>;
+    type Error = Error;
+    type SerializeSeq = ListCollector;
+    type SerializeTuple = ListCollector;
+    type SerializeTupleStruct = ListCollector;
+    type SerializeTupleVariant = Impossible<Self::Ok, Error>;
+    type SerializeMap = MapCollector;
+    type SerializeStruct = MapCollector;
+    type SerializeStructVariant = Impossible<Self::Ok, Error>;
+
+    serialize_all_scalars!();
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(ListCollector {
+            items: Vec::with_capacity(len.unwrap_or_default()),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(MapCollector {
+            entries: Vec::with_capacity(len.unwrap_or_default()),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeStruct, Self::Error> {
+        self.serialize_map(Some(len))
+    }
+}
+
+/// Serializes an item of a list or associative array, returning `None` for undefined items.
+struct ScalarSerializer;
+
+impl ScalarSerializer {
+    #[expect(clippy::unnecessary_wraps, reason = "shares its signature with the other serializer methods")]
+    #[expect(clippy::unused_self, reason = "called by the serializer methods of all scalar types")]
+    fn scalar(self, text: String) -> Result<Option<Text>, Error> {
+        Ok(Some(Text(text)))
+    }
+}
+
+impl ser::Serializer for ScalarSerializer {
+    type Ok = Option<Text>;
+    type Error = Error;
+    type SerializeSeq = Impossible<Self::Ok, Error>;
+    type SerializeTuple = Impossible<Self::Ok, Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Error>;
+    type SerializeMap = Impossible<Self::Ok, Error>;
+    type SerializeStruct = Impossible<Self::Ok, Error>;
+    type SerializeStructVariant = Impossible<Self::Ok, Error>;
+
+    serialize_all_scalars!();
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(Error::unsupported("a nested list"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(Error::unsupported("a nested list"))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(Error::unsupported("a nested list"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(Error::unsupported("a nested associative array"))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(Error::unsupported("a nested associative array"))
+    }
+}
+
+struct ListCollector {
+    items: Vec<Text>,
+}
+
+impl ListCollector {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.items.extend(value.serialize(ScalarSerializer)?);
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for ListCollector {
+    type Ok = Option<Collected>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(Collected::List(self.items)))
+    }
+}
+
+impl ser::SerializeTuple for ListCollector {
+    type Ok = Option<Collected>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(Collected::List(self.items)))
+    }
+}
+
+impl ser::SerializeTupleStruct for ListCollector {
+    type Ok = Option<Collected>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(Collected::List(self.items)))
+    }
+}
+
+struct MapCollector {
+    entries: Vec<(Text, Text)>,
+    // The key of the entry whose value is serialized next.
+    key: Option<Text>,
+}
+
+impl MapCollector {
+    /// Adds an entry, unless its value is undefined.
+    fn insert<T: Serialize + ?Sized>(&mut self, key: Text, value: &T) -> Result<(), Error> {
+        if let Some(value) = value.serialize(ScalarSerializer)? {
+            self.entries.push((key, value));
+        }
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for MapCollector {
+    type Ok = Option<Collected>;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.key = Some(
+            key.serialize(ScalarSerializer)?
+                .ok_or_else(|| Error::unsupported("an undefined key"))?,
+        );
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error("a value was serialized before its key".to_owned()))?;
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(Collected::Map(self.entries)))
+    }
+}
+
+impl ser::SerializeStruct for MapCollector {
+    type Ok = Option<Collected>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> {
+        self.insert(Text(key.to_owned()), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(Collected::Map(self.entries)))
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use ohno::ErrorExt;
+    use serde_json::json;
+
+    use crate::Template;
+
+    fn expand(template: &str, values: &serde_json::Value) -> String {
+        let path = Template::parse(template).unwrap().expand_serialized(values).unwrap();
+        path.to_string().declassify_ref().clone()
+    }
+
+    /// The example variables of RFC 6570 section 3.2.
+    fn rfc_values() -> serde_json::Value {
+        json!({
+            "count": ["one", "two", "three"],
+            "dom": ["example", "com"],
+            "dub": "me/too",
+            "hello": "Hello World!",
+            "half": "50%",
+            "var": "value",
+            "who": "fred",
+            "path": "/foo/bar",
+            "list": ["red", "green", "blue"],
+            "keys": {"comma": ",", "dot": ".", "semi": ";"},
+            "v": "6",
+            "x": "1024",
+            "y": "768",
+            "empty": "",
+            "empty_keys": {},
+            "undef": null
+        })
+    }
+
+    #[test]
+    fn expands_rfc_examples() {
+        let values = rfc_values();
+
+        let examples = [
+            ("/{var}", "/value"),
+            ("/{hello}", "/Hello%20World%21"),
+            ("/{half}", "/50%25"),
+            ("/O{empty}X", "/OX"),
+            ("/O{undef}X", "/OX"),
+            ("/{x,y}", "/1024,768"),
+            ("/{x,hello,y}", "/1024,Hello%20World%21,768"),
+            ("/?{x,empty}", "/?1024,"),
+            ("/?{x,undef}", "/?1024"),
+            ("/{var:3}", "/val"),
+            ("/{var:30}", "/value"),
+            ("/{list}", "/red,green,blue"),
+            ("/{list*}", "/red,green,blue"),
+            ("/{keys}", "/comma,%2C,dot,.,semi,%3B"),
+            ("/{keys*}", "/comma=%2C,dot=.,semi=%3B"),
+            ("/x{+path}/here", "/x/foo/bar/here"),
+            ("/{+hello}", "/Hello%20World!"),
+            ("/{+half}", "/50%25"),
+            ("/here?ref={+path}", "/here?ref=/foo/bar"),
+            ("/up{+path}{var}/here", "/up/foo/barvalue/here"),
+            ("/x{+path:6}/here", "/x/foo/b/here"),
+            ("/{+list*}", "/red,green,blue"),
+            ("/{+keys*}", "/comma=,,dot=.,semi=;"),
+            ("/X{.var}", "/X.value"),
+            ("/X{.x,y}", "/X.1024.768"),
+            ("/X{.list*}", "/X.red.green.blue"),
+            ("/X{.empty_keys}", "/X"),
+            ("/X{.empty_keys*}", "/X"),
+            ("{/who}", "/fred"),
+            ("{/who,who}", "/fred/fred"),
+            ("{/half,who}", "/50%25/fred"),
+            ("{/who,dub}", "/fred/me%2Ftoo"),
+            ("{/var,x}/here", "/value/1024/here"),
+            ("{/var:1,var}", "/v/value"),
+            ("{/list}", "/red,green,blue"),
+            ("{/list*}", "/red/green/blue"),
+            ("{/list*,path:4}", "/red/green/blue/%2Ffoo"),
+            ("{/keys}", "/comma,%2C,dot,.,semi,%3B"),
+            ("{/keys*}", "/comma=%2C/dot=./semi=%3B"),
+            ("/x{;who}", "/x;who=fred"),
+            ("/x{;half}", "/x;half=50%25"),
+            ("/x{;empty}", "/x;empty"),
+            ("/x{;v,empty,who}", "/x;v=6;empty;who=fred"),
+            ("/x{;v,bar,who}", "/x;v=6;who=fred"),
+            ("/x{;x,y}", "/x;x=1024;y=768"),
+            ("/x{;x,y,empty}", "/x;x=1024;y=768;empty"),
+            ("/x{;x,y,undef}", "/x;x=1024;y=768"),
+            ("/x{;hello:5}", "/x;hello=Hello"),
+            ("/x{;list}", "/x;list=red,green,blue"),
+            ("/x{;list*}", "/x;list=red;list=green;list=blue"),
+            ("/x{;keys}", "/x;keys=comma,%2C,dot,.,semi,%3B"),
+            ("/x{;keys*}", "/x;comma=%2C;dot=.;semi=%3B"),
+            ("/{?who}", "/?who=fred"),
+            ("/{?half}", "/?half=50%25"),
+            ("/{?x,y}", "/?x=1024&y=768"),
+            ("/{?x,y,empty}", "/?x=1024&y=768&empty="),
+            ("/{?x,y,undef}", "/?x=1024&y=768"),
+            ("/{?var:3}", "/?var=val"),
+            ("/{?list}", "/?list=red,green,blue"),
+            ("/{?list*}", "/?list=red&list=green&list=blue"),
+            ("/{?keys}", "/?keys=comma,%2C,dot,.,semi,%3B"),
+            ("/{?keys*}", "/?comma=%2C&dot=.&semi=%3B"),
+            // Unlike in RFC 6570, a continuation starts the query if there is none to continue.
+            ("/{&who}", "/?who=fred"),
+            ("/{&half}", "/?half=50%25"),
+            ("/?fixed=yes{&x}", "/?fixed=yes&x=1024"),
+            ("/{&x,y,empty}", "/?x=1024&y=768&empty="),
+            ("/{&var:3}", "/?var=val"),
+            ("/{&list}", "/?list=red,green,blue"),
+            ("/{&list*}", "/?list=red&list=green&list=blue"),
+            ("/{&keys}", "/?keys=comma,%2C,dot,.,semi,%3B"),
+            ("/{&keys*}", "/?comma=%2C&dot=.&semi=%3B"),
+        ];
+
+        for (template, expected) in examples {
+            assert_eq!(expand(template, &values), expected, "{template}");
+        }
+    }
+
+    #[test]
+    fn reserved_expansion_encodes_fragments_and_invalid_escapes() {
+        let values = json!({"path": "/a#b/%zz/%41/ü"});
+
+        assert_eq!(expand("/x{+path}", &values), "/x/a%23b/%25zz/%41/%C3%BC");
+    }
+
+    #[test]
+    fn expands_struct_fields() {
+        #[derive(serde::Serialize)]
+        struct Filter {
+            color: &'static str,
+            size: Option<u32>,
+        }
+
+        #[derive(serde::Serialize)]
+        enum Order {
+            Newest,
+        }
+
+        #[derive(serde::Serialize)]
+        struct Search {
+            id: u64,
+            active: bool,
+            order: Order,
+            tags: (&'static str, char),
+            filter: Filter,
+            page: Option<u32>,
+            limit: Option<u32>,
+        }
+
+        let search = Search {
+            id: 7,
+            active: true,
+            order: Order::Newest,
+            tags: ("a b", 'c'),
+            filter: Filter { color: "red", size: None },
+            page: None,
+            limit: Some(10),
+        };
+
+        let path = Template::parse("/items/{id}{?active,order,tags*,filter*,page,limit}")
+            .unwrap()
+            .expand_serialized(&search)
+            .unwrap();
+        assert_eq!(
+            path.to_string().declassify_ref(),
+            "/items/7?active=true&order=Newest&tags=a%20b&tags=c&color=red&limit=10"
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_values() {
+        let error = |template: &str, values: &serde_json::Value| {
+            Template::parse(template).unwrap().expand_serialized(values).unwrap_err().message()
+        };
+
+        assert_eq!(
+            error("/{var}", &json!(["value"])),
+            "cannot expand the template with the serialized values: the template variables must serialize as a struct or a map"
+        );
+        assert_eq!(
+            error("/{var}", &json!({"var": [["nested"]]})),
+            "cannot expand the template with the serialized values: a nested list cannot fill a template variable"
+        );
+        assert_eq!(
+            error("/{var}", &json!({"var": {"key": {"nested": 1}}})),
+            "cannot expand the template with the serialized values: a nested associative array cannot fill a template variable"
+        );
+        assert_eq!(
+            error("/{list:3}", &json!({"list": ["red"]})),
+            "the prefix modifier of 'list' cannot be applied to a list or associative array"
+        );
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use crate::{PathAndQuery, PathAndQueryTemplate, UriError};
+
+/// The telemetry labels of the templates an application sends requests with.
+///
+/// Per-route telemetry tags metrics with the label of the template of a request, or with the
+/// template itself if it has no label. The number of distinct tags stays bounded only as long
+/// as every request uses one of a known set of templates. A `TemplateRegistry` collects those
+/// labels at startup, and validates that requests use one of them, for example in debug builds
+/// or in tests.
+///
+/// Templated types are registered with [`register`](Self::register), which collects the labels
+/// of all variants of an enum, and the templates only known at runtime with
+/// [`register_label`](Self::register_label).
+///
+/// # Examples
+///
+/// ```rust
+/// use templated_uri::{PathAndQuery, TemplateRegistry, templated};
+///
+/// #[templated(template = "/users/{user_id}", label = "user", unredacted)]
+/// struct UserPath {
+///     user_id: u32,
+/// }
+///
+/// #[templated]
+/// enum ServiceApi {
+///     #[templated(template = "/healthz")]
+///     HealthCheck,
+///     User(UserPath),
+/// }
+///
+/// let registry = TemplateRegistry::new().register::<ServiceApi>();
+/// assert!(registry.contains("user"));
+/// assert!(registry.contains("/healthz"));
+///
+/// assert!(
+///     registry
+///         .validate(&PathAndQuery::from_template(UserPath { user_id: 42 }))
+///         .is_ok()
+/// );
+/// assert!(
+///     registry
+///         .validate(&PathAndQuery::from_static("/users/42"))
+///         .is_err()
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TemplateRegistry {
+    labels: HashSet<Cow<'static, str>>,
+}
+
+impl TemplateRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the [`telemetry_labels`](PathAndQueryTemplate::telemetry_labels) of `T`.
+    #[must_use]
+    pub fn register<T: PathAndQueryTemplate>(mut self) -> Self {
+        self.labels.extend(T::telemetry_labels().into_iter().map(Cow::Borrowed));
+        self
+    }
+
+    /// Registers a single telemetry label, such as the source of a runtime
+    /// [`Template`](crate::Template).
+    #[must_use]
+    pub fn register_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.labels.insert(label.into());
+        self
+    }
+
+    /// Returns whether `label` is registered.
+    #[must_use]
+    pub fn contains(&self, label: &str) -> bool {
+        self.labels.contains(label)
+    }
+
+    /// Returns the registered labels, in no particular order.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.labels.iter().map(AsRef::as_ref)
+    }
+
+    /// Returns the number of registered labels.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns whether no labels are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Validates that `path` uses a registered template: that its label, or its template if it
+    /// has no label, is registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UriError`] if the label of `path` is not registered. The error leaves out
+    /// the label, as the template of a static path is the path itself, which can contain
+    /// sensitive values.
+    pub fn validate(&self, path: &PathAndQuery) -> Result<(), UriError> {
+        let label = path.label().unwrap_or_else(|| path.template());
+        if self.contains(&label) {
+            Ok(())
+        } else {
+            Err(UriError::invalid_uri("the path and query does not use a registered template"))
+        }
+    }
+
+    /// Validates that `request` uses a registered template, as [`validate`](Self::validate)
+    /// does for the [`PathAndQuery`] attached to it, such as by
+    /// [`UriRequestBuilderExt::templated_uri`](crate::UriRequestBuilderExt::templated_uri).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UriError`] if no [`PathAndQuery`] is attached to `request`, or if its label
+    /// is not registered.
+    pub fn validate_request<B>(&self, request: &http::Request<B>) -> Result<(), UriError> {
+        let path = request
+            .extensions()
+            .get::<PathAndQuery>()
+            .ok_or_else(|| UriError::invalid_uri("the request has no templated path and query"))?;
+        self.validate(path)
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use ohno::ErrorExt;
+
+    use super::*;
+    use crate::{Uri, UriRequestBuilderExt};
+
+    #[test]
+    fn register_label_and_contains() {
+        let registry = TemplateRegistry::new()
+            .register_label("/items")
+            .register_label(String::from("items"));
+        assert!(registry.contains("/items"));
+        assert!(registry.contains("items"));
+        assert!(!registry.contains("/other"));
+        assert_eq!(registry.len(), 2);
+        assert!(!registry.is_empty());
+        assert!(TemplateRegistry::new().is_empty());
+
+        let mut labels: Vec<_> = registry.labels().collect();
+        labels.sort_unstable();
+        assert_eq!(labels, ["/items", "items"]);
+    }
+
+    #[test]
+    fn validate_static_path_by_template() {
+        let registry = TemplateRegistry::new().register_label("/healthz");
+        registry.validate(&PathAndQuery::from_static("/healthz")).unwrap();
+
+        let err = registry.validate(&PathAndQuery::from_static("/users/jane")).unwrap_err();
+        assert_eq!(err.message(), "the path and query does not use a registered template");
+    }
+
+    #[test]
+    fn validate_request_requires_path() {
+        let registry = TemplateRegistry::new().register_label("/healthz");
+
+        let request = http::Request::builder()
+            .templated_uri(Uri::from(PathAndQuery::from_static("/healthz")))
+            .unwrap()
+            .body(())
+            .unwrap();
+        registry.validate_request(&request).unwrap();
+
+        let request = http::Request::get("/healthz").body(()).unwrap();
+        let err = registry.validate_request(&request).unwrap_err();
+        assert_eq!(err.message(), "the request has no templated path and query");
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! [`Escape`] implementations for UTC timestamps of the `chrono` and `time` crates.
+//!
+//! Timestamps are written in RFC 3339 form, such as `2024-05-01T12:30:00Z`, with the fraction
+//! of a second in as few of 3, 6 or 9 digits as represent it exactly. The colons are
+//! reserved characters, so they are percent-encoded as `%3A`.
+
+use std::fmt::{self, Display};
+
+use crate::{Escape, Escaped};
+
+/// The fields of a UTC timestamp, displayed as an escaped RFC 3339 timestamp.
+struct Timestamp {
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}%3A{:02}%3A{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        match self.nanosecond {
+            0 => {}
+            nanos if nanos % 1_000_000 == 0 => write!(f, ".{:03}", nanos / 1_000_000)?,
+            nanos if nanos % 1_000 == 0 => write!(f, ".{:06}", nanos / 1_000)?,
+            nanos => write!(f, ".{nanos:09}")?,
+        }
+        f.write_str("Z")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Escape for chrono::DateTime<chrono::Utc> {
+    fn escape(&self) -> Escaped<impl Display> {
+        use chrono::{Datelike, Timelike};
+
+        // chrono represents a leap second as a nanosecond value past the last second.
+        let (second, nanosecond) = match self.nanosecond().checked_sub(1_000_000_000) {
+            Some(nanosecond) => (self.second() + 1, nanosecond),
+            None => (self.second(), self.nanosecond()),
+        };
+        #[expect(clippy::cast_possible_truncation, reason = "chrono keeps the fields of dates and times below 256")]
+        Escaped::from_escaped(Timestamp {
+            year: self.year(),
+            month: self.month() as u8,
+            day: self.day() as u8,
+            hour: self.hour() as u8,
+            minute: self.minute() as u8,
+            second: second as u8,
+            nanosecond,
+        })
+    }
+}
+
+#[cfg(feature = "time")]
+impl Escape for time::UtcDateTime {
+    fn escape(&self) -> Escaped<impl Display> {
+        Escaped::from_escaped(Timestamp {
+            year: self.year(),
+            month: self.month().into(),
+            day: self.day(),
+            hour: self.hour(),
+            minute: self.minute(),
+            second: self.second(),
+            nanosecond: self.nanosecond(),
+        })
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timestamp(nanosecond: u32) -> String {
+        Timestamp {
+            year: 2024,
+            month: 5,
+            day: 1,
+            hour: 12,
+            minute: 30,
+            second: 5,
+            nanosecond,
+        }
+        .to_string()
+    }
+
+    #[test]
+    fn writes_shortest_exact_fraction() {
+        assert_eq!(timestamp(0), "2024-05-01T12%3A30%3A05Z");
+        assert_eq!(timestamp(120_000_000), "2024-05-01T12%3A30%3A05.120Z");
+        assert_eq!(timestamp(123_456_000), "2024-05-01T12%3A30%3A05.123456Z");
+        assert_eq!(timestamp(123_456_789), "2024-05-01T12%3A30%3A05.123456789Z");
+        assert_eq!(timestamp(1), "2024-05-01T12%3A30%3A05.000000001Z");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn escapes_chrono_timestamps() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let value = Utc.with_ymd_and_hms(2024, 5, 1, 12, 30, 5).unwrap();
+        assert_eq!(value.escape().to_string(), "2024-05-01T12%3A30%3A05Z");
+
+        let leap_second = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_milli_opt(23, 59, 59, 1_500)
+            .unwrap()
+            .and_utc();
+        assert_eq!(leap_second.escape().to_string(), "2016-12-31T23%3A59%3A60.500Z");
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn escapes_time_timestamps() {
+        use time::{Date, Month, Time, UtcDateTime};
+
+        let value = UtcDateTime::new(
+            Date::from_calendar_date(2024, Month::May, 1).unwrap(),
+            Time::from_hms_micro(12, 30, 5, 250).unwrap(),
+        );
+        assert_eq!(value.escape().to_string(), "2024-05-01T12%3A30%3A05.000250Z");
+    }
+}
@@ -11,7 +11,7 @@ use data_privacy::{DataClass, RedactedDebug, RedactedDisplay, RedactedToString,
 use http::uri::{Parts, PathAndQuery as HttpPathAndQuery};
 
 use crate::error::UriError;
-use crate::{BasePath, BaseUri, Origin, PathAndQuery};
+use crate::{BasePath, BaseUri, Origin, PathAndQuery, QueryParams};
 
 /// Target URI for HTTP requests, with optional [`BaseUri`] and [`PathAndQuery`] components.
 ///
@@ -86,6 +86,44 @@ impl Uri {
         Self::try_from(http::Uri::from_static(uri)).expect("static str is not a valid URI")
     }
 
+    /// Parses an absolute URI, such as an endpoint read from configuration, into a [`BaseUri`]
+    /// and a [`PathAndQuery`].
+    ///
+    /// Unlike [`Uri::from_str`], which also accepts a path alone, the URI must have a scheme and
+    /// an authority. The [`BaseUri`] holds the origin of the URI with the root path, and the
+    /// path and query, if any, is a static one classified as [`Uri::DATA_CLASS`]. Use
+    /// [`Uri::into_parts`] to take the components apart.
+    ///
+    /// ```
+    /// use templated_uri::{BaseUri, Uri};
+    ///
+    /// let uri = Uri::parse("https://example.com:8443/api/items?page=2")?;
+    /// let (base_uri, path_and_query) = uri.into_parts();
+    /// assert_eq!(
+    ///     base_uri,
+    ///     Some(BaseUri::from_static("https://example.com:8443"))
+    /// );
+    /// assert_eq!(
+    ///     path_and_query.unwrap().to_string().declassify_ref(),
+    ///     "/api/items?page=2"
+    /// );
+    ///
+    /// assert!(Uri::parse("/api/items").is_err());
+    /// # Ok::<(), templated_uri::UriError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UriError`] if the string is not a valid URI, or if it does not contain both
+    /// a scheme and an authority.
+    pub fn parse(uri: &str) -> Result<Self, UriError> {
+        let uri = Self::from_str(uri)?;
+        if uri.base_uri.is_none() {
+            return Err(UriError::invalid_uri("URI must have both scheme and authority components"));
+        }
+        Ok(uri)
+    }
+
     /// Creates a new [`Uri`] from a [`BaseUri`] and a [`PathAndQuery`].
     ///
     /// ```
@@ -130,6 +168,19 @@ impl Uri {
         }
     }
 
+    /// Appends the query parameters `params` to the path and query of this `Uri`, as
+    /// [`PathAndQuery::with_query_params`] does, and returns the updated value.
+    ///
+    /// A `Uri` without a path and query gets the path `/` followed by the parameters.
+    #[must_use]
+    pub fn with_query_params(self, params: QueryParams) -> Self {
+        let path_and_query = self.path_and_query.unwrap_or_else(|| PathAndQuery::from_static("/"));
+        Self {
+            path_and_query: Some(path_and_query.with_query_params(params)),
+            ..self
+        }
+    }
+
     /// Sets the [`BaseUri`] of this `Uri` and returns the updated value.
     #[must_use]
     pub fn with_base(self, base: impl Into<BaseUri>) -> Self {
@@ -148,6 +199,62 @@ impl Uri {
         self.path_and_query.clone()
     }
 
+    /// Resolves the URI reference `reference` against this URI, as specified by
+    /// [RFC 3986 section 5](https://www.rfc-editor.org/rfc/rfc3986#section-5).
+    ///
+    /// This lets clients follow links such as `Location` headers without leaving the
+    /// classified `Uri` type. Relative paths are merged with the path of this URI and dot
+    /// segments are removed; the fragment of `reference`, if any, is dropped as for any other
+    /// `Uri`. A `Uri` without a [`BaseUri`] resolves relative references against its path alone.
+    ///
+    /// The resolved path and query is a static one classified as [`Uri::DATA_CLASS`], so it has
+    /// no template. The [`BaseUri`] of this URI is kept when the target has the same origin and
+    /// stays below its base path.
+    ///
+    /// ```
+    /// use templated_uri::Uri;
+    ///
+    /// let uri = Uri::from_static("https://example.com/api/v1/users/42?expand=true");
+    /// let resolved = uri.resolve("../groups/7")?;
+    /// assert_eq!(
+    ///     resolved.to_string().declassify_ref(),
+    ///     "https://example.com/api/v1/groups/7"
+    /// );
+    ///
+    /// let resolved = uri.resolve("https://other.example.com/login?next=%2F")?;
+    /// assert_eq!(
+    ///     resolved.to_string().declassify_ref(),
+    ///     "https://other.example.com/login?next=%2F"
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UriError`] if the resolved target is not a valid URI, for example because
+    /// `reference` contains characters that are not allowed in a URI.
+    pub fn resolve(&self, reference: &str) -> Result<Self, UriError> {
+        let base = self.to_string();
+        let base = match base.declassify_ref().as_str() {
+            "" => "/",
+            base => base,
+        };
+        let target = http::Uri::try_from(crate::reference::resolve(base, reference))?;
+
+        if let (Some(base_uri), Some(scheme), Some(authority), Some(path_and_query)) =
+            (&self.base_uri, target.scheme(), target.authority(), target.path_and_query())
+            && Origin::from_parts(scheme.clone(), authority.clone()) == *base_uri.origin()
+            && let Some(relative) = path_and_query.as_str().strip_prefix(base_uri.path().as_str())
+        {
+            return Ok(Self {
+                base_uri: Some(base_uri.clone()),
+                path_and_query: Some(PathAndQuery::from(HttpPathAndQuery::try_from(format!("/{relative}"))?)),
+            });
+        }
+
+        target.try_into()
+    }
+
     /// Materializes into an [`http::Uri`], reusing an already-rendered path-and-query instead
     /// of re-rendering the templated path of this URI.
     ///
@@ -560,6 +667,24 @@ mod tests {
         assert_eq!(&uri.to_string().declassify_ref(), &uri_str);
     }
 
+    #[test]
+    fn parse_splits_absolute_uri() {
+        let (base_uri, path_and_query) = Uri::parse("http://example.com/a/b?c=d").unwrap().into_parts();
+        assert_eq!(base_uri.unwrap().to_string(), "http://example.com/");
+        assert_eq!(path_and_query.unwrap().to_string().declassify_ref(), "/a/b?c=d");
+
+        let (base_uri, path_and_query) = Uri::parse("https://example.com").unwrap().into_parts();
+        assert_eq!(base_uri.unwrap().to_string(), "https://example.com/");
+        assert!(path_and_query.is_none_or(|path| path.to_string().declassify_ref() == "/"));
+    }
+
+    #[test]
+    fn parse_rejects_relative_and_invalid_uris() {
+        for uri in ["/a/b", "example.com", "http://[::1:bad"] {
+            assert!(Uri::parse(uri).is_err(), "{uri}");
+        }
+    }
+
     #[test]
     fn test_path_only_uri() {
         let uri_str = "/path/to/resource";
@@ -661,6 +786,57 @@ mod tests {
         assert_eq!(redacted_debug, "", "RedactedDebug should return empty string for empty URI");
     }
 
+    #[test]
+    fn with_query_params_appends_to_path_and_query() {
+        let params = || QueryParams::new().append("page", 2_u32);
+
+        let uri = Uri::from_static("https://example.com/items?limit=10").with_query_params(params());
+        assert_eq!(uri.to_string().declassify_ref(), "https://example.com/items?limit=10&page=2");
+
+        let uri = Uri::default()
+            .with_base(BaseUri::from_static("https://example.com/"))
+            .with_query_params(params());
+        assert_eq!(uri.to_string().declassify_ref(), "https://example.com/?page=2");
+    }
+
+    #[test]
+    fn resolve_keeps_base_uri_below_base_path() {
+        let uri = Uri::default()
+            .with_base(BaseUri::from_static("https://example.com/api/v1/"))
+            .with_path_and_query(PathAndQuery::from_static("/users/42"));
+
+        let resolved = uri.resolve("../groups?page=2").unwrap();
+        assert_eq!(resolved.base_uri, uri.base_uri);
+        assert_eq!(resolved.to_string().declassify_ref(), "https://example.com/api/v1/groups?page=2");
+
+        let resolved = uri.resolve("/login").unwrap();
+        assert_eq!(resolved.base_uri, Some(BaseUri::from_static("https://example.com/")));
+        assert_eq!(resolved.to_string().declassify_ref(), "https://example.com/login");
+
+        let resolved = uri.resolve("//other.example.com/api/v1/users").unwrap();
+        assert_eq!(resolved.base_uri, Some(BaseUri::from_static("https://other.example.com/")));
+        assert_eq!(resolved.to_string().declassify_ref(), "https://other.example.com/api/v1/users");
+    }
+
+    #[test]
+    fn resolve_without_base_uri_resolves_against_path() {
+        let uri = Uri::from(HttpPathAndQuery::from_static("/users/42?expand=true"));
+        let resolved = uri.resolve("7#profile").unwrap();
+        assert!(resolved.base_uri.is_none());
+        assert_eq!(resolved.to_string().declassify_ref(), "/users/7");
+
+        assert_eq!(Uri::default().resolve("users").unwrap().to_string().declassify_ref(), "/users");
+    }
+
+    #[test]
+    fn resolve_rejects_invalid_reference() {
+        use ohno::Labeled;
+
+        let err = Uri::from_static("https://example.com/").resolve("/SECRET path").unwrap_err();
+        assert_eq!(err.label(), "uri_invalid");
+        assert!(!err.to_string().contains("SECRET"), "resolve error must not leak the reference");
+    }
+
     #[test]
     fn try_into_http_uri() {
         let uri = Uri::from_str("https://example.com/path?query=1").unwrap();
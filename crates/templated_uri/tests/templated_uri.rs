@@ -5,9 +5,9 @@
 
 use std::fmt::Display;
 
-use data_privacy::simple_redactor::SimpleRedactor;
-use data_privacy::{RedactedToString, RedactionEngine, Sensitive, classified, taxonomy};
-use templated_uri::{BaseUri, Escape, EscapedString, PathAndQueryTemplate, Raw, Uri, templated};
+use data_privacy::simple_redactor::{SimpleRedactor, SimpleRedactorMode};
+use data_privacy::{RedactedDisplay, RedactedToString, RedactionEngine, Sensitive, classified, taxonomy};
+use templated_uri::{BaseUri, Escape, EscapedString, PathAndQueryTemplate, Raw, Template, Uri, templated};
 
 // Local taxonomy for testing purposes, mimicking microsoft_enterprise_data_taxonomy
 #[taxonomy(test_taxonomy)]
@@ -228,6 +228,112 @@ fn template_enum() {
     );
 }
 
+#[templated]
+#[derive(Clone)]
+enum ServiceApi {
+    #[templated(template = "/healthz", label = "health")]
+    HealthCheck,
+    #[templated(template = "/{org_id}/user/{user_id}{?fields*}")]
+    User {
+        org_id: OrgId,
+        #[unredacted]
+        user_id: u32,
+        #[unredacted]
+        fields: Vec<EscapedString>,
+    },
+    Edit(UserActionPath),
+}
+
+#[test]
+fn template_enum_unit_and_struct_variants() {
+    let health = ServiceApi::HealthCheck;
+    assert_eq!(health.render(), "/healthz");
+    assert_eq!(health.template(), "/healthz");
+    assert_eq!(health.label(), Some("health"));
+    assert_eq!(format!("{health:?}"), r#"ServiceApi("/healthz")"#);
+
+    let user = ServiceApi::User {
+        org_id: OrgId(EscapedString::from_static("Acme")),
+        user_id: 42,
+        fields: vec![EscapedString::from_static("name"), EscapedString::from_static("email")],
+    };
+    assert_eq!(user.render(), "/Acme/user/42?fields=name&fields=email");
+    assert_eq!(user.template(), "/{org_id}/user/{user_id}{?fields*}");
+    assert_eq!(user.label(), None);
+    assert_eq!(user.to_path_and_query().unwrap().as_str(), "/Acme/user/42?fields=name&fields=email");
+
+    let edit = ServiceApi::from(UserActionPath {
+        org_id: OrgId(EscapedString::from_static("Acme")),
+        user_id: UserId(EscapedString::from_static("Will_E_Coyote")),
+        action: Action::Edit,
+    });
+    assert_eq!(edit.render(), "/Acme/user/Will_E_Coyote/edit/");
+
+    let redaction_engine = RedactionEngine::builder().set_fallback_redactor(SimpleRedactor::new()).build();
+    assert_eq!(health.to_redacted_string(&redaction_engine), "/healthz");
+    assert_eq!(user.to_redacted_string(&redaction_engine), "/****/user/42?fields=name&fields=email");
+}
+
+#[test]
+fn template_enum_telemetry_labels() {
+    assert_eq!(
+        ServiceApi::telemetry_labels(),
+        [
+            "health",
+            "/{org_id}/user/{user_id}{?fields*}",
+            "/{org_id}/user/{user_id}/{+action}/"
+        ]
+    );
+    assert_eq!(
+        UserApi::telemetry_labels(),
+        ["/{org_id}/user/{user_id}/", "/{org_id}/user/{user_id}/{+action}/"]
+    );
+}
+
+mod catalog {
+    use templated_uri::{EscapedString, templated_catalog};
+
+    use super::{OrgId, UserActionPath};
+
+    #[templated_catalog]
+    #[derive(Clone)]
+    pub(super) enum CatalogApi {
+        #[templated(template = "/healthz", label = "health")]
+        HealthCheck,
+        #[templated(template = "/{org_id}/items/{id}{?tag}")]
+        Item {
+            org_id: OrgId,
+            #[unredacted]
+            id: u32,
+            #[unredacted]
+            tag: Option<EscapedString>,
+        },
+        Edit(UserActionPath),
+    }
+}
+
+#[test]
+fn template_catalog_generates_route_structs() {
+    let item = catalog::Item {
+        org_id: OrgId(EscapedString::from_static("Acme")),
+        id: 7,
+        tag: None,
+    };
+    assert_eq!(item.render(), "/Acme/items/7");
+    assert_eq!(item.template(), "/{org_id}/items/{id}{?tag}");
+
+    let api = catalog::CatalogApi::from(item.clone());
+    assert_eq!(api.render(), item.render());
+    assert_eq!(catalog::CatalogApi::HealthCheck.render(), "/healthz");
+    assert_eq!(
+        catalog::CatalogApi::telemetry_labels(),
+        ["health", "/{org_id}/items/{id}{?tag}", "/{org_id}/user/{user_id}/{+action}/"]
+    );
+
+    let redaction_engine = RedactionEngine::builder().set_fallback_redactor(SimpleRedactor::new()).build();
+    assert_eq!(api.to_redacted_string(&redaction_engine), "/****/items/7");
+}
+
 #[templated(template = "/{org_id}/product/{product_id}/")]
 #[derive(Clone)]
 struct MixedRedactionPath {
@@ -618,6 +724,37 @@ fn optional_query_continuation_none() {
     assert_eq!(path.render(), "/data?required=1");
 }
 
+#[templated(template = "/data{?a,b}{&c}{&d}")]
+#[derive(Clone)]
+struct QueryContinuationAfterOptional {
+    #[unredacted]
+    a: Option<u32>,
+    #[unredacted]
+    b: Option<u32>,
+    c: Option<OrgId>,
+    #[unredacted]
+    d: Option<u32>,
+}
+
+#[test]
+fn optional_query_continuation_starts_query() {
+    let redaction_engine = RedactionEngine::builder().set_fallback_redactor(SimpleRedactor::new()).build();
+    let org_id = || Some(OrgId(EscapedString::from_static("Acme")));
+    let cases = [
+        ((None, None, None, None), "/data", "/data"),
+        ((None, None, org_id(), Some(4)), "/data?c=Acme&d=4", "/data?c=****&d=4"),
+        ((None, None, None, Some(4)), "/data?d=4", "/data?d=4"),
+        ((None, Some(2), None, Some(4)), "/data?b=2&d=4", "/data?b=2&d=4"),
+        ((Some(1), None, org_id(), None), "/data?a=1&c=Acme", "/data?a=1&c=****"),
+    ];
+
+    for ((a, b, c, d), rendered, redacted) in cases {
+        let path = QueryContinuationAfterOptional { a, b, c, d };
+        assert_eq!(path.render(), rendered);
+        assert_eq!(path.to_redacted_string(&redaction_engine), redacted);
+    }
+}
+
 #[templated(template = "/{x,y}", unredacted)]
 #[derive(Clone)]
 struct SimpleMultiOptional {
@@ -1090,6 +1227,50 @@ fn macro_render_into_appends_without_reallocating_prefix() {
     assert_eq!(enum_buf, format!("base:{}", action.render()));
 }
 
+#[test]
+fn macro_write_uri_matches_render() {
+    fn write_uri(template: &impl PathAndQueryTemplate) -> String {
+        let mut out = String::from("prefix:");
+        template.write_uri(&mut out).unwrap();
+        out
+    }
+
+    let prefixed = PrefixPath {
+        name: EscapedString::escape("é t"),
+        id: 12345,
+        q: Some(EscapedString::from_static("searching")),
+    };
+    assert_eq!(write_uri(&prefixed), "prefix:/users/%C3%A9%20t/12?q=sear");
+
+    let exploded = ExplodedMapPath {
+        keys: std::collections::BTreeMap::from([(EscapedString::from_static("semi"), EscapedString::escape(";"))]),
+        matrix: Some(vec![1, 2]),
+        rest: vec!["a/b".to_string(), "c".to_string()],
+    };
+    assert_eq!(write_uri(&exploded), format!("prefix:{}", exploded.render()));
+
+    let without_optional = ReferenceFieldPath {
+        id: &REF_ID_VALUE,
+        maybe: None,
+    };
+    assert_eq!(write_uri(&without_optional), "prefix:/items/4242");
+
+    let user = ServiceApi::User {
+        org_id: OrgId(EscapedString::from_static("Acme")),
+        user_id: 42,
+        fields: vec![EscapedString::from_static("name")],
+    };
+    assert_eq!(write_uri(&user), "prefix:/Acme/user/42?fields=name");
+    assert_eq!(write_uri(&ServiceApi::HealthCheck), "prefix:/healthz");
+
+    let edit = ServiceApi::from(UserActionPath {
+        org_id: OrgId(EscapedString::from_static("Acme")),
+        user_id: UserId(EscapedString::from_static("Wile")),
+        action: Action::Edit,
+    });
+    assert_eq!(write_uri(&edit), format!("prefix:{}", edit.render()));
+}
+
 static REF_ID_VALUE: u32 = 4242;
 
 #[templated(template = "/items/{id}{?maybe}", unredacted)]
@@ -1120,3 +1301,332 @@ fn reference_fields_render_in_required_and_optional_positions() {
     };
     assert_eq!(without_opt.render(), "/items/4242");
 }
+
+#[templated(template = "/hosts/{host}{?offset,active}", unredacted)]
+#[derive(Clone)]
+struct PrimitiveFieldPath {
+    host: std::net::Ipv4Addr,
+    offset: i64,
+    active: Option<bool>,
+}
+
+#[test]
+fn primitive_fields_render_without_newtypes() {
+    let path = PrimitiveFieldPath {
+        host: std::net::Ipv4Addr::new(10, 0, 0, 1),
+        offset: -20,
+        active: Some(true),
+    };
+    assert_eq!(path.render(), "/hosts/10.0.0.1?offset=-20&active=true");
+}
+
+// ======== Level 4 modifiers (RFC 6570 section 2.4) ========
+#[templated(template = "/users/{name:3}{/id:2}{?q:4}", unredacted)]
+#[derive(Clone)]
+struct PrefixPath {
+    name: EscapedString,
+    id: u32,
+    q: Option<EscapedString>,
+}
+
+#[test]
+fn prefix_modifier_truncates_values() {
+    let path = PrefixPath {
+        name: EscapedString::escape("é t"),
+        id: 12345,
+        q: Some(EscapedString::from_static("searching")),
+    };
+    // The prefix counts characters before encoding, so `é` and the space count one each.
+    assert_eq!(path.render(), "/users/%C3%A9%20t/12?q=sear");
+    assert_eq!(path.format_template(), "/users/{name}/{id}?q={q}");
+
+    let redaction_engine = RedactionEngine::builder().set_fallback_redactor(SimpleRedactor::new()).build();
+    assert_eq!(path.to_redacted_string(&redaction_engine), path.render());
+}
+
+#[templated(template = "/files{/segments*}{?tags*,page}", unredacted)]
+#[derive(Clone)]
+struct ExplodedListPath {
+    segments: Vec<EscapedString>,
+    tags: Vec<EscapedString>,
+    page: u32,
+}
+
+#[test]
+fn explode_modifier_expands_lists() {
+    let path = ExplodedListPath {
+        segments: vec![EscapedString::from_static("docs"), EscapedString::escape("my notes")],
+        tags: vec![EscapedString::from_static("red"), EscapedString::from_static("blue")],
+        page: 2,
+    };
+    assert_eq!(path.render(), "/files/docs/my%20notes?tags=red&tags=blue&page=2");
+    assert_eq!(path.format_template(), "/files/{segments}?{tags}&page={page}");
+
+    let redaction_engine = RedactionEngine::builder().set_fallback_redactor(SimpleRedactor::new()).build();
+    assert_eq!(path.to_redacted_string(&redaction_engine), path.render());
+}
+
+#[test]
+fn explode_modifier_omits_empty_lists() {
+    let path = ExplodedListPath {
+        segments: Vec::new(),
+        tags: Vec::new(),
+        page: 2,
+    };
+    assert_eq!(path.render(), "/files?page=2");
+}
+
+#[templated(template = "/items/{ids}{?tags,page}{;matrix}", unredacted)]
+#[derive(Clone)]
+struct ListPath {
+    ids: Vec<u32>,
+    tags: Option<Vec<EscapedString>>,
+    page: u32,
+    matrix: Vec<EscapedString>,
+}
+
+#[test]
+fn list_fields_join_items_with_commas() {
+    let path = ListPath {
+        ids: vec![1, 2, 3],
+        tags: Some(vec![EscapedString::from_static("red"), EscapedString::escape("dark blue")]),
+        page: 2,
+        matrix: vec![EscapedString::from_static("a")],
+    };
+    assert_eq!(path.render(), "/items/1,2,3?tags=red,dark%20blue&page=2;matrix=a");
+
+    let redaction_engine = RedactionEngine::builder().set_fallback_redactor(SimpleRedactor::new()).build();
+    assert_eq!(path.to_redacted_string(&redaction_engine), path.render());
+}
+
+#[test]
+fn list_fields_omit_empty_lists() {
+    let path = ListPath {
+        ids: Vec::new(),
+        tags: Some(Vec::new()),
+        page: 2,
+        matrix: Vec::new(),
+    };
+    assert_eq!(path.render(), "/items/?page=2");
+}
+
+#[templated(template = "/orgs/{orgs}")]
+#[derive(Clone)]
+struct RedactedListPath {
+    orgs: Vec<OrgId>,
+}
+
+#[test]
+fn list_fields_redact_each_item() {
+    let path = RedactedListPath {
+        orgs: vec![
+            OrgId(EscapedString::from_static("Acme")),
+            OrgId(EscapedString::from_static("Initech")),
+        ],
+    };
+    assert_eq!(path.render(), "/orgs/Acme,Initech");
+
+    let redaction_engine = RedactionEngine::builder().set_fallback_redactor(SimpleRedactor::new()).build();
+    assert_eq!(path.to_redacted_string(&redaction_engine), "/orgs/****,*******");
+}
+
+#[templated(template = "/search{?keys*}{;matrix*}{+rest*}", unredacted)]
+#[derive(Clone)]
+struct ExplodedMapPath {
+    keys: std::collections::BTreeMap<EscapedString, EscapedString>,
+    matrix: Option<Vec<u32>>,
+    rest: Vec<String>,
+}
+
+#[test]
+fn explode_modifier_expands_maps() {
+    let path = ExplodedMapPath {
+        keys: std::collections::BTreeMap::from([
+            (EscapedString::from_static("dot"), EscapedString::from_static(".")),
+            (EscapedString::from_static("semi"), EscapedString::escape(";")),
+        ]),
+        matrix: Some(vec![1, 2]),
+        rest: vec!["a/b".to_string(), "c".to_string()],
+    };
+    assert_eq!(path.render(), "/search?dot=.&semi=%3B;matrix=1;matrix=2a/b,c");
+
+    let path = ExplodedMapPath {
+        keys: std::collections::BTreeMap::new(),
+        matrix: None,
+        rest: Vec::new(),
+    };
+    assert_eq!(path.render(), "/search");
+}
+
+#[templated(template = "/orgs{/orgs*}")]
+#[derive(Clone)]
+struct RedactedExplodedPath {
+    orgs: Vec<OrgId>,
+}
+
+#[test]
+fn explode_modifier_redacts_each_item() {
+    let path = RedactedExplodedPath {
+        orgs: vec![
+            OrgId(EscapedString::from_static("Acme")),
+            OrgId(EscapedString::from_static("Initech")),
+        ],
+    };
+    assert_eq!(path.render(), "/orgs/Acme/Initech");
+
+    let redaction_engine = RedactionEngine::builder().set_fallback_redactor(SimpleRedactor::new()).build();
+    assert_eq!(path.to_redacted_string(&redaction_engine), "/orgs/****/*******");
+}
+
+#[templated(template = "/{org_id}/items/{id}{?extra}")]
+#[derive(Clone)]
+struct GenericItemPath<T, E = u32>
+where
+    T: Escape + RedactedDisplay + Send + Sync + 'static,
+    E: Escape + Display + Send + Sync + 'static,
+{
+    org_id: OrgId,
+    id: T,
+    #[unredacted]
+    extra: Option<E>,
+}
+
+#[test]
+fn generic_struct_renders_and_redacts() {
+    let path: GenericItemPath<UserId> = GenericItemPath {
+        org_id: OrgId(EscapedString::from_static("Acme")),
+        id: UserId(EscapedString::from_static("jane")),
+        extra: Some(7),
+    };
+    assert_eq!(path.render(), "/Acme/items/jane?extra=7");
+    assert_eq!(format!("{path:?}"), r#"GenericItemPath("/{org_id}/items/{id}{?extra}")"#);
+
+    let redaction_engine = RedactionEngine::builder().set_fallback_redactor(SimpleRedactor::new()).build();
+    assert_eq!(path.to_redacted_string(&redaction_engine), "/****/items/****?extra=7");
+
+    let path_and_query: templated_uri::PathAndQuery = GenericItemPath::<OrgId, EscapedString> {
+        org_id: OrgId(EscapedString::from_static("Acme")),
+        id: OrgId(EscapedString::from_static("Initech")),
+        extra: None,
+    }
+    .into();
+    assert_eq!(path_and_query.to_string().declassify_ref(), "/Acme/items/Initech");
+    assert_eq!(path_and_query.template(), "/{org_id}/items/{id}{?extra}");
+}
+
+#[cfg(feature = "serde")]
+#[classified(TestTaxonomy::Eupi)]
+#[derive(Clone, PartialEq, Escape, serde::Deserialize)]
+struct SerdeUserId(EscapedString);
+
+#[cfg(feature = "serde")]
+#[templated(template = "/users/{user_id}/files{/path*}{?tags,limit}", serde)]
+#[derive(Clone)]
+struct SerdeFilesPath {
+    user_id: SerdeUserId,
+    #[unredacted]
+    path: Vec<EscapedString>,
+    #[unredacted]
+    tags: Vec<EscapedString>,
+    #[unredacted]
+    limit: Option<u32>,
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trips_through_rendered_path() {
+    let path = SerdeFilesPath {
+        user_id: SerdeUserId(EscapedString::escape("jane doe")),
+        path: vec![EscapedString::from_static("docs"), EscapedString::escape("my notes")],
+        tags: vec![EscapedString::from_static("red"), EscapedString::from_static("blue")],
+        limit: Some(10),
+    };
+
+    let json = serde_json::to_string(&path).unwrap();
+    assert_eq!(json, r#""/users/jane%20doe/files/docs/my%20notes?tags=red,blue&limit=10""#);
+
+    let deserialized: SerdeFilesPath = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.user_id, path.user_id);
+    assert_eq!(deserialized.path, path.path);
+    assert_eq!(deserialized.tags, path.tags);
+    assert_eq!(deserialized.limit, Some(10));
+    assert_eq!(deserialized.render(), path.render());
+
+    let redaction_engine = RedactionEngine::builder().set_fallback_redactor(SimpleRedactor::new()).build();
+    assert_eq!(
+        deserialized.to_redacted_string(&redaction_engine),
+        "/users/**********/files/docs/my%20notes?tags=red,blue&limit=10"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trips_undefined_values() {
+    let path: SerdeFilesPath = serde_json::from_str(r#""/users/42/files""#).unwrap();
+    assert_eq!(path.user_id, SerdeUserId(EscapedString::from_static("42")));
+    assert!(path.path.is_empty());
+    assert!(path.tags.is_empty());
+    assert_eq!(path.limit, None);
+    assert_eq!(path.render(), "/users/42/files");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_errors_do_not_leak_the_path() {
+    let error = serde_json::from_str::<SerdeFilesPath>(r#""/accounts/SECRET""#).unwrap_err();
+    assert!(!error.to_string().contains("SECRET"), "{error}");
+    assert!(error.to_string().contains("does not match the template"), "{error}");
+
+    let error = serde_json::from_str::<SerdeFilesPath>(r#""/users/42/files?limit=SECRET""#).unwrap_err();
+    assert!(!error.to_string().contains("SECRET"), "{error}");
+    assert!(error.to_string().contains("template variable `limit`"), "{error}");
+}
+
+#[templated(openapi = "/users/{user_id}/posts/{post_id}", unredacted)]
+#[derive(Clone)]
+struct OpenApiPostPath {
+    user_id: u32,
+    post_id: u32,
+}
+
+#[test]
+fn openapi_paths_render_like_templates() {
+    let path = OpenApiPostPath { user_id: 42, post_id: 7 };
+    assert_eq!(path.render(), "/users/42/posts/7");
+    assert_eq!(path.template(), "/users/{user_id}/posts/{post_id}");
+
+    let template = Template::parse(path.template()).unwrap();
+    assert_eq!(template.to_openapi().unwrap(), path.template());
+}
+
+#[templated(template = "/{org}/user/{user_id}{?tags,page}")]
+struct ClassifiedFieldsPath {
+    #[templated(classify = TestTaxonomy::Oii)]
+    org: EscapedString,
+    #[templated(classify = TestTaxonomy::Eupi)]
+    user_id: u32,
+    #[templated(classify = TestTaxonomy::Public)]
+    tags: Vec<EscapedString>,
+    #[templated(classify = TestTaxonomy::Public)]
+    page: Option<u32>,
+}
+
+#[test]
+fn classified_fields_are_redacted_as_their_data_class() {
+    let path = ClassifiedFieldsPath {
+        org: EscapedString::from_static("Acme"),
+        user_id: 42,
+        tags: vec![EscapedString::from_static("red"), EscapedString::from_static("blue")],
+        page: Some(2),
+    };
+    assert_eq!(path.render(), "/Acme/user/42?tags=red,blue&page=2");
+
+    let redaction_engine = RedactionEngine::builder()
+        .set_fallback_redactor(SimpleRedactor::with_mode(SimpleRedactorMode::EraseAndTag))
+        .suppress_redaction(TestTaxonomy::Public)
+        .build();
+    assert_eq!(
+        path.to_redacted_string(&redaction_engine),
+        "/<test_taxonomy/oii:>/user/<test_taxonomy/eupi:>?tags=red,blue&page=2"
+    );
+}
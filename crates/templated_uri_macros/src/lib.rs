@@ -11,7 +11,7 @@
 //! Macros for the [`templated_uri`](https://docs.rs/templated_uri) crate.
 
 use proc_macro::TokenStream;
-use templated_uri_macros_impl::{raw_derive_impl, templated_paq_impl, uri_param_derive_impl};
+use templated_uri_macros_impl::{raw_derive_impl, templated_catalog_impl, templated_paq_impl, uri_param_derive_impl};
 
 #[cfg_attr(test, mutants::skip)] // The macro is tested indirectly through the `templated_uri` crate's tests, so we can skip it in mutation testing here.
 #[proc_macro_attribute]
@@ -20,6 +20,13 @@ pub fn templated(attr: TokenStream, item: TokenStream) -> TokenStream {
     output.into()
 }
 
+#[cfg_attr(test, mutants::skip)] // The macro is tested indirectly through the `templated_uri` crate's tests, so we can skip it in mutation testing here.
+#[proc_macro_attribute]
+pub fn templated_catalog(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let output = templated_catalog_impl(&attr.into(), item.into());
+    output.into()
+}
+
 #[cfg_attr(test, mutants::skip)] // The macro is tested indirectly through the `templated_uri` crate's tests, so we can skip it in mutation testing here.
 #[proc_macro_derive(Escape)]
 pub fn uri_param(input: TokenStream) -> TokenStream {
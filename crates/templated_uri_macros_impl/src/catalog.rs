@@ -0,0 +1,68 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields};
+
+use crate::bail;
+
+/// Generates a templated struct for each struct variant of the catalog enum, and the
+/// `#[templated]` enum holding them.
+///
+/// Struct variants become tuple variants holding a struct of the same name, which takes the
+/// `#[templated(...)]` options of the variant and its fields, while its doc comments apply to
+/// both. Unit variants have no fields to move, and tuple variants already hold a templated
+/// struct, so both stay in the enum.
+pub(crate) fn catalog(input: DeriveInput) -> TokenStream {
+    let Data::Enum(data) = &input.data else {
+        bail!(input, "#[templated_catalog] can only be applied to enums");
+    };
+
+    let vis = &input.vis;
+    let ident = &input.ident;
+    // Derives, such as `Clone`, apply to the structs of the routes as well as to the enum.
+    let derives: Vec<&Attribute> = input.attrs.iter().filter(|attr| attr.path().is_ident("derive")).collect();
+
+    let mut structs = Vec::new();
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        let Fields::Named(fields) = &variant.fields else {
+            variants.push(quote! { #variant });
+            continue;
+        };
+
+        let variant_ident = &variant.ident;
+        let variant_attrs = &variant.attrs;
+        let docs = variant.attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+        let fields = fields.named.iter().map(|field| {
+            let attrs = &field.attrs;
+            let field_ident = &field.ident;
+            let ty = &field.ty;
+            quote! { #(#attrs)* #vis #field_ident: #ty }
+        });
+
+        // The `#[templated(...)]` options of the variant are read by `#[templated]` from the
+        // attributes of the struct, as are `#[unredacted]` and the options of its fields.
+        structs.push(quote! {
+            #[::templated_uri::templated]
+            #(#derives)*
+            #(#variant_attrs)*
+            #vis struct #variant_ident {
+                #(#fields),*
+            }
+        });
+        variants.push(quote! { #(#docs)* #variant_ident(#variant_ident) });
+    }
+
+    let attrs = &input.attrs;
+    quote! {
+        #(#structs)*
+
+        #[::templated_uri::templated]
+        #(#attrs)*
+        #vis enum #ident {
+            #(#variants),*
+        }
+    }
+}
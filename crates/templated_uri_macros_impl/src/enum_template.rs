@@ -1,92 +1,152 @@
 // Copyright (c) Microsoft Corporation.
 // Licensed under the MIT License.
 
+use darling::FromAttributes;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::{DataEnum, Fields};
+use syn::{DataEnum, Field, Fields, Variant};
 
 use crate::bail;
+use crate::struct_template::{Opts, Receiver, TemplateCode, TemplateSource};
+
+/// The match arms of the impls for a single enum variant.
+struct VariantArms {
+    template: TokenStream,
+    format_template: TokenStream,
+    label: TokenStream,
+    /// The statement adding the telemetry labels of the variant to `__labels`.
+    telemetry_labels: TokenStream,
+    to_path_and_query: TokenStream,
+    render: TokenStream,
+    render_into: TokenStream,
+    render_capacity_hint: TokenStream,
+    write_uri: TokenStream,
+    debug: TokenStream,
+    redacted_display: TokenStream,
+}
 
-#[expect(clippy::cognitive_complexity, reason = "Macros can be complex")]
 pub(crate) fn enum_template(ident: &Ident, data: &DataEnum) -> TokenStream {
     let enum_name = ident.to_string();
-    let mut all_variants = Vec::new();
-    let mut variant_types = Vec::new();
+    let mut arms = Vec::new();
+    let mut from_impls = Vec::new();
 
     for variant in &data.variants {
-        let Fields::Unnamed(fields) = &variant.fields else {
-            bail!(
-                variant,
-                "Only unnamed fields (tuples) are supported in enum variants for TemplatedUri",
-            )
-        };
-        if fields.unnamed.len() != 1 {
-            bail!(
-                fields,
-                "TemplatedUri enum variants must have exactly one field containing a TemplatedUri struct",
-            )
+        let variant_ident = &variant.ident;
+        match &variant.fields {
+            Fields::Unnamed(fields) => {
+                if variant.attrs.iter().any(|attr| attr.path().is_ident("templated")) {
+                    bail!(
+                        variant,
+                        "Tuple variants use the template of their field, so they cannot have #[templated] options",
+                    )
+                }
+                if fields.unnamed.len() != 1 {
+                    bail!(
+                        fields,
+                        "TemplatedUri enum variants must have exactly one field containing a TemplatedUri struct",
+                    )
+                }
+                let (variant_arms, from_impl) = delegated_arms(ident, &enum_name, variant_ident, &fields.unnamed[0].ty);
+                arms.push(variant_arms);
+                from_impls.push(from_impl);
+            }
+            Fields::Named(_) | Fields::Unit => match inline_arms(ident, &enum_name, variant) {
+                Ok(variant_arms) => arms.push(variant_arms),
+                Err(err) => return err,
+            },
         }
-        all_variants.push(&variant.ident);
-        variant_types.push(&fields.unnamed[0].ty);
     }
 
-    // Matching patterns for all the enum variants (`Foo::Variant(bar)` part of `Foo::Variant(bar) => {}`)
-    let variant_matches: Vec<_> = all_variants
-        .iter()
-        .map(|variant_ident| {
-            quote! { #ident::#variant_ident(template_variant) }
-        })
-        .collect();
+    let template_arms = arms.iter().map(|arms| &arms.template);
+    let format_template_arms = arms.iter().map(|arms| &arms.format_template);
+    let label_arms = arms.iter().map(|arms| &arms.label);
+    let telemetry_labels = arms.iter().map(|arms| &arms.telemetry_labels);
+    let to_path_and_query_arms = arms.iter().map(|arms| &arms.to_path_and_query);
+    let render_arms = arms.iter().map(|arms| &arms.render);
+    let render_into_arms = arms.iter().map(|arms| &arms.render_into);
+    let render_capacity_hint_arms = arms.iter().map(|arms| &arms.render_capacity_hint);
+    let write_uri_arms = arms.iter().map(|arms| &arms.write_uri);
+    let formatting_impls = formatting_impls(ident, &arms);
 
     quote! {
         impl ::templated_uri::PathAndQueryTemplate for #ident {
             fn template(&self) -> &'static core::primitive::str {
                 match self {
-                    #(#variant_matches => template_variant.template()),*
+                    #(#template_arms),*
                 }
             }
 
             fn format_template(&self) -> &'static core::primitive::str {
                 match self {
-                    #(#variant_matches => template_variant.format_template()),*
+                    #(#format_template_arms),*
                 }
             }
 
             fn label(&self) -> ::core::option::Option<&'static core::primitive::str> {
                 match self {
-                    #(#variant_matches => template_variant.label()),*
+                    #(#label_arms),*
                 }
             }
 
+            fn telemetry_labels() -> ::std::vec::Vec<&'static core::primitive::str> {
+                let mut __labels = ::std::vec::Vec::new();
+                #(#telemetry_labels)*
+                __labels
+            }
+
             fn to_path_and_query(&self) -> ::std::result::Result<::templated_uri::__private::http::uri::PathAndQuery, ::templated_uri::UriError> {
                 match self {
-                    #(#variant_matches => template_variant.to_path_and_query()),*
+                    #(#to_path_and_query_arms),*
                 }
             }
 
             fn render(&self) -> ::std::string::String {
                 match self {
-                    #(#variant_matches => template_variant.render()),*
+                    #(#render_arms),*
                 }
             }
 
             fn render_into(&self, __out: &mut ::std::string::String) {
                 match self {
-                    #(#variant_matches => ::templated_uri::PathAndQueryTemplate::render_into(template_variant, __out)),*
+                    #(#render_into_arms),*
                 }
             }
 
             fn render_capacity_hint(&self) -> ::core::primitive::usize {
                 match self {
-                    #(#variant_matches => ::templated_uri::PathAndQueryTemplate::render_capacity_hint(template_variant)),*
+                    #(#render_capacity_hint_arms),*
+                }
+            }
+
+            fn write_uri(&self, __out: &mut dyn ::std::fmt::Write) -> ::std::fmt::Result {
+                match self {
+                    #(#write_uri_arms),*
                 }
             }
         }
 
+        #formatting_impls
+
+        #(#from_impls)*
+
+        impl From<#ident> for ::templated_uri::PathAndQuery {
+            fn from(value: #ident) -> Self {
+                ::templated_uri::PathAndQuery::from_template(value)
+            }
+        }
+    }
+}
+
+/// Generates the `Debug` and `RedactedDisplay` impls of the enum.
+fn formatting_impls(ident: &Ident, arms: &[VariantArms]) -> TokenStream {
+    let debug_arms = arms.iter().map(|arms| &arms.debug);
+    let redacted_display_arms = arms.iter().map(|arms| &arms.redacted_display);
+
+    quote! {
         impl ::std::fmt::Debug for #ident {
             fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
                 match self {
-                    #(#variant_matches => f.debug_tuple(#enum_name).field(&template_variant).finish()),*
+                    #(#debug_arms),*
                 }
             }
         }
@@ -94,24 +154,106 @@ pub(crate) fn enum_template(ident: &Ident, data: &DataEnum) -> TokenStream {
         impl ::templated_uri::__private::RedactedDisplay for #ident {
             fn fmt(&self, redactor: &dyn ::templated_uri::__private::Redactor, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
                 match self {
-                    #(#variant_matches => ::templated_uri::__private::RedactedDisplay::fmt(template_variant, redactor, f)?),*
+                    #(#redacted_display_arms),*
                 }
-                Ok(())
             }
         }
+    }
+}
 
-        #(
-            impl ::std::convert::From<#variant_types> for #ident {
-                fn from(template_variant: #variant_types) -> Self {
-                    Self::#all_variants(template_variant)
-                }
-            }
-        )*
+/// Returns the arms of a tuple variant, which delegate to the templated struct it holds, and
+/// the `From` impl creating the variant from that struct.
+fn delegated_arms(ident: &Ident, enum_name: &str, variant_ident: &Ident, variant_type: &syn::Type) -> (VariantArms, TokenStream) {
+    // Matching pattern for the variant (`Foo::Variant(bar)` part of `Foo::Variant(bar) => {}`)
+    let pattern = quote! { #ident::#variant_ident(template_variant) };
 
-        impl From<#ident> for ::templated_uri::PathAndQuery {
-            fn from(value: #ident) -> Self {
-                ::templated_uri::PathAndQuery::from_template(value)
+    let from_impl = quote! {
+        impl ::std::convert::From<#variant_type> for #ident {
+            fn from(template_variant: #variant_type) -> Self {
+                Self::#variant_ident(template_variant)
             }
         }
+    };
+
+    let arms = VariantArms {
+        template: quote! { #pattern => template_variant.template() },
+        format_template: quote! { #pattern => template_variant.format_template() },
+        label: quote! { #pattern => template_variant.label() },
+        telemetry_labels: quote! {
+            __labels.extend(<#variant_type as ::templated_uri::PathAndQueryTemplate>::telemetry_labels());
+        },
+        to_path_and_query: quote! { #pattern => template_variant.to_path_and_query() },
+        render: quote! { #pattern => template_variant.render() },
+        render_into: quote! { #pattern => ::templated_uri::PathAndQueryTemplate::render_into(template_variant, __out) },
+        render_capacity_hint: quote! {
+            #pattern => ::templated_uri::PathAndQueryTemplate::render_capacity_hint(template_variant)
+        },
+        write_uri: quote! { #pattern => ::templated_uri::PathAndQueryTemplate::write_uri(template_variant, __out) },
+        debug: quote! { #pattern => f.debug_tuple(#enum_name).field(&template_variant).finish() },
+        redacted_display: quote! {
+            #pattern => ::templated_uri::__private::RedactedDisplay::fmt(template_variant, redactor, f)
+        },
+    };
+
+    (arms, from_impl)
+}
+
+/// Returns the arms of a unit or struct variant, which render the template of its own
+/// `#[templated(...)]` attribute from its fields, as a struct would.
+fn inline_arms(ident: &Ident, enum_name: &str, variant: &Variant) -> Result<VariantArms, TokenStream> {
+    let variant_ident = &variant.ident;
+    let opts = Opts::from_attributes(&variant.attrs).map_err(darling::Error::write_errors)?;
+    if opts.serde {
+        return Err(syn::Error::new_spanned(variant_ident, "`serde` is not supported for enum variants").to_compile_error());
     }
+
+    let input_template = TemplateSource::from_opts(&opts, variant_ident)?;
+    let template = input_template.parse(variant_ident)?;
+    let input_template = input_template.as_str();
+    let telemetry_label = opts.label.as_deref().unwrap_or(input_template);
+
+    let variant_fields: Vec<&Field> = variant.fields.iter().collect();
+    let TemplateCode {
+        format_template,
+        label,
+        render_statements,
+        render_capacity,
+        write_statements,
+        redacted_display,
+    } = TemplateCode::new(variant_ident, &opts, &template, &variant_fields, Receiver::Variant)?;
+
+    // `{ .. }` also matches unit variants, so both kinds share their patterns.
+    let pattern = quote! { #ident::#variant_ident { .. } };
+    let bindings = variant_fields.iter().filter_map(|f| f.ident.as_ref()).map(|field_ident| {
+        let binding = Receiver::binding(field_ident);
+        quote! { #field_ident: #binding }
+    });
+    let bound_pattern = quote! { #ident::#variant_ident { #(#bindings,)* .. } };
+
+    Ok(VariantArms {
+        template: quote! { #pattern => #input_template },
+        format_template: quote! { #pattern => #format_template },
+        label: quote! { #pattern => #label },
+        telemetry_labels: quote! { __labels.push(#telemetry_label); },
+        to_path_and_query: quote! {
+            #pattern => Ok(::templated_uri::__private::http::uri::PathAndQuery::try_from(::templated_uri::PathAndQueryTemplate::render(self))?)
+        },
+        render: quote! {
+            #pattern => {
+                let mut __out = ::std::string::String::with_capacity(#render_capacity);
+                ::templated_uri::PathAndQueryTemplate::render_into(self, &mut __out);
+                __out
+            }
+        },
+        render_into: quote! { #bound_pattern => { #(#render_statements)* } },
+        render_capacity_hint: quote! { #pattern => #render_capacity },
+        write_uri: quote! {
+            #bound_pattern => {
+                #(#write_statements)*
+                ::std::result::Result::Ok(())
+            }
+        },
+        debug: quote! { #pattern => f.debug_tuple(#enum_name).field(&#input_template).finish() },
+        redacted_display: quote! { #bound_pattern => { #redacted_display } },
+    })
 }
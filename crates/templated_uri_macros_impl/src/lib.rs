@@ -12,6 +12,7 @@
 
 //! Macros for the [`templated_uri`](https://docs.rs/templated_uri) crate.
 
+mod catalog;
 mod enum_template;
 pub(crate) mod error;
 mod struct_template;
@@ -22,6 +23,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Attribute, DeriveInput, Field, parse_quote, parse2};
 
+use crate::catalog::catalog;
 use crate::enum_template::enum_template;
 use crate::struct_template::struct_template;
 use crate::uri_param::{raw_impl, uri_param_impl};
@@ -46,9 +48,13 @@ pub fn templated_paq_impl(attr: &TokenStream, item: TokenStream) -> TokenStream
         Err(err) => return err.to_compile_error(),
     };
 
-    // Generic types are not supported
-    if !input.generics.params.is_empty() {
-        return syn::Error::new_spanned(&input.generics, "Generic types are not supported for #[templated]").to_compile_error();
+    // Templates must be `'static`, so lifetime parameters are not supported; neither are
+    // generic enums, which only dispatch to the templates of their variants.
+    if let Some(lifetime) = input.generics.lifetimes().next() {
+        return syn::Error::new_spanned(lifetime, "Lifetime parameters are not supported for #[templated]").to_compile_error();
+    }
+    if matches!(input.data, syn::Data::Enum(_)) && !input.generics.params.is_empty() {
+        return syn::Error::new_spanned(&input.generics, "Generic enums are not supported for #[templated]").to_compile_error();
     }
 
     // If attributes were passed via the attribute macro, parse and add them
@@ -61,7 +67,7 @@ pub fn templated_paq_impl(attr: &TokenStream, item: TokenStream) -> TokenStream
     let original = filter_original(&input);
 
     let implementation = match input.data {
-        syn::Data::Struct(ref s) => struct_template(input.ident.clone(), s, &input.attrs),
+        syn::Data::Struct(ref s) => struct_template(input.ident.clone(), &input.generics, s, &input.attrs),
         syn::Data::Enum(ref e) => enum_template(&input.ident, e),
         syn::Data::Union(_) => {
             return syn::Error::new_spanned(input.ident, "Unions are not supported for TemplatedUri").to_compile_error();
@@ -74,13 +80,26 @@ pub fn templated_paq_impl(attr: &TokenStream, item: TokenStream) -> TokenStream
     }
 }
 
+#[must_use]
+#[cfg_attr(test, mutants::skip)] // not relevant for auto-generated proc macros
+pub fn templated_catalog_impl(attr: &TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new_spanned(attr, "#[templated_catalog] takes no options").to_compile_error();
+    }
+    match parse2(item) {
+        Ok(input) => catalog(input),
+        Err(err) => err.to_compile_error(),
+    }
+}
+
 #[cfg_attr(test, mutants::skip)] // not relevant for auto-generated proc macros
 fn filter_original(input: &DeriveInput) -> TokenStream {
     // Generate the original item definition WITHOUT the templated attribute
     let vis = &input.vis;
     let ident = &input.ident;
+    // The declared generics, unlike `split_for_impl()`, keep the defaults of type parameters.
     let generics = &input.generics;
-    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let where_clause = &generics.where_clause;
 
     // Filter out the 'templated' attribute from the output to avoid recursion
     let output_attrs: Vec<_> = input.attrs.iter().filter(|attr| !attr.path().is_ident("templated")).collect();
@@ -126,29 +145,40 @@ fn filter_original(input: &DeriveInput) -> TokenStream {
                 syn::Fields::Named(_) => {
                     quote! {
                         #(#output_attrs)*
-                        #vis struct #ident #impl_generics #filtered_fields #where_clause
+                        #vis struct #ident #generics #where_clause #filtered_fields
                     }
                 }
                 syn::Fields::Unnamed(_) => {
                     quote! {
                         #(#output_attrs)*
-                        #vis struct #ident #impl_generics #filtered_fields #where_clause;
+                        #vis struct #ident #generics #filtered_fields #where_clause;
                     }
                 }
                 syn::Fields::Unit => {
                     quote! {
                         #(#output_attrs)*
-                        #vis struct #ident #impl_generics #where_clause;
+                        #vis struct #ident #generics #where_clause;
                     }
                 }
             }
         }
         syn::Data::Enum(e) => {
-            let variants = &e.variants;
+            // Filter out templated attributes from variants, and templated and unredacted
+            // attributes from the fields of struct variants
+            let variants = e.variants.iter().map(|variant| {
+                let mut variant = variant.clone();
+                variant.attrs.retain(|attr| !attr.path().is_ident("templated"));
+                for field in &mut variant.fields {
+                    field
+                        .attrs
+                        .retain(|attr| !attr.path().is_ident("templated") && !attr.path().is_ident("unredacted"));
+                }
+                variant
+            });
             quote! {
                 #(#output_attrs)*
-                #vis enum #ident #impl_generics #where_clause {
-                    #variants
+                #vis enum #ident #generics #where_clause {
+                    #(#variants),*
                 }
             }
         }
@@ -156,7 +186,7 @@ fn filter_original(input: &DeriveInput) -> TokenStream {
             let fields = &u.fields;
             quote! {
                 #(#output_attrs)*
-                #vis union #ident #impl_generics #fields #where_clause
+                #vis union #ident #generics #where_clause #fields
             }
         }
     }
@@ -343,6 +373,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_field_level_classify() {
+        // Locks in that `classify` redacts the displayed value as the declared data class in
+        // every position: required, optional and list fields, even when the struct is unredacted.
+        assert_paq_snapshot!(
+            [template = "/users/{user_id}{/ids}{?email}", unredacted],
+            struct Test {
+                #[templated(classify = Taxonomy::Pii)]
+                user_id: u32,
+                #[templated(classify = Taxonomy::Pii)]
+                ids: Vec<u32>,
+                #[templated(classify = EMAIL)]
+                email: Option<EscapedString>,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unredacted_classified_field_error() {
+        assert_paq_compile_error!(
+            [template = "/users/{user_id}"],
+            struct Test {
+                #[unredacted]
+                #[templated(classify = Taxonomy::Pii)]
+                user_id: u32,
+            },
+            "A field cannot be both unredacted and classified"
+        );
+    }
+
     /// Locks in that the bare `#[unredacted]` field attribute and the namespaced
     /// `#[templated(unredacted)]` form produce **byte-identical** codegen, instead
     /// of duplicating the full codegen snapshot under two test names. The reference
@@ -410,6 +470,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_query_continuation_codegen() {
+        // `{&page}` follows an optional `{?query}`, so whether it starts or continues the query
+        // is tracked at runtime in `__query`. `{&sort}` follows the required `page`, so it
+        // always continues the query with `&`, and `{?query}` does not set `__query` for it.
+        assert_paq_snapshot!(
+            [template = "/items{?query}{&page}{&sort}", unredacted],
+            struct QueryContinuationTest {
+                query: Option<String>,
+                page: u32,
+                sort: Option<String>,
+            }
+        );
+    }
+
     #[test]
     fn test_optional_field_with_unredacted_codegen() {
         // Pins down the `unredacted || field_unredacted` semantics inside the
@@ -445,6 +520,97 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_level_4_modifiers_codegen() {
+        // Locks in the Level 4 render paths:
+        //   * `{name:3}` truncates through `escape_prefix_into` / `display_prefix`,
+        //   * `{/segments*}` and `{?filters*}` expand through the `explode_*` helpers with
+        //     the group separator and the named flag of their operator,
+        //   * the exploded `{?filters*}` group is treated as possibly undefined.
+        assert_paq_snapshot!(
+            [template = "/users/{name:3}{/segments*}{?filters*}"],
+            struct Level4Test {
+                name: String,
+                segments: Vec<EscapedString>,
+                filters: BTreeMap<EscapedString, EscapedString>,
+            }
+        );
+    }
+
+    #[test]
+    fn test_list_field_codegen() {
+        // Locks in the non-exploded list render paths: `Vec<T>` fields expand through the
+        // `explode_*` helpers with `exploded: false`, joining their items with commas, and
+        // make their group possibly undefined as an empty list is undefined.
+        assert_paq_snapshot!(
+            [template = "/items/{ids}{?tags,page}"],
+            struct ListTest {
+                ids: Vec<u32>,
+                tags: Option<Vec<EscapedString>>,
+                page: u32,
+            }
+        );
+    }
+
+    #[test]
+    fn test_prefixed_list_field_error() {
+        assert_paq_compile_error!(
+            [template = "/items/{ids:3}"],
+            struct PrefixedListTest {
+                ids: Vec<u32>,
+            },
+            "Prefix modifiers cannot be applied to list field `ids`"
+        );
+    }
+
+    #[test]
+    fn test_serde_codegen() {
+        // Locks in the `serde` option: `Serialize` goes through the rendered path and query,
+        // `Deserialize` matches it against the template with the non-exploded list `ids`
+        // and bounds the type parameter by `DeserializeOwned`.
+        assert_paq_snapshot!(
+            [template = "/items/{ids}{/id}{?tags*}", serde],
+            struct SerdeTest<T: Escape> {
+                ids: Vec<u32>,
+                id: T,
+                tags: Vec<EscapedString>,
+            }
+        );
+    }
+
+    #[test]
+    fn test_openapi_template_errors() {
+        assert_paq_compile_error!(
+            [openapi = "/users/{id}{?fields}"],
+            struct OpenApiTest {
+                id: u32,
+                fields: String,
+            },
+            "OpenAPI path templates only support `{name}` expressions, found an RFC 6570 expression for `fields`"
+        );
+        assert_paq_compile_error!(
+            [openapi = "/users/{id}?active=true"],
+            struct OpenApiTest {
+                id: u32,
+            },
+            "OpenAPI path templates cannot have a query"
+        );
+        assert_paq_compile_error!(
+            [template = "/users/{id}", openapi = "/users/{id}"],
+            struct OpenApiTest {
+                id: u32,
+            },
+            "Only one of `template` and `openapi` can be set for #[templated]"
+        );
+        assert_paq_compile_error!(
+            [unredacted],
+            struct OpenApiTest {
+                id: u32,
+            },
+            "Missing `template` or `openapi` for #[templated]"
+        );
+    }
+
     #[test]
     fn test_excessive_template_impl() {
         assert_paq_compile_error!(
@@ -509,6 +675,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_enum_unit_and_struct_variants() {
+        assert_paq_snapshot!(
+            enum Test {
+                #[templated(template = "/healthz", label = "health")]
+                HealthCheck,
+                #[templated(template = "/users/{user_id}{?fields}")]
+                User {
+                    #[unredacted]
+                    user_id: u32,
+                    fields: Option<Vec<Field>>,
+                },
+                Other(Other),
+            }
+        );
+    }
+
+    #[test]
+    fn test_enum_variant_errors() {
+        assert_paq_compile_error!(
+            enum Test {
+                HealthCheck,
+            },
+            "Missing `template` or `openapi` for #[templated]"
+        );
+        assert_paq_compile_error!(
+            enum Test {
+                #[templated(template = "/healthz/{id}")]
+                HealthCheck,
+            },
+            "Missing values in struct"
+        );
+        assert_paq_compile_error!(
+            enum Test {
+                #[templated(template = "/users/{id}", serde)]
+                User { id: u32 },
+            },
+            "`serde` is not supported for enum variants"
+        );
+        assert_paq_compile_error!(
+            enum Test {
+                #[templated(template = "/users/{id}")]
+                User(UserPath),
+            },
+            "Tuple variants use the template of their field"
+        );
+    }
+
     #[test]
     fn test_enum_single_item_only_error() {
         assert_paq_snapshot!(
@@ -528,6 +742,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_catalog_codegen() {
+        let output = templated_catalog_impl(
+            &quote! {},
+            quote! {
+                #[derive(Clone)]
+                pub enum Test {
+                    #[templated(template = "/healthz")]
+                    HealthCheck,
+                    /// A single user.
+                    #[templated(template = "/users/{user_id}{?fields}", label = "user")]
+                    User {
+                        #[unredacted]
+                        user_id: u32,
+                        fields: Option<Vec<Field>>,
+                    },
+                    Other(Other),
+                }
+            },
+        );
+        let output_pretty = prettyplease::unparse(&syn::parse_file(&output.to_string()).unwrap());
+        insta::assert_snapshot!(output_pretty);
+    }
+
+    #[test]
+    fn test_catalog_errors() {
+        let output = templated_catalog_impl(&quote! { label = "api" }, quote! { enum Test {} }).to_string();
+        assert!(output.contains("#[templated_catalog] takes no options"), "{output}");
+
+        let output = templated_catalog_impl(&quote! {}, quote! { struct Test {} }).to_string();
+        assert!(output.contains("#[templated_catalog] can only be applied to enums"), "{output}");
+
+        let output = templated_catalog_impl(&quote! {}, quote! { fn not_an_enum() {} }).to_string();
+        assert!(output.contains("compile_error"), "{output}");
+    }
+
     #[test]
     fn test_raw_impl() {
         assert_raw_snapshot!(
@@ -788,15 +1038,42 @@ mod tests {
     }
 
     #[test]
-    fn test_generic_struct_rejected() {
+    fn test_generic_struct_codegen() {
+        // Locks in that the generics and where-clause of the struct are kept on its
+        // declaration, defaults included, and propagated to every generated impl.
         assert_paq_snapshot!(
-            [template = "/{param}"],
-            struct GenericTemplate<T> {
+            [template = "/{param}/{+rest}"],
+            struct GenericTemplate<T: Escape, R = String, const N: usize = 1>
+            where
+                R: Raw,
+            {
                 param: T,
+                rest: R,
             }
         );
     }
 
+    #[test]
+    fn test_lifetime_struct_rejected() {
+        assert_paq_compile_error!(
+            [template = "/{param}"],
+            struct LifetimeTemplate<'a> {
+                param: &'a str,
+            },
+            "Lifetime parameters are not supported"
+        );
+    }
+
+    #[test]
+    fn test_generic_enum_rejected() {
+        assert_paq_compile_error!(
+            enum GenericEnum<T> {
+                Variant(T),
+            },
+            "Generic enums are not supported"
+        );
+    }
+
     #[test]
     fn test_generic_uri_param_rejected() {
         let input = quote! {
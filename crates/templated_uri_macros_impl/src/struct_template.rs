@@ -10,27 +10,82 @@
 use std::collections::HashSet;
 
 use darling::{FromAttributes, FromField};
-use proc_macro2::{Ident, TokenStream};
-use quote::{quote, quote_spanned};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::ext::IdentExt;
 use syn::spanned::Spanned;
-use syn::{Attribute, DataStruct, Field};
+use syn::{Attribute, DataStruct, Field, Generics, parse_quote};
 
-use crate::template_parser::{ParamGroup, TemplatePart, UriTemplate};
+use crate::template_parser::{Modifier, ParamGroup, TemplatePart, UriTemplate, Variable};
 
-type FieldMap<'a> = std::collections::HashMap<String, &'a Field>;
 type FieldOptsMap<'a> = std::collections::HashMap<String, &'a FieldOpts>;
 
+/// How the generated code reaches the values of the fields of a template.
+#[derive(Clone, Copy)]
+pub(crate) enum Receiver {
+    /// The fields of `self`, in the impls of a struct.
+    Struct,
+    /// The bindings of a `Self::Variant { field: __field, .. }` pattern, in the impls of an
+    /// enum. The bindings are references to the fields.
+    Variant,
+}
+
+impl Receiver {
+    /// Returns the name a variant pattern binds the field `ident` to. The `__` prefix keeps
+    /// fields such as `f` from shadowing the locals of the generated code.
+    pub(crate) fn binding(ident: &Ident) -> Ident {
+        format_ident!("__{}", ident.unraw())
+    }
+}
+
+/// The fields of a template by name.
+struct FieldMap<'a> {
+    fields: std::collections::HashMap<String, &'a Field>,
+    receiver: Receiver,
+}
+
+impl<'a> FieldMap<'a> {
+    fn new(fields: &[&'a Field], receiver: Receiver) -> Self {
+        let fields = fields
+            .iter()
+            .filter_map(|f| f.ident.as_ref().map(|ident| (ident.to_string(), *f)))
+            .collect();
+        Self { fields, receiver }
+    }
+
+    fn get(&self, name: &str) -> Option<&&'a Field> {
+        self.fields.get(name)
+    }
+
+    /// Returns the place expression of the value of the field `ident`, such as `self.id`.
+    fn value(&self, ident: &Ident, span: Span) -> TokenStream {
+        match self.receiver {
+            Receiver::Struct => quote_spanned! { span => self.#ident },
+            Receiver::Variant => {
+                let binding = Receiver::binding(ident);
+                quote_spanned! { span => *#binding }
+            }
+        }
+    }
+}
+
 #[derive(Debug, FromAttributes)]
 #[darling(attributes(templated))]
 pub(crate) struct Opts {
-    #[darling(rename = "template")]
-    pub input_template: String,
+    #[darling(default, rename = "template")]
+    pub input_template: Option<String>,
+    /// An `OpenAPI` path template such as `/users/{id}`, used instead of `template`.
+    #[darling(default)]
+    pub openapi: Option<String>,
     #[darling(default)]
     pub unredacted: bool,
     /// Optional label for telemetry. When provided, this label is used in metrics
     /// instead of the full template string, which is useful for complex templates.
     #[darling(default)]
     pub label: Option<String>,
+    /// Whether to implement `Serialize` and `Deserialize` through the rendered path and query.
+    #[darling(default)]
+    pub serde: bool,
 }
 
 #[derive(Debug, FromField)]
@@ -39,6 +94,33 @@ pub(crate) struct FieldOpts {
     pub ident: Option<Ident>,
     #[darling(default)]
     pub unredacted: bool,
+    /// The data class to redact the field as, such as `MyTaxonomy::Pii`, instead of the
+    /// classification of its type.
+    #[darling(default)]
+    pub classify: Option<syn::Path>,
+}
+
+/// How the `RedactedDisplay` impl displays the value of a field.
+enum Redaction<'a> {
+    /// Displayed as is, with `Display`.
+    Unredacted,
+    /// Displayed with `Display`, then redacted as the given data class.
+    Classified(&'a syn::Path),
+    /// Redacted by the `RedactedDisplay` impl of the field type.
+    Redacted,
+}
+
+impl<'a> Redaction<'a> {
+    /// Determines the redaction of the field of `name`. A field's own `classify` takes
+    /// precedence over the struct-level `unredacted`.
+    fn of(name: &str, field_opts_map: &FieldOptsMap<'a>, unredacted: bool) -> Self {
+        let opts = field_opts_map.get(name);
+        match opts.and_then(|opts| opts.classify.as_ref()) {
+            Some(data_class) => Self::Classified(data_class),
+            None if unredacted || opts.is_some_and(|opts| opts.unredacted) => Self::Unredacted,
+            None => Self::Redacted,
+        }
+    }
 }
 
 /// Represents the fields of a struct with their options parsed from attributes.
@@ -57,6 +139,9 @@ impl Fields {
                 if !opts.unredacted {
                     opts.unredacted = f.attrs.iter().any(|attr| attr.path().is_ident("unredacted"));
                 }
+                if opts.unredacted && opts.classify.is_some() {
+                    return Err(darling::Error::custom("A field cannot be both unredacted and classified").with_span(f));
+                }
                 Ok(opts)
             })
             .collect::<darling::Result<Vec<_>>>()?;
@@ -73,74 +158,55 @@ impl Fields {
 }
 
 // #[proc_macro_derive(TemplatedPathAndQuery, attributes(templated, unredacted))]
-pub(crate) fn struct_template(ident: Ident, data: &DataStruct, attrs: &[Attribute]) -> TokenStream {
+pub(crate) fn struct_template(ident: Ident, generics: &Generics, data: &DataStruct, attrs: &[Attribute]) -> TokenStream {
     if !matches!(data.fields, syn::Fields::Named(_)) {
         crate::bail!(ident, "#[templated] can only be applied to structs with named fields");
     }
 
     // Parse the derive input using the Opts struct with custom parsing
     let struct_name = ident.to_string();
-    let Opts {
-        input_template,
-        unredacted,
-        label,
-    } = match Opts::from_attributes(attrs) {
+    let opts = match Opts::from_attributes(attrs) {
         Ok(opts) => opts,
         Err(err) => return err.write_errors(),
     };
 
-    let template = match UriTemplate::parse(&input_template) {
-        Ok(template) => template,
-        Err(err) => {
-            return err.to_compile_error(ident.span());
-        }
+    let input_template = match TemplateSource::from_opts(&opts, &ident) {
+        Ok(input_template) => input_template,
+        Err(err) => return err,
     };
 
-    let format_template = template.format_template();
+    let template = match input_template.parse(&ident) {
+        Ok(template) => template,
+        Err(err) => return err,
+    };
 
-    let template_params: Vec<_> = template.params().collect();
     let struct_fields: Vec<&Field> = data.fields.iter().collect();
 
-    let fields = match Fields::from_fields(struct_fields.as_slice()) {
-        Ok(fields) => fields,
-        Err(err) => return err.write_errors(),
+    let input_template = input_template.as_str();
+    let TemplateCode {
+        format_template,
+        label,
+        render_statements,
+        render_capacity,
+        write_statements,
+        redacted_display,
+    } = match TemplateCode::new(&ident, &opts, &template, &struct_fields, Receiver::Struct) {
+        Ok(code) => code,
+        Err(err) => return err,
     };
 
-    let struct_field_names = fields.field_names();
-    let template_param_names = template.param_names();
-
-    // Compare the template parameters with the struct fields and errors if there are mismatches.
-    let mut excess_values: Vec<_> = struct_field_names.difference(&template_param_names).collect();
-    excess_values.sort();
-
-    let mut missing_values: Vec<_> = template_param_names.difference(&struct_field_names).collect();
-    missing_values.sort();
-
-    if !missing_values.is_empty() {
-        crate::bail!(ident, "Missing values in struct: {missing_values:?}")
-    }
-
-    if !excess_values.is_empty() {
-        crate::bail!(ident, "Excess values in struct: {excess_values:?}")
-    }
-
-    // Determine which parameters are unrestricted (Can contain any value) and which are restricted (Must be `Escaped`).
-    let unrestricted_params: HashSet<String> = template_params
-        .iter()
-        .filter(|p| p.is_unrestricted)
-        .map(|p| p.name.to_owned())
-        .collect();
+    let telemetry_label = opts.label.as_deref().unwrap_or(input_template);
 
-    let (render_statements, render_capacity) = construct_render(&template, &struct_fields, &unrestricted_params);
-    let redacted_display = construct_redacted_display(&template, &struct_fields, &fields, unredacted);
+    let serde_impls = opts
+        .serde
+        .then(|| construct_serde(&ident, generics, &template, &struct_fields, input_template));
 
-    let label_impl = label.as_ref().map_or_else(
-        || quote! { ::core::option::Option::None },
-        |l| quote! { ::core::option::Option::Some(#l) },
-    );
+    // The bounds the generated code needs on type parameters, such as `T: Escape` for a
+    // field `id: T` in `{id}`, are left to the declaration of the struct.
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     quote! {
-        impl ::templated_uri::PathAndQueryTemplate for #ident {
+        impl #impl_generics ::templated_uri::PathAndQueryTemplate for #ident #ty_generics #where_clause {
             fn template(&self) -> &'static core::primitive::str {
                 #input_template
             }
@@ -150,7 +216,11 @@ pub(crate) fn struct_template(ident: Ident, data: &DataStruct, attrs: &[Attribut
             }
 
             fn label(&self) -> ::core::option::Option<&'static core::primitive::str> {
-                #label_impl
+                #label
+            }
+
+            fn telemetry_labels() -> ::std::vec::Vec<&'static core::primitive::str> {
+                ::std::vec![#telemetry_label]
             }
 
             fn render(&self) -> ::std::string::String {
@@ -163,6 +233,11 @@ pub(crate) fn struct_template(ident: Ident, data: &DataStruct, attrs: &[Attribut
                 #(#render_statements)*
             }
 
+            fn write_uri(&self, __out: &mut dyn ::std::fmt::Write) -> ::std::fmt::Result {
+                #(#write_statements)*
+                ::std::result::Result::Ok(())
+            }
+
             fn render_capacity_hint(&self) -> ::core::primitive::usize {
                 #render_capacity
             }
@@ -172,7 +247,7 @@ pub(crate) fn struct_template(ident: Ident, data: &DataStruct, attrs: &[Attribut
             }
         }
 
-        impl ::std::fmt::Debug for #ident {
+        impl #impl_generics ::std::fmt::Debug for #ident #ty_generics #where_clause {
             fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
                 f.debug_tuple(#struct_name)
                     .field(&#input_template)
@@ -180,18 +255,256 @@ pub(crate) fn struct_template(ident: Ident, data: &DataStruct, attrs: &[Attribut
             }
         }
 
-        impl ::templated_uri::__private::RedactedDisplay for #ident {
+        impl #impl_generics ::templated_uri::__private::RedactedDisplay for #ident #ty_generics #where_clause {
             fn fmt(&self, redactor: &dyn ::templated_uri::__private::Redactor, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
                 #redacted_display
             }
         }
 
-        impl From<#ident> for ::templated_uri::PathAndQuery {
-            fn from(value: #ident) -> Self {
+        impl #impl_generics From<#ident #ty_generics> for ::templated_uri::PathAndQuery #where_clause {
+            fn from(value: #ident #ty_generics) -> Self {
                 ::templated_uri::PathAndQuery::from_template(value)
             }
         }
+
+        #serde_impls
+    }
+}
+
+/// The generated code of a template, shared by the impls of structs and of the unit and
+/// struct variants of enums.
+pub(crate) struct TemplateCode {
+    pub format_template: String,
+    /// The `Option<&'static str>` expression of the label.
+    pub label: TokenStream,
+    /// The statements appending the rendered template to `__out`.
+    pub render_statements: Vec<TokenStream>,
+    pub render_capacity: usize,
+    /// The statements writing the rendered template to `__out: &mut dyn fmt::Write`.
+    pub write_statements: Vec<TokenStream>,
+    /// The body of `RedactedDisplay::fmt`.
+    pub redacted_display: TokenStream,
+}
+
+impl TemplateCode {
+    /// Generates the code rendering `template` from `struct_fields`, returning the compile
+    /// error to emit if the fields don't match the template.
+    pub(crate) fn new(
+        ident: &Ident,
+        opts: &Opts,
+        template: &UriTemplate<'_>,
+        struct_fields: &[&Field],
+        receiver: Receiver,
+    ) -> Result<Self, TokenStream> {
+        let fields = Fields::from_fields(struct_fields).map_err(darling::Error::write_errors)?;
+        check_fields(ident, &fields, template)?;
+        reject_prefixed_lists(template, struct_fields)?;
+
+        // Determine which parameters are unrestricted (Can contain any value) and which are restricted (Must be `Escaped`).
+        let unrestricted_params: HashSet<String> = template.params().filter(|p| p.is_unrestricted).map(|p| p.name.to_owned()).collect();
+
+        let (render_statements, render_capacity) = construct_render(template, struct_fields, receiver, &unrestricted_params, Sink::Buffer);
+        let (write_statements, _) = construct_render(template, struct_fields, receiver, &unrestricted_params, Sink::Writer);
+        let redacted_display = construct_redacted_display(template, struct_fields, receiver, &fields, opts.unredacted);
+
+        let label = opts.label.as_ref().map_or_else(
+            || quote! { ::core::option::Option::None },
+            |l| quote! { ::core::option::Option::Some(#l) },
+        );
+
+        Ok(Self {
+            format_template: template.format_template(),
+            label,
+            render_statements,
+            render_capacity,
+            write_statements,
+            redacted_display,
+        })
+    }
+}
+
+/// Generates the `Serialize` and `Deserialize` impls of `#[templated(serde)]`, which
+/// round-trip through the rendered path and query.
+///
+/// Deserialization matches the path and query against the template and deserializes each
+/// field from the values captured for its variable, so type parameters are additionally
+/// bound by `DeserializeOwned` in the `Deserialize` impl.
+fn construct_serde(
+    ident: &Ident,
+    generics: &Generics,
+    template: &UriTemplate,
+    struct_fields: &[&Field],
+    input_template: &str,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut de_generics = generics.clone();
+    de_generics.params.insert(0, parse_quote! { '__de });
+    let de_where_clause = de_generics.make_where_clause();
+    for param in generics.type_params() {
+        let param = &param.ident;
+        de_where_clause
+            .predicates
+            .push(parse_quote! { #param: ::templated_uri::__private::serde::de::DeserializeOwned });
+    }
+    let (de_impl_generics, _, de_where_clause) = de_generics.split_for_impl();
+
+    // Non-exploded list variables capture their items joined with commas.
+    let lists: Vec<&str> = template
+        .template_parts()
+        .iter()
+        .filter_map(|part| match part {
+            TemplatePart::ParamGroup(group) => Some(group.variables()),
+            TemplatePart::Content(_) => None,
+        })
+        .flatten()
+        .filter(|variable| variable.modifier != Some(Modifier::Explode))
+        .filter(|variable| {
+            struct_fields
+                .iter()
+                .any(|f| f.ident.as_ref().is_some_and(|ident| ident == variable.name) && is_list(&f.ty))
+        })
+        .map(|variable| variable.name)
+        .collect();
+
+    let field_values = struct_fields.iter().filter_map(|f| f.ident.as_ref()).map(|field_ident| {
+        let name = field_ident.to_string();
+        quote! {
+            #field_ident: ::templated_uri::__private::deserialize_variable::<_, __D::Error>(&__captures, #name)?
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::templated_uri::__private::serde::Serialize for #ident #ty_generics #where_clause {
+            fn serialize<__S: ::templated_uri::__private::serde::Serializer>(&self, serializer: __S) -> ::std::result::Result<__S::Ok, __S::Error> {
+                ::templated_uri::__private::serialize_rendered(self, serializer)
+            }
+        }
+
+        impl #de_impl_generics ::templated_uri::__private::serde::Deserialize<'__de> for #ident #ty_generics #de_where_clause {
+            fn deserialize<__D: ::templated_uri::__private::serde::Deserializer<'__de>>(deserializer: __D) -> ::std::result::Result<Self, __D::Error> {
+                let __rendered = <::std::string::String as ::templated_uri::__private::serde::Deserialize>::deserialize(deserializer)?;
+                let __template = ::templated_uri::__private::parse_template::<__D::Error>(#input_template)?;
+                let __captures = ::templated_uri::__private::match_rendered::<__D::Error>(&__template, &__rendered, &[#(#lists),*])?;
+                ::std::result::Result::Ok(Self {
+                    #(#field_values,)*
+                })
+            }
+        }
+    }
+}
+
+/// Compares the template parameters with the struct fields, returning the compile error to
+/// emit if there are mismatches.
+fn check_fields(ident: &Ident, fields: &Fields, template: &UriTemplate) -> Result<(), TokenStream> {
+    let struct_field_names = fields.field_names();
+    let template_param_names = template.param_names();
+
+    let mut excess_values: Vec<_> = struct_field_names.difference(&template_param_names).collect();
+    excess_values.sort();
+
+    let mut missing_values: Vec<_> = template_param_names.difference(&struct_field_names).collect();
+    missing_values.sort();
+
+    if !missing_values.is_empty() {
+        return Err(syn::Error::new_spanned(ident, format!("Missing values in struct: {missing_values:?}")).to_compile_error());
+    }
+
+    if !excess_values.is_empty() {
+        return Err(syn::Error::new_spanned(ident, format!("Excess values in struct: {excess_values:?}")).to_compile_error());
+    }
+
+    Ok(())
+}
+
+/// The template of a struct, in the syntax of the attribute option it was given with.
+pub(crate) enum TemplateSource<'a> {
+    /// `template = "..."`, an RFC 6570 template.
+    Rfc6570(&'a str),
+    /// `openapi = "..."`, an `OpenAPI` path template.
+    OpenApi(&'a str),
+}
+
+impl<'a> TemplateSource<'a> {
+    /// Returns the template set in `opts`, returning the compile error to emit unless
+    /// exactly one of `template` and `openapi` is set.
+    pub(crate) fn from_opts(opts: &'a Opts, ident: &Ident) -> Result<Self, TokenStream> {
+        match (&opts.input_template, &opts.openapi) {
+            (Some(input_template), None) => Ok(Self::Rfc6570(input_template)),
+            (None, Some(openapi)) => Ok(Self::OpenApi(openapi)),
+            (Some(_), Some(_)) => {
+                Err(syn::Error::new_spanned(ident, "Only one of `template` and `openapi` can be set for #[templated]").to_compile_error())
+            }
+            (None, None) => Err(syn::Error::new_spanned(ident, "Missing `template` or `openapi` for #[templated]").to_compile_error()),
+        }
+    }
+
+    pub(crate) const fn as_str(&self) -> &'a str {
+        match self {
+            Self::Rfc6570(source) | Self::OpenApi(source) => source,
+        }
+    }
+
+    /// Parses the template, returning the compile error to emit if it is invalid.
+    pub(crate) fn parse(&self, ident: &Ident) -> Result<UriTemplate<'a>, TokenStream> {
+        let template = UriTemplate::parse(self.as_str()).map_err(|err| err.to_compile_error(ident.span()))?;
+        if let Self::OpenApi(_) = self {
+            check_openapi(&template).map_err(|message| syn::Error::new(ident.span(), message).to_compile_error())?;
+        }
+        Ok(template)
+    }
+}
+
+/// Checks that `template` is an `OpenAPI` path template: a path whose expressions are all
+/// `{name}`, which are valid RFC 6570 simple expansions as they are.
+fn check_openapi(template: &UriTemplate) -> Result<(), String> {
+    for part in template.template_parts() {
+        match part {
+            TemplatePart::Content(content) if content.contains('?') => {
+                return Err("OpenAPI path templates cannot have a query".to_owned());
+            }
+            TemplatePart::Content(_) => {}
+            TemplatePart::ParamGroup(group) => {
+                if let [variable] = group.variables()
+                    && group.is_simple()
+                    && variable.modifier.is_none()
+                {
+                    continue;
+                }
+                let name = group.variables().first().map_or("", |variable| variable.name);
+                return Err(format!(
+                    "OpenAPI path templates only support `{{name}}` expressions, found an RFC 6570 expression for `{name}`"
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Rejects prefix modifiers (`{var:3}`) on list fields, which RFC 6570 section 2.4.1 does
+/// not define, returning the compile error to emit.
+fn reject_prefixed_lists(template: &UriTemplate, struct_fields: &[&Field]) -> Result<(), TokenStream> {
+    let variables = template.template_parts().iter().flat_map(|part| match part {
+        TemplatePart::Content(_) => &[][..],
+        TemplatePart::ParamGroup(group) => group.variables(),
+    });
+
+    for variable in variables {
+        let Some(Modifier::Prefix(_)) = variable.modifier else {
+            continue;
+        };
+        let name = variable.name;
+        let field_type = struct_fields
+            .iter()
+            .find(|f| f.ident.as_ref().is_some_and(|ident| ident == name))
+            .map(|f| &f.ty);
+        if let Some(field_type) = field_type.filter(|ty| is_list(ty)) {
+            let message = format!("Prefix modifiers cannot be applied to list field `{name}`");
+            return Err(syn::Error::new_spanned(field_type, message).to_compile_error());
+        }
     }
+
+    Ok(())
 }
 
 /// Checks whether `ty` is syntactically `Option<T>` (or `std::option::Option<T>`, etc.)
@@ -200,11 +513,24 @@ pub(crate) fn struct_template(ident: Ident, data: &DataStruct, attrs: &[Attribut
 /// This is the standard approach used by serde, clap, and other derive macros.
 /// It won't detect type aliases for `Option`, which is a known and accepted limitation.
 fn extract_option_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    extract_generic_inner(ty, "Option")
+}
+
+/// Checks whether `ty` is syntactically `Vec<T>` or `Option<Vec<T>>`, a list whose items
+/// non-exploded variables such as `{list}` join with commas.
+///
+/// As with [`extract_option_inner`], type aliases for `Vec` are not detected.
+fn is_list(ty: &syn::Type) -> bool {
+    extract_generic_inner(extract_option_inner(ty).unwrap_or(ty), "Vec").is_some()
+}
+
+/// Checks whether `ty` is syntactically a path ending in `name<T>` and returns `T`.
+fn extract_generic_inner<'a>(ty: &'a syn::Type, name: &str) -> Option<&'a syn::Type> {
     let syn::Type::Path(type_path) = ty else {
         return None;
     };
     let last_segment = type_path.path.segments.last()?;
-    if last_segment.ident != "Option" {
+    if last_segment.ident != name {
         return None;
     }
     let syn::PathArguments::AngleBracketed(args) = &last_segment.arguments else {
@@ -219,6 +545,25 @@ fn extract_option_inner(ty: &syn::Type) -> Option<&syn::Type> {
     Some(inner_ty)
 }
 
+/// Where the render code generated by [`construct_render`] writes the rendered template.
+#[derive(Clone, Copy)]
+enum Sink {
+    /// Appends to `__out: &mut String`, in `render_into`.
+    Buffer,
+    /// Writes to `__out: &mut dyn fmt::Write`, in `write_uri`, returning its errors.
+    Writer,
+}
+
+impl Sink {
+    /// Returns the statement writing the `&str` expression `s`.
+    fn write_str(self, s: &TokenStream) -> TokenStream {
+        match self {
+            Self::Buffer => quote! { __out.push_str(#s); },
+            Self::Writer => quote! { __out.write_str(#s)?; },
+        }
+    }
+}
+
 /// Generates the append statements and capacity hint for the render methods.
 ///
 /// Walks the parsed template parts and emits statements that append into a buffer named
@@ -226,11 +571,15 @@ fn extract_option_inner(ty: &syn::Type) -> Option<&syn::Type> {
 /// field values), handling RFC 6570 undefined-value semantics for `Option<T>` fields.
 /// Returns the statements plus the compile-time capacity estimate so the caller can build
 /// both `render` (owns a sized buffer) and `render_into` (appends into a caller buffer).
-fn construct_render(template: &UriTemplate, struct_fields: &[&Field], unrestricted_params: &HashSet<String>) -> (Vec<TokenStream>, usize) {
-    let field_map: FieldMap<'_> = struct_fields
-        .iter()
-        .filter_map(|f| f.ident.as_ref().map(|ident| (ident.to_string(), *f)))
-        .collect();
+/// With [`Sink::Writer`], the statements write to a `fmt::Write` for `write_uri` instead.
+fn construct_render(
+    template: &UriTemplate,
+    struct_fields: &[&Field],
+    receiver: Receiver,
+    unrestricted_params: &HashSet<String>,
+    sink: Sink,
+) -> (Vec<TokenStream>, usize) {
+    let field_map = FieldMap::new(struct_fields, receiver);
 
     // Compile-time heuristic used to pre-size the buffer. See `render_capacity_hint` for
     // the precise semantics; it may slightly over-allocate when a group's parameters are
@@ -238,16 +587,21 @@ fn construct_render(template: &UriTemplate, struct_fields: &[&Field], unrestrict
     // `String::new()` would incur.
     let initial_capacity = render_capacity_hint(template);
 
-    let statements: Vec<TokenStream> = template
-        .template_parts()
-        .iter()
-        .flat_map(|part| match part {
-            TemplatePart::Content(content) => {
-                vec![quote! { __out.push_str(#content); }]
+    let (prefixes, reads_query) = group_prefixes(template, &field_map);
+    let mut prefixes = prefixes.iter();
+    let mut statements = Vec::new();
+    if reads_query {
+        statements.push(quote! { let mut __query = false; });
+    }
+    for part in template.template_parts() {
+        match part {
+            TemplatePart::Content(content) => statements.push(sink.write_str(&quote! { #content })),
+            TemplatePart::ParamGroup(group) => {
+                let prefix = prefixes.next().expect("there is a prefix for each parameter group");
+                statements.extend(construct_render_group(group, prefix, &field_map, unrestricted_params, sink));
             }
-            TemplatePart::ParamGroup(group) => construct_render_group(group, &field_map, unrestricted_params),
-        })
-        .collect();
+        }
+    }
 
     (statements, initial_capacity)
 }
@@ -283,30 +637,177 @@ fn render_capacity_hint(template: &UriTemplate) -> usize {
             TemplatePart::Content(content) => content.len(),
             TemplatePart::ParamGroup(group) => {
                 let prefix_len = group.prefix().map_or(0, str::len);
-                let param_names = group.param_names();
+                let variables = group.variables();
                 // Every RFC 6570 separator emitted by `ParamKind::separator()` (`,`, `;`,
                 // `&`, `.`, `/`) is exactly 1 byte, so the separator-byte count equals the
                 // number of separators. If the vocabulary ever grows a multi-byte member,
                 // multiply by `group.separator().len()` and add a unit test for it.
-                let separators_len = param_names.len().saturating_sub(1);
+                let separators_len = variables.len().saturating_sub(1);
                 let kv_len = if group.is_kv() {
                     // Each value gets `key=` prepended.
-                    param_names.iter().map(|n| n.len() + 1).sum::<usize>()
+                    variables.iter().map(|v| v.name.len() + 1).sum::<usize>()
                 } else {
                     0
                 };
-                let values_len = param_names.len() * ESTIMATED_VALUE_LEN;
+                let values_len = variables.len() * ESTIMATED_VALUE_LEN;
                 prefix_len + separators_len + kv_len + values_len
             }
         })
         .sum()
 }
 
-/// Returns true if any parameter in `param_names` is backed by an `Option<T>` field.
-fn group_has_any_optional(param_names: &[&str], field_map: &FieldMap<'_>) -> bool {
-    param_names
-        .iter()
-        .any(|name| field_map.get(*name).is_some_and(|f| extract_option_inner(&f.ty).is_some()))
+/// The prefix a parameter group writes before its first defined value.
+///
+/// This is the prefix of the operator of the group, except for `{&...}` groups: they
+/// continue the query with `&` after a literal `?` or a query group that always has a value,
+/// and start it with `?` if no query group precedes them, so an undefined `{?...}` group does
+/// not leave a dangling `&`. If only query groups that may be undefined precede one, the
+/// generated code tracks in `__query` whether one of them started the query.
+struct GroupPrefix {
+    /// An expression of type `&'static str`, or `None` if the group has no prefix.
+    prefix: Option<TokenStream>,
+    /// Whether the group sets `__query` once it writes a value, as a later group reads it.
+    sets_query: bool,
+}
+
+/// Whether the query of a URI has started at some point of its template.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum QueryState {
+    NotStarted,
+    MaybeStarted,
+    Started,
+}
+
+/// Returns the [`GroupPrefix`] of each parameter group of `template`, in order, and whether
+/// any of them reads `__query`.
+fn group_prefixes(template: &UriTemplate, field_map: &FieldMap<'_>) -> (Vec<GroupPrefix>, bool) {
+    let mut prefixes: Vec<GroupPrefix> = Vec::new();
+    let mut state = QueryState::NotStarted;
+    // The query groups that may be undefined since the query was last known not to have started.
+    let mut maybe_started_by: Vec<usize> = Vec::new();
+    let mut reads_query = false;
+
+    for part in template.template_parts() {
+        let group = match part {
+            TemplatePart::Content(content) => {
+                if content.contains('?') {
+                    state = QueryState::Started;
+                }
+                continue;
+            }
+            TemplatePart::ParamGroup(group) => group,
+        };
+
+        let prefix = if group.is_query_continuation() {
+            Some(match state {
+                QueryState::NotStarted => quote! { "?" },
+                QueryState::Started => quote! { "&" },
+                QueryState::MaybeStarted => {
+                    reads_query = true;
+                    for index in std::mem::take(&mut maybe_started_by) {
+                        prefixes[index].sets_query = true;
+                    }
+                    quote! { if __query { "&" } else { "?" } }
+                }
+            })
+        } else {
+            group.prefix().map(|prefix| quote! { #prefix })
+        };
+        prefixes.push(GroupPrefix { prefix, sets_query: false });
+
+        if group.is_query() && state != QueryState::Started {
+            if group_may_be_undefined(group, field_map) {
+                state = QueryState::MaybeStarted;
+                maybe_started_by.push(prefixes.len() - 1);
+            } else {
+                state = QueryState::Started;
+            }
+        }
+    }
+
+    (prefixes, reads_query)
+}
+
+/// Returns true if the values of `group` may be undefined, so its prefix and separators
+/// have to be tracked at runtime: if any parameter is backed by an `Option<T>` or `Vec<T>`
+/// field or is exploded, as an empty list or associative array is undefined too.
+fn group_may_be_undefined(group: &ParamGroup, field_map: &FieldMap<'_>) -> bool {
+    group.has_explode()
+        || group.variables().iter().any(|variable| {
+            field_map
+                .get(variable.name)
+                .is_some_and(|f| extract_option_inner(&f.ty).is_some() || is_list(&f.ty))
+        })
+}
+
+/// Returns true if `variable` expands a list or associative array through the `explode_*`
+/// helpers: if it is exploded or backed by a `Vec<T>` field.
+fn is_composite(variable: &Variable, field: &Field) -> bool {
+    variable.modifier == Some(Modifier::Explode) || is_list(&field.ty)
+}
+
+/// Returns the `ExplodeSpec` describing how the items of composite `variable` are delimited.
+fn explode_spec(group: &ParamGroup, prefix: &GroupPrefix, variable: &Variable) -> TokenStream {
+    let name = variable.name;
+    let prefix = prefix.prefix.clone().unwrap_or_else(|| quote! { "" });
+    let separator = group.separator();
+    let named = group.is_kv();
+    let exploded = variable.modifier == Some(Modifier::Explode);
+    quote! {
+        &::templated_uri::__private::ExplodeSpec {
+            name: #name,
+            prefix: #prefix,
+            separator: #separator,
+            named: #named,
+            exploded: #exploded,
+        }
+    }
+}
+
+/// Returns the statement appending the value of a non-exploded `variable` to `__out`,
+/// truncating it first if the variable has a prefix modifier (`{var:3}`).
+fn render_value(variable: &Variable, receiver: &TokenStream, unrestricted: bool, ty_span: Span, sink: Sink) -> TokenStream {
+    match (variable.modifier, unrestricted, sink) {
+        (Some(Modifier::Prefix(len)), false, Sink::Buffer) => {
+            let len = usize::from(len);
+            quote_spanned! { ty_span => ::templated_uri::__private::escape_prefix_into(#receiver, #len, __out); }
+        }
+        (Some(Modifier::Prefix(len)), true, Sink::Buffer) => {
+            let len = usize::from(len);
+            quote_spanned! { ty_span => ::templated_uri::__private::raw_prefix_into(#receiver, #len, __out); }
+        }
+        (Some(Modifier::Prefix(len)), false, Sink::Writer) => {
+            let len = usize::from(len);
+            quote_spanned! { ty_span => ::templated_uri::__private::escape_prefix_write(#receiver, #len, __out)?; }
+        }
+        (Some(Modifier::Prefix(len)), true, Sink::Writer) => {
+            let len = usize::from(len);
+            quote_spanned! { ty_span => ::templated_uri::__private::raw_prefix_write(#receiver, #len, __out)?; }
+        }
+        (_, false, Sink::Buffer) => quote_spanned! { ty_span => ::templated_uri::Escape::escape_into(#receiver, __out); },
+        (_, true, Sink::Buffer) => quote_spanned! { ty_span => ::templated_uri::Raw::raw_into(#receiver, __out); },
+        (_, false, Sink::Writer) => quote_spanned! { ty_span => ::std::write!(__out, "{}", ::templated_uri::Escape::escape(#receiver))?; },
+        (_, true, Sink::Writer) => quote_spanned! { ty_span => ::std::write!(__out, "{}", ::templated_uri::Raw::raw(#receiver))?; },
+    }
+}
+
+/// Returns the statement displaying the unredacted value of a non-exploded `variable`,
+/// truncating it first if the variable has a prefix modifier (`{var:3}`).
+fn display_value(variable: &Variable, value: &TokenStream) -> TokenStream {
+    if let Some(Modifier::Prefix(len)) = variable.modifier {
+        let len = usize::from(len);
+        quote! { ::templated_uri::__private::display_prefix(&#value, #len, f)?; }
+    } else {
+        quote! { ::std::write!(f, "{}", #value)?; }
+    }
+}
+
+/// Returns the statement displaying `value` redacted as `data_class`.
+///
+/// The whole value is redacted, even if a prefix modifier truncates it in the rendered URI,
+/// as redacted values are.
+fn display_classified(value: &TokenStream, data_class: &syn::Path) -> TokenStream {
+    quote! { ::templated_uri::__private::display_classified(&#value, &#data_class, redactor, f)?; }
 }
 
 /// Returns the `(emit_delim, emit_kv)` token-stream pair used inside the optional-aware
@@ -314,26 +815,26 @@ fn group_has_any_optional(param_names: &[&str], field_map: &FieldMap<'_>) -> boo
 /// `redacted_display_group_with_optional`.
 ///
 /// `write_lit` is a closure that emits the writer-specific statement for writing a
-/// single string literal: `__out.push_str(s)` for render, `f.write_str(s)?` for the
+/// single string expression: [`Sink::write_str`] for render, `f.write_str(s)?` for the
 /// `RedactedDisplay` impl. Factoring this here keeps both code paths in lockstep so
 /// future operator tweaks can't drift between `render()` and `RedactedDisplay::fmt`.
 fn emit_optional_delim_and_kv(
-    prefix: &str,
+    prefix: &GroupPrefix,
     separator: &str,
     key: Option<&str>,
-    write_lit: impl Fn(&str) -> TokenStream,
+    write_lit: impl Fn(&TokenStream) -> TokenStream,
 ) -> (TokenStream, TokenStream) {
-    let emit_delim = if prefix.is_empty() {
-        let sep = write_lit(separator);
-        quote! { if !__first { #sep } }
-    } else {
-        let pfx = write_lit(prefix);
-        let sep = write_lit(separator);
-        quote! { if __first { #pfx } else { #sep } }
+    let sep = write_lit(&quote! { #separator });
+    let emit_delim = match &prefix.prefix {
+        None => quote! { if !__first { #sep } },
+        Some(prefix) => {
+            let pfx = write_lit(prefix);
+            quote! { if __first { #pfx } else { #sep } }
+        }
     };
     let emit_kv = key.map_or_else(TokenStream::new, |k| {
-        let key_tok = write_lit(k);
-        let eq_tok = write_lit("=");
+        let key_tok = write_lit(&quote! { #k });
+        let eq_tok = write_lit(&quote! { "=" });
         quote! { #key_tok #eq_tok }
     });
     (emit_delim, emit_kv)
@@ -342,12 +843,18 @@ fn emit_optional_delim_and_kv(
 /// Generates render code for a single parameter group (e.g. `{?x,y}`, `{/a,b}`, `{x}`).
 ///
 /// Dispatches to the all-required fast path or the optional-aware path depending on
-/// whether the group contains any `Option<T>` field.
-fn construct_render_group(group: &ParamGroup, field_map: &FieldMap<'_>, unrestricted_params: &HashSet<String>) -> Vec<TokenStream> {
-    if group_has_any_optional(group.param_names(), field_map) {
-        render_group_with_optional(group, field_map, unrestricted_params)
+/// whether the group contains any `Option<T>` field or exploded parameter.
+fn construct_render_group(
+    group: &ParamGroup,
+    prefix: &GroupPrefix,
+    field_map: &FieldMap<'_>,
+    unrestricted_params: &HashSet<String>,
+    sink: Sink,
+) -> Vec<TokenStream> {
+    if group_may_be_undefined(group, field_map) {
+        render_group_with_optional(group, prefix, field_map, unrestricted_params, sink)
     } else {
-        render_group_all_required(group, field_map, unrestricted_params)
+        render_group_all_required(group, prefix, field_map, unrestricted_params, sink)
     }
 }
 
@@ -355,61 +862,79 @@ fn construct_render_group(group: &ParamGroup, field_map: &FieldMap<'_>, unrestri
 ///
 /// Emits a flat sequence of `push_str` and `write!` statements. No `__first`
 /// tracking is needed because every parameter contributes a value.
-fn render_group_all_required(group: &ParamGroup, field_map: &FieldMap<'_>, unrestricted_params: &HashSet<String>) -> Vec<TokenStream> {
-    let prefix = group.prefix().unwrap_or_default();
+fn render_group_all_required(
+    group: &ParamGroup,
+    prefix: &GroupPrefix,
+    field_map: &FieldMap<'_>,
+    unrestricted_params: &HashSet<String>,
+    sink: Sink,
+) -> Vec<TokenStream> {
     let separator = group.separator();
     let is_kv = group.is_kv();
-    let param_names = group.param_names();
 
     let mut stmts = Vec::new();
-    for (i, param_name) in param_names.iter().enumerate() {
-        let delim = if i == 0 { prefix } else { separator };
-        if !delim.is_empty() {
-            stmts.push(quote! { __out.push_str(#delim); });
+    for (i, variable) in group.variables().iter().enumerate() {
+        let delim = if i == 0 {
+            prefix.prefix.clone()
+        } else {
+            Some(quote! { #separator })
+        };
+        if let Some(delim) = delim {
+            stmts.push(sink.write_str(&delim));
         }
         if is_kv {
-            let key = *param_name;
-            stmts.push(quote! { __out.push_str(#key); __out.push_str("="); });
+            let key = variable.name;
+            stmts.push(sink.write_str(&quote! { #key }));
+            stmts.push(sink.write_str(&quote! { "=" }));
         }
-        let field = field_map.get(*param_name).expect("field should exist (validated earlier)");
+        let field = field_map.get(variable.name).expect("field should exist (validated earlier)");
         let field_ident = field.ident.as_ref().expect("struct fields must be named");
         let ty_span = field.ty.span();
+        let field_value = field_map.value(field_ident, ty_span);
         // `Escape`/`Raw` take `&self`, so the receiver must be `&FieldType`. For an owned
         // field that is `&self.field`; for a reference field (`&T`) the field itself already
         // is `&T`, so pass it directly - matching the `*__val` deref in the optional path so
         // both positions require the same bound (`T: Escape`/`T: Raw`) for `&T` fields.
         let receiver = if matches!(&field.ty, syn::Type::Reference(_)) {
-            quote_spanned! { ty_span => self.#field_ident }
+            field_value
         } else {
-            quote_spanned! { ty_span => &self.#field_ident }
+            quote_spanned! { ty_span => &#field_value }
         };
-        if unrestricted_params.contains(*param_name) {
-            stmts.push(quote_spanned! { ty_span => ::templated_uri::Raw::raw_into(#receiver, __out); });
-        } else {
-            stmts.push(quote_spanned! { ty_span => ::templated_uri::Escape::escape_into(#receiver, __out); });
-        }
+        stmts.push(render_value(
+            variable,
+            &receiver,
+            unrestricted_params.contains(variable.name),
+            ty_span,
+            sink,
+        ));
     }
     stmts
 }
 
-/// Render path for groups containing at least one `Option<T>` parameter.
+/// Render path for groups containing at least one `Option<T>`, `Vec<T>` or exploded parameter.
 ///
 /// Emits a `__first`-tracked block per RFC 6570 section 3.2: when a variable is
-/// undefined (`None`), its prefix or separator is also omitted so that the
-/// first *defined* variable receives the prefix and subsequent defined
-/// variables receive the separator.
-fn render_group_with_optional(group: &ParamGroup, field_map: &FieldMap<'_>, unrestricted_params: &HashSet<String>) -> Vec<TokenStream> {
-    let prefix = group.prefix().unwrap_or_default();
+/// undefined (`None`, or an empty list), its prefix or separator is also
+/// omitted so that the first *defined* variable receives the prefix and subsequent
+/// defined variables receive the separator. Exploded and list variables are rendered by
+/// `explode_escaped_into`/`explode_raw_into`, which delimit each item the same way.
+fn render_group_with_optional(
+    group: &ParamGroup,
+    prefix: &GroupPrefix,
+    field_map: &FieldMap<'_>,
+    unrestricted_params: &HashSet<String>,
+    sink: Sink,
+) -> Vec<TokenStream> {
     let separator = group.separator();
     let is_kv = group.is_kv();
-    let param_names = group.param_names();
 
     let mut inner_stmts = Vec::new();
     inner_stmts.push(quote! { let mut __first = true; });
 
-    for param_name in param_names {
-        let field = field_map.get(*param_name).expect("field should exist (validated earlier)");
+    for variable in group.variables() {
+        let field = field_map.get(variable.name).expect("field should exist (validated earlier)");
         let field_ident = field.ident.as_ref().expect("struct fields must be named");
+        let field_value = field_map.value(field_ident, Span::call_site());
         let optional_inner = extract_option_inner(&field.ty);
         let ty_span = optional_inner.map_or_else(|| field.ty.span(), syn::spanned::Spanned::span);
 
@@ -422,46 +947,68 @@ fn render_group_with_optional(group: &ParamGroup, field_map: &FieldMap<'_>, unre
             quote! { __val }
         };
 
-        let append_stmt = if unrestricted_params.contains(*param_name) {
-            quote_spanned! { ty_span => ::templated_uri::Raw::raw_into(#val_arg, __out); }
+        let unrestricted = unrestricted_params.contains(variable.name);
+
+        let body = if is_composite(variable, field) {
+            let spec = explode_spec(group, prefix, variable);
+            match (unrestricted, sink) {
+                (true, Sink::Buffer) => {
+                    quote_spanned! { ty_span => ::templated_uri::__private::explode_raw_into(#val_arg, #spec, &mut __first, __out); }
+                }
+                (false, Sink::Buffer) => {
+                    quote_spanned! { ty_span => ::templated_uri::__private::explode_escaped_into(#val_arg, #spec, &mut __first, __out); }
+                }
+                (true, Sink::Writer) => {
+                    quote_spanned! { ty_span => ::templated_uri::__private::explode_raw_write(#val_arg, #spec, &mut __first, __out)?; }
+                }
+                (false, Sink::Writer) => {
+                    quote_spanned! { ty_span => ::templated_uri::__private::explode_escaped_write(#val_arg, #spec, &mut __first, __out)?; }
+                }
+            }
         } else {
-            quote_spanned! { ty_span => ::templated_uri::Escape::escape_into(#val_arg, __out); }
-        };
+            let append_stmt = render_value(variable, &val_arg, unrestricted, ty_span, sink);
 
-        let key_for_kv = is_kv.then_some(*param_name);
-        let (emit_delim, emit_kv) = emit_optional_delim_and_kv(prefix, separator, key_for_kv, |s| quote! { __out.push_str(#s); });
+            let key_for_kv = is_kv.then_some(variable.name);
+            let (emit_delim, emit_kv) = emit_optional_delim_and_kv(prefix, separator, key_for_kv, |s| sink.write_str(s));
 
-        let body = quote! {
-            #emit_delim
-            #emit_kv
-            #append_stmt
-            __first = false;
+            quote! {
+                #emit_delim
+                #emit_kv
+                #append_stmt
+                __first = false;
+            }
         };
 
         if optional_inner.is_some() {
             inner_stmts.push(quote! {
-                if let ::core::option::Option::Some(ref __val) = self.#field_ident {
+                if let ::core::option::Option::Some(ref __val) = #field_value {
                     #body
                 }
             });
         } else {
             inner_stmts.push(quote! {
                 {
-                    let __val = &self.#field_ident;
+                    let __val = &#field_value;
                     #body
                 }
             });
         }
     }
 
+    if prefix.sets_query {
+        inner_stmts.push(quote! { __query |= !__first; });
+    }
     vec![quote! { { #(#inner_stmts)* } }]
 }
 
-fn construct_redacted_display(template: &UriTemplate, struct_fields: &[&Field], fields: &Fields, unredacted: bool) -> TokenStream {
-    let field_map: FieldMap<'_> = struct_fields
-        .iter()
-        .filter_map(|f| f.ident.as_ref().map(|ident| (ident.to_string(), *f)))
-        .collect();
+fn construct_redacted_display(
+    template: &UriTemplate,
+    struct_fields: &[&Field],
+    receiver: Receiver,
+    fields: &Fields,
+    unredacted: bool,
+) -> TokenStream {
+    let field_map = FieldMap::new(struct_fields, receiver);
 
     let field_opts_map: FieldOptsMap<'_> = fields
         .fields
@@ -469,16 +1016,27 @@ fn construct_redacted_display(template: &UriTemplate, struct_fields: &[&Field],
         .filter_map(|f| f.ident.as_ref().map(|ident| (ident.to_string(), f)))
         .collect();
 
-    let statements: Vec<TokenStream> = template
-        .template_parts()
-        .iter()
-        .flat_map(|part| match part {
-            TemplatePart::Content(content) => {
-                vec![quote! { f.write_str(#content)?; }]
+    let (prefixes, reads_query) = group_prefixes(template, &field_map);
+    let mut prefixes = prefixes.iter();
+    let mut statements = Vec::new();
+    if reads_query {
+        statements.push(quote! { let mut __query = false; });
+    }
+    for part in template.template_parts() {
+        match part {
+            TemplatePart::Content(content) => statements.push(quote! { f.write_str(#content)?; }),
+            TemplatePart::ParamGroup(group) => {
+                let prefix = prefixes.next().expect("there is a prefix for each parameter group");
+                statements.extend(construct_redacted_display_group(
+                    group,
+                    prefix,
+                    &field_map,
+                    &field_opts_map,
+                    unredacted,
+                ));
             }
-            TemplatePart::ParamGroup(group) => construct_redacted_display_group(group, &field_map, &field_opts_map, unredacted),
-        })
-        .collect();
+        }
+    }
 
     quote! {
         #(#statements)*
@@ -489,81 +1047,120 @@ fn construct_redacted_display(template: &UriTemplate, struct_fields: &[&Field],
 /// Generates redacted-display code for a single parameter group.
 ///
 /// Dispatches to the all-required fast path or the optional-aware path depending on
-/// whether the group contains any `Option<T>` field.
+/// whether the group contains any `Option<T>` field or exploded parameter.
 fn construct_redacted_display_group(
     group: &ParamGroup,
+    prefix: &GroupPrefix,
     field_map: &FieldMap<'_>,
     field_opts_map: &FieldOptsMap<'_>,
     unredacted: bool,
 ) -> Vec<TokenStream> {
-    if group_has_any_optional(group.param_names(), field_map) {
-        redacted_display_group_with_optional(group, field_map, field_opts_map, unredacted)
+    if group_may_be_undefined(group, field_map) {
+        redacted_display_group_with_optional(group, prefix, field_map, field_opts_map, unredacted)
     } else {
-        redacted_display_group_all_required(group, field_map, field_opts_map, unredacted)
+        redacted_display_group_all_required(group, prefix, field_map, field_opts_map, unredacted)
     }
 }
 
 /// Redacted-display path for groups whose parameters are all required.
 fn redacted_display_group_all_required(
     group: &ParamGroup,
+    prefix: &GroupPrefix,
     field_map: &FieldMap<'_>,
     field_opts_map: &FieldOptsMap<'_>,
     unredacted: bool,
 ) -> Vec<TokenStream> {
-    let prefix = group.prefix().unwrap_or_default();
     let separator = group.separator();
     let is_kv = group.is_kv();
-    let param_names = group.param_names();
 
     let mut stmts = Vec::new();
-    for (i, param_name) in param_names.iter().enumerate() {
-        let delim = if i == 0 { prefix } else { separator };
-        if !delim.is_empty() {
+    for (i, variable) in group.variables().iter().enumerate() {
+        let delim = if i == 0 {
+            prefix.prefix.clone()
+        } else {
+            Some(quote! { #separator })
+        };
+        if let Some(delim) = delim {
             stmts.push(quote! { f.write_str(#delim)?; });
         }
         if is_kv {
-            let key = *param_name;
+            let key = variable.name;
             stmts.push(quote! { f.write_str(#key)?; f.write_str("=")?; });
         }
-        let field = field_map.get(*param_name).expect("field should exist (validated earlier)");
+        let field = field_map.get(variable.name).expect("field should exist (validated earlier)");
         let field_ident = field.ident.as_ref().expect("struct fields must be named");
+        let field_value = field_map.value(field_ident, Span::call_site());
         let field_type = &field.ty;
-        let field_unredacted = field_opts_map.get(*param_name).is_some_and(|opts| opts.unredacted);
 
-        if unredacted || field_unredacted {
-            stmts.push(quote! { ::std::write!(f, "{}", self.#field_ident)?; });
-        } else {
-            stmts.push(quote! { <#field_type as ::templated_uri::__private::RedactedDisplay>::fmt(&self.#field_ident, redactor, f)?; });
-        }
+        stmts.push(match Redaction::of(variable.name, field_opts_map, unredacted) {
+            Redaction::Unredacted => display_value(variable, &field_value),
+            Redaction::Classified(data_class) => display_classified(&field_value, data_class),
+            Redaction::Redacted => {
+                quote! { <#field_type as ::templated_uri::__private::RedactedDisplay>::fmt(&#field_value, redactor, f)?; }
+            }
+        });
     }
     stmts
 }
 
-/// Redacted-display path for groups containing at least one `Option<T>` parameter.
+/// Redacted-display path for groups containing at least one `Option<T>`, `Vec<T>` or exploded
+/// parameter.
 ///
 /// Mirrors `render_group_with_optional`: undefined values are skipped along with
-/// their prefix/separator using `__first` tracking.
+/// their prefix/separator using `__first` tracking. Values truncated by a prefix modifier
+/// are only truncated when unredacted, as truncating the output of the redactor could
+/// reveal or mangle it.
 fn redacted_display_group_with_optional(
     group: &ParamGroup,
+    prefix: &GroupPrefix,
     field_map: &FieldMap<'_>,
     field_opts_map: &FieldOptsMap<'_>,
     unredacted: bool,
 ) -> Vec<TokenStream> {
-    let prefix = group.prefix().unwrap_or_default();
     let separator = group.separator();
     let is_kv = group.is_kv();
-    let param_names = group.param_names();
 
     let mut inner_stmts = Vec::new();
     inner_stmts.push(quote! { let mut __first = true; });
 
-    for param_name in param_names {
-        let field = field_map.get(*param_name).expect("field should exist (validated earlier)");
+    for variable in group.variables() {
+        let field = field_map.get(variable.name).expect("field should exist (validated earlier)");
         let field_ident = field.ident.as_ref().expect("struct fields must be named");
+        let field_value = field_map.value(field_ident, Span::call_site());
         let optional_inner = extract_option_inner(&field.ty);
-        let field_unredacted = field_opts_map.get(*param_name).is_some_and(|opts| opts.unredacted);
+        let redaction = Redaction::of(variable.name, field_opts_map, unredacted);
+
+        if is_composite(variable, field) {
+            let spec = explode_spec(group, prefix, variable);
+            let explode = |value: TokenStream| match redaction {
+                Redaction::Unredacted => quote! { ::templated_uri::__private::explode_display(#value, #spec, &mut __first, f)?; },
+                Redaction::Classified(data_class) => quote! {
+                    ::templated_uri::__private::explode_classified(#value, #spec, &mut __first, &#data_class, redactor, f)?;
+                },
+                Redaction::Redacted => {
+                    quote! { ::templated_uri::__private::explode_redacted(#value, #spec, &mut __first, redactor, f)?; }
+                }
+            };
+
+            if let Some(inner_type) = optional_inner {
+                let val_arg = if matches!(inner_type, syn::Type::Reference(_)) {
+                    quote! { *__val }
+                } else {
+                    quote! { __val }
+                };
+                let explode_stmt = explode(val_arg);
+                inner_stmts.push(quote! {
+                    if let ::core::option::Option::Some(ref __val) = #field_value {
+                        #explode_stmt
+                    }
+                });
+            } else {
+                inner_stmts.push(explode(quote! { &#field_value }));
+            }
+            continue;
+        }
 
-        let key_for_kv = is_kv.then_some(*param_name);
+        let key_for_kv = is_kv.then_some(variable.name);
         let (emit_delim, emit_kv) = emit_optional_delim_and_kv(prefix, separator, key_for_kv, |s| quote! { f.write_str(#s)?; });
 
         if let Some(inner_type) = optional_inner {
@@ -577,14 +1174,14 @@ fn redacted_display_group_with_optional(
                 }
                 _ => (quote! { #inner_type }, quote! { __val }),
             };
-            let display_value = if unredacted || field_unredacted {
-                quote! { ::std::write!(f, "{}", #val_arg)?; }
-            } else {
-                quote! { <#self_ty as ::templated_uri::__private::RedactedDisplay>::fmt(#val_arg, redactor, f)?; }
+            let display_value = match redaction {
+                Redaction::Unredacted => display_value(variable, &val_arg),
+                Redaction::Classified(data_class) => display_classified(&val_arg, data_class),
+                Redaction::Redacted => quote! { <#self_ty as ::templated_uri::__private::RedactedDisplay>::fmt(#val_arg, redactor, f)?; },
             };
 
             inner_stmts.push(quote! {
-                if let ::core::option::Option::Some(ref __val) = self.#field_ident {
+                if let ::core::option::Option::Some(ref __val) = #field_value {
                     #emit_delim
                     #emit_kv
                     #display_value
@@ -592,11 +1189,13 @@ fn redacted_display_group_with_optional(
                 }
             });
         } else {
-            let display_value = if unredacted || field_unredacted {
-                quote! { ::std::write!(f, "{}", self.#field_ident)?; }
-            } else {
-                let field_type = &field.ty;
-                quote! { <#field_type as ::templated_uri::__private::RedactedDisplay>::fmt(&self.#field_ident, redactor, f)?; }
+            let field_type = &field.ty;
+            let display_value = match redaction {
+                Redaction::Unredacted => display_value(variable, &field_value),
+                Redaction::Classified(data_class) => display_classified(&field_value, data_class),
+                Redaction::Redacted => {
+                    quote! { <#field_type as ::templated_uri::__private::RedactedDisplay>::fmt(&#field_value, redactor, f)?; }
+                }
             };
 
             inner_stmts.push(quote! {
@@ -610,6 +1209,9 @@ fn redacted_display_group_with_optional(
         }
     }
 
+    if prefix.sets_query {
+        inner_stmts.push(quote! { __query |= !__first; });
+    }
     vec![quote! { { #(#inner_stmts)* } }]
 }
 
@@ -617,7 +1219,7 @@ fn redacted_display_group_with_optional(
 mod tests {
     use syn::parse_quote;
 
-    use super::extract_option_inner;
+    use super::{extract_option_inner, is_list};
 
     #[test]
     fn extract_option_inner_some_for_simple_option() {
@@ -670,4 +1272,24 @@ mod tests {
         let ty: syn::Type = parse_quote! { Option<'a> };
         assert!(extract_option_inner(&ty).is_none());
     }
+
+    #[test]
+    fn is_list_for_vec_and_optional_vec() {
+        let ty: syn::Type = parse_quote! { Vec<EscapedString> };
+        assert!(is_list(&ty));
+        let ty: syn::Type = parse_quote! { std::vec::Vec<u32> };
+        assert!(is_list(&ty));
+        let ty: syn::Type = parse_quote! { Option<Vec<u32>> };
+        assert!(is_list(&ty));
+    }
+
+    #[test]
+    fn is_list_false_for_scalars_and_maps() {
+        let ty: syn::Type = parse_quote! { Option<u32> };
+        assert!(!is_list(&ty));
+        let ty: syn::Type = parse_quote! { BTreeMap<String, String> };
+        assert!(!is_list(&ty));
+        let ty: syn::Type = parse_quote! { &[u32] };
+        assert!(!is_list(&ty));
+    }
 }
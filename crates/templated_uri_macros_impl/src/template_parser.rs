@@ -98,22 +98,21 @@ impl<'a> TemplatePart<'a> {
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct ParamGroup<'a> {
     param_kind: ParamKind,
-    param_names: Vec<&'a str>,
+    variables: Vec<Variable<'a>>,
 }
 
 impl<'a> ParamGroup<'a> {
     fn parser() -> impl Parser<'a, &'a str, ParamGroup<'a>, Error<'a>> {
-        let param_name = text::ascii::ident().labelled("parameter name");
         let params = ParamKind::parser().then(
-            param_name
+            Variable::parser()
                 .separated_by(just(','))
                 .at_least(1)
-                .collect::<Vec<&str>>()
+                .collect::<Vec<Variable<'a>>>()
                 .labelled("comma separated parameters"),
         );
         params
             .delimited_by(just('{'), just('}'))
-            .map(|(param_kind, param_names)| ParamGroup { param_kind, param_names })
+            .map(|(param_kind, variables)| ParamGroup { param_kind, variables })
     }
 
     /// Checks if the parameter group allows unrestricted (reserved) characters in values.
@@ -123,15 +122,25 @@ impl<'a> ParamGroup<'a> {
 
     /// Returns an iterator over the parameters in this group.
     pub(crate) fn params(&self) -> impl Iterator<Item = Param<'a>> {
-        self.param_names.iter().map(|&name| Param {
-            name,
+        self.variables.iter().map(|variable| Param {
+            name: variable.name,
             is_unrestricted: self.is_unrestricted(),
         })
     }
 
-    /// Returns the parameter names in this group.
-    pub(crate) fn param_names(&self) -> &[&'a str] {
-        &self.param_names
+    /// Checks if this is a simple expansion (`{var}`), without an operator.
+    pub(crate) fn is_simple(&self) -> bool {
+        matches!(self.param_kind, ParamKind::Simple)
+    }
+
+    /// Returns the variables in this group, with their modifiers.
+    pub(crate) fn variables(&self) -> &[Variable<'a>] {
+        &self.variables
+    }
+
+    /// Returns whether any variable in this group has the explode modifier (`{list*}`).
+    pub(crate) fn has_explode(&self) -> bool {
+        self.variables.iter().any(|variable| variable.modifier == Some(Modifier::Explode))
     }
 
     /// Returns the prefix for this parameter group (e.g. `?`, `&`, `/`).
@@ -144,21 +153,34 @@ impl<'a> ParamGroup<'a> {
         self.param_kind.separator()
     }
 
+    /// Returns whether this group expands into the query (`{?var}` or `{&var}`).
+    pub(crate) fn is_query(&self) -> bool {
+        matches!(self.param_kind, ParamKind::Form { .. })
+    }
+
+    /// Returns whether this group continues the query (`{&var}`).
+    pub(crate) fn is_query_continuation(&self) -> bool {
+        self.param_kind == ParamKind::Form { start_char: false }
+    }
+
     /// Returns whether this group uses key-value format (e.g. `?key=value`).
     pub(crate) fn is_kv(&self) -> bool {
         self.param_kind.is_kv()
     }
 
     /// Returns the raw template string for this parameter group.
+    ///
+    /// Modifiers are dropped. An exploded variable renders its own keys, so it is flattened
+    /// into a bare placeholder even in key-value groups.
     fn raw_template(&self) -> String {
         let params: Vec<String> = self
-            .param_names
+            .variables
             .iter()
-            .map(|param_name| {
-                if self.param_kind.is_kv() {
-                    format!("{param_name}={{{param_name}}}")
+            .map(|Variable { name, modifier }| {
+                if self.param_kind.is_kv() && *modifier != Some(Modifier::Explode) {
+                    format!("{name}={{{name}}}")
                 } else {
-                    format!("{{{param_name}}}")
+                    format!("{{{name}}}")
                 }
             })
             .collect();
@@ -167,6 +189,50 @@ impl<'a> ParamGroup<'a> {
     }
 }
 
+/// A variable of a parameter group, such as `var`, `var:3` or `list*`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct Variable<'a> {
+    pub(crate) name: &'a str,
+    pub(crate) modifier: Option<Modifier>,
+}
+
+impl<'a> Variable<'a> {
+    fn parser() -> impl Parser<'a, &'a str, Variable<'a>, Error<'a>> {
+        text::ascii::ident()
+            .labelled("parameter name")
+            .then(Modifier::parser().or_not())
+            .map(|(name, modifier)| Variable { name, modifier })
+    }
+}
+
+/// An RFC 6570 Level 4 value modifier.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum Modifier {
+    /// `{var:N}` expands to at most the first `N` characters of the value.
+    Prefix(u16),
+    /// `{var*}` expands each item of a list or associative array as a separate value.
+    Explode,
+}
+
+impl Modifier {
+    /// The largest prefix length allowed by RFC 6570 section 2.4.1.
+    const MAX_PREFIX_LEN: u16 = 9999;
+
+    fn parser<'a>() -> impl Parser<'a, &'a str, Self, Error<'a>> {
+        let prefix_len = text::int(10).labelled("prefix length").try_map(|digits: &str, span| {
+            digits
+                .parse::<u16>()
+                .ok()
+                .filter(|len| (1..=Self::MAX_PREFIX_LEN).contains(len))
+                .map(Self::Prefix)
+                .ok_or_else(|| Rich::custom(span, "prefix length must be between 1 and 9999"))
+        });
+        let prefix = just(':').ignore_then(prefix_len);
+        let explode = just('*').to(Self::Explode);
+        prefix.or(explode)
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) struct Param<'a> {
     pub(crate) name: &'a str,
@@ -269,17 +335,21 @@ mod test {
     use ohno::ErrorExt;
 
     use super::*;
+
+    fn var(name: &str) -> Variable<'_> {
+        Variable { name, modifier: None }
+    }
     #[test]
     fn test_param_group_parser() {
         let input = "{+param1,param2}";
         let parsed = ParamGroup::parser().parse(input).unwrap();
-        assert_eq!(parsed.param_names, vec!["param1", "param2"]);
+        assert_eq!(parsed.params().map(|param| param.name).collect::<Vec<_>>(), ["param1", "param2"]);
         assert_eq!(parsed.param_kind, ParamKind::Unfiltered);
         assert_eq!(parsed.raw_template(), "{param1},{param2}");
 
         let input = "{param1,param2}";
         let parsed = ParamGroup::parser().parse(input).unwrap();
-        assert_eq!(parsed.param_names, vec!["param1", "param2"]);
+        assert_eq!(parsed.params().map(|param| param.name).collect::<Vec<_>>(), ["param1", "param2"]);
         assert_eq!(parsed.param_kind, ParamKind::Simple);
         assert_eq!(parsed.raw_template(), "{param1},{param2}");
 
@@ -325,27 +395,27 @@ mod test {
             vec![
                 TemplatePart::ParamGroup(ParamGroup {
                     param_kind: ParamKind::Simple,
-                    param_names: vec!["first"]
+                    variables: vec![var("first")]
                 }),
                 TemplatePart::ParamGroup(ParamGroup {
                     param_kind: ParamKind::Unfiltered,
-                    param_names: vec!["param1", "param2"]
+                    variables: vec![var("param1"), var("param2")]
                 }),
                 TemplatePart::ParamGroup(ParamGroup {
                     param_kind: ParamKind::SemicolonKV,
-                    param_names: vec!["param3", "param4"]
+                    variables: vec![var("param3"), var("param4")]
                 }),
                 TemplatePart::ParamGroup(ParamGroup {
                     param_kind: ParamKind::Form { start_char: true },
-                    param_names: vec!["query2", "query3"]
+                    variables: vec![var("query2"), var("query3")]
                 }),
                 TemplatePart::ParamGroup(ParamGroup {
                     param_kind: ParamKind::Prefixed(Prefix::Dot),
-                    param_names: vec!["dot1", "dot2"]
+                    variables: vec![var("dot1"), var("dot2")]
                 }),
                 TemplatePart::ParamGroup(ParamGroup {
                     param_kind: ParamKind::Prefixed(Prefix::Slash),
-                    param_names: vec!["slash1", "slash2"]
+                    variables: vec![var("slash1"), var("slash2")]
                 }),
             ]
         );
@@ -402,4 +472,48 @@ mod test {
             "Failed to parse URI: [fragment operator {#...} is not supported: URI fragments are stripped by the http crate and ignored by HTTP clients at 8..9]"
         );
     }
+
+    #[test]
+    fn level_4_modifiers() {
+        let parsed = ParamGroup::parser().parse("{?var:3,list*,keys}").unwrap();
+        assert_eq!(
+            parsed.variables(),
+            [
+                Variable {
+                    name: "var",
+                    modifier: Some(Modifier::Prefix(3))
+                },
+                Variable {
+                    name: "list",
+                    modifier: Some(Modifier::Explode)
+                },
+                var("keys"),
+            ]
+        );
+        assert!(parsed.has_explode());
+        assert_eq!(parsed.raw_template(), "?var={var}&{list}&keys={keys}");
+
+        let parsed = ParamGroup::parser().parse("{/path:9999}").unwrap();
+        assert_eq!(parsed.variables()[0].modifier, Some(Modifier::Prefix(9999)));
+        assert!(!parsed.has_explode());
+        assert_eq!(parsed.raw_template(), "/{path}");
+    }
+
+    #[test]
+    fn invalid_level_4_modifiers() {
+        for input in ["/{var:0}", "/{var:10000}", "/{var:99999999999}"] {
+            assert_eq!(
+                UriTemplate::parse(input).unwrap_err().message(),
+                format!(
+                    "Failed to parse URI: [prefix length must be between 1 and 9999 at 6..{}]",
+                    input.len() - 1
+                ),
+                "{input}"
+            );
+        }
+
+        for input in ["/{var:}", "/{var:3*}", "/{var*:3}", "/{var:01}"] {
+            UriTemplate::parse(input).unwrap_err();
+        }
+    }
 }
@@ -0,0 +1,148 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+use crate::tracing_logs::logs_directory;
+
+/// The environment variable that makes [`assert_matches_golden()`] update golden files
+/// instead of comparing against them, when set to `1`.
+pub const UPDATE_GOLDEN_VARIABLE: &str = "OXIDIZER_UPDATE_GOLDEN";
+
+/// Returns the path of golden file `name`, in a "golden" directory under the "test-logs"
+/// directory of the Cargo workspace root.
+///
+/// The name may contain `/` separators to group golden files by crate or test.
+#[must_use]
+pub fn golden_file(name: &str) -> PathBuf {
+    Path::new(&logs_directory()).join("golden").join(name)
+}
+
+/// Asserts that `actual` matches the contents of golden file `name`.
+///
+/// This is meant for binary outputs, such as serialized buffers or redacted logs, where inline
+/// snapshots are awkward. See [`golden_file()`] for where golden files are stored.
+///
+/// If `OXIDIZER_UPDATE_GOLDEN=1` is set, the golden file is created or overwritten with
+/// `actual` instead, so review the changes to golden files before committing them.
+///
+/// # Panics
+///
+/// Panics if the golden file does not exist or differs from `actual`. On a mismatch, `actual`
+/// is written next to the golden file with an `.actual` extension for inspection.
+#[track_caller]
+pub fn assert_matches_golden(name: &str, actual: impl AsRef<[u8]>) {
+    let update = env::var(UPDATE_GOLDEN_VARIABLE).as_deref() == Ok("1");
+
+    if let Err(message) = compare_golden(&golden_file(name), actual.as_ref(), update) {
+        panic!("{message}");
+    }
+}
+
+fn compare_golden(path: &Path, actual: &[u8], update: bool) -> Result<(), String> {
+    let actual_path = path.with_added_extension("actual");
+
+    if update {
+        fs::create_dir_all(path.parent().expect("a golden file path always has a parent directory")).unwrap();
+        fs::write(path, actual).unwrap();
+        // A stale mismatch from a previous run is no longer relevant.
+        _ = fs::remove_file(&actual_path);
+        return Ok(());
+    }
+
+    let Ok(expected) = fs::read(path) else {
+        return Err(format!(
+            "golden file {} does not exist, create it by re-running the test with {UPDATE_GOLDEN_VARIABLE}=1",
+            path.display()
+        ));
+    };
+
+    if expected == actual {
+        return Ok(());
+    }
+
+    fs::write(&actual_path, actual).unwrap();
+
+    let first_difference = expected
+        .iter()
+        .zip(actual)
+        .position(|(e, a)| e != a)
+        .unwrap_or_else(|| expected.len().min(actual.len()));
+
+    Err(format!(
+        "output does not match golden file {} ({} bytes expected, {} bytes found, first difference at byte {first_difference}), \
+         the output was written to {}, accept it by re-running the test with {UPDATE_GOLDEN_VARIABLE}=1",
+        path.display(),
+        expected.len(),
+        actual.len(),
+        actual_path.display()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_golden(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("testing_aids_golden_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        _ = fs::remove_file(&path);
+        _ = fs::remove_file(path.with_added_extension("actual"));
+        path
+    }
+
+    #[test]
+    fn missing_golden_file_fails() {
+        let path = temp_golden("missing.bin");
+
+        let message = compare_golden(&path, b"data", false).unwrap_err();
+        assert!(message.contains("does not exist"), "{message}");
+        assert!(message.contains("OXIDIZER_UPDATE_GOLDEN=1"), "{message}");
+    }
+
+    #[test]
+    fn update_writes_golden_file() {
+        let path = temp_golden("nested/updated.bin");
+
+        compare_golden(&path, b"\x00\x01\x02", true).unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"\x00\x01\x02");
+
+        compare_golden(&path, b"\x00\x01\x02", false).unwrap();
+    }
+
+    #[test]
+    fn mismatch_writes_actual_file() {
+        let path = temp_golden("mismatch.bin");
+        fs::write(&path, b"hello world").unwrap();
+
+        let message = compare_golden(&path, b"hello there", false).unwrap_err();
+        assert!(
+            message.contains("11 bytes expected, 11 bytes found, first difference at byte 6"),
+            "{message}"
+        );
+
+        let actual_path = path.with_added_extension("actual");
+        assert_eq!(fs::read(&actual_path).unwrap(), b"hello there");
+
+        // Accepting the output clears the stale mismatch.
+        compare_golden(&path, b"hello there", true).unwrap();
+        assert!(!actual_path.exists());
+    }
+
+    #[test]
+    fn prefix_mismatch_reports_end_of_shorter_output() {
+        let path = temp_golden("prefix.bin");
+        fs::write(&path, b"abc").unwrap();
+
+        let message = compare_golden(&path, b"abcdef", false).unwrap_err();
+        assert!(message.contains("first difference at byte 3"), "{message}");
+    }
+
+    #[test]
+    fn golden_files_are_under_test_logs() {
+        let path = golden_file("bytesbuf/view.bin");
+        assert!(path.ends_with("test-logs/golden/bytesbuf/view.bin"), "{}", path.display());
+    }
+}
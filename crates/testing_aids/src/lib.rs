@@ -17,17 +17,25 @@ use std::sync::mpsc;
 use std::time::Duration;
 use std::{env, process, thread};
 
+use tick::{Clock, ClockControl};
+
+mod golden;
 mod io;
 mod macros;
 mod metrics;
+mod rng;
 pub mod tracing_logs;
+mod watchdog;
 mod yielding;
 
+pub use golden::*;
 pub use io::*;
 pub use metrics::*;
+pub use rng::*;
 pub use yielding::*;
 
-/// If something (whatever) does not happen in a test within this time, the test will fail.
+/// If something (whatever) does not happen in a test within this time, the test will fail,
+/// unless overridden by `OXIDIZER_TEST_TIMEOUT` or a per-call timeout. See [`test_timeout()`].
 ///
 /// We are conservative here and allow much time - this is only to break out of infinite loops, not for any
 /// situations that are actually expected.
@@ -35,7 +43,30 @@ pub use yielding::*;
 /// This should be significantly smaller than the .cargo/mutants.toml timeout because multiple
 /// tests may be executed during a single cargo-mutants run, so this timeout might not start
 /// immediately at the start of a test run.
-pub const TEST_TIMEOUT: Duration = Duration::from_secs(10);
+pub const DEFAULT_TEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The environment variable that overrides [`DEFAULT_TEST_TIMEOUT`], in seconds.
+///
+/// Slow targets such as Miri or coverage runs can set this to give legitimately passing
+/// tests more time, for example `OXIDIZER_TEST_TIMEOUT=120`.
+pub const TEST_TIMEOUT_VARIABLE: &str = "OXIDIZER_TEST_TIMEOUT";
+
+/// Returns the test timeout: the number of seconds in `OXIDIZER_TEST_TIMEOUT` if set,
+/// otherwise [`DEFAULT_TEST_TIMEOUT`].
+///
+/// # Panics
+///
+/// Panics if `OXIDIZER_TEST_TIMEOUT` is set but is not a non-negative number of seconds.
+#[must_use]
+pub fn test_timeout() -> Duration {
+    env::var(TEST_TIMEOUT_VARIABLE).ok().map_or(DEFAULT_TEST_TIMEOUT, |value| {
+        parse_timeout(&value).unwrap_or_else(|| panic!("{TEST_TIMEOUT_VARIABLE} must be a number of seconds, got '{value}'"))
+    })
+}
+
+fn parse_timeout(value: &str) -> Option<Duration> {
+    Duration::try_from_secs_f64(value.trim().parse().ok()?).ok()
+}
 
 #[must_use]
 pub fn is_mutation_testing() -> bool {
@@ -43,13 +74,15 @@ pub fn is_mutation_testing() -> bool {
 }
 
 /// Executes a thread-safe function on a background thread and abandons it if
-/// it does not complete before the provided timeout.
+/// it does not complete before the timeout.
+///
+/// The timeout is `timeout` if provided, otherwise [`test_timeout()`].
 ///
 /// # Panics
 ///
 /// Panics if the test panics or the test timeout is exceeded.
 #[must_use]
-pub fn execute_or_abandon<F, R>(f: F) -> Option<R>
+pub fn execute_or_abandon<F, R>(timeout: Option<Duration>, f: F) -> Option<R>
 where
     F: FnOnce() -> R + Send + 'static,
     R: Send + 'static,
@@ -75,19 +108,25 @@ where
         sender.send(result).unwrap();
     });
 
-    receiver.recv_timeout(TEST_TIMEOUT).ok()
+    receiver.recv_timeout(timeout.unwrap_or_else(test_timeout)).ok()
 }
 
 /// Executes a function on the current thread and sets up a watchdog timer that terminates the
 /// process if the target function does not complete before the provided timeout.
 ///
+/// Before terminating the process, the watchdog prints diagnostics to stderr: the threads of
+/// the process (where supported), the pending timers of the running
+/// `async_test_with_clock_control()` calls and the last lines captured by `tracing_logs`.
+///
 /// This is a variant of `execute_or_abandon()` that can be used with single-threaded
 /// logic that does not support being moved to a background thread.
 ///
+/// The timeout is `timeout` if provided, otherwise [`test_timeout()`].
+///
 /// # Panics
 ///
 /// Panics if the test panics or the test timeout is exceeded.
-pub fn execute_or_terminate_process<F, R>(f: F) -> R
+pub fn execute_or_terminate_process<F, R>(timeout: Option<Duration>, f: F) -> R
 where
     F: FnOnce() -> R,
 {
@@ -99,14 +138,15 @@ where
         return f();
     }
 
+    let timeout = timeout.unwrap_or_else(test_timeout);
     let (sender, receiver) = mpsc::channel();
 
     let watchdog = thread::Builder::new()
         .name("test watchdog".to_string())
         .spawn(move || {
-            if receiver.recv_timeout(TEST_TIMEOUT) == Ok(()) {
+            if receiver.recv_timeout(timeout) == Ok(()) {
             } else {
-                eprintln!("Test timed out, terminating process.");
+                eprintln!("{}", watchdog::timeout_diagnostics(timeout));
                 #[expect(
                     clippy::exit,
                     reason = "test harness is intentionally terminating test process that cannot continue execution"
@@ -146,7 +186,80 @@ where
     F: FnOnce() -> FF + 'static,
     FF: Future<Output = ()>,
 {
-    execute_or_terminate_process(|| {
+    execute_or_terminate_process(None, || {
         ::futures::executor::block_on(f());
     });
 }
+
+/// Executes an async function with a controlled [`Clock`] on the Miri-compatible `futures`
+/// async task runtime, blocking until it completes and enforcing a test timeout.
+///
+/// The clock is created from a [`ClockControl`] that automatically advances to fire the
+/// upcoming timers, so delays and timeouts complete immediately and deterministically.
+///
+/// # Panics
+///
+/// Panics if the test panics or the test timeout is exceeded.
+pub fn async_test_with_clock<F, FF>(f: F)
+where
+    F: FnOnce(Clock) -> FF + 'static,
+    FF: Future<Output = ()>,
+{
+    async_test_with_clock_control(&ClockControl::new().auto_advance_timers(true), f);
+}
+
+/// Executes an async function with a [`Clock`] of `control` on the Miri-compatible `futures`
+/// async task runtime, blocking until it completes and enforcing a test timeout.
+///
+/// This is a variant of `async_test_with_clock()` for tests that need a custom [`ClockControl`],
+/// such as one with an auto-advance limit.
+///
+/// # Panics
+///
+/// Panics if the test panics, the test timeout is exceeded, or any timer of `control` is
+/// still pending when the test completes - all timers are expected to have fired or been
+/// dropped by then.
+pub fn async_test_with_clock_control<F, FF>(control: &ClockControl, f: F)
+where
+    F: FnOnce(Clock) -> FF + 'static,
+    FF: Future<Output = ()>,
+{
+    let clock = control.to_clock();
+    let _registration = watchdog::register_clock(control);
+
+    execute_or_terminate_process(None, || {
+        ::futures::executor::block_on(f(clock));
+    });
+
+    let pending_timers = control.pending_timers();
+    assert!(
+        pending_timers == 0,
+        "{pending_timers} timer(s) still pending at the end of the test, drop or await all delays and timeouts"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_timeout_accepts_seconds() {
+        assert_eq!(parse_timeout("30"), Some(Duration::from_secs(30)));
+        assert_eq!(parse_timeout(" 2.5 "), Some(Duration::from_millis(2500)));
+        assert_eq!(parse_timeout("-1"), None);
+        assert_eq!(parse_timeout("10s"), None);
+    }
+
+    #[test]
+    fn per_call_timeout_overrides_default() {
+        if is_mutation_testing() {
+            return;
+        }
+
+        let result = execute_or_abandon(Some(Duration::from_millis(10)), || thread::sleep(Duration::from_millis(500)));
+        assert!(result.is_none());
+
+        assert_eq!(execute_or_abandon(Some(Duration::from_secs(1)), || 42), Some(42));
+        assert_eq!(execute_or_terminate_process(Some(Duration::from_secs(1)), || 42), 42);
+    }
+}
@@ -47,3 +47,38 @@ macro_rules! init_tracing {
         };
     };
 }
+
+/// Asserts that the innermost active [`capture_logs()`](crate::tracing_logs::capture_logs)
+/// of the current thread recorded a matching `tracing` event.
+///
+/// Takes comma-separated conditions, all of which one event must satisfy:
+///
+/// * `level: WARN` - the event has exactly this level.
+/// * `target: "seatbelt::retry"` - the event target starts with this prefix.
+/// * `contains: "retry"` - the message or a field value contains this text. Can be repeated.
+///
+/// ```ignore
+/// let logs = testing_aids::tracing_logs::capture_logs();
+/// tracing::warn!(attempt = 2, "retrying request");
+/// testing_aids::assert_event!(level: WARN, contains: "retry");
+/// ```
+#[macro_export]
+macro_rules! assert_event {
+    ($($conditions:tt)+) => {
+        $crate::tracing_logs::assert_event(&$crate::__event_filter!($crate::tracing_logs::EventFilter::new(); $($conditions)+))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __event_filter {
+    ($filter:expr;) => {
+        $filter
+    };
+    ($filter:expr; level: $level:ident $(, $($rest:tt)*)?) => {
+        $crate::__event_filter!($filter.level($crate::tracing_logs::Level::$level); $($($rest)*)?)
+    };
+    ($filter:expr; $condition:ident: $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__event_filter!($filter.$condition($value); $($($rest)*)?)
+    };
+}
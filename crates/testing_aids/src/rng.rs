@@ -0,0 +1,112 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::ops::{Deref, DerefMut};
+use std::{env, thread};
+
+/// The environment variable that [`seeded_rng()`] reads the seed from.
+pub const TEST_SEED_VARIABLE: &str = "OXIDIZER_TEST_SEED";
+
+/// Returns a deterministic random number generator for randomized tests.
+///
+/// The seed is taken from the `OXIDIZER_TEST_SEED` environment variable if set, otherwise
+/// a new one is generated. If the test panics while the generator is alive, the seed is
+/// printed to standard error, so a failure seen in CI can be reproduced exactly by
+/// re-running the test with `OXIDIZER_TEST_SEED` set to that seed.
+///
+/// # Panics
+///
+/// Panics if `OXIDIZER_TEST_SEED` is set but is not a decimal `u64`.
+#[must_use]
+pub fn seeded_rng() -> SeededRng {
+    let seed = env::var(TEST_SEED_VARIABLE).ok().map_or_else(
+        || fastrand::u64(..),
+        |value| parse_seed(&value).unwrap_or_else(|| panic!("{TEST_SEED_VARIABLE} must be a decimal u64, got '{value}'")),
+    );
+
+    SeededRng::with_seed(seed)
+}
+
+fn parse_seed(value: &str) -> Option<u64> {
+    value.trim().parse().ok()
+}
+
+/// A deterministic random number generator created by [`seeded_rng()`].
+///
+/// Dereferences to [`fastrand::Rng`], and prints its seed if dropped during a panic.
+#[derive(Debug)]
+pub struct SeededRng {
+    rng: fastrand::Rng,
+    seed: u64,
+}
+
+impl SeededRng {
+    /// Creates a generator from a known seed, for example one printed by a failed test.
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            rng: fastrand::Rng::with_seed(seed),
+            seed,
+        }
+    }
+
+    #[must_use]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl Deref for SeededRng {
+    type Target = fastrand::Rng;
+
+    fn deref(&self) -> &Self::Target {
+        &self.rng
+    }
+}
+
+impl DerefMut for SeededRng {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.rng
+    }
+}
+
+impl Drop for SeededRng {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            eprintln!(
+                "randomized test failed with seed {seed}, reproduce it with {TEST_SEED_VARIABLE}={seed}",
+                seed = self.seed
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut first = SeededRng::with_seed(42);
+        let mut second = SeededRng::with_seed(42);
+
+        let first: Vec<_> = (0..8).map(|_| first.u32(..)).collect();
+        let second: Vec<_> = (0..8).map(|_| second.u32(..)).collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn parses_decimal_seeds() {
+        assert_eq!(parse_seed("42"), Some(42));
+        assert_eq!(parse_seed(" 18446744073709551615\n"), Some(u64::MAX));
+        assert_eq!(parse_seed("0x2a"), None);
+        assert_eq!(parse_seed(""), None);
+    }
+
+    #[test]
+    fn reports_seed() {
+        assert_eq!(SeededRng::with_seed(7).seed(), 7);
+        let rng = seeded_rng();
+        assert_eq!(SeededRng::with_seed(rng.seed()).u64(..), SeededRng::with_seed(rng.seed()).u64(..));
+    }
+}
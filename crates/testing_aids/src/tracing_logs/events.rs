@@ -0,0 +1,291 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Structured event capture with assertions.
+
+use std::cell::RefCell;
+use std::fmt::{self, Debug, Display, Formatter, Write as _};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+pub use tracing::Level;
+use tracing::field::{Field, Visit};
+use tracing::subscriber::DefaultGuard;
+use tracing::{Event, Subscriber};
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+
+/// Justification for `expect` on an event buffer mutex: it is only ever locked for
+/// infallible `Vec` operations, so it can never be poisoned.
+const EVENTS_NEVER_POISONED: &str = "the event buffer is only locked for infallible Vec operations, so the mutex is never poisoned";
+
+/// The event buffers of the active [`LogCapture`]s of all threads, for the diagnostics printed
+/// when a test times out.
+static ALL_ACTIVE: Mutex<Vec<Arc<Mutex<Vec<CapturedEvent>>>>> = Mutex::new(Vec::new());
+
+thread_local! {
+    /// The event buffers of the active [`LogCapture`]s of this thread, innermost last.
+    static ACTIVE: RefCell<Vec<Arc<Mutex<Vec<CapturedEvent>>>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Starts recording the `tracing` events emitted on the current thread.
+///
+/// The events are recorded until the returned guard is dropped, and can be inspected
+/// with [`LogCapture::events`] or asserted on with [`assert_event!`](crate::assert_event).
+///
+/// Like [`Capture`](super::Capture), this installs a subscriber for the current thread
+/// only, so it needs no `#[serial]` and events emitted on other threads are not recorded.
+///
+/// # Panics
+///
+/// Panics if the silent always-interested subscriber has not been installed by
+/// `testing_aids::init_tracing!()`. See `docs/tracing-tests.md`.
+pub fn capture_logs() -> LogCapture {
+    super::output::assert_initialized();
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let subscriber = tracing_subscriber::registry().with(RecordingLayer {
+        events: Arc::clone(&events),
+    });
+    let guard = tracing::subscriber::set_default(subscriber);
+    ACTIVE.with_borrow_mut(|active| active.push(Arc::clone(&events)));
+    all_active().push(Arc::clone(&events));
+
+    LogCapture { events, _guard: guard }
+}
+
+/// Records the `tracing` events of the current thread, started by [`capture_logs`].
+#[derive(Debug)]
+#[must_use]
+pub struct LogCapture {
+    events: Arc<Mutex<Vec<CapturedEvent>>>,
+    _guard: DefaultGuard,
+}
+
+impl LogCapture {
+    /// Returns the events recorded so far, in emission order.
+    #[must_use]
+    #[expect(clippy::missing_panics_doc, reason = "the event buffer mutex is never poisoned")]
+    pub fn events(&self) -> Vec<CapturedEvent> {
+        self.events.lock().expect(EVENTS_NEVER_POISONED).clone()
+    }
+}
+
+impl Drop for LogCapture {
+    fn drop(&mut self) {
+        ACTIVE.with_borrow_mut(|active| active.retain(|events| !Arc::ptr_eq(events, &self.events)));
+        all_active().retain(|events| !Arc::ptr_eq(events, &self.events));
+    }
+}
+
+// The list is valid after every operation, so it can be used even if a panic poisoned the lock.
+fn all_active() -> MutexGuard<'static, Vec<Arc<Mutex<Vec<CapturedEvent>>>>> {
+    ALL_ACTIVE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Returns up to the last `count` events recorded by the active [`LogCapture`]s of all threads.
+///
+/// Never blocks: buffers that are locked at the time of the call are skipped, so this is
+/// safe to call from a watchdog while the test is stuck holding a lock.
+pub(crate) fn recent_events(count: usize) -> Vec<String> {
+    let Ok(active) = ALL_ACTIVE.try_lock() else {
+        return Vec::new();
+    };
+
+    let mut lines: Vec<String> = active
+        .iter()
+        .filter_map(|events| events.try_lock().ok())
+        .flat_map(|events| events.iter().map(ToString::to_string).collect::<Vec<_>>())
+        .collect();
+
+    lines.drain(..lines.len().saturating_sub(count));
+    lines
+}
+
+/// A `tracing` event recorded by [`capture_logs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturedEvent {
+    level: Level,
+    target: String,
+    message: String,
+    fields: Vec<(String, String)>,
+}
+
+impl CapturedEvent {
+    #[must_use]
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    #[must_use]
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Returns the message of the event, empty if it has none.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the value of field `name`, formatted with `Debug` unless it is a string.
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields.iter().find(|(field, _)| field == name).map(|(_, value)| value.as_str())
+    }
+}
+
+/// Formats the event as `LEVEL target: message field=value ...`.
+impl Display for CapturedEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.level, self.target, self.message)?;
+        for (name, value) in &self.fields {
+            write!(f, " {name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Conditions an event must satisfy to match, used by [`assert_event!`](crate::assert_event).
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct EventFilter {
+    level: Option<Level>,
+    target: Option<String>,
+    contains: Vec<String>,
+}
+
+impl EventFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches events of exactly `level`.
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Matches events whose target starts with `target`, such as a module path.
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// Matches events whose message or field values contain `text`.
+    pub fn contains(mut self, text: impl Into<String>) -> Self {
+        self.contains.push(text.into());
+        self
+    }
+
+    #[must_use]
+    pub fn matches(&self, event: &CapturedEvent) -> bool {
+        let formatted = event.to_string();
+        self.level.is_none_or(|level| event.level == level)
+            && self.target.as_ref().is_none_or(|target| event.target.starts_with(target.as_str()))
+            && self.contains.iter().all(|text| formatted.contains(text.as_str()))
+    }
+}
+
+/// Asserts that the innermost active [`capture_logs`] of this thread recorded an
+/// event matching `filter`.
+///
+/// Prefer the [`assert_event!`](crate::assert_event) macro.
+///
+/// # Panics
+///
+/// Panics if no event matches, listing the recorded events, or if no capture is
+/// active on this thread.
+#[track_caller]
+pub fn assert_event(filter: &EventFilter) {
+    let events = ACTIVE
+        .with_borrow(|active| active.last().map(|events| events.lock().expect(EVENTS_NEVER_POISONED).clone()))
+        .expect("assert_event! requires an active capture_logs() guard on the current thread");
+
+    if events.iter().any(|event| filter.matches(event)) {
+        return;
+    }
+
+    let mut recorded = String::new();
+    for event in &events {
+        writeln!(recorded, "  {event}").expect("writing to a String cannot fail");
+    }
+    panic!("no recorded event matches {filter:?}, recorded events:\n{recorded}");
+}
+
+/// Records every event into the buffer of a [`LogCapture`].
+struct RecordingLayer {
+    events: Arc<Mutex<Vec<CapturedEvent>>>,
+}
+
+impl<S: Subscriber> Layer<S> for RecordingLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut visitor = EventVisitor::default();
+        event.record(&mut visitor);
+
+        self.events.lock().expect(EVENTS_NEVER_POISONED).push(CapturedEvent {
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+            fields: visitor.fields,
+        });
+    }
+}
+
+#[derive(Default)]
+struct EventVisitor {
+    message: String,
+    fields: Vec<(String, String)>,
+}
+
+impl Visit for EventVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            value.clone_into(&mut self.message);
+        } else {
+            self.fields.push((field.name().to_string(), value.to_string()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        } else {
+            self.fields.push((field.name().to_string(), format!("{value:?}")));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(level: Level, target: &str, message: &str) -> CapturedEvent {
+        CapturedEvent {
+            level,
+            target: target.to_string(),
+            message: message.to_string(),
+            fields: vec![("attempt".to_string(), "2".to_string())],
+        }
+    }
+
+    #[test]
+    fn filter_matches_all_conditions() {
+        let event = event(Level::WARN, "seatbelt::retry", "retrying request");
+
+        assert!(EventFilter::new().matches(&event));
+        assert!(EventFilter::new().level(Level::WARN).contains("retry").matches(&event));
+        assert!(EventFilter::new().target("seatbelt").contains("attempt=2").matches(&event));
+        assert!(!EventFilter::new().level(Level::ERROR).contains("retry").matches(&event));
+        assert!(!EventFilter::new().target("cachet").matches(&event));
+        assert!(!EventFilter::new().contains("retry").contains("timeout").matches(&event));
+    }
+
+    #[test]
+    fn display_includes_fields() {
+        let event = event(Level::INFO, "app", "started");
+        assert_eq!(event.to_string(), "INFO app: started attempt=2");
+        assert_eq!(event.field("attempt"), Some("2"));
+        assert_eq!(event.field("missing"), None);
+    }
+}
@@ -7,13 +7,16 @@
 //! process-global state. This module installs a silent, always-interested
 //! subscriber (via [`initialize`]) so that no callsite can ever be poisoned into the
 //! disabled state, and provides sanctioned ways to capture emitted events:
-//! thread-local capture with [`Capture`] for unit tests, and the process-global
-//! [`write_to_stdout_and_buffer`] bridge for `#[serial]` integration tests.
+//! thread-local capture with [`Capture`] for unit tests, structured thread-local
+//! capture with [`capture_logs`] for asserting on individual events, and the
+//! process-global [`write_to_stdout_and_buffer`] bridge for `#[serial]` integration tests.
 //!
 //! See `docs/tracing-tests.md` for the full design and rules.
 
 mod capture;
+mod events;
 mod output;
 
 pub use capture::*;
+pub use events::*;
 pub use output::*;
@@ -99,7 +99,7 @@ fn workspace_directory() -> String {
 ///
 /// Panics if the directory cannot be created or accessed.
 #[must_use]
-fn logs_directory() -> String {
+pub(crate) fn logs_directory() -> String {
     let workspace_dir = workspace_directory();
     let logs_dir = format!("{workspace_dir}/test-logs");
 
@@ -414,6 +414,21 @@ const CAPTURE_BUFFER_NEVER_POISONED: &str =
 /// active, one entry per line. `None` when no capture is in progress.
 static LOG_BUFFER: Mutex<Option<Arc<Mutex<Vec<String>>>>> = Mutex::new(None);
 
+/// Returns up to the last `count` lines captured by the active [`BufferGuard`], if any.
+///
+/// Never blocks, returning no lines if the buffer is locked at the time of the call, so this
+/// is safe to call from a watchdog while the test is stuck.
+pub(crate) fn recent_lines(count: usize) -> Vec<String> {
+    let Ok(slot) = LOG_BUFFER.try_lock() else {
+        return Vec::new();
+    };
+    let Some(Ok(lines)) = slot.as_ref().map(|buffer| buffer.try_lock()) else {
+        return Vec::new();
+    };
+
+    lines[lines.len().saturating_sub(count)..].to_vec()
+}
+
 /// Lock-free mirror of whether [`LOG_BUFFER`] currently holds a buffer. Set under the
 /// `LOG_BUFFER` lock on attach/detach and read by [`buffer_active`] on the per-event
 /// path so the filter never has to take the global mutex.
@@ -0,0 +1,176 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Diagnostics printed by the watchdog of `execute_or_terminate_process()` before it
+//! terminates a test process that timed out.
+
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use tick::ClockControl;
+
+use crate::tracing_logs;
+
+/// How many of the most recently captured log lines to print.
+const LOG_LINE_COUNT: usize = 20;
+
+/// The clock controls of the running `async_test_with_clock_control()` calls.
+static CLOCKS: Mutex<Vec<(u64, ClockControl)>> = Mutex::new(Vec::new());
+
+static NEXT_CLOCK_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Keeps a [`ClockControl`] in the timeout diagnostics until dropped.
+#[derive(Debug)]
+pub(crate) struct ClockRegistration {
+    id: u64,
+}
+
+impl Drop for ClockRegistration {
+    fn drop(&mut self) {
+        clocks().retain(|(id, _)| *id != self.id);
+    }
+}
+
+/// Includes the pending timers of `control` in the diagnostics printed if the test times out.
+pub(crate) fn register_clock(control: &ClockControl) -> ClockRegistration {
+    let id = NEXT_CLOCK_ID.fetch_add(1, Ordering::Relaxed);
+    clocks().push((id, control.clone()));
+    ClockRegistration { id }
+}
+
+// The list is valid after every operation, so it can be used even if a panic poisoned the lock.
+fn clocks() -> MutexGuard<'static, Vec<(u64, ClockControl)>> {
+    CLOCKS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Describes the state of the process for a test that did not complete within `timeout`.
+///
+/// Never blocks on locks held by the test, which is likely stuck.
+pub(crate) fn timeout_diagnostics(timeout: Duration) -> String {
+    let mut report = String::new();
+    let mut line = |text: &str| writeln!(report, "{text}").expect("writing to a String cannot fail");
+
+    line(&format!("Test timed out after {timeout:?}, terminating process."));
+
+    line("");
+    line("Threads:");
+    for thread in threads() {
+        line(&format!("  {thread}"));
+    }
+
+    line("");
+    line("Pending timers:");
+    match CLOCKS.try_lock() {
+        Ok(clocks) if clocks.is_empty() => line("  no ClockControl registered"),
+        Ok(clocks) => {
+            for (_, control) in clocks.iter() {
+                line(&format!("  {} timer(s) pending on {control:?}", control.pending_timers()));
+            }
+        }
+        Err(_) => line("  unavailable, the clock registry is locked"),
+    }
+
+    line("");
+    line(&format!("Last {LOG_LINE_COUNT} captured log lines:"));
+    let mut logs = tracing_logs::recent_lines(LOG_LINE_COUNT);
+    logs.extend(tracing_logs::recent_events(LOG_LINE_COUNT));
+    if logs.is_empty() {
+        line("  no log lines captured");
+    }
+    for log in &logs[logs.len().saturating_sub(LOG_LINE_COUNT)..] {
+        line(&format!("  {log}"));
+    }
+
+    report
+}
+
+/// Lists the threads of the process with their scheduling state and what they wait on.
+///
+/// The standard library cannot capture the backtraces of other threads, so this reads the
+/// closest available information from procfs.
+#[cfg(target_os = "linux")]
+fn threads() -> Vec<String> {
+    use std::fs;
+
+    let tasks = match fs::read_dir("/proc/self/task") {
+        Ok(tasks) => tasks,
+        Err(error) => return vec![format!("unavailable: {error}")],
+    };
+
+    let mut threads: Vec<_> = tasks
+        .filter_map(Result::ok)
+        .map(|task| {
+            let path = task.path();
+            let read = |file: &str| fs::read_to_string(path.join(file)).unwrap_or_default();
+
+            let name = read("comm");
+            // The state follows the parenthesized name, which may itself contain spaces.
+            let stat = read("stat");
+            let state = stat.rsplit_once(") ").and_then(|(_, rest)| rest.split(' ').next()).unwrap_or("?");
+            let wait_channel = read("wchan");
+
+            let mut thread = format!("{} {:?} state={state}", task.file_name().to_string_lossy(), name.trim_end());
+            if !wait_channel.is_empty() && wait_channel != "0" {
+                write!(thread, " waiting in {wait_channel}").expect("writing to a String cannot fail");
+            }
+            thread
+        })
+        .collect();
+
+    threads.sort();
+    threads
+}
+
+#[cfg(not(target_os = "linux"))]
+fn threads() -> Vec<String> {
+    vec!["thread dumps are not supported on this platform".to_string()]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::task::{Context, Waker};
+
+    use super::*;
+
+    #[test]
+    fn diagnostics_include_registered_clocks() {
+        let control = ClockControl::new();
+        let clock = control.to_clock();
+        let mut delay = Box::pin(clock.delay(Duration::from_secs(1)));
+        assert!(delay.as_mut().poll(&mut Context::from_waker(Waker::noop())).is_pending());
+
+        let registration = register_clock(&control);
+        let report = timeout_diagnostics(Duration::from_secs(3));
+        assert!(report.starts_with("Test timed out after 3s, terminating process."), "{report}");
+        assert!(report.contains("1 timer(s) pending on ClockControl"), "{report}");
+
+        drop(registration);
+        let report = timeout_diagnostics(Duration::from_secs(3));
+        assert!(report.contains("no ClockControl registered"), "{report}");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[cfg_attr(miri, ignore)] // Miri does not provide access to procfs.
+    #[test]
+    fn diagnostics_list_threads() {
+        let (started_sender, started) = std::sync::mpsc::channel();
+        let (sender, receiver) = std::sync::mpsc::channel::<()>();
+        let thread = std::thread::Builder::new()
+            .name("stuck-worker".to_string())
+            .spawn(move || {
+                started_sender.send(()).unwrap();
+                receiver.recv()
+            })
+            .unwrap();
+
+        // The thread names itself once it starts running.
+        started.recv().unwrap();
+        let report = timeout_diagnostics(Duration::from_secs(1));
+        assert!(report.contains("\"stuck-worker\" state="), "{report}");
+
+        drop(sender);
+        thread.join().unwrap().unwrap_err();
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Tests for `async_test_with_clock` and `async_test_with_clock_control`.
+
+use std::future::poll_fn;
+use std::task::Poll;
+use std::time::Duration;
+
+use testing_aids::{async_test_with_clock, async_test_with_clock_control};
+use tick::ClockControl;
+
+#[test]
+fn delays_complete_without_waiting() {
+    async_test_with_clock(async |clock| {
+        let start = clock.instant();
+        clock.delay(Duration::from_hours(1)).await;
+        assert_eq!(clock.instant().duration_since(start), Duration::from_hours(1));
+    });
+}
+
+#[test]
+fn dropped_delays_are_not_pending() {
+    let control = ClockControl::new().auto_advance_timers(true).auto_advance_limit(Duration::ZERO);
+
+    async_test_with_clock_control(&control, async |clock| {
+        let mut delay = Box::pin(clock.delay(Duration::from_secs(1)));
+        poll_fn(|cx| {
+            assert!(delay.as_mut().poll(cx).is_pending());
+            Poll::Ready(())
+        })
+        .await;
+    });
+}
+
+#[test]
+#[should_panic(expected = "1 timer(s) still pending at the end of the test")]
+fn pending_timers_fail_the_test() {
+    let control = ClockControl::new().auto_advance_timers(true).auto_advance_limit(Duration::ZERO);
+
+    async_test_with_clock_control(&control, async |clock| {
+        let mut delay = Box::pin(clock.delay(Duration::from_secs(1)));
+        poll_fn(|cx| {
+            assert!(delay.as_mut().poll(cx).is_pending());
+            Poll::Ready(())
+        })
+        .await;
+
+        // Leaks the delay without unregistering its timer.
+        std::mem::forget(delay);
+    });
+}
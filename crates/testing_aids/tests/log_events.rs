@@ -0,0 +1,69 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Tests for the thread-local `capture_logs` event capture and `assert_event!`.
+
+use testing_aids::assert_event;
+use testing_aids::tracing_logs::{Level, capture_logs};
+
+testing_aids::init_tracing!();
+
+#[test]
+fn records_events_with_fields() {
+    let logs = capture_logs();
+    tracing::warn!(attempt = 2, endpoint = "primary", "retrying request");
+
+    let events = logs.events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].level(), Level::WARN);
+    assert_eq!(events[0].target(), "log_events");
+    assert_eq!(events[0].message(), "retrying request");
+    assert_eq!(events[0].field("attempt"), Some("2"));
+    assert_eq!(events[0].field("endpoint"), Some("primary"));
+}
+
+#[test]
+fn assert_event_matches_conditions() {
+    let _logs = capture_logs();
+    tracing::info!("request started");
+    tracing::warn!(attempt = 2, "retrying request");
+
+    assert_event!(level: WARN, contains: "retry");
+    assert_event!(target: "log_events", contains: "retrying", contains: "attempt=2",);
+    assert_event!(level: INFO);
+}
+
+#[test]
+fn assert_event_reports_recorded_events() {
+    let _logs = capture_logs();
+    tracing::info!("request started");
+
+    let panic = std::panic::catch_unwind(|| assert_event!(level: WARN, contains: "retry")).unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(message.contains("INFO log_events: request started"), "{message}");
+}
+
+#[test]
+fn nested_captures_record_separately() {
+    let outer = capture_logs();
+    tracing::info!("outer event");
+
+    {
+        let inner = capture_logs();
+        tracing::info!("inner event");
+        assert_event!(contains: "inner");
+        assert_eq!(inner.events().len(), 1);
+    }
+
+    tracing::info!("outer again");
+    let messages: Vec<_> = outer.events().iter().map(|event| event.message().to_string()).collect();
+    assert_eq!(messages, ["outer event", "outer again"]);
+}
+
+#[test]
+fn events_of_other_threads_are_not_recorded() {
+    let logs = capture_logs();
+    std::thread::spawn(|| tracing::info!("background event")).join().unwrap();
+
+    assert!(logs.events().is_empty());
+}
@@ -1,9 +1,10 @@
 // Copyright (c) Microsoft Corporation.
 // Licensed under the MIT License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::hash::{BuildHasher, Hash};
 use std::path::{Path, PathBuf};
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime};
 
 use crate::affinity::Affinity;
 use crate::core::ThreadAware;
@@ -35,6 +36,8 @@ impl_transfer!(char);
 impl_transfer!(String);
 impl_transfer!(PathBuf);
 impl_transfer!(Duration);
+impl_transfer!(Instant);
+impl_transfer!(SystemTime);
 impl_transfer!(&Path);
 
 impl_transfer!(&'static str);
@@ -132,14 +135,53 @@ where
     }
 }
 
-// TODO: We should probably support custom hashers as well.
-#[expect(
-    clippy::implicit_hasher,
-    reason = "Supporting custom hashers would complicate the implementation significantly."
-)]
-impl<K, V> ThreadAware for HashMap<K, V>
+impl<T> ThreadAware for VecDeque<T>
 where
-    K: ThreadAware + Eq + std::hash::Hash,
+    T: ThreadAware,
+{
+    fn relocate(&mut self, source: Option<Affinity>, destination: Affinity) {
+        for value in self.iter_mut() {
+            value.relocate(source, destination);
+        }
+    }
+}
+
+// Relocating a key may change its hash or ordering, so the keyed collections below
+// reinsert every entry after relocating it.
+
+impl<K, V, S> ThreadAware for HashMap<K, V, S>
+where
+    K: ThreadAware + Eq + Hash,
+    V: ThreadAware,
+    S: BuildHasher + Send,
+{
+    fn relocate(&mut self, source: Option<Affinity>, destination: Affinity) {
+        let entries: Vec<_> = self.drain().collect();
+        for (mut key, mut value) in entries {
+            key.relocate(source, destination);
+            value.relocate(source, destination);
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<T, S> ThreadAware for HashSet<T, S>
+where
+    T: ThreadAware + Eq + Hash,
+    S: BuildHasher + Send,
+{
+    fn relocate(&mut self, source: Option<Affinity>, destination: Affinity) {
+        let values: Vec<_> = self.drain().collect();
+        for mut value in values {
+            value.relocate(source, destination);
+            self.insert(value);
+        }
+    }
+}
+
+impl<K, V> ThreadAware for BTreeMap<K, V>
+where
+    K: ThreadAware + Ord,
     V: ThreadAware,
 {
     fn relocate(&mut self, source: Option<Affinity>, destination: Affinity) {
@@ -152,6 +194,19 @@ where
     }
 }
 
+impl<T> ThreadAware for BTreeSet<T>
+where
+    T: ThreadAware + Ord,
+{
+    fn relocate(&mut self, source: Option<Affinity>, destination: Affinity) {
+        let old = std::mem::take(self);
+        for mut value in old {
+            value.relocate(source, destination);
+            self.insert(value);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ThreadAware;
@@ -341,7 +396,7 @@ mod tests {
 
     /// A type whose `relocate` visibly mutates state, so mutation tests catch
     /// no-op replacements.
-    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
     struct Tracker(bool);
 
     impl ThreadAware for Tracker {
@@ -416,4 +471,73 @@ mod tests {
             assert!(v.0, "value must be relocated");
         }
     }
+
+    #[test]
+    fn hashmap_with_custom_hasher_forwards_relocate() {
+        use std::collections::HashMap;
+        use std::hash::{BuildHasherDefault, DefaultHasher};
+
+        let (src, dst) = affinities();
+        let mut map: HashMap<Tracker, Tracker, BuildHasherDefault<DefaultHasher>> = HashMap::default();
+        map.insert(Tracker(false), Tracker(false));
+        map.relocate(src, dst);
+        assert_eq!(map.get(&Tracker(true)), Some(&Tracker(true)));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn hashset_forwards_relocate() {
+        use std::collections::HashSet;
+        use std::hash::{BuildHasherDefault, DefaultHasher};
+
+        let (src, dst) = affinities();
+        let mut set: HashSet<Tracker> = HashSet::from([Tracker(false)]);
+        set.relocate(src, dst);
+        assert!(set.contains(&Tracker(true)), "elements must be relocated and reinserted");
+        assert_eq!(set.len(), 1);
+
+        let mut set: HashSet<Tracker, BuildHasherDefault<DefaultHasher>> = HashSet::default();
+        set.insert(Tracker(false));
+        set.relocate(src, dst);
+        assert!(set.contains(&Tracker(true)), "elements must be relocated and reinserted");
+    }
+
+    #[test]
+    fn btree_collections_forward_relocate() {
+        use std::collections::{BTreeMap, BTreeSet};
+
+        let (src, dst) = affinities();
+        let mut map = BTreeMap::from([(Tracker(false), Tracker(false))]);
+        map.relocate(src, dst);
+        assert_eq!(map, BTreeMap::from([(Tracker(true), Tracker(true))]));
+
+        let mut set = BTreeSet::from([Tracker(false)]);
+        set.relocate(src, dst);
+        assert_eq!(set, BTreeSet::from([Tracker(true)]));
+    }
+
+    #[test]
+    fn vecdeque_forwards_relocate_to_elements() {
+        use std::collections::VecDeque;
+
+        let (src, dst) = affinities();
+        let mut val = VecDeque::from([Tracker(false), Tracker(false)]);
+        val.relocate(src, dst);
+        assert!(val.iter().all(|t| t.0), "all elements must be relocated");
+    }
+
+    #[test]
+    fn time_types_relocate_is_noop() {
+        use std::time::{Instant, SystemTime};
+
+        let (src, dst) = affinities();
+        let mut instant = Instant::now();
+        let expected = instant;
+        instant.relocate(src, dst);
+        assert_eq!(instant, expected);
+
+        let mut time = SystemTime::UNIX_EPOCH;
+        time.relocate(src, dst);
+        assert_eq!(time, SystemTime::UNIX_EPOCH);
+    }
 }
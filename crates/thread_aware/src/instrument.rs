@@ -0,0 +1,408 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Observing relocations to quantify cross-affinity traffic.
+//!
+//! Wrapping a value in an [`Instrumented`] reports each of its relocations to a
+//! [`RelocationObserver`], identified by the wrapped type and the source and destination
+//! affinities. Observers can be closures that forward relocations to a metrics system, or a
+//! [`RelocationCounter`] that aggregates them in memory for inspection.
+//!
+//! Values of types implementing [`ByteEstimate`], which includes the standard containers, also
+//! report an estimate of the heap memory they own, which approximates the amount of memory
+//! accessed across memory regions after relocating them.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::sync::Arc;
+//!
+//! use thread_aware::ThreadAware;
+//! use thread_aware::affinity::pinned_affinities;
+//! use thread_aware::instrument::{Instrumented, RelocationCounter, RelocationPath};
+//!
+//! let affinities = pinned_affinities(&[1, 1]);
+//! let counter = Arc::new(RelocationCounter::new());
+//!
+//! let mut buffer = Instrumented::with_byte_estimate(vec![0_u8; 1024], Arc::clone(&counter) as _);
+//! buffer.relocate(Some(affinities[0]), affinities[1]);
+//!
+//! let path = RelocationPath::new::<Vec<u8>>(Some(affinities[0]), affinities[1]);
+//! let stats = counter.get(&path);
+//! assert_eq!(stats.count(), 1);
+//! assert_eq!(stats.bytes(), 1024);
+//! ```
+
+use std::any::type_name;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Debug, Formatter};
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex};
+
+use crate::ThreadAware;
+use crate::affinity::Affinity;
+
+const POISONED_LOCK_MSG: &str = "poisoned lock means type invariants may not hold - not safe to continue execution";
+
+/// Types that can estimate the heap memory they own.
+pub trait ByteEstimate {
+    /// Returns the estimated number of bytes of heap memory owned by this value.
+    ///
+    /// The estimate covers the allocations of the value itself, not the ones of the values
+    /// it contains.
+    fn estimated_bytes(&self) -> usize;
+}
+
+impl<T> ByteEstimate for Vec<T> {
+    fn estimated_bytes(&self) -> usize {
+        self.capacity() * size_of::<T>()
+    }
+}
+
+impl<T> ByteEstimate for VecDeque<T> {
+    fn estimated_bytes(&self) -> usize {
+        self.capacity() * size_of::<T>()
+    }
+}
+
+impl ByteEstimate for String {
+    fn estimated_bytes(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<T> ByteEstimate for Box<T> {
+    fn estimated_bytes(&self) -> usize {
+        size_of::<T>()
+    }
+}
+
+impl<K, V, S> ByteEstimate for HashMap<K, V, S> {
+    fn estimated_bytes(&self) -> usize {
+        self.capacity() * size_of::<(K, V)>()
+    }
+}
+
+/// The type and the affinities of a relocation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RelocationPath {
+    type_name: &'static str,
+    source: Option<Affinity>,
+    destination: Affinity,
+}
+
+impl RelocationPath {
+    /// Creates the path of a relocation of a value of type `T`.
+    #[must_use]
+    pub fn new<T: ?Sized>(source: Option<Affinity>, destination: Affinity) -> Self {
+        Self {
+            type_name: type_name::<T>(),
+            source,
+            destination,
+        }
+    }
+
+    /// Returns the name of the relocated type, as returned by [`std::any::type_name`].
+    #[must_use]
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns the affinity the value was relocated from, if it was known.
+    #[must_use]
+    pub const fn source(&self) -> Option<Affinity> {
+        self.source
+    }
+
+    /// Returns the affinity the value was relocated to.
+    #[must_use]
+    pub const fn destination(&self) -> Affinity {
+        self.destination
+    }
+
+    /// Returns `true` if the value was relocated to a different memory region.
+    ///
+    /// Relocations from an unknown source are considered to cross memory regions.
+    #[must_use]
+    pub fn crosses_memory_regions(&self) -> bool {
+        self.source.map(Affinity::memory_region_index) != Some(self.destination.memory_region_index())
+    }
+}
+
+/// A relocation reported to a [`RelocationObserver`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Relocation {
+    path: RelocationPath,
+    bytes: Option<usize>,
+}
+
+impl Relocation {
+    /// Returns the type and the affinities of the relocation.
+    #[must_use]
+    pub const fn path(&self) -> &RelocationPath {
+        &self.path
+    }
+
+    /// Returns the estimated heap memory owned by the relocated value, if it is known.
+    #[must_use]
+    pub const fn bytes(&self) -> Option<usize> {
+        self.bytes
+    }
+}
+
+/// Receives the relocations of [`Instrumented`] values.
+///
+/// Observers are called on the thread performing the relocation, so they should be cheap
+/// and avoid contention. Closures taking a [`Relocation`] implement this trait.
+pub trait RelocationObserver: Send + Sync {
+    /// Called after a value was relocated.
+    fn relocated(&self, relocation: &Relocation);
+}
+
+impl<F> RelocationObserver for F
+where
+    F: Fn(&Relocation) + Send + Sync,
+{
+    fn relocated(&self, relocation: &Relocation) {
+        self(relocation);
+    }
+}
+
+/// Reports the relocations of the wrapped value to a [`RelocationObserver`].
+///
+/// Cloning the wrapper shares the observer. See the [module documentation](self) for details.
+pub struct Instrumented<T> {
+    value: T,
+    observer: Arc<dyn RelocationObserver>,
+    estimate: fn(&T) -> Option<usize>,
+}
+
+impl<T> Instrumented<T> {
+    /// Wraps a value whose relocations are reported to `observer` without a byte estimate.
+    pub fn new(value: T, observer: Arc<dyn RelocationObserver>) -> Self {
+        Self {
+            value,
+            observer,
+            estimate: |_| None,
+        }
+    }
+
+    /// Wraps a value whose relocations are reported to `observer` with a byte estimate.
+    pub fn with_byte_estimate(value: T, observer: Arc<dyn RelocationObserver>) -> Self
+    where
+        T: ByteEstimate,
+    {
+        Self {
+            value,
+            observer,
+            estimate: |value| Some(value.estimated_bytes()),
+        }
+    }
+
+    /// Consumes the wrapper and returns the inner value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Clone> Clone for Instrumented<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            observer: Arc::clone(&self.observer),
+            estimate: self.estimate,
+        }
+    }
+}
+
+impl<T: Debug> Debug for Instrumented<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Instrumented").field(&self.value).finish_non_exhaustive()
+    }
+}
+
+impl<T> Deref for Instrumented<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Instrumented<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T: ThreadAware> ThreadAware for Instrumented<T> {
+    fn relocate(&mut self, source: Option<Affinity>, destination: Affinity) {
+        self.value.relocate(source, destination);
+        self.observer.relocated(&Relocation {
+            path: RelocationPath::new::<T>(source, destination),
+            bytes: (self.estimate)(&self.value),
+        });
+    }
+}
+
+/// Aggregated relocations of a [`RelocationPath`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RelocationStats {
+    count: u64,
+    bytes: u64,
+}
+
+impl RelocationStats {
+    /// Returns the number of relocations.
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the sum of the byte estimates of the relocations.
+    ///
+    /// Relocations without a byte estimate are not included.
+    #[must_use]
+    pub const fn bytes(&self) -> u64 {
+        self.bytes
+    }
+}
+
+/// A [`RelocationObserver`] counting relocations per [`RelocationPath`].
+///
+/// All relocations update a single shared map, so the counter is meant for diagnostics and
+/// tests rather than hot paths in production.
+#[derive(Debug, Default)]
+pub struct RelocationCounter {
+    paths: Mutex<HashMap<RelocationPath, RelocationStats>>,
+}
+
+impl RelocationCounter {
+    /// Creates a counter without any relocations.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the aggregated relocations of `path`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the internal lock is poisoned.
+    #[must_use]
+    pub fn get(&self, path: &RelocationPath) -> RelocationStats {
+        self.paths.lock().expect(POISONED_LOCK_MSG).get(path).copied().unwrap_or_default()
+    }
+
+    /// Returns the aggregated relocations of all paths, the most frequent paths first.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the internal lock is poisoned.
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<(RelocationPath, RelocationStats)> {
+        let mut paths: Vec<_> = self
+            .paths
+            .lock()
+            .expect(POISONED_LOCK_MSG)
+            .iter()
+            .map(|(path, stats)| (*path, *stats))
+            .collect();
+        paths.sort_by(|(_, a), (_, b)| b.count.cmp(&a.count).then(b.bytes.cmp(&a.bytes)));
+        paths
+    }
+
+    /// Clears all counted relocations.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the internal lock is poisoned.
+    pub fn reset(&self) {
+        self.paths.lock().expect(POISONED_LOCK_MSG).clear();
+    }
+}
+
+impl RelocationObserver for RelocationCounter {
+    fn relocated(&self, relocation: &Relocation) {
+        let mut paths = self.paths.lock().expect(POISONED_LOCK_MSG);
+        let stats = paths.entry(relocation.path).or_default();
+        stats.count += 1;
+        stats.bytes += relocation.bytes.map_or(0, |bytes| bytes as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::affinity::pinned_affinities;
+
+    #[test]
+    fn byte_estimates() {
+        assert_eq!(Vec::<u32>::with_capacity(4).estimated_bytes(), 16);
+        assert_eq!(VecDeque::<u16>::with_capacity(4).estimated_bytes(), 8);
+        assert_eq!(String::with_capacity(5).estimated_bytes(), 5);
+        assert_eq!(Box::new(0_u64).estimated_bytes(), 8);
+
+        let map: HashMap<u32, u32> = HashMap::with_capacity(3);
+        assert_eq!(map.estimated_bytes(), map.capacity() * 8);
+    }
+
+    #[test]
+    fn observer_receives_relocations() {
+        let affinities = pinned_affinities(&[1, 1]);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let observed = Arc::clone(&calls);
+
+        let mut value = Instrumented::new(
+            42_u32,
+            Arc::new(move |relocation: &Relocation| {
+                assert_eq!(relocation.path().type_name(), "u32");
+                assert!(relocation.path().crosses_memory_regions());
+                assert_eq!(relocation.bytes(), None);
+                observed.fetch_add(1, Ordering::Relaxed);
+            }),
+        );
+
+        value.relocate(Some(affinities[0]), affinities[1]);
+        value.clone().relocate(None, affinities[0]);
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+        assert_eq!(value.into_inner(), 42);
+    }
+
+    #[test]
+    fn counter_aggregates_per_path() {
+        let affinities = pinned_affinities(&[2]);
+        let counter = Arc::new(RelocationCounter::new());
+        let forward = RelocationPath::new::<String>(Some(affinities[0]), affinities[1]);
+        let backward = RelocationPath::new::<String>(Some(affinities[1]), affinities[0]);
+        assert!(!forward.crosses_memory_regions());
+
+        let mut value = Instrumented::with_byte_estimate(String::with_capacity(10), Arc::clone(&counter) as _);
+        value.push_str("hello");
+        value.relocate(Some(affinities[0]), affinities[1]);
+        value.relocate(Some(affinities[0]), affinities[1]);
+        value.relocate(Some(affinities[1]), affinities[0]);
+
+        assert_eq!(counter.get(&forward).count(), 2);
+        assert_eq!(counter.get(&forward).bytes(), 20);
+        assert_eq!(counter.get(&backward).count(), 1);
+        assert_eq!(
+            counter.snapshot(),
+            vec![(forward, counter.get(&forward)), (backward, counter.get(&backward))]
+        );
+
+        counter.reset();
+        assert_eq!(counter.get(&forward), RelocationStats::default());
+        assert!(counter.snapshot().is_empty());
+    }
+
+    #[test]
+    fn instrumented_debug_and_deref() {
+        let mut value = Instrumented::new(vec![1], Arc::new(RelocationCounter::new()));
+        value.push(2);
+        assert_eq!(*value, [1, 2]);
+        assert_eq!(format!("{value:?}"), "Instrumented([1, 2], ..)");
+    }
+}
@@ -90,7 +90,10 @@
 //! * **`derive`** *(default)*: Re-exports the `#[derive(ThreadAware)]` macro from the companion
 //!   `thread_aware_macros` crate. Disable to avoid pulling in proc-macro code in minimal
 //!   environments: `default-features = false`.
-//! * **`threads`**: Enables features mainly used by async runtimes for OS interactions.
+//! * **`serde`**: Implements `Serialize` for the diagnostic snapshots of the `topology` module.
+//! * **`threads`**: Enables features mainly used by async runtimes for OS interactions, such as
+//!   pinning threads with a `registry::ThreadRegistry`, running work on pinned threads with a
+//!   `spawner::PinnedSpawner`, and capturing a `topology::Topology` for diagnostics.
 //!
 //! ## 3rd-party crate impls
 //!
@@ -114,6 +117,7 @@
 //!   `http::uri::PathAndQuery`, `http::uri::Port<T>`, `http::Error`,
 //!   `http::uri::InvalidUri`, `http::Request<T>`, `http::Response<T>`.
 //! * **`jiff02`**: Impls for `jiff::Timestamp`, `jiff::civil::DateTime`, etc.
+//! * **`smallvec`**: Impl for `smallvec::SmallVec<A>`.
 //! * **`uuid`**: Impl for `uuid::Uuid`.
 //!
 //! # Examples
@@ -173,10 +177,18 @@ pub mod closure;
 
 #[cfg(feature = "threads")]
 pub mod registry;
+#[cfg(feature = "threads")]
+pub mod spawner;
+#[cfg(feature = "threads")]
+pub mod topology;
+#[cfg(feature = "threads")]
+pub mod validator;
 
 #[doc(hidden)]
 pub mod __private;
 pub mod affinity;
+pub mod instrument;
+pub mod numa;
 
 #[doc(inline)]
 pub use core::ThreadAware;
@@ -198,6 +210,11 @@ pub use core::ThreadAware;
 ///
 /// # Attributes
 /// * `#[thread_aware(skip)]`: Prevents a field from being recursively transferred.
+/// * `#[thread_aware(with = "path::to::relocate")]`: Relocates a field by calling the given
+///   function instead of its own `ThreadAware::relocate` method. The function takes the same
+///   arguments as `relocate`, with the field as the receiver:
+///   `fn(&mut Field, Option<Affinity>, Affinity)`. Use this for resources that need active
+///   migration, such as buffers registered with a driver, and don't implement `ThreadAware`.
 ///
 /// # Generic Bounds
 /// Generic type parameters appearing in non-skipped fields automatically receive a
@@ -230,4 +247,6 @@ pub use core::ThreadAware;
 #[cfg(feature = "derive")]
 pub use ::thread_aware_macros::ThreadAware;
 pub use cell::{Arc, PerCore, PerNuma, PerProcess, storage};
+#[cfg(feature = "threads")]
+pub use registry::detect_topology;
 pub use wrappers::{Unaware, unaware};
@@ -0,0 +1,269 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Moving heap memory to the memory region of the destination affinity.
+//!
+//! Relocating a `Vec`, `String`, `Box`, or `HashMap` only moves its pointer: the backing
+//! memory stays in the memory region it was allocated in, so the destination thread keeps
+//! accessing remote memory. Wrapping such a value in a [`NumaLocal`] opts into copying the
+//! backing memory into a fresh allocation whenever the value is relocated to a different
+//! memory region.
+//!
+//! The fresh allocation is made and written by the thread that calls
+//! [`relocate`](ThreadAware::relocate). Operating systems place new memory in the memory region
+//! of the thread that first touches it by default, so the memory becomes local if relocation
+//! happens on the destination thread, like it does for work run by a
+//! `spawner::PinnedSpawner`. Types with their own NUMA-aware allocator, for example one using
+//! `mbind` or `VirtualAllocExNuma`, can implement [`Reallocate`] to use it instead.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use thread_aware::ThreadAware;
+//! use thread_aware::affinity::pinned_affinities;
+//! use thread_aware::numa::NumaLocal;
+//!
+//! let affinities = pinned_affinities(&[1, 1]);
+//! let mut buffer = NumaLocal(vec![0_u8; 4096]);
+//!
+//! // Copies the buffer into memory allocated by the calling thread
+//! buffer.relocate(Some(affinities[0]), affinities[1]);
+//! assert_eq!(buffer.len(), 4096);
+//! ```
+
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::mem::{self, MaybeUninit};
+use std::ops::{Deref, DerefMut};
+
+use crate::ThreadAware;
+use crate::affinity::Affinity;
+
+/// Types that can move their heap memory into a fresh allocation.
+///
+/// Implementations must keep the value logically unchanged. They are called by [`NumaLocal`]
+/// when the value is relocated to a different memory region.
+pub trait Reallocate {
+    /// Moves the backing memory of this value into a fresh allocation made by the calling
+    /// thread.
+    fn reallocate(&mut self);
+}
+
+impl<T> Reallocate for Vec<T> {
+    fn reallocate(&mut self) {
+        if self.capacity() == 0 || size_of::<T>() == 0 {
+            return;
+        }
+
+        let mut fresh = Self::with_capacity(self.capacity());
+        fresh.append(self);
+        *self = fresh;
+    }
+}
+
+impl Reallocate for String {
+    fn reallocate(&mut self) {
+        if self.capacity() == 0 {
+            return;
+        }
+
+        let mut fresh = Self::with_capacity(self.capacity());
+        fresh.push_str(self);
+        *self = fresh;
+    }
+}
+
+impl<T> Reallocate for Box<T> {
+    fn reallocate(&mut self) {
+        if size_of::<T>() == 0 {
+            return;
+        }
+
+        let mut fresh = Self::new_uninit();
+        // SAFETY: the source is a valid value and the fresh allocation can't overlap it.
+        unsafe { fresh.as_mut_ptr().copy_from_nonoverlapping(&raw const **self, 1) };
+        // SAFETY: the copy above fully initialized the fresh allocation.
+        let fresh = unsafe { fresh.assume_init() };
+
+        // The value now lives in the fresh allocation, so the stale allocation is released
+        // without dropping its bitwise copy of the value.
+        let stale = Self::into_raw(mem::replace(self, fresh)).cast::<MaybeUninit<T>>();
+        // SAFETY: the pointer comes from `Box::into_raw`, and `MaybeUninit<T>` has the same layout as `T`.
+        drop(unsafe { Box::from_raw(stale) });
+    }
+}
+
+impl<K, V, S> Reallocate for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Clone,
+{
+    fn reallocate(&mut self) {
+        if self.capacity() == 0 {
+            return;
+        }
+
+        let mut fresh = Self::with_capacity_and_hasher(self.capacity(), self.hasher().clone());
+        fresh.extend(self.drain());
+        *self = fresh;
+    }
+}
+
+/// Reallocates the wrapped value when it is relocated to a different memory region.
+///
+/// On [`relocate`](ThreadAware::relocate), the wrapped value is relocated first, and then
+/// [reallocated](Reallocate::reallocate) if the destination is in a different memory region
+/// than the source, or if the source is unknown. See the [module documentation](self) for how
+/// the fresh allocation becomes local to the destination.
+///
+/// # Performance Considerations
+///
+/// Reallocation copies the whole value, so it only pays off for large values that are accessed
+/// frequently after relocation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[repr(transparent)]
+pub struct NumaLocal<T>(pub T);
+
+impl<T> NumaLocal<T> {
+    /// Consumes the wrapper and returns the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for NumaLocal<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> Deref for NumaLocal<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for NumaLocal<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: ThreadAware + Reallocate> ThreadAware for NumaLocal<T> {
+    fn relocate(&mut self, source: Option<Affinity>, destination: Affinity) {
+        self.0.relocate(source, destination);
+
+        if source.map(Affinity::memory_region_index) != Some(destination.memory_region_index()) {
+            self.0.reallocate();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::affinity::pinned_affinities;
+
+    #[test]
+    fn reallocate_vec() {
+        let mut value = Vec::with_capacity(8);
+        value.extend(["a".to_string(), "b".to_string()]);
+        let before = value.as_ptr();
+
+        value.reallocate();
+        assert_ne!(value.as_ptr(), before);
+        assert_eq!(value, ["a", "b"]);
+        assert_eq!(value.capacity(), 8);
+
+        let mut empty: Vec<u8> = Vec::new();
+        empty.reallocate();
+        assert_eq!(empty.capacity(), 0);
+
+        let mut zero_sized = vec![(); 3];
+        zero_sized.reallocate();
+        assert_eq!(zero_sized.len(), 3);
+    }
+
+    #[test]
+    fn reallocate_string() {
+        let mut value = "hello".to_string();
+        let before = value.as_ptr();
+
+        value.reallocate();
+        assert_ne!(value.as_ptr(), before);
+        assert_eq!(value, "hello");
+
+        let mut empty = String::new();
+        empty.reallocate();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn reallocate_box_drops_value_once() {
+        struct Counted(Rc<Cell<usize>>);
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let mut value = Box::new(Counted(Rc::clone(&drops)));
+        let before = (&raw const *value).addr();
+
+        value.reallocate();
+        assert_ne!((&raw const *value).addr(), before);
+        assert_eq!(drops.get(), 0);
+
+        drop(value);
+        assert_eq!(drops.get(), 1);
+
+        let mut zero_sized = Box::new(());
+        zero_sized.reallocate();
+    }
+
+    #[test]
+    fn reallocate_hash_map() {
+        let mut value: HashMap<i32, String> = (0..16).map(|i| (i, i.to_string())).collect();
+        let expected = value.clone();
+
+        value.reallocate();
+        assert_eq!(value, expected);
+
+        let mut empty: HashMap<i32, i32> = HashMap::new();
+        empty.reallocate();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn numa_local_reallocates_across_memory_regions() {
+        let affinities = pinned_affinities(&[2, 1]);
+        let mut value = NumaLocal(vec![1, 2, 3]);
+
+        let before = value.as_ptr();
+        value.relocate(Some(affinities[0]), affinities[2]);
+        assert_ne!(value.as_ptr(), before);
+
+        let before = value.as_ptr();
+        value.relocate(None, affinities[0]);
+        assert_ne!(value.as_ptr(), before);
+
+        assert_eq!(value.into_inner(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn numa_local_keeps_allocation_within_memory_region() {
+        let affinities = pinned_affinities(&[2, 1]);
+        let mut value = NumaLocal::from("hello".to_string());
+
+        let before = value.as_ptr();
+        value.relocate(Some(affinities[0]), affinities[1]);
+        assert_eq!(value.as_ptr(), before);
+    }
+}
@@ -3,14 +3,16 @@
 
 //! Building blocks for runtimes and thread-aware hosts.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::marker::PhantomData;
 use std::num::NonZero;
-use std::sync::Mutex;
-use std::thread::ThreadId;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle, ThreadId};
 
 use many_cpus::SystemHardware;
 
 use crate::affinity::Affinity;
+use crate::topology::{MemoryRegion, ProcessorInfo, ThreadInfo, Topology};
 
 const POISONED_LOCK_MSG: &str = "poisoned lock means type invariants may not hold - not safe to continue execution";
 
@@ -54,12 +56,20 @@ impl NumaNode {
     }
 }
 
+/// A thread pinned by a [`ThreadRegistry`].
+#[derive(Debug)]
+struct PinnedThread {
+    affinity: Affinity,
+    name: Option<String>,
+}
+
 /// A registry for managing pinning threads to specific processors.
 #[derive(Debug)]
 pub struct ThreadRegistry {
-    threads: Mutex<HashMap<ThreadId, Affinity>>,
+    threads: Mutex<HashMap<ThreadId, PinnedThread>>,
     processors: Vec<Processor>,
     numa_nodes: Vec<NumaNode>,
+    hardware: SystemHardware,
 }
 
 impl ThreadRegistry {
@@ -119,6 +129,7 @@ impl ThreadRegistry {
             processors: Processor::unpack(&processors),
             numa_nodes,
             threads: Mutex::new(HashMap::new()),
+            hardware: hardware.clone(),
         }
     }
 
@@ -154,7 +165,7 @@ impl ThreadRegistry {
             .lock()
             .expect(POISONED_LOCK_MSG)
             .get(&std::thread::current().id())
-            .copied()
+            .map(|thread| thread.affinity)
     }
 
     /// Pins the current thread to the specified memory affinity.
@@ -166,10 +177,212 @@ impl ThreadRegistry {
         let core_index = affinity.processor_index();
         let processor = &self.processors[core_index];
         processor.pin_current_thread_to();
-        self.threads
-            .lock()
-            .expect(POISONED_LOCK_MSG)
-            .insert(std::thread::current().id(), affinity);
+        self.register_current_thread(Some(affinity));
+    }
+
+    /// Spawns a thread that is pinned to the specified memory affinity for its whole lifetime.
+    ///
+    /// The thread pins itself and registers with this registry before it runs `f`, and
+    /// unregisters after `f` returns or panics. Operating systems place memory in the memory
+    /// region of the thread that first touches it by default, so all allocations made by `f`
+    /// land in the memory region of `affinity`.
+    ///
+    /// The thread is named after the processor index of `affinity`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use thread_aware::registry::ThreadRegistry;
+    ///
+    /// let registry = Arc::new(ThreadRegistry::default());
+    /// let affinity = registry.affinities().next().unwrap();
+    ///
+    /// let pinned = Arc::clone(&registry);
+    /// let handle = registry.spawn_pinned(affinity, move || pinned.current_affinity());
+    ///
+    /// assert_eq!(handle.join().unwrap(), Some(affinity));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This will panic if affinity contains incorrect processor index, or if the operating
+    /// system fails to create the thread.
+    pub fn spawn_pinned<F, T>(self: &Arc<Self>, affinity: Affinity, f: F) -> JoinHandle<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        assert!(
+            affinity.processor_index() < self.processors.len(),
+            "the affinity must be one of the affinities of this registry"
+        );
+
+        let registry = Arc::clone(self);
+        thread::Builder::new()
+            .name(format!("thread_aware-pinned-{}", affinity.processor_index()))
+            .spawn(move || {
+                registry.pin_to(affinity);
+                let _registration = Registration(&registry);
+                f()
+            })
+            .expect("the operating system must be able to create pinned threads")
+    }
+
+    /// Records the affinity the current thread is pinned to, or that it is not pinned.
+    fn register_current_thread(&self, affinity: Option<Affinity>) {
+        let current = std::thread::current();
+        let mut threads = self.threads.lock().expect(POISONED_LOCK_MSG);
+        match affinity {
+            Some(affinity) => threads.insert(
+                current.id(),
+                PinnedThread {
+                    affinity,
+                    name: current.name().map(str::to_owned),
+                },
+            ),
+            None => threads.remove(&current.id()),
+        };
+        crate::validator::set_pinned(affinity);
+    }
+
+    /// Captures how the processors of this registry map onto the hardware, and which threads
+    /// are pinned to them.
+    ///
+    /// The snapshot can be logged with its [`Display`](std::fmt::Display) implementation,
+    /// or serialized when the `serde` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thread_aware::registry::ThreadRegistry;
+    ///
+    /// let registry = ThreadRegistry::default();
+    /// let topology = registry.topology();
+    ///
+    /// assert_eq!(topology.processor_count(), registry.num_affinities());
+    /// println!("{topology}");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This will panic if the internal lock is poisoned.
+    #[must_use]
+    pub fn topology(&self) -> Topology {
+        let threads = self.threads.lock().expect(POISONED_LOCK_MSG);
+        let mut memory_regions = BTreeMap::new();
+
+        for (affinity, processor) in self.affinities().zip(&self.processors) {
+            let processor = processor.as_processor();
+
+            let mut pinned_threads: Vec<_> = threads
+                .iter()
+                .filter(|(_, thread)| thread.affinity == affinity)
+                .map(|(id, thread)| ThreadInfo {
+                    id: *id,
+                    name: thread.name.clone(),
+                })
+                .collect();
+            pinned_threads.sort_by(|a, b| a.name.cmp(&b.name));
+
+            // Processors of different memory regions may be interleaved
+            memory_regions
+                .entry(affinity.memory_region_index())
+                .or_insert_with(|| MemoryRegion {
+                    index: affinity.memory_region_index(),
+                    os_id: processor.memory_region_id(),
+                    processors: Vec::new(),
+                })
+                .processors
+                .push(ProcessorInfo {
+                    index: affinity.processor_index(),
+                    os_id: processor.id(),
+                    pinned_threads,
+                });
+        }
+
+        Topology {
+            memory_regions: memory_regions.into_values().collect(),
+        }
+    }
+
+    /// Pins the current thread to the specified memory affinity until the returned guard is
+    /// dropped.
+    ///
+    /// Dropping the guard restores the processors the thread was pinned to before, and the
+    /// affinity reported by [`current_affinity`](Self::current_affinity). This allows setup
+    /// code and benchmarks to run on a specific affinity without a dedicated thread. Guards
+    /// can be nested, as long as they are dropped in reverse order.
+    ///
+    /// Only pinning done through this crate or `many_cpus` can be restored. A thread that was
+    /// not pinned that way is allowed to run on all processors available to the process
+    /// after the guard is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thread_aware::registry::ThreadRegistry;
+    ///
+    /// let registry = ThreadRegistry::default();
+    /// let affinity = registry.affinities().next().unwrap();
+    ///
+    /// {
+    ///     let _guard = registry.enter(affinity);
+    ///     assert_eq!(registry.current_affinity(), Some(affinity));
+    /// }
+    ///
+    /// assert_eq!(registry.current_affinity(), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This will panic if affinity contains incorrect processor index, or if the internal lock
+    /// is poisoned.
+    #[must_use = "the thread is only pinned until the guard is dropped"]
+    pub fn enter(&self, affinity: Affinity) -> AffinityGuard<'_> {
+        let previous_affinity = self.current_affinity();
+        let previous_processors = self.hardware.thread_processors();
+        self.pin_to(affinity);
+
+        AffinityGuard {
+            registry: self,
+            previous_affinity,
+            previous_processors,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// Keeps the current thread pinned to an affinity, returned by [`ThreadRegistry::enter`].
+///
+/// Dropping the guard restores the pinning of the thread from before the guard was created.
+#[derive(Debug)]
+pub struct AffinityGuard<'a> {
+    registry: &'a ThreadRegistry,
+    previous_affinity: Option<Affinity>,
+    previous_processors: Option<many_cpus::ProcessorSet>,
+    // The guard restores the pinning of the thread that created it
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for AffinityGuard<'_> {
+    fn drop(&mut self) {
+        match &self.previous_processors {
+            Some(processors) => processors.pin_current_thread_to(),
+            None => self.registry.hardware.processors().pin_current_thread_to(),
+        }
+
+        self.registry.register_current_thread(self.previous_affinity);
+    }
+}
+
+/// Unregisters the current thread from a registry when dropped, even during unwinding.
+struct Registration<'a>(&'a ThreadRegistry);
+
+impl Drop for Registration<'_> {
+    fn drop(&mut self) {
+        self.0.register_current_thread(None);
     }
 }
 
@@ -179,6 +392,42 @@ impl Default for ThreadRegistry {
     }
 }
 
+/// Detects the affinities of the current machine.
+///
+/// Returns one affinity for every processor available to the current process, with the
+/// memory regions (NUMA nodes) reported by the operating system. Use this instead of
+/// [`pinned_affinities`](crate::affinity::pinned_affinities) when the affinities should
+/// reflect the actual hardware rather than hand-coded counts.
+///
+/// Processors are the logical processors reported by the operating system, so the
+/// simultaneous multithreading siblings of a core appear as separate processors. The
+/// detected affinities match the ones of a [`ThreadRegistry`] using all processors, so
+/// they can be passed to [`ThreadRegistry::pin_to`].
+///
+/// # Examples
+///
+/// ```
+/// let affinities = thread_aware::detect_topology();
+///
+/// assert!(!affinities.is_empty());
+/// for affinity in &affinities {
+///     assert_eq!(affinity.processor_count(), affinities.len());
+///     assert!(affinity.memory_region_index() < affinity.memory_region_count());
+/// }
+/// ```
+///
+/// # Panics
+///
+/// If there are more than `u16::MAX` processors or memory regions.
+#[must_use]
+pub fn detect_topology() -> Vec<Affinity> {
+    topology(SystemHardware::current())
+}
+
+fn topology(hardware: &SystemHardware) -> Vec<Affinity> {
+    ThreadRegistry::with_hardware(&ProcessorCount::All, hardware).affinities().collect()
+}
+
 /// A wrapper around `many_cpus::ProcessorSet` that contains only a single processor
 #[derive(Debug)]
 struct Processor {
@@ -220,7 +469,7 @@ mod tests {
     use std::num::NonZero;
 
     use crate::affinity::pinned_affinities;
-    use crate::registry::{NumaNode, ProcessorCount, ThreadRegistry};
+    use crate::registry::{NumaNode, ProcessorCount, ThreadRegistry, detect_topology};
 
     #[test]
     #[cfg_attr(miri, ignore)]
@@ -281,6 +530,15 @@ mod tests {
         assert_eq!(current, Some(first));
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_detect_topology() {
+        let detected = detect_topology();
+        let registry = ThreadRegistry::new(&ProcessorCount::All);
+
+        assert_eq!(detected, registry.affinities().collect::<Vec<_>>());
+    }
+
     #[test]
     fn test_numa_node() {
         let invalid = NumaNode::invalid();
@@ -447,4 +705,141 @@ mod test_fake_hardware {
         assert!(hw.is_thread_processor_pinned());
         assert!(hw.is_thread_memory_region_pinned());
     }
+
+    #[test]
+    fn enter_restores_previous_pinning() {
+        let hw = SystemHardware::fake(HardwareBuilder::from_counts(nz!(4), nz!(2)));
+        let registry = ThreadRegistry::with_hardware(&ProcessorCount::Auto, &hw);
+        let affinities: Vec<_> = registry.affinities().collect();
+
+        {
+            let _outer = registry.enter(affinities[2]);
+            assert_eq!(registry.current_affinity(), Some(affinities[2]));
+            assert!(hw.is_thread_processor_pinned());
+
+            {
+                let _inner = registry.enter(affinities[0]);
+                assert_eq!(registry.current_affinity(), Some(affinities[0]));
+                assert_eq!(hw.current_processor_id(), registry.processors[0].as_processor().id());
+            }
+
+            assert_eq!(registry.current_affinity(), Some(affinities[2]));
+            assert_eq!(hw.current_processor_id(), registry.processors[2].as_processor().id());
+        }
+
+        assert_eq!(registry.current_affinity(), None);
+        assert!(!hw.is_thread_processor_pinned());
+        assert!(!hw.is_thread_memory_region_pinned());
+    }
+
+    #[test]
+    fn enter_after_pin_to_restores_pinned_affinity() {
+        let hw = SystemHardware::fake(HardwareBuilder::from_counts(nz!(4), nz!(2)));
+        let registry = ThreadRegistry::with_hardware(&ProcessorCount::Auto, &hw);
+        let affinities: Vec<_> = registry.affinities().collect();
+
+        registry.pin_to(affinities[1]);
+        drop(registry.enter(affinities[3]));
+
+        assert_eq!(registry.current_affinity(), Some(affinities[1]));
+        assert_eq!(hw.current_processor_id(), registry.processors[1].as_processor().id());
+    }
+
+    #[test]
+    fn topology_from_fake_hardware() {
+        let hw = SystemHardware::fake(HardwareBuilder::from_counts(nz!(6), nz!(3)));
+        let affinities = topology(&hw);
+
+        assert_eq!(affinities.len(), 6);
+        for (index, affinity) in affinities.iter().enumerate() {
+            assert_eq!(affinity.processor_index(), index);
+            assert_eq!(affinity.processor_count(), 6);
+            assert_eq!(affinity.memory_region_count(), 3);
+        }
+
+        let regions: HashSet<_> = affinities.iter().map(|affinity| affinity.memory_region_index()).collect();
+        assert_eq!(regions, HashSet::from([0, 1, 2]));
+    }
+
+    #[test]
+    fn topology_snapshot_lists_pinned_threads() {
+        let hw = SystemHardware::fake(HardwareBuilder::from_counts(nz!(4), nz!(2)));
+        let registry = ThreadRegistry::with_hardware(&ProcessorCount::Auto, &hw);
+        let affinities: Vec<_> = registry.affinities().collect();
+
+        std::thread::Builder::new()
+            .name("topology-test".to_string())
+            .spawn(move || {
+                registry.pin_to(affinities[3]);
+                let topology = registry.topology();
+
+                assert_eq!(topology.processor_count(), 4);
+                assert_eq!(topology.memory_regions().len(), 2);
+
+                for region in topology.memory_regions() {
+                    for processor in region.processors() {
+                        let affinity = affinities[processor.index()];
+                        assert_eq!(region.index(), affinity.memory_region_index());
+                        assert_eq!(processor.os_id(), registry.processors[processor.index()].as_processor().id());
+
+                        let names: Vec<_> = processor.pinned_threads().iter().map(|thread| thread.name()).collect();
+                        if processor.index() == 3 {
+                            assert_eq!(names, [Some("topology-test")]);
+                        } else {
+                            assert!(names.is_empty(), "{names:?}");
+                        }
+                    }
+                }
+
+                assert!(topology.to_string().contains(": topology-test"));
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn spawn_pinned_registers_thread_while_running() {
+        let hw = SystemHardware::fake(HardwareBuilder::from_counts(nz!(4), nz!(2)));
+        let registry = Arc::new(ThreadRegistry::with_hardware(&ProcessorCount::Auto, &hw));
+        let affinity = registry.affinities().nth(2).unwrap();
+
+        let pinned = Arc::clone(&registry);
+        let (affinity_inside, processor_id, name) = registry
+            .spawn_pinned(affinity, move || {
+                (
+                    pinned.current_affinity(),
+                    hw.current_processor_id(),
+                    thread::current().name().map(str::to_owned),
+                )
+            })
+            .join()
+            .unwrap();
+
+        assert_eq!(affinity_inside, Some(affinity));
+        assert_eq!(processor_id, registry.processors[2].as_processor().id());
+        assert_eq!(name.as_deref(), Some("thread_aware-pinned-2"));
+        assert!(registry.threads.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn spawn_pinned_unregisters_thread_on_panic() {
+        let hw = SystemHardware::fake(HardwareBuilder::from_counts(nz!(2), nz!(1)));
+        let registry = Arc::new(ThreadRegistry::with_hardware(&ProcessorCount::Auto, &hw));
+        let affinity = registry.affinities().next().unwrap();
+
+        let result = registry.spawn_pinned(affinity, || panic!("work failed")).join();
+
+        assert!(result.is_err());
+        assert!(registry.threads.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "the affinity must be one of the affinities of this registry")]
+    fn spawn_pinned_rejects_foreign_affinity() {
+        let hw = SystemHardware::fake(HardwareBuilder::from_counts(nz!(2), nz!(1)));
+        let registry = Arc::new(ThreadRegistry::with_hardware(&ProcessorCount::Auto, &hw));
+
+        drop(registry.spawn_pinned(crate::affinity::pinned_affinities(&[4])[3], || ()));
+    }
 }
@@ -0,0 +1,307 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Threads pinned to affinities that run thread-aware work.
+
+use std::any::Any;
+use std::fmt::{self, Debug, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, mpsc};
+use std::thread::{self, JoinHandle};
+
+use crate::affinity::Affinity;
+use crate::closure::ThreadAwareFnOnce;
+use crate::registry::ThreadRegistry;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A set of OS threads, each pinned to one affinity of a [`ThreadRegistry`], that run
+/// [`ThreadAwareFnOnce`] work.
+///
+/// This is the runtime-side counterpart of [`ThreadAware`](crate::ThreadAware): work sent to
+/// an affinity is [relocated](crate::ThreadAware::relocate) from the affinity of the calling
+/// thread to the destination affinity before it runs on the thread pinned to the destination.
+/// Calling threads that are not pinned by the spawner's registry relocate from `None`.
+///
+/// Each thread runs its work in the order it was spawned. Dropping the spawner waits for all
+/// spawned work to complete and then stops the threads.
+///
+/// # Examples
+///
+/// ```
+/// use thread_aware::closure::closure_once;
+/// use thread_aware::registry::{ProcessorCount, ThreadRegistry};
+/// use thread_aware::spawner::PinnedSpawner;
+///
+/// let spawner = PinnedSpawner::new(ThreadRegistry::new(&ProcessorCount::Auto));
+/// let destination = spawner.affinities().next().unwrap();
+///
+/// let task = spawner.spawn(destination, closure_once(21, |value: i32| value * 2));
+/// assert_eq!(task.join().unwrap(), 42);
+/// ```
+pub struct PinnedSpawner {
+    registry: Arc<ThreadRegistry>,
+    workers: Vec<Worker>,
+}
+
+struct Worker {
+    sender: Option<mpsc::Sender<Job>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl PinnedSpawner {
+    /// Creates a spawner with one thread for every affinity of `registry`.
+    ///
+    /// Each thread is created with [`ThreadRegistry::spawn_pinned`], so it is pinned to its
+    /// affinity before it runs any work.
+    ///
+    /// # Panics
+    ///
+    /// If the operating system fails to create a thread.
+    #[must_use]
+    pub fn new(registry: ThreadRegistry) -> Self {
+        let registry = Arc::new(registry);
+        let workers = registry.affinities().map(|affinity| Worker::start(&registry, affinity)).collect();
+
+        Self { registry, workers }
+    }
+
+    /// Returns the registry the threads of this spawner are pinned by.
+    #[must_use]
+    pub fn registry(&self) -> &ThreadRegistry {
+        &self.registry
+    }
+
+    /// Returns the affinities this spawner has threads for.
+    pub fn affinities(&self) -> impl Iterator<Item = Affinity> {
+        self.registry.affinities()
+    }
+
+    /// Runs `work` on the thread pinned to `destination`.
+    ///
+    /// The work is relocated from the affinity of the calling thread to `destination` on the
+    /// destination thread, right before it is called. Use the returned [`PinnedTask`] to wait
+    /// for the result.
+    ///
+    /// Work runs to completion before the next work on the same thread starts, so work that
+    /// waits for other work spawned to its own affinity never completes.
+    ///
+    /// # Panics
+    ///
+    /// If `destination` is not one of the [affinities](Self::affinities) of this spawner.
+    pub fn spawn<T, W>(&self, destination: Affinity, work: W) -> PinnedTask<T>
+    where
+        T: Send + 'static,
+        W: ThreadAwareFnOnce<T> + 'static,
+    {
+        let worker = self
+            .workers
+            .get(destination.processor_index())
+            .filter(|_| destination.processor_count() == self.workers.len())
+            .expect("the destination must be one of the affinities of this spawner");
+
+        let source = self.registry.current_affinity();
+        let (result_sender, result) = mpsc::sync_channel(1);
+        let job: Job = Box::new(move || {
+            let mut work = work;
+            let output = panic::catch_unwind(AssertUnwindSafe(|| {
+                work.relocate(source, destination);
+                work.call_once()
+            }));
+
+            // The task may have been dropped without waiting for the result
+            let _ = result_sender.send(output);
+        });
+
+        worker
+            .sender
+            .as_ref()
+            .expect("workers only release their sender when the spawner is dropped")
+            .send(job)
+            .expect("worker threads run until the spawner is dropped");
+
+        PinnedTask { result }
+    }
+}
+
+impl Default for PinnedSpawner {
+    fn default() -> Self {
+        Self::new(ThreadRegistry::default())
+    }
+}
+
+impl Debug for PinnedSpawner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PinnedSpawner")
+            .field("registry", &self.registry)
+            .field("threads", &self.workers.len())
+            .finish()
+    }
+}
+
+impl Drop for PinnedSpawner {
+    fn drop(&mut self) {
+        // Closing all channels first lets the threads drain their work concurrently
+        for worker in &mut self.workers {
+            worker.sender = None;
+        }
+
+        for worker in &mut self.workers {
+            // Work that owns the last handle to the spawner drops it on one of its threads,
+            // which can't wait for itself
+            if let Some(thread) = worker.thread.take().filter(|thread| thread.thread().id() != thread::current().id()) {
+                // Workers catch panics of their work, so joining only fails if the thread
+                // itself panicked, which leaves nothing to clean up
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+impl Worker {
+    fn start(registry: &Arc<ThreadRegistry>, affinity: Affinity) -> Self {
+        let (sender, jobs) = mpsc::channel::<Job>();
+        let thread = registry.spawn_pinned(affinity, move || {
+            for job in jobs {
+                job();
+            }
+        });
+
+        Self {
+            sender: Some(sender),
+            thread: Some(thread),
+        }
+    }
+}
+
+/// The result of work spawned by a [`PinnedSpawner`].
+///
+/// Dropping the task detaches it: the work still runs, but its result is discarded.
+#[derive(Debug)]
+pub struct PinnedTask<T> {
+    result: mpsc::Receiver<thread::Result<T>>,
+}
+
+impl<T> PinnedTask<T> {
+    /// Waits for the work to complete and returns its result.
+    ///
+    /// # Errors
+    ///
+    /// Returns the panic payload if the work panicked, or a `&'static str` payload if the
+    /// pinned thread stopped before running the work.
+    pub fn join(self) -> Result<T, Box<dyn Any + Send + 'static>> {
+        // Work always sends its result, even when it panics, so the channel only closes
+        // without a result if the thread itself failed, for example while pinning
+        self.result
+            .recv()
+            .unwrap_or_else(|_| Err(Box::new("the pinned thread stopped before running the work")))
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::ThreadAware;
+    use crate::closure::closure_once;
+    use crate::registry::ProcessorCount;
+
+    /// Records the relocations it receives.
+    #[derive(Debug, Default)]
+    struct Recorder(Vec<(Option<Affinity>, Affinity)>);
+
+    impl ThreadAware for Recorder {
+        fn relocate(&mut self, source: Option<Affinity>, destination: Affinity) {
+            self.0.push((source, destination));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn spawn_relocates_and_runs_pinned() {
+        let spawner = PinnedSpawner::default();
+
+        for destination in spawner.affinities() {
+            let task = spawner.spawn(
+                destination,
+                closure_once(
+                    (Recorder::default(), crate::Unaware(Arc::clone(&spawner.registry))),
+                    |(recorder, registry)| (recorder.0, registry.0.current_affinity()),
+                ),
+            );
+
+            let (relocations, pinned) = task.join().expect("the work does not panic");
+            assert_eq!(relocations, vec![(None, destination)]);
+            assert_eq!(pinned, Some(destination));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn spawn_from_pinned_thread_relocates_from_its_affinity() {
+        let spawner = Arc::new(PinnedSpawner::default());
+        let affinities: Vec<_> = spawner.affinities().collect();
+        let source = affinities[0];
+        let destination = *affinities.last().expect("registries have at least one affinity");
+
+        let inner = Arc::clone(&spawner);
+        let task = spawner.spawn(
+            source,
+            closure_once((crate::Unaware(inner), crate::Unaware(destination)), |(spawner, destination)| {
+                // The destination may be the same thread, so the inner task is joined outside
+                spawner
+                    .0
+                    .spawn(destination.0, closure_once(Recorder::default(), |recorder| recorder.0))
+            }),
+        );
+
+        let inner = task.join().expect("the work does not panic");
+        assert_eq!(inner.join().expect("the work does not panic"), vec![(Some(source), destination)]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn panicking_work_is_reported_and_thread_survives() {
+        let spawner = PinnedSpawner::new(ThreadRegistry::new(&ProcessorCount::Exactly(1.try_into().unwrap())));
+        let destination = spawner.affinities().next().unwrap();
+
+        let task = spawner.spawn(destination, closure_once((), |()| panic!("work failed")));
+        let payload = task.join().unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"work failed"));
+
+        let task = spawner.spawn(destination, closure_once(1, |value: i32| value + 1));
+        assert_eq!(task.join().expect("the work does not panic"), 2);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn drop_waits_for_detached_work() {
+        let completed = Arc::new(Mutex::new(0));
+        let spawner = PinnedSpawner::default();
+
+        for destination in spawner.affinities() {
+            drop(spawner.spawn(
+                destination,
+                closure_once(crate::Unaware(Arc::clone(&completed)), |completed| {
+                    *completed.0.lock().unwrap() += 1;
+                }),
+            ));
+        }
+
+        let count = spawner.affinities().count();
+        drop(spawner);
+        assert_eq!(*completed.lock().unwrap(), count);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[should_panic(expected = "the destination must be one of the affinities of this spawner")]
+    fn spawn_to_foreign_affinity_panics() {
+        let spawner = PinnedSpawner::new(ThreadRegistry::new(&ProcessorCount::Exactly(1.try_into().unwrap())));
+        let foreign = crate::affinity::pinned_affinities(&[2])[1];
+
+        let _task = spawner.spawn(foreign, closure_once((), |()| ()));
+    }
+}
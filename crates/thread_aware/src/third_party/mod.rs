@@ -5,8 +5,9 @@
 //!
 //! Each submodule is gated behind a Cargo feature named after the wrapped
 //! crate (and its major / 0.x minor where applicable): `bytes`, `http`,
-//! `jiff02`, `uuid`. Enabling a feature pulls in that crate as a dependency
-//! and exposes `ThreadAware` impls for inert, self-contained types from it.
+//! `jiff02`, `smallvec`, `uuid`. Enabling a feature pulls in that crate as a
+//! dependency and exposes `ThreadAware` impls for inert, self-contained types
+//! (or, for containers, forwarding impls) from it.
 //! By default no such features are enabled, so this crate does not pull in
 //! any of these wrapped crates as additional dependencies.
 //!
@@ -53,5 +54,8 @@ mod http;
 #[cfg(any(test, feature = "jiff02"))]
 mod jiff02;
 
+#[cfg(any(test, feature = "smallvec"))]
+mod smallvec;
+
 #[cfg(any(test, feature = "uuid"))]
 mod uuid;
@@ -0,0 +1,58 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! `ThreadAware` impls for [`smallvec`](::smallvec) (1.x) types.
+//!
+//! Enable with the `smallvec` Cargo feature.
+//!
+//! Like `Vec`, a `SmallVec` forwards relocation to each of its elements.
+
+use ::smallvec::{Array, SmallVec};
+
+use crate::ThreadAware;
+use crate::affinity::Affinity;
+
+impl<A> ThreadAware for SmallVec<A>
+where
+    A: Array,
+    A::Item: ThreadAware,
+{
+    fn relocate(&mut self, source: Option<Affinity>, destination: Affinity) {
+        for value in self.iter_mut() {
+            value.relocate(source, destination);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::smallvec::{SmallVec, smallvec};
+    use static_assertions::assert_impl_all;
+
+    use crate::ThreadAware;
+    use crate::affinity::{Affinity, pinned_affinities};
+
+    assert_impl_all!(SmallVec<[u32; 4]>: ThreadAware, Send, Sync);
+
+    #[derive(Debug, PartialEq)]
+    struct Tracker(bool);
+
+    impl ThreadAware for Tracker {
+        fn relocate(&mut self, _source: Option<Affinity>, _destination: Affinity) {
+            self.0 = true;
+        }
+    }
+
+    #[test]
+    fn smallvec_forwards_relocate_to_elements() {
+        let affinities = pinned_affinities(&[2]);
+
+        // Both inline and spilled storage
+        let mut inline: SmallVec<[Tracker; 2]> = smallvec![Tracker(false)];
+        let mut spilled: SmallVec<[Tracker; 2]> = smallvec![Tracker(false), Tracker(false), Tracker(false)];
+        inline.relocate(Some(affinities[0]), affinities[1]);
+        spilled.relocate(Some(affinities[0]), affinities[1]);
+
+        assert!(inline.iter().chain(&spilled).all(|t| t.0), "all elements must be relocated");
+    }
+}
@@ -0,0 +1,303 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Diagnostic snapshots of how a [`ThreadRegistry`](crate::registry::ThreadRegistry) maps onto
+//! the hardware.
+//!
+//! A [`Topology`] captured with
+//! [`ThreadRegistry::topology`](crate::registry::ThreadRegistry::topology) lists the memory
+//! regions of the registry, their processors, and the threads pinned to each processor. Its
+//! [`Display`] implementation renders it as a tree for startup logs:
+//!
+//! ```text
+//! 4 processors in 2 memory regions
+//! memory region 0 (OS memory region 0)
+//!   processor 0 (OS processor 0): main
+//!   processor 1 (OS processor 1)
+//! memory region 1 (OS memory region 1)
+//!   processor 2 (OS processor 2): worker-0, worker-1
+//!   processor 3 (OS processor 3)
+//! ```
+//!
+//! With the `serde` feature enabled, the snapshot can also be serialized, for example to include
+//! it in support bundles.
+
+use std::fmt::{self, Display, Formatter};
+use std::thread::ThreadId;
+
+/// A snapshot of the memory regions, processors, and pinned threads of a registry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Topology {
+    pub(crate) memory_regions: Vec<MemoryRegion>,
+}
+
+impl Topology {
+    /// Returns the memory regions, ordered by their index.
+    #[must_use]
+    pub fn memory_regions(&self) -> &[MemoryRegion] {
+        &self.memory_regions
+    }
+
+    /// Returns the number of processors in all memory regions.
+    #[must_use]
+    pub fn processor_count(&self) -> usize {
+        self.memory_regions.iter().map(|region| region.processors.len()).sum()
+    }
+}
+
+impl Display for Topology {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} processors in {} memory regions",
+            self.processor_count(),
+            self.memory_regions.len()
+        )?;
+
+        for region in &self.memory_regions {
+            write!(f, "\nmemory region {} (OS memory region {})", region.index, region.os_id)?;
+
+            for processor in &region.processors {
+                write!(f, "\n  processor {} (OS processor {})", processor.index, processor.os_id)?;
+
+                for (position, thread) in processor.pinned_threads.iter().enumerate() {
+                    f.write_str(if position == 0 { ": " } else { ", " })?;
+                    write!(f, "{thread}")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A memory region of a [`Topology`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryRegion {
+    pub(crate) index: usize,
+    pub(crate) os_id: u32,
+    pub(crate) processors: Vec<ProcessorInfo>,
+}
+
+impl MemoryRegion {
+    /// Returns the index of the memory region, as used by
+    /// [`Affinity::memory_region_index`](crate::affinity::Affinity::memory_region_index).
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the identifier of the memory region assigned by the operating system.
+    #[must_use]
+    pub const fn os_id(&self) -> u32 {
+        self.os_id
+    }
+
+    /// Returns the processors of the memory region, ordered by their index.
+    #[must_use]
+    pub fn processors(&self) -> &[ProcessorInfo] {
+        &self.processors
+    }
+}
+
+/// A processor of a [`Topology`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProcessorInfo {
+    pub(crate) index: usize,
+    pub(crate) os_id: u32,
+    pub(crate) pinned_threads: Vec<ThreadInfo>,
+}
+
+impl ProcessorInfo {
+    /// Returns the index of the processor, as used by
+    /// [`Affinity::processor_index`](crate::affinity::Affinity::processor_index).
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the identifier of the processor assigned by the operating system.
+    #[must_use]
+    pub const fn os_id(&self) -> u32 {
+        self.os_id
+    }
+
+    /// Returns the threads pinned to the processor, ordered by their name.
+    #[must_use]
+    pub fn pinned_threads(&self) -> &[ThreadInfo] {
+        &self.pinned_threads
+    }
+}
+
+/// A thread pinned to a processor of a [`Topology`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThreadInfo {
+    pub(crate) id: ThreadId,
+    pub(crate) name: Option<String>,
+}
+
+impl ThreadInfo {
+    /// Returns the identifier of the thread.
+    #[must_use]
+    pub const fn id(&self) -> ThreadId {
+        self.id
+    }
+
+    /// Returns the name of the thread, if it has one.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+impl Display for ThreadInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{:?}", self.id),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serialization {
+    use serde_core::Serialize;
+    use serde_core::ser::{SerializeStruct, Serializer};
+
+    use super::{MemoryRegion, ProcessorInfo, ThreadInfo, Topology};
+
+    impl Serialize for Topology {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("Topology", 2)?;
+            state.serialize_field("processor_count", &self.processor_count())?;
+            state.serialize_field("memory_regions", &self.memory_regions)?;
+            state.end()
+        }
+    }
+
+    impl Serialize for MemoryRegion {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("MemoryRegion", 3)?;
+            state.serialize_field("index", &self.index)?;
+            state.serialize_field("os_id", &self.os_id)?;
+            state.serialize_field("processors", &self.processors)?;
+            state.end()
+        }
+    }
+
+    impl Serialize for ProcessorInfo {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("ProcessorInfo", 3)?;
+            state.serialize_field("index", &self.index)?;
+            state.serialize_field("os_id", &self.os_id)?;
+            state.serialize_field("pinned_threads", &self.pinned_threads)?;
+            state.end()
+        }
+    }
+
+    impl Serialize for ThreadInfo {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("ThreadInfo", 2)?;
+            // Thread identifiers have no stable numeric representation, only a debug one
+            state.serialize_field("id", &format!("{:?}", self.id))?;
+            state.serialize_field("name", &self.name)?;
+            state.end()
+        }
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn topology() -> Topology {
+        let id = std::thread::current().id();
+        let thread = |name: Option<&str>| ThreadInfo {
+            id,
+            name: name.map(str::to_owned),
+        };
+
+        Topology {
+            memory_regions: vec![
+                MemoryRegion {
+                    index: 0,
+                    os_id: 0,
+                    processors: vec![ProcessorInfo {
+                        index: 0,
+                        os_id: 4,
+                        pinned_threads: vec![thread(Some("main"))],
+                    }],
+                },
+                MemoryRegion {
+                    index: 1,
+                    os_id: 2,
+                    processors: vec![
+                        ProcessorInfo {
+                            index: 1,
+                            os_id: 8,
+                            pinned_threads: vec![thread(Some("worker-0")), thread(None)],
+                        },
+                        ProcessorInfo {
+                            index: 2,
+                            os_id: 9,
+                            pinned_threads: Vec::new(),
+                        },
+                    ],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn display_renders_tree() {
+        let id = std::thread::current().id();
+
+        assert_eq!(
+            topology().to_string(),
+            format!(
+                "3 processors in 2 memory regions\n\
+                 memory region 0 (OS memory region 0)\n  \
+                 processor 0 (OS processor 4): main\n\
+                 memory region 1 (OS memory region 2)\n  \
+                 processor 1 (OS processor 8): worker-0, {id:?}\n  \
+                 processor 2 (OS processor 9)"
+            )
+        );
+    }
+
+    #[test]
+    fn accessors() {
+        let topology = topology();
+        assert_eq!(topology.processor_count(), 3);
+
+        let region = &topology.memory_regions()[1];
+        assert_eq!((region.index(), region.os_id()), (1, 2));
+
+        let processor = &region.processors()[0];
+        assert_eq!((processor.index(), processor.os_id()), (1, 8));
+        assert_eq!(processor.pinned_threads()[0].name(), Some("worker-0"));
+        assert_eq!(processor.pinned_threads()[1].id(), std::thread::current().id());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize() {
+        let id = format!("{:?}", std::thread::current().id());
+        let serialized = serde_json::to_value(topology()).unwrap();
+
+        assert_eq!(serialized["processor_count"], 3);
+        assert_eq!(serialized["memory_regions"][1]["os_id"], 2);
+        assert_eq!(
+            serialized["memory_regions"][1]["processors"][0],
+            serde_json::json!({
+                "index": 1,
+                "os_id": 8,
+                "pinned_threads": [
+                    { "id": id, "name": "worker-0" },
+                    { "id": id, "name": null },
+                ],
+            })
+        );
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Debug checks that thread-aware values are relocated before they are accessed.
+//!
+//! Forgetting to [`relocate`](ThreadAware::relocate) a value that moves to another thread
+//! doesn't fail: the value keeps working, it just keeps using the state of its old
+//! affinity, which only shows up as contention or remote memory accesses. Wrapping a value in
+//! a [`Validated`] turns these bugs into panics at the access site in debug builds.
+//!
+//! The affinity of the accessing thread is the one it was pinned to with
+//! [`ThreadRegistry::pin_to`](crate::registry::ThreadRegistry::pin_to). Accesses from threads
+//! that are not pinned are not checked. In release builds, [`Validated`] performs no checks
+//! and stores nothing besides the wrapped value.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use thread_aware::ThreadAware;
+//! use thread_aware::registry::ThreadRegistry;
+//! use thread_aware::validator::Validated;
+//!
+//! let registry = ThreadRegistry::default();
+//! let affinity = registry.affinities().next().unwrap();
+//!
+//! let mut counter = Validated::new(0_u64);
+//!
+//! std::thread::scope(|scope| {
+//!     scope.spawn(|| {
+//!         registry.pin_to(affinity);
+//!
+//!         // Accessing the counter before relocating it would panic in debug builds
+//!         counter.relocate(None, affinity);
+//!         *counter += 1;
+//!     });
+//! });
+//! ```
+
+use std::cell::Cell;
+use std::ops::{Deref, DerefMut};
+
+use crate::ThreadAware;
+use crate::affinity::Affinity;
+
+thread_local! {
+    static PINNED: Cell<Option<Affinity>> = const { Cell::new(None) };
+}
+
+/// Records the affinity the current thread is pinned to, if any.
+pub(crate) fn set_pinned(affinity: Option<Affinity>) {
+    PINNED.set(affinity);
+}
+
+/// A value that checks in debug builds that it is only accessed from its affinity.
+///
+/// The affinity of the value is the affinity of the thread that created it, and the
+/// destination of its latest [relocation](ThreadAware::relocate). Every access through
+/// [`Deref`], [`DerefMut`], or [`into_inner`](Self::into_inner) panics in debug builds if the
+/// value has an affinity and the accessing thread is pinned to a different one.
+///
+/// See the [module documentation](self) for details.
+#[derive(Clone, Debug, Default)]
+pub struct Validated<T> {
+    value: T,
+    #[cfg(debug_assertions)]
+    affinity: Option<Affinity>,
+}
+
+impl<T> Validated<T> {
+    /// Wraps a value that belongs to the affinity of the current thread.
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            #[cfg(debug_assertions)]
+            affinity: PINNED.get(),
+        }
+    }
+
+    /// Consumes the wrapper and returns the inner value.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if the current thread is pinned to a different affinity than the value.
+    #[track_caller]
+    pub fn into_inner(self) -> T {
+        self.validate();
+        self.value
+    }
+
+    #[track_caller]
+    #[cfg_attr(not(debug_assertions), expect(clippy::unused_self, reason = "checks only exist in debug builds"))]
+    fn validate(&self) {
+        #[cfg(debug_assertions)]
+        if let (Some(affinity), Some(current)) = (self.affinity, PINNED.get()) {
+            assert!(
+                affinity == current,
+                "a value of affinity {affinity:?} was accessed from a thread pinned to {current:?}, relocate values moved to another affinity before accessing them"
+            );
+        }
+    }
+}
+
+impl<T> From<T> for Validated<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Deref for Validated<T> {
+    type Target = T;
+
+    #[track_caller]
+    fn deref(&self) -> &Self::Target {
+        self.validate();
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Validated<T> {
+    #[track_caller]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.validate();
+        &mut self.value
+    }
+}
+
+impl<T: ThreadAware> ThreadAware for Validated<T> {
+    fn relocate(&mut self, source: Option<Affinity>, destination: Affinity) {
+        self.value.relocate(source, destination);
+
+        #[cfg(debug_assertions)]
+        {
+            self.affinity = Some(destination);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::affinity::pinned_affinities;
+
+    #[test]
+    fn unpinned_access_is_not_checked() {
+        let affinities = pinned_affinities(&[2]);
+        let mut value = Validated::new(1);
+
+        value.relocate(None, affinities[0]);
+        *value += 1;
+        assert_eq!(value.into_inner(), 2);
+    }
+
+    #[test]
+    fn access_from_own_affinity() {
+        let affinities = pinned_affinities(&[2]);
+
+        thread::spawn(move || {
+            set_pinned(Some(affinities[1]));
+
+            let mut created = Validated::new(1);
+            *created += 1;
+            assert_eq!(*created, 2);
+
+            let mut relocated = Validated::from(vec![1]);
+            relocated.relocate(Some(affinities[0]), affinities[1]);
+            relocated.push(2);
+            assert_eq!(relocated.into_inner(), vec![1, 2]);
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn access_from_other_affinity_panics() {
+        let affinities = pinned_affinities(&[2]);
+        let mut value = Validated::new(1);
+        value.relocate(None, affinities[0]);
+
+        let result = thread::spawn(move || {
+            set_pinned(Some(affinities[1]));
+            *value
+        })
+        .join();
+
+        let payload = result.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.contains("relocate values moved to another affinity"), "{message}");
+    }
+}
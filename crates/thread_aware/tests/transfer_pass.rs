@@ -5,7 +5,7 @@
 #![allow(dead_code, unused_variables, unused_assignments, reason = "compile-only derive test")]
 
 use thread_aware::ThreadAware;
-use thread_aware::affinity::pinned_affinities;
+use thread_aware::affinity::{Affinity, pinned_affinities};
 
 #[derive(ThreadAware)]
 struct Simple {
@@ -27,7 +27,27 @@ enum E {
     },
 }
 
-// adapter test removed (no 'with' support currently)
+/// A resource without a `ThreadAware` impl that needs active migration.
+struct Registration {
+    node: Option<usize>,
+}
+
+fn reregister(registration: &mut Registration, _source: Option<Affinity>, destination: Affinity) {
+    registration.node = Some(destination.memory_region_index());
+}
+
+#[derive(ThreadAware)]
+struct WithAdapter {
+    #[thread_aware(with = "reregister")]
+    registration: Registration,
+    data: Vec<u8>,
+}
+
+#[derive(ThreadAware)]
+enum WithAdapterEnum {
+    Registered(#[thread_aware(with = "reregister")] Registration),
+    Other,
+}
 
 #[test]
 fn derive_compiles_and_runs() {
@@ -49,5 +69,14 @@ fn derive_compiles_and_runs() {
     let mut e = E::C { x: 1, y: 2 };
     thread_aware::ThreadAware::relocate(&mut e, d0, d1);
 
-    // removed adapter test; basic derive coverage above is sufficient
+    let mut w = WithAdapter {
+        registration: Registration { node: None },
+        data: vec![1],
+    };
+    thread_aware::ThreadAware::relocate(&mut w, d0, d1);
+    assert_eq!(w.registration.node, Some(0));
+
+    let mut e = WithAdapterEnum::Registered(Registration { node: None });
+    thread_aware::ThreadAware::relocate(&mut e, d0, d1);
+    assert!(matches!(e, WithAdapterEnum::Registered(Registration { node: Some(0) })));
 }
@@ -1,13 +1,17 @@
 // Copyright (c) Microsoft Corporation.
 // Licensed under the MIT License.
 
-use syn::{Attribute, Expr, Type};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Expr, Lit, Path, Type};
 
 /// Configuration for field attributes.
 #[derive(Default, Debug)]
 pub struct FieldAttrCfg {
     /// Whether to skip this field in thread-aware processing.
     pub skip: bool,
+    /// A custom function relocating this field instead of its `ThreadAware` implementation.
+    pub with: Option<Path>,
 }
 
 /// Parses the `thread_aware` attributes on a field.
@@ -30,18 +34,48 @@ pub fn parse_field_attrs(attrs: &[Attribute]) -> syn::Result<FieldAttrCfg> {
                     }
                     cfg.skip = true;
                 }
+                Expr::Assign(assign) if matches!(&*assign.left, Expr::Path(p) if p.path.is_ident("with")) => {
+                    if cfg.with.is_some() {
+                        return Err(syn::Error::new_spanned(assign, "duplicate 'with'"));
+                    }
+                    let Expr::Lit(syn::ExprLit { lit: Lit::Str(lit), .. }) = &*assign.right else {
+                        return Err(syn::Error::new_spanned(
+                            &assign.right,
+                            "'with' expects a string literal naming a function, e.g. with = \"path::to::relocate\"",
+                        ));
+                    };
+                    cfg.with = Some(lit.parse()?);
+                }
                 other => {
                     return Err(syn::Error::new_spanned(
                         other,
-                        "unknown thread_aware attribute (only 'skip' is supported)",
+                        "unknown thread_aware attribute (only 'skip' and 'with' are supported)",
                     ));
                 }
             }
         }
     }
+    if cfg.skip
+        && let Some(with) = &cfg.with
+    {
+        return Err(syn::Error::new_spanned(with, "'skip' and 'with' cannot be combined"));
+    }
     Ok(cfg)
 }
 
+/// Builds the statement relocating the field at `place`, a `&mut` expression, if the field is relocated at all.
+pub(crate) fn relocate_field(cfg: &FieldAttrCfg, ty: &Type, place: &TokenStream, root_path: &Path) -> Option<TokenStream> {
+    if let Some(with) = &cfg.with {
+        return Some(quote! { #with(#place, source, destination); });
+    }
+    if is_phantom_data(ty) || cfg.skip {
+        return None;
+    }
+    let mut path = root_path.clone();
+    path.segments.push(syn::parse_quote!(ThreadAware));
+    Some(quote! { #path::relocate(#place, source, destination); })
+}
+
 /// Checks if the given type is `PhantomData`.
 #[must_use]
 pub fn is_phantom_data(ty: &Type) -> bool {
@@ -130,6 +164,38 @@ mod tests {
         assert!(result.skip);
     }
 
+    #[test]
+    fn test_parse_field_attrs_with() {
+        let attrs: Vec<Attribute> = vec![parse_quote! { #[thread_aware(with = "driver::relocate_buffer")] }];
+        let result = parse_field_attrs(&attrs).unwrap();
+        assert!(!result.skip);
+        let expected: Path = parse_quote!(driver::relocate_buffer);
+        assert_eq!(result.with, Some(expected));
+    }
+
+    #[test]
+    fn test_parse_field_attrs_with_invalid() {
+        let cases: Vec<(Attribute, &str)> = vec![
+            (parse_quote! { #[thread_aware(with = relocate)] }, "expects a string literal"),
+            (parse_quote! { #[thread_aware(with = "not a path")] }, "unexpected token"),
+        ];
+        for (attr, expected) in cases {
+            let error = parse_field_attrs(&[attr]).unwrap_err().to_string();
+            assert!(error.contains(expected), "{error}");
+        }
+
+        let attrs: Vec<Attribute> = vec![
+            parse_quote! { #[thread_aware(with = "a")] },
+            parse_quote! { #[thread_aware(with = "b")] },
+        ];
+        let error = parse_field_attrs(&attrs).unwrap_err().to_string();
+        assert!(error.contains("duplicate 'with'"), "{error}");
+
+        let attrs: Vec<Attribute> = vec![parse_quote! { #[thread_aware(skip)] }, parse_quote! { #[thread_aware(with = "a")] }];
+        let error = parse_field_attrs(&attrs).unwrap_err().to_string();
+        assert!(error.contains("'skip' and 'with' cannot be combined"), "{error}");
+    }
+
     #[test]
     fn test_is_phantom_data_simple() {
         // Test with simple PhantomData type
@@ -204,6 +270,7 @@ mod tests {
         // Test that FieldAttrCfg::default() works correctly
         let cfg = FieldAttrCfg::default();
         assert!(!cfg.skip);
+        assert!(cfg.with.is_none());
     }
 
     #[test]
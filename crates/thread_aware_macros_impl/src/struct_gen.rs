@@ -4,7 +4,7 @@
 use quote::quote;
 use syn::Fields;
 
-use crate::field_attrs::{FieldAttrCfg, is_phantom_data, parse_field_attrs};
+use crate::field_attrs::{FieldAttrCfg, parse_field_attrs, relocate_field};
 
 pub(crate) fn build_struct_body(_name: &syn::Ident, fields: &Fields, root_path: &syn::Path) -> syn::Result<proc_macro2::TokenStream> {
     Ok(match fields {
@@ -13,11 +13,7 @@ pub(crate) fn build_struct_body(_name: &syn::Ident, fields: &Fields, root_path:
             for f in &named.named {
                 let ident = f.ident.as_ref().expect("Field identifier is missing");
                 let attr_cfg: FieldAttrCfg = parse_field_attrs(&f.attrs)?;
-                if !is_phantom_data(&f.ty) && !attr_cfg.skip {
-                    let mut path = root_path.clone();
-                    path.segments.push(syn::parse_quote!(ThreadAware));
-                    stmts.push(quote! { #path::relocate(&mut self.#ident, source, destination); });
-                }
+                stmts.extend(relocate_field(&attr_cfg, &f.ty, &quote! { &mut self.#ident }, root_path));
             }
             quote! { #( #stmts )* }
         }
@@ -25,12 +21,8 @@ pub(crate) fn build_struct_body(_name: &syn::Ident, fields: &Fields, root_path:
             let mut stmts = Vec::new();
             for (i, f) in unnamed.unnamed.iter().enumerate() {
                 let attr_cfg: FieldAttrCfg = parse_field_attrs(&f.attrs)?;
-                if !is_phantom_data(&f.ty) && !attr_cfg.skip {
-                    let index = syn::Index::from(i);
-                    let mut path = root_path.clone();
-                    path.segments.push(syn::parse_quote!(ThreadAware));
-                    stmts.push(quote! { #path::relocate(&mut self.#index, source, destination); });
-                }
+                let index = syn::Index::from(i);
+                stmts.extend(relocate_field(&attr_cfg, &f.ty, &quote! { &mut self.#index }, root_path));
             }
             quote! { #( #stmts )* }
         }
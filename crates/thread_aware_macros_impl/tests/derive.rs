@@ -115,6 +115,32 @@ fn generics_array_usage_adds_bound() {
     assert_snapshot!(expand(input));
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn enum_attr_with() {
+    let input = quote! {
+        #[derive(ThreadAware)]
+        enum E {
+            A(#[thread_aware(with = "driver::reregister")] Buffer, u32),
+            B { #[thread_aware(with = "reregister")] buffer: Buffer },
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn struct_attr_with() {
+    let input = quote! {
+        #[derive(ThreadAware)]
+        struct Foo {
+            #[thread_aware(with = "driver::reregister")] buffer: Buffer,
+            data: Vec<u8>,
+        }
+    };
+    assert_snapshot!(expand(input));
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn error_unknown_attr() {
@@ -86,7 +86,7 @@ impl std::fmt::Debug for ClockControl {
             Err(_) => debug.field("UNIX offset", &"negative"),
         };
 
-        debug.field("timers", &self.timers_len()).finish_non_exhaustive()
+        debug.field("timers", &self.pending_timers()).finish_non_exhaustive()
     }
 }
 
@@ -373,6 +373,38 @@ impl ClockControl {
         }
     }
 
+    /// Returns the number of timers registered with this clock that have not fired yet.
+    ///
+    /// A timer is registered by a pending delay, timeout, or periodic timer created from a
+    /// clock of this `ClockControl`, and unregistered when it fires or is dropped. Tests can
+    /// check this to make sure no time-dependent work is left behind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::pin::pin;
+    /// use std::task::{Context, Waker};
+    /// use std::time::Duration;
+    ///
+    /// use tick::ClockControl;
+    ///
+    /// let control = ClockControl::new();
+    /// let clock = control.to_clock();
+    ///
+    /// // Polling the delay registers its timer.
+    /// let mut delay = pin!(clock.delay(Duration::from_secs(1)));
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert!(delay.as_mut().poll(&mut cx).is_pending());
+    /// assert_eq!(control.pending_timers(), 1);
+    ///
+    /// control.advance(Duration::from_secs(1));
+    /// assert_eq!(control.pending_timers(), 0);
+    /// ```
+    #[must_use]
+    pub fn pending_timers(&self) -> usize {
+        self.with_state(|s| s.timers.len())
+    }
+
     pub(super) fn system_time(&self) -> SystemTime {
         self.with_state(State::now)
     }
@@ -395,10 +427,6 @@ impl ClockControl {
         self.with_state(|s| s.timers.next_timer())
     }
 
-    pub(super) fn timers_len(&self) -> usize {
-        self.with_state(|s| s.timers.len())
-    }
-
     fn with_state<F, R>(&self, f: F) -> R
     where
         F: FnOnce(&mut State) -> R,
@@ -659,9 +687,9 @@ mod tests {
         let key = control.register_timer(Instant::now(), Waker::noop().clone());
 
         // assert
-        assert_eq!(control.timers_len(), 1);
+        assert_eq!(control.pending_timers(), 1);
         control.unregister_timer(key);
-        assert_eq!(control.timers_len(), 0);
+        assert_eq!(control.pending_timers(), 0);
     }
 
     #[test]
@@ -684,7 +712,7 @@ mod tests {
         control.unregister_timer(key);
 
         // assert
-        assert_eq!(control.timers_len(), 0);
+        assert_eq!(control.pending_timers(), 0);
     }
 
     #[test]
@@ -710,7 +738,7 @@ mod tests {
         control.advance(Duration::from_secs(1));
 
         // assert
-        assert_eq!(control.timers_len(), 0);
+        assert_eq!(control.pending_timers(), 0);
     }
 
     #[test]
@@ -772,7 +800,7 @@ mod tests {
         assert_eq!(clock.instant().saturating_duration_since(start_instant), Duration::from_secs(100));
 
         // All timers should have been triggered and removed
-        assert_eq!(control.timers_len(), 0);
+        assert_eq!(control.pending_timers(), 0);
     }
 
     #[test]
@@ -794,7 +822,7 @@ mod tests {
         // Time should have advanced to process all timers
         // The actual time advanced depends on when timers were registered
         // but all timers should have been processed
-        assert_eq!(control.timers_len(), 0);
+        assert_eq!(control.pending_timers(), 0);
 
         // Time should have advanced at least to the last timer
         assert!(clock.instant().saturating_duration_since(start_instant) >= Duration::from_millis(1));
@@ -832,7 +860,7 @@ mod tests {
         assert_eq!(current_instant.saturating_duration_since(start_instant), Duration::from_secs(1));
 
         // The timer should still be registered since we couldn't advance further to reach it
-        assert_eq!(control.timers_len(), 1);
+        assert_eq!(control.pending_timers(), 1);
     }
 
     #[test]
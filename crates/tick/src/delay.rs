@@ -157,9 +157,9 @@ mod tests {
         thread::sleep(Duration::from_millis(1));
         assert_eq!(poll_delay(&mut delay), Poll::Pending);
 
-        let len = control.timers_len();
+        let len = control.pending_timers();
         control.advance(Duration::from_millis(2));
-        assert_eq!(control.timers_len(), len - 1);
+        assert_eq!(control.pending_timers(), len - 1);
         assert_eq!(poll_delay(&mut delay), Poll::Ready(()));
     }
 
@@ -137,7 +137,7 @@ mod tests {
         let next = driver.advance_timers(control.instant() + Duration::from_secs(2)).unwrap();
 
         // Verify timers are not advanced (still registered)
-        assert_eq!(control.timers_len(), 1);
+        assert_eq!(control.pending_timers(), 1);
         // Verify next timer time is returned
         assert_eq!(next, Some(when));
     }
@@ -45,7 +45,7 @@ impl ClockState {
     pub(crate) fn timers_len(&self) -> usize {
         match self {
             #[cfg(any(feature = "test-util", test))]
-            Self::ClockControl(control) => control.timers_len(),
+            Self::ClockControl(control) => control.pending_timers(),
             Self::System(timers) => timers.with_timers(|t| t.len()),
         }
     }
@@ -0,0 +1,112 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::borrow::Borrow;
+use std::hash::Hash;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use thread_aware::storage::Strategy;
+
+use crate::{ExecuteError, Merger};
+
+impl<K, T, S> Merger<K, T, S>
+where
+    K: Hash + Eq + Send + Sync,
+    T: Send + Sync,
+    S: Strategy + Send + Sync,
+{
+    /// Execute and return the value for a given blocking function, making sure that only
+    /// one operation is in-flight at a given moment.
+    ///
+    /// This is the blocking counterpart of [`execute`][Self::execute] for code paths that
+    /// don't run on an executor, such as loading configuration during startup. The leader
+    /// runs `func` on the calling thread, while followers park their threads until the
+    /// result is available. Blocking and async callers for the same key share a single
+    /// execution.
+    ///
+    /// Don't call this method from async code: it blocks the calling thread, which stalls
+    /// the executor. Timers of [`MergerBuilder::leader_timeout`][crate::MergerBuilder::leader_timeout]
+    /// only fire when the clock is driven by a runtime; without one, blocking followers
+    /// wait for the leader for as long as it takes.
+    ///
+    /// # Errors
+    ///
+    /// Fails the same way as [`execute`][Self::execute].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use uniflight::Merger;
+    ///
+    /// let merger: Merger<String, String> = Merger::new();
+    /// let config = merger.execute_blocking("config", || "setting = 1".to_string());
+    /// assert_eq!(config, Ok("setting = 1".to_string()));
+    /// ```
+    pub fn execute_blocking<Q, F>(&self, key: &Q, func: F) -> Result<T, ExecuteError>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+        F: FnOnce() -> T + Send,
+        T: Clone,
+    {
+        block_on(self.execute(key, || async move { func() }))
+    }
+}
+
+/// Wakes a thread that is parked in [`block_on`].
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Drives the future to completion on the current thread, parking it while the future is pending.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        // Spurious wake-ups only cause another poll
+        thread::park();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_on_waits_for_wake() {
+        let (sender, receiver) = std::sync::mpsc::channel::<Waker>();
+        let mut polled = false;
+
+        let handle = thread::spawn(move || {
+            let waker = receiver.recv().expect("waker should be sent");
+            waker.wake();
+        });
+
+        let output = block_on(std::future::poll_fn(|cx| {
+            if polled {
+                return Poll::Ready(42);
+            }
+            polled = true;
+            sender.send(cx.waker().clone()).expect("receiver should be alive");
+            Poll::Pending
+        }));
+
+        assert_eq!(output, 42);
+        handle.join().expect("waking thread should not panic");
+    }
+}
@@ -0,0 +1,269 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
+use thread_aware::storage::Strategy;
+use tick::Clock;
+
+use crate::{Merger, MergerMetrics, Options};
+
+/// Builder for a [`Merger`] with optional behavior.
+///
+/// Created by [`Merger::builder`].
+#[must_use]
+pub struct MergerBuilder<K, T, S: Strategy> {
+    options: Options,
+    _phantom: PhantomData<(K, T, S)>,
+}
+
+impl<K, T, S: Strategy> Debug for MergerBuilder<K, T, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MergerBuilder").field("options", &self.options).finish()
+    }
+}
+
+impl<K, T, S> MergerBuilder<K, T, S>
+where
+    K: Hash + Eq + Send + Sync + 'static,
+    T: Send + Sync + 'static,
+    S: Strategy,
+{
+    pub(crate) fn new(clock: &Clock) -> Self {
+        Self {
+            options: Options {
+                clock: Some(clock.clone()),
+                ..Options::default()
+            },
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Keeps a completed result for the given duration after the leader finishes.
+    ///
+    /// By default, a result is only shared with callers that join while the leader is
+    /// running. With a TTL, calls that arrive shortly after the leader completed receive
+    /// the same result instead of starting new work, which turns the `Merger` into a
+    /// short-lived cache that protects the underlying operation from a thundering herd.
+    ///
+    /// The TTL is measured from the moment the leader completes, using the clock passed to
+    /// [`Merger::builder`]. Only successful results are retained: a result of a panicking
+    /// leader, or a failure returned to [`Merger::try_execute`], is never kept.
+    ///
+    /// Expired results are dropped when the key is requested again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use tick::Clock;
+    /// use uniflight::Merger;
+    ///
+    /// # async fn example(clock: &Clock) {
+    /// let merger: Merger<String, i32> = Merger::builder(clock)
+    ///     .result_ttl(Duration::from_secs(1))
+    ///     .build();
+    ///
+    /// assert_eq!(merger.execute("key", || async { 1 }).await, Ok(1));
+    ///
+    /// // Within the TTL, the completed result is reused
+    /// assert_eq!(merger.execute("key", || async { 2 }).await, Ok(1));
+    /// # }
+    /// ```
+    pub fn result_ttl(mut self, ttl: Duration) -> Self {
+        self.options.result_ttl = Some(ttl);
+        self
+    }
+
+    /// Limits how long followers wait for the leader to produce a result.
+    ///
+    /// When the leader has not completed within the timeout, waiting followers stop waiting
+    /// for it and one of them is promoted to start its own attempt, which the remaining
+    /// followers and new callers join. The original leader is not cancelled: it keeps
+    /// running and its result is still returned to its own caller. This protects followers
+    /// from a stuck leader without aborting work that may still complete.
+    ///
+    /// The timeout is measured from the moment the execution is created, using the clock
+    /// passed to [`Merger::builder`]. A caller that executes its own function is never
+    /// interrupted by the timeout; combine the `Merger` with a regular timeout to bound it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use tick::Clock;
+    /// use uniflight::Merger;
+    ///
+    /// # fn example(clock: &Clock) {
+    /// let merger: Merger<String, String> = Merger::builder(clock)
+    ///     .leader_timeout(Duration::from_secs(5))
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn leader_timeout(mut self, timeout: Duration) -> Self {
+        self.options.leader_timeout = Some(timeout);
+        self
+    }
+
+    /// Caps the number of keys tracked by the `Merger`.
+    ///
+    /// When a new key is about to be tracked and the cap is reached, entries that can no
+    /// longer be joined are removed first, followed by retained results of
+    /// [`result_ttl`][Self::result_ttl] in the order they were completed. In-flight
+    /// executions are never evicted, so the number of tracked keys can exceed the cap when
+    /// all of them are in flight.
+    ///
+    /// Eviction scans all tracked keys, so the cap is meant as a safety limit rather than
+    /// a threshold that is reached on every call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use tick::Clock;
+    /// use uniflight::Merger;
+    ///
+    /// # fn example(clock: &Clock) {
+    /// let merger: Merger<String, String> = Merger::builder(clock)
+    ///     .result_ttl(Duration::from_mins(1))
+    ///     .max_keys(10_000)
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn max_keys(mut self, max_keys: usize) -> Self {
+        self.options.max_keys = Some(max_keys);
+        self
+    }
+
+    /// Limits the number of followers that wait for a single execution.
+    ///
+    /// When an execution already has `max_waiters` followers, the `policy` decides what
+    /// happens to additional callers. Spilling excess callers to independent executions caps
+    /// how many callers are affected by a single slow leader, at the cost of running more
+    /// work. Results kept by [`result_ttl`][Self::result_ttl] are returned to any number of
+    /// callers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tick::Clock;
+    /// use uniflight::{Merger, OverflowPolicy};
+    ///
+    /// # fn example(clock: &Clock) {
+    /// let merger: Merger<String, String> = Merger::builder(clock)
+    ///     .max_waiters(100, OverflowPolicy::ExecuteIndependently)
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn max_waiters(mut self, max_waiters: usize, policy: OverflowPolicy) -> Self {
+        self.options.max_waiters = Some((max_waiters, policy));
+        self
+    }
+
+    /// Records how much work the `Merger` deduplicates into the given metrics.
+    ///
+    /// See [`MergerMetrics`] for the available counters.
+    pub fn metrics(mut self, metrics: &MergerMetrics) -> Self {
+        self.options.metrics = Some(metrics.clone());
+        self
+    }
+
+    /// Calls the hook whenever a caller starts executing its function as the leader.
+    ///
+    /// Hooks are called synchronously on the task of the caller they describe, so they can
+    /// be used to record the current tracing span, for example to link the spans of
+    /// followers to the span of the leader. Hooks should be cheap and must not panic.
+    ///
+    /// Followers that take over an execution are reported to
+    /// [`on_promotion`][Self::on_promotion] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tick::Clock;
+    /// use uniflight::Merger;
+    ///
+    /// # fn example(clock: &Clock) {
+    /// let merger: Merger<String, String> = Merger::builder(clock)
+    ///     .on_leader_start(|| println!("leader started"))
+    ///     .on_follower_joined(|| println!("follower joined"))
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn on_leader_start(mut self, hook: impl Fn() + Send + Sync + 'static) -> Self {
+        self.options.hooks.leader_start = Some(Arc::new(hook));
+        self
+    }
+
+    /// Calls the hook whenever a caller joins an execution started by another caller.
+    ///
+    /// This includes callers that receive a result kept by [`result_ttl`][Self::result_ttl].
+    /// The hook is called when the caller first polls its future.
+    pub fn on_follower_joined(mut self, hook: impl Fn() + Send + Sync + 'static) -> Self {
+        self.options.hooks.follower_joined = Some(Arc::new(hook));
+        self
+    }
+
+    /// Calls the hook whenever a follower starts executing its own function, either because
+    /// the leader was dropped before completing or because it exceeded
+    /// [`leader_timeout`][Self::leader_timeout].
+    pub fn on_promotion(mut self, hook: impl Fn() + Send + Sync + 'static) -> Self {
+        self.options.hooks.promotion = Some(Arc::new(hook));
+        self
+    }
+
+    /// Calls the hook whenever a caller's function returns a value.
+    ///
+    /// The hook receives how long the function ran, measured with the clock passed to
+    /// [`Merger::builder`], and the number of other callers waiting for the result at that
+    /// moment. Executions that panic or are cancelled are not reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tick::Clock;
+    /// use uniflight::Merger;
+    ///
+    /// # fn example(clock: &Clock) {
+    /// let merger: Merger<String, String> = Merger::builder(clock)
+    ///     .on_complete(|duration, waiters| {
+    ///         println!("completed in {duration:?} for {waiters} waiting callers");
+    ///     })
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn on_complete(mut self, hook: impl Fn(Duration, usize) + Send + Sync + 'static) -> Self {
+        self.options.hooks.complete = Some(Arc::new(hook));
+        self
+    }
+
+    /// Creates the [`Merger`].
+    #[must_use]
+    pub fn build(self) -> Merger<K, T, S> {
+        Merger::with_options(self.options)
+    }
+}
+
+/// Decides what happens to callers that exceed [`MergerBuilder::max_waiters`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OverflowPolicy {
+    /// The caller executes its own function without joining the execution.
+    ///
+    /// The independent execution is not shared with other callers and can't be cancelled
+    /// with [`Merger::cancel`].
+    ExecuteIndependently,
+
+    /// The caller fails immediately with [`ExecuteError::TooManyWaiters`][crate::ExecuteError::TooManyWaiters].
+    FailFast,
+
+    /// The caller joins the execution anyway.
+    Wait,
+}
@@ -0,0 +1,88 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::Context;
+
+use futures_util::task::AtomicWaker;
+
+/// Cancels a single execution of a [`Merger`][crate::Merger].
+///
+/// Returned by [`Merger::execute_cancellable`][crate::Merger::execute_cancellable]. Cancelling
+/// stops the leader at its next suspension point and completes every caller waiting on the
+/// execution with [`ExecuteError::Cancelled`][crate::ExecuteError::Cancelled]. The handle can
+/// be cloned and sent to other tasks, for example to a shutdown path.
+#[derive(Debug, Clone)]
+pub struct CancelHandle {
+    signal: Arc<CancelSignal>,
+}
+
+impl CancelHandle {
+    pub(crate) fn new(signal: Arc<CancelSignal>) -> Self {
+        Self { signal }
+    }
+
+    /// Cancels the execution.
+    ///
+    /// Cancelling an execution that already completed does not change the result seen by
+    /// its callers, but the result is no longer shared with new callers.
+    pub fn cancel(&self) {
+        self.signal.cancel();
+    }
+
+    /// Returns `true` if the execution was cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.signal.is_cancelled()
+    }
+}
+
+/// Cancellation state shared by the cell of an execution and its handles.
+#[derive(Debug, Default)]
+pub(crate) struct CancelSignal {
+    cancelled: AtomicBool,
+    waker: AtomicWaker,
+}
+
+impl CancelSignal {
+    pub(crate) fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+        self.waker.wake();
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if cancelled, otherwise registers the task to be woken on cancellation.
+    pub(crate) fn poll_cancelled(&self, cx: &Context<'_>) -> bool {
+        if self.is_cancelled() {
+            return true;
+        }
+        self.waker.register(cx.waker());
+        // Check again to not miss a cancellation that raced with the registration
+        self.is_cancelled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::task::Waker;
+
+    use super::*;
+
+    #[test]
+    fn handle_cancels_signal() {
+        let signal = Arc::new(CancelSignal::default());
+        let handle = CancelHandle::new(Arc::clone(&signal));
+        assert!(!handle.is_cancelled());
+
+        let cx = Context::from_waker(Waker::noop());
+        assert!(!signal.poll_cancelled(&cx));
+
+        handle.cancel();
+        assert!(handle.is_cancelled());
+        assert!(signal.poll_cancelled(&cx));
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::metrics::Role;
+
+type Hook = Arc<dyn Fn() + Send + Sync>;
+type CompleteHook = Arc<dyn Fn(Duration, usize) + Send + Sync>;
+
+/// Callbacks invoked at the points of an execution's lifecycle.
+#[derive(Clone, Default)]
+pub(crate) struct Hooks {
+    pub(crate) leader_start: Option<Hook>,
+    pub(crate) follower_joined: Option<Hook>,
+    pub(crate) promotion: Option<Hook>,
+    pub(crate) complete: Option<CompleteHook>,
+}
+
+impl Hooks {
+    /// Notifies that a caller in the given role started executing its function.
+    pub(crate) fn started(&self, role: Role) {
+        let hook = match role {
+            Role::Leader => self.leader_start.as_ref(),
+            Role::Promoted => self.promotion.as_ref(),
+            Role::Follower => None,
+        };
+        if let Some(hook) = hook {
+            hook();
+        }
+    }
+
+    pub(crate) fn follower_joined(&self) {
+        if let Some(hook) = &self.follower_joined {
+            hook();
+        }
+    }
+
+    pub(crate) fn completed(&self, duration: Duration, waiters: usize) {
+        if let Some(hook) = &self.complete {
+            hook(duration, waiters);
+        }
+    }
+}
+
+impl Debug for Hooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hooks")
+            .field("leader_start", &self.leader_start.is_some())
+            .field("follower_joined", &self.follower_joined.is_some())
+            .field("promotion", &self.promotion.is_some())
+            .field("complete", &self.complete.is_some())
+            .finish()
+    }
+}
@@ -79,14 +79,20 @@
 //! `Merger` handles task cancellation and panics explicitly:
 //!
 //! - If the leader task is cancelled or dropped, a follower becomes the new leader
-//! - If the leader task panics, followers receive [`LeaderPanicked`] error with the panic message
+//! - If the leader task panics, followers receive [`ExecuteError::Panicked`] with the panic message
+//! - If the execution is cancelled through [`Merger::cancel`] or a [`CancelHandle`], the leader
+//!   stops and all callers receive [`ExecuteError::Cancelled`]
 //! - Followers that join before the leader completes receive the value the leader returns
+//! - With [`MergerBuilder::leader_timeout`], followers stop waiting for a stuck leader and
+//!   one of them starts its own attempt while the original leader continues
+//! - With [`MergerBuilder::max_waiters`], callers beyond the limit are handled according to
+//!   the configured [`OverflowPolicy`]
 //!
 //! When a panic occurs, followers are notified via the error type rather than silently
 //! retrying. The panic message is captured and available via [`LeaderPanicked::message`]:
 //!
 //! ```
-//! # use uniflight::Merger;
+//! # use uniflight::{ExecuteError, Merger};
 //! # async fn example() {
 //! let merger: Merger<String, String> = Merger::new();
 //! match merger
@@ -94,18 +100,86 @@
 //!     .await
 //! {
 //!     Ok(value) => println!("got {value}"),
-//!     Err(err) => {
+//!     Err(ExecuteError::Panicked(err)) => {
 //!         println!("leader panicked: {}", err.message());
 //!         // Decide whether to retry
 //!     }
+//!     Err(err) => println!("execution failed: {err}"),
 //! }
 //! # }
 //! ```
 //!
+//! # Fallible Operations
+//!
+//! With [`Merger::execute`], whatever the leader returns is shared with every caller that joins
+//! before the leader's result is released, including errors. For fallible work, use
+//! [`Merger::try_execute`] on a `Merger` whose value type is a [`Result`]. An `Err` returned by the
+//! leader is delivered to the callers already waiting on it, but the next caller becomes a fresh
+//! leader instead of receiving the same failure:
+//!
+//! ```
+//! # use uniflight::Merger;
+//! # async fn example() {
+//! let merger: Merger<String, Result<String, String>> = Merger::new();
+//! let result = merger
+//!     .try_execute("key", || async { Ok("result".to_string()) })
+//!     .await
+//!     .expect("leader should not panic");
+//! assert_eq!(result, Ok("result".to_string()));
+//! # }
+//! ```
+//!
+//! # Blocking Code
+//!
+//! [`Merger::execute_blocking`] provides the same deduplication for code that does not run
+//! on an executor. Followers park their threads until the leader's result is available, and
+//! blocking callers share executions with async callers for the same key.
+//!
+//! # Result Caching
+//!
+//! By default, a result is shared only with callers that join while the leader is running.
+//! Use [`MergerBuilder::result_ttl`] to keep a completed result for a while, so that calls
+//! arriving shortly after the leader finished still coalesce instead of starting new work:
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use tick::Clock;
+//! use uniflight::Merger;
+//!
+//! # fn example(clock: &Clock) {
+//! let merger: Merger<String, String> = Merger::builder(clock)
+//!     .result_ttl(Duration::from_millis(500))
+//!     .build();
+//! # }
+//! ```
+//!
+//! # Observability
+//!
+//! [`Merger::len`], [`Merger::contains`], and [`Merger::waiters`] report what is currently
+//! in flight. To measure how much deduplication happens over time, pass [`MergerMetrics`]
+//! to [`MergerBuilder::metrics`]; it counts leader executions, coalesced followers, and
+//! followers promoted after their leader was dropped. To observe individual executions,
+//! for example to link tracing spans or measure coalescing latency, register hooks such as
+//! [`MergerBuilder::on_leader_start`] and [`MergerBuilder::on_complete`].
+//!
+//! # Multiple Groups
+//!
+//! Applications with many distinct coalescing domains, such as one per downstream API or per
+//! cache, can keep their mergers in a [`MergerRegistry`]. The registry creates each named
+//! group on first use with its own settings and returns the same group on later requests.
+//!
 //! # Memory Management
 //!
 //! Completed entries are automatically removed from the internal map when the last caller
-//! finishes. This ensures no stale entries accumulate over time.
+//! finishes. This ensures no stale entries accumulate over time. When a result TTL is
+//! configured, the entry is kept until the TTL elapses and is removed when its key is
+//! requested again.
+//!
+//! Under high key cardinality, retained results of keys that are not requested again can
+//! accumulate. Call [`Merger::purge`] periodically to remove expired entries, or use
+//! [`MergerBuilder::max_keys`] to cap the number of tracked keys, which evicts the least
+//! recently completed results first.
 //!
 //! # Type Requirements
 //!
@@ -117,6 +191,26 @@
 //! [`Merger`] is `Send` and `Sync`, and can be shared across threads. The returned futures
 //! are `Send` when the closure, future, key, and value types are `Send`.
 //!
+//! The returned futures don't borrow the `Merger` or the key: they hold their own reference
+//! to the shared state and an owned copy of the key. When the closure and its future are
+//! `'static`, so is the returned future, which means it can be spawned and detached, and
+//! it keeps working after the `Merger` it was created from is dropped:
+//!
+//! ```
+//! # use uniflight::Merger;
+//! # async fn example() {
+//! let merger: Merger<String, String> = Merger::new();
+//! let key = "user:123".to_string();
+//!
+//! let result = merger.execute(&key, || async { "value".to_string() });
+//! drop(key);
+//! drop(merger);
+//!
+//! let handle = tokio::spawn(result);
+//! # let _ = handle;
+//! # }
+//! ```
+//!
 //! # Performance
 //!
 //! Run benchmarks with `cargo bench -p uniflight`. The suite covers:
@@ -134,16 +228,35 @@ use std::borrow::Borrow;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Weak};
+use std::task::Poll;
+use std::time::{Duration, Instant};
 
 use ahash::RandomState;
 use async_once_cell::OnceCell;
 use dashmap::DashMap;
 use dashmap::Entry::{Occupied, Vacant};
-use futures_util::FutureExt; // catch_unwind, map
+use futures_util::FutureExt; // catch_unwind
 use thread_aware::affinity::Affinity;
 use thread_aware::storage::Strategy;
 use thread_aware::{Arc as TaArc, PerCore, PerNuma, PerProcess, ThreadAware};
+use tick::Clock;
+
+mod blocking;
+mod builder;
+mod cancel;
+mod hooks;
+mod metrics;
+mod registry;
+
+pub use builder::{MergerBuilder, OverflowPolicy};
+pub use cancel::CancelHandle;
+use cancel::CancelSignal;
+use hooks::Hooks;
+pub use metrics::MergerMetrics;
+use metrics::Role;
+pub use registry::MergerRegistry;
 
 /// Suppresses duplicate async operations identified by a key.
 ///
@@ -151,19 +264,58 @@ use thread_aware::{Arc as TaArc, PerCore, PerNuma, PerProcess, ThreadAware};
 /// - [`PerProcess`]: Single global scope (default, maximum deduplication)
 /// - [`PerNuma`]: Per-NUMA-node scope (NUMA-local memory access)
 /// - [`PerCore`]: Per-core scope (no deduplication)
+///
+/// Use [`Merger::builder`] to configure optional behavior, such as keeping completed
+/// results around for a while.
 pub struct Merger<K, T, S: Strategy = PerProcess> {
-    inner: TaArc<DashMap<K, Weak<PanicAwareCell<T>>, RandomState>, S>,
+    inner: TaArc<Map<K, T>, S>,
+    options: Arc<Options>,
+}
+
+type Map<K, T> = DashMap<K, Slot<T>, RandomState>;
+
+/// Optional behavior of a [`Merger`], configured through [`MergerBuilder`].
+#[derive(Debug, Default)]
+struct Options {
+    clock: Option<Clock>,
+    result_ttl: Option<Duration>,
+    leader_timeout: Option<Duration>,
+    max_keys: Option<usize>,
+    max_waiters: Option<(usize, OverflowPolicy)>,
+    metrics: Option<MergerMetrics>,
+    hooks: Hooks,
+}
+
+impl Options {
+    /// Returns the current time if it is needed to track retained results or leader deadlines.
+    fn now(&self) -> Option<Instant> {
+        if self.result_ttl.is_none() && self.leader_timeout.is_none() {
+            return None;
+        }
+        self.clock.as_ref().map(Clock::instant)
+    }
+
+    /// Returns the time by which a leader starting at `now` has to produce its result.
+    fn leader_deadline(&self, now: Option<Instant>) -> Option<Instant> {
+        now.zip(self.leader_timeout).map(|(now, timeout)| now + timeout)
+    }
 }
 
 impl<K, T, S: Strategy> Debug for Merger<K, T, S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Merger").field("inner", &format_args!("DashMap<...>")).finish()
+        f.debug_struct("Merger")
+            .field("inner", &format_args!("DashMap<...>"))
+            .field("options", &self.options)
+            .finish()
     }
 }
 
 impl<K, T, S: Strategy> Clone for Merger<K, T, S> {
     fn clone(&self) -> Self {
-        Self { inner: self.inner.clone() }
+        Self {
+            inner: self.inner.clone(),
+            options: Arc::clone(&self.options),
+        }
     }
 }
 
@@ -174,9 +326,7 @@ where
     S: Strategy,
 {
     fn default() -> Self {
-        Self {
-            inner: TaArc::new(|| DashMap::with_hasher(RandomState::new())),
-        }
+        Self::with_options(Options::default())
     }
 }
 
@@ -213,6 +363,36 @@ where
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Creates a builder for a `Merger` with optional behavior.
+    ///
+    /// The clock is used to measure time for time-based options, such as
+    /// [`result_ttl`][MergerBuilder::result_ttl].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use tick::Clock;
+    /// use uniflight::Merger;
+    ///
+    /// # fn example(clock: &Clock) {
+    /// let merger: Merger<String, String> = Merger::builder(clock)
+    ///     .result_ttl(Duration::from_secs(1))
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn builder(clock: &Clock) -> MergerBuilder<K, T, S> {
+        MergerBuilder::new(clock)
+    }
+
+    fn with_options(options: Options) -> Self {
+        Self {
+            inner: TaArc::new(|| DashMap::with_hasher(RandomState::new())),
+            options: Arc::new(options),
+        }
+    }
 }
 
 impl<K, T> Merger<K, T, PerProcess>
@@ -294,17 +474,125 @@ impl<K, T, S: Strategy> Merger<K, T, S>
 where
     K: Hash + Eq,
 {
-    /// Returns the number of in-flight operations.
-    #[cfg(test)]
-    fn len(&self) -> usize {
+    /// Returns the number of keys tracked by the `Merger`.
+    ///
+    /// This includes keys with in-flight executions and keys whose results are retained by
+    /// [`MergerBuilder::result_ttl`]. Entries are removed lazily, so the count can include
+    /// keys whose executions just completed. With [`PerNuma`] or [`PerCore`] scoping, only
+    /// the keys of the current scope are counted.
+    #[must_use]
+    pub fn len(&self) -> usize {
         self.inner.len()
     }
 
-    /// Returns `true` if there are no in-flight operations.
-    #[cfg(test)]
-    fn is_empty(&self) -> bool {
+    /// Returns `true` if no keys are tracked by the `Merger`.
+    ///
+    /// See [`len`][Self::len] for what is counted.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
         self.inner.is_empty()
     }
+
+    /// Returns `true` if a call for the key would join an in-flight execution or a
+    /// retained result instead of starting new work.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use uniflight::Merger;
+    /// # async fn example() {
+    /// let merger: Merger<String, i32> = Merger::new();
+    /// let result = merger.execute("my-key", || async { 42 });
+    /// assert!(merger.contains("my-key"));
+    ///
+    /// assert_eq!(result.await, Ok(42));
+    /// assert!(!merger.contains("my-key"));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.live_cell(key).is_some()
+    }
+
+    /// Returns the number of callers that joined the execution for the key and have not
+    /// received its result yet.
+    ///
+    /// This counts the leader and its followers, including callers whose futures were
+    /// created but not yet polled. Returns `0` if no execution is tracked for the key.
+    #[must_use]
+    pub fn waiters<Q>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map_slot(key, |slot, now| {
+            slot.live(now).map_or(0, |cell| {
+                // Exclude the reference held by this method and by the retained result
+                Arc::strong_count(&cell) - 1 - usize::from(slot.retained.is_some())
+            })
+        })
+    }
+
+    fn live_cell<Q>(&self, key: &Q) -> Option<Arc<PanicAwareCell<T>>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map_slot(key, Slot::live)
+    }
+
+    /// Removes all entries that new callers can no longer join.
+    ///
+    /// This drops expired results retained by [`MergerBuilder::result_ttl`], as well as
+    /// entries of cancelled executions and of leaders that exceeded
+    /// [`MergerBuilder::leader_timeout`]. Such entries are otherwise only removed when their
+    /// key is requested again or when [`MergerBuilder::max_keys`] is reached. In-flight
+    /// executions and retained results that have not expired are kept.
+    ///
+    /// Returns the number of removed entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use tick::ClockControl;
+    /// use uniflight::Merger;
+    ///
+    /// # async fn example() {
+    /// let control = ClockControl::new();
+    /// let merger: Merger<String, i32> = Merger::builder(&control.to_clock())
+    ///     .result_ttl(Duration::from_secs(1))
+    ///     .build();
+    ///
+    /// assert_eq!(merger.execute("my-key", || async { 42 }).await, Ok(42));
+    /// assert_eq!(merger.len(), 1);
+    ///
+    /// control.advance(Duration::from_secs(1));
+    /// assert_eq!(merger.purge(), 1);
+    /// assert!(merger.is_empty());
+    /// # }
+    /// ```
+    #[expect(
+        clippy::must_use_candidate,
+        reason = "purging is useful without inspecting the number of removed entries"
+    )]
+    pub fn purge(&self) -> usize {
+        purge(&self.inner, self.options.now())
+    }
+
+    fn map_slot<Q, R: Default>(&self, key: &Q, f: impl FnOnce(&Slot<T>, Option<Instant>) -> R) -> R
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let now = self.options.now();
+        self.inner.get(key).map(|slot| f(&slot, now)).unwrap_or_default()
+    }
 }
 
 impl<K, T, S> ThreadAware for Merger<K, T, S>
@@ -331,8 +619,9 @@ where
     ///
     /// # Errors
     ///
-    /// Returns [`LeaderPanicked`] if the leader task panicked during execution.
-    /// Callers can retry by calling `execute` again if desired.
+    /// Returns [`ExecuteError::Panicked`] if the leader task panicked during execution, or
+    /// [`ExecuteError::Cancelled`] if the execution was cancelled. Callers can retry by
+    /// calling `execute` again if desired.
     ///
     /// # Example
     ///
@@ -347,7 +636,107 @@ where
     /// assert_eq!(result, Ok(42));
     /// # }
     /// ```
-    pub fn execute<Q, F, Fut>(&self, key: &Q, func: F) -> impl Future<Output = Result<T, LeaderPanicked>> + Send + use<Q, F, Fut, K, T, S>
+    pub fn execute<Q, F, Fut>(&self, key: &Q, func: F) -> impl Future<Output = Result<T, ExecuteError>> + Send + use<Q, F, Fut, K, T, S>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = T> + Send,
+        T: Clone,
+    {
+        self.execute_with(key, func, |_| true).1
+    }
+
+    /// Works like [`execute`][Self::execute], and additionally returns a handle that cancels
+    /// the execution the caller joined.
+    ///
+    /// Unlike [`cancel`][Self::cancel], the handle is bound to a single execution: when the
+    /// caller joined an execution that has since been cancelled or replaced, cancelling the
+    /// handle does not affect newer executions for the same key.
+    ///
+    /// # Errors
+    ///
+    /// The returned future fails the same way as the future returned by [`execute`][Self::execute].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use uniflight::{ExecuteError, Merger};
+    /// # async fn example() {
+    /// let merger: Merger<String, i32> = Merger::new();
+    /// let (handle, result) = merger.execute_cancellable("my-key", || std::future::pending());
+    ///
+    /// handle.cancel();
+    /// assert_eq!(result.await, Err(ExecuteError::Cancelled));
+    /// # }
+    /// ```
+    pub fn execute_cancellable<Q, F, Fut>(
+        &self,
+        key: &Q,
+        func: F,
+    ) -> (
+        CancelHandle,
+        impl Future<Output = Result<T, ExecuteError>> + Send + use<Q, F, Fut, K, T, S>,
+    )
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = T> + Send,
+        T: Clone,
+    {
+        self.execute_with(key, func, |_| true)
+    }
+
+    /// Cancels the in-flight execution for the given key.
+    ///
+    /// The leader stops executing at its next suspension point and every caller waiting on
+    /// the execution receives [`ExecuteError::Cancelled`]. The next caller for the key starts
+    /// a fresh execution. If the execution already completed, callers keep its result, but a
+    /// result retained by [`MergerBuilder::result_ttl`] is discarded.
+    ///
+    /// Returns `true` if an execution was in flight for the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use uniflight::{ExecuteError, Merger};
+    /// # async fn example() {
+    /// let merger: Merger<String, i32> = Merger::new();
+    /// let result = merger.execute("my-key", || std::future::pending());
+    ///
+    /// assert!(merger.cancel("my-key"));
+    /// assert_eq!(result.await, Err(ExecuteError::Cancelled));
+    /// # }
+    /// ```
+    pub fn cancel<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some((_, slot)) = self.inner.remove(key) else {
+            return false;
+        };
+        slot.cell.upgrade().is_some_and(|cell| {
+            cell.signal.cancel();
+            cell.inner.get().is_none()
+        })
+    }
+
+    /// Shared implementation of [`execute`][Self::execute] and [`try_execute`][Self::try_execute].
+    ///
+    /// When `is_settled` returns `false` for the result, the cell is detached from the map so
+    /// that the next caller starts a fresh execution. Callers that already joined the cell
+    /// still receive the result.
+    fn execute_with<Q, F, Fut>(
+        &self,
+        key: &Q,
+        func: F,
+        is_settled: fn(&Result<T, ExecuteError>) -> bool,
+    ) -> (
+        CancelHandle,
+        impl Future<Output = Result<T, ExecuteError>> + Send + use<Q, F, Fut, K, T, S>,
+    )
     where
         K: Borrow<Q>,
         Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
@@ -357,36 +746,113 @@ where
     {
         // Clone the TaArc - the async block owns this clone
         let inner = self.inner.clone();
-        let cell = Self::get_or_create_cell(&inner, key);
+        let options = Arc::clone(&self.options);
+        let mut cell = Self::get_or_create_cell(&inner, key, &options);
+        let mut rejected = false;
+        if let Some((max_waiters, policy)) = options.max_waiters
+            && cell.inner.get().is_none()
+            // Our own reference and the leader's are not counted as waiters
+            && Arc::strong_count(&cell) >= max_waiters.saturating_add(2)
+        {
+            match policy {
+                OverflowPolicy::Wait => {}
+                OverflowPolicy::ExecuteIndependently => cell = Arc::new(PanicAwareCell::new(None)),
+                OverflowPolicy::FailFast => rejected = true,
+            }
+        }
+        let handle = CancelHandle::new(Arc::clone(&cell.signal));
         let owned_key = key.to_owned();
-        async move {
+        let execution = async move {
+            if rejected {
+                return Err(ExecuteError::TooManyWaiters);
+            }
             // Box the future immediately to keep state machine size small.
             // Without boxing, the entire Fut type would be embedded in our state machine.
             // With boxing, we only store a thin pointer.
-            let boxed = Box::pin(func());
-            let result = cell.get_or_init(boxed).await.clone();
+            let mut future = Box::pin(func());
+            let mut role = Role::Follower;
+            let mut escaped = false;
+            let result = loop {
+                let executing = AtomicBool::new(false);
+                let execute = async {
+                    let started = cell.start();
+                    role = if escaped { Role::Promoted } else { started };
+                    executing.store(true, Ordering::Relaxed);
+                    if let Some(metrics) = &options.metrics {
+                        metrics.record_start(role);
+                    }
+                    options.hooks.started(role);
+                    let stopwatch = options.hooks.complete.as_ref().and(options.clock.as_ref()).map(Clock::stopwatch);
+                    let value = (&mut future).await;
+                    if let Some(stopwatch) = stopwatch {
+                        // Exclude the reference held by this caller
+                        options.hooks.completed(stopwatch.elapsed(), Arc::strong_count(&cell) - 1);
+                    }
+                    value
+                };
+                let wait = notify_joined(cell.get_or_init(execute), &options.hooks, &executing);
+                let result = match (cell.deadline, &options.clock) {
+                    (Some(deadline), Some(clock)) => wait_for_leader(wait, clock, deadline, &executing).await,
+                    _ => Some(wait.await),
+                };
+                if let Some(result) = result {
+                    break result.clone();
+                }
+                // The leader is stuck, start our own attempt while it continues. Our future
+                // was never polled, so it can still be used for the new attempt.
+                escaped = true;
+                cell = Self::get_or_create_cell(&inner, owned_key.borrow(), &options);
+            };
+            if role == Role::Follower
+                && let Some(metrics) = &options.metrics
+            {
+                metrics.record_coalesced();
+            }
+            if !is_settled(&result) {
+                // Detach our cell so that the next caller becomes a fresh leader
+                inner.remove_if(owned_key.borrow(), |_, slot| slot.holds(&cell));
+            } else if result.is_ok()
+                && let Some(ttl) = options.result_ttl
+                && let Some(now) = options.now()
+                && let Some(mut slot) = inner.get_mut(owned_key.borrow())
+            {
+                slot.retain(&cell, now + ttl);
+            }
             drop(cell); // Release our Arc before cleanup check
-            // Remove entry if no one else is using it (weak can't upgrade)
-            inner.remove_if(owned_key.borrow(), |_, weak| weak.upgrade().is_none());
+            // Remove entry if no one else is using it (weak can't upgrade) and its result,
+            // if retained, has expired
+            let now = options.now();
+            inner.remove_if(owned_key.borrow(), |_, slot| slot.live(now).is_none());
             result
-        }
+        };
+        (handle, execution)
     }
 
     /// Gets an existing cell for the key, or creates a new one.
-    fn get_or_create_cell<Q>(map: &DashMap<K, Weak<PanicAwareCell<T>>, RandomState>, key: &Q) -> Arc<PanicAwareCell<T>>
+    fn get_or_create_cell<Q>(map: &Map<K, T>, key: &Q, options: &Options) -> Arc<PanicAwareCell<T>>
     where
         K: Borrow<Q>,
         Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
     {
+        let now = options.now();
+
         // Fast path: check if entry exists and is still valid
         if let Some(entry) = map.get(key)
-            && let Some(cell) = entry.value().upgrade()
+            && let Some(cell) = entry.value().live(now)
         {
             return cell;
         }
 
+        // Make room before inserting. This can't happen while holding the entry below,
+        // as scanning the map would deadlock on its shard.
+        if let Some(max_keys) = options.max_keys
+            && map.len() >= max_keys
+        {
+            evict(map, max_keys, now);
+        }
+
         // Slow path: need to insert or replace expired entry
-        Self::insert_or_get_existing(map, key)
+        Self::insert_or_get_existing(map, key, now, options.leader_deadline(now))
     }
 
     /// Inserts a new cell or returns an existing live cell (handling races).
@@ -394,27 +860,27 @@ where
     /// This is the slow path of `get_or_create_cell`, separated for testability.
     /// It handles the case where another thread may have inserted a cell between
     /// our fast-path check and this insertion attempt.
-    fn insert_or_get_existing<Q>(map: &DashMap<K, Weak<PanicAwareCell<T>>, RandomState>, key: &Q) -> Arc<PanicAwareCell<T>>
+    fn insert_or_get_existing<Q>(map: &Map<K, T>, key: &Q, now: Option<Instant>, deadline: Option<Instant>) -> Arc<PanicAwareCell<T>>
     where
         K: Borrow<Q>,
         Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
     {
-        let cell = Arc::new(PanicAwareCell::new());
-        let weak = Arc::downgrade(&cell);
+        let cell = Arc::new(PanicAwareCell::new(deadline));
+        let slot = Slot::new(&cell);
 
         // Use Entry enum to atomically check-and-return or insert
         match map.entry(key.to_owned()) {
             Occupied(mut entry) => {
                 // Entry exists - check if still alive
-                if let Some(existing) = entry.get().upgrade() {
+                if let Some(existing) = entry.get().live(now) {
                     // Another thread's cell is still alive - use it
                     return existing;
                 }
                 // Expired - replace with ours
-                entry.insert(weak);
+                entry.insert(slot);
             }
             Vacant(entry) => {
-                entry.insert(weak);
+                entry.insert(slot);
             }
         }
 
@@ -423,11 +889,106 @@ where
     }
 }
 
+impl<K, T, E, S> Merger<K, Result<T, E>, S>
+where
+    K: Hash + Eq + Send + Sync,
+    T: Send + Sync,
+    E: Send + Sync,
+    S: Strategy + Send + Sync,
+{
+    /// Execute a fallible function, making sure that only one operation is in-flight
+    /// at a given moment, without keeping failures around.
+    ///
+    /// Works like [`execute`][Self::execute], except that an `Err` returned by the leader
+    /// is not treated as the settled value for the key. Callers that are already waiting
+    /// on the leader receive the error, but the next caller starts a fresh execution
+    /// instead of joining the failed one. This keeps a transient failure from being handed
+    /// to callers that arrive after it happened. A panicking leader is handled the same way.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExecuteError`] if the leader task panicked or the execution was cancelled.
+    /// Errors returned by `func` are delivered in the inner `Result`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use uniflight::Merger;
+    /// # async fn example() {
+    /// let merger: Merger<String, Result<i32, String>> = Merger::new();
+    ///
+    /// let result = merger
+    ///     .try_execute("my-key", || async { Err("unavailable".to_string()) })
+    ///     .await;
+    /// assert_eq!(result, Ok(Err("unavailable".to_string())));
+    ///
+    /// // The failure is not reused, the next call executes again
+    /// let result = merger.try_execute("my-key", || async { Ok(42) }).await;
+    /// assert_eq!(result, Ok(Ok(42)));
+    /// # }
+    /// ```
+    pub fn try_execute<Q, F, Fut>(
+        &self,
+        key: &Q,
+        func: F,
+    ) -> impl Future<Output = Result<Result<T, E>, ExecuteError>> + Send + use<Q, F, Fut, K, T, E, S>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = Result<T, E>> + Send,
+        T: Clone,
+        E: Clone,
+    {
+        self.execute_with(key, func, |result| matches!(result, Ok(Ok(_)))).1
+    }
+}
+
+/// Error returned when an execution did not produce a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExecuteError {
+    /// The leader task panicked during execution.
+    Panicked(LeaderPanicked),
+
+    /// The execution was cancelled through [`Merger::cancel`] or a [`CancelHandle`].
+    Cancelled,
+
+    /// The execution already had the maximum number of waiters configured by
+    /// [`MergerBuilder::max_waiters`] with [`OverflowPolicy::FailFast`].
+    TooManyWaiters,
+}
+
+impl std::fmt::Display for ExecuteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Panicked(panicked) => std::fmt::Display::fmt(panicked, f),
+            Self::Cancelled => f.write_str("execution was cancelled"),
+            Self::TooManyWaiters => f.write_str("too many callers are waiting for the execution"),
+        }
+    }
+}
+
+impl std::error::Error for ExecuteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Panicked(panicked) => Some(panicked),
+            Self::Cancelled | Self::TooManyWaiters => None,
+        }
+    }
+}
+
+impl From<LeaderPanicked> for ExecuteError {
+    fn from(panicked: LeaderPanicked) -> Self {
+        Self::Panicked(panicked)
+    }
+}
+
 /// Error returned when the leader task panicked during execution.
 ///
-/// When a leader task panics, followers receive this error instead of
-/// silently retrying. Callers can decide whether to retry by calling
-/// `execute` again.
+/// When a leader task panics, followers receive this error, wrapped in
+/// [`ExecuteError::Panicked`], instead of silently retrying. Callers can
+/// decide whether to retry by calling `execute` again.
 ///
 /// The panic message is captured and available via [`std::fmt::Display`] or [`LeaderPanicked::message`].
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -464,25 +1025,175 @@ fn extract_panic_message(payload: &(dyn std::any::Any + Send)) -> Arc<str> {
     Arc::from("unknown panic")
 }
 
+/// An entry of the internal map.
+struct Slot<T> {
+    cell: Weak<PanicAwareCell<T>>,
+
+    /// Keeps a completed cell alive until the deadline when a result TTL is configured.
+    retained: Option<(Arc<PanicAwareCell<T>>, Instant)>,
+}
+
+impl<T> Slot<T> {
+    fn new(cell: &Arc<PanicAwareCell<T>>) -> Self {
+        Self {
+            cell: Arc::downgrade(cell),
+            retained: None,
+        }
+    }
+
+    /// Returns the cell if new callers can still join it.
+    ///
+    /// A cancelled cell, a cell whose leader missed its deadline, or a cell whose retained
+    /// result has expired is never joined, even if other callers still hold it.
+    fn live(&self, now: Option<Instant>) -> Option<Arc<PanicAwareCell<T>>> {
+        match &self.retained {
+            Some((_, until)) if now.is_none_or(|now| now >= *until) => None,
+            _ => self.cell.upgrade().filter(|cell| cell.is_joinable(now)),
+        }
+    }
+
+    /// Returns `true` if this slot points to the given cell.
+    fn holds(&self, cell: &Arc<PanicAwareCell<T>>) -> bool {
+        std::ptr::eq(self.cell.as_ptr(), Arc::as_ptr(cell))
+    }
+
+    /// Keeps the completed cell alive until `until`, unless it is retained already.
+    fn retain(&mut self, cell: &Arc<PanicAwareCell<T>>, until: Instant) {
+        if self.retained.is_none() && self.holds(cell) {
+            self.retained = Some((Arc::clone(cell), until));
+        }
+    }
+}
+
+/// Removes entries that can no longer be joined and returns their count.
+fn purge<K: Hash + Eq, T>(map: &Map<K, T>, now: Option<Instant>) -> usize {
+    let mut removed = 0;
+    map.retain(|_, slot| {
+        let keep = slot.live(now).is_some();
+        removed += usize::from(!keep);
+        keep
+    });
+    removed
+}
+
+/// Makes room for a new entry in a map that tracks `max_keys` entries or more.
+///
+/// Stale entries are removed first, followed by the retained results closest to expiring,
+/// which are the least recently completed ones. In-flight executions are never evicted, so
+/// the map can exceed `max_keys` when all of its entries are in flight.
+fn evict<K: Hash + Eq, T>(map: &Map<K, T>, max_keys: usize, now: Option<Instant>) {
+    purge(map, now);
+
+    let excess = (map.len() + 1).saturating_sub(max_keys);
+    if excess == 0 {
+        return;
+    }
+
+    let mut deadlines: Vec<Instant> = map
+        .iter()
+        .filter_map(|entry| entry.retained.as_ref().map(|(_, until)| *until))
+        .collect();
+    if deadlines.is_empty() {
+        return;
+    }
+
+    let index = excess.min(deadlines.len()) - 1;
+    let (_, cutoff, _) = deadlines.select_nth_unstable(index);
+    let cutoff = *cutoff;
+    map.retain(|_, slot| slot.retained.as_ref().is_none_or(|(_, until)| *until > cutoff));
+}
+
+/// Waits for the result of an execution, notifying the hooks if the caller joined
+/// another caller's execution.
+async fn notify_joined<R>(wait: impl Future<Output = R>, hooks: &Hooks, executing: &AtomicBool) -> R {
+    let mut wait = std::pin::pin!(wait);
+    let mut first_poll = true;
+    std::future::poll_fn(move |cx| {
+        let poll = wait.as_mut().poll(cx);
+        if std::mem::take(&mut first_poll) && !executing.load(Ordering::Relaxed) {
+            hooks.follower_joined();
+        }
+        poll
+    })
+    .await
+}
+
+/// Waits for the result of an execution until the leader's deadline.
+///
+/// Returns `None` if the deadline passed while another caller was executing. A caller that
+/// executes itself always waits for its own result.
+async fn wait_for_leader<R>(wait: impl Future<Output = R>, clock: &Clock, deadline: Instant, executing: &AtomicBool) -> Option<R> {
+    let mut wait = std::pin::pin!(wait);
+    let mut delay = std::pin::pin!(clock.delay(deadline.saturating_duration_since(clock.instant())));
+    std::future::poll_fn(move |cx| {
+        if let Poll::Ready(result) = wait.as_mut().poll(cx) {
+            return Poll::Ready(Some(result));
+        }
+        if !executing.load(Ordering::Relaxed) && delay.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(None);
+        }
+        Poll::Pending
+    })
+    .await
+}
+
 struct PanicAwareCell<T> {
-    inner: OnceCell<Result<T, LeaderPanicked>>,
+    inner: OnceCell<Result<T, ExecuteError>>,
+    signal: Arc<CancelSignal>,
+    started: AtomicBool,
+
+    /// The time by which the leader has to produce its result before followers stop waiting.
+    deadline: Option<Instant>,
 }
 
 impl<T> PanicAwareCell<T> {
-    fn new() -> Self {
-        Self { inner: OnceCell::new() }
+    fn new(deadline: Option<Instant>) -> Self {
+        Self {
+            inner: OnceCell::new(),
+            signal: Arc::default(),
+            started: AtomicBool::new(false),
+            deadline,
+        }
+    }
+
+    /// Returns `true` if new callers can join the execution.
+    fn is_joinable(&self, now: Option<Instant>) -> bool {
+        if self.signal.is_cancelled() {
+            return false;
+        }
+        let overdue = self.deadline.zip(now).is_some_and(|(deadline, now)| now >= deadline);
+        !overdue || self.inner.get().is_some()
+    }
+
+    /// Marks the execution as started and returns the role of the caller that starts it.
+    fn start(&self) -> Role {
+        if self.started.swap(true, Ordering::AcqRel) {
+            Role::Promoted
+        } else {
+            Role::Leader
+        }
     }
 
     #[expect(clippy::future_not_send, reason = "Send bounds enforced by Merger::execute")]
-    async fn get_or_init<F>(&self, f: F) -> &Result<T, LeaderPanicked>
+    async fn get_or_init<F>(&self, f: F) -> &Result<T, ExecuteError>
     where
         F: Future<Output = T>,
     {
-        // Use map combinator instead of async block to avoid extra state machine
+        let mut f = std::pin::pin!(AssertUnwindSafe(f).catch_unwind());
+        let signal = &self.signal;
+
+        // Use poll_fn instead of an async block to avoid an extra state machine
         self.inner
-            .get_or_init(AssertUnwindSafe(f).catch_unwind().map(|result| {
-                result.map_err(|payload| LeaderPanicked {
-                    message: extract_panic_message(&*payload),
+            .get_or_init(std::future::poll_fn(move |cx| {
+                if signal.poll_cancelled(cx) {
+                    return Poll::Ready(Err(ExecuteError::Cancelled));
+                }
+                f.as_mut().poll(cx).map(|result| {
+                    result.map_err(|payload| {
+                        ExecuteError::Panicked(LeaderPanicked {
+                            message: extract_panic_message(&*payload),
+                        })
+                    })
                 })
             }))
             .await
@@ -491,9 +1202,8 @@ impl<T> PanicAwareCell<T> {
 
 #[cfg(test)]
 mod tests {
-    use std::time::Duration;
-
     use thread_aware::affinity::pinned_affinities;
+    use tick::ClockControl;
 
     use super::*;
 
@@ -512,35 +1222,91 @@ mod tests {
 
     #[test]
     fn fast_path_returns_existing() {
-        let map: DashMap<String, Weak<PanicAwareCell<String>>, RandomState> = DashMap::with_hasher(RandomState::new());
-        let existing_cell = Arc::new(PanicAwareCell::new());
-        map.insert("key".to_string(), Arc::downgrade(&existing_cell));
+        let map: Map<String, String> = DashMap::with_hasher(RandomState::new());
+        let existing_cell = Arc::new(PanicAwareCell::new(None));
+        map.insert("key".to_string(), Slot::new(&existing_cell));
 
-        let result = Merger::<String, String>::get_or_create_cell(&map, "key");
+        let result = Merger::<String, String>::get_or_create_cell(&map, "key", &Options::default());
 
         assert!(Arc::ptr_eq(&result, &existing_cell));
     }
 
     #[test]
     fn replaces_expired_entry() {
-        let map: DashMap<String, Weak<PanicAwareCell<String>>, RandomState> = DashMap::with_hasher(RandomState::new());
-        let expired_weak = Arc::downgrade(&Arc::new(PanicAwareCell::<String>::new()));
-        map.insert("key".to_string(), expired_weak);
+        let map: Map<String, String> = DashMap::with_hasher(RandomState::new());
+        let expired_slot = Slot::new(&Arc::new(PanicAwareCell::<String>::new(None)));
+        map.insert("key".to_string(), expired_slot);
 
-        let result = Merger::<String, String>::get_or_create_cell(&map, "key");
+        let result = Merger::<String, String>::get_or_create_cell(&map, "key", &Options::default());
 
         let entry = map.get("key").unwrap();
-        assert!(Arc::ptr_eq(&result, &entry.value().upgrade().unwrap()));
+        assert!(Arc::ptr_eq(&result, &entry.value().cell.upgrade().unwrap()));
+    }
+
+    #[test]
+    fn replaces_entry_with_expired_result() {
+        let map: Map<String, String> = DashMap::with_hasher(RandomState::new());
+        let control = ClockControl::new();
+        let options = Options {
+            clock: Some(control.to_clock()),
+            result_ttl: Some(Duration::from_secs(1)),
+            ..Options::default()
+        };
+        let retained_cell = Arc::new(PanicAwareCell::new(None));
+        let mut slot = Slot::new(&retained_cell);
+        slot.retain(&retained_cell, control.to_clock().instant() + Duration::from_secs(1));
+        map.insert("key".to_string(), slot);
+
+        // The retained result is reused before it expires
+        let result = Merger::<String, String>::get_or_create_cell(&map, "key", &options);
+        assert!(Arc::ptr_eq(&result, &retained_cell));
+
+        // Once expired, a new cell replaces it even though it is still held
+        control.advance(Duration::from_secs(1));
+        let result = Merger::<String, String>::get_or_create_cell(&map, "key", &options);
+        assert!(!Arc::ptr_eq(&result, &retained_cell));
+        assert!(map.get("key").unwrap().retained.is_none());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn overdue_cell_is_joinable_once_completed() {
+        let now = Instant::now();
+        let cell = PanicAwareCell::<String>::new(Some(now));
+        assert!(cell.is_joinable(None));
+        assert!(!cell.is_joinable(Some(now)));
+
+        let result = cell.get_or_init(async { "Result".to_string() }).await;
+        assert_eq!(result, &Ok("Result".to_string()));
+        assert!(cell.is_joinable(Some(now)));
+
+        cell.signal.cancel();
+        assert!(!cell.is_joinable(Some(now)));
+    }
+
+    #[test]
+    fn retain_ignores_other_cells() {
+        let cell = Arc::new(PanicAwareCell::<String>::new(None));
+        let other = Arc::new(PanicAwareCell::<String>::new(None));
+        let until = Instant::now();
+
+        let mut slot = Slot::new(&cell);
+        slot.retain(&other, until);
+        assert!(slot.retained.is_none());
+
+        slot.retain(&cell, until);
+        slot.retain(&cell, until + Duration::from_secs(1));
+        assert_eq!(slot.retained.as_ref().map(|(_, until)| *until), Some(until));
     }
 
     /// Simulates a race where another thread inserted between fast-path check and `entry()`.
     #[test]
     fn race_returns_existing() {
-        let map: DashMap<String, Weak<PanicAwareCell<String>>, RandomState> = DashMap::with_hasher(RandomState::new());
-        let other_cell = Arc::new(PanicAwareCell::new());
-        map.insert("key".to_string(), Arc::downgrade(&other_cell));
+        let map: Map<String, String> = DashMap::with_hasher(RandomState::new());
+        let other_cell = Arc::new(PanicAwareCell::new(None));
+        map.insert("key".to_string(), Slot::new(&other_cell));
 
-        let result = Merger::<String, String>::insert_or_get_existing(&map, "key");
+        let result = Merger::<String, String>::insert_or_get_existing(&map, "key", None, None);
 
         assert!(Arc::ptr_eq(&result, &other_cell));
     }
@@ -590,6 +1356,70 @@ mod tests {
         assert!(group.is_empty(), "Map should be empty after all keys complete");
     }
 
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn try_execute_detaches_failed_cell() {
+        let group: Merger<String, Result<String, String>> = Merger::new();
+
+        let leader = group.try_execute("key", || async { Err("failure".to_string()) });
+        let follower = group.try_execute("key", || async { Ok("unreachable".to_string()) });
+        assert_eq!(group.len(), 1);
+
+        // The follower still holds the failed cell, but the map no longer points to it
+        assert_eq!(leader.await, Ok(Err("failure".to_string())));
+        assert!(group.is_empty(), "failed cell should be detached from the map");
+
+        assert_eq!(follower.await, Ok(Err("failure".to_string())));
+        assert!(group.is_empty());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn try_execute_keeps_successful_cell() {
+        let group: Merger<String, Result<String, String>> = Merger::new();
+
+        let leader = group.try_execute("key", || async { Ok("value".to_string()) });
+        let follower = group.try_execute("key", || async { Ok("unreachable".to_string()) });
+
+        // The follower still holds the cell, so late callers can join it
+        assert_eq!(leader.await, Ok(Ok("value".to_string())));
+        assert_eq!(group.len(), 1);
+
+        assert_eq!(follower.await, Ok(Ok("value".to_string())));
+        assert!(group.is_empty());
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn result_ttl_retains_entry_until_expired() {
+        let control = ClockControl::new();
+        let group: Merger<String, String> = Merger::builder(&control.to_clock()).result_ttl(Duration::from_secs(1)).build();
+
+        let result = group.execute("key", || async { "Result".to_string() }).await;
+        assert_eq!(result, Ok("Result".to_string()));
+        assert_eq!(group.len(), 1, "completed result should be retained");
+
+        control.advance(Duration::from_secs(1));
+        let result = group.execute("key", || async { "Fresh".to_string() }).await;
+        assert_eq!(result, Ok("Fresh".to_string()));
+        assert_eq!(group.len(), 1, "fresh result should be retained");
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[tokio::test]
+    async fn result_ttl_does_not_retain_failures() {
+        let control = ClockControl::new();
+        let group: Merger<String, Result<String, String>> = Merger::builder(&control.to_clock()).result_ttl(Duration::from_secs(1)).build();
+
+        let result = group.try_execute("key", || async { Err("failure".to_string()) }).await;
+        assert_eq!(result, Ok(Err("failure".to_string())));
+        assert!(group.is_empty());
+
+        let result = group.execute("panic", || async { panic!("leader panicked") }).await;
+        assert!(matches!(result, Err(ExecuteError::Panicked(_))), "expected panic, got {result:?}");
+        assert!(group.is_empty());
+    }
+
     #[cfg_attr(miri, ignore)]
     #[tokio::test]
     async fn catch_unwind_works() {
@@ -608,7 +1438,7 @@ mod tests {
     #[cfg_attr(miri, ignore)]
     #[tokio::test]
     async fn panic_aware_cell_catches_panic() {
-        let cell = PanicAwareCell::<String>::new();
+        let cell = PanicAwareCell::<String>::new(None);
         let result = cell
             .get_or_init(async {
                 panic!("test panic");
@@ -617,7 +1447,9 @@ mod tests {
             })
             .await;
 
-        let err = result.as_ref().unwrap_err();
+        let Err(ExecuteError::Panicked(err)) = result else {
+            panic!("expected ExecuteError::Panicked, got {result:?}");
+        };
         assert_eq!(err.message(), "test panic");
     }
 
@@ -0,0 +1,96 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counters that describe how much work a [`Merger`][crate::Merger] deduplicates.
+///
+/// Pass the metrics to [`MergerBuilder::metrics`][crate::MergerBuilder::metrics] and read
+/// the counters at any time, for example to export them to a monitoring system. Clones share
+/// the same counters, so a single instance can also aggregate several mergers.
+///
+/// # Examples
+///
+/// ```
+/// use tick::Clock;
+/// use uniflight::{Merger, MergerMetrics};
+///
+/// # async fn example(clock: &Clock) {
+/// let metrics = MergerMetrics::new();
+/// let merger: Merger<String, i32> = Merger::builder(clock).metrics(&metrics).build();
+///
+/// let first = merger.execute("key", || async { 42 });
+/// let second = merger.execute("key", || async { 42 });
+/// let (first, second) = futures_util::future::join(first, second).await;
+/// assert_eq!((first, second), (Ok(42), Ok(42)));
+///
+/// assert_eq!(metrics.leader_executions(), 1);
+/// assert_eq!(metrics.coalesced_followers(), 1);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MergerMetrics {
+    counters: Arc<Counters>,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    leader_executions: AtomicU64,
+    coalesced_followers: AtomicU64,
+    promotions: AtomicU64,
+}
+
+impl MergerMetrics {
+    /// Creates metrics with all counters set to zero.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of times a caller started executing its function.
+    ///
+    /// This includes executions started by promoted followers.
+    #[must_use]
+    pub fn leader_executions(&self) -> u64 {
+        self.counters.leader_executions.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of callers that received the result of another caller's execution.
+    #[must_use]
+    pub fn coalesced_followers(&self) -> u64 {
+        self.counters.coalesced_followers.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of followers that took over an execution after its leader was
+    /// dropped before completing, or that started their own attempt after the leader
+    /// exceeded [`MergerBuilder::leader_timeout`][crate::MergerBuilder::leader_timeout].
+    #[must_use]
+    pub fn promotions(&self) -> u64 {
+        self.counters.promotions.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn record_start(&self, role: Role) {
+        self.counters.leader_executions.fetch_add(1, Ordering::Relaxed);
+        if role == Role::Promoted {
+            self.counters.promotions.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn record_coalesced(&self) {
+        self.counters.coalesced_followers.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// The part a caller took in an execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Role {
+    /// The caller started the execution.
+    Leader,
+
+    /// The caller took over the execution after an earlier leader was dropped or timed out.
+    Promoted,
+
+    /// The caller received the result of another caller's execution.
+    Follower,
+}
@@ -0,0 +1,167 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use std::any::{Any, TypeId};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use thread_aware::storage::Strategy;
+use tick::Clock;
+
+use crate::{Merger, MergerBuilder};
+
+/// A collection of named [`Merger`] groups that share a single handle.
+///
+/// Applications often coalesce several unrelated kinds of work, for example one group per
+/// downstream API or per cache. Instead of creating and passing around a `Merger` for each
+/// of them, a registry creates each group on first use and returns the same group for every
+/// later request of its name. Clones of the registry share the same groups.
+///
+/// Groups are identified by their name together with their key, value, and storage
+/// strategy types, so requesting a name with different types returns a different group.
+/// Each group has its own settings: [`group`][Self::group] creates a group with default
+/// settings, while [`group_with`][Self::group_with] configures it through a
+/// [`MergerBuilder`] using the clock passed to [`MergerRegistry::new`].
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use tick::Clock;
+/// use uniflight::{Merger, MergerRegistry};
+///
+/// # async fn example(clock: &Clock) {
+/// let registry = MergerRegistry::new(clock);
+///
+/// let users: Merger<String, String> = registry.group_with("users", |builder| {
+///     builder.result_ttl(Duration::from_secs(1))
+/// });
+/// let orders: Merger<u64, Vec<u8>> = registry.group("orders");
+///
+/// // Requesting a group again returns the same group
+/// assert_eq!(
+///     users
+///         .execute("alice", || async { "Alice".to_string() })
+///         .await,
+///     Ok("Alice".to_string())
+/// );
+/// let users: Merger<String, String> = registry.group("users");
+/// assert!(users.contains("alice"));
+/// # let _ = orders;
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MergerRegistry {
+    clock: Clock,
+    groups: Arc<Mutex<HashMap<GroupId, Box<dyn Group>>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct GroupId {
+    name: Cow<'static, str>,
+    types: TypeId,
+}
+
+impl MergerRegistry {
+    /// Creates an empty registry whose groups use the given clock.
+    #[must_use]
+    pub fn new(clock: &Clock) -> Self {
+        Self {
+            clock: clock.clone(),
+            groups: Arc::default(),
+        }
+    }
+
+    /// Returns the group with the given name, creating it with default settings if needed.
+    #[must_use]
+    pub fn group<K, T, S>(&self, name: impl Into<Cow<'static, str>>) -> Merger<K, T, S>
+    where
+        K: Hash + Eq + Send + Sync + 'static,
+        T: Send + Sync + 'static,
+        S: Strategy + Send + Sync + 'static,
+    {
+        self.group_with(name, |builder| builder)
+    }
+
+    /// Returns the group with the given name, creating it with the settings applied by
+    /// `configure` if needed.
+    ///
+    /// `configure` is only called when the group is created. Once a group exists, its
+    /// settings are not changed by later calls.
+    #[must_use]
+    #[expect(clippy::missing_panics_doc, reason = "the panic never happens as groups are keyed by their type")]
+    pub fn group_with<K, T, S>(
+        &self,
+        name: impl Into<Cow<'static, str>>,
+        configure: impl FnOnce(MergerBuilder<K, T, S>) -> MergerBuilder<K, T, S>,
+    ) -> Merger<K, T, S>
+    where
+        K: Hash + Eq + Send + Sync + 'static,
+        T: Send + Sync + 'static,
+        S: Strategy + Send + Sync + 'static,
+    {
+        let id = GroupId {
+            name: name.into(),
+            types: TypeId::of::<Merger<K, T, S>>(),
+        };
+        let mut groups = self.groups();
+        groups
+            .entry(id)
+            .or_insert_with(|| Box::new(configure(MergerBuilder::new(&self.clock)).build()))
+            .as_any()
+            .downcast_ref::<Merger<K, T, S>>()
+            .expect("groups are keyed by their type, so the stored group always has the requested type")
+            .clone()
+    }
+
+    /// Returns the number of groups in the registry.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.groups().len()
+    }
+
+    /// Returns `true` if the registry has no groups.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes the entries that new callers can no longer join from all groups.
+    ///
+    /// Returns the total number of removed entries. See [`Merger::purge`] for details.
+    #[expect(clippy::must_use_candidate, reason = "purging is useful without inspecting the count")]
+    pub fn purge(&self) -> usize {
+        self.groups().values().map(|group| group.purge()).sum()
+    }
+
+    fn groups(&self) -> MutexGuard<'_, HashMap<GroupId, Box<dyn Group>>> {
+        // A panic in `configure` leaves the map unchanged, so a poisoned lock is still usable
+        self.groups.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A type-erased group stored in a [`MergerRegistry`].
+trait Group: Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    fn purge(&self) -> usize;
+}
+
+impl<K, T, S> Group for Merger<K, T, S>
+where
+    K: Hash + Eq + Send + Sync + 'static,
+    T: Send + Sync + 'static,
+    S: Strategy + Send + Sync + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn purge(&self) -> usize {
+        Self::purge(self)
+    }
+}
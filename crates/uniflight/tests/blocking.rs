@@ -0,0 +1,102 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Integration tests for [`Merger::execute_blocking()`].
+
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::{AcqRel, Acquire};
+use std::thread;
+use std::time::Duration;
+
+use uniflight::{ExecuteError, Merger};
+
+/// Waits until the given number of callers joined the execution for the key.
+fn await_waiters(group: &Merger<String, String>, key: &str, count: usize) {
+    for _ in 0..500 {
+        if group.waiters(key) >= count {
+            return;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert!(group.waiters(key) >= count, "{count} callers should join before timeout");
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn blocking_callers_share_execution() {
+    let group: Merger<String, String> = Merger::new();
+    let call_counter = AtomicUsize::default();
+
+    let results: Vec<_> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                scope.spawn(|| {
+                    group.execute_blocking("key", || {
+                        call_counter.fetch_add(1, AcqRel);
+                        // Keep the execution in flight until all callers joined it
+                        await_waiters(&group, "key", 4);
+                        "Result".to_string()
+                    })
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("thread should not panic"))
+            .collect()
+    });
+
+    assert!(results.iter().all(|result| *result == Ok("Result".to_string())));
+    assert_eq!(call_counter.load(Acquire), 1);
+    assert!(group.is_empty());
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn blocking_leader_panic_is_returned() {
+    let group: Merger<String, String> = Merger::new();
+
+    let result = group.execute_blocking("key", || panic!("blocking panic"));
+    let Err(ExecuteError::Panicked(panicked)) = result else {
+        panic!("expected ExecuteError::Panicked, got {result:?}");
+    };
+    assert_eq!(panicked.message(), "blocking panic");
+
+    assert_eq!(
+        group.execute_blocking("key", || "Recovered".to_string()),
+        Ok("Recovered".to_string())
+    );
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test(flavor = "multi_thread")]
+async fn blocking_follower_joins_async_leader() {
+    let group: Arc<Merger<String, String>> = Arc::new(Merger::new());
+
+    let leader = tokio::spawn({
+        let group = Arc::clone(&group);
+        async move {
+            let waiting = Arc::clone(&group);
+            group
+                .execute("key", || async move {
+                    while waiting.waiters("key") < 2 {
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                    }
+                    "Async".to_string()
+                })
+                .await
+        }
+    });
+
+    let follower = tokio::task::spawn_blocking({
+        let group = Arc::clone(&group);
+        move || {
+            await_waiters(&group, "key", 1);
+            group.execute_blocking("key", || "Unreachable".to_string())
+        }
+    });
+
+    assert_eq!(leader.await.expect("task join"), Ok("Async".to_string()));
+    assert_eq!(follower.await.expect("task join"), Ok("Async".to_string()));
+}
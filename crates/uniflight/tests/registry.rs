@@ -0,0 +1,82 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+//! Integration tests for [`MergerRegistry`].
+
+use std::time::Duration;
+
+use tick::ClockControl;
+use uniflight::{Merger, MergerRegistry};
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn group_is_shared_by_name() {
+    let registry = MergerRegistry::new(&ClockControl::new().to_clock());
+    let first: Merger<String, String> = registry.group_with("users", |builder| builder.result_ttl(Duration::from_secs(1)));
+    let second: Merger<String, String> = registry.clone().group("users");
+
+    assert_eq!(
+        first.execute("key", || async { "First".to_string() }).await,
+        Ok("First".to_string())
+    );
+    assert_eq!(
+        second.execute("key", || async { "Second".to_string() }).await,
+        Ok("First".to_string())
+    );
+    assert_eq!(registry.len(), 1);
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn groups_have_independent_settings() {
+    let registry = MergerRegistry::new(&ClockControl::new().to_clock());
+    let cached: Merger<String, String> = registry.group_with("cached", |builder| builder.result_ttl(Duration::from_secs(1)));
+    let uncached: Merger<String, String> = registry.group("uncached");
+
+    cached
+        .execute("key", || async { "Result".to_string() })
+        .await
+        .expect("leader completes");
+    uncached
+        .execute("key", || async { "Result".to_string() })
+        .await
+        .expect("leader completes");
+
+    assert!(cached.contains("key"));
+    assert!(!uncached.contains("key"));
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn groups_are_keyed_by_type() {
+    let registry = MergerRegistry::new(&ClockControl::new().to_clock());
+    let strings: Merger<String, String> = registry.group("shared");
+    let numbers: Merger<u64, u64> = registry.group("shared");
+
+    assert_eq!(
+        strings.execute("key", || async { "Result".to_string() }).await,
+        Ok("Result".to_string())
+    );
+    assert_eq!(numbers.execute(&1, || async { 1 }).await, Ok(1));
+    assert_eq!(registry.len(), 2);
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn purge_covers_all_groups() {
+    let control = ClockControl::new();
+    let registry = MergerRegistry::new(&control.to_clock());
+    assert!(registry.is_empty());
+
+    for name in ["first", "second"] {
+        let group: Merger<String, String> = registry.group_with(name, |builder| builder.result_ttl(Duration::from_secs(1)));
+        group
+            .execute("key", || async { "Result".to_string() })
+            .await
+            .expect("leader completes");
+    }
+
+    assert_eq!(registry.purge(), 0);
+    control.advance(Duration::from_secs(1));
+    assert_eq!(registry.purge(), 2);
+}
@@ -1,22 +1,39 @@
 // Copyright (c) Microsoft Corporation.
 // Licensed under the MIT License.
 
-//! Integration tests for [`Merger::execute()`].
+//! Integration tests for [`Merger::execute()`] and [`Merger::try_execute()`].
 
+use std::pin::Pin;
 use std::sync::Arc;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering::{AcqRel, Acquire};
+use std::task::{Context, Poll, Waker};
 use std::time::Duration;
 
 use futures_util::StreamExt;
 use futures_util::stream::FuturesUnordered;
+use tick::{Clock, ClockControl};
 use tokio::sync::Notify;
-use uniflight::Merger;
+use uniflight::{ExecuteError, LeaderPanicked, Merger, MergerMetrics, OverflowPolicy};
 
 fn unreachable_future() -> std::future::Pending<String> {
     std::future::pending()
 }
 
+/// Polls the future once without a runtime.
+fn poll_once<F: Future + Unpin>(future: &mut F) -> Poll<F::Output> {
+    Pin::new(future).poll(&mut Context::from_waker(Waker::noop()))
+}
+
+/// Extracts the panic of the leader from a failed execution.
+fn unwrap_panicked<T: std::fmt::Debug>(result: Result<T, ExecuteError>) -> LeaderPanicked {
+    match result {
+        Err(ExecuteError::Panicked(panicked)) => Some(panicked),
+        _ => None,
+    }
+    .expect("execution should fail with a leader panic")
+}
+
 /// Waits for a [`Notify`] signal with a generous timeout, panicking with `msg`
 /// if the signal is not received. Prevents test hangs if synchronization breaks.
 async fn await_notify(notify: &Notify, msg: &str) {
@@ -196,11 +213,11 @@ async fn leader_panic_returns_error_to_all() {
     });
 
     // Leader gets LeaderPanicked error (panic is caught, not propagated)
-    let leader_err = leader_handle.await.expect("task should not panic - panic is caught").unwrap_err();
+    let leader_err = unwrap_panicked(leader_handle.await.expect("task should not panic - panic is caught"));
     assert_eq!(leader_err.message(), "leader panicked");
 
     // Follower also gets LeaderPanicked error with same message
-    let follower_err = follower_handle.await.expect("follower task should not panic").unwrap_err();
+    let follower_err = unwrap_panicked(follower_handle.await.expect("follower task should not panic"));
     assert_eq!(follower_err.message(), "leader panicked");
 }
 
@@ -287,9 +304,7 @@ async fn leader_panicked_error_traits() {
     // Create an error by triggering a panic
     let group: Merger<String, String> = Merger::new();
     let result = group.execute("key", || async { panic!("test message") }).await;
-    let Err(error) = result else {
-        panic!("expected Err");
-    };
+    let error = unwrap_panicked(result);
 
     // Test message()
     assert_eq!(error.message(), "test message");
@@ -322,9 +337,7 @@ async fn retry_after_panic_succeeds() {
 
     // First call panics
     let result = group.execute("key", || async { panic!("intentional panic") }).await;
-    let Err(err) = result else {
-        panic!("expected Err");
-    };
+    let err = unwrap_panicked(result);
     assert_eq!(err.message(), "intentional panic");
 
     // Retry with the same key should succeed
@@ -365,9 +378,7 @@ async fn mixed_panic_and_success() {
     let (panic_result, success_result) = tokio::join!(panic_fut, success_fut);
 
     // Panic key returns error with message
-    let Err(err) = panic_result else {
-        panic!("expected Err");
-    };
+    let err = unwrap_panicked(panic_result);
     assert_eq!(err.message(), "intentional panic");
 
     // Success key returns value
@@ -414,12 +425,651 @@ async fn follower_closure_not_called_on_panic() {
         }
     });
 
-    let leader_err = leader_handle.await.expect("task join").unwrap_err();
+    let leader_err = unwrap_panicked(leader_handle.await.expect("task join"));
     assert_eq!(leader_err.message(), "leader panic");
 
-    let follower_err = follower_handle.await.expect("task join").unwrap_err();
+    let follower_err = unwrap_panicked(follower_handle.await.expect("task join"));
     assert_eq!(follower_err.message(), "leader panic");
 
     // Follower's closure was never called
     assert_eq!(follower_called.load(Acquire), 0);
 }
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn try_execute_error_reaches_waiters_only() {
+    let group: Merger<String, Result<String, String>> = Merger::new();
+    let call_counter = AtomicUsize::default();
+
+    let leader = group.try_execute("key", || async {
+        call_counter.fetch_add(1, AcqRel);
+        Err("transient".to_string())
+    });
+    let follower = group.try_execute("key", || async {
+        call_counter.fetch_add(1, AcqRel);
+        Ok("unreachable".to_string())
+    });
+
+    assert_eq!(leader.await, Ok(Err("transient".to_string())));
+
+    // The follower joined before the failure and is still waiting on it, but a new
+    // caller does not receive the failure and becomes a fresh leader instead.
+    let next = group.try_execute("key", || async {
+        call_counter.fetch_add(1, AcqRel);
+        Ok("recovered".to_string())
+    });
+    assert_eq!(next.await, Ok(Ok("recovered".to_string())));
+    assert_eq!(follower.await, Ok(Err("transient".to_string())));
+    assert_eq!(call_counter.load(Acquire), 2);
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn execute_shares_error_with_late_callers() {
+    let group: Merger<String, Result<String, String>> = Merger::new();
+
+    let leader = group.execute("key", || async { Err("transient".to_string()) });
+    let follower = group.execute("key", || async { Ok("unreachable".to_string()) });
+
+    assert_eq!(leader.await, Ok(Err("transient".to_string())));
+
+    // Unlike try_execute, the error is the settled value while the follower holds the cell
+    let late = group.execute("key", || async { Ok("unreachable".to_string()) });
+    assert_eq!(late.await, Ok(Err("transient".to_string())));
+    assert_eq!(follower.await, Ok(Err("transient".to_string())));
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn try_execute_coalesces_success() {
+    let group: Merger<String, Result<String, String>> = Merger::new();
+    let call_counter = AtomicUsize::default();
+
+    let futures = FuturesUnordered::new();
+    for _ in 0..10 {
+        futures.push(group.try_execute("key", || async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            call_counter.fetch_add(1, AcqRel);
+            Ok("Result".to_string())
+        }));
+    }
+
+    assert!(futures.all(|out| async move { out == Ok(Ok("Result".to_string())) }).await);
+    assert_eq!(call_counter.load(Acquire), 1);
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn result_ttl_coalesces_calls_after_completion() {
+    let control = ClockControl::new();
+    let group: Merger<String, String> = Merger::builder(&control.to_clock()).result_ttl(Duration::from_secs(10)).build();
+    let call_counter = AtomicUsize::default();
+
+    let result = group
+        .execute("key", || async {
+            call_counter.fetch_add(1, AcqRel);
+            "First".to_string()
+        })
+        .await;
+    assert_eq!(result, Ok("First".to_string()));
+
+    // Calls within the TTL receive the completed result
+    control.advance(Duration::from_secs(5));
+    let result = group
+        .execute("key", || async {
+            call_counter.fetch_add(1, AcqRel);
+            "Second".to_string()
+        })
+        .await;
+    assert_eq!(result, Ok("First".to_string()));
+    assert_eq!(call_counter.load(Acquire), 1);
+
+    // Once the TTL elapses, a new leader executes
+    control.advance(Duration::from_secs(5));
+    let result = group
+        .execute("key", || async {
+            call_counter.fetch_add(1, AcqRel);
+            "Third".to_string()
+        })
+        .await;
+    assert_eq!(result, Ok("Third".to_string()));
+    assert_eq!(call_counter.load(Acquire), 2);
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn result_ttl_is_per_key() {
+    let control = ClockControl::new();
+    let group: Merger<String, String> = Merger::builder(&control.to_clock()).result_ttl(Duration::from_secs(10)).build();
+
+    assert_eq!(group.execute("a", || async { "A".to_string() }).await, Ok("A".to_string()));
+    assert_eq!(group.execute("b", || async { "B".to_string() }).await, Ok("B".to_string()));
+
+    assert_eq!(group.execute("a", unreachable_future).await, Ok("A".to_string()));
+    assert_eq!(group.execute("b", unreachable_future).await, Ok("B".to_string()));
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn cancel_wakes_all_waiters() {
+    let group: Arc<Merger<String, String>> = Arc::new(Merger::new());
+    let leader_started = Arc::new(Notify::new());
+
+    let leader_handle = tokio::spawn({
+        let group = Arc::clone(&group);
+        let leader_started = Arc::clone(&leader_started);
+        async move {
+            group
+                .execute("key", || async move {
+                    leader_started.notify_one();
+                    std::future::pending().await
+                })
+                .await
+        }
+    });
+    await_notify(&leader_started, "leader should start before timeout").await;
+
+    let follower = group.execute("key", unreachable_future);
+    assert!(group.cancel("key"));
+
+    assert_eq!(leader_handle.await.expect("task join"), Err(ExecuteError::Cancelled));
+    assert_eq!(follower.await, Err(ExecuteError::Cancelled));
+
+    // Nothing left to cancel, and the next caller starts a fresh execution
+    assert!(!group.cancel("key"));
+    let result = group.execute("key", || async { "Fresh".to_string() }).await;
+    assert_eq!(result, Ok("Fresh".to_string()));
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn cancel_before_leader_polled() {
+    let group: Merger<String, String> = Merger::new();
+    let call_counter = AtomicUsize::default();
+
+    let leader = group.execute("key", || async {
+        call_counter.fetch_add(1, AcqRel);
+        "Unreachable".to_string()
+    });
+    assert!(group.cancel("key"));
+
+    assert_eq!(leader.await, Err(ExecuteError::Cancelled));
+    assert_eq!(call_counter.load(Acquire), 0);
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn cancel_handle_targets_its_execution() {
+    let group: Merger<String, String> = Merger::new();
+
+    let (handle, first) = group.execute_cancellable("key", unreachable_future);
+    handle.cancel();
+    assert!(handle.is_cancelled());
+
+    // A cancelled execution is not joined by new callers
+    let (second_handle, second) = group.execute_cancellable("key", || async { "Second".to_string() });
+    assert!(!second_handle.is_cancelled());
+
+    // Cancelling the old handle again does not affect the newer execution
+    handle.cancel();
+    assert_eq!(first.await, Err(ExecuteError::Cancelled));
+    assert_eq!(second.await, Ok("Second".to_string()));
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn cancel_does_not_affect_completed_results() {
+    let group: Merger<String, String> = Merger::new();
+
+    let (handle, leader) = group.execute_cancellable("key", || async { "Result".to_string() });
+    let follower = group.execute("key", unreachable_future);
+
+    assert_eq!(leader.await, Ok("Result".to_string()));
+
+    // The execution completed, so the follower still receives its result
+    assert!(!group.cancel("key"));
+    handle.cancel();
+    assert_eq!(follower.await, Ok("Result".to_string()));
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn cancel_discards_retained_result() {
+    let control = ClockControl::new();
+    let group: Merger<String, String> = Merger::builder(&control.to_clock()).result_ttl(Duration::from_secs(10)).build();
+
+    assert_eq!(
+        group.execute("key", || async { "First".to_string() }).await,
+        Ok("First".to_string())
+    );
+    assert!(!group.cancel("key"));
+
+    let result = group.execute("key", || async { "Second".to_string() }).await;
+    assert_eq!(result, Ok("Second".to_string()));
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn execute_error_traits() {
+    let group: Merger<String, String> = Merger::new();
+    let result = group.execute("key", || async { panic!("test message") }).await;
+    let leader_panicked = unwrap_panicked(result.clone());
+    let panicked = result.unwrap_err();
+
+    assert_eq!(
+        panicked.to_string(),
+        "leader task panicked: test message",
+        "display should match the panic error"
+    );
+    let source = std::error::Error::source(&panicked).expect("panic should be the source");
+    assert!(source.is::<LeaderPanicked>());
+
+    let cancelled = ExecuteError::Cancelled;
+    assert_eq!(cancelled.to_string(), "execution was cancelled");
+    assert!(std::error::Error::source(&cancelled).is_none());
+
+    assert_eq!(ExecuteError::from(leader_panicked), panicked);
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn contains_and_waiters_track_execution() {
+    let group: Merger<String, String> = Merger::new();
+    assert!(!group.contains("key"));
+    assert_eq!(group.waiters("key"), 0);
+
+    let leader = group.execute("key", || async { "Result".to_string() });
+    let follower = group.execute("key", unreachable_future);
+    assert!(group.contains("key"));
+    assert!(!group.contains("other"));
+    assert_eq!(group.waiters("key"), 2);
+    assert_eq!(group.len(), 1);
+
+    assert_eq!(leader.await, Ok("Result".to_string()));
+    assert_eq!(group.waiters("key"), 1);
+
+    assert_eq!(follower.await, Ok("Result".to_string()));
+    assert!(!group.contains("key"));
+    assert_eq!(group.waiters("key"), 0);
+    assert!(group.is_empty());
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn waiters_exclude_retained_result() {
+    let control = ClockControl::new();
+    let group: Merger<String, String> = Merger::builder(&control.to_clock()).result_ttl(Duration::from_secs(10)).build();
+
+    assert_eq!(
+        group.execute("key", || async { "Result".to_string() }).await,
+        Ok("Result".to_string())
+    );
+    assert!(group.contains("key"));
+    assert_eq!(group.waiters("key"), 0);
+
+    control.advance(Duration::from_secs(10));
+    assert!(!group.contains("key"));
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn metrics_count_leaders_and_followers() {
+    let metrics = MergerMetrics::new();
+    let group: Merger<String, String> = Merger::builder(&Clock::new_frozen()).metrics(&metrics).build();
+
+    let futures = FuturesUnordered::new();
+    for _ in 0..5 {
+        futures.push(group.execute("key", || async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            "Result".to_string()
+        }));
+    }
+    assert!(futures.all(|out| async move { out == Ok("Result".to_string()) }).await);
+
+    assert_eq!(metrics.leader_executions(), 1);
+    assert_eq!(metrics.coalesced_followers(), 4);
+    assert_eq!(metrics.promotions(), 0);
+
+    // Separate executions are counted as separate leaders
+    assert_eq!(
+        group.execute("key", || async { "Again".to_string() }).await,
+        Ok("Again".to_string())
+    );
+    assert_eq!(metrics.leader_executions(), 2);
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn metrics_count_promotions() {
+    let metrics = MergerMetrics::new();
+    let group: Merger<String, String> = Merger::builder(&Clock::new_frozen()).metrics(&metrics).build();
+
+    let mut leader = Box::pin(group.execute("key", unreachable_future));
+    let follower = group.execute("key", || async { "Promoted".to_string() });
+
+    // Start the leader, then drop it before it completes
+    tokio::time::timeout(Duration::from_millis(10), &mut leader)
+        .await
+        .expect_err("leader should not complete");
+    drop(leader);
+
+    assert_eq!(follower.await, Ok("Promoted".to_string()));
+    assert_eq!(metrics.leader_executions(), 2);
+    assert_eq!(metrics.promotions(), 1);
+    assert_eq!(metrics.coalesced_followers(), 0);
+}
+
+#[test]
+fn leader_timeout_promotes_follower() {
+    let control = ClockControl::new();
+    let clock = control.to_clock();
+    let metrics = MergerMetrics::new();
+    let group: Merger<String, String> = Merger::builder(&clock)
+        .leader_timeout(Duration::from_secs(1))
+        .metrics(&metrics)
+        .build();
+
+    let mut leader = Box::pin(group.execute("key", || {
+        let clock = clock.clone();
+        async move {
+            clock.delay(Duration::from_secs(3)).await;
+            "Leader".to_string()
+        }
+    }));
+    let mut follower = Box::pin(group.execute("key", || async { "Follower".to_string() }));
+
+    assert!(poll_once(&mut leader).is_pending());
+    assert!(poll_once(&mut follower).is_pending());
+
+    // The leader is stuck, so the follower starts its own attempt
+    control.advance(Duration::from_secs(1));
+    assert_eq!(poll_once(&mut follower), Poll::Ready(Ok("Follower".to_string())));
+    assert_eq!(metrics.promotions(), 1);
+
+    // New callers no longer join the overdue leader
+    let mut late = Box::pin(group.execute("key", || async { "Late".to_string() }));
+    assert_eq!(poll_once(&mut late), Poll::Ready(Ok("Late".to_string())));
+
+    // The original leader keeps running and returns its own result
+    assert!(poll_once(&mut leader).is_pending());
+    control.advance(Duration::from_secs(2));
+    assert_eq!(poll_once(&mut leader), Poll::Ready(Ok("Leader".to_string())));
+    assert_eq!(metrics.leader_executions(), 3);
+}
+
+#[test]
+fn leader_timeout_promoted_follower_is_joined() {
+    let control = ClockControl::new();
+    let clock = control.to_clock();
+    let group: Merger<String, String> = Merger::builder(&clock).leader_timeout(Duration::from_secs(5)).build();
+    let call_counter = AtomicUsize::default();
+
+    let mut leader = Box::pin(group.execute("key", unreachable_future));
+    let mut first = Box::pin(group.execute("key", || async {
+        call_counter.fetch_add(1, AcqRel);
+        clock.delay(Duration::from_secs(1)).await;
+        "Promoted".to_string()
+    }));
+    let mut second = Box::pin(group.execute("key", || async {
+        call_counter.fetch_add(1, AcqRel);
+        "Unreachable".to_string()
+    }));
+
+    assert!(poll_once(&mut leader).is_pending());
+    assert!(poll_once(&mut first).is_pending());
+    assert!(poll_once(&mut second).is_pending());
+
+    control.advance(Duration::from_secs(5));
+    assert!(poll_once(&mut first).is_pending());
+
+    // The second follower joins the promoted attempt instead of starting another one
+    assert!(poll_once(&mut second).is_pending());
+    control.advance(Duration::from_secs(1));
+    assert_eq!(poll_once(&mut first), Poll::Ready(Ok("Promoted".to_string())));
+    assert_eq!(poll_once(&mut second), Poll::Ready(Ok("Promoted".to_string())));
+    assert_eq!(call_counter.load(Acquire), 1);
+    assert!(poll_once(&mut leader).is_pending());
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn leader_timeout_does_not_affect_fast_leaders() {
+    let control = ClockControl::new();
+    let group: Merger<String, String> = Merger::builder(&control.to_clock()).leader_timeout(Duration::from_secs(1)).build();
+
+    let leader = group.execute("key", || async { "Result".to_string() });
+    let follower = group.execute("key", unreachable_future);
+
+    let (leader, follower) = tokio::join!(leader, follower);
+    assert_eq!(leader, Ok("Result".to_string()));
+    assert_eq!(follower, Ok("Result".to_string()));
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn purge_removes_expired_results() {
+    let control = ClockControl::new();
+    let group: Merger<String, String> = Merger::builder(&control.to_clock()).result_ttl(Duration::from_secs(10)).build();
+
+    assert_eq!(group.execute("a", || async { "A".to_string() }).await, Ok("A".to_string()));
+    control.advance(Duration::from_secs(5));
+    assert_eq!(group.execute("b", || async { "B".to_string() }).await, Ok("B".to_string()));
+    let in_flight = group.execute("c", unreachable_future);
+    assert_eq!(group.len(), 3);
+
+    // Nothing expired yet
+    assert_eq!(group.purge(), 0);
+
+    control.advance(Duration::from_secs(5));
+    assert_eq!(group.purge(), 1);
+    assert!(!group.contains("a"));
+    assert!(group.contains("b"));
+    assert!(group.contains("c"));
+    drop(in_flight);
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn max_keys_evicts_oldest_results() {
+    let control = ClockControl::new();
+    let group: Merger<String, String> = Merger::builder(&control.to_clock())
+        .result_ttl(Duration::from_mins(1))
+        .max_keys(2)
+        .build();
+
+    assert_eq!(group.execute("a", || async { "A".to_string() }).await, Ok("A".to_string()));
+    control.advance(Duration::from_secs(1));
+    assert_eq!(group.execute("b", || async { "B".to_string() }).await, Ok("B".to_string()));
+    control.advance(Duration::from_secs(1));
+    assert_eq!(group.execute("c", || async { "C".to_string() }).await, Ok("C".to_string()));
+
+    assert_eq!(group.len(), 2);
+    assert!(!group.contains("a"), "oldest result should be evicted");
+    assert!(group.contains("b"));
+    assert!(group.contains("c"));
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn max_keys_never_evicts_in_flight() {
+    let group: Merger<String, String> = Merger::builder(&Clock::new_frozen()).max_keys(1).build();
+
+    let first = group.execute("a", || async { "A".to_string() });
+    let second = group.execute("b", || async { "B".to_string() });
+    assert_eq!(group.len(), 2);
+
+    let (first, second) = tokio::join!(first, second);
+    assert_eq!(first, Ok("A".to_string()));
+    assert_eq!(second, Ok("B".to_string()));
+    assert!(group.is_empty());
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn execute_future_is_static() {
+    fn assert_static<F: Future + Send + 'static>(future: F) -> F {
+        future
+    }
+
+    let group: Merger<String, String> = Merger::new();
+    let key = "key".to_string();
+
+    let leader = assert_static(group.execute(&key, || async {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        "Result".to_string()
+    }));
+    let follower = assert_static(group.execute(&key, unreachable_future));
+    drop(key);
+    drop(group);
+
+    // The futures outlive the borrowed key and the merger, so they can be detached
+    let leader = tokio::spawn(leader);
+    let follower = tokio::spawn(follower);
+    assert_eq!(leader.await.expect("task join"), Ok("Result".to_string()));
+    assert_eq!(follower.await.expect("task join"), Ok("Result".to_string()));
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn max_waiters_execute_independently() {
+    let group: Merger<String, String> = Merger::builder(&Clock::new_frozen())
+        .max_waiters(1, OverflowPolicy::ExecuteIndependently)
+        .build();
+    let call_counter = AtomicUsize::default();
+
+    let leader = group.execute("key", || async {
+        call_counter.fetch_add(1, AcqRel);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        "Shared".to_string()
+    });
+    let follower = group.execute("key", unreachable_future);
+    let overflow = group.execute("key", || async {
+        call_counter.fetch_add(1, AcqRel);
+        "Independent".to_string()
+    });
+    assert_eq!(group.waiters("key"), 2, "the overflowing caller should not join");
+
+    let (leader, follower, overflow) = tokio::join!(leader, follower, overflow);
+    assert_eq!(leader, Ok("Shared".to_string()));
+    assert_eq!(follower, Ok("Shared".to_string()));
+    assert_eq!(overflow, Ok("Independent".to_string()));
+    assert_eq!(call_counter.load(Acquire), 2);
+    assert!(group.is_empty());
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn max_waiters_fail_fast() {
+    let group: Merger<String, String> = Merger::builder(&Clock::new_frozen())
+        .max_waiters(0, OverflowPolicy::FailFast)
+        .build();
+
+    let leader = group.execute("key", || async { "Result".to_string() });
+    let overflow = group.execute("key", unreachable_future);
+
+    assert_eq!(overflow.await, Err(ExecuteError::TooManyWaiters));
+    assert_eq!(leader.await, Ok("Result".to_string()));
+    assert_eq!(
+        ExecuteError::TooManyWaiters.to_string(),
+        "too many callers are waiting for the execution"
+    );
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn max_waiters_wait() {
+    let group: Merger<String, String> = Merger::builder(&Clock::new_frozen()).max_waiters(0, OverflowPolicy::Wait).build();
+
+    let leader = group.execute("key", || async { "Result".to_string() });
+    let follower = group.execute("key", unreachable_future);
+    assert_eq!(group.waiters("key"), 2);
+
+    let (leader, follower) = tokio::join!(leader, follower);
+    assert_eq!(leader, Ok("Result".to_string()));
+    assert_eq!(follower, Ok("Result".to_string()));
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn max_waiters_fail_fast_does_not_detach_execution() {
+    let group: Merger<String, Result<String, String>> = Merger::builder(&Clock::new_frozen())
+        .max_waiters(0, OverflowPolicy::FailFast)
+        .build();
+
+    let leader = group.try_execute("key", || async { Ok("Result".to_string()) });
+    let overflow = group.try_execute("key", || async { Ok("Unreachable".to_string()) });
+    assert_eq!(overflow.await, Err(ExecuteError::TooManyWaiters));
+
+    // The rejected caller leaves the execution in place
+    assert!(group.contains("key"));
+    assert_eq!(leader.await, Ok(Ok("Result".to_string())));
+}
+
+#[test]
+fn hooks_report_lifecycle() {
+    let control = ClockControl::new();
+    let clock = control.to_clock();
+    let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let record = |event: &'static str| {
+        let events = Arc::clone(&events);
+        move || events.lock().expect("the test never panics while holding the lock").push(event)
+    };
+    let completed = Arc::clone(&events);
+    let group: Merger<String, String> = Merger::builder(&clock)
+        .on_leader_start(record("leader"))
+        .on_follower_joined(record("follower"))
+        .on_promotion(record("promotion"))
+        .on_complete(move |duration, waiters| {
+            assert_eq!(duration, Duration::from_secs(2));
+            assert_eq!(waiters, 1);
+            completed
+                .lock()
+                .expect("the test never panics while holding the lock")
+                .push("complete");
+        })
+        .build();
+
+    let mut leader = Box::pin(group.execute("key", || {
+        let clock = clock.clone();
+        async move {
+            clock.delay(Duration::from_secs(2)).await;
+            "Result".to_string()
+        }
+    }));
+    let mut follower = Box::pin(group.execute("key", unreachable_future));
+
+    assert!(poll_once(&mut leader).is_pending());
+    assert!(poll_once(&mut follower).is_pending());
+    control.advance(Duration::from_secs(2));
+    assert_eq!(poll_once(&mut leader), Poll::Ready(Ok("Result".to_string())));
+    assert_eq!(poll_once(&mut follower), Poll::Ready(Ok("Result".to_string())));
+
+    assert_eq!(
+        *events.lock().expect("the test never panics while holding the lock"),
+        ["leader", "follower", "complete"]
+    );
+}
+
+#[cfg_attr(miri, ignore)]
+#[tokio::test]
+async fn hooks_report_promotion() {
+    let promotions = Arc::new(AtomicUsize::default());
+    let counter = Arc::clone(&promotions);
+    let group: Merger<String, String> = Merger::builder(&Clock::new_frozen())
+        .on_promotion(move || {
+            counter.fetch_add(1, AcqRel);
+        })
+        .build();
+
+    let mut leader = Box::pin(group.execute("key", unreachable_future));
+    let follower = group.execute("key", || async { "Promoted".to_string() });
+
+    // Start the leader, then drop it before it completes
+    tokio::time::timeout(Duration::from_millis(10), &mut leader)
+        .await
+        .expect_err("leader should not complete");
+    drop(leader);
+
+    assert_eq!(follower.await, Ok("Promoted".to_string()));
+    assert_eq!(promotions.load(Acquire), 1);
+}